@@ -0,0 +1,113 @@
+//! Raw VM Service RPCs for the DevTools console panel.
+//!
+//! [`spawn_call_console_rpc`] sends a typed method and forwards the result as
+//! `Message::ConsoleRpcCompleted`. [`spawn_tail_console_stream`] marks a
+//! stream as tailed on the handle (so `forward_vm_events` echoes its events)
+//! and subscribes to it, cancelling the subscription again on untail if the
+//! console made it. [`spawn_fetch_console_extensions`] collects the
+//! registered service extensions for method completion.
+
+use tokio::sync::mpsc;
+
+use crate::message::Message;
+use crate::session::SessionId;
+use fdemon_daemon::vm_service::{
+    call_raw, is_stream_already_subscribed, list_extension_rpcs, VmRequestHandle,
+};
+
+/// Spawn a background task that sends a console RPC.
+///
+/// Sends `Message::ConsoleRpcCompleted` with the JSON result or the raw
+/// error string.
+pub(super) fn spawn_call_console_rpc(
+    session_id: SessionId,
+    method: String,
+    params: Option<serde_json::Map<String, serde_json::Value>>,
+    handle: VmRequestHandle,
+    msg_tx: mpsc::Sender<Message>,
+) {
+    tokio::spawn(async move {
+        let result = call_raw(&handle, &method, params)
+            .await
+            .map_err(|e| e.to_string());
+        let _ = msg_tx
+            .send(Message::ConsoleRpcCompleted {
+                session_id,
+                method,
+                result,
+            })
+            .await;
+    });
+}
+
+/// Spawn a background task that starts or stops echoing `stream_id`.
+///
+/// Tailing subscribes with `streamListen`; a stream fdemon already listens
+/// to answers error 103 ("stream already subscribed"), which counts as
+/// success but leaves the subscription to its owner. Untailing stops the
+/// echo and sends `streamCancel` only for a subscription the console made
+/// itself, since other panels may rely on theirs. Sends
+/// `Message::ConsoleStreamTailed` either way.
+pub(super) fn spawn_tail_console_stream(
+    session_id: SessionId,
+    stream_id: String,
+    tail: bool,
+    handle: VmRequestHandle,
+    msg_tx: mpsc::Sender<Message>,
+) {
+    tokio::spawn(async move {
+        handle.set_stream_tailed(&stream_id, tail);
+        let params = serde_json::json!({ "streamId": stream_id });
+        let result = if tail {
+            match call_raw(&handle, "streamListen", params.as_object().cloned()).await {
+                Ok(_) => {
+                    handle.mark_console_subscription(&stream_id);
+                    Ok(())
+                }
+                Err(e) if is_stream_already_subscribed(&e) => Ok(()),
+                Err(e) => {
+                    handle.set_stream_tailed(&stream_id, false);
+                    Err(e.to_string())
+                }
+            }
+        } else if handle.take_console_subscription(&stream_id) {
+            call_raw(&handle, "streamCancel", params.as_object().cloned())
+                .await
+                .map(|_| ())
+                .map_err(|e| e.to_string())
+        } else {
+            Ok(())
+        };
+        let _ = msg_tx
+            .send(Message::ConsoleStreamTailed {
+                session_id,
+                stream_id,
+                tailed: tail,
+                result,
+            })
+            .await;
+    });
+}
+
+/// Spawn a background task that lists the session's service extensions.
+///
+/// Sends `Message::ConsoleExtensionsFetched`; on failure the list is empty
+/// and completion falls back to the protocol methods.
+pub(super) fn spawn_fetch_console_extensions(
+    session_id: SessionId,
+    handle: VmRequestHandle,
+    msg_tx: mpsc::Sender<Message>,
+) {
+    tokio::spawn(async move {
+        let extensions = list_extension_rpcs(&handle).await.unwrap_or_else(|e| {
+            tracing::debug!("Console extension list failed: {}", e);
+            Vec::new()
+        });
+        let _ = msg_tx
+            .send(Message::ConsoleExtensionsFetched {
+                session_id,
+                extensions,
+            })
+            .await;
+    });
+}
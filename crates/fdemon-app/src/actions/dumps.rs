@@ -0,0 +1,107 @@
+//! Text dump fetching and saving for the DevTools dump viewer.
+//!
+//! [`spawn_fetch_dump`] calls one of the `ext.flutter.debugDump*` extensions
+//! and forwards the text as `Message::DumpFetched`. [`spawn_save_dump`] writes
+//! a loaded dump under `.fdemon/dumps/` and reports the path as
+//! `Message::DumpSaved`.
+
+use std::path::{Path, PathBuf};
+
+use tokio::sync::mpsc;
+
+use crate::dump_view_state::DebugDumpKind;
+use crate::message::Message;
+use crate::session::SessionId;
+use fdemon_daemon::vm_service::{parse_data_extension_response, VmRequestHandle};
+
+/// Directory (relative to the project root) where saved dumps are written.
+pub(crate) const DUMP_DIR: &str = ".fdemon/dumps";
+
+/// Spawn a background task that fetches a text dump of `kind`.
+///
+/// Sends `Message::DumpFetched` on success or `Message::DumpFetchFailed`
+/// with the raw error string otherwise.
+pub(super) fn spawn_fetch_dump(
+    session_id: SessionId,
+    kind: DebugDumpKind,
+    handle: VmRequestHandle,
+    msg_tx: mpsc::Sender<Message>,
+) {
+    tokio::spawn(async move {
+        let result = async {
+            let isolate_id = handle.main_isolate_id().await?;
+            let value = handle
+                .call_extension(kind.method(), &isolate_id, None)
+                .await?;
+            parse_data_extension_response(&value)
+        }
+        .await;
+
+        let msg = match result {
+            Ok(text) => Message::DumpFetched {
+                session_id,
+                kind,
+                text,
+            },
+            Err(e) => Message::DumpFetchFailed {
+                session_id,
+                kind,
+                error: e.to_string(),
+            },
+        };
+        let _ = msg_tx.send(msg).await;
+    });
+}
+
+/// Path of a saved dump: `.fdemon/dumps/<kind>-<timestamp>.txt`.
+fn dump_file_path(project_path: &Path, kind: DebugDumpKind, timestamp: &str) -> PathBuf {
+    project_path
+        .join(DUMP_DIR)
+        .join(format!("{}-{}.txt", kind.label().to_lowercase(), timestamp))
+}
+
+/// Spawn a blocking task that writes `text` to a new file under
+/// [`DUMP_DIR`] and sends `Message::DumpSaved` with the path or error.
+pub(super) fn spawn_save_dump(
+    project_path: &Path,
+    kind: DebugDumpKind,
+    text: String,
+    msg_tx: mpsc::Sender<Message>,
+) {
+    let timestamp = chrono::Local::now().format("%Y%m%d-%H%M%S").to_string();
+    let path = dump_file_path(project_path, kind, &timestamp);
+    tokio::spawn(async move {
+        let result = tokio::task::spawn_blocking(move || {
+            if let Some(dir) = path.parent() {
+                std::fs::create_dir_all(dir)?;
+            }
+            std::fs::write(&path, text)?;
+            Ok::<_, std::io::Error>(path)
+        })
+        .await;
+        let result = match result {
+            Ok(Ok(path)) => Ok(path),
+            Ok(Err(e)) => Err(e.to_string()),
+            Err(e) => Err(e.to_string()),
+        };
+        let _ = msg_tx.send(Message::DumpSaved { result }).await;
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dump_file_path() {
+        let path = dump_file_path(
+            Path::new("/app"),
+            DebugDumpKind::RenderTree,
+            "20260101-120000",
+        );
+        assert_eq!(
+            path,
+            PathBuf::from("/app/.fdemon/dumps/render-20260101-120000.txt")
+        );
+    }
+}
@@ -0,0 +1,74 @@
+//! App-registered service extensions for the DevTools Extensions panel.
+//!
+//! [`spawn_fetch_service_extensions`] lists the extensions registered by the
+//! DevTools isolate and [`spawn_call_service_extension`] calls one of them;
+//! both target the isolate picked with `I` (the main isolate by default).
+
+use std::collections::HashMap;
+
+use tokio::sync::mpsc;
+
+use crate::message::Message;
+use crate::session::SessionId;
+use fdemon_daemon::vm_service::{is_framework_extension, isolate_extension_rpcs, VmRequestHandle};
+
+/// Spawn a background task that lists the app-registered extensions.
+///
+/// Sends `Message::ServiceExtensionsFetched` with the extensions that are
+/// not registered by Flutter or the Dart SDK, or the raw error string.
+pub(super) fn spawn_fetch_service_extensions(
+    session_id: SessionId,
+    handle: VmRequestHandle,
+    msg_tx: mpsc::Sender<Message>,
+) {
+    tokio::spawn(async move {
+        let result = async {
+            let isolate_id = handle.target_isolate_id().await?;
+            isolate_extension_rpcs(&handle, &isolate_id).await
+        }
+        .await
+        .map(|methods| {
+            methods
+                .into_iter()
+                .filter(|m| !is_framework_extension(m))
+                .collect()
+        })
+        .map_err(|e| e.to_string());
+
+        let _ = msg_tx
+            .send(Message::ServiceExtensionsFetched { session_id, result })
+            .await;
+    });
+}
+
+/// Spawn a background task that calls `method` with `args`.
+///
+/// Sends `Message::ServiceExtensionCalled` with the response or the raw
+/// error string.
+pub(super) fn spawn_call_service_extension(
+    session_id: SessionId,
+    label: String,
+    method: String,
+    args: HashMap<String, String>,
+    handle: VmRequestHandle,
+    msg_tx: mpsc::Sender<Message>,
+) {
+    tokio::spawn(async move {
+        let result = async {
+            let isolate_id = handle.target_isolate_id().await?;
+            let args = (!args.is_empty()).then_some(args);
+            handle.call_extension(&method, &isolate_id, args).await
+        }
+        .await
+        .map_err(|e| e.to_string());
+
+        let _ = msg_tx
+            .send(Message::ServiceExtensionCalled {
+                session_id,
+                label,
+                method,
+                result,
+            })
+            .await;
+    });
+}
@@ -1,6 +1,7 @@
-//! DevTools inspector actions: widget tree, overlay toggle, layout explorer, and group disposal.
+//! DevTools inspector actions: widget tree, overlay toggle, device selection,
+//! layout explorer, properties, and group disposal.
 //!
-//! All functions are private to the `actions` module. The four `spawn_*`
+//! All functions are private to the `actions` module. The `spawn_*`
 //! entry points are called from `actions/mod.rs`'s `handle_action` dispatcher
 //! and are therefore `pub(super)`.
 //!
@@ -15,15 +16,28 @@ use std::time::Duration;
 
 use tokio::sync::mpsc;
 
-use crate::message::{DebugOverlayKind, Message};
+use crate::message::{DebugOverlayKind, DebugOverrideKind, Message};
 use crate::session::SessionId;
 use fdemon_daemon::vm_service::{
-    ext, extract_layout_info, parse_bool_extension_response, VmRequestHandle,
+    ext, extract_layout_info, next_brightness, next_platform, parse_bool_extension_response,
+    parse_diagnostics_node_response, parse_optional_diagnostics_node_response,
+    parse_string_extension_response, VmRequestHandle, PLATFORM_OVERRIDE_RESET,
+    SLOW_ANIMATIONS_TIME_DILATION,
 };
 
 /// Timeout for a single `getLayoutExplorerNode` RPC call.
 const LAYOUT_FETCH_TIMEOUT: Duration = Duration::from_secs(10);
 
+/// Timeout for a single `getDetailsSubtree` RPC call.
+const PROPERTIES_FETCH_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Object group scoping the references returned for the properties pane.
+const PROPERTIES_GROUP: &str = "devtools-properties";
+
+/// Depth of the details subtree: the widget's properties plus one level of
+/// nested property values (e.g. the fields of a `TextStyle`).
+const PROPERTIES_SUBTREE_DEPTH: u32 = 2;
+
 /// Spawn a background task that fetches the root widget tree via VM Service.
 ///
 /// Uses `ext.flutter.inspector.getRootWidgetTree` (with automatic fallback to
@@ -156,6 +170,27 @@ pub(super) fn spawn_toggle_overlay(
             DebugOverlayKind::RepaintRainbow => ext::REPAINT_RAINBOW,
             DebugOverlayKind::DebugPaint => ext::DEBUG_PAINT,
             DebugOverlayKind::PerformanceOverlay => ext::SHOW_PERFORMANCE_OVERLAY,
+            DebugOverlayKind::DebugBanner => ext::DEBUG_ALLOW_BANNER,
+            DebugOverlayKind::InvertOversizedImages => ext::INVERT_OVERSIZED_IMAGES,
+            DebugOverlayKind::ProfileWidgetBuilds => ext::PROFILE_WIDGET_BUILDS,
+            DebugOverlayKind::PaintBaselines => ext::DEBUG_PAINT_BASELINES,
+            DebugOverlayKind::SlowAnimations => {
+                // Not a boolean extension: flip `timeDilation` between 1.0
+                // and the slow factor instead.
+                match flip_slow_animations(&handle, &isolate_id).await {
+                    Ok(enabled) => {
+                        let _ = msg_tx
+                            .send(Message::DebugOverlayToggled { extension, enabled })
+                            .await;
+                    }
+                    Err(e) => tracing::debug!(
+                        "ToggleOverlay: slow animations unavailable for session {}: {}",
+                        session_id,
+                        e
+                    ),
+                }
+                return;
+            }
         };
 
         // Step 1: read the current state.
@@ -222,6 +257,219 @@ pub(super) fn spawn_toggle_overlay(
     });
 }
 
+/// Flip `ext.flutter.timeDilation` between `1.0` and
+/// [`SLOW_ANIMATIONS_TIME_DILATION`]. Returns whether animations are slowed
+/// after the change.
+async fn flip_slow_animations(
+    handle: &VmRequestHandle,
+    isolate_id: &str,
+) -> fdemon_core::Result<bool> {
+    let parse = |value: &serde_json::Value| -> fdemon_core::Result<f64> {
+        let text = parse_string_extension_response(value, "timeDilation")?;
+        text.parse().map_err(|_| {
+            fdemon_core::Error::protocol(format!("invalid timeDilation value: {text}"))
+        })
+    };
+    let current = parse(
+        &handle
+            .call_extension(ext::TIME_DILATION, isolate_id, None)
+            .await?,
+    )?;
+    let target = if current > 1.0 {
+        1.0
+    } else {
+        SLOW_ANIMATIONS_TIME_DILATION
+    };
+    let mut args = HashMap::new();
+    args.insert("timeDilation".to_string(), format!("{target:.1}"));
+    let new = parse(
+        &handle
+            .call_extension(ext::TIME_DILATION, isolate_id, Some(args))
+            .await?,
+    )?;
+    Ok(new > 1.0)
+}
+
+/// Spawn a background task that advances a debug override to its next value.
+///
+/// Sets the value after `current` (the next platform in `OVERRIDE_PLATFORMS`,
+/// then `"default"` to clear it; or the opposite brightness). Sends
+/// `Message::DebugOverrideChanged` with the value reported back by the
+/// extension, or `None` once the platform override is cleared; failures
+/// (e.g. release builds) are logged and dropped.
+pub(super) fn spawn_cycle_override(
+    session_id: SessionId,
+    kind: DebugOverrideKind,
+    current: Option<String>,
+    handle: VmRequestHandle,
+    msg_tx: mpsc::Sender<Message>,
+) {
+    tokio::spawn(async move {
+        let method = match kind {
+            DebugOverrideKind::Platform => ext::PLATFORM_OVERRIDE,
+            DebugOverrideKind::Brightness => ext::BRIGHTNESS_OVERRIDE,
+        };
+        let result = async {
+            let isolate_id = handle.main_isolate_id().await?;
+            let next = match kind {
+                DebugOverrideKind::Platform => next_platform(current.as_deref()),
+                DebugOverrideKind::Brightness => next_brightness(current.as_deref()),
+            };
+            let mut args = HashMap::new();
+            args.insert("value".to_string(), next.to_string());
+            let value = handle
+                .call_extension(method, &isolate_id, Some(args))
+                .await?;
+            let value = parse_string_extension_response(&value, "value")?;
+            // The getter reports the device's own platform once cleared.
+            Ok::<_, fdemon_core::Error>((next != PLATFORM_OVERRIDE_RESET).then_some(value))
+        }
+        .await;
+
+        match result {
+            Ok(value) => {
+                let _ = msg_tx
+                    .send(Message::DebugOverrideChanged { kind, value })
+                    .await;
+            }
+            Err(e) => tracing::debug!(
+                "CycleOverride: {:?} not available for session {}: {}",
+                kind,
+                session_id,
+                e
+            ),
+        }
+    });
+}
+
+/// Spawn a background task that sets `ext.flutter.inspector.show` on the device.
+///
+/// While enabled, taps on the device select widgets (reported as `Inspect`
+/// events on the Debug stream) instead of reaching the app. Sends
+/// `Message::InspectorSelectModeChanged` with the state reported by the
+/// device; failures are logged and leave the state unchanged.
+pub(super) fn spawn_set_select_mode(
+    session_id: SessionId,
+    enabled: bool,
+    handle: VmRequestHandle,
+    msg_tx: mpsc::Sender<Message>,
+) {
+    tokio::spawn(async move {
+        let isolate_id = match handle.main_isolate_id().await {
+            Ok(id) => id,
+            Err(e) => {
+                tracing::warn!(
+                    "SetInspectorSelectMode: could not get isolate ID for session {}: {}",
+                    session_id,
+                    e
+                );
+                return;
+            }
+        };
+
+        let mut args = HashMap::new();
+        args.insert("enabled".to_string(), enabled.to_string());
+        let result = handle
+            .call_extension(ext::INSPECTOR_SHOW, &isolate_id, Some(args))
+            .await;
+        let enabled = match result {
+            Ok(value) => match parse_bool_extension_response(&value) {
+                Ok(v) => v,
+                Err(e) => {
+                    tracing::warn!(
+                        "SetInspectorSelectMode: failed to parse response for session {}: {}",
+                        session_id,
+                        e
+                    );
+                    return;
+                }
+            },
+            Err(e) => {
+                tracing::debug!(
+                    "SetInspectorSelectMode: extension not available for session {}: {}",
+                    session_id,
+                    e
+                );
+                return;
+            }
+        };
+
+        let _ = msg_tx
+            .send(Message::InspectorSelectModeChanged {
+                session_id,
+                enabled,
+            })
+            .await;
+    });
+}
+
+/// Spawn a background task that fetches the widget selected on the device.
+///
+/// Uses `ext.flutter.inspector.getSelectedSummaryWidget` so the returned node
+/// is part of the summary tree shown in the tree panel, falling back to
+/// `getSelectedWidget` on Flutter versions without it. The node is fetched
+/// into the `"fdemon-inspector-1"` object group so its `valueId` matches the
+/// loaded tree.
+///
+/// Sends `Message::InspectorSelectedWidgetFetched` on success; failures are
+/// logged and ignored.
+pub(super) fn spawn_fetch_selected_widget(
+    session_id: SessionId,
+    handle: VmRequestHandle,
+    msg_tx: mpsc::Sender<Message>,
+) {
+    tokio::spawn(async move {
+        let isolate_id = match handle.main_isolate_id().await {
+            Ok(id) => id,
+            Err(e) => {
+                tracing::warn!(
+                    "FetchSelectedWidget: could not get isolate ID for session {}: {}",
+                    session_id,
+                    e
+                );
+                return;
+            }
+        };
+
+        let mut args = HashMap::new();
+        args.insert("objectGroup".to_string(), "fdemon-inspector-1".to_string());
+
+        let mut result = handle
+            .call_extension(
+                ext::GET_SELECTED_SUMMARY_WIDGET,
+                &isolate_id,
+                Some(args.clone()),
+            )
+            .await;
+        if matches!(&result, Err(e) if widget_tree::is_method_not_found(e)) {
+            result = handle
+                .call_extension(ext::GET_SELECTED_WIDGET, &isolate_id, Some(args))
+                .await;
+        }
+
+        let node = match result.map_err(|e| e.to_string()).and_then(|value| {
+            parse_optional_diagnostics_node_response(&value).map_err(|e| e.to_string())
+        }) {
+            Ok(node) => node,
+            Err(e) => {
+                tracing::warn!(
+                    "FetchSelectedWidget failed for session {}: {}",
+                    session_id,
+                    e
+                );
+                return;
+            }
+        };
+
+        let _ = msg_tx
+            .send(Message::InspectorSelectedWidgetFetched {
+                session_id,
+                node: node.map(Box::new),
+            })
+            .await;
+    });
+}
+
 /// Spawn a background task that fetches layout data for a widget node via VM Service.
 ///
 /// Uses `ext.flutter.inspector.getLayoutExplorerNode` to retrieve the layout
@@ -353,14 +601,101 @@ pub(super) fn spawn_fetch_layout_data(
     });
 }
 
-/// Spawn a background task that disposes both DevTools VM object groups.
+/// Spawn a background task that fetches every diagnostics property of a widget.
+///
+/// Uses `ext.flutter.inspector.getDetailsSubtree` with the widget's `valueId`
+/// as `arg`, in the `"devtools-properties"` object group (the previous group is
+/// disposed first, as for the layout explorer).
+///
+/// Sends `Message::WidgetPropertiesFetched` on success or
+/// `Message::WidgetPropertiesFetchFailed` on failure or timeout.
+pub(super) fn spawn_fetch_widget_properties(
+    session_id: SessionId,
+    node_id: String,
+    handle: VmRequestHandle,
+    msg_tx: mpsc::Sender<Message>,
+) {
+    tokio::spawn(async move {
+        let fetch_result = tokio::time::timeout(PROPERTIES_FETCH_TIMEOUT, async {
+            let isolate_id = handle
+                .main_isolate_id()
+                .await
+                .map_err(|e| format!("Could not get isolate ID: {e}"))?;
+
+            let mut dispose_args = HashMap::new();
+            dispose_args.insert("objectGroup".to_string(), PROPERTIES_GROUP.to_string());
+            if let Err(e) = handle
+                .call_extension(ext::DISPOSE_GROUP, &isolate_id, Some(dispose_args))
+                .await
+            {
+                tracing::debug!(
+                    "FetchWidgetProperties: disposeGroup '{}' failed for session {} \
+                     (non-fatal): {}",
+                    PROPERTIES_GROUP,
+                    session_id,
+                    e
+                );
+            }
+
+            let mut args = HashMap::new();
+            args.insert("arg".to_string(), node_id.clone());
+            args.insert("objectGroup".to_string(), PROPERTIES_GROUP.to_string());
+            args.insert(
+                "subtreeDepth".to_string(),
+                PROPERTIES_SUBTREE_DEPTH.to_string(),
+            );
+            let value = handle
+                .call_extension(ext::GET_DETAILS_SUBTREE, &isolate_id, Some(args))
+                .await
+                .map_err(|e| e.to_string())?;
+            parse_diagnostics_node_response(&value).map_err(|e| e.to_string())
+        })
+        .await;
+
+        let msg = match fetch_result {
+            Ok(Ok(details)) => Message::WidgetPropertiesFetched {
+                session_id,
+                node_id,
+                details: Box::new(details),
+            },
+            Ok(Err(error)) => {
+                tracing::warn!(
+                    "FetchWidgetProperties failed for session {}: {}",
+                    session_id,
+                    error
+                );
+                Message::WidgetPropertiesFetchFailed {
+                    session_id,
+                    node_id,
+                    error,
+                }
+            }
+            Err(_timeout) => {
+                tracing::warn!(
+                    "FetchWidgetProperties timed out after {}s for session {}",
+                    PROPERTIES_FETCH_TIMEOUT.as_secs(),
+                    session_id
+                );
+                Message::WidgetPropertiesFetchFailed {
+                    session_id,
+                    node_id,
+                    error: "Request timed out".to_string(),
+                }
+            }
+        };
+        let _ = msg_tx.send(msg).await;
+    });
+}
+
+/// Spawn a background task that disposes the DevTools VM object groups.
 ///
-/// Disposes `"fdemon-inspector-1"` (widget inspector) and `"devtools-layout"`
-/// (layout explorer) groups. Called when the user exits DevTools mode to release
-/// VM references held by the Flutter inspector and prevent memory accumulation
-/// during long debugging sessions.
+/// Disposes the `"fdemon-inspector-1"` (widget inspector), `"devtools-layout"`
+/// (layout explorer) and `"devtools-properties"` (properties pane) groups.
+/// Called when the user exits DevTools mode to release VM references held by
+/// the Flutter inspector and prevent memory accumulation during long debugging
+/// sessions.
 ///
-/// Both disposal calls are fire-and-forget: failures are logged at debug level
+/// All disposal calls are fire-and-forget: failures are logged at debug level
 /// and do not surface to the UI. `disposeGroup` is idempotent, so calling it
 /// when a group does not exist is also safe.
 pub(super) fn spawn_dispose_devtools_groups(session_id: SessionId, handle: VmRequestHandle) {
@@ -378,7 +713,7 @@ pub(super) fn spawn_dispose_devtools_groups(session_id: SessionId, handle: VmReq
             }
         };
 
-        for group in &["fdemon-inspector-1", "devtools-layout"] {
+        for group in &["fdemon-inspector-1", "devtools-layout", PROPERTIES_GROUP] {
             let mut args = HashMap::new();
             args.insert("objectGroup".to_string(), (*group).to_string());
             if let Err(e) = handle
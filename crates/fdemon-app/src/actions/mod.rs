@@ -18,11 +18,18 @@ use super::spawn;
 
 pub(super) mod session;
 
+pub(super) mod console;
+pub(super) mod dumps;
+pub(super) mod extensions;
 pub(super) mod inspector;
 pub(super) mod native_logs;
 pub(super) mod network;
+pub(super) mod perf_baseline;
+pub(super) mod perf_export;
 pub(super) mod performance;
+pub(super) mod profiler;
 pub(super) mod ready_check;
+pub(super) mod screenshot;
 pub(super) mod vm_service;
 
 /// Convenience type alias for session task tracking
@@ -72,6 +79,20 @@ pub fn handle_action(
             }
         }
 
+        UpdateAction::SpawnGroupTasks { tasks } => {
+            // Spawn every task concurrently with its session's command sender
+            for task in tasks {
+                let sender = session_senders
+                    .iter()
+                    .find(|(session_id, _, _)| *session_id == task.session_id())
+                    .map(|(_, _, sender)| sender.clone());
+                let msg_tx_clone = msg_tx.clone();
+                tokio::spawn(async move {
+                    session::execute_task(task, msg_tx_clone, sender).await;
+                });
+            }
+        }
+
         UpdateAction::DiscoverDevices { flutter } => {
             spawn::spawn_device_discovery(msg_tx, flutter);
         }
@@ -111,17 +132,27 @@ pub fn handle_action(
             );
         }
 
+        UpdateAction::DiscoverDevicesAndRestoreWorkspace {
+            configs,
+            workspace,
+            flutter,
+        } => {
+            spawn::spawn_workspace_restore(msg_tx, configs, workspace, flutter);
+        }
+
         UpdateAction::SpawnSession {
             session_id,
             device,
             config,
             flutter,
+            proxy,
         } => {
             session::spawn_session(
                 session_id,
                 device,
                 config,
                 flutter,
+                proxy,
                 project_path,
                 msg_tx,
                 session_tasks,
@@ -301,6 +332,319 @@ pub fn handle_action(
             }
         }
 
+        UpdateAction::CycleOverride {
+            session_id,
+            kind,
+            current,
+            vm_handle,
+        } => {
+            if let Some(handle) = vm_handle {
+                inspector::spawn_cycle_override(session_id, kind, current, handle, msg_tx);
+            } else {
+                warn!(
+                    "CycleOverride reached handle_action with no VmRequestHandle \
+                     for session {} — skipping",
+                    session_id
+                );
+            }
+        }
+
+        UpdateAction::FetchWidgetProperties {
+            session_id,
+            node_id,
+            vm_handle,
+        } => {
+            if let Some(handle) = vm_handle {
+                inspector::spawn_fetch_widget_properties(session_id, node_id, handle, msg_tx);
+            } else {
+                warn!(
+                    "FetchWidgetProperties reached handle_action with no VmRequestHandle \
+                     for session {} — skipping",
+                    session_id
+                );
+            }
+        }
+
+        UpdateAction::SetInspectorSelectMode {
+            session_id,
+            enabled,
+            vm_handle,
+        } => {
+            if let Some(handle) = vm_handle {
+                inspector::spawn_set_select_mode(session_id, enabled, handle, msg_tx);
+            } else {
+                warn!(
+                    "SetInspectorSelectMode reached handle_action with no VmRequestHandle \
+                     for session {} — skipping",
+                    session_id
+                );
+            }
+        }
+
+        UpdateAction::SetRebuildTracking {
+            session_id,
+            enabled,
+            vm_handle,
+        } => {
+            if let Some(handle) = vm_handle {
+                performance::spawn_set_rebuild_tracking(session_id, enabled, handle, msg_tx);
+            } else {
+                warn!(
+                    "SetRebuildTracking reached handle_action with no VmRequestHandle \
+                     for session {} — skipping",
+                    session_id
+                );
+            }
+        }
+
+        UpdateAction::FetchDump {
+            session_id,
+            kind,
+            vm_handle,
+        } => {
+            if let Some(handle) = vm_handle {
+                dumps::spawn_fetch_dump(session_id, kind, handle, msg_tx);
+            } else {
+                warn!(
+                    "FetchDump reached handle_action with no VmRequestHandle \
+                     for session {} — skipping",
+                    session_id
+                );
+            }
+        }
+
+        UpdateAction::SaveDump { kind, text } => {
+            dumps::spawn_save_dump(project_path, kind, text, msg_tx);
+        }
+
+        UpdateAction::ExportPerformance {
+            session_id,
+            capture,
+        } => {
+            perf_export::spawn_export_performance(project_path, session_id, capture, msg_tx);
+        }
+
+        UpdateAction::SavePerfBaseline {
+            session_id,
+            baseline,
+        } => {
+            perf_baseline::spawn_save_baseline(project_path, session_id, baseline, msg_tx);
+        }
+
+        UpdateAction::ComparePerfBaseline {
+            session_id,
+            current,
+            thresholds,
+        } => {
+            perf_baseline::spawn_compare_baseline(
+                project_path,
+                session_id,
+                current,
+                thresholds,
+                msg_tx,
+            );
+        }
+
+        UpdateAction::CaptureAllocationDiff {
+            session_id,
+            vm_handle,
+        } => {
+            if let Some(handle) = vm_handle {
+                performance::spawn_capture_allocation_diff(session_id, handle, msg_tx);
+            } else {
+                warn!(
+                    "CaptureAllocationDiff reached handle_action with no VmRequestHandle \
+                     for session {} — skipping",
+                    session_id
+                );
+            }
+        }
+
+        UpdateAction::CaptureLeakCheckpoint {
+            session_id,
+            vm_handle,
+        } => {
+            if let Some(handle) = vm_handle {
+                performance::spawn_capture_leak_checkpoint(session_id, handle, msg_tx);
+            } else {
+                warn!(
+                    "CaptureLeakCheckpoint reached handle_action with no VmRequestHandle \
+                     for session {} — skipping",
+                    session_id
+                );
+            }
+        }
+
+        UpdateAction::FetchInstances {
+            session_id,
+            class_id,
+            vm_handle,
+        } => {
+            if let Some(handle) = vm_handle {
+                performance::spawn_fetch_instances(session_id, class_id, handle, msg_tx);
+            } else {
+                warn!(
+                    "FetchInstances reached handle_action with no VmRequestHandle \
+                     for session {} — skipping",
+                    session_id
+                );
+            }
+        }
+
+        UpdateAction::InspectInstance {
+            session_id,
+            object_id,
+            vm_handle,
+        } => {
+            if let Some(handle) = vm_handle {
+                performance::spawn_inspect_instance(session_id, object_id, handle, msg_tx);
+            } else {
+                warn!(
+                    "InspectInstance reached handle_action with no VmRequestHandle \
+                     for session {} — skipping",
+                    session_id
+                );
+            }
+        }
+
+        UpdateAction::StartCpuProfiling {
+            session_id,
+            vm_handle,
+        } => {
+            if let Some(handle) = vm_handle {
+                profiler::spawn_start_cpu_profiling(session_id, handle, msg_tx);
+            } else {
+                warn!(
+                    "StartCpuProfiling reached handle_action with no VmRequestHandle \
+                     for session {} — skipping",
+                    session_id
+                );
+            }
+        }
+
+        UpdateAction::StopCpuProfiling {
+            session_id,
+            origin_micros,
+            vm_handle,
+        } => {
+            if let Some(handle) = vm_handle {
+                profiler::spawn_stop_cpu_profiling(session_id, origin_micros, handle, msg_tx);
+            } else {
+                warn!(
+                    "StopCpuProfiling reached handle_action with no VmRequestHandle \
+                     for session {} — skipping",
+                    session_id
+                );
+            }
+        }
+
+        UpdateAction::CallConsoleRpc {
+            session_id,
+            method,
+            params,
+            vm_handle,
+        } => {
+            if let Some(handle) = vm_handle {
+                console::spawn_call_console_rpc(session_id, method, params, handle, msg_tx);
+            } else {
+                warn!(
+                    "CallConsoleRpc reached handle_action with no VmRequestHandle \
+                     for session {} — skipping",
+                    session_id
+                );
+            }
+        }
+
+        UpdateAction::TailConsoleStream {
+            session_id,
+            stream_id,
+            tail,
+            vm_handle,
+        } => {
+            if let Some(handle) = vm_handle {
+                console::spawn_tail_console_stream(session_id, stream_id, tail, handle, msg_tx);
+            } else {
+                warn!(
+                    "TailConsoleStream reached handle_action with no VmRequestHandle \
+                     for session {} — skipping",
+                    session_id
+                );
+            }
+        }
+
+        UpdateAction::FetchConsoleExtensions {
+            session_id,
+            vm_handle,
+        } => {
+            if let Some(handle) = vm_handle {
+                console::spawn_fetch_console_extensions(session_id, handle, msg_tx);
+            } else {
+                warn!(
+                    "FetchConsoleExtensions reached handle_action with no VmRequestHandle \
+                     for session {} — skipping",
+                    session_id
+                );
+            }
+        }
+
+        UpdateAction::FetchServiceExtensions {
+            session_id,
+            vm_handle,
+        } => {
+            if let Some(handle) = vm_handle {
+                extensions::spawn_fetch_service_extensions(session_id, handle, msg_tx);
+            } else {
+                warn!(
+                    "FetchServiceExtensions reached handle_action with no VmRequestHandle \
+                     for session {} — skipping",
+                    session_id
+                );
+            }
+        }
+
+        UpdateAction::CallServiceExtension {
+            session_id,
+            label,
+            method,
+            args,
+            vm_handle,
+        } => {
+            if let Some(handle) = vm_handle {
+                extensions::spawn_call_service_extension(
+                    session_id, label, method, args, handle, msg_tx,
+                );
+            } else {
+                warn!(
+                    "CallServiceExtension reached handle_action with no VmRequestHandle \
+                     for session {} — skipping",
+                    session_id
+                );
+            }
+        }
+
+        UpdateAction::CaptureScreenshots {
+            targets,
+            dir,
+            flutter,
+        } => {
+            screenshot::spawn_capture_screenshots(targets, dir, flutter, msg_tx);
+        }
+
+        UpdateAction::FetchSelectedWidget {
+            session_id,
+            vm_handle,
+        } => {
+            if let Some(handle) = vm_handle {
+                inspector::spawn_fetch_selected_widget(session_id, handle, msg_tx);
+            } else {
+                warn!(
+                    "FetchSelectedWidget reached handle_action with no VmRequestHandle \
+                     for session {} — skipping",
+                    session_id
+                );
+            }
+        }
+
         // ─────────────────────────────────────────────────────────
         // DevTools Group Disposal (Phase 4, Task 07)
         // ─────────────────────────────────────────────────────────
@@ -388,6 +732,14 @@ pub fn handle_action(
             }
         }
 
+        UpdateAction::SpillNetworkEntries {
+            device_id,
+            entries,
+            max_entries,
+        } => {
+            network::spawn_spill_network_entries(project_path, &device_id, entries, max_entries);
+        }
+
         // ─────────────────────────────────────────────────────────────────────
         // Debug RPC Actions (DAP Server Phase 1, Task 05)
         //
@@ -26,13 +26,17 @@
 //! This reduces VM Service round-trip frequency while keeping network data
 //! reasonably fresh. In debug mode, [`NETWORK_POLL_MIN_MS`] applies.
 
+use std::io::Write;
+use std::path::{Path, PathBuf};
 use std::process::Command;
 
+use serde::Serialize;
 use tokio::sync::mpsc;
 
 use crate::config::FlutterMode;
 use crate::message::Message;
 use crate::session::SessionId;
+use fdemon_core::network::HttpProfileEntry;
 use fdemon_daemon::vm_service::{network, VmRequestHandle};
 
 /// Minimum network polling interval (500 ms) to avoid excessive VM Service calls.
@@ -146,7 +150,7 @@ pub(super) fn spawn_network_monitoring(
         }
 
         // Obtain the main isolate ID (cached after the first successful call).
-        let isolate_id = match handle.main_isolate_id().await {
+        let mut isolate_id = match handle.main_isolate_id().await {
             Ok(id) => id,
             Err(e) => {
                 tracing::warn!(
@@ -213,6 +217,8 @@ pub(super) fn spawn_network_monitoring(
         poll_tick.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
         // Track the last profile timestamp for incremental `updatedSince` polling.
         let mut last_timestamp: Option<i64> = None;
+        // Hot restart replaces the main isolate; follow it right away.
+        let mut isolate_changes = handle.isolate_changes();
 
         loop {
             tokio::select! {
@@ -229,6 +235,7 @@ pub(super) fn spawn_network_monitoring(
                                 && msg_tx
                                     .send(Message::VmServiceHttpProfileReceived {
                                         session_id,
+                                        isolate_id: isolate_id.clone(),
                                         timestamp: profile.timestamp,
                                         entries: profile.requests,
                                     })
@@ -248,6 +255,15 @@ pub(super) fn spawn_network_monitoring(
                                 session_id,
                                 e
                             );
+                            // The restart notification may have raced the
+                            // cache refresh; check again.
+                            follow_isolate_change(
+                                &handle,
+                                session_id,
+                                &mut isolate_id,
+                                &mut last_timestamp,
+                            )
+                            .await;
                         }
                     }
                 }
@@ -269,6 +285,7 @@ pub(super) fn spawn_network_monitoring(
                                     && msg_tx
                                         .send(Message::VmServiceHttpProfileReceived {
                                             session_id,
+                                            isolate_id: isolate_id.clone(),
                                             timestamp: profile.timestamp,
                                             entries: profile.requests,
                                         })
@@ -286,10 +303,26 @@ pub(super) fn spawn_network_monitoring(
                                     session_id,
                                     e
                                 );
+                                follow_isolate_change(
+                                    &handle,
+                                    session_id,
+                                    &mut isolate_id,
+                                    &mut last_timestamp,
+                                )
+                                .await;
                             }
                         }
                     }
                 }
+                Ok(()) = isolate_changes.changed() => {
+                    follow_isolate_change(
+                        &handle,
+                        session_id,
+                        &mut isolate_id,
+                        &mut last_timestamp,
+                    )
+                    .await;
+                }
                 _ = network_shutdown_rx.changed() => {
                     if *network_shutdown_rx.borrow() {
                         tracing::info!(
@@ -311,6 +344,42 @@ pub(super) fn spawn_network_monitoring(
         .map(|mut slot| *slot = Some(join_handle));
 }
 
+/// Re-resolve the main isolate after a hot restart or a failed poll.
+///
+/// Hot restart replaces the isolate (and invalidates the cached ID), so the
+/// old ID stops working. When the isolate changed, switch to it, restart
+/// incremental polling from scratch and re-enable HTTP timeline logging,
+/// which is per-isolate.
+async fn follow_isolate_change(
+    handle: &VmRequestHandle,
+    session_id: SessionId,
+    isolate_id: &mut String,
+    last_timestamp: &mut Option<i64>,
+) {
+    let Ok(current) = handle.main_isolate_id().await else {
+        return;
+    };
+    if current == *isolate_id {
+        return;
+    }
+    tracing::debug!(
+        "Network monitoring: isolate changed for session {} ({} -> {})",
+        session_id,
+        isolate_id,
+        current
+    );
+    *isolate_id = current;
+    *last_timestamp = None;
+    if let Err(e) = network::enable_http_timeline_logging_handle(handle, isolate_id, true).await {
+        tracing::debug!(
+            "Network monitoring: failed to re-enable HTTP timeline logging for \
+             session {}: {}",
+            session_id,
+            e
+        );
+    }
+}
+
 /// Spawn a one-shot task that fetches full detail for a single HTTP request.
 ///
 /// Uses `ext.dart.io.getHttpProfileRequest` to retrieve request/response
@@ -404,6 +473,113 @@ pub(super) fn spawn_clear_http_profile(session_id: SessionId, handle: VmRequestH
     });
 }
 
+/// Directory, relative to the project root, that evicted network entries
+/// are spilled to.
+pub(crate) const NETWORK_SPILL_DIR: &str = ".fdemon/network";
+
+/// One line of a network spill file.
+#[derive(Serialize)]
+struct SpilledEntry<'a> {
+    /// Restart generation the entry was captured in.
+    generation: u32,
+    #[serde(flatten)]
+    entry: &'a HttpProfileEntry,
+}
+
+/// Path of the spill file for a device. Characters that are not safe in
+/// file names (device IDs can contain `:` or `/`) are replaced with `_`.
+pub(crate) fn network_spill_path(project_path: &Path, device_id: &str) -> PathBuf {
+    let name: String = device_id
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '-' || c == '.' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    project_path
+        .join(NETWORK_SPILL_DIR)
+        .join(format!("{name}.jsonl"))
+}
+
+/// Append entries to a spill file as JSON lines, creating it if needed.
+///
+/// The file keeps at most `max_entries` lines: when an append would exceed
+/// that, the oldest lines are dropped and the file is rewritten in place
+/// (through a temporary file, so a crash never leaves it half-written).
+fn append_spilled_entries(
+    path: &Path,
+    entries: &[(u32, HttpProfileEntry)],
+    max_entries: usize,
+) -> std::io::Result<()> {
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    let mut lines = Vec::with_capacity(entries.len());
+    for (generation, entry) in entries {
+        let line = serde_json::to_string(&SpilledEntry {
+            generation: *generation,
+            entry,
+        })
+        .map_err(std::io::Error::other)?;
+        lines.push(line);
+    }
+
+    let existing = match std::fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => String::new(),
+        Err(e) => return Err(e),
+    };
+    let existing_count = existing.lines().count();
+
+    if existing_count + lines.len() <= max_entries {
+        let mut out = lines.join("\n");
+        out.push('\n');
+        let mut file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)?;
+        return file.write_all(out.as_bytes());
+    }
+
+    let kept: Vec<&str> = existing
+        .lines()
+        .chain(lines.iter().map(String::as_str))
+        .collect();
+    let kept = &kept[kept.len().saturating_sub(max_entries)..];
+    let mut out = kept.join("\n");
+    if !out.is_empty() {
+        out.push('\n');
+    }
+    let tmp = path.with_extension("jsonl.tmp");
+    std::fs::write(&tmp, out)?;
+    std::fs::rename(&tmp, path)
+}
+
+/// Spawn a blocking task that appends evicted network entries to the
+/// device's spill file, capped at `max_entries` lines. Fire-and-forget:
+/// errors are logged at warn level.
+pub(super) fn spawn_spill_network_entries(
+    project_path: &Path,
+    device_id: &str,
+    entries: Vec<(u32, HttpProfileEntry)>,
+    max_entries: usize,
+) {
+    let path = network_spill_path(project_path, device_id);
+    tokio::task::spawn_blocking(move || {
+        if let Err(e) = append_spilled_entries(&path, &entries, max_entries) {
+            tracing::warn!(
+                "Failed to spill {} network entries to {}: {}",
+                entries.len(),
+                path.display(),
+                e
+            );
+        }
+    });
+}
+
 /// Open a URL in the system browser (cross-platform, fire-and-forget).
 ///
 /// If `browser` is non-empty, uses it as the browser command.
@@ -553,4 +729,76 @@ mod tests {
             "multiplier should be applied after base clamp"
         );
     }
+
+    #[test]
+    fn test_network_spill_path_sanitizes_device_id() {
+        let path = network_spill_path(Path::new("/proj"), "emulator-5554:web/x");
+        assert_eq!(
+            path,
+            Path::new("/proj/.fdemon/network/emulator-5554_web_x.jsonl")
+        );
+    }
+
+    #[test]
+    fn test_append_spilled_entries_writes_json_lines() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = network_spill_path(dir.path(), "device");
+        let entry = HttpProfileEntry {
+            id: "1".to_string(),
+            method: "GET".to_string(),
+            uri: "https://example.com/".to_string(),
+            status_code: Some(200),
+            content_type: None,
+            start_time_us: 0,
+            end_time_us: Some(10),
+            request_content_length: None,
+            response_content_length: None,
+            error: None,
+        };
+        append_spilled_entries(&path, &[(0, entry.clone())], 10).unwrap();
+        append_spilled_entries(&path, &[(1, entry)], 10).unwrap();
+
+        let content = std::fs::read_to_string(&path).unwrap();
+        let lines: Vec<serde_json::Value> = content
+            .lines()
+            .map(|l| serde_json::from_str(l).unwrap())
+            .collect();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0]["generation"], 0);
+        assert_eq!(lines[1]["generation"], 1);
+        assert_eq!(lines[1]["id"], "1");
+        assert_eq!(lines[1]["status_code"], 200);
+    }
+
+    #[test]
+    fn test_append_spilled_entries_drops_oldest_lines_over_cap() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = network_spill_path(dir.path(), "device");
+        let entry = |id: &str| HttpProfileEntry {
+            id: id.to_string(),
+            method: "GET".to_string(),
+            uri: "https://example.com/".to_string(),
+            status_code: Some(200),
+            content_type: None,
+            start_time_us: 0,
+            end_time_us: Some(10),
+            request_content_length: None,
+            response_content_length: None,
+            error: None,
+        };
+        append_spilled_entries(&path, &[(0, entry("1")), (0, entry("2"))], 3).unwrap();
+        append_spilled_entries(&path, &[(0, entry("3")), (1, entry("4"))], 3).unwrap();
+        append_spilled_entries(&path, &[(1, entry("5"))], 3).unwrap();
+
+        let content = std::fs::read_to_string(&path).unwrap();
+        let ids: Vec<String> = content
+            .lines()
+            .map(|l| {
+                let value: serde_json::Value = serde_json::from_str(l).unwrap();
+                value["id"].as_str().unwrap().to_string()
+            })
+            .collect();
+        assert_eq!(ids, vec!["3", "4", "5"]);
+        assert!(!path.with_extension("jsonl.tmp").exists());
+    }
 }
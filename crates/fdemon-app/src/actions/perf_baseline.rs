@@ -0,0 +1,119 @@
+//! Performance baseline storage.
+//!
+//! Baselines are stored as pretty-printed JSON at
+//! `.fdemon/baselines/<name>.json`. [`spawn_save_baseline`] reports
+//! `Message::PerfBaselineSaved`; [`spawn_compare_baseline`] loads the baseline
+//! of the same name and reports `Message::PerfBaselineCompared`.
+
+use std::path::{Path, PathBuf};
+
+use tokio::sync::mpsc;
+
+use crate::message::Message;
+use crate::session::SessionId;
+use fdemon_core::{PerfBaseline, PerfThresholds};
+
+/// Directory (relative to the project root) where baselines are stored.
+pub(crate) const BASELINE_DIR: &str = ".fdemon/baselines";
+
+/// File of the baseline called `name`. Characters other than ASCII
+/// alphanumerics, `-`, `_` and `.` are replaced with `_`.
+fn baseline_path(project_path: &Path, name: &str) -> PathBuf {
+    let file: String = name
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.') {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    project_path.join(BASELINE_DIR).join(format!("{file}.json"))
+}
+
+fn write_baseline(path: &Path, baseline: &PerfBaseline) -> Result<(), String> {
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir).map_err(|e| e.to_string())?;
+    }
+    let json = serde_json::to_string_pretty(baseline).map_err(|e| e.to_string())?;
+    std::fs::write(path, json).map_err(|e| e.to_string())
+}
+
+fn read_baseline(path: &Path) -> Result<PerfBaseline, String> {
+    let json = std::fs::read_to_string(path).map_err(|e| match e.kind() {
+        std::io::ErrorKind::NotFound => format!("no baseline at {}", path.display()),
+        _ => e.to_string(),
+    })?;
+    serde_json::from_str(&json).map_err(|e| format!("invalid baseline {}: {e}", path.display()))
+}
+
+/// Spawn a blocking task that writes `baseline` and sends
+/// `Message::PerfBaselineSaved` with the file or error.
+pub(super) fn spawn_save_baseline(
+    project_path: &Path,
+    session_id: SessionId,
+    baseline: PerfBaseline,
+    msg_tx: mpsc::Sender<Message>,
+) {
+    let path = baseline_path(project_path, &baseline.name);
+    tokio::spawn(async move {
+        let result = tokio::task::spawn_blocking(move || {
+            write_baseline(&path, &baseline)?;
+            Ok(path)
+        })
+        .await
+        .unwrap_or_else(|e| Err(e.to_string()));
+        let _ = msg_tx
+            .send(Message::PerfBaselineSaved { session_id, result })
+            .await;
+    });
+}
+
+/// Spawn a blocking task that loads the baseline named after `current` and
+/// sends `Message::PerfBaselineCompared` with the comparison or error.
+pub(super) fn spawn_compare_baseline(
+    project_path: &Path,
+    session_id: SessionId,
+    current: PerfBaseline,
+    thresholds: PerfThresholds,
+    msg_tx: mpsc::Sender<Message>,
+) {
+    let path = baseline_path(project_path, &current.name);
+    tokio::spawn(async move {
+        let result = tokio::task::spawn_blocking(move || {
+            let baseline = read_baseline(&path)?;
+            Ok(baseline.compare(&current, &thresholds))
+        })
+        .await
+        .unwrap_or_else(|e| Err(e.to_string()));
+        let _ = msg_tx
+            .send(Message::PerfBaselineCompared { session_id, result })
+            .await;
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fdemon_core::PerfCapture;
+
+    #[test]
+    fn test_baseline_path_sanitizes_name() {
+        assert_eq!(
+            baseline_path(Path::new("/app"), "release/1.2 scroll"),
+            PathBuf::from("/app/.fdemon/baselines/release_1.2_scroll.json")
+        );
+    }
+
+    #[test]
+    fn test_baseline_round_trip() {
+        let tmp = tempfile::tempdir().unwrap();
+        let path = baseline_path(tmp.path(), "scroll");
+        assert!(read_baseline(&path).unwrap_err().starts_with("no baseline"));
+
+        let baseline = PerfBaseline::from_capture("scroll", &PerfCapture::default());
+        write_baseline(&path, &baseline).unwrap();
+        assert_eq!(read_baseline(&path).unwrap(), baseline);
+    }
+}
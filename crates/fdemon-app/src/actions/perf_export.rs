@@ -0,0 +1,100 @@
+//! Performance data export.
+//!
+//! [`spawn_export_performance`] writes a [`PerfCapture`] to a new directory
+//! under `.fdemon/perf/` and reports it as `Message::PerformanceExported`:
+//!
+//! - `frames.csv`, `memory.csv`, `gc.csv` — one row per sample
+//! - `capture.json` — every series plus a summary
+//! - `trace.json` — Chrome trace events, loadable in Perfetto
+
+use std::path::{Path, PathBuf};
+
+use tokio::sync::mpsc;
+
+use crate::message::Message;
+use crate::session::SessionId;
+use fdemon_core::PerfCapture;
+
+/// Directory (relative to the project root) where exports are written.
+pub(crate) const PERF_EXPORT_DIR: &str = ".fdemon/perf";
+
+/// Directory of one export: `.fdemon/perf/<timestamp>-session<id>/`.
+fn export_dir(project_path: &Path, session_id: SessionId, timestamp: &str) -> PathBuf {
+    project_path
+        .join(PERF_EXPORT_DIR)
+        .join(format!("{timestamp}-session{session_id}"))
+}
+
+/// Write every export file for `capture` into `dir`.
+fn write_export(dir: &Path, capture: &PerfCapture) -> std::io::Result<()> {
+    std::fs::create_dir_all(dir)?;
+    std::fs::write(dir.join("frames.csv"), capture.frames_csv())?;
+    std::fs::write(dir.join("memory.csv"), capture.memory_csv())?;
+    std::fs::write(dir.join("gc.csv"), capture.gc_csv())?;
+    std::fs::write(
+        dir.join("capture.json"),
+        serde_json::to_string_pretty(&capture.to_json())?,
+    )?;
+    std::fs::write(
+        dir.join("trace.json"),
+        serde_json::to_string(&capture.to_chrome_trace())?,
+    )?;
+    Ok(())
+}
+
+/// Spawn a blocking task that writes `capture` to a new export directory and
+/// sends `Message::PerformanceExported` with the directory or error.
+pub(super) fn spawn_export_performance(
+    project_path: &Path,
+    session_id: SessionId,
+    capture: PerfCapture,
+    msg_tx: mpsc::Sender<Message>,
+) {
+    let timestamp = chrono::Local::now().format("%Y%m%d-%H%M%S").to_string();
+    let dir = export_dir(project_path, session_id, &timestamp);
+    tokio::spawn(async move {
+        let result = tokio::task::spawn_blocking(move || {
+            write_export(&dir, &capture)?;
+            Ok::<_, std::io::Error>(dir)
+        })
+        .await;
+        let result = match result {
+            Ok(Ok(dir)) => Ok(dir),
+            Ok(Err(e)) => Err(e.to_string()),
+            Err(e) => Err(e.to_string()),
+        };
+        let _ = msg_tx
+            .send(Message::PerformanceExported { session_id, result })
+            .await;
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_export_dir() {
+        let dir = export_dir(Path::new("/app"), 3, "20260101-120000");
+        assert_eq!(
+            dir,
+            PathBuf::from("/app/.fdemon/perf/20260101-120000-session3")
+        );
+    }
+
+    #[test]
+    fn test_write_export_creates_all_files() {
+        let tmp = tempfile::tempdir().unwrap();
+        let dir = tmp.path().join("export");
+        write_export(&dir, &PerfCapture::default()).unwrap();
+        for name in [
+            "frames.csv",
+            "memory.csv",
+            "gc.csv",
+            "capture.json",
+            "trace.json",
+        ] {
+            assert!(dir.join(name).exists(), "missing {name}");
+        }
+    }
+}
@@ -5,7 +5,15 @@
 //!
 //! The single public-to-module entry point is [`spawn_performance_polling`],
 //! called from `mod.rs`'s `handle_action` dispatcher for the
-//! `StartPerformanceMonitoring` action.
+//! `StartPerformanceMonitoring` action. [`spawn_set_rebuild_tracking`] handles
+//! the one-shot `SetRebuildTracking` toggle, [`spawn_capture_allocation_diff`]
+//! the one-shot `CaptureAllocationDiff` fetch, [`spawn_capture_leak_checkpoint`]
+//! the delayed `CaptureLeakCheckpoint` fetch, and [`spawn_fetch_instances`] /
+//! [`spawn_inspect_instance`] the instance browser RPCs.
+//!
+//! Before polling starts, the task asks the engine for the display refresh
+//! rate once and reports it as `VmServiceDisplayRefreshRate`, so jank is
+//! measured against the device's real frame budget.
 //!
 //! **Polling strategy:**
 //! - Memory tick (every `performance_refresh_ms`, min [`PERF_POLL_MIN_MS`]):
@@ -16,6 +24,9 @@
 //! - Allocation tick (every `allocation_profile_interval_ms`, min
 //!   [`ALLOC_PROFILE_POLL_MIN_MS`]): calls `getAllocationProfile` (expensive —
 //!   forces a full heap walk), so it runs at a lower frequency than the memory tick.
+//! - Timeline recording of the frame phase streams is turned on while the
+//!   allocation gate is open (Performance panel visible) and the previous
+//!   `getVMTimelineFlags` streams are put back when it closes or the task ends.
 //!
 //! **Mode-aware scaling:**
 //! In profile/release mode, both intervals are scaled by [`PROFILE_MODE_MULTIPLIER`]
@@ -24,6 +35,7 @@
 //! VM Service pressure from ~4 RPCs/sec (debug) to ~1.2 RPCs/sec (profile) with
 //! the reporter's aggressive 500ms/1000ms settings, eliminating observable jank.
 
+use std::collections::HashMap;
 use std::time::Duration;
 
 use tokio::sync::mpsc;
@@ -32,7 +44,11 @@ use tracing::info;
 use crate::config::FlutterMode;
 use crate::message::Message;
 use crate::session::SessionId;
-use fdemon_daemon::vm_service::VmRequestHandle;
+use fdemon_core::InstanceDetails;
+use fdemon_daemon::vm_service::{
+    enable_frame_timeline, ext, get_display_refresh_rate, get_instance_fields, get_instances,
+    get_retaining_path, parse_bool_extension_response, restore_timeline_streams, VmRequestHandle,
+};
 
 /// Minimum polling interval for memory usage (500ms) to prevent excessive VM Service calls.
 pub(super) const PERF_POLL_MIN_MS: u64 = 500;
@@ -217,12 +233,36 @@ pub(super) fn spawn_performance_polling(
             return;
         }
 
+        // Not every embedder implements the refresh rate query; without it
+        // the session keeps its configured or 60 Hz budget.
+        match get_display_refresh_rate(&handle).await {
+            Ok(refresh_rate_hz) => {
+                let _ = msg_tx
+                    .send(Message::VmServiceDisplayRefreshRate {
+                        session_id,
+                        refresh_rate_hz,
+                    })
+                    .await;
+            }
+            Err(e) => {
+                tracing::debug!(
+                    "Display refresh rate unavailable for session {}: {}",
+                    session_id,
+                    e
+                );
+            }
+        }
+
         let mut memory_tick = tokio::time::interval(memory_interval);
         memory_tick.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
 
         let mut alloc_tick = tokio::time::interval(alloc_interval);
         alloc_tick.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
 
+        // Timeline streams recorded before frame phase recording was turned
+        // on; `Some` while it is on.
+        let mut recorded_before: Option<Vec<String>> = None;
+
         loop {
             tokio::select! {
                 _ = memory_tick.tick() => {
@@ -233,9 +273,9 @@ pub(super) fn spawn_performance_polling(
                         continue;
                     }
 
-                    // Fetch the main isolate ID (cached after first call).
-                    let isolate_id = match handle.main_isolate_id().await {
-                        Ok(id) => id,
+                    // Fetch the isolate picked in DevTools (main by default).
+                    let (picked, isolate_id) = match picked_isolate(&handle).await {
+                        Ok(ids) => ids,
                         Err(e) => {
                             tracing::debug!(
                                 "Could not get isolate ID for memory polling (session {}): {}",
@@ -271,6 +311,7 @@ pub(super) fn spawn_performance_polling(
                     if msg_tx
                         .send(Message::VmServiceMemorySnapshot {
                             session_id,
+                            isolate_id: picked.clone(),
                             memory: usage.clone(),
                         })
                         .await
@@ -293,7 +334,11 @@ pub(super) fn spawn_performance_polling(
                         .await
                     {
                         if msg_tx
-                            .send(Message::VmServiceMemorySample { session_id, sample })
+                            .send(Message::VmServiceMemorySample {
+                                session_id,
+                                isolate_id: picked.clone(),
+                                sample,
+                            })
                             .await
                             .is_err()
                         {
@@ -333,8 +378,8 @@ pub(super) fn spawn_performance_polling(
 
                     // Transitioned to active (user entered DevTools). Fire one immediate
                     // memory fetch so the Performance panel shows current data.
-                    let isolate_id = match handle.main_isolate_id().await {
-                        Ok(id) => id,
+                    let (picked, isolate_id) = match picked_isolate(&handle).await {
+                        Ok(ids) => ids,
                         Err(e) => {
                             tracing::debug!(
                                 "Could not get isolate ID for immediate memory fetch (session {}): {}",
@@ -358,6 +403,7 @@ pub(super) fn spawn_performance_polling(
                     if msg_tx
                         .send(Message::VmServiceMemorySnapshot {
                             session_id,
+                            isolate_id: picked.clone(),
                             memory: usage.clone(),
                         })
                         .await
@@ -375,7 +421,11 @@ pub(super) fn spawn_performance_polling(
                         .await
                     {
                         if msg_tx
-                            .send(Message::VmServiceMemorySample { session_id, sample })
+                            .send(Message::VmServiceMemorySample {
+                                session_id,
+                                isolate_id: picked.clone(),
+                                sample,
+                            })
                             .await
                             .is_err()
                         {
@@ -389,12 +439,27 @@ pub(super) fn spawn_performance_polling(
                 // waiting up to `alloc_interval` for the next scheduled tick.
                 // The `watch` channel coalesces rapid toggles — only the final
                 // value matters, so burst panel switches don't create burst fetches.
+                //
+                // Timeline recording for frame phases follows the same gate:
+                // it is only on while the Performance panel is visible.
                 Ok(()) = alloc_pause_rx.changed() => {
                     if *alloc_pause_rx.borrow() {
-                        // Transitioned to paused — nothing to do.
+                        // Transitioned to paused — put the previous timeline
+                        // flags back.
+                        restore_frame_timeline(&handle, &mut recorded_before, session_id).await;
                         continue;
                     }
 
+                    if recorded_before.is_none() {
+                        match enable_frame_timeline(&handle).await {
+                            Ok(previous) => recorded_before = Some(previous),
+                            Err(e) => tracing::debug!(
+                                "Could not enable timeline recording for session {}: {}",
+                                session_id, e
+                            ),
+                        }
+                    }
+
                     // Transitioned to active (Performance panel became visible).
                     // Fire one immediate allocation profile fetch so the panel is
                     // populated without waiting for the next tick.
@@ -414,6 +479,8 @@ pub(super) fn spawn_performance_polling(
                 }
             }
         }
+
+        restore_frame_timeline(&handle, &mut recorded_before, session_id).await;
     });
 
     // Synchronously store the JoinHandle in the slot. The task hasn't run yet
@@ -424,6 +491,37 @@ pub(super) fn spawn_performance_polling(
     };
 }
 
+/// The isolate picked in DevTools (`None` for the main isolate) and the ID
+/// to query it with. Results are tagged with the pick so the handler can drop
+/// those that arrive after the user switched isolates.
+async fn picked_isolate(handle: &VmRequestHandle) -> fdemon_core::Result<(Option<String>, String)> {
+    let picked = handle.target_isolate();
+    let isolate_id = match &picked {
+        Some(id) => id.clone(),
+        None => handle.main_isolate_id().await?,
+    };
+    Ok((picked, isolate_id))
+}
+
+/// Put back the timeline streams recorded before [`enable_frame_timeline`],
+/// if frame phase recording is on.
+async fn restore_frame_timeline(
+    handle: &VmRequestHandle,
+    recorded_before: &mut Option<Vec<String>>,
+    session_id: SessionId,
+) {
+    let Some(streams) = recorded_before.take() else {
+        return;
+    };
+    if let Err(e) = restore_timeline_streams(handle, &streams).await {
+        tracing::debug!(
+            "Could not restore timeline recording for session {}: {}",
+            session_id,
+            e
+        );
+    }
+}
+
 /// Fetch the allocation profile for the session and send it to the TEA handler.
 ///
 /// Returns `true` if the message channel is closed (caller should `break`),
@@ -433,8 +531,8 @@ async fn fetch_and_send_alloc_profile(
     msg_tx: &mpsc::Sender<Message>,
     session_id: SessionId,
 ) -> bool {
-    let isolate_id = match handle.main_isolate_id().await {
-        Ok(id) => id,
+    let (picked, isolate_id) = match picked_isolate(handle).await {
+        Ok(ids) => ids,
         Err(e) => {
             tracing::debug!(
                 "Could not get isolate ID for allocation polling (session {}): {}",
@@ -456,6 +554,7 @@ async fn fetch_and_send_alloc_profile(
             if msg_tx
                 .send(Message::VmServiceAllocationProfileReceived {
                     session_id,
+                    isolate_id: picked,
                     profile,
                 })
                 .await
@@ -476,6 +575,186 @@ async fn fetch_and_send_alloc_profile(
     false
 }
 
+/// Spawn a background task that fetches the allocation profile with a forced
+/// GC, so only retained objects are counted, and sends
+/// `Message::AllocationDiffCaptured` with the profile or error.
+pub(super) fn spawn_capture_allocation_diff(
+    session_id: SessionId,
+    handle: VmRequestHandle,
+    msg_tx: mpsc::Sender<Message>,
+) {
+    tokio::spawn(async move {
+        let result = match handle.target_isolate_id().await {
+            Ok(isolate_id) => {
+                fdemon_daemon::vm_service::get_allocation_profile(&handle, &isolate_id, true)
+                    .await
+                    .map_err(|e| e.to_string())
+            }
+            Err(e) => Err(e.to_string()),
+        };
+        let _ = msg_tx
+            .send(Message::AllocationDiffCaptured { session_id, result })
+            .await;
+    });
+}
+
+/// Time for route transitions to finish and disposed objects to become
+/// unreachable before a leak hint checkpoint is taken.
+const LEAK_CHECKPOINT_SETTLE: Duration = Duration::from_secs(1);
+
+/// Spawn a background task that waits [`LEAK_CHECKPOINT_SETTLE`], fetches an
+/// allocation profile after a forced GC and sends
+/// `Message::LeakCheckpointCaptured`.
+///
+/// Always reads the main isolate, where widget `State`s and controllers
+/// live, so switching the DevTools isolate does not mix isolates in the
+/// tracker's history. The profile only feeds the tracker, never the
+/// allocation table of the picked isolate.
+pub(super) fn spawn_capture_leak_checkpoint(
+    session_id: SessionId,
+    handle: VmRequestHandle,
+    msg_tx: mpsc::Sender<Message>,
+) {
+    tokio::spawn(async move {
+        tokio::time::sleep(LEAK_CHECKPOINT_SETTLE).await;
+        let result = match handle.main_isolate_id().await {
+            Ok(isolate_id) => {
+                fdemon_daemon::vm_service::get_allocation_profile(&handle, &isolate_id, true)
+                    .await
+                    .map_err(|e| e.to_string())
+            }
+            Err(e) => Err(e.to_string()),
+        };
+        let _ = msg_tx
+            .send(Message::LeakCheckpointCaptured { session_id, result })
+            .await;
+    });
+}
+
+/// Maximum number of instances listed by the instance browser.
+const INSTANCE_LIMIT: u32 = 100;
+
+/// Maximum number of objects on a fetched retaining path.
+const RETAINING_PATH_LIMIT: u32 = 32;
+
+/// Spawn a background task that lists live instances of `class_id` and sends
+/// `Message::InstancesFetched`.
+pub(super) fn spawn_fetch_instances(
+    session_id: SessionId,
+    class_id: String,
+    handle: VmRequestHandle,
+    msg_tx: mpsc::Sender<Message>,
+) {
+    tokio::spawn(async move {
+        let result = match handle.target_isolate_id().await {
+            Ok(isolate_id) => get_instances(&handle, &isolate_id, &class_id, INSTANCE_LIMIT)
+                .await
+                .map_err(|e| e.to_string()),
+            Err(e) => Err(e.to_string()),
+        };
+        let _ = msg_tx
+            .send(Message::InstancesFetched {
+                session_id,
+                class_id,
+                result,
+            })
+            .await;
+    });
+}
+
+/// Spawn a background task that fetches the fields and retaining path of
+/// `object_id` and sends `Message::InstanceInspected`.
+pub(super) fn spawn_inspect_instance(
+    session_id: SessionId,
+    object_id: String,
+    handle: VmRequestHandle,
+    msg_tx: mpsc::Sender<Message>,
+) {
+    tokio::spawn(async move {
+        let result = async {
+            let isolate_id = handle.target_isolate_id().await?;
+            let fields = get_instance_fields(&handle, &isolate_id, &object_id).await?;
+            let retaining_path =
+                get_retaining_path(&handle, &isolate_id, &object_id, RETAINING_PATH_LIMIT).await?;
+            Ok::<_, fdemon_core::Error>(InstanceDetails {
+                fields,
+                retaining_path,
+            })
+        }
+        .await
+        .map_err(|e| e.to_string());
+        let _ = msg_tx
+            .send(Message::InstanceInspected {
+                session_id,
+                object_id,
+                result,
+            })
+            .await;
+    });
+}
+
+/// Spawn a background task that sets
+/// `ext.flutter.inspector.trackRebuildDirtyWidgets` on the device.
+///
+/// While enabled, Flutter posts a `Flutter.RebuiltWidgets` event after every
+/// frame that rebuilt widgets. Sends `Message::RebuildTrackingChanged` with the
+/// state reported by the device; failures are logged and leave the state
+/// unchanged (the extension is missing in profile/release builds).
+pub(super) fn spawn_set_rebuild_tracking(
+    session_id: SessionId,
+    enabled: bool,
+    handle: VmRequestHandle,
+    msg_tx: mpsc::Sender<Message>,
+) {
+    tokio::spawn(async move {
+        let isolate_id = match handle.main_isolate_id().await {
+            Ok(id) => id,
+            Err(e) => {
+                tracing::warn!(
+                    "SetRebuildTracking: could not get isolate ID for session {}: {}",
+                    session_id,
+                    e
+                );
+                return;
+            }
+        };
+
+        let mut args = HashMap::new();
+        args.insert("enabled".to_string(), enabled.to_string());
+        let result = handle
+            .call_extension(ext::TRACK_REBUILD_DIRTY_WIDGETS, &isolate_id, Some(args))
+            .await;
+        let enabled = match result {
+            Ok(value) => match parse_bool_extension_response(&value) {
+                Ok(v) => v,
+                Err(e) => {
+                    tracing::warn!(
+                        "SetRebuildTracking: failed to parse response for session {}: {}",
+                        session_id,
+                        e
+                    );
+                    return;
+                }
+            },
+            Err(e) => {
+                tracing::debug!(
+                    "SetRebuildTracking: extension not available for session {}: {}",
+                    session_id,
+                    e
+                );
+                return;
+            }
+        };
+
+        let _ = msg_tx
+            .send(Message::RebuildTrackingChanged {
+                session_id,
+                enabled,
+            })
+            .await;
+    });
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,79 @@
+//! CPU profiler recording for the DevTools profiler panel.
+//!
+//! [`spawn_start_cpu_profiling`] clears old samples and reports the start of
+//! the recording window as `Message::CpuProfilingStarted`.
+//! [`spawn_stop_cpu_profiling`] fetches the samples taken since then and
+//! forwards them as `Message::CpuProfileFetched`.
+
+use tokio::sync::mpsc;
+
+use crate::message::Message;
+use crate::session::SessionId;
+use fdemon_daemon::vm_service::{
+    clear_cpu_samples, get_cpu_samples, get_vm_timeline_micros, VmRequestHandle,
+};
+
+/// Spawn a background task that starts a CPU recording.
+///
+/// Sends `Message::CpuProfilingStarted` on success or
+/// `Message::CpuProfilingFailed` with the raw error string otherwise.
+pub(super) fn spawn_start_cpu_profiling(
+    session_id: SessionId,
+    handle: VmRequestHandle,
+    msg_tx: mpsc::Sender<Message>,
+) {
+    tokio::spawn(async move {
+        let result = async {
+            let isolate_id = handle.target_isolate_id().await?;
+            clear_cpu_samples(&handle, &isolate_id).await?;
+            get_vm_timeline_micros(&handle).await
+        }
+        .await;
+
+        let msg = match result {
+            Ok(origin_micros) => Message::CpuProfilingStarted {
+                session_id,
+                origin_micros,
+            },
+            Err(e) => Message::CpuProfilingFailed {
+                session_id,
+                error: e.to_string(),
+            },
+        };
+        let _ = msg_tx.send(msg).await;
+    });
+}
+
+/// Spawn a background task that fetches the samples recorded since
+/// `origin_micros`.
+///
+/// Sends `Message::CpuProfileFetched` on success or
+/// `Message::CpuProfilingFailed` with the raw error string otherwise.
+pub(super) fn spawn_stop_cpu_profiling(
+    session_id: SessionId,
+    origin_micros: i64,
+    handle: VmRequestHandle,
+    msg_tx: mpsc::Sender<Message>,
+) {
+    tokio::spawn(async move {
+        let result = async {
+            let isolate_id = handle.target_isolate_id().await?;
+            let now = get_vm_timeline_micros(&handle).await?;
+            let extent = (now - origin_micros).max(0);
+            get_cpu_samples(&handle, &isolate_id, origin_micros, extent).await
+        }
+        .await;
+
+        let msg = match result {
+            Ok(profile) => Message::CpuProfileFetched {
+                session_id,
+                profile,
+            },
+            Err(e) => Message::CpuProfilingFailed {
+                session_id,
+                error: e.to_string(),
+            },
+        };
+        let _ = msg_tx.send(msg).await;
+    });
+}
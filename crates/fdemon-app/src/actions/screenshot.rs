@@ -0,0 +1,192 @@
+//! Device screenshot capture.
+//!
+//! [`spawn_capture_screenshots`] captures every target concurrently. Each
+//! capture tries the daemon's `app.screenshot` request, then the engine's
+//! `_flutter.screenshot` VM Service RPC, then `flutter screenshot`, and
+//! reports the written PNG as `Message::ScreenshotSaved`.
+
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use tokio::sync::mpsc;
+use tracing::debug;
+
+use crate::handler::ScreenshotTarget;
+use crate::message::Message;
+use crate::session::SessionId;
+use fdemon_daemon::{
+    decode_screenshot_response, flutter_cli_screenshot, vm_screenshot, CommandSender,
+    DaemonCommand, FlutterExecutable,
+};
+
+/// Timeout for the daemon `app.screenshot` request before falling back.
+const DAEMON_SCREENSHOT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Path of a screenshot: `<dir>/<device>-s<session>-<timestamp>.png`.
+///
+/// The device name is lowercased with runs of non-alphanumeric characters
+/// collapsed to `-`, e.g. `"Pixel 7 (emulator)"` → `pixel-7-emulator`. The
+/// session id keeps sessions on identically named devices apart.
+fn screenshot_path(
+    dir: &Path,
+    device_name: &str,
+    session_id: SessionId,
+    timestamp: &str,
+) -> PathBuf {
+    let mut slug = String::new();
+    for c in device_name.chars() {
+        if c.is_ascii_alphanumeric() {
+            slug.push(c.to_ascii_lowercase());
+        } else if !slug.is_empty() && !slug.ends_with('-') {
+            slug.push('-');
+        }
+    }
+    let slug = slug.trim_end_matches('-');
+    let slug = if slug.is_empty() { "device" } else { slug };
+    dir.join(format!("{slug}-s{session_id}-{timestamp}.png"))
+}
+
+/// `path`, or `path` with a `-2`, `-3`, … suffix if a file of that name
+/// already exists (e.g. two captures of one session within a second).
+async fn unused_path(path: PathBuf) -> PathBuf {
+    let stem = path
+        .file_stem()
+        .map(|s| s.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let mut candidate = path.clone();
+    let mut n = 1;
+    while tokio::fs::try_exists(&candidate).await.unwrap_or(false) {
+        n += 1;
+        candidate = path.with_file_name(format!("{stem}-{n}.png"));
+    }
+    candidate
+}
+
+/// Ask the daemon for a screenshot. `None` if unsupported or failed.
+async fn daemon_screenshot(sender: &CommandSender, app_id: String) -> Option<Vec<u8>> {
+    let response = sender
+        .send_with_timeout(
+            DaemonCommand::Screenshot { app_id },
+            DAEMON_SCREENSHOT_TIMEOUT,
+        )
+        .await;
+    match response {
+        Ok(response) if response.success => {
+            match decode_screenshot_response(response.result.as_ref()?) {
+                Ok(bytes) => Some(bytes),
+                Err(e) => {
+                    debug!("app.screenshot returned no image: {}", e);
+                    None
+                }
+            }
+        }
+        Ok(response) => {
+            debug!("app.screenshot failed: {:?}", response.error);
+            None
+        }
+        Err(e) => {
+            debug!("app.screenshot failed: {}", e);
+            None
+        }
+    }
+}
+
+/// Capture one target into `path`, trying each capture path in turn.
+async fn capture(
+    target: ScreenshotTarget,
+    path: &Path,
+    flutter: Option<FlutterExecutable>,
+) -> Result<(), String> {
+    if let Some(dir) = path.parent() {
+        tokio::fs::create_dir_all(dir)
+            .await
+            .map_err(|e| e.to_string())?;
+    }
+
+    let mut bytes = None;
+    if let (Some(sender), Some(app_id)) = (&target.cmd_sender, target.app_id) {
+        bytes = daemon_screenshot(sender, app_id).await;
+    }
+    if bytes.is_none() {
+        if let Some(handle) = &target.vm_handle {
+            match vm_screenshot(handle).await {
+                Ok(png) => bytes = Some(png),
+                Err(e) => debug!("_flutter.screenshot failed: {}", e),
+            }
+        }
+    }
+    if let Some(bytes) = bytes {
+        return tokio::fs::write(path, bytes)
+            .await
+            .map_err(|e| e.to_string());
+    }
+
+    let flutter = flutter.ok_or("no VM Service connection and no Flutter SDK available")?;
+    flutter_cli_screenshot(&flutter, &target.device_id, path)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Spawn one capture task per target; each sends `Message::ScreenshotSaved`.
+pub(super) fn spawn_capture_screenshots(
+    targets: Vec<ScreenshotTarget>,
+    dir: PathBuf,
+    flutter: Option<FlutterExecutable>,
+    msg_tx: mpsc::Sender<Message>,
+) {
+    let timestamp = chrono::Local::now().format("%Y%m%d-%H%M%S").to_string();
+    for target in targets {
+        let path = screenshot_path(&dir, &target.device_name, target.session_id, &timestamp);
+        let flutter = flutter.clone();
+        let msg_tx = msg_tx.clone();
+        tokio::spawn(async move {
+            let session_id = target.session_id;
+            let path = unused_path(path).await;
+            let result = capture(target, &path, flutter).await.map(|()| path);
+            let _ = msg_tx
+                .send(Message::ScreenshotSaved { session_id, result })
+                .await;
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_screenshot_path_slugs_device_name() {
+        assert_eq!(
+            screenshot_path(
+                Path::new("/app/shots"),
+                "Pixel 7 (emulator)",
+                1,
+                "20260101-120000"
+            ),
+            PathBuf::from("/app/shots/pixel-7-emulator-s1-20260101-120000.png")
+        );
+        assert_eq!(
+            screenshot_path(Path::new("/app/shots"), "  ", 2, "20260101-120000"),
+            PathBuf::from("/app/shots/device-s2-20260101-120000.png")
+        );
+    }
+
+    #[test]
+    fn test_screenshot_paths_differ_for_same_device_name() {
+        let dir = Path::new("/app/shots");
+        let first = screenshot_path(dir, "iPhone 15", 1, "20260101-120000");
+        let second = screenshot_path(dir, "iPhone 15", 2, "20260101-120000");
+        assert_ne!(first, second);
+    }
+
+    #[tokio::test]
+    async fn test_unused_path_adds_suffix_for_existing_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = screenshot_path(dir.path(), "iPhone 15", 1, "20260101-120000");
+        assert_eq!(unused_path(path.clone()).await, path);
+
+        std::fs::write(&path, b"png").unwrap();
+        let next = unused_path(path.clone()).await;
+        assert_eq!(next, dir.path().join("iphone-15-s1-20260101-120000-2.png"));
+    }
+}
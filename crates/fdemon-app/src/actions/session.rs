@@ -4,7 +4,8 @@
 //! dispatcher in `mod.rs`:
 //!
 //! - [`spawn_session`] — spawns a `FlutterProcess`, forwards daemon events to
-//!   the TEA message loop, and manages a process watchdog.
+//!   the TEA message loop, and manages a process watchdog. When configured,
+//!   a local HTTP proxy is started first and its address passed to the app.
 //! - [`execute_task`] — sends a single daemon command (reload / restart / stop)
 //!   and returns a completion message.
 
@@ -13,15 +14,17 @@ use std::sync::Arc;
 use std::time::Duration;
 
 use tokio::sync::{mpsc, watch};
+use tokio::task::JoinHandle;
 use tracing::{error, info, warn};
 
-use crate::config::LaunchConfig;
+use crate::config::{LaunchConfig, NetworkProxySettings};
 use crate::handler::Task;
 use crate::message::Message;
 use crate::session::SessionId;
 use fdemon_core::{DaemonEvent, DaemonMessage};
 use fdemon_daemon::{
-    CommandSender, DaemonCommand, Device, FlutterExecutable, FlutterProcess, RequestTracker,
+    CommandSender, DaemonCommand, Device, FlutterExecutable, FlutterProcess, HttpProxy,
+    ProxyConfig, RequestTracker,
 };
 
 use super::SessionTaskMap;
@@ -30,6 +33,12 @@ use super::SessionTaskMap;
 /// When stdout EOF does not occur, this ensures the session is marked exited within 5 seconds.
 pub(super) const PROCESS_WATCHDOG_INTERVAL: Duration = Duration::from_secs(5);
 
+/// Host through which an Android emulator reaches the development machine.
+const ANDROID_EMULATOR_HOST: &str = "10.0.2.2";
+
+/// Host advertised to apps on devices that share the machine's network stack.
+const LOOPBACK_HOST: &str = "127.0.0.1";
+
 /// Spawn a Flutter session for a device (multi-session mode)
 #[allow(clippy::too_many_arguments)]
 pub(super) fn spawn_session(
@@ -37,6 +46,7 @@ pub(super) fn spawn_session(
     device: Device,
     config: Option<Box<LaunchConfig>>,
     flutter: FlutterExecutable,
+    proxy: Option<NetworkProxySettings>,
     project_path: &Path,
     msg_tx: mpsc::Sender<Message>,
     session_tasks: SessionTaskMap,
@@ -49,6 +59,13 @@ pub(super) fn spawn_session(
     let device_id = device.id.clone();
     let device_name = device.name.clone();
     let device_platform = device.platform.clone();
+    // An attached app was built without the proxy's dart-define, so it
+    // could never be routed through one.
+    let attach = config.as_ref().is_some_and(|c| c.attach);
+    let advertised_host = proxy
+        .as_ref()
+        .filter(|_| !attach)
+        .map(|settings| proxy_advertised_host(settings, &device));
 
     let handle = tokio::spawn(async move {
         info!(
@@ -59,14 +76,27 @@ pub(super) fn spawn_session(
         // Create event channel for this session
         let (daemon_tx, mut daemon_rx) = mpsc::channel::<DaemonEvent>(256);
 
-        // Spawn the Flutter process
-        let spawn_result = if let Some(cfg) = config {
-            // Build flutter args from config (conversion happens here in app layer)
-            let args = cfg.build_flutter_args(&device_id);
-            FlutterProcess::spawn_with_args(&flutter, &project_path, args, daemon_tx).await
-        } else {
-            FlutterProcess::spawn_with_device(&flutter, &project_path, &device_id, daemon_tx).await
+        // Start the local HTTP proxy first so its address can be passed to the
+        // app. A failure is reported but does not prevent the launch.
+        let session_proxy = match (proxy, advertised_host) {
+            (Some(settings), Some(host)) => {
+                start_session_proxy(session_id, &settings, host, &project_path, &msg_tx_clone).await
+            }
+            _ => None,
         };
+        let proxy_define = session_proxy.as_ref().map(|p| p.dart_define.clone());
+
+        // Spawn the Flutter process. Flutter args are built from the config
+        // here in the app layer; without one the daemon defaults are used.
+        let mut args = match &config {
+            Some(cfg) => cfg.build_flutter_args(&device_id),
+            None => FlutterProcess::device_run_args(&device_id),
+        };
+        if let Some(define) = proxy_define {
+            args.extend(["--dart-define".to_string(), define]);
+        }
+        let spawn_result =
+            FlutterProcess::spawn_with_args(&flutter, &project_path, args, daemon_tx).await;
 
         match spawn_result {
             Ok(mut process) => {
@@ -221,6 +251,10 @@ pub(super) fn spawn_session(
             }
         }
 
+        if let Some(session_proxy) = session_proxy {
+            session_proxy.stop();
+        }
+
         // Remove this session's task from the tracking map
         if let Ok(mut guard) = session_tasks_clone.lock() {
             guard.remove(&session_id);
@@ -252,6 +286,98 @@ pub(super) fn spawn_session(
     }
 }
 
+/// A session's running HTTP proxy: the accept loop plus the task forwarding
+/// recorded exchanges into the message loop.
+struct SessionProxy {
+    /// `KEY=host:port` value for `--dart-define`.
+    dart_define: String,
+    proxy_task: JoinHandle<()>,
+    forward_task: JoinHandle<()>,
+}
+
+impl SessionProxy {
+    fn stop(self) {
+        self.proxy_task.abort();
+        self.forward_task.abort();
+    }
+}
+
+/// Host the app should use to reach the proxy on `device`.
+///
+/// An explicit `advertised_host` wins; otherwise Android emulators get their
+/// host-loopback alias and everything else uses `127.0.0.1`.
+fn proxy_advertised_host(settings: &NetworkProxySettings, device: &Device) -> String {
+    if !settings.advertised_host.is_empty() {
+        return settings.advertised_host.clone();
+    }
+    if device.emulator && device.platform.starts_with("android") {
+        ANDROID_EMULATOR_HOST.to_string()
+    } else {
+        LOOPBACK_HOST.to_string()
+    }
+}
+
+/// Bind and start the session's HTTP proxy, reporting the outcome.
+async fn start_session_proxy(
+    session_id: SessionId,
+    settings: &NetworkProxySettings,
+    advertised_host: String,
+    project_path: &Path,
+    msg_tx: &mpsc::Sender<Message>,
+) -> Option<SessionProxy> {
+    let config = ProxyConfig {
+        bind_address: settings.bind_address.clone(),
+        port: settings.port,
+        rules_dir: project_path.join(&settings.rules_dir),
+    };
+    let handle = match HttpProxy::bind(config).await.and_then(HttpProxy::spawn) {
+        Ok(handle) => handle,
+        Err(e) => {
+            warn!("HTTP proxy for session {} failed: {}", session_id, e);
+            let _ = msg_tx
+                .send(Message::NetworkProxyFailed {
+                    session_id,
+                    error: e.to_string(),
+                })
+                .await;
+            return None;
+        }
+    };
+
+    let address = format!("{}:{}", advertised_host, handle.local_addr.port());
+    info!(
+        "HTTP proxy for session {} listening on {} (advertised as {})",
+        session_id, handle.local_addr, address
+    );
+    let _ = msg_tx
+        .send(Message::NetworkProxyStarted {
+            session_id,
+            address: address.clone(),
+            dart_define: settings.dart_define.clone(),
+        })
+        .await;
+
+    let mut exchange_rx = handle.exchange_rx;
+    let forward_tx = msg_tx.clone();
+    let forward_task = tokio::spawn(async move {
+        while let Some(detail) = exchange_rx.recv().await {
+            let msg = Message::NetworkProxyExchange {
+                session_id,
+                detail: Box::new(detail),
+            };
+            if forward_tx.send(msg).await.is_err() {
+                break;
+            }
+        }
+    });
+
+    Some(SessionProxy {
+        dart_define: format!("{}={}", settings.dart_define, address),
+        proxy_task: handle.task_handle,
+        forward_task,
+    })
+}
+
 /// Execute a task and send completion message
 pub(super) async fn execute_task(
     task: Task,
@@ -269,7 +395,10 @@ pub(super) async fn execute_task(
                 session_id,
                 reason: "Flutter not running".to_string(),
             },
-            Task::Stop { .. } => return, // Nothing to do
+            Task::Stop { session_id, .. } => Message::SessionStopFailed {
+                session_id,
+                reason: "Flutter not running".to_string(),
+            },
         };
         let _ = msg_tx.send(msg).await;
         return;
@@ -337,14 +466,39 @@ pub(super) async fn execute_task(
                 }
             }
         }
-        Task::Stop { session_id, app_id } => {
+        Task::Stop {
+            session_id,
+            app_id,
+            detach,
+        } => {
             info!(
-                "Executing stop for session {} (app_id: {})",
-                session_id, app_id
+                "Executing {} for session {} (app_id: {})",
+                if detach { "detach" } else { "stop" },
+                session_id,
+                app_id
             );
-            if let Err(e) = sender.send(DaemonCommand::Stop { app_id }).await {
-                error!("Failed to stop app: {}", e);
-            }
+            let command = if detach {
+                DaemonCommand::Detach { app_id }
+            } else {
+                DaemonCommand::Stop { app_id }
+            };
+            let msg = match sender.send(command).await {
+                Ok(response) if response.success => Message::SessionStopCompleted { session_id },
+                Ok(response) => Message::SessionStopFailed {
+                    session_id,
+                    reason: response
+                        .error
+                        .unwrap_or_else(|| "Unknown error".to_string()),
+                },
+                Err(e) => {
+                    error!("Failed to stop app: {}", e);
+                    Message::SessionStopFailed {
+                        session_id,
+                        reason: e.to_string(),
+                    }
+                }
+            };
+            let _ = msg_tx.send(msg).await;
         }
     }
 }
@@ -353,6 +507,49 @@ pub(super) async fn execute_task(
 mod tests {
     use super::*;
 
+    fn make_device(platform: &str, emulator: bool) -> Device {
+        Device {
+            id: "d".to_string(),
+            name: "Device".to_string(),
+            platform: platform.to_string(),
+            emulator,
+            category: None,
+            platform_type: None,
+            ephemeral: false,
+            emulator_id: None,
+        }
+    }
+
+    #[test]
+    fn test_proxy_advertised_host_android_emulator() {
+        let settings = NetworkProxySettings::default();
+        let host = proxy_advertised_host(&settings, &make_device("android-arm64", true));
+        assert_eq!(host, ANDROID_EMULATOR_HOST);
+    }
+
+    #[test]
+    fn test_proxy_advertised_host_defaults_to_loopback() {
+        let settings = NetworkProxySettings::default();
+        assert_eq!(
+            proxy_advertised_host(&settings, &make_device("ios", true)),
+            LOOPBACK_HOST
+        );
+        assert_eq!(
+            proxy_advertised_host(&settings, &make_device("android-arm64", false)),
+            LOOPBACK_HOST
+        );
+    }
+
+    #[test]
+    fn test_proxy_advertised_host_explicit_override() {
+        let settings = NetworkProxySettings {
+            advertised_host: "192.168.1.20".to_string(),
+            ..Default::default()
+        };
+        let host = proxy_advertised_host(&settings, &make_device("android-arm64", true));
+        assert_eq!(host, "192.168.1.20");
+    }
+
     #[test]
     fn test_watchdog_interval_is_reasonable() {
         assert_eq!(
@@ -21,9 +21,10 @@ use crate::message::Message;
 use crate::session::SessionId;
 use fdemon_daemon::vm_service::protocol::stream_id;
 use fdemon_daemon::vm_service::{
-    enable_frame_tracking, flutter_error_to_log_entry, parse_debug_event, parse_flutter_error,
-    parse_frame_timing, parse_gc_event, parse_isolate_event, parse_log_record, vm_log_to_log_entry,
-    VmClientEvent, VmServiceClient,
+    enable_frame_tracking, flutter_error_to_log_entry, is_navigation_event, parse_debug_event,
+    parse_flutter_error, parse_frame_timing, parse_gc_event, parse_isolate_event, parse_log_record,
+    parse_rebuilt_widgets, query_all_overlays, stream_event_to_json, timeline_events,
+    vm_log_to_log_entry, DebugIsolateRef, FramePhaseCorrelator, VmClientEvent, VmServiceClient,
 };
 
 /// Maximum time to wait for the initial VM Service WebSocket connection.
@@ -80,8 +81,36 @@ pub(super) fn spawn_vm_service_connection(
                 // `Flutter.Frame` events may already arrive without this call;
                 // this attempts to also enable `profileWidgetBuilds` for build
                 // timing detail. Errors are silently ignored (profile mode, etc.).
+                //
+                // Then read the current debug overlay and toggle states so the
+                // DevTools header reflects the device before the first toggle.
                 if let Ok(isolate_id) = client.main_isolate_id().await {
                     let _ = enable_frame_tracking(&client.request_handle(), &isolate_id).await;
+                    let state = query_all_overlays(&client, &isolate_id).await;
+                    let _ = msg_tx
+                        .send(Message::DebugTogglesQueried { session_id, state })
+                        .await;
+                }
+
+                // Isolates started before the Isolate stream subscription
+                // (background workers spawned at startup) are only known
+                // from `getVM`.
+                if let Ok(vm) = client.get_vm().await {
+                    let isolates = vm
+                        .isolates
+                        .into_iter()
+                        .filter(|i| !i.is_system_isolate.unwrap_or(false))
+                        .map(|i| DebugIsolateRef {
+                            id: i.id,
+                            name: Some(i.name),
+                        })
+                        .collect();
+                    let _ = msg_tx
+                        .send(Message::VmServiceIsolatesDiscovered {
+                            session_id,
+                            isolates,
+                        })
+                        .await;
                 }
 
                 // Extract the request handle BEFORE entering the forwarding loop.
@@ -148,12 +177,25 @@ async fn forward_vm_events(
     let mut heartbeat = tokio::time::interval(HEARTBEAT_INTERVAL);
     heartbeat.tick().await; // consume the immediate first tick so the first real probe fires after 30s
     let mut consecutive_failures: u32 = 0;
+    let mut frame_phases = FramePhaseCorrelator::new();
 
     loop {
         tokio::select! {
             event = client.event_receiver().recv() => {
                 match event {
                     Some(VmClientEvent::StreamEvent(event)) => {
+                        // Echo events of streams tailed in the console. This
+                        // does not consume the event: it is still parsed below.
+                        if heartbeat_handle.is_stream_tailed(&event.params.stream_id) {
+                            let _ = msg_tx
+                                .send(Message::VmServiceConsoleEvent {
+                                    session_id,
+                                    stream_id: event.params.stream_id.clone(),
+                                    event: stream_event_to_json(&event.params.event),
+                                })
+                                .await;
+                        }
+
                         // Try parsing as Flutter.Error (Extension stream) — most critical.
                         if let Some(flutter_error) = parse_flutter_error(&event.params.event) {
                             let log_entry = flutter_error_to_log_entry(&flutter_error);
@@ -181,6 +223,27 @@ async fn forward_vm_events(
                             continue;
                         }
 
+                        // Rebuild counts (Flutter.RebuiltWidgets) — only posted while
+                        // rebuild tracking is enabled.
+                        if let Some(rebuilds) = parse_rebuilt_widgets(&event.params.event) {
+                            let _ = msg_tx
+                                .send(Message::VmServiceRebuiltWidgets {
+                                    session_id,
+                                    rebuilds,
+                                })
+                                .await;
+                            continue;
+                        }
+
+                        // Route changes (Flutter.Navigation, Extension stream) make a
+                        // previously fetched widget tree stale.
+                        if is_navigation_event(&event.params.event) {
+                            let _ = msg_tx
+                                .send(Message::VmServiceNavigationEvent { session_id })
+                                .await;
+                            continue;
+                        }
+
                         // Try parsing as a GC event (GC stream).
                         if let Some(gc_event) = parse_gc_event(&event.params.event) {
                             let _ = msg_tx
@@ -242,7 +305,22 @@ async fn forward_vm_events(
                             continue;
                         }
 
-                        // Other event kinds (Timeline, etc.) are intentionally ignored
+                        // Timeline stream batches: correlate trace events into
+                        // per-frame build/layout/paint/raster breakdowns.
+                        if let Some(events) = timeline_events(&event.params.event) {
+                            for (number, phases) in frame_phases.ingest(events) {
+                                let _ = msg_tx
+                                    .send(Message::VmServiceFramePhases {
+                                        session_id,
+                                        number,
+                                        phases,
+                                    })
+                                    .await;
+                            }
+                            continue;
+                        }
+
+                        // Other event kinds are intentionally ignored
                     }
                     Some(VmClientEvent::Reconnecting { attempt, max_attempts }) => {
                         consecutive_failures = 0; // prevent accumulation during backoff
@@ -140,6 +140,12 @@ mode = "debug"          # debug | profile | release
 # mode = "release"
 # flavor = "production"
 # extra_args = ["--obfuscate", "--split-debug-info=build/symbols"]
+
+# [[configurations]]
+# name = "Attach to host app"
+# device = "emulator-5554"
+# attach = true
+# debug_url = "http://127.0.0.1:50300/abc123=/"  # omit to wait for the app
 "#;
         std::fs::write(&launch_path, default_content)
             .map_err(|e| Error::config(format!("Failed to write launch.toml: {}", e)))?;
@@ -159,6 +165,8 @@ pub fn create_default_launch_config() -> LaunchConfig {
         dart_defines: std::collections::HashMap::new(),
         extra_args: Vec::new(),
         auto_start: false,
+        attach: false,
+        debug_url: None,
     }
 }
 
@@ -302,16 +310,30 @@ pub fn parse_dart_defines(s: &str) -> std::collections::HashMap<String, String>
 }
 
 impl LaunchConfig {
-    /// Build flutter run arguments from this configuration
+    /// Build flutter run (or flutter attach) arguments from this configuration
     pub fn build_flutter_args(&self, device_id: &str) -> Vec<String> {
+        let command = if self.attach { "attach" } else { "run" };
         let mut args = vec![
-            "run".to_string(),
+            command.to_string(),
             "--machine".to_string(),
             "-d".to_string(),
             device_id.to_string(),
-            self.mode.as_arg().to_string(),
         ];
 
+        // `flutter attach` takes `--debug` / `--profile` to match the running
+        // app, but rejects `--release`: release builds have no VM Service
+        if !(self.attach && self.mode == FlutterMode::Release) {
+            args.push(self.mode.as_arg().to_string());
+        }
+
+        // Add the VM Service URI of the app to attach to
+        if self.attach {
+            if let Some(ref url) = self.debug_url {
+                args.push("--debug-url".to_string());
+                args.push(url.clone());
+            }
+        }
+
         // Add entry point if specified
         if let Some(ref entry) = self.entry_point {
             args.push("-t".to_string());
@@ -554,6 +576,8 @@ flavor = "production"
             .collect(),
             extra_args: vec!["--obfuscate".to_string()],
             auto_start: false,
+            attach: false,
+            debug_url: None,
         };
 
         let args = config.build_flutter_args("iphone-15");
@@ -571,6 +595,63 @@ flavor = "production"
         assert!(args.contains(&"--obfuscate".to_string()));
     }
 
+    #[test]
+    fn test_build_flutter_args_attach() {
+        let config = LaunchConfig {
+            name: "Host app".to_string(),
+            attach: true,
+            debug_url: Some("http://127.0.0.1:50300/abc=/".to_string()),
+            ..Default::default()
+        };
+
+        let args = config.build_flutter_args("emulator-5554");
+
+        assert_eq!(args[0], "attach");
+        assert_eq!(args[1], "--machine");
+        assert_eq!(args[3], "emulator-5554");
+        let url = args.iter().position(|a| a == "--debug-url").unwrap();
+        assert_eq!(args[url + 1], "http://127.0.0.1:50300/abc=/");
+
+        // A debug_url without attach is ignored
+        let config = LaunchConfig {
+            attach: false,
+            ..config
+        };
+        let args = config.build_flutter_args("emulator-5554");
+        assert_eq!(args[0], "run");
+        assert!(!args.contains(&"--debug-url".to_string()));
+    }
+
+    #[test]
+    fn test_build_flutter_args_attach_skips_release_mode() {
+        let config = LaunchConfig {
+            name: "Host app".to_string(),
+            attach: true,
+            mode: FlutterMode::Release,
+            ..Default::default()
+        };
+
+        let args = config.build_flutter_args("emulator-5554");
+
+        assert_eq!(args, vec!["attach", "--machine", "-d", "emulator-5554"]);
+
+        let profile = LaunchConfig {
+            mode: FlutterMode::Profile,
+            ..config.clone()
+        };
+        assert!(profile
+            .build_flutter_args("emulator-5554")
+            .contains(&"--profile".to_string()));
+
+        let config = LaunchConfig {
+            attach: false,
+            ..config
+        };
+        assert!(config
+            .build_flutter_args("emulator-5554")
+            .contains(&"--release".to_string()));
+    }
+
     #[test]
     fn test_init_launch_file() {
         let temp = tempdir().unwrap();
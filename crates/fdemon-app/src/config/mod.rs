@@ -3,6 +3,7 @@
 //! Supports:
 //! - `.fdemon/config.toml` - Global settings
 //! - `.fdemon/launch.toml` - Launch configurations
+//! - `.fdemon/workspace.toml` - Saved session layout
 //! - `.vscode/launch.json` - VSCode compatibility
 
 pub mod launch;
@@ -10,6 +11,7 @@ pub mod priority;
 pub mod settings;
 pub mod types;
 pub mod vscode;
+pub mod workspace;
 pub mod writer;
 
 pub use launch::{
@@ -18,8 +20,8 @@ pub use launch::{
     save_launch_configs, update_launch_config_dart_defines, update_launch_config_field,
 };
 pub use priority::{
-    find_config, get_first_auto_start, get_first_config, load_all_configs, LoadedConfigs,
-    SourcedConfig,
+    add_cli_attach_config, find_config, get_first_auto_start, get_first_config, load_all_configs,
+    LoadedConfigs, SourcedConfig,
 };
 pub use settings::{
     clear_last_selection, detect_editor, detect_parent_ide, editor_config_for_ide,
@@ -31,11 +33,13 @@ pub use settings::{
 // Re-export public config types used by TUI and other crates
 pub use types::{
     BehaviorSettings, ConfigSource, CustomSourceConfig, DapSettings, DevToolsLoggingSettings,
-    DevToolsSettings, EditorSettings, FlutterMode, IconMode, LaunchConfig, LaunchFile,
-    NativeLogsSettings, ParentIde, ReadyCheck, ResolvedLaunchConfig, SettingItem, SettingValue,
-    Settings, SettingsTab, TagConfig, UiSettings, UserPreferences, WatcherSettings, WindowPrefs,
+    DevToolsSettings, EditorSettings, ExtensionButton, FlutterMode, IconMode, LaunchConfig,
+    LaunchFile, LeakHintSettings, NativeLogsSettings, NetworkProxySettings, ParentIde,
+    PerfBaselineSettings, ReadyCheck, ResolvedLaunchConfig, SettingItem, SettingValue, Settings,
+    SettingsTab, TagConfig, UiSettings, UserPreferences, WatcherSettings, WindowPrefs,
 };
 pub use vscode::load_vscode_configs;
+pub use workspace::{load_workspace, save_workspace, Workspace, WorkspaceSession};
 pub use writer::{
     save_fdemon_configs, update_config_dart_defines, update_config_flavor, update_config_mode,
     ConfigAutoSaver,
@@ -94,6 +94,28 @@ pub fn get_first_auto_start(configs: &LoadedConfigs) -> Option<&SourcedConfig> {
     configs.configs.iter().find(|c| c.config.auto_start)
 }
 
+/// Put an attach configuration for `--attach <url>` ahead of all others and
+/// mark it `auto_start`, so auto-launch attaches to that VM Service.
+pub fn add_cli_attach_config(configs: &mut LoadedConfigs, debug_url: &str) {
+    let config = LaunchConfig {
+        name: "Attach".to_string(),
+        auto_start: true,
+        attach: true,
+        debug_url: Some(debug_url.to_string()),
+        ..Default::default()
+    };
+    configs.configs.insert(
+        0,
+        SourcedConfig {
+            display_name: format!("{} (CLI)", config.name),
+            config,
+            source: ConfigSource::CommandLine,
+        },
+    );
+    configs.vscode_start_index = configs.vscode_start_index.map(|i| i + 1);
+    configs.is_empty = false;
+}
+
 /// Get first config overall (for fallback)
 pub fn get_first_config(configs: &LoadedConfigs) -> Option<&SourcedConfig> {
     configs.configs.first()
@@ -177,6 +199,33 @@ device = "auto"
         assert_eq!(loaded.vscode_start_index, Some(0));
     }
 
+    #[test]
+    fn test_add_cli_attach_config_goes_first_and_auto_starts() {
+        let mut loaded = LoadedConfigs {
+            configs: vec![SourcedConfig::from_resolved(ResolvedLaunchConfig {
+                config: LaunchConfig {
+                    name: "Dev".to_string(),
+                    auto_start: true,
+                    ..Default::default()
+                },
+                source: ConfigSource::VSCode,
+            })],
+            vscode_start_index: Some(0),
+            is_empty: false,
+        };
+
+        add_cli_attach_config(&mut loaded, "http://127.0.0.1:50300/abc=/");
+
+        let first = get_first_auto_start(&loaded).unwrap();
+        assert_eq!(first.source, ConfigSource::CommandLine);
+        assert!(first.config.attach);
+        assert_eq!(
+            first.config.debug_url.as_deref(),
+            Some("http://127.0.0.1:50300/abc=/")
+        );
+        assert_eq!(loaded.vscode_start_index, Some(1));
+    }
+
     #[test]
     fn test_find_config_case_insensitive() {
         let temp = tempdir().unwrap();
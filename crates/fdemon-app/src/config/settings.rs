@@ -451,6 +451,7 @@ pub fn init_config_dir(project_path: &Path) -> Result<()> {
 
 [behavior]
 confirm_quit = true     # Ask before quitting with running apps
+screenshot_dir = ".fdemon/screenshots"  # Where [p]/[P] screenshots are saved
 
 [watcher]
 paths = ["lib"]
@@ -470,7 +471,7 @@ icons = "nerd_fonts"
 [devtools]
 auto_open = false
 browser = ""                          # Empty = system default
-default_panel = "inspector"           # "inspector", "performance", or "network"
+default_panel = "inspector"           # "inspector", "performance", "network", "dumps", "profiler", "console", or "extensions"
 performance_refresh_ms = 2000         # Memory polling interval (min 500ms)
 memory_history_size = 60              # Memory snapshots to retain
 tree_max_depth = 0                    # Widget tree depth (0 = unlimited)
@@ -479,6 +480,44 @@ allocation_profile_interval_ms = 5000 # Class allocation fetch interval (min 100
 max_network_entries = 500             # Max HTTP entries per session (FIFO eviction)
 network_auto_record = true            # Auto-start recording when entering Network tab
 network_poll_interval_ms = 1000       # HTTP profile poll interval (min 500ms)
+network_persist_history = false       # Keep HTTP history across restarts
+network_spill_to_disk = false         # Append evicted entries to .fdemon/network/
+
+# Local HTTP proxy for recording and mocking app traffic (see docs/CONFIGURATION.md)
+# [devtools.proxy]
+# enabled = false                     # Start a proxy for each new session
+# port = 0                            # 0 = pick a free port per session
+# advertised_host = ""                # Empty = auto (10.0.2.2 on Android emulators)
+# dart_define = "FDEMON_HTTP_PROXY"   # --dart-define carrying host:port
+# rules_dir = ".fdemon/mocks"         # Mock rule files (*.toml)
+
+# Display refresh rate per device id or name, overriding the rate the engine
+# reports. Sets the frame budget used for jank detection (see docs/CONFIGURATION.md)
+# [devtools.refresh_rates]
+# "emulator-5554" = 120
+
+# Performance baselines saved with 'B' and compared with 'C' (see docs/CONFIGURATION.md)
+# [devtools.baseline]
+# name = "default"                    # Baseline used when no name is given
+# fps_drop_pct = 10.0                 # Allowed FPS drop (%)
+# frame_time_increase_pct = 20.0      # Allowed p95/p99 frame time increase (%)
+# jank_increase = 5                   # Allowed extra janky frames
+# heap_increase_pct = 20.0            # Allowed peak heap increase (%)
+
+# Leak hints: count watched classes after each hot reload and route change
+# and flag classes that keep growing (see docs/CONFIGURATION.md)
+# [devtools.leak_hints]
+# enabled = true                      # Off by default: each checkpoint forces a GC
+# classes = ["State", "AnimationController", "StreamSubscription", "TextEditingController", "ScrollController", "FocusNode"]
+# checkpoints = 3                     # Consecutive checkpoints with growth to flag a class
+
+# Buttons for service extensions the app registers with registerExtension,
+# shown in the Extensions panel (see docs/CONFIGURATION.md)
+# [[devtools.extensions]]
+# label = "Debug menu"
+# method = "ext.myapp.toggleDebugMenu"
+# key = "m"                           # Presses the button in the Extensions panel
+# args = { enabled = "true" }         # String values only
 
 [editor]
 # Editor command (leave empty for auto-detection)
@@ -653,6 +692,7 @@ fn generate_default_config() -> String {
 
 [behavior]
 confirm_quit = true     # Ask before quitting with running apps
+screenshot_dir = ".fdemon/screenshots"  # Where [p]/[P] screenshots are saved
 
 [watcher]
 paths = ["lib"]
@@ -674,7 +714,7 @@ icons = "nerd_fonts"
 [devtools]
 auto_open = false
 browser = ""                          # Empty = system default
-default_panel = "inspector"           # "inspector", "performance", or "network"
+default_panel = "inspector"           # "inspector", "performance", "network", "dumps", "profiler", "console", or "extensions"
 performance_refresh_ms = 2000         # Memory polling interval (min 500ms)
 memory_history_size = 60              # Memory snapshots to retain
 tree_max_depth = 0                    # Widget tree depth (0 = unlimited)
@@ -683,6 +723,44 @@ allocation_profile_interval_ms = 5000 # Class allocation fetch interval (min 100
 max_network_entries = 500             # Max HTTP entries per session (FIFO eviction)
 network_auto_record = true            # Auto-start recording when entering Network tab
 network_poll_interval_ms = 1000       # HTTP profile poll interval (min 500ms)
+network_persist_history = false       # Keep HTTP history across restarts
+network_spill_to_disk = false         # Append evicted entries to .fdemon/network/
+
+# Local HTTP proxy for recording and mocking app traffic (see docs/CONFIGURATION.md)
+# [devtools.proxy]
+# enabled = false                     # Start a proxy for each new session
+# port = 0                            # 0 = pick a free port per session
+# advertised_host = ""                # Empty = auto (10.0.2.2 on Android emulators)
+# dart_define = "FDEMON_HTTP_PROXY"   # --dart-define carrying host:port
+# rules_dir = ".fdemon/mocks"         # Mock rule files (*.toml)
+
+# Display refresh rate per device id or name, overriding the rate the engine
+# reports. Sets the frame budget used for jank detection (see docs/CONFIGURATION.md)
+# [devtools.refresh_rates]
+# "emulator-5554" = 120
+
+# Performance baselines saved with 'B' and compared with 'C' (see docs/CONFIGURATION.md)
+# [devtools.baseline]
+# name = "default"                    # Baseline used when no name is given
+# fps_drop_pct = 10.0                 # Allowed FPS drop (%)
+# frame_time_increase_pct = 20.0      # Allowed p95/p99 frame time increase (%)
+# jank_increase = 5                   # Allowed extra janky frames
+# heap_increase_pct = 20.0            # Allowed peak heap increase (%)
+
+# Leak hints: count watched classes after each hot reload and route change
+# and flag classes that keep growing (see docs/CONFIGURATION.md)
+# [devtools.leak_hints]
+# enabled = true                      # Off by default: each checkpoint forces a GC
+# classes = ["State", "AnimationController", "StreamSubscription", "TextEditingController", "ScrollController", "FocusNode"]
+# checkpoints = 3                     # Consecutive checkpoints with growth to flag a class
+
+# Buttons for service extensions the app registers with registerExtension,
+# shown in the Extensions panel (see docs/CONFIGURATION.md)
+# [[devtools.extensions]]
+# label = "Debug menu"
+# method = "ext.myapp.toggleDebugMenu"
+# key = "m"                           # Presses the button in the Extensions panel
+# args = { enabled = "true" }         # String values only
 
 [editor]
 # Editor command (leave empty for auto-detection)
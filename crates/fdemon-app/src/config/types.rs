@@ -44,6 +44,16 @@ pub struct LaunchConfig {
     /// Whether to start this config automatically
     #[serde(default)]
     pub auto_start: bool,
+
+    /// Attach to an already-running app with `flutter attach` instead of
+    /// launching it with `flutter run`
+    #[serde(default)]
+    pub attach: bool,
+
+    /// VM Service URI of the app to attach to (`--debug-url`). When unset,
+    /// `flutter attach` waits for the app to appear on the device.
+    #[serde(default)]
+    pub debug_url: Option<String>,
 }
 
 impl Default for LaunchConfig {
@@ -57,6 +67,8 @@ impl Default for LaunchConfig {
             dart_defines: HashMap::new(),
             extra_args: Vec::new(),
             auto_start: false,
+            attach: false,
+            debug_url: None,
         }
     }
 }
@@ -164,6 +176,18 @@ pub struct BehaviorSettings {
     /// for the dialog" only, not a launch trigger.
     #[serde(default)]
     pub auto_launch: bool,
+    /// When true, the sessions, filters, hidden tags and DevTools panel are
+    /// saved to `.fdemon/workspace.toml` on quit and restored on the next
+    /// start (takes precedence over `auto_start` configs).
+    #[serde(default)]
+    pub restore_workspace: bool,
+    /// Directory (relative to the project) where screenshots are written.
+    #[serde(default = "default_screenshot_dir")]
+    pub screenshot_dir: String,
+    /// Maximum number of concurrent sessions. When the limit is reached, the
+    /// oldest stopped session is evicted to make room.
+    #[serde(default = "default_max_sessions")]
+    pub max_sessions: usize,
 }
 
 impl Default for BehaviorSettings {
@@ -171,10 +195,21 @@ impl Default for BehaviorSettings {
         Self {
             confirm_quit: true,
             auto_launch: false,
+            restore_workspace: false,
+            screenshot_dir: default_screenshot_dir(),
+            max_sessions: default_max_sessions(),
         }
     }
 }
 
+fn default_screenshot_dir() -> String {
+    ".fdemon/screenshots".to_string()
+}
+
+fn default_max_sessions() -> usize {
+    crate::session_manager::MAX_SESSIONS
+}
+
 /// File watcher settings
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct WatcherSettings {
@@ -314,7 +349,7 @@ pub struct DevToolsSettings {
     #[serde(default)]
     pub browser: String,
 
-    /// Default panel when entering DevTools mode ("inspector", "performance", "network")
+    /// Default panel when entering DevTools mode ("inspector", "performance", "network", "dumps", "profiler", "console", "extensions")
     #[serde(default = "default_devtools_panel")]
     pub default_panel: String,
 
@@ -368,9 +403,45 @@ pub struct DevToolsSettings {
     #[serde(default = "default_network_poll_interval_ms")]
     pub network_poll_interval_ms: u64,
 
+    /// Keep network history across hot restarts and session relaunches on the
+    /// same device. Entries are labelled with the restart generation they
+    /// belong to. Default: false.
+    #[serde(default)]
+    pub network_persist_history: bool,
+
+    /// Append entries evicted by `max_network_entries` to
+    /// `.fdemon/network/<device>.jsonl` instead of discarding them. The file
+    /// is capped at `max_network_entries` lines, dropping the oldest.
+    /// Default: false.
+    #[serde(default)]
+    pub network_spill_to_disk: bool,
+
     /// Logging sub-settings
     #[serde(default)]
     pub logging: DevToolsLoggingSettings,
+
+    /// Local HTTP proxy sub-settings (`[devtools.proxy]`)
+    #[serde(default)]
+    pub proxy: NetworkProxySettings,
+
+    /// Performance baseline sub-settings (`[devtools.baseline]`)
+    #[serde(default)]
+    pub baseline: PerfBaselineSettings,
+
+    /// Leak hint sub-settings (`[devtools.leak_hints]`)
+    #[serde(default)]
+    pub leak_hints: LeakHintSettings,
+
+    /// Display refresh rate in Hz per device id or name
+    /// (`[devtools.refresh_rates]`). Overrides the rate reported by the
+    /// engine when set.
+    #[serde(default)]
+    pub refresh_rates: HashMap<String, f64>,
+
+    /// Buttons for app-registered service extensions
+    /// (`[[devtools.extensions]]`), shown in the Extensions panel.
+    #[serde(default)]
+    pub extensions: Vec<ExtensionButton>,
 }
 
 impl Default for DevToolsSettings {
@@ -389,11 +460,35 @@ impl Default for DevToolsSettings {
             max_network_entries: default_max_network_entries(),
             network_auto_record: default_network_auto_record(),
             network_poll_interval_ms: default_network_poll_interval_ms(),
+            network_persist_history: false,
+            network_spill_to_disk: false,
             logging: DevToolsLoggingSettings::default(),
+            proxy: NetworkProxySettings::default(),
+            baseline: PerfBaselineSettings::default(),
+            leak_hints: LeakHintSettings::default(),
+            refresh_rates: HashMap::new(),
+            extensions: Vec::new(),
         }
     }
 }
 
+impl DevToolsSettings {
+    /// Proxy settings to launch new sessions with, or `None` when disabled.
+    pub fn proxy_if_enabled(&self) -> Option<NetworkProxySettings> {
+        self.proxy.enabled.then(|| self.proxy.clone())
+    }
+
+    /// Configured refresh rate for a device, matched by id before name.
+    /// Rates that are not positive are ignored.
+    pub fn refresh_rate_for(&self, device_id: &str, device_name: &str) -> Option<f64> {
+        self.refresh_rates
+            .get(device_id)
+            .or_else(|| self.refresh_rates.get(device_name))
+            .copied()
+            .filter(|hz| *hz > 0.0)
+    }
+}
+
 fn default_devtools_panel() -> String {
     "inspector".to_string()
 }
@@ -461,6 +556,216 @@ fn default_dedupe_threshold_ms() -> u64 {
     100
 }
 
+/// Local HTTP proxy sub-settings.
+///
+/// When enabled, a recording proxy is started before each session launch and
+/// its address is passed to the app as a `--dart-define`. The app must route
+/// its HTTP client through that address for traffic to be captured or mocked.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct NetworkProxySettings {
+    /// Start the proxy for new sessions
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Listen port (0 = pick a free port per session; a taken port falls
+    /// back to a free one)
+    #[serde(default)]
+    pub port: u16,
+
+    /// Address to bind the listener to. The proxy is unauthenticated, so a
+    /// non-loopback address such as `0.0.0.0` opens it (and its `CONNECT`
+    /// tunnelling) to the whole network
+    #[serde(default = "default_proxy_bind_address")]
+    pub bind_address: String,
+
+    /// Host advertised to the app (empty = auto: `10.0.2.2` for Android
+    /// emulators, `127.0.0.1` otherwise)
+    #[serde(default)]
+    pub advertised_host: String,
+
+    /// Name of the `--dart-define` carrying `host:port`
+    #[serde(default = "default_proxy_dart_define")]
+    pub dart_define: String,
+
+    /// Directory of `*.toml` mock rule files, relative to the project
+    #[serde(default = "default_proxy_rules_dir")]
+    pub rules_dir: String,
+}
+
+impl Default for NetworkProxySettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            port: 0,
+            bind_address: default_proxy_bind_address(),
+            advertised_host: String::new(),
+            dart_define: default_proxy_dart_define(),
+            rules_dir: default_proxy_rules_dir(),
+        }
+    }
+}
+
+fn default_proxy_bind_address() -> String {
+    "127.0.0.1".to_string()
+}
+
+fn default_proxy_dart_define() -> String {
+    "FDEMON_HTTP_PROXY".to_string()
+}
+
+fn default_proxy_rules_dir() -> String {
+    ".fdemon/mocks".to_string()
+}
+
+/// Performance baseline sub-settings.
+///
+/// Baselines are saved to `.fdemon/baselines/<name>.json`. A comparison
+/// fails when any metric is worse than the baseline by more than its limit.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct PerfBaselineSettings {
+    /// Baseline used when no name is given
+    #[serde(default = "default_baseline_name")]
+    pub name: String,
+
+    /// Allowed FPS drop, in percent
+    #[serde(default = "default_baseline_fps_drop_pct")]
+    pub fps_drop_pct: f64,
+
+    /// Allowed p95/p99 frame time increase, in percent
+    #[serde(default = "default_baseline_frame_time_increase_pct")]
+    pub frame_time_increase_pct: f64,
+
+    /// Allowed increase in janky frames
+    #[serde(default = "default_baseline_jank_increase")]
+    pub jank_increase: u32,
+
+    /// Allowed peak heap increase, in percent
+    #[serde(default = "default_baseline_heap_increase_pct")]
+    pub heap_increase_pct: f64,
+}
+
+impl Default for PerfBaselineSettings {
+    fn default() -> Self {
+        Self {
+            name: default_baseline_name(),
+            fps_drop_pct: default_baseline_fps_drop_pct(),
+            frame_time_increase_pct: default_baseline_frame_time_increase_pct(),
+            jank_increase: default_baseline_jank_increase(),
+            heap_increase_pct: default_baseline_heap_increase_pct(),
+        }
+    }
+}
+
+impl PerfBaselineSettings {
+    /// Regression limits for [`fdemon_core::PerfBaseline::compare`].
+    pub fn thresholds(&self) -> fdemon_core::PerfThresholds {
+        fdemon_core::PerfThresholds {
+            fps_drop_pct: self.fps_drop_pct,
+            frame_time_increase_pct: self.frame_time_increase_pct,
+            jank_increase: self.jank_increase,
+            heap_increase_pct: self.heap_increase_pct,
+        }
+    }
+}
+
+fn default_baseline_name() -> String {
+    "default".to_string()
+}
+
+fn default_baseline_fps_drop_pct() -> f64 {
+    10.0
+}
+
+fn default_baseline_frame_time_increase_pct() -> f64 {
+    20.0
+}
+
+fn default_baseline_jank_increase() -> u32 {
+    5
+}
+
+fn default_baseline_heap_increase_pct() -> f64 {
+    20.0
+}
+
+/// Leak hint settings (`[devtools.leak_hints]`).
+///
+/// After each hot reload or route change, live instances of the watched
+/// classes are counted after a forced GC. A class whose count grew at
+/// `checkpoints` consecutive checkpoints is flagged in the performance panel
+/// and logged as a warning. Off by default since every checkpoint forces a GC.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct LeakHintSettings {
+    /// Count watched classes at each reload and route change
+    #[serde(default = "default_leak_hints_enabled")]
+    pub enabled: bool,
+
+    /// Class names to watch. A class matches when its name ends with an
+    /// entry, so `State` covers every `State` subclass.
+    #[serde(default = "default_leak_watch_classes")]
+    pub classes: Vec<String>,
+
+    /// Consecutive checkpoints with growth needed to flag a class
+    #[serde(default = "default_leak_checkpoints")]
+    pub checkpoints: usize,
+}
+
+impl Default for LeakHintSettings {
+    fn default() -> Self {
+        Self {
+            enabled: default_leak_hints_enabled(),
+            classes: default_leak_watch_classes(),
+            checkpoints: default_leak_checkpoints(),
+        }
+    }
+}
+
+impl LeakHintSettings {
+    /// Tracker for a new VM connection, or `None` when disabled.
+    pub fn tracker(&self) -> Option<fdemon_core::LeakTracker> {
+        (self.enabled && !self.classes.is_empty())
+            .then(|| fdemon_core::LeakTracker::new(self.classes.clone(), self.checkpoints))
+    }
+}
+
+fn default_leak_hints_enabled() -> bool {
+    false
+}
+
+fn default_leak_watch_classes() -> Vec<String> {
+    fdemon_core::DEFAULT_LEAK_WATCH_CLASSES
+        .iter()
+        .map(|c| c.to_string())
+        .collect()
+}
+
+fn default_leak_checkpoints() -> usize {
+    3
+}
+
+/// A button for a service extension the app registers with
+/// `registerExtension` (`[[devtools.extensions]]`).
+///
+/// Pressing it in the Extensions panel calls `method` on the DevTools
+/// isolate with `args` and logs the result.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ExtensionButton {
+    /// Text shown on the button and in the log
+    pub label: String,
+
+    /// Extension method, e.g. `ext.myapp.toggleDebugMenu`
+    pub method: String,
+
+    /// Key that presses the button while the Extensions panel is shown
+    #[serde(default)]
+    pub key: Option<char>,
+
+    /// Parameters passed to the extension. The protocol only allows string
+    /// values.
+    #[serde(default)]
+    pub args: HashMap<String, String>,
+}
+
 // ─────────────────────────────────────────────────────────────────────────────
 // Editor Settings
 // ─────────────────────────────────────────────────────────────────────────────
@@ -1682,6 +1987,8 @@ theme = "default"
         assert_eq!(settings.max_network_entries, 500);
         assert!(settings.network_auto_record);
         assert_eq!(settings.network_poll_interval_ms, 1000);
+        assert!(!settings.network_persist_history);
+        assert!(!settings.network_spill_to_disk);
     }
 
     #[test]
@@ -1719,6 +2026,8 @@ theme = "default"
             max_network_entries = 200
             network_auto_record = false
             network_poll_interval_ms = 2000
+            network_persist_history = true
+            network_spill_to_disk = true
 
             [logging]
             hybrid_enabled = true
@@ -1739,6 +2048,111 @@ theme = "default"
         assert_eq!(settings.max_network_entries, 200);
         assert!(!settings.network_auto_record);
         assert_eq!(settings.network_poll_interval_ms, 2000);
+        assert!(settings.network_persist_history);
+        assert!(settings.network_spill_to_disk);
+    }
+
+    #[test]
+    fn test_network_proxy_settings_defaults() {
+        let proxy = NetworkProxySettings::default();
+        assert!(!proxy.enabled);
+        assert_eq!(proxy.port, 0);
+        assert_eq!(proxy.bind_address, "127.0.0.1");
+        assert!(proxy.advertised_host.is_empty());
+        assert_eq!(proxy.dart_define, "FDEMON_HTTP_PROXY");
+        assert_eq!(proxy.rules_dir, ".fdemon/mocks");
+    }
+
+    #[test]
+    fn test_network_proxy_settings_from_toml() {
+        let settings: Settings = toml::from_str(
+            r#"
+            [devtools.proxy]
+            enabled = true
+            port = 8899
+            advertised_host = "192.168.1.20"
+            "#,
+        )
+        .unwrap();
+        let proxy = &settings.devtools.proxy;
+        assert!(proxy.enabled);
+        assert_eq!(proxy.port, 8899);
+        assert_eq!(proxy.advertised_host, "192.168.1.20");
+        assert_eq!(proxy.dart_define, "FDEMON_HTTP_PROXY");
+    }
+
+    #[test]
+    fn test_extension_buttons_from_toml() {
+        let settings: Settings = toml::from_str(
+            r#"
+            [[devtools.extensions]]
+            label = "Debug menu"
+            method = "ext.myapp.toggleDebugMenu"
+            key = "m"
+
+            [[devtools.extensions]]
+            label = "Freeze clock"
+            method = "ext.myapp.fakeClock"
+            args = { time = "2024-01-01T09:00:00Z" }
+            "#,
+        )
+        .unwrap();
+        let buttons = &settings.devtools.extensions;
+        assert_eq!(buttons.len(), 2);
+        assert_eq!(buttons[0].key, Some('m'));
+        assert!(buttons[0].args.is_empty());
+        assert_eq!(buttons[1].key, None);
+        assert_eq!(buttons[1].args["time"], "2024-01-01T09:00:00Z");
+        assert!(Settings::default().devtools.extensions.is_empty());
+    }
+
+    #[test]
+    fn test_leak_hints_from_toml() {
+        let settings: Settings = toml::from_str(
+            r#"
+            [devtools.leak_hints]
+            enabled = true
+            classes = ["Bloc", "Controller"]
+            checkpoints = 5
+            "#,
+        )
+        .unwrap();
+        let leak_hints = &settings.devtools.leak_hints;
+        assert!(leak_hints.enabled);
+        assert_eq!(leak_hints.classes, ["Bloc", "Controller"]);
+        assert_eq!(leak_hints.checkpoints, 5);
+        assert!(leak_hints.tracker().is_some());
+
+        let defaults = LeakHintSettings::default();
+        assert!(!defaults.enabled, "leak hints are opt-in");
+        assert!(defaults.classes.iter().any(|c| c == "AnimationController"));
+        let disabled = LeakHintSettings {
+            enabled: false,
+            ..defaults
+        };
+        assert!(disabled.tracker().is_none());
+    }
+
+    #[test]
+    fn test_refresh_rates_from_toml() {
+        let settings: Settings = toml::from_str(
+            r#"
+            [devtools.refresh_rates]
+            "emulator-5554" = 120
+            "Pixel 8 Pro" = 90.0
+            "broken" = 0
+            "#,
+        )
+        .unwrap();
+        let devtools = &settings.devtools;
+        assert_eq!(
+            devtools.refresh_rate_for("emulator-5554", "Pixel 8 Pro"),
+            Some(120.0),
+            "device id matches before name"
+        );
+        assert_eq!(devtools.refresh_rate_for("R5CT", "Pixel 8 Pro"), Some(90.0));
+        assert_eq!(devtools.refresh_rate_for("broken", "x"), None);
+        assert_eq!(devtools.refresh_rate_for("other", "Other"), None);
     }
 
     #[test]
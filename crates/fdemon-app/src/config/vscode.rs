@@ -33,9 +33,12 @@ struct VSCodeConfiguration {
     config_type: String,
 
     /// Request type: "launch" or "attach"
-    #[allow(dead_code)]
     request: String,
 
+    /// VM Service URI of the app to attach to (attach requests only)
+    #[serde(default)]
+    vm_service_uri: Option<String>,
+
     /// Entry point program
     #[serde(default)]
     program: Option<String>,
@@ -177,6 +180,8 @@ fn convert_vscode_config(vscode: VSCodeConfiguration) -> Option<ResolvedLaunchCo
         dart_defines,
         extra_args,
         auto_start: false, // VSCode imports never auto-start
+        attach: vscode.request == "attach",
+        debug_url: vscode.vm_service_uri.filter(|u| !u.is_empty()),
     };
 
     Some(ResolvedLaunchConfig {
@@ -460,6 +465,40 @@ mod tests {
         assert!(!configs[0].config.auto_start);
     }
 
+    #[test]
+    fn test_vscode_attach_config() {
+        let temp = tempdir().unwrap();
+        let vscode_dir = temp.path().join(".vscode");
+        std::fs::create_dir_all(&vscode_dir).unwrap();
+
+        let content = r#"{
+            "configurations": [
+                {
+                    "name": "Attach",
+                    "type": "dart",
+                    "request": "attach",
+                    "vmServiceUri": "http://127.0.0.1:50300/abc=/"
+                },
+                {
+                    "name": "Launch",
+                    "type": "dart",
+                    "request": "launch"
+                }
+            ]
+        }"#;
+        std::fs::write(vscode_dir.join("launch.json"), content).unwrap();
+
+        let configs = load_vscode_configs(temp.path());
+
+        assert!(configs[0].config.attach);
+        assert_eq!(
+            configs[0].config.debug_url.as_deref(),
+            Some("http://127.0.0.1:50300/abc=/")
+        );
+        assert!(!configs[1].config.attach);
+        assert!(configs[1].config.debug_url.is_none());
+    }
+
     #[test]
     fn test_vscode_config_empty_device_id_defaults_to_auto() {
         let temp = tempdir().unwrap();
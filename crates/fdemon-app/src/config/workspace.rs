@@ -0,0 +1,183 @@
+//! Workspace snapshot (`.fdemon/workspace.toml`)
+//!
+//! With `[behavior] restore_workspace = true`, the sessions open when fdemon
+//! quits are written here — device, launch config, log filters, search query
+//! and hidden native tags — together with the selected session and the
+//! DevTools panel. The next start relaunches the same set of sessions.
+
+use std::path::Path;
+
+use fdemon_core::prelude::*;
+use fdemon_core::{LogLevelFilter, LogSourceFilter};
+use serde::{Deserialize, Serialize};
+
+const FDEMON_DIR: &str = ".fdemon";
+const WORKSPACE_FILENAME: &str = "workspace.toml";
+
+/// Layout saved to `.fdemon/workspace.toml`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Workspace {
+    /// Index of the selected session in [`Self::sessions`].
+    #[serde(default)]
+    pub selected: usize,
+
+    /// Active DevTools panel when DevTools mode was open (same names as
+    /// `[devtools] default_panel`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub devtools_panel: Option<String>,
+
+    /// Sessions in tab order.
+    #[serde(default)]
+    pub sessions: Vec<WorkspaceSession>,
+}
+
+/// One saved session.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct WorkspaceSession {
+    /// Device ID the session ran on.
+    pub device_id: String,
+
+    /// Device name, used in messages when the device is not connected.
+    #[serde(default)]
+    pub device_name: String,
+
+    /// Launch config name (`None` = bare `flutter run`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub config: Option<String>,
+
+    /// Log level filter.
+    #[serde(default)]
+    pub level_filter: LogLevelFilter,
+
+    /// Log source filter.
+    #[serde(default)]
+    pub source_filter: LogSourceFilter,
+
+    /// Log search query.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub search: String,
+
+    /// Native log tags hidden in the tag filter.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub hidden_tags: Vec<String>,
+}
+
+/// Load the workspace from `.fdemon/workspace.toml`.
+///
+/// Returns `None` if the file doesn't exist or can't be parsed.
+pub fn load_workspace(project_path: &Path) -> Option<Workspace> {
+    let path = project_path.join(FDEMON_DIR).join(WORKSPACE_FILENAME);
+
+    if !path.exists() {
+        debug!("No workspace file at {:?}", path);
+        return None;
+    }
+
+    match std::fs::read_to_string(&path) {
+        Ok(content) => match toml::from_str(&content) {
+            Ok(workspace) => {
+                debug!("Loaded workspace from {:?}", path);
+                Some(workspace)
+            }
+            Err(e) => {
+                warn!("Failed to parse {:?}: {}", path, e);
+                None
+            }
+        },
+        Err(e) => {
+            warn!("Failed to read {:?}: {}", path, e);
+            None
+        }
+    }
+}
+
+/// Save the workspace to `.fdemon/workspace.toml`.
+///
+/// Uses atomic write (temp file + rename) for safety.
+pub fn save_workspace(project_path: &Path, workspace: &Workspace) -> Result<()> {
+    let fdemon_dir = project_path.join(FDEMON_DIR);
+
+    if !fdemon_dir.exists() {
+        std::fs::create_dir_all(&fdemon_dir)
+            .map_err(|e| Error::config(format!("Failed to create .fdemon dir: {}", e)))?;
+    }
+
+    let path = fdemon_dir.join(WORKSPACE_FILENAME);
+    let temp_path = fdemon_dir.join(".workspace.toml.tmp");
+
+    let header = "# Sessions restored on startup ([behavior] restore_workspace = true)\n\
+                  # Rewritten by fdemon on quit\n\n";
+
+    let content = toml::to_string_pretty(workspace)
+        .map_err(|e| Error::config(format!("Failed to serialize workspace: {}", e)))?;
+
+    std::fs::write(&temp_path, format!("{}{}", header, content))
+        .map_err(|e| Error::config(format!("Failed to write temp file: {}", e)))?;
+
+    std::fs::rename(&temp_path, &path)
+        .map_err(|e| Error::config(format!("Failed to rename temp file: {}", e)))?;
+
+    debug!("Saved workspace to {:?}", path);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_load_workspace_missing_file() {
+        let temp = tempdir().unwrap();
+        assert!(load_workspace(temp.path()).is_none());
+    }
+
+    #[test]
+    fn test_save_and_load_workspace_roundtrip() {
+        let temp = tempdir().unwrap();
+        let workspace = Workspace {
+            selected: 1,
+            devtools_panel: Some("network".to_string()),
+            sessions: vec![
+                WorkspaceSession {
+                    device_id: "emulator-5554".to_string(),
+                    device_name: "Pixel 7".to_string(),
+                    config: Some("Development".to_string()),
+                    level_filter: LogLevelFilter::Warnings,
+                    source_filter: LogSourceFilter::Native,
+                    search: "auth".to_string(),
+                    hidden_tags: vec!["okhttp".to_string()],
+                },
+                WorkspaceSession {
+                    device_id: "macos".to_string(),
+                    device_name: "macOS".to_string(),
+                    ..Default::default()
+                },
+            ],
+        };
+
+        save_workspace(temp.path(), &workspace).unwrap();
+        let content = std::fs::read_to_string(temp.path().join(".fdemon/workspace.toml")).unwrap();
+        assert!(content.contains("level_filter = \"warnings\""));
+        assert!(content.contains("[[sessions]]"));
+
+        assert_eq!(load_workspace(temp.path()), Some(workspace));
+    }
+
+    #[test]
+    fn test_load_workspace_defaults_missing_fields() {
+        let temp = tempdir().unwrap();
+        std::fs::create_dir_all(temp.path().join(".fdemon")).unwrap();
+        std::fs::write(
+            temp.path().join(".fdemon/workspace.toml"),
+            "[[sessions]]\ndevice_id = \"chrome\"\n",
+        )
+        .unwrap();
+
+        let workspace = load_workspace(temp.path()).unwrap();
+        assert_eq!(workspace.selected, 0);
+        assert!(workspace.devtools_panel.is_none());
+        assert_eq!(workspace.sessions[0].device_id, "chrome");
+        assert_eq!(workspace.sessions[0].level_filter, LogLevelFilter::All);
+    }
+}
@@ -125,6 +125,17 @@ fn write_config_section(content: &mut String, config: &LaunchConfig, name: &str)
         .map_err(|e| Error::config(format!("Failed to write entry_point: {}", e)))?;
     }
 
+    // Attach mode and the VM Service URI to attach to. Written before the
+    // dart_defines table so they stay in the [[configurations]] entry.
+    if config.attach {
+        writeln!(content, "attach = true")
+            .map_err(|e| Error::config(format!("Failed to write attach: {}", e)))?;
+    }
+    if let Some(ref debug_url) = config.debug_url {
+        writeln!(content, "debug_url = \"{}\"", escape_toml_string(debug_url))
+            .map_err(|e| Error::config(format!("Failed to write debug_url: {}", e)))?;
+    }
+
     // Dart defines (as HashMap, written as TOML table)
     if !config.dart_defines.is_empty() {
         writeln!(content)
@@ -305,6 +316,8 @@ mod tests {
                 dart_defines: std::collections::HashMap::new(),
                 extra_args: Vec::new(),
                 auto_start: false,
+                attach: false,
+                debug_url: None,
             },
             source: ConfigSource::FDemon,
             display_name: "Development".to_string(),
@@ -334,6 +347,8 @@ mod tests {
                 dart_defines,
                 extra_args: Vec::new(),
                 auto_start: false,
+                attach: false,
+                debug_url: None,
             },
             source: ConfigSource::FDemon,
             display_name: "Dev".to_string(),
@@ -358,6 +373,8 @@ mod tests {
                 dart_defines: std::collections::HashMap::new(),
                 extra_args: vec!["--verbose".to_string(), "--trace-startup".to_string()],
                 auto_start: false,
+                attach: false,
+                debug_url: None,
             },
             source: ConfigSource::FDemon,
             display_name: "Test".to_string(),
@@ -386,6 +403,8 @@ mod tests {
                 dart_defines: std::collections::HashMap::new(),
                 extra_args: Vec::new(),
                 auto_start: false,
+                attach: false,
+                debug_url: None,
             },
             source: ConfigSource::FDemon,
             display_name: "Test".to_string(),
@@ -417,6 +436,8 @@ mod tests {
                 dart_defines: std::collections::HashMap::new(),
                 extra_args: Vec::new(),
                 auto_start: false,
+                attach: false,
+                debug_url: None,
             },
             source: ConfigSource::VSCode,
             display_name: "VSCode Config".to_string(),
@@ -445,6 +466,8 @@ mod tests {
                 dart_defines: std::collections::HashMap::new(),
                 extra_args: Vec::new(),
                 auto_start: false,
+                attach: false,
+                debug_url: None,
             },
             source: ConfigSource::FDemon,
             display_name: "FDemon Config".to_string(),
@@ -459,6 +482,8 @@ mod tests {
                 dart_defines: std::collections::HashMap::new(),
                 extra_args: Vec::new(),
                 auto_start: false,
+                attach: false,
+                debug_url: None,
             },
             source: ConfigSource::VSCode,
             display_name: "VSCode Config".to_string(),
@@ -581,6 +606,8 @@ mod tests {
                 dart_defines: std::collections::HashMap::new(),
                 extra_args: Vec::new(),
                 auto_start: true,
+                attach: false,
+                debug_url: None,
             },
             source: ConfigSource::FDemon,
             display_name: "Dev".to_string(),
@@ -596,6 +623,8 @@ mod tests {
                 dart_defines: std::collections::HashMap::new(),
                 extra_args: Vec::new(),
                 auto_start: false,
+                attach: false,
+                debug_url: None,
             },
             source: ConfigSource::FDemon,
             display_name: "Prod".to_string(),
@@ -627,6 +656,8 @@ mod tests {
             dart_defines: std::collections::HashMap::new(),
             extra_args: Vec::new(),
             auto_start: false,
+            attach: false,
+            debug_url: None,
         };
 
         let mut content = String::new();
@@ -655,6 +686,8 @@ mod tests {
                 dart_defines,
                 extra_args: vec!["--verbose".to_string()],
                 auto_start: true,
+                attach: false,
+                debug_url: None,
             },
             source: ConfigSource::FDemon,
             display_name: "Dev".to_string(),
@@ -675,4 +708,38 @@ mod tests {
         assert!(content.contains("\"--verbose\""));
         assert!(content.contains("auto_start = true"));
     }
+
+    #[test]
+    fn test_save_fdemon_configs_roundtrip_attach() {
+        let temp_dir = TempDir::new().unwrap();
+        let project_path = temp_dir.path();
+
+        let mut dart_defines = std::collections::HashMap::new();
+        dart_defines.insert("API_URL".to_string(), "https://test.com".to_string());
+
+        let mut configs = LoadedConfigs::default();
+        configs.configs.push(SourcedConfig {
+            config: LaunchConfig {
+                name: "Host app".to_string(),
+                attach: true,
+                debug_url: Some("http://127.0.0.1:50300/abc=/".to_string()),
+                dart_defines,
+                ..Default::default()
+            },
+            source: ConfigSource::FDemon,
+            display_name: "Host app".to_string(),
+        });
+
+        save_fdemon_configs(project_path, &configs).unwrap();
+
+        let content = std::fs::read_to_string(project_path.join(".fdemon/launch.toml")).unwrap();
+        let file: crate::config::LaunchFile = toml::from_str(&content).unwrap();
+        let config = &file.configurations[0];
+        assert!(config.attach);
+        assert_eq!(
+            config.debug_url.as_deref(),
+            Some("http://127.0.0.1:50300/abc=/")
+        );
+        assert_eq!(config.dart_defines.len(), 1);
+    }
 }
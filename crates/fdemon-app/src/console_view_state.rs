@@ -0,0 +1,353 @@
+//! VM Service console state - command input, history, completion and output.
+//!
+//! The console sends raw VM Service RPCs typed as `method {json params}`,
+//! e.g. `getIsolate` or `getObject {"objectId": "classes/12"}`, and shows
+//! each response as pretty-printed JSON. `tail <streamId>` echoes the raw
+//! events of a stream until `untail <streamId>`, and `clear` empties the
+//! output. This module parses commands and tracks the input line, command
+//! history, method completion and the output scrollback; it is shared by
+//! the app handler layer and the TUI console view.
+
+use std::collections::{BTreeSet, VecDeque};
+
+use fdemon_daemon::vm_service::VM_SERVICE_METHODS;
+use serde_json::{Map, Value};
+
+use crate::session::SessionId;
+
+/// Number of lines scrolled by PageUp / PageDown in the console output.
+pub const CONSOLE_PAGE_STEP: usize = 10;
+
+/// Output lines kept; the oldest are dropped first.
+pub const MAX_CONSOLE_LINES: usize = 2_000;
+
+/// Commands kept in the input history.
+pub const MAX_CONSOLE_HISTORY: usize = 100;
+
+/// Console commands that are not VM Service methods.
+const CONSOLE_COMMANDS: &[&str] = &["clear", "tail", "untail"];
+
+/// A parsed console input line.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConsoleCommand {
+    /// Call a VM Service method or service extension.
+    Call {
+        method: String,
+        /// JSON object given after the method name.
+        params: Option<Map<String, Value>>,
+    },
+    /// Subscribe to a stream and echo its events.
+    Tail(String),
+    /// Stop echoing a stream's events.
+    Untail(String),
+    /// Empty the output.
+    Clear,
+}
+
+/// Parse a console input line.
+///
+/// # Errors
+///
+/// Returns a message for an empty line, a `tail`/`untail` without a stream
+/// ID, or params that are not a JSON object.
+pub fn parse_console_command(line: &str) -> Result<ConsoleCommand, String> {
+    let line = line.trim();
+    let (word, rest) = match line.split_once(char::is_whitespace) {
+        Some((word, rest)) => (word, rest.trim()),
+        None => (line, ""),
+    };
+    match word {
+        "" => Err("Type a method name, e.g. getVM".to_string()),
+        "clear" if rest.is_empty() => Ok(ConsoleCommand::Clear),
+        "tail" | "untail" => {
+            if rest.is_empty() || rest.contains(char::is_whitespace) {
+                return Err(format!("Usage: {word} <streamId>"));
+            }
+            Ok(if word == "tail" {
+                ConsoleCommand::Tail(rest.to_string())
+            } else {
+                ConsoleCommand::Untail(rest.to_string())
+            })
+        }
+        method => {
+            let params = if rest.is_empty() {
+                None
+            } else {
+                match serde_json::from_str(rest) {
+                    Ok(Value::Object(map)) => Some(map),
+                    Ok(_) => return Err("Params must be a JSON object".to_string()),
+                    Err(e) => return Err(format!("Invalid params: {e}")),
+                }
+            };
+            Ok(ConsoleCommand::Call {
+                method: method.to_string(),
+                params,
+            })
+        }
+    }
+}
+
+/// How an output line is styled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConsoleLineKind {
+    /// An entered command.
+    Command,
+    /// Part of an RPC response.
+    Response,
+    /// A failed command or RPC.
+    Error,
+    /// A tailed stream event.
+    Event,
+    /// Status and completion listings.
+    Info,
+}
+
+/// One line of console output.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConsoleLine {
+    pub kind: ConsoleLineKind,
+    pub text: String,
+}
+
+/// State for the DevTools VM Service console panel.
+#[derive(Debug, Clone, Default)]
+pub struct ConsoleViewState {
+    /// The command being typed.
+    pub input: String,
+
+    /// Whether keys go to `input`.
+    pub input_active: bool,
+
+    /// Output scrollback, oldest first.
+    pub lines: VecDeque<ConsoleLine>,
+
+    /// Lines scrolled up from the bottom of the output (0 follows new output).
+    pub scroll: usize,
+
+    /// Entered commands, oldest first.
+    pub history: Vec<String>,
+
+    /// Index into `history` while stepping through it with Up/Down.
+    pub history_index: Option<usize>,
+
+    /// Service extensions registered by the app's isolates, for completion.
+    pub extensions: Vec<String>,
+
+    /// Session the extension list was requested for, if any.
+    pub extensions_requested: Option<SessionId>,
+
+    /// Streams whose events are echoed. Mirrors the tails on the selected
+    /// session's VM Service handle.
+    pub tailed: BTreeSet<String>,
+
+    /// Method of the RPC in flight, if any.
+    pub pending: Option<String>,
+}
+
+impl ConsoleViewState {
+    /// Reset to the initial empty state, keeping the command history.
+    pub fn reset(&mut self) {
+        *self = Self {
+            history: std::mem::take(&mut self.history),
+            ..Self::default()
+        };
+    }
+
+    /// Append a line, dropping the oldest beyond [`MAX_CONSOLE_LINES`].
+    /// A scrolled-up view stays on the lines it shows.
+    pub fn push(&mut self, kind: ConsoleLineKind, text: impl Into<String>) {
+        self.lines.push_back(ConsoleLine {
+            kind,
+            text: text.into(),
+        });
+        if self.lines.len() > MAX_CONSOLE_LINES {
+            self.lines.pop_front();
+        } else if self.scroll > 0 {
+            self.scroll += 1;
+        }
+    }
+
+    /// Append `value` as pretty-printed JSON, one line per output line.
+    pub fn push_json(&mut self, value: &Value) {
+        let pretty = serde_json::to_string_pretty(value).unwrap_or_else(|_| value.to_string());
+        for line in pretty.lines() {
+            self.push(ConsoleLineKind::Response, line);
+        }
+    }
+
+    /// Append a tailed stream event as one line of compact JSON.
+    pub fn push_event(&mut self, stream_id: &str, event: &Value) {
+        self.push(ConsoleLineKind::Event, format!("[{stream_id}] {event}"));
+    }
+
+    /// Scroll the output by `delta` lines (positive scrolls up, towards
+    /// older lines), clamped to the scrollback.
+    pub fn scroll_by(&mut self, delta: isize) {
+        self.scroll = self
+            .scroll
+            .saturating_add_signed(delta)
+            .min(self.lines.len().saturating_sub(1));
+    }
+
+    /// Take the input line for submission: record it in the history and
+    /// clear the input.
+    pub fn take_input(&mut self) -> String {
+        let line = std::mem::take(&mut self.input).trim().to_string();
+        self.history_index = None;
+        if !line.is_empty() && self.history.last() != Some(&line) {
+            self.history.push(line.clone());
+            if self.history.len() > MAX_CONSOLE_HISTORY {
+                self.history.remove(0);
+            }
+        }
+        line
+    }
+
+    /// Replace the input with the previous (`older`) or next history entry.
+    /// Stepping past the newest entry clears the input.
+    pub fn step_history(&mut self, older: bool) {
+        if self.history.is_empty() {
+            return;
+        }
+        let index = match (self.history_index, older) {
+            (None, true) => Some(self.history.len() - 1),
+            (None, false) => None,
+            (Some(i), true) => Some(i.saturating_sub(1)),
+            (Some(i), false) if i + 1 < self.history.len() => Some(i + 1),
+            (Some(_), false) => None,
+        };
+        self.history_index = index;
+        self.input = index.map(|i| self.history[i].clone()).unwrap_or_default();
+    }
+
+    /// Complete the method name being typed. A single match is completed
+    /// with a trailing space, several are completed to their common prefix
+    /// or listed in the output.
+    pub fn complete(&mut self) {
+        if self.input.contains(char::is_whitespace) {
+            return;
+        }
+        let matches: Vec<String> = CONSOLE_COMMANDS
+            .iter()
+            .chain(VM_SERVICE_METHODS)
+            .copied()
+            .chain(self.extensions.iter().map(String::as_str))
+            .filter(|candidate| candidate.starts_with(self.input.as_str()))
+            .map(String::from)
+            .collect();
+
+        match matches.as_slice() {
+            [] => {}
+            [only] => self.input = format!("{only} "),
+            [first, rest @ ..] => {
+                let common = rest.iter().fold(first.as_str(), |common, m| {
+                    let len = common
+                        .char_indices()
+                        .zip(m.chars())
+                        .take_while(|((_, a), b)| a == b)
+                        .last()
+                        .map_or(0, |((i, a), _)| i + a.len_utf8());
+                    &common[..len]
+                });
+                if common.len() > self.input.len() {
+                    self.input = common.to_string();
+                } else {
+                    self.push(ConsoleLineKind::Info, matches.join("  "));
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_parse_console_command() {
+        assert_eq!(
+            parse_console_command("  getVM "),
+            Ok(ConsoleCommand::Call {
+                method: "getVM".to_string(),
+                params: None,
+            })
+        );
+        assert_eq!(
+            parse_console_command(r#"getObject {"objectId": "classes/12"}"#),
+            Ok(ConsoleCommand::Call {
+                method: "getObject".to_string(),
+                params: json!({ "objectId": "classes/12" }).as_object().cloned(),
+            })
+        );
+        assert_eq!(
+            parse_console_command("tail Stdout"),
+            Ok(ConsoleCommand::Tail("Stdout".to_string()))
+        );
+        assert_eq!(
+            parse_console_command("untail Stdout"),
+            Ok(ConsoleCommand::Untail("Stdout".to_string()))
+        );
+        assert_eq!(parse_console_command("clear"), Ok(ConsoleCommand::Clear));
+        assert!(parse_console_command("").is_err());
+        assert!(parse_console_command("tail").is_err());
+        assert!(parse_console_command("getObject [1]").is_err());
+        assert!(parse_console_command("getObject {").is_err());
+    }
+
+    #[test]
+    fn test_complete_method_names() {
+        let mut state = ConsoleViewState {
+            extensions: vec!["ext.flutter.debugDumpApp".to_string()],
+            ..Default::default()
+        };
+
+        state.input = "getIsolateG".to_string();
+        state.complete();
+        assert_eq!(state.input, "getIsolateGroup");
+
+        // Ambiguous with no longer common prefix: the matches are listed.
+        state.complete();
+        assert_eq!(state.input, "getIsolateGroup");
+        assert_eq!(
+            state.lines.back().unwrap().text,
+            "getIsolateGroup  getIsolateGroupMemoryUsage"
+        );
+
+        state.input = "ext.flutter.debugD".to_string();
+        state.complete();
+        assert_eq!(state.input, "ext.flutter.debugDumpApp ");
+
+        // No completion once params are being typed.
+        state.complete();
+        assert_eq!(state.input, "ext.flutter.debugDumpApp ");
+    }
+
+    #[test]
+    fn test_history_and_scroll() {
+        let mut state = ConsoleViewState::default();
+        for command in ["getVM", "getVersion", "getVersion"] {
+            state.input = command.to_string();
+            state.take_input();
+        }
+        assert_eq!(state.history, ["getVM", "getVersion"]);
+
+        state.step_history(true);
+        assert_eq!(state.input, "getVersion");
+        state.step_history(true);
+        state.step_history(true);
+        assert_eq!(state.input, "getVM");
+        state.step_history(false);
+        state.step_history(false);
+        assert_eq!(state.input, "");
+
+        state.push_json(&json!({ "type": "Version", "major": 4 }));
+        assert_eq!(state.lines.len(), 4);
+        state.scroll_by(10);
+        assert_eq!(state.scroll, 3);
+        state.push(ConsoleLineKind::Info, "new");
+        assert_eq!(state.scroll, 4);
+        state.scroll_by(-10);
+        assert_eq!(state.scroll, 0);
+    }
+}
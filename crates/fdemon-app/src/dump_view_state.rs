@@ -0,0 +1,447 @@
+//! Dump viewer state - parsed text dumps with collapsible indentation and search.
+//!
+//! Flutter's `debugDump*` service extensions return the widget, render, layer,
+//! semantics and focus trees as indented text. This module splits that text
+//! into lines, derives a nesting depth from each line's indentation, and
+//! tracks which lines are collapsed, which one is selected, and the search
+//! query. It is shared by the app handler layer and the TUI dump view.
+
+use std::collections::HashSet;
+
+use crate::state::DevToolsError;
+
+pub use fdemon_daemon::vm_service::DebugDumpKind;
+
+/// Number of lines moved by PageUp / PageDown in the dump view.
+pub const DUMP_PAGE_STEP: usize = 10;
+
+// ─────────────────────────────────────────────────────────────────────────────
+// DumpLine
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// A single line of a text dump.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DumpLine {
+    /// The line as returned by the VM Service (without the trailing newline).
+    pub text: String,
+    /// Nesting depth: the number of leading indentation characters
+    /// (whitespace and box-drawing tree connectors).
+    pub depth: usize,
+}
+
+/// Whether `c` is part of a dump's indentation prefix.
+fn is_indent_char(c: char) -> bool {
+    c.is_whitespace() || ('\u{2500}'..='\u{257F}').contains(&c)
+}
+
+/// Split a dump into [`DumpLine`]s.
+///
+/// Lines made only of indentation (blank lines and bare `│` connectors) take
+/// the depth of the next content line, so they fold together with the
+/// subtree they lead into.
+pub fn parse_dump(text: &str) -> Vec<DumpLine> {
+    let mut lines: Vec<DumpLine> = Vec::new();
+    let mut connectors = Vec::new();
+    for raw in text.lines() {
+        let prefix = raw.chars().take_while(|&c| is_indent_char(c)).count();
+        if prefix == raw.chars().count() {
+            connectors.push(lines.len());
+        }
+        lines.push(DumpLine {
+            text: raw.trim_end().to_string(),
+            depth: prefix,
+        });
+    }
+    let mut next_depth = 0;
+    for i in (0..lines.len()).rev() {
+        if connectors.binary_search(&i).is_ok() {
+            lines[i].depth = next_depth;
+        } else {
+            next_depth = lines[i].depth;
+        }
+    }
+    lines
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// DumpViewState
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// State for the DevTools dump viewer panel.
+#[derive(Debug, Clone, Default)]
+pub struct DumpViewState {
+    /// Dump kind shown in the kind tabs (the next one to fetch).
+    pub kind: DebugDumpKind,
+
+    /// Kind of the dump currently held in `lines`, if any.
+    pub loaded_kind: Option<DebugDumpKind>,
+
+    /// Raw dump text, kept for saving to disk.
+    pub raw: String,
+
+    /// Parsed lines of the loaded dump.
+    pub lines: Vec<DumpLine>,
+
+    /// Indices into `lines` of collapsed lines.
+    pub collapsed: HashSet<usize>,
+
+    /// Index into `lines` of the selected line.
+    pub selected: usize,
+
+    /// Whether a dump fetch is in flight.
+    pub loading: bool,
+
+    /// Last fetch error, if any.
+    pub error: Option<DevToolsError>,
+
+    /// Whether the search input bar is active (keys go to `search_query`).
+    pub search_input_active: bool,
+
+    /// Case-insensitive substring searched for in dump lines. Changed only
+    /// through [`Self::set_search_query`] and friends so `search_matches`
+    /// stays in sync.
+    search_query: String,
+
+    /// Sorted indices into `lines` of the lines matching `search_query`,
+    /// recomputed when the query or the dump changes rather than per render.
+    search_matches: Vec<usize>,
+
+    /// One-line status message (e.g. where the dump was saved).
+    pub status: Option<String>,
+}
+
+impl DumpViewState {
+    /// Reset to the initial empty state, keeping the selected dump kind.
+    pub fn reset(&mut self) {
+        *self = Self {
+            kind: self.kind,
+            ..Self::default()
+        };
+    }
+
+    /// Mark a fetch of `kind` as in flight.
+    pub fn start_loading(&mut self, kind: DebugDumpKind) {
+        self.kind = kind;
+        self.loading = true;
+        self.error = None;
+        self.status = None;
+    }
+
+    /// Replace the loaded dump with `text`.
+    pub fn set_dump(&mut self, kind: DebugDumpKind, text: String) {
+        self.lines = parse_dump(&text);
+        self.raw = text;
+        self.loaded_kind = Some(kind);
+        self.collapsed.clear();
+        self.selected = 0;
+        self.loading = false;
+        self.error = None;
+        self.update_search_matches();
+    }
+
+    /// Record a failed fetch. The previously loaded dump is kept.
+    pub fn set_error(&mut self, error: DevToolsError) {
+        self.loading = false;
+        self.error = Some(error);
+    }
+
+    /// Whether the line at `index` has deeper lines directly below it.
+    pub fn has_children(&self, index: usize) -> bool {
+        match (self.lines.get(index), self.lines.get(index + 1)) {
+            (Some(line), Some(next)) => next.depth > line.depth,
+            _ => false,
+        }
+    }
+
+    /// Index one past the last descendant of the line at `index`.
+    fn subtree_end(&self, index: usize) -> usize {
+        let depth = self.lines[index].depth;
+        self.lines[index + 1..]
+            .iter()
+            .position(|line| line.depth <= depth)
+            .map_or(self.lines.len(), |offset| index + 1 + offset)
+    }
+
+    /// Indices into `lines` of the lines not hidden by a collapsed ancestor.
+    pub fn visible_lines(&self) -> Vec<usize> {
+        let mut visible = Vec::with_capacity(self.lines.len());
+        let mut i = 0;
+        while i < self.lines.len() {
+            visible.push(i);
+            i = if self.collapsed.contains(&i) && self.has_children(i) {
+                self.subtree_end(i)
+            } else {
+                i + 1
+            };
+        }
+        visible
+    }
+
+    /// Move the selection by `delta` visible lines, clamped to the ends.
+    pub fn move_selection(&mut self, delta: isize) {
+        let visible = self.visible_lines();
+        if visible.is_empty() {
+            return;
+        }
+        let current = visible
+            .iter()
+            .position(|&i| i == self.selected)
+            .unwrap_or(0);
+        let target = current.saturating_add_signed(delta).min(visible.len() - 1);
+        self.selected = visible[target];
+    }
+
+    /// Expand the selected line if it is collapsed.
+    pub fn expand_selected(&mut self) {
+        self.collapsed.remove(&self.selected);
+    }
+
+    /// Collapse the selected line, or select its parent if it has no
+    /// children or is already collapsed.
+    pub fn collapse_selected(&mut self) {
+        if self.has_children(self.selected) && !self.collapsed.contains(&self.selected) {
+            self.collapsed.insert(self.selected);
+        } else if let Some(parent) = self.ancestors(self.selected).first() {
+            self.selected = *parent;
+        }
+    }
+
+    /// Ancestors of the line at `index`, nearest first.
+    fn ancestors(&self, index: usize) -> Vec<usize> {
+        let Some(line) = self.lines.get(index) else {
+            return Vec::new();
+        };
+        let mut depth = line.depth;
+        let mut out = Vec::new();
+        for i in (0..index).rev() {
+            if self.lines[i].depth < depth {
+                out.push(i);
+                depth = self.lines[i].depth;
+            }
+        }
+        out
+    }
+
+    /// The current search query.
+    pub fn search_query(&self) -> &str {
+        &self.search_query
+    }
+
+    /// Replace the search query.
+    pub fn set_search_query(&mut self, query: impl Into<String>) {
+        self.search_query = query.into();
+        self.update_search_matches();
+    }
+
+    /// Append `c` to the search query.
+    pub fn push_search_char(&mut self, c: char) {
+        self.search_query.push(c);
+        self.update_search_matches();
+    }
+
+    /// Remove the last character of the search query. Returns `false` if the
+    /// query was already empty.
+    pub fn pop_search_char(&mut self) -> bool {
+        if self.search_query.pop().is_none() {
+            return false;
+        }
+        self.update_search_matches();
+        true
+    }
+
+    /// Recompute `search_matches` for the current query and lines.
+    fn update_search_matches(&mut self) {
+        self.search_matches.clear();
+        if self.search_query.is_empty() {
+            return;
+        }
+        let query = self.search_query.to_lowercase();
+        self.search_matches.extend(
+            self.lines
+                .iter()
+                .enumerate()
+                .filter(|(_, line)| line.text.to_lowercase().contains(&query))
+                .map(|(i, _)| i),
+        );
+    }
+
+    /// Whether the line at `index` matches the current search query.
+    pub fn is_search_match(&self, index: usize) -> bool {
+        self.search_matches.binary_search(&index).is_ok()
+    }
+
+    /// Number of matching lines and the 1-based position of the selected
+    /// line among them (if it is a match).
+    pub fn search_match_position(&self) -> (usize, Option<usize>) {
+        let position = self
+            .search_matches
+            .binary_search(&self.selected)
+            .ok()
+            .map(|p| p + 1);
+        (self.search_matches.len(), position)
+    }
+
+    /// Select the next (or previous) matching line, wrapping around, and
+    /// expand its collapsed ancestors so it is visible.
+    ///
+    /// With `include_current` the selected line itself counts as a candidate.
+    /// Returns `true` if a match was selected.
+    pub fn select_search_match(&mut self, forward: bool, include_current: bool) -> bool {
+        let count = self.lines.len();
+        if count == 0 || self.search_query.is_empty() {
+            return false;
+        }
+        let start = if include_current { 0 } else { 1 };
+        let target = (start..start + count)
+            .map(|offset| {
+                if forward {
+                    (self.selected + offset) % count
+                } else {
+                    (self.selected + count - offset % count) % count
+                }
+            })
+            .find(|&i| self.is_search_match(i));
+        match target {
+            Some(index) => {
+                for ancestor in self.ancestors(index) {
+                    self.collapsed.remove(&ancestor);
+                }
+                self.selected = index;
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RENDER_DUMP: &str = "\
+RenderView#1
+ │ debug mode enabled
+ │
+ └─child: RenderFlex#2
+   │ direction: vertical
+   │
+   ├─child 1: RenderParagraph#3
+   │   text: \"Hello\"
+   │
+   └─child 2: RenderParagraph#4
+       text: \"World\"
+";
+
+    fn loaded() -> DumpViewState {
+        let mut state = DumpViewState::default();
+        state.set_dump(DebugDumpKind::RenderTree, RENDER_DUMP.to_string());
+        state
+    }
+
+    #[test]
+    fn test_parse_dump_depths() {
+        let lines = parse_dump(RENDER_DUMP);
+        assert_eq!(lines.len(), 11);
+        assert_eq!(lines[0].depth, 0);
+        assert_eq!(lines[1].depth, 3);
+        assert_eq!(lines[3].text, " └─child: RenderFlex#2");
+        assert_eq!(lines[3].depth, 3);
+        // Bare connector takes the depth of the next content line.
+        assert_eq!(lines[5].depth, 5);
+        assert_eq!(lines[6].depth, 5);
+        assert_eq!(lines[7].depth, 7);
+    }
+
+    #[test]
+    fn test_collapse_hides_subtree() {
+        let mut state = loaded();
+        state.selected = 3;
+        assert!(state.has_children(3));
+        state.collapse_selected();
+        assert_eq!(state.visible_lines(), vec![0, 1, 2, 3]);
+
+        state.expand_selected();
+        assert_eq!(state.visible_lines().len(), 11);
+    }
+
+    #[test]
+    fn test_collapse_leaf_selects_parent() {
+        let mut state = loaded();
+        state.selected = 7;
+        state.collapse_selected();
+        assert_eq!(state.selected, 6);
+    }
+
+    #[test]
+    fn test_move_selection_skips_collapsed_lines() {
+        let mut state = loaded();
+        state.collapsed.insert(3);
+        state.selected = 2;
+        state.move_selection(1);
+        assert_eq!(state.selected, 3);
+        state.move_selection(1);
+        assert_eq!(state.selected, 3, "clamped at the last visible line");
+        state.move_selection(-10);
+        assert_eq!(state.selected, 0);
+    }
+
+    #[test]
+    fn test_search_reveals_collapsed_match() {
+        let mut state = loaded();
+        state.collapsed.insert(0);
+        state.collapsed.insert(3);
+        state.set_search_query("world");
+        assert!(state.select_search_match(true, true));
+        assert_eq!(state.selected, 10);
+        assert!(state.visible_lines().contains(&10));
+        assert_eq!(state.search_match_position(), (1, Some(1)));
+    }
+
+    #[test]
+    fn test_search_wraps_backwards() {
+        let mut state = loaded();
+        state.set_search_query("RenderParagraph");
+        assert!(state.select_search_match(false, false));
+        assert_eq!(state.selected, 9);
+        assert!(state.select_search_match(false, false));
+        assert_eq!(state.selected, 6);
+    }
+
+    #[test]
+    fn test_search_matches_follow_query_and_dump() {
+        let mut state = loaded();
+        state.set_search_query("renderparagraph");
+        assert_eq!(state.search_match_position(), (2, None));
+        assert!(state.is_search_match(6));
+        assert!(state.is_search_match(9));
+
+        state.push_search_char('#');
+        state.push_search_char('4');
+        assert_eq!(state.search_match_position(), (1, None));
+        assert!(!state.is_search_match(6));
+
+        assert!(state.pop_search_char());
+        assert_eq!(state.search_match_position(), (2, None));
+
+        // A new dump keeps the query and recomputes its matches
+        state.set_dump(
+            DebugDumpKind::RenderTree,
+            "RenderView#1\n └─child: RenderParagraph#2".to_string(),
+        );
+        assert_eq!(state.search_match_position(), (1, None));
+        assert!(state.is_search_match(1));
+
+        state.set_search_query("");
+        assert_eq!(state.search_match_position(), (0, None));
+    }
+
+    #[test]
+    fn test_reset_keeps_kind() {
+        let mut state = loaded();
+        state.kind = DebugDumpKind::FocusTree;
+        state.reset();
+        assert_eq!(state.kind, DebugDumpKind::FocusTree);
+        assert!(state.lines.is_empty());
+        assert!(state.loaded_kind.is_none());
+    }
+}
@@ -145,6 +145,7 @@ pub fn open_in_editor(
 ///
 /// - `package:app/src/main.dart` - Resolved to `lib/src/main.dart` under project root
 /// - `dart:core/list.dart` - SDK files, cannot be opened (returns error)
+/// - `file:///absolute/path/file.dart` - File URI, resolved as an absolute path
 /// - `/absolute/path/file.dart` - Returned as-is
 /// - `lib/main.dart` - Resolved relative to project root
 pub fn resolve_file_path(path: &str, project_root: &Path) -> Result<PathBuf, EditorError> {
@@ -182,6 +183,9 @@ pub fn resolve_file_path(path: &str, project_root: &Path) -> Result<PathBuf, Edi
         )));
     }
 
+    // Handle file: URIs (e.g. widget creation locations from the VM Service)
+    let path = path.strip_prefix("file://").unwrap_or(path);
+
     // Handle relative paths
     let path_buf = PathBuf::from(path);
     if path_buf.is_absolute() {
@@ -380,6 +384,14 @@ mod tests {
         assert_eq!(result, PathBuf::from("/absolute/path/file.dart"));
     }
 
+    #[test]
+    fn test_resolve_file_path_file_uri() {
+        let result =
+            resolve_file_path("file:///absolute/path/file.dart", Path::new("/project")).unwrap();
+
+        assert_eq!(result, PathBuf::from("/absolute/path/file.dart"));
+    }
+
     #[test]
     fn test_resolve_file_path_relative() {
         let result = resolve_file_path("lib/main.dart", Path::new("/project")).unwrap();
@@ -461,6 +461,7 @@ impl Engine {
                 device,
                 config,
                 flutter,
+                proxy: self.state.settings.devtools.proxy_if_enabled(),
             },
             self.msg_tx.clone(),
             None,
@@ -508,6 +509,15 @@ impl Engine {
         tracing::info!("DAP IDE config overridden by --dap-config: {:?}", ide);
     }
 
+    /// Apply a CLI-provided VM Service URI to attach to (`--attach <uri>`).
+    ///
+    /// Stores the URI on `AppState`; startup then auto-launches an attach
+    /// configuration for it (see [`crate::config::add_cli_attach_config`]).
+    pub fn apply_cli_attach(&mut self, debug_url: String) {
+        tracing::info!("Attaching to VM Service from --attach: {}", debug_url);
+        self.state.cli_attach_url = Some(debug_url);
+    }
+
     /// Check if the application should quit.
     pub fn should_quit(&self) -> bool {
         self.state.should_quit()
@@ -552,6 +562,16 @@ impl Engine {
         // Notify plugins first
         self.notify_plugins_shutdown();
 
+        // Snapshot the workspace while the sessions still exist. Quitting
+        // with no sessions keeps the previous snapshot.
+        if self.state.settings.behavior.restore_workspace && !self.state.session_manager.is_empty()
+        {
+            let workspace = crate::handler::workspace::snapshot_workspace(&self.state);
+            if let Err(e) = crate::config::save_workspace(&self.project_path, &workspace) {
+                warn!("Failed to save workspace: {}", e);
+            }
+        }
+
         // Emit shutdown event
         self.emit(EngineEvent::Shutdown);
 
@@ -0,0 +1,205 @@
+//! Extensions panel state - buttons for app-registered service extensions.
+//!
+//! Apps register their own service extensions with `registerExtension`
+//! (feature flags, fake clocks, cache resets). The panel lists the buttons
+//! configured under `[[devtools.extensions]]` followed by the app-registered
+//! `ext.*` methods of the DevTools isolate that have no button, and calls the
+//! selected one. This module tracks the rows and selection; it is shared by
+//! the app handler layer and the TUI extensions view.
+
+use crate::config::ExtensionButton;
+use crate::state::DevToolsError;
+
+/// One row of the Extensions panel.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ExtensionRow<'a> {
+    /// A configured button. `registered` is `None` until the isolate's
+    /// extensions are known.
+    Button {
+        index: usize,
+        button: &'a ExtensionButton,
+        registered: Option<bool>,
+    },
+    /// An app-registered extension without a button, called without args.
+    Registered(&'a str),
+}
+
+impl ExtensionRow<'_> {
+    /// The extension method the row calls.
+    pub fn method(&self) -> &str {
+        match self {
+            ExtensionRow::Button { button, .. } => &button.method,
+            ExtensionRow::Registered(method) => method,
+        }
+    }
+}
+
+/// State for the DevTools Extensions panel.
+#[derive(Debug, Clone, Default)]
+pub struct ExtensionsViewState {
+    /// Buttons from `[[devtools.extensions]]`, copied from the settings when
+    /// the panel is shown.
+    pub buttons: Vec<ExtensionButton>,
+
+    /// App-registered extensions of the DevTools isolate, sorted. `None`
+    /// until fetched.
+    pub registered: Option<Vec<String>>,
+
+    /// Whether the extension list is being fetched.
+    pub loading: bool,
+
+    /// Why the extension list could not be fetched.
+    pub error: Option<DevToolsError>,
+
+    /// Index of the selected row in [`Self::rows`].
+    pub selected: usize,
+
+    /// Outcome of the last call, shown under the rows.
+    pub status: Option<String>,
+}
+
+impl ExtensionsViewState {
+    /// Reset session-specific data, keeping the configured buttons.
+    pub fn reset(&mut self) {
+        *self = Self {
+            buttons: std::mem::take(&mut self.buttons),
+            ..Self::default()
+        };
+    }
+
+    /// Store the fetched extension list.
+    pub fn set_registered(&mut self, registered: Vec<String>) {
+        self.registered = Some(registered);
+        self.loading = false;
+        self.error = None;
+        self.clamp_selection();
+    }
+
+    /// Show a fetch failure.
+    pub fn set_error(&mut self, error: DevToolsError) {
+        self.error = Some(error);
+        self.loading = false;
+    }
+
+    /// Configured buttons followed by the registered extensions that have no
+    /// button.
+    pub fn rows(&self) -> Vec<ExtensionRow<'_>> {
+        let registered = self.registered.as_deref();
+        let mut rows: Vec<ExtensionRow<'_>> = self
+            .buttons
+            .iter()
+            .enumerate()
+            .map(|(index, button)| ExtensionRow::Button {
+                index,
+                button,
+                registered: registered.map(|r| r.contains(&button.method)),
+            })
+            .collect();
+        rows.extend(
+            registered
+                .unwrap_or_default()
+                .iter()
+                .filter(|method| !self.buttons.iter().any(|b| &b.method == *method))
+                .map(|method| ExtensionRow::Registered(method)),
+        );
+        rows
+    }
+
+    /// The selected row, if any.
+    pub fn selected_row(&self) -> Option<ExtensionRow<'_>> {
+        self.rows().get(self.selected).copied()
+    }
+
+    /// Index of the button bound to `key`.
+    pub fn button_for_key(&self, key: char) -> Option<usize> {
+        self.buttons.iter().position(|b| b.key == Some(key))
+    }
+
+    /// Move the selection by `delta` rows, clamped to the list.
+    pub fn move_selection(&mut self, delta: isize) {
+        self.selected = self.selected.saturating_add_signed(delta);
+        self.clamp_selection();
+    }
+
+    fn clamp_selection(&mut self) {
+        self.selected = self.selected.min(self.rows().len().saturating_sub(1));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn button(label: &str, method: &str, key: Option<char>) -> ExtensionButton {
+        ExtensionButton {
+            label: label.to_string(),
+            method: method.to_string(),
+            key,
+            args: Default::default(),
+        }
+    }
+
+    #[test]
+    fn test_rows_list_buttons_then_unbound_extensions() {
+        let mut state = ExtensionsViewState {
+            buttons: vec![
+                button("Debug menu", "ext.myapp.debugMenu", Some('m')),
+                button("Old flag", "ext.myapp.removed", None),
+            ],
+            ..Default::default()
+        };
+        assert!(matches!(
+            state.rows()[0],
+            ExtensionRow::Button {
+                registered: None,
+                ..
+            }
+        ));
+
+        state.set_registered(vec![
+            "ext.myapp.debugMenu".to_string(),
+            "ext.myapp.resetCache".to_string(),
+        ]);
+        let rows = state.rows();
+        assert_eq!(rows.len(), 3);
+        assert!(matches!(
+            rows[0],
+            ExtensionRow::Button {
+                index: 0,
+                registered: Some(true),
+                ..
+            }
+        ));
+        assert!(matches!(
+            rows[1],
+            ExtensionRow::Button {
+                registered: Some(false),
+                ..
+            }
+        ));
+        assert_eq!(rows[2], ExtensionRow::Registered("ext.myapp.resetCache"));
+        assert_eq!(state.button_for_key('m'), Some(0));
+        assert_eq!(state.button_for_key('z'), None);
+    }
+
+    #[test]
+    fn test_selection_is_clamped_and_reset_keeps_buttons() {
+        let mut state = ExtensionsViewState {
+            buttons: vec![button("Debug menu", "ext.myapp.debugMenu", None)],
+            ..Default::default()
+        };
+        state.set_registered(vec!["ext.myapp.resetCache".to_string()]);
+        state.move_selection(5);
+        assert_eq!(state.selected, 1);
+        assert_eq!(
+            state.selected_row().unwrap().method(),
+            "ext.myapp.resetCache"
+        );
+        state.move_selection(-5);
+        assert_eq!(state.selected, 0);
+
+        state.reset();
+        assert_eq!(state.buttons.len(), 1);
+        assert!(state.registered.is_none());
+    }
+}
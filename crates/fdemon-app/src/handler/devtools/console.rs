@@ -0,0 +1,508 @@
+//! VM Service console handlers.
+//!
+//! Handles the console command line (typing, completion, history), running
+//! submitted commands as raw RPCs or stream tails, and showing their results
+//! and tailed events in the console output.
+
+use crate::console_view_state::{
+    parse_console_command, ConsoleCommand, ConsoleLineKind, ConsoleViewState, CONSOLE_PAGE_STEP,
+};
+use crate::handler::{UpdateAction, UpdateResult};
+use crate::message::ConsoleNav;
+use crate::session::SessionId;
+use crate::state::{AppState, DevToolsPanel};
+
+/// Request the selected session's service extensions for completion,
+/// unless they were already requested for it.
+///
+/// Used when the Console panel becomes visible.
+pub(crate) fn fetch_extensions_if_needed(state: &mut AppState) -> UpdateResult {
+    let console = &mut state.devtools_view_state.console;
+    match state.session_manager.selected() {
+        Some(handle) if console.extensions_requested == Some(handle.session.id) => {
+            UpdateResult::none()
+        }
+        Some(handle) if handle.session.vm_connected => {
+            console.extensions_requested = Some(handle.session.id);
+            UpdateResult::action(UpdateAction::FetchConsoleExtensions {
+                session_id: handle.session.id,
+                vm_handle: None, // hydrated by process.rs
+            })
+        }
+        _ => UpdateResult::none(),
+    }
+}
+
+/// Show the selected session's stream tails in the console.
+///
+/// Tails live on the session's VM Service handle, so they survive a session
+/// switch; called after the console is reset for the new session.
+pub(crate) fn sync_tailed_streams(state: &mut AppState) {
+    state.devtools_view_state.console.tailed = state
+        .session_manager
+        .selected()
+        .and_then(|handle| handle.vm_request_handle.as_ref())
+        .map(|vm| vm.tailed_streams())
+        .unwrap_or_default();
+}
+
+/// Show the Console panel and focus its command line.
+pub(crate) fn handle_focus_input(state: &mut AppState) -> UpdateResult {
+    let result = if state.devtools_view_state.active_panel == DevToolsPanel::Console {
+        UpdateResult::none()
+    } else {
+        super::handle_switch_panel(state, DevToolsPanel::Console)
+    };
+    state.devtools_view_state.console.input_active = true;
+    result
+}
+
+/// Unfocus the command line, keeping the typed text.
+pub(crate) fn handle_blur_input(state: &mut AppState) -> UpdateResult {
+    state.devtools_view_state.console.input_active = false;
+    UpdateResult::none()
+}
+
+/// Append a character to the command line.
+pub(crate) fn handle_input(state: &mut AppState, c: char) -> UpdateResult {
+    let console = &mut state.devtools_view_state.console;
+    console.input.push(c);
+    console.history_index = None;
+    UpdateResult::none()
+}
+
+/// Delete the last character of the command line.
+pub(crate) fn handle_backspace(state: &mut AppState) -> UpdateResult {
+    let console = &mut state.devtools_view_state.console;
+    console.input.pop();
+    console.history_index = None;
+    UpdateResult::none()
+}
+
+/// Complete the method name on the command line.
+pub(crate) fn handle_complete(state: &mut AppState) -> UpdateResult {
+    state.devtools_view_state.console.complete();
+    UpdateResult::none()
+}
+
+/// Recall an earlier or later command from the history.
+pub(crate) fn handle_history(state: &mut AppState, older: bool) -> UpdateResult {
+    state.devtools_view_state.console.step_history(older);
+    UpdateResult::none()
+}
+
+/// Scroll the console output.
+pub(crate) fn handle_navigate(state: &mut AppState, nav: ConsoleNav) -> UpdateResult {
+    let console = &mut state.devtools_view_state.console;
+    match nav {
+        ConsoleNav::Up => console.scroll_by(1),
+        ConsoleNav::Down => console.scroll_by(-1),
+        ConsoleNav::PageUp => console.scroll_by(CONSOLE_PAGE_STEP as isize),
+        ConsoleNav::PageDown => console.scroll_by(-(CONSOLE_PAGE_STEP as isize)),
+    }
+    UpdateResult::none()
+}
+
+/// Run the command line: echo it, then send its RPC or stream tail for the
+/// selected session.
+pub(crate) fn handle_submit(state: &mut AppState) -> UpdateResult {
+    let session = state
+        .session_manager
+        .selected()
+        .map(|h| (h.session.id, h.session.vm_connected));
+
+    let console = &mut state.devtools_view_state.console;
+    let line = console.take_input();
+    if line.is_empty() {
+        return UpdateResult::none();
+    }
+    console.scroll = 0;
+    console.push(ConsoleLineKind::Command, format!("> {line}"));
+
+    let command = match parse_console_command(&line) {
+        Ok(command) => command,
+        Err(e) => {
+            console.push(ConsoleLineKind::Error, e);
+            return UpdateResult::none();
+        }
+    };
+
+    match command {
+        ConsoleCommand::Clear => {
+            console.lines.clear();
+            UpdateResult::none()
+        }
+        ConsoleCommand::Call { method, params } => {
+            let Some(session_id) = connected_session(console, session) else {
+                return UpdateResult::none();
+            };
+            console.pending = Some(method.clone());
+            UpdateResult::action(UpdateAction::CallConsoleRpc {
+                session_id,
+                method,
+                params,
+                vm_handle: None, // hydrated by process.rs
+            })
+        }
+        ConsoleCommand::Tail(stream_id) => submit_tail(console, session, stream_id, true),
+        ConsoleCommand::Untail(stream_id) => submit_tail(console, session, stream_id, false),
+    }
+}
+
+/// The session to send a console RPC to, or `None` after reporting that the
+/// VM Service is not connected.
+fn connected_session(
+    console: &mut ConsoleViewState,
+    session: Option<(SessionId, bool)>,
+) -> Option<SessionId> {
+    match session {
+        Some((session_id, true)) => Some(session_id),
+        _ => {
+            console.push(ConsoleLineKind::Error, "VM Service not connected");
+            None
+        }
+    }
+}
+
+/// Start or stop tailing `stream_id`, unless it already is / is not tailed.
+fn submit_tail(
+    console: &mut ConsoleViewState,
+    session: Option<(SessionId, bool)>,
+    stream_id: String,
+    tail: bool,
+) -> UpdateResult {
+    if console.tailed.contains(&stream_id) == tail {
+        let status = if tail { "already tailed" } else { "not tailed" };
+        console.push(ConsoleLineKind::Info, format!("{stream_id} is {status}"));
+        return UpdateResult::none();
+    }
+    let Some(session_id) = connected_session(console, session) else {
+        return UpdateResult::none();
+    };
+    UpdateResult::action(UpdateAction::TailConsoleStream {
+        session_id,
+        stream_id,
+        tail,
+        vm_handle: None, // hydrated by process.rs
+    })
+}
+
+/// Whether a result belongs to the session the console is showing.
+fn is_current(state: &AppState, session_id: SessionId) -> bool {
+    state.session_manager.selected_id() == Some(session_id)
+}
+
+/// Show an RPC response as pretty-printed JSON, or its error.
+pub(crate) fn handle_rpc_completed(
+    state: &mut AppState,
+    session_id: SessionId,
+    method: String,
+    result: Result<serde_json::Value, String>,
+) -> UpdateResult {
+    if !is_current(state, session_id) {
+        return UpdateResult::none();
+    }
+    let console = &mut state.devtools_view_state.console;
+    if console.pending.as_deref() == Some(method.as_str()) {
+        console.pending = None;
+    }
+    match result {
+        Ok(value) => console.push_json(&value),
+        Err(e) => console.push(ConsoleLineKind::Error, format!("{method}: {e}")),
+    }
+    UpdateResult::none()
+}
+
+/// Store the service extensions used for completion.
+pub(crate) fn handle_extensions_fetched(
+    state: &mut AppState,
+    session_id: SessionId,
+    extensions: Vec<String>,
+) -> UpdateResult {
+    if is_current(state, session_id) {
+        state.devtools_view_state.console.extensions = extensions;
+    }
+    UpdateResult::none()
+}
+
+/// Record a started or stopped stream tail.
+pub(crate) fn handle_stream_tailed(
+    state: &mut AppState,
+    session_id: SessionId,
+    stream_id: String,
+    tailed: bool,
+    result: Result<(), String>,
+) -> UpdateResult {
+    if !is_current(state, session_id) {
+        return UpdateResult::none();
+    }
+    let console = &mut state.devtools_view_state.console;
+    match result {
+        Ok(()) if tailed => {
+            console.push(
+                ConsoleLineKind::Info,
+                format!("Tailing {stream_id} events (untail {stream_id} to stop)"),
+            );
+            console.tailed.insert(stream_id);
+        }
+        Ok(()) => {
+            console.push(
+                ConsoleLineKind::Info,
+                format!("Stopped tailing {stream_id}"),
+            );
+            console.tailed.remove(&stream_id);
+        }
+        Err(e) if tailed => console.push(
+            ConsoleLineKind::Error,
+            format!("Cannot tail {stream_id}: {e}"),
+        ),
+        // The echo stopped already; only the streamCancel failed.
+        Err(e) => {
+            console.push(
+                ConsoleLineKind::Error,
+                format!("Stopped tailing {stream_id}, but streamCancel failed: {e}"),
+            );
+            console.tailed.remove(&stream_id);
+        }
+    }
+    UpdateResult::none()
+}
+
+/// Show an event of a tailed stream.
+pub(crate) fn handle_stream_event(
+    state: &mut AppState,
+    session_id: SessionId,
+    stream_id: String,
+    event: serde_json::Value,
+) -> UpdateResult {
+    if is_current(state, session_id) {
+        state
+            .devtools_view_state
+            .console
+            .push_event(&stream_id, &event);
+    }
+    UpdateResult::none()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::handler::update::update;
+    use crate::message::Message;
+
+    fn test_device() -> fdemon_daemon::Device {
+        fdemon_daemon::Device {
+            id: "dev-1".to_string(),
+            name: "Test Device".to_string(),
+            platform: "android".to_string(),
+            emulator: false,
+            category: None,
+            platform_type: None,
+            ephemeral: false,
+            emulator_id: None,
+        }
+    }
+
+    fn state_with_session(vm_connected: bool) -> (AppState, SessionId) {
+        let mut state = AppState::new();
+        let id = state
+            .session_manager
+            .create_session(&test_device())
+            .unwrap();
+        if let Some(handle) = state.session_manager.selected_mut() {
+            handle.session.vm_connected = vm_connected;
+        }
+        (state, id)
+    }
+
+    fn type_line(state: &mut AppState, line: &str) -> UpdateResult {
+        for c in line.chars() {
+            update(state, Message::ConsoleInput(c));
+        }
+        update(state, Message::ConsoleSubmit)
+    }
+
+    #[test]
+    fn test_focus_input_opens_console_and_fetches_extensions_once() {
+        let (mut state, id) = state_with_session(true);
+        let result = update(&mut state, Message::ConsoleFocusInput);
+        assert_eq!(
+            state.devtools_view_state.active_panel,
+            DevToolsPanel::Console
+        );
+        assert!(state.devtools_view_state.console.input_active);
+        assert!(matches!(
+            result.action,
+            Some(UpdateAction::FetchConsoleExtensions { session_id, .. }) if session_id == id
+        ));
+
+        update(&mut state, Message::ConsoleBlurInput);
+        let result = update(&mut state, Message::ConsoleFocusInput);
+        assert!(result.action.is_none());
+    }
+
+    #[test]
+    fn test_session_switch_keeps_tails_of_selected_session() {
+        let (mut state, first) = state_with_session(true);
+        let vm = fdemon_daemon::vm_service::VmRequestHandle::new_for_test(None);
+        vm.set_stream_tailed("GC", true);
+        state
+            .session_manager
+            .get_mut(first)
+            .unwrap()
+            .vm_request_handle = Some(vm);
+        state
+            .devtools_view_state
+            .console
+            .tailed
+            .insert("GC".to_string());
+        state.devtools_view_state.console.extensions_requested = Some(first);
+        let mut second_device = test_device();
+        second_device.id = "dev-2".to_string();
+        state
+            .session_manager
+            .create_session(&second_device)
+            .unwrap();
+
+        update(&mut state, Message::SelectSessionByIndex(1));
+        assert!(state.devtools_view_state.console.tailed.is_empty());
+        assert!(state
+            .devtools_view_state
+            .console
+            .extensions_requested
+            .is_none());
+
+        update(&mut state, Message::SelectSessionByIndex(0));
+        assert!(state.devtools_view_state.console.tailed.contains("GC"));
+        let result = type_line(&mut state, "untail GC");
+        assert!(matches!(
+            result.action,
+            Some(UpdateAction::TailConsoleStream { tail: false, .. })
+        ));
+    }
+
+    #[test]
+    fn test_submit_call_and_show_response() {
+        let (mut state, id) = state_with_session(true);
+        let result = type_line(&mut state, r#"getObject {"objectId": "classes/1"}"#);
+        match result.action {
+            Some(UpdateAction::CallConsoleRpc {
+                session_id,
+                ref method,
+                ref params,
+                ..
+            }) => {
+                assert_eq!(session_id, id);
+                assert_eq!(method, "getObject");
+                assert_eq!(params.as_ref().unwrap()["objectId"], "classes/1");
+            }
+            _ => panic!("expected CallConsoleRpc, got {:?}", result.action),
+        }
+        let console = &state.devtools_view_state.console;
+        assert_eq!(console.pending.as_deref(), Some("getObject"));
+        assert!(console.input.is_empty());
+
+        update(
+            &mut state,
+            Message::ConsoleRpcCompleted {
+                session_id: id,
+                method: "getObject".to_string(),
+                result: Ok(serde_json::json!({ "type": "Class", "name": "Foo" })),
+            },
+        );
+        let console = &state.devtools_view_state.console;
+        assert!(console.pending.is_none());
+        let text: Vec<&str> = console.lines.iter().map(|l| l.text.as_str()).collect();
+        assert_eq!(
+            text,
+            [
+                r#"> getObject {"objectId": "classes/1"}"#,
+                "{",
+                r#"  "name": "Foo","#,
+                r#"  "type": "Class""#,
+                "}",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_submit_errors_stay_in_console() {
+        let (mut state, _) = state_with_session(false);
+        assert!(type_line(&mut state, "getVM [1]").action.is_none());
+        assert!(type_line(&mut state, "getVM").action.is_none());
+        let kinds: Vec<ConsoleLineKind> = state
+            .devtools_view_state
+            .console
+            .lines
+            .iter()
+            .map(|l| l.kind)
+            .collect();
+        assert_eq!(
+            kinds,
+            [
+                ConsoleLineKind::Command,
+                ConsoleLineKind::Error,
+                ConsoleLineKind::Command,
+                ConsoleLineKind::Error,
+            ]
+        );
+
+        type_line(&mut state, "clear");
+        assert!(state.devtools_view_state.console.lines.is_empty());
+    }
+
+    #[test]
+    fn test_tail_stream_and_show_events() {
+        let (mut state, id) = state_with_session(true);
+        let result = type_line(&mut state, "tail GC");
+        assert!(matches!(
+            result.action,
+            Some(UpdateAction::TailConsoleStream { ref stream_id, tail: true, .. })
+                if stream_id == "GC"
+        ));
+        update(
+            &mut state,
+            Message::ConsoleStreamTailed {
+                session_id: id,
+                stream_id: "GC".to_string(),
+                tailed: true,
+                result: Ok(()),
+            },
+        );
+        assert!(state.devtools_view_state.console.tailed.contains("GC"));
+
+        // Tailing again is a no-op.
+        assert!(type_line(&mut state, "tail GC").action.is_none());
+
+        update(
+            &mut state,
+            Message::VmServiceConsoleEvent {
+                session_id: id,
+                stream_id: "GC".to_string(),
+                event: serde_json::json!({ "kind": "GC" }),
+            },
+        );
+        let last = state.devtools_view_state.console.lines.back().unwrap();
+        assert_eq!(last.kind, ConsoleLineKind::Event);
+        assert_eq!(last.text, r#"[GC] {"kind":"GC"}"#);
+
+        assert!(matches!(
+            type_line(&mut state, "untail GC").action,
+            Some(UpdateAction::TailConsoleStream { tail: false, .. })
+        ));
+
+        // A failed streamCancel still stops the tail.
+        update(
+            &mut state,
+            Message::ConsoleStreamTailed {
+                session_id: id,
+                stream_id: "GC".to_string(),
+                tailed: false,
+                result: Err("VM Service error 104: Stream not subscribed".to_string()),
+            },
+        );
+        let console = &state.devtools_view_state.console;
+        assert!(!console.tailed.contains("GC"));
+        assert_eq!(console.lines.back().unwrap().kind, ConsoleLineKind::Error);
+    }
+}
@@ -31,7 +31,8 @@ use crate::message::Message;
 use crate::session::debug_state::PauseReason;
 use crate::session::SessionId;
 use crate::state::AppState;
-use fdemon_daemon::vm_service::debugger_types::{DebugEvent, IsolateEvent};
+use fdemon_core::{LogEntry, LogSource};
+use fdemon_daemon::vm_service::debugger_types::{DebugEvent, IsolateEvent, IsolateRef};
 use fdemon_dap::adapter::{DebugEvent as DapDebugEvent, PauseReason as DapPauseReason};
 
 /// Handles a debug stream event for the given session.
@@ -222,6 +223,8 @@ pub fn handle_debug_event(
         }
         DebugEvent::Inspect { inspectee, .. } => {
             tracing::debug!("Inspect event: {:?}", inspectee.kind);
+            // Widgets tapped in inspector select mode arrive as Inspect events.
+            return super::inspector::handle_device_selection(state, session_id);
         }
     }
 
@@ -263,6 +266,22 @@ pub fn handle_debug_event(
     }
 }
 
+/// Adds the isolates that were running when the VM Service connected.
+///
+/// No-op if the session does not exist.
+pub(crate) fn handle_isolates_discovered(
+    state: &mut AppState,
+    session_id: SessionId,
+    isolates: Vec<IsolateRef>,
+) -> UpdateResult {
+    if let Some(handle) = state.session_manager.get_mut(session_id) {
+        for isolate in isolates {
+            handle.session.debug.add_isolate(isolate);
+        }
+    }
+    UpdateResult::none()
+}
+
 /// Handles an isolate lifecycle event for the given session.
 ///
 /// Updates the per-session `DebugState` isolate tracking based on the incoming
@@ -310,6 +329,17 @@ pub fn handle_isolate_event(
         }
         IsolateEvent::IsolateExit { isolate } => {
             handle.session.debug.remove_isolate(&isolate.id);
+            // DevTools data was read from the exited isolate: fall back to main.
+            if handle.session.performance.isolate_id.as_deref() == Some(&isolate.id) {
+                super::set_devtools_isolate(handle, None);
+                handle.session.add_log(LogEntry::info(
+                    LogSource::App,
+                    format!(
+                        "Isolate {} exited — DevTools isolate: main",
+                        isolate.name.as_deref().unwrap_or(&isolate.id)
+                    ),
+                ));
+            }
             // If the paused isolate exited, clear pause state to reflect reality.
             if handle.session.debug.paused_isolate_id.as_deref() == Some(&isolate.id) {
                 handle.session.debug.mark_resumed();
@@ -399,7 +429,6 @@ pub(crate) fn forward_dap_event(
 mod tests {
     use super::*;
     use crate::state::AppState;
-    use fdemon_daemon::vm_service::debugger_types::IsolateRef;
 
     fn make_state_with_session() -> (AppState, SessionId) {
         let mut state = AppState::new();
@@ -0,0 +1,387 @@
+//! Dump viewer handlers.
+//!
+//! Handles fetching `ext.flutter.debugDump*` text dumps, navigating and
+//! collapsing the dump text, incremental search, and saving dumps to disk.
+
+use crate::config::FlutterMode;
+use crate::dump_view_state::{DebugDumpKind, DUMP_PAGE_STEP};
+use crate::handler::{UpdateAction, UpdateResult};
+use crate::message::{DumpNav, Message};
+use crate::session::SessionId;
+use crate::state::{AppState, DevToolsError};
+
+use super::map_rpc_error;
+
+/// Request a dump for the selected session when none is loaded or loading.
+///
+/// Used when the Dumps panel becomes visible.
+pub(crate) fn fetch_if_empty(state: &mut AppState) -> UpdateResult {
+    let dumps = &state.devtools_view_state.dumps;
+    if dumps.loaded_kind.is_some() || dumps.loading {
+        return UpdateResult::none();
+    }
+    match state.session_manager.selected() {
+        Some(handle) if handle.session.vm_connected => {
+            UpdateResult::message(Message::RequestDump {
+                session_id: handle.session.id,
+            })
+        }
+        _ => UpdateResult::none(),
+    }
+}
+
+/// Switch the dump kind and fetch it for the selected session.
+pub(crate) fn handle_select_kind(state: &mut AppState, kind: DebugDumpKind) -> UpdateResult {
+    state.devtools_view_state.dumps.kind = kind;
+    match state.session_manager.selected_id() {
+        Some(session_id) => handle_request_dump(state, session_id),
+        None => UpdateResult::none(),
+    }
+}
+
+/// Fetch the selected dump kind for `session_id`.
+///
+/// Debug-only dumps are not requested from profile builds, where the
+/// extension is not registered.
+pub(crate) fn handle_request_dump(state: &mut AppState, session_id: SessionId) -> UpdateResult {
+    let (vm_connected, build_mode) = state
+        .session_manager
+        .get(session_id)
+        .map(|h| (h.session.vm_connected, h.session.build_mode()))
+        .unwrap_or((false, FlutterMode::Debug));
+
+    let dumps = &mut state.devtools_view_state.dumps;
+    if !vm_connected {
+        dumps.set_error(DevToolsError::new(
+            "VM Service not available",
+            "Ensure the app is running in debug mode",
+        ));
+        return UpdateResult::none();
+    }
+
+    let kind = dumps.kind;
+    if build_mode == FlutterMode::Profile && !kind.available_in_profile() {
+        dumps.set_error(DevToolsError::new(
+            format!("{} dump not available in profile mode", kind.label()),
+            "Run in debug mode to dump this tree",
+        ));
+        return UpdateResult::none();
+    }
+
+    dumps.start_loading(kind);
+    UpdateResult::action(UpdateAction::FetchDump {
+        session_id,
+        kind,
+        vm_handle: None, // hydrated by process.rs
+    })
+}
+
+/// Whether a fetch result still matches what the dump viewer is waiting for.
+///
+/// Results for another session, or for a kind the user has since switched
+/// away from, are dropped.
+fn is_current(state: &AppState, session_id: SessionId, kind: DebugDumpKind) -> bool {
+    state.session_manager.selected_id() == Some(session_id)
+        && state.devtools_view_state.dumps.kind == kind
+}
+
+/// Store a fetched dump.
+pub(crate) fn handle_dump_fetched(
+    state: &mut AppState,
+    session_id: SessionId,
+    kind: DebugDumpKind,
+    text: String,
+) -> UpdateResult {
+    if is_current(state, session_id, kind) {
+        state.devtools_view_state.dumps.set_dump(kind, text);
+    }
+    UpdateResult::none()
+}
+
+/// Show a dump fetch failure.
+///
+/// A missing extension gets a dump-specific message: the layer tree is only
+/// registered in debug mode.
+pub(crate) fn handle_dump_fetch_failed(
+    state: &mut AppState,
+    session_id: SessionId,
+    kind: DebugDumpKind,
+    error: String,
+) -> UpdateResult {
+    if !is_current(state, session_id, kind) {
+        return UpdateResult::none();
+    }
+    tracing::debug!("{} dump fetch failed: {}", kind.label(), error);
+    let lower = error.to_lowercase();
+    let mapped = if lower.contains("method not found") || lower.contains("extension not registered")
+    {
+        DevToolsError::new(
+            format!("{} dump not available in this mode", kind.label()),
+            "Try running in debug mode",
+        )
+    } else {
+        map_rpc_error(&error)
+    };
+    state.devtools_view_state.dumps.set_error(mapped);
+    UpdateResult::none()
+}
+
+/// Move the selection, collapse/expand lines, or step between matches.
+pub(crate) fn handle_navigate(state: &mut AppState, nav: DumpNav) -> UpdateResult {
+    let dumps = &mut state.devtools_view_state.dumps;
+    match nav {
+        DumpNav::Up => dumps.move_selection(-1),
+        DumpNav::Down => dumps.move_selection(1),
+        DumpNav::PageUp => dumps.move_selection(-(DUMP_PAGE_STEP as isize)),
+        DumpNav::PageDown => dumps.move_selection(DUMP_PAGE_STEP as isize),
+        DumpNav::Expand => dumps.expand_selected(),
+        DumpNav::Collapse => dumps.collapse_selected(),
+        DumpNav::NextMatch => {
+            dumps.select_search_match(true, false);
+        }
+        DumpNav::PrevMatch => {
+            dumps.select_search_match(false, false);
+        }
+    }
+    UpdateResult::none()
+}
+
+/// Open the search bar.
+pub(crate) fn handle_enter_search_mode(state: &mut AppState) -> UpdateResult {
+    state.devtools_view_state.dumps.search_input_active = true;
+    UpdateResult::none()
+}
+
+/// Close the search bar and clear the query.
+pub(crate) fn handle_exit_search_mode(state: &mut AppState) -> UpdateResult {
+    let dumps = &mut state.devtools_view_state.dumps;
+    dumps.search_input_active = false;
+    dumps.set_search_query(String::new());
+    UpdateResult::none()
+}
+
+/// Close the search bar, keeping the query so `]`/`[` can step between matches.
+pub(crate) fn handle_commit_search(state: &mut AppState) -> UpdateResult {
+    state.devtools_view_state.dumps.search_input_active = false;
+    UpdateResult::none()
+}
+
+/// Extend the query and jump to the first match at or after the selection.
+pub(crate) fn handle_search_input(state: &mut AppState, c: char) -> UpdateResult {
+    let dumps = &mut state.devtools_view_state.dumps;
+    dumps.push_search_char(c);
+    dumps.select_search_match(true, true);
+    UpdateResult::none()
+}
+
+/// Shorten the query and re-run the incremental search.
+pub(crate) fn handle_search_backspace(state: &mut AppState) -> UpdateResult {
+    let dumps = &mut state.devtools_view_state.dumps;
+    if dumps.pop_search_char() {
+        dumps.select_search_match(true, true);
+    }
+    UpdateResult::none()
+}
+
+/// Save the loaded dump under `.fdemon/dumps/`.
+pub(crate) fn handle_save_dump(state: &mut AppState) -> UpdateResult {
+    let dumps = &mut state.devtools_view_state.dumps;
+    let Some(kind) = dumps.loaded_kind else {
+        dumps.status = Some("No dump loaded".to_string());
+        return UpdateResult::none();
+    };
+    UpdateResult::action(UpdateAction::SaveDump {
+        kind,
+        text: dumps.raw.clone(),
+    })
+}
+
+/// Report where a dump was saved (relative to the project when possible).
+pub(crate) fn handle_dump_saved(
+    state: &mut AppState,
+    result: Result<std::path::PathBuf, String>,
+) -> UpdateResult {
+    let status = match result {
+        Ok(path) => {
+            let shown = path.strip_prefix(&state.project_path).unwrap_or(&path);
+            format!("Saved to {}", shown.display())
+        }
+        Err(e) => {
+            tracing::warn!("Failed to save dump: {}", e);
+            format!("Save failed: {e}")
+        }
+    };
+    state.devtools_view_state.dumps.status = Some(status);
+    UpdateResult::none()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::handler::update::update;
+    use crate::state::DevToolsPanel;
+
+    fn test_device() -> fdemon_daemon::Device {
+        fdemon_daemon::Device {
+            id: "dev-1".to_string(),
+            name: "Test Device".to_string(),
+            platform: "android".to_string(),
+            emulator: false,
+            category: None,
+            platform_type: None,
+            ephemeral: false,
+            emulator_id: None,
+        }
+    }
+
+    fn state_with_session(vm_connected: bool) -> (AppState, SessionId) {
+        let mut state = AppState::new();
+        let id = state
+            .session_manager
+            .create_session(&test_device())
+            .unwrap();
+        if let Some(handle) = state.session_manager.selected_mut() {
+            handle.session.vm_connected = vm_connected;
+        }
+        (state, id)
+    }
+
+    #[test]
+    fn test_switch_to_dumps_panel_requests_dump() {
+        let (mut state, id) = state_with_session(true);
+        let result = update(
+            &mut state,
+            Message::SwitchDevToolsPanel(DevToolsPanel::Dumps),
+        );
+        assert!(matches!(
+            result.message,
+            Some(Message::RequestDump { session_id }) if session_id == id
+        ));
+
+        let result = update(&mut state, Message::RequestDump { session_id: id });
+        assert!(matches!(
+            result.action,
+            Some(UpdateAction::FetchDump {
+                kind: DebugDumpKind::WidgetTree,
+                ..
+            })
+        ));
+        assert!(state.devtools_view_state.dumps.loading);
+    }
+
+    #[test]
+    fn test_request_dump_without_vm_sets_error() {
+        let (mut state, id) = state_with_session(false);
+        let result = update(&mut state, Message::RequestDump { session_id: id });
+        assert!(result.action.is_none());
+        assert!(state.devtools_view_state.dumps.error.is_some());
+    }
+
+    #[test]
+    fn test_stale_dump_kind_is_dropped() {
+        let (mut state, id) = state_with_session(true);
+        update(
+            &mut state,
+            Message::DumpSelectKind(DebugDumpKind::FocusTree),
+        );
+        update(
+            &mut state,
+            Message::DumpFetched {
+                session_id: id,
+                kind: DebugDumpKind::WidgetTree,
+                text: "MyApp".to_string(),
+            },
+        );
+        assert!(state.devtools_view_state.dumps.lines.is_empty());
+
+        update(
+            &mut state,
+            Message::DumpFetched {
+                session_id: id,
+                kind: DebugDumpKind::FocusTree,
+                text: "FocusManager#1".to_string(),
+            },
+        );
+        let dumps = &state.devtools_view_state.dumps;
+        assert_eq!(dumps.loaded_kind, Some(DebugDumpKind::FocusTree));
+        assert_eq!(dumps.lines.len(), 1);
+        assert!(!dumps.loading);
+    }
+
+    #[test]
+    fn test_missing_extension_error_names_dump_kind() {
+        let (mut state, id) = state_with_session(true);
+        update(
+            &mut state,
+            Message::DumpSelectKind(DebugDumpKind::LayerTree),
+        );
+        update(
+            &mut state,
+            Message::DumpFetchFailed {
+                session_id: id,
+                kind: DebugDumpKind::LayerTree,
+                error: "Protocol error: -32601 Method not found".to_string(),
+            },
+        );
+        let error = state.devtools_view_state.dumps.error.clone().unwrap();
+        assert_eq!(error.message, "Layers dump not available in this mode");
+    }
+
+    #[test]
+    fn test_debug_only_dump_not_requested_in_profile_mode() {
+        let (mut state, id) = state_with_session(true);
+        state
+            .session_manager
+            .get_mut(id)
+            .unwrap()
+            .session
+            .launch_config = Some(crate::config::LaunchConfig {
+            mode: FlutterMode::Profile,
+            ..Default::default()
+        });
+
+        let result = update(
+            &mut state,
+            Message::DumpSelectKind(DebugDumpKind::LayerTree),
+        );
+        assert!(result.action.is_none());
+        let dumps = &state.devtools_view_state.dumps;
+        assert!(!dumps.loading);
+        assert_eq!(
+            dumps.error.as_ref().unwrap().message,
+            "Layers dump not available in profile mode"
+        );
+
+        let result = update(
+            &mut state,
+            Message::DumpSelectKind(DebugDumpKind::RenderTree),
+        );
+        assert!(matches!(
+            result.action,
+            Some(UpdateAction::FetchDump { .. })
+        ));
+    }
+
+    #[test]
+    fn test_save_dump_requires_loaded_dump() {
+        let (mut state, id) = state_with_session(true);
+        let result = update(&mut state, Message::SaveDump);
+        assert!(result.action.is_none());
+        assert!(state.devtools_view_state.dumps.status.is_some());
+
+        update(
+            &mut state,
+            Message::DumpFetched {
+                session_id: id,
+                kind: DebugDumpKind::WidgetTree,
+                text: "MyApp\n └─child".to_string(),
+            },
+        );
+        let result = update(&mut state, Message::SaveDump);
+        assert!(matches!(
+            result.action,
+            Some(UpdateAction::SaveDump { kind: DebugDumpKind::WidgetTree, ref text })
+                if text == "MyApp\n └─child"
+        ));
+    }
+}
@@ -0,0 +1,329 @@
+//! Extensions panel handlers.
+//!
+//! Handles listing the app-registered service extensions of the DevTools
+//! isolate, moving the selection, and calling configured buttons or
+//! registered extensions. Call results are written to the session log.
+
+use std::collections::HashMap;
+
+use super::map_rpc_error;
+use crate::extensions_view_state::ExtensionRow;
+use crate::handler::{UpdateAction, UpdateResult};
+use crate::message::ExtensionNav;
+use crate::session::SessionId;
+use crate::state::AppState;
+use fdemon_core::{LogEntry, LogSource};
+
+/// Load the configured buttons and fetch the registered extensions.
+///
+/// Used when the Extensions panel becomes visible, on refresh and after the
+/// DevTools isolate changes.
+pub(crate) fn fetch_on_show(state: &mut AppState) -> UpdateResult {
+    let extensions = &mut state.devtools_view_state.extensions;
+    extensions.buttons = state.settings.devtools.extensions.clone();
+    if extensions.loading {
+        return UpdateResult::none();
+    }
+    match state.session_manager.selected() {
+        Some(handle) if handle.session.vm_connected => {
+            extensions.loading = true;
+            UpdateResult::action(UpdateAction::FetchServiceExtensions {
+                session_id: handle.session.id,
+                vm_handle: None, // hydrated by process.rs
+            })
+        }
+        _ => UpdateResult::none(),
+    }
+}
+
+/// Whether a result belongs to the session the panel is showing.
+fn is_current(state: &AppState, session_id: SessionId) -> bool {
+    state.session_manager.selected_id() == Some(session_id)
+}
+
+/// Store the fetched extensions, or show why they could not be listed.
+pub(crate) fn handle_extensions_fetched(
+    state: &mut AppState,
+    session_id: SessionId,
+    result: Result<Vec<String>, String>,
+) -> UpdateResult {
+    if !is_current(state, session_id) {
+        return UpdateResult::none();
+    }
+    let extensions = &mut state.devtools_view_state.extensions;
+    match result {
+        Ok(registered) => extensions.set_registered(registered),
+        Err(e) => extensions.set_error(map_rpc_error(&e)),
+    }
+    UpdateResult::none()
+}
+
+/// Move the selection.
+pub(crate) fn handle_navigate(state: &mut AppState, nav: ExtensionNav) -> UpdateResult {
+    let extensions = &mut state.devtools_view_state.extensions;
+    match nav {
+        ExtensionNav::Up => extensions.move_selection(-1),
+        ExtensionNav::Down => extensions.move_selection(1),
+    }
+    UpdateResult::none()
+}
+
+/// Call the selected row: a configured button with its args, or a
+/// registered extension without args.
+pub(crate) fn handle_call_selected(state: &mut AppState) -> UpdateResult {
+    let call = match state.devtools_view_state.extensions.selected_row() {
+        Some(ExtensionRow::Button { button, .. }) => (
+            button.label.clone(),
+            button.method.clone(),
+            button.args.clone(),
+        ),
+        Some(ExtensionRow::Registered(method)) => {
+            (method.to_string(), method.to_string(), HashMap::new())
+        }
+        None => return UpdateResult::none(),
+    };
+    call_extension(state, call)
+}
+
+/// Call the configured button at `index` (pressed by its key).
+pub(crate) fn handle_press_button(state: &mut AppState, index: usize) -> UpdateResult {
+    let extensions = &mut state.devtools_view_state.extensions;
+    let Some(button) = extensions.buttons.get(index) else {
+        return UpdateResult::none();
+    };
+    let call = (
+        button.label.clone(),
+        button.method.clone(),
+        button.args.clone(),
+    );
+    if let Some(row) = extensions
+        .rows()
+        .iter()
+        .position(|row| matches!(row, ExtensionRow::Button { index: i, .. } if *i == index))
+    {
+        extensions.selected = row;
+    }
+    call_extension(state, call)
+}
+
+fn call_extension(
+    state: &mut AppState,
+    (label, method, args): (String, String, HashMap<String, String>),
+) -> UpdateResult {
+    let session = state
+        .session_manager
+        .selected()
+        .map(|h| (h.session.id, h.session.vm_connected));
+    let extensions = &mut state.devtools_view_state.extensions;
+    match session {
+        Some((session_id, true)) => {
+            extensions.status = Some(format!("Calling {label}..."));
+            UpdateResult::action(UpdateAction::CallServiceExtension {
+                session_id,
+                label,
+                method,
+                args,
+                vm_handle: None, // hydrated by process.rs
+            })
+        }
+        _ => {
+            extensions.status = Some("VM Service not connected".to_string());
+            UpdateResult::none()
+        }
+    }
+}
+
+/// Log the outcome of an extension call to its session.
+pub(crate) fn handle_extension_called(
+    state: &mut AppState,
+    session_id: SessionId,
+    label: String,
+    method: String,
+    result: Result<serde_json::Value, String>,
+) -> UpdateResult {
+    let (entry, status) = match result {
+        Ok(value) => {
+            let summary = summarize_response(value);
+            (
+                LogEntry::info(LogSource::VmService, format!("{label}: {summary}")),
+                format!("{label}: {summary}"),
+            )
+        }
+        Err(e) => (
+            LogEntry::error(
+                LogSource::VmService,
+                format!("{label} ({method}) failed: {e}"),
+            ),
+            format!("{label} failed: {e}"),
+        ),
+    };
+    if let Some(handle) = state.session_manager.get_mut(session_id) {
+        handle.session.add_log(entry);
+    }
+    if is_current(state, session_id) {
+        state.devtools_view_state.extensions.status = Some(status);
+    }
+    UpdateResult::none()
+}
+
+/// Compact JSON of an extension response without the protocol `type` and
+/// `method` keys, or `done` when nothing else is left.
+fn summarize_response(mut value: serde_json::Value) -> String {
+    if let Some(object) = value.as_object_mut() {
+        object.remove("type");
+        object.remove("method");
+        if object.is_empty() {
+            return "done".to_string();
+        }
+    }
+    value.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::ExtensionButton;
+    use crate::handler::update::update;
+    use crate::message::Message;
+    use crate::state::DevToolsPanel;
+
+    fn test_device() -> fdemon_daemon::Device {
+        fdemon_daemon::Device {
+            id: "dev-1".to_string(),
+            name: "Test Device".to_string(),
+            platform: "android".to_string(),
+            emulator: false,
+            category: None,
+            platform_type: None,
+            ephemeral: false,
+            emulator_id: None,
+        }
+    }
+
+    fn state_with_session(vm_connected: bool) -> (AppState, SessionId) {
+        let mut state = AppState::new();
+        state.settings.devtools.extensions = vec![ExtensionButton {
+            label: "Debug menu".to_string(),
+            method: "ext.myapp.debugMenu".to_string(),
+            key: Some('m'),
+            args: HashMap::from([("enabled".to_string(), "true".to_string())]),
+        }];
+        let id = state
+            .session_manager
+            .create_session(&test_device())
+            .unwrap();
+        if let Some(handle) = state.session_manager.selected_mut() {
+            handle.session.vm_connected = vm_connected;
+        }
+        (state, id)
+    }
+
+    #[test]
+    fn test_switch_panel_loads_buttons_and_fetches_extensions() {
+        let (mut state, id) = state_with_session(true);
+        let result = update(
+            &mut state,
+            Message::SwitchDevToolsPanel(DevToolsPanel::Extensions),
+        );
+        assert!(matches!(
+            result.action,
+            Some(UpdateAction::FetchServiceExtensions { session_id, .. }) if session_id == id
+        ));
+        let extensions = &state.devtools_view_state.extensions;
+        assert_eq!(extensions.buttons.len(), 1);
+        assert!(extensions.loading);
+
+        update(
+            &mut state,
+            Message::ServiceExtensionsFetched {
+                session_id: id,
+                result: Ok(vec!["ext.myapp.resetCache".to_string()]),
+            },
+        );
+        let extensions = &state.devtools_view_state.extensions;
+        assert!(!extensions.loading);
+        assert_eq!(extensions.rows().len(), 2);
+
+        update(
+            &mut state,
+            Message::ServiceExtensionsFetched {
+                session_id: id,
+                result: Err("Connection closed".to_string()),
+            },
+        );
+        assert!(state.devtools_view_state.extensions.error.is_some());
+    }
+
+    #[test]
+    fn test_press_button_and_log_result() {
+        let (mut state, id) = state_with_session(true);
+        update(
+            &mut state,
+            Message::SwitchDevToolsPanel(DevToolsPanel::Extensions),
+        );
+        let result = update(&mut state, Message::PressExtensionButton(0));
+        match result.action {
+            Some(UpdateAction::CallServiceExtension {
+                session_id,
+                ref label,
+                ref method,
+                ref args,
+                ..
+            }) => {
+                assert_eq!(session_id, id);
+                assert_eq!(label, "Debug menu");
+                assert_eq!(method, "ext.myapp.debugMenu");
+                assert_eq!(args["enabled"], "true");
+            }
+            ref other => panic!("expected CallServiceExtension, got {other:?}"),
+        }
+        assert_eq!(
+            state.devtools_view_state.extensions.status.as_deref(),
+            Some("Calling Debug menu...")
+        );
+
+        update(
+            &mut state,
+            Message::ServiceExtensionCalled {
+                session_id: id,
+                label: "Debug menu".to_string(),
+                method: "ext.myapp.debugMenu".to_string(),
+                result: Ok(serde_json::json!({"type": "_extensionType", "enabled": true})),
+            },
+        );
+        let handle = state.session_manager.get(id).unwrap();
+        let last = handle.session.logs.back().unwrap();
+        assert_eq!(last.message, r#"Debug menu: {"enabled":true}"#);
+        assert_eq!(
+            state.devtools_view_state.extensions.status.as_deref(),
+            Some(r#"Debug menu: {"enabled":true}"#)
+        );
+    }
+
+    #[test]
+    fn test_call_selected_without_vm_sets_status() {
+        let (mut state, _) = state_with_session(false);
+        update(
+            &mut state,
+            Message::SwitchDevToolsPanel(DevToolsPanel::Extensions),
+        );
+        let result = update(&mut state, Message::CallSelectedExtension);
+        assert!(result.action.is_none());
+        assert_eq!(
+            state.devtools_view_state.extensions.status.as_deref(),
+            Some("VM Service not connected")
+        );
+    }
+
+    #[test]
+    fn test_summarize_response() {
+        assert_eq!(
+            summarize_response(serde_json::json!({"type": "_extensionType", "method": "ext.x"})),
+            "done"
+        );
+        assert_eq!(
+            summarize_response(serde_json::json!({"value": 3})),
+            r#"{"value":3}"#
+        );
+    }
+}
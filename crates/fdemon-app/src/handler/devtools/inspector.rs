@@ -1,22 +1,23 @@
 //! Inspector-specific DevTools handlers.
 //!
-//! Handles widget tree fetch results and inspector navigation for the
-//! Widget Inspector panel in DevTools mode.
+//! Handles widget tree fetch results, inspector navigation, and on-device
+//! select mode for the Widget Inspector panel in DevTools mode.
 
 use std::time::Instant;
 
 use crate::handler::{UpdateAction, UpdateResult};
-use crate::message::InspectorNav;
+use crate::message::{InspectorNav, Message};
 use crate::session::SessionId;
-use crate::state::{AppState, DevToolsError, InspectorState};
+use crate::state::{AppState, DevToolsError, InspectorDetailView, InspectorState};
 
 use super::map_rpc_error;
 
 /// Handle widget tree fetch completion.
 ///
 /// Updates the inspector state with the fetched root node, auto-expands it,
-/// and dispatches an initial layout fetch for the root node so the layout
-/// panel shows data immediately without requiring a navigation event.
+/// and dispatches an initial layout (or properties) fetch for the selected
+/// node so the details pane shows data immediately without requiring a
+/// navigation event.
 pub fn handle_widget_tree_fetched(
     state: &mut AppState,
     session_id: SessionId,
@@ -43,6 +44,16 @@ pub fn handle_widget_tree_fetched(
         }
 
         state.devtools_view_state.inspector.root = Some(root_node);
+
+        // Apply a device selection that arrived before the widget existed in
+        // the previous tree.
+        if let Some(value_id) = state.devtools_view_state.inspector.pending_selection.take() {
+            state
+                .devtools_view_state
+                .inspector
+                .select_value_id(&value_id);
+        }
+
         state.devtools_view_state.inspector.loading = false;
         state.devtools_view_state.inspector.error = None;
         state.devtools_view_state.inspector.has_object_group = true;
@@ -55,8 +66,13 @@ pub fn handle_widget_tree_fetched(
         state.devtools_view_state.inspector.last_fetched_node_id = None;
         state.devtools_view_state.inspector.pending_node_id = None;
         state.devtools_view_state.inspector.layout_last_fetch_time = None;
+        state.devtools_view_state.inspector.clear_properties();
+
+        if state.devtools_view_state.inspector.detail_view == InspectorDetailView::Properties {
+            return properties_fetch_for_selection(state);
+        }
 
-        // Auto-fetch layout for the initially selected node (root at index 0)
+        // Auto-fetch layout for the initially selected node (usually the root)
         // so the layout panel shows data immediately on Inspector entry.
         if let Some(node_id) = get_selected_value_id(&state.devtools_view_state.inspector) {
             state.devtools_view_state.inspector.layout_loading = true;
@@ -92,18 +108,21 @@ pub fn handle_widget_tree_fetch_failed(
     UpdateResult::none()
 }
 
-/// Handle inspector tree navigation (Up/Down/Expand/Collapse).
+/// Handle inspector tree navigation (Up/Down/Expand/Collapse and
+/// NextMatch/PrevMatch).
 ///
-/// On Up/Down navigation: clears stale layout data immediately (so the UI
-/// shows a loading state), then dispatches a `FetchLayoutData` action for the
-/// newly selected node unless debounced or already fetched.
+/// When the selection moves (Up/Down, or jumping between search matches):
+/// clears stale details immediately (so the UI shows a loading state), then
+/// dispatches a `FetchLayoutData` or `FetchWidgetProperties` action for the
+/// newly selected node, depending on the details view, unless debounced or
+/// already fetched.
 ///
-/// On Expand/Collapse: no layout fetch is triggered (selection does not change).
+/// On Expand/Collapse: no fetch is triggered (selection does not change).
 pub fn handle_inspector_navigate(state: &mut AppState, nav: InspectorNav) -> UpdateResult {
     // Phase 1: read the visible node count and current selection, then handle navigation.
     // We scope the mutable borrow of `inspector` here so it ends before we access
     // `state.session_manager` below.
-    let (old_index, new_index, should_fetch_layout) = {
+    let should_fetch_layout = {
         let inspector = &mut state.devtools_view_state.inspector;
         let visible = inspector.visible_nodes();
         let count = visible.len();
@@ -149,61 +168,374 @@ pub fn handle_inspector_navigate(state: &mut AppState, nav: InspectorNav) -> Upd
                 }
                 return UpdateResult::none();
             }
+            InspectorNav::NextMatch => {
+                inspector.select_search_match(true, false);
+            }
+            InspectorNav::PrevMatch => {
+                inspector.select_search_match(false, false);
+            }
         }
 
-        let new_index = inspector.selected_index;
-        let selection_changed = new_index != old_index;
-
-        if selection_changed {
-            // Clear stale layout data immediately — user sees loading state.
-            inspector.layout = None;
-            inspector.layout_error = None;
-        }
-
-        (old_index, new_index, selection_changed)
+        inspector.selected_index != old_index
     };
     // `inspector` borrow has ended here — we can now access other fields.
 
-    // Phase 2: auto-fetch layout for the newly selected node (Up/Down only).
+    // Phase 2: auto-fetch layout for the newly selected node.
     if should_fetch_layout {
-        let _ = (old_index, new_index); // suppress unused warning
+        return detail_fetch_for_selection(state);
+    }
 
-        // Determine whether to fetch — collect node_id while holding the borrow.
-        // The borrow of `inspector` ends at the close of this block, allowing us
-        // to access `state.session_manager` afterwards.
-        let fetch_node_id: Option<String> = {
-            let inspector = &mut state.devtools_view_state.inspector;
+    UpdateResult::none()
+}
+
+/// Refresh whichever details view is shown after the selection moved.
+fn detail_fetch_for_selection(state: &mut AppState) -> UpdateResult {
+    match state.devtools_view_state.inspector.detail_view {
+        InspectorDetailView::Layout => layout_fetch_for_selection(state),
+        InspectorDetailView::Properties => properties_fetch_for_selection(state),
+    }
+}
 
-            if inspector.is_layout_fetch_debounced() {
+/// Clear stale layout data after the selection moved and dispatch a layout
+/// fetch for the newly selected node, unless debounced or already fetched.
+fn layout_fetch_for_selection(state: &mut AppState) -> UpdateResult {
+    // Determine whether to fetch — collect node_id while holding the borrow.
+    // The borrow of `inspector` ends at the close of this block, allowing us
+    // to access `state.session_manager` afterwards.
+    let fetch_node_id: Option<String> = {
+        let inspector = &mut state.devtools_view_state.inspector;
+
+        // Clear stale layout data immediately — user sees loading state.
+        inspector.layout = None;
+        inspector.layout_error = None;
+
+        if inspector.is_layout_fetch_debounced() {
+            None
+        } else if let Some(node_id) = get_selected_value_id(inspector) {
+            // Skip if the same node was already fetched (cache hit).
+            if inspector.last_fetched_node_id.as_deref() == Some(node_id.as_str()) {
                 None
-            } else if let Some(node_id) = get_selected_value_id(inspector) {
-                // Skip if the same node was already fetched (cache hit).
-                if inspector.last_fetched_node_id.as_deref() == Some(node_id.as_str()) {
-                    None
-                } else {
-                    inspector.layout_loading = true;
-                    inspector.pending_node_id = Some(node_id.clone());
-                    inspector.layout_last_fetch_time = Some(Instant::now());
-                    Some(node_id)
-                }
             } else {
-                None
+                inspector.layout_loading = true;
+                inspector.pending_node_id = Some(node_id.clone());
+                inspector.layout_last_fetch_time = Some(Instant::now());
+                Some(node_id)
             }
-        };
-        // `inspector` borrow has ended — we can now access session_manager.
-
-        if let Some(node_id) = fetch_node_id {
-            let session_id = state.session_manager.selected().map(|h| h.session.id);
-            if let Some(session_id) = session_id {
-                return UpdateResult::action(UpdateAction::FetchLayoutData {
-                    session_id,
-                    node_id,
-                    vm_handle: None, // hydrated by process.rs
-                });
+        } else {
+            None
+        }
+    };
+    // `inspector` borrow has ended — we can now access session_manager.
+
+    if let Some(node_id) = fetch_node_id {
+        let session_id = state.session_manager.selected().map(|h| h.session.id);
+        if let Some(session_id) = session_id {
+            return UpdateResult::action(UpdateAction::FetchLayoutData {
+                session_id,
+                node_id,
+                vm_handle: None, // hydrated by process.rs
+            });
+        }
+    }
+
+    UpdateResult::none()
+}
+
+/// Dispatch a properties fetch for the selected node unless its properties
+/// are already loaded or in flight.
+fn properties_fetch_for_selection(state: &mut AppState) -> UpdateResult {
+    let inspector = &mut state.devtools_view_state.inspector;
+    let Some(node_id) = get_selected_value_id(inspector) else {
+        inspector.clear_properties();
+        return UpdateResult::none();
+    };
+    if inspector.properties_node_id.as_deref() == Some(node_id.as_str()) {
+        return UpdateResult::none();
+    }
+    inspector.clear_properties();
+    inspector.properties_loading = true;
+    inspector.properties_node_id = Some(node_id.clone());
+
+    match state.session_manager.selected().map(|h| h.session.id) {
+        Some(session_id) => UpdateResult::action(UpdateAction::FetchWidgetProperties {
+            session_id,
+            node_id,
+            vm_handle: None, // hydrated by process.rs
+        }),
+        None => UpdateResult::none(),
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Properties pane handlers
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Switch the details pane between Layout and Properties, fetching data for
+/// the selected node if the new view has none for it.
+pub fn handle_toggle_detail_view(state: &mut AppState) -> UpdateResult {
+    let inspector = &mut state.devtools_view_state.inspector;
+    match inspector.detail_view {
+        InspectorDetailView::Layout => {
+            inspector.detail_view = InspectorDetailView::Properties;
+            properties_fetch_for_selection(state)
+        }
+        InspectorDetailView::Properties => {
+            inspector.detail_view = InspectorDetailView::Layout;
+            inspector.properties_focused = false;
+            if get_selected_value_id(inspector) != inspector.last_fetched_node_id {
+                return layout_fetch_for_selection(state);
             }
+            UpdateResult::none()
         }
     }
+}
 
+/// Move keyboard focus between the tree and the properties pane. Focusing
+/// the pane switches the details view to Properties first.
+pub fn handle_toggle_focus(state: &mut AppState) -> UpdateResult {
+    let inspector = &mut state.devtools_view_state.inspector;
+    if inspector.properties_focused {
+        inspector.properties_focused = false;
+        return UpdateResult::none();
+    }
+    inspector.properties_focused = true;
+    if inspector.detail_view != InspectorDetailView::Properties {
+        inspector.detail_view = InspectorDetailView::Properties;
+        return properties_fetch_for_selection(state);
+    }
+    UpdateResult::none()
+}
+
+/// Navigate the property rows: move the cursor, or expand/collapse nested
+/// property values. Collapsing a row that is not expanded jumps to its parent.
+pub fn handle_properties_navigate(state: &mut AppState, nav: InspectorNav) -> UpdateResult {
+    let inspector = &mut state.devtools_view_state.inspector;
+    let rows: Vec<(Vec<usize>, bool)> = inspector
+        .visible_properties()
+        .into_iter()
+        .map(|(path, node, _)| (path, !node.properties.is_empty()))
+        .collect();
+    let Some((path, has_nested)) = rows.get(inspector.properties_selected).cloned() else {
+        return UpdateResult::none();
+    };
+
+    match nav {
+        InspectorNav::Up => {
+            inspector.properties_selected = inspector.properties_selected.saturating_sub(1);
+        }
+        InspectorNav::Down => {
+            if inspector.properties_selected + 1 < rows.len() {
+                inspector.properties_selected += 1;
+            }
+        }
+        InspectorNav::Expand => {
+            if has_nested {
+                inspector.properties_expanded.insert(path);
+            }
+        }
+        InspectorNav::Collapse => {
+            if !inspector.properties_expanded.remove(&path) && path.len() > 1 {
+                let parent = &path[..path.len() - 1];
+                if let Some(index) = rows.iter().position(|(p, _)| p == parent) {
+                    inspector.properties_selected = index;
+                }
+            }
+        }
+        InspectorNav::NextMatch | InspectorNav::PrevMatch => {}
+    }
+    UpdateResult::none()
+}
+
+/// Handle a properties fetch result. Responses for a widget other than the
+/// one currently awaited are discarded.
+pub fn handle_widget_properties_fetched(
+    state: &mut AppState,
+    session_id: SessionId,
+    node_id: String,
+    details: Box<fdemon_core::DiagnosticsNode>,
+) -> UpdateResult {
+    let active_id = state.session_manager.selected().map(|h| h.session.id);
+    let inspector = &mut state.devtools_view_state.inspector;
+    if active_id == Some(session_id) && inspector.properties_node_id.as_ref() == Some(&node_id) {
+        inspector.properties = Some(*details);
+        inspector.properties_loading = false;
+        inspector.properties_error = None;
+        inspector.properties_selected = 0;
+        inspector.properties_expanded.clear();
+    }
+    UpdateResult::none()
+}
+
+/// Handle a properties fetch failure for the awaited widget.
+pub fn handle_widget_properties_fetch_failed(
+    state: &mut AppState,
+    session_id: SessionId,
+    node_id: String,
+    error: String,
+) -> UpdateResult {
+    let active_id = state.session_manager.selected().map(|h| h.session.id);
+    let inspector = &mut state.devtools_view_state.inspector;
+    if active_id == Some(session_id) && inspector.properties_node_id.as_ref() == Some(&node_id) {
+        inspector.properties_loading = false;
+        inspector.properties_error = Some(map_rpc_error(&error));
+    }
+    UpdateResult::none()
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Search and user-code filter handlers
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Open the search bar. Re-opening keeps the previous query for editing.
+pub fn handle_enter_search_mode(state: &mut AppState) -> UpdateResult {
+    state.devtools_view_state.inspector.search_input_active = true;
+    UpdateResult::none()
+}
+
+/// Close the search bar and clear the query. The selection stays on the
+/// last match.
+pub fn handle_exit_search_mode(state: &mut AppState) -> UpdateResult {
+    let inspector = &mut state.devtools_view_state.inspector;
+    inspector.search_input_active = false;
+    inspector.search_query.clear();
+    UpdateResult::none()
+}
+
+/// Close the search bar, keeping the query so `]`/`[` can step between matches.
+pub fn handle_commit_search(state: &mut AppState) -> UpdateResult {
+    state.devtools_view_state.inspector.search_input_active = false;
+    UpdateResult::none()
+}
+
+/// Extend the query and jump to the first match at or after the selection.
+pub fn handle_search_input(state: &mut AppState, c: char) -> UpdateResult {
+    state.devtools_view_state.inspector.search_query.push(c);
+    select_incremental_match(state)
+}
+
+/// Shorten the query and re-run the incremental search.
+pub fn handle_search_backspace(state: &mut AppState) -> UpdateResult {
+    if state
+        .devtools_view_state
+        .inspector
+        .search_query
+        .pop()
+        .is_none()
+    {
+        return UpdateResult::none();
+    }
+    select_incremental_match(state)
+}
+
+fn select_incremental_match(state: &mut AppState) -> UpdateResult {
+    let inspector = &mut state.devtools_view_state.inspector;
+    let old_index = inspector.selected_index;
+    inspector.select_search_match(true, true);
+    if inspector.selected_index != old_index {
+        return detail_fetch_for_selection(state);
+    }
+    UpdateResult::none()
+}
+
+/// Toggle the user-code-only filter.
+pub fn handle_toggle_user_code_only(state: &mut AppState) -> UpdateResult {
+    let inspector = &mut state.devtools_view_state.inspector;
+    let old_id = get_selected_value_id(inspector);
+    inspector.toggle_user_code_only();
+    if get_selected_value_id(inspector) != old_id {
+        return detail_fetch_for_selection(state);
+    }
+    UpdateResult::none()
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// On-device select mode handlers
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Ask the device to enable or disable widget selection by tapping.
+pub fn handle_set_select_mode(state: &mut AppState, enabled: bool) -> UpdateResult {
+    let Some(handle) = state.session_manager.selected() else {
+        return UpdateResult::none();
+    };
+    if !handle.session.vm_connected {
+        return UpdateResult::none();
+    }
+    UpdateResult::action(UpdateAction::SetInspectorSelectMode {
+        session_id: handle.session.id,
+        enabled,
+        vm_handle: None, // hydrated by process.rs
+    })
+}
+
+/// Record the select mode state reported by the device.
+pub fn handle_select_mode_changed(
+    state: &mut AppState,
+    session_id: SessionId,
+    enabled: bool,
+) -> UpdateResult {
+    let active_id = state.session_manager.selected().map(|h| h.session.id);
+    if active_id == Some(session_id) {
+        state.devtools_view_state.inspector.select_mode = enabled;
+    }
+    UpdateResult::none()
+}
+
+/// Handle an `Inspect` event from the Debug stream.
+///
+/// Flutter calls `developer.inspect()` whenever a widget is tapped in select
+/// mode. The event only carries a VM instance reference, so the inspector
+/// node is fetched separately.
+pub fn handle_device_selection(state: &mut AppState, session_id: SessionId) -> UpdateResult {
+    let active_id = state.session_manager.selected().map(|h| h.session.id);
+    if active_id != Some(session_id) || !state.devtools_view_state.inspector.select_mode {
+        return UpdateResult::none();
+    }
+    UpdateResult::action(UpdateAction::FetchSelectedWidget {
+        session_id,
+        vm_handle: None, // hydrated by process.rs
+    })
+}
+
+/// Jump the tree panel to the widget selected on the device.
+///
+/// If the widget is not in the loaded tree (it was built after the last
+/// fetch), the tree is refreshed and the selection applied when it arrives.
+pub fn handle_selected_widget_fetched(
+    state: &mut AppState,
+    session_id: SessionId,
+    node: Option<Box<fdemon_core::DiagnosticsNode>>,
+) -> UpdateResult {
+    let active_id = state.session_manager.selected().map(|h| h.session.id);
+    if active_id != Some(session_id) {
+        return UpdateResult::none();
+    }
+    let Some(value_id) = node.and_then(|n| n.value_id) else {
+        return UpdateResult::none();
+    };
+
+    let inspector = &mut state.devtools_view_state.inspector;
+    let old_id = get_selected_value_id(inspector);
+    if inspector.select_value_id(&value_id) {
+        if get_selected_value_id(inspector) != old_id {
+            return detail_fetch_for_selection(state);
+        }
+        return UpdateResult::none();
+    }
+
+    inspector.pending_selection = Some(value_id);
+    UpdateResult::message(Message::RequestWidgetTree { session_id })
+}
+
+/// Handle a `Flutter.Navigation` event: the route changed on the device.
+///
+/// In select mode the tree panel mirrors the device, so a loaded tree is
+/// refreshed to pick up the new route's widgets.
+pub fn handle_navigation_event(state: &mut AppState, session_id: SessionId) -> UpdateResult {
+    let active_id = state.session_manager.selected().map(|h| h.session.id);
+    let inspector = &state.devtools_view_state.inspector;
+    if active_id == Some(session_id) && inspector.select_mode && inspector.root.is_some() {
+        return UpdateResult::message(Message::RequestWidgetTree { session_id });
+    }
     UpdateResult::none()
 }
 
@@ -1212,4 +1544,393 @@ mod tests {
             "layout_loading should remain false when no fetch is dispatched"
         );
     }
+
+    // ── Search and user-code filter ───────────────────────────────────────────
+
+    /// Root (framework) → Scaffold (user) → Column (framework) → two user
+    /// ListTiles. Only the root is expanded.
+    fn make_search_tree() -> fdemon_core::DiagnosticsNode {
+        serde_json::from_value(serde_json::json!({
+            "description": "MaterialApp",
+            "valueId": "app",
+            "children": [{
+                "description": "Scaffold",
+                "valueId": "scaffold",
+                "createdByLocalProject": true,
+                "children": [{
+                    "description": "Column",
+                    "valueId": "column",
+                    "children": [
+                        {"description": "ListTile", "valueId": "tile-1", "createdByLocalProject": true},
+                        {"description": "ListTile", "valueId": "tile-2", "createdByLocalProject": true}
+                    ]
+                }]
+            }]
+        }))
+        .expect("valid DiagnosticsNode")
+    }
+
+    fn make_search_state() -> AppState {
+        let mut state = make_state_with_session();
+        state.devtools_view_state.inspector.root = Some(make_search_tree());
+        state
+            .devtools_view_state
+            .inspector
+            .expanded
+            .insert("app".to_string());
+        state
+    }
+
+    fn selected_description(state: &AppState) -> Option<String> {
+        state
+            .devtools_view_state
+            .inspector
+            .selected_node_description()
+    }
+
+    #[test]
+    fn test_search_input_expands_to_deep_match() {
+        let mut state = make_search_state();
+        handle_enter_search_mode(&mut state);
+        for c in "listt".chars() {
+            handle_search_input(&mut state, c);
+        }
+        let inspector = &state.devtools_view_state.inspector;
+        assert!(inspector.search_input_active);
+        assert!(inspector.is_expanded("scaffold"));
+        assert!(inspector.is_expanded("column"));
+        assert_eq!(selected_description(&state).as_deref(), Some("ListTile"));
+        assert_eq!(inspector.search_match_position(), (2, Some(1)));
+    }
+
+    #[test]
+    fn test_search_input_triggers_layout_fetch() {
+        let mut state = make_search_state();
+        let result = handle_search_input(&mut state, 'S');
+        assert!(matches!(
+            result.action,
+            Some(UpdateAction::FetchLayoutData { .. })
+        ));
+        assert_eq!(selected_description(&state).as_deref(), Some("Scaffold"));
+    }
+
+    #[test]
+    fn test_next_and_prev_match_wrap() {
+        let mut state = make_search_state();
+        for c in "tile".chars() {
+            handle_search_input(&mut state, c);
+        }
+        handle_commit_search(&mut state);
+        assert!(!state.devtools_view_state.inspector.search_input_active);
+        assert_eq!(state.devtools_view_state.inspector.search_query, "tile");
+
+        handle_inspector_navigate(&mut state, InspectorNav::NextMatch);
+        assert_eq!(
+            state.devtools_view_state.inspector.search_match_position(),
+            (2, Some(2))
+        );
+        handle_inspector_navigate(&mut state, InspectorNav::NextMatch);
+        assert_eq!(
+            state.devtools_view_state.inspector.search_match_position(),
+            (2, Some(1))
+        );
+        handle_inspector_navigate(&mut state, InspectorNav::PrevMatch);
+        assert_eq!(
+            state.devtools_view_state.inspector.search_match_position(),
+            (2, Some(2))
+        );
+    }
+
+    #[test]
+    fn test_search_without_match_keeps_selection() {
+        let mut state = make_search_state();
+        handle_search_input(&mut state, 'z');
+        assert_eq!(state.devtools_view_state.inspector.selected_index, 0);
+        assert_eq!(
+            state.devtools_view_state.inspector.search_match_position(),
+            (0, None)
+        );
+    }
+
+    #[test]
+    fn test_exit_search_mode_clears_query() {
+        let mut state = make_search_state();
+        handle_enter_search_mode(&mut state);
+        handle_search_input(&mut state, 'x');
+        handle_search_backspace(&mut state);
+        handle_search_input(&mut state, 'y');
+        handle_exit_search_mode(&mut state);
+        assert!(!state.devtools_view_state.inspector.search_input_active);
+        assert!(state.devtools_view_state.inspector.search_query.is_empty());
+    }
+
+    #[test]
+    fn test_user_code_only_lifts_user_widgets() {
+        let mut state = make_search_state();
+        state
+            .devtools_view_state
+            .inspector
+            .expanded
+            .insert("scaffold".to_string());
+        handle_toggle_user_code_only(&mut state);
+        let inspector = &state.devtools_view_state.inspector;
+        let visible: Vec<(&str, usize)> = inspector
+            .visible_nodes()
+            .iter()
+            .map(|(n, d)| (n.description.as_str(), *d))
+            .collect();
+        assert_eq!(
+            visible,
+            vec![("Scaffold", 0), ("ListTile", 1), ("ListTile", 1)]
+        );
+        assert_eq!(selected_description(&state).as_deref(), Some("Scaffold"));
+    }
+
+    #[test]
+    fn test_user_code_only_keeps_selected_node() {
+        let mut state = make_search_state();
+        for c in "listtile".chars() {
+            handle_search_input(&mut state, c);
+        }
+        handle_toggle_user_code_only(&mut state);
+        assert!(state.devtools_view_state.inspector.user_code_only);
+        assert_eq!(selected_description(&state).as_deref(), Some("ListTile"));
+        assert_eq!(state.devtools_view_state.inspector.selected_index, 1);
+    }
+
+    #[test]
+    fn test_user_code_only_excludes_framework_matches() {
+        let mut state = make_search_state();
+        state.devtools_view_state.inspector.user_code_only = true;
+        for c in "column".chars() {
+            handle_search_input(&mut state, c);
+        }
+        assert_eq!(
+            state.devtools_view_state.inspector.search_match_position(),
+            (0, None)
+        );
+    }
+
+    // ── On-device select mode ─────────────────────────────────────────────────
+
+    fn active_session_id(state: &AppState) -> SessionId {
+        state.session_manager.selected().unwrap().session.id
+    }
+
+    fn selected_node(value_id: &str) -> Option<Box<fdemon_core::DiagnosticsNode>> {
+        let mut node = make_node("tapped");
+        node.value_id = Some(value_id.to_string());
+        Some(Box::new(node))
+    }
+
+    #[test]
+    fn test_set_select_mode_requires_vm_connection() {
+        let mut state = make_state_with_session();
+        let result = handle_set_select_mode(&mut state, true);
+        assert!(result.action.is_none());
+
+        state
+            .session_manager
+            .selected_mut()
+            .unwrap()
+            .session
+            .vm_connected = true;
+        let result = handle_set_select_mode(&mut state, true);
+        assert!(matches!(
+            result.action,
+            Some(UpdateAction::SetInspectorSelectMode { enabled: true, .. })
+        ));
+    }
+
+    #[test]
+    fn test_inspect_event_fetches_selection_only_in_select_mode() {
+        let mut state = make_search_state();
+        let session_id = active_session_id(&state);
+        assert!(handle_device_selection(&mut state, session_id)
+            .action
+            .is_none());
+
+        handle_select_mode_changed(&mut state, session_id, true);
+        assert!(matches!(
+            handle_device_selection(&mut state, session_id).action,
+            Some(UpdateAction::FetchSelectedWidget { .. })
+        ));
+    }
+
+    #[test]
+    fn test_device_selection_reveals_node_and_fetches_layout() {
+        let mut state = make_search_state();
+        let session_id = active_session_id(&state);
+        let result =
+            handle_selected_widget_fetched(&mut state, session_id, selected_node("tile-2"));
+
+        let inspector = &state.devtools_view_state.inspector;
+        assert!(inspector.is_expanded("scaffold"));
+        assert!(inspector.is_expanded("column"));
+        assert_eq!(inspector.selected_index, 4);
+        assert!(matches!(
+            result.action,
+            Some(UpdateAction::FetchLayoutData { ref node_id, .. }) if node_id == "tile-2"
+        ));
+    }
+
+    #[test]
+    fn test_device_selection_of_hidden_framework_widget_selects_ancestor() {
+        let mut state = make_search_state();
+        state.devtools_view_state.inspector.user_code_only = true;
+        let session_id = active_session_id(&state);
+        handle_selected_widget_fetched(&mut state, session_id, selected_node("column"));
+        assert_eq!(selected_description(&state).as_deref(), Some("Scaffold"));
+    }
+
+    #[test]
+    fn test_unknown_device_selection_refreshes_tree_then_selects() {
+        let mut state = make_search_state();
+        let session_id = active_session_id(&state);
+        let result =
+            handle_selected_widget_fetched(&mut state, session_id, selected_node("tile-2"));
+        assert!(result.message.is_none());
+
+        let result = handle_selected_widget_fetched(&mut state, session_id, selected_node("new"));
+        assert!(matches!(
+            result.message,
+            Some(Message::RequestWidgetTree { .. })
+        ));
+        assert_eq!(
+            state
+                .devtools_view_state
+                .inspector
+                .pending_selection
+                .as_deref(),
+            Some("new")
+        );
+
+        let mut root = make_search_tree();
+        let mut added = make_node("Banner");
+        added.value_id = Some("new".to_string());
+        root.children[0].children.push(added);
+        handle_widget_tree_fetched(&mut state, session_id, Box::new(root));
+
+        assert!(state
+            .devtools_view_state
+            .inspector
+            .pending_selection
+            .is_none());
+        assert_eq!(selected_description(&state).as_deref(), Some("Banner"));
+    }
+
+    #[test]
+    fn test_navigation_event_refreshes_tree_in_select_mode() {
+        let mut state = make_search_state();
+        let session_id = active_session_id(&state);
+        assert!(handle_navigation_event(&mut state, session_id)
+            .message
+            .is_none());
+
+        state.devtools_view_state.inspector.select_mode = true;
+        assert!(matches!(
+            handle_navigation_event(&mut state, session_id).message,
+            Some(Message::RequestWidgetTree { .. })
+        ));
+    }
+
+    // ── Properties pane ───────────────────────────────────────────────────────
+
+    fn make_details() -> Box<fdemon_core::DiagnosticsNode> {
+        Box::new(
+            serde_json::from_value(serde_json::json!({
+                "description": "MaterialApp",
+                "valueId": "app",
+                "properties": [
+                    {"name": "title", "description": "\"Demo\"", "defaultValue": "\"\""},
+                    {"name": "theme", "description": "ThemeData", "properties": [
+                        {"name": "brightness", "description": "dark"}
+                    ]},
+                    {"name": "debugLabel", "description": "x", "level": "hidden"}
+                ]
+            }))
+            .expect("valid DiagnosticsNode"),
+        )
+    }
+
+    #[test]
+    fn test_toggle_detail_view_fetches_properties_for_selection() {
+        let mut state = make_search_state();
+        let result = handle_toggle_detail_view(&mut state);
+        let inspector = &state.devtools_view_state.inspector;
+        assert_eq!(inspector.detail_view, InspectorDetailView::Properties);
+        assert!(inspector.properties_loading);
+        assert!(matches!(
+            result.action,
+            Some(UpdateAction::FetchWidgetProperties { ref node_id, .. }) if node_id == "app"
+        ));
+
+        // Toggling again while the same node is in flight does not re-fetch.
+        handle_toggle_detail_view(&mut state);
+        let result = handle_toggle_detail_view(&mut state);
+        assert!(result.action.is_none());
+    }
+
+    #[test]
+    fn test_properties_fetched_discards_stale_node() {
+        let mut state = make_search_state();
+        let session_id = active_session_id(&state);
+        handle_toggle_detail_view(&mut state);
+
+        handle_widget_properties_fetched(&mut state, session_id, "other".into(), make_details());
+        assert!(state.devtools_view_state.inspector.properties.is_none());
+
+        handle_widget_properties_fetched(&mut state, session_id, "app".into(), make_details());
+        let inspector = &state.devtools_view_state.inspector;
+        assert!(!inspector.properties_loading);
+        // The hidden property is not listed.
+        assert_eq!(inspector.visible_properties().len(), 2);
+    }
+
+    #[test]
+    fn test_properties_navigate_expands_nested_and_collapses_to_parent() {
+        let mut state = make_search_state();
+        let session_id = active_session_id(&state);
+        handle_toggle_focus(&mut state);
+        assert!(state.devtools_view_state.inspector.properties_focused);
+        handle_widget_properties_fetched(&mut state, session_id, "app".into(), make_details());
+
+        handle_properties_navigate(&mut state, InspectorNav::Down);
+        handle_properties_navigate(&mut state, InspectorNav::Expand);
+        handle_properties_navigate(&mut state, InspectorNav::Down);
+        let inspector = &state.devtools_view_state.inspector;
+        let rows = inspector.visible_properties();
+        assert_eq!(rows.len(), 3);
+        assert_eq!(inspector.properties_selected, 2);
+        assert_eq!(rows[2].1.name.as_deref(), Some("brightness"));
+        assert_eq!(rows[2].2, 1);
+
+        handle_properties_navigate(&mut state, InspectorNav::Collapse);
+        assert_eq!(state.devtools_view_state.inspector.properties_selected, 1);
+        handle_properties_navigate(&mut state, InspectorNav::Collapse);
+        assert_eq!(
+            state
+                .devtools_view_state
+                .inspector
+                .visible_properties()
+                .len(),
+            2
+        );
+    }
+
+    #[test]
+    fn test_tree_navigation_in_properties_view_fetches_properties() {
+        let mut state = make_search_state();
+        state.devtools_view_state.inspector.detail_view = InspectorDetailView::Properties;
+        let result = handle_inspector_navigate(&mut state, InspectorNav::Down);
+        assert!(matches!(
+            result.action,
+            Some(UpdateAction::FetchWidgetProperties { ref node_id, .. }) if node_id == "scaffold"
+        ));
+        assert!(state
+            .devtools_view_state
+            .inspector
+            .pending_node_id
+            .is_none());
+    }
 }
@@ -5,13 +5,21 @@
 //! launching, and debug overlay toggling.
 //!
 //! Sub-modules:
-//! - `inspector`: Widget tree fetch handlers, inspector navigation, and layout data handlers
+//! - `inspector`: Widget tree fetch handlers, inspector navigation, select mode, and layout data handlers
 //! - `performance`: Frame selection, memory sample, and allocation profile handlers
+//! - `dumps`: Text dump fetch, navigation, search and save handlers
+//! - `profiler`: CPU recording, call tree view and navigation handlers
+//! - `console`: VM Service console input, RPC, stream tail and output handlers
+//! - `extensions`: App-registered service extension listing and button handlers
 
+pub(crate) mod console;
 pub(crate) mod debug;
+pub(crate) mod dumps;
+pub(crate) mod extensions;
 pub mod inspector;
 pub(crate) mod network;
 pub(crate) mod performance;
+pub(crate) mod profiler;
 
 pub use inspector::{
     handle_inspector_navigate, handle_layout_data_fetch_failed, handle_layout_data_fetch_timeout,
@@ -25,9 +33,10 @@ pub(crate) use performance::{
 };
 
 use crate::handler::{UpdateAction, UpdateResult};
-use crate::message::DebugOverlayKind;
-use crate::session::SessionId;
+use crate::message::{DebugOverlayKind, DebugOverrideKind, Message};
+use crate::session::{SessionHandle, SessionId};
 use crate::state::{AppState, DevToolsError, DevToolsPanel, VmConnectionStatus};
+use fdemon_core::{LogEntry, LogSource};
 
 /// Map a raw RPC error string to a user-friendly [`DevToolsError`].
 ///
@@ -94,10 +103,29 @@ pub fn parse_default_panel(panel: &str) -> DevToolsPanel {
     match panel {
         "performance" => DevToolsPanel::Performance,
         "network" | "net" => DevToolsPanel::Network,
+        "dumps" => DevToolsPanel::Dumps,
+        "profiler" | "cpu" => DevToolsPanel::Profiler,
+        "console" => DevToolsPanel::Console,
+        "extensions" | "ext" => DevToolsPanel::Extensions,
         _ => DevToolsPanel::Inspector, // "layout" falls through to Inspector
     }
 }
 
+/// The `default_panel` config string for a [`DevToolsPanel`] variant.
+///
+/// Inverse of [`parse_default_panel`]; used when saving the workspace.
+pub fn panel_config_name(panel: DevToolsPanel) -> &'static str {
+    match panel {
+        DevToolsPanel::Inspector => "inspector",
+        DevToolsPanel::Performance => "performance",
+        DevToolsPanel::Network => "network",
+        DevToolsPanel::Dumps => "dumps",
+        DevToolsPanel::Profiler => "profiler",
+        DevToolsPanel::Console => "console",
+        DevToolsPanel::Extensions => "extensions",
+    }
+}
+
 /// Handle entering DevTools mode from Normal mode.
 ///
 /// Sets default panel from config, transitions to DevTools mode, and
@@ -228,6 +256,18 @@ pub fn handle_enter_devtools_mode(state: &mut AppState) -> UpdateResult {
         }
     }
 
+    if state.devtools_view_state.active_panel == DevToolsPanel::Dumps {
+        return dumps::fetch_if_empty(state);
+    }
+
+    if state.devtools_view_state.active_panel == DevToolsPanel::Console {
+        return console::fetch_extensions_if_needed(state);
+    }
+
+    if state.devtools_view_state.active_panel == DevToolsPanel::Extensions {
+        return extensions::fetch_on_show(state);
+    }
+
     UpdateResult::none()
 }
 
@@ -266,10 +306,18 @@ pub fn handle_exit_devtools_mode(state: &mut AppState) -> UpdateResult {
     if let Some(handle) = state.session_manager.selected() {
         if handle.session.vm_connected {
             let session_id = handle.session.id;
-            return UpdateResult::action(UpdateAction::DisposeDevToolsGroups {
+            let action = UpdateAction::DisposeDevToolsGroups {
                 session_id,
                 vm_handle: None, // hydrated by process.rs
-            });
+            };
+            // Hand taps back to the app once the inspector is no longer visible.
+            if state.devtools_view_state.inspector.select_mode {
+                return UpdateResult::message_and_action(
+                    Message::InspectorSetSelectMode { enabled: false },
+                    action,
+                );
+            }
+            return UpdateResult::action(action);
         }
     }
 
@@ -277,8 +325,10 @@ pub fn handle_exit_devtools_mode(state: &mut AppState) -> UpdateResult {
 }
 
 /// Handle switching DevTools sub-panel. Auto-fetches data when switching to
-/// Inspector (widget tree). Pauses/unpauses allocation polling based on whether
-/// the Performance panel is becoming visible or hidden.
+/// Inspector (widget tree), Dumps (the selected text dump) or Console (the
+/// service extensions used for completion). Pauses/unpauses
+/// allocation polling based on whether the Performance panel is becoming
+/// visible or hidden.
 pub fn handle_switch_panel(state: &mut AppState, panel: DevToolsPanel) -> UpdateResult {
     // Before switching, check if we are leaving the Performance panel — if so,
     // pause allocation polling. The `watch` channel coalesces rapid toggles so
@@ -376,6 +426,10 @@ pub fn handle_switch_panel(state: &mut AppState, panel: DevToolsPanel) -> Update
                 }
             }
         }
+        DevToolsPanel::Dumps => return dumps::fetch_if_empty(state),
+        DevToolsPanel::Profiler => {}
+        DevToolsPanel::Console => return console::fetch_extensions_if_needed(state),
+        DevToolsPanel::Extensions => return extensions::fetch_on_show(state),
     }
 
     UpdateResult::none()
@@ -400,6 +454,49 @@ pub fn handle_open_browser_devtools(state: &AppState) -> UpdateResult {
     UpdateResult::action(UpdateAction::OpenBrowserDevTools { url, browser })
 }
 
+/// Handle switching DevTools memory, allocation and CPU data to the next
+/// isolate of the selected session, wrapping back to the main isolate.
+pub fn handle_cycle_isolate(state: &mut AppState) -> UpdateResult {
+    let Some(handle) = state.session_manager.selected_mut() else {
+        return UpdateResult::none();
+    };
+    let isolates = handle.session.debug.devtools_isolates();
+    if isolates.len() < 2 {
+        handle.session.add_log(LogEntry::info(
+            LogSource::App,
+            "No background isolates to switch to",
+        ));
+        return UpdateResult::none();
+    }
+    let current = isolates
+        .iter()
+        .position(|(id, _)| *id == handle.session.performance.isolate_id.as_deref())
+        .unwrap_or(0);
+    let (id, name) = isolates[(current + 1) % isolates.len()];
+    let (id, name) = (id.map(String::from), name.to_string());
+
+    set_devtools_isolate(handle, id);
+    handle.session.add_log(LogEntry::info(
+        LogSource::App,
+        format!("DevTools isolate: {name}"),
+    ));
+
+    // Each isolate registers its own service extensions.
+    if state.devtools_view_state.active_panel == DevToolsPanel::Extensions {
+        return extensions::fetch_on_show(state);
+    }
+    UpdateResult::none()
+}
+
+/// Point per-isolate DevTools RPCs at `isolate_id` (`None` for the main
+/// isolate) and drop the data read from the previous isolate.
+pub(crate) fn set_devtools_isolate(handle: &mut SessionHandle, isolate_id: Option<String>) {
+    if let Some(vm_handle) = &handle.vm_request_handle {
+        vm_handle.set_target_isolate(isolate_id.clone());
+    }
+    handle.session.performance.set_isolate(isolate_id);
+}
+
 /// Handle debug overlay toggle result from VM Service.
 pub fn handle_debug_overlay_toggled(
     state: &mut AppState,
@@ -416,6 +513,81 @@ pub fn handle_debug_overlay_toggled(
         DebugOverlayKind::PerformanceOverlay => {
             state.devtools_view_state.overlay_performance = enabled;
         }
+        DebugOverlayKind::SlowAnimations => {
+            state.devtools_view_state.overlay_slow_animations = enabled;
+        }
+        DebugOverlayKind::DebugBanner => {
+            state.devtools_view_state.overlay_banner_hidden = !enabled;
+        }
+        DebugOverlayKind::InvertOversizedImages => {
+            state.devtools_view_state.overlay_invert_oversized_images = enabled;
+        }
+        DebugOverlayKind::ProfileWidgetBuilds => {
+            state.devtools_view_state.overlay_widget_builds_off = !enabled;
+        }
+        DebugOverlayKind::PaintBaselines => {
+            state.devtools_view_state.overlay_paint_baselines = enabled;
+        }
+    }
+    UpdateResult::none()
+}
+
+/// Handle a debug override change result from VM Service.
+pub fn handle_debug_override_changed(
+    state: &mut AppState,
+    kind: DebugOverrideKind,
+    value: Option<String>,
+) -> UpdateResult {
+    match kind {
+        DebugOverrideKind::Platform => state.devtools_view_state.platform_override = value,
+        DebugOverrideKind::Brightness => state.devtools_view_state.brightness_override = value,
+    }
+    UpdateResult::none()
+}
+
+/// Apply the debug overlay and toggle states queried on VM Service connect.
+///
+/// Only applied for the selected session; fields the VM did not report
+/// (extension unavailable) are left unchanged. The reported platform is the
+/// device's own unless overridden, so it is not shown as an override.
+pub fn handle_debug_toggles_queried(
+    state: &mut AppState,
+    session_id: SessionId,
+    queried: fdemon_daemon::vm_service::DebugOverlayState,
+) -> UpdateResult {
+    if state.session_manager.selected_id() != Some(session_id) {
+        return UpdateResult::none();
+    }
+    let view = &mut state.devtools_view_state;
+    let flags = [
+        (queried.repaint_rainbow, &mut view.overlay_repaint_rainbow),
+        (queried.debug_paint, &mut view.overlay_debug_paint),
+        (queried.performance_overlay, &mut view.overlay_performance),
+        (
+            queried.invert_oversized_images,
+            &mut view.overlay_invert_oversized_images,
+        ),
+        (
+            queried.debug_paint_baselines,
+            &mut view.overlay_paint_baselines,
+        ),
+    ];
+    for (value, field) in flags {
+        if let Some(value) = value {
+            *field = value;
+        }
+    }
+    if let Some(allow_banner) = queried.debug_allow_banner {
+        view.overlay_banner_hidden = !allow_banner;
+    }
+    if let Some(widget_builds) = queried.profile_widget_builds {
+        view.overlay_widget_builds_off = !widget_builds;
+    }
+    if let Some(dilation) = queried.time_dilation {
+        view.overlay_slow_animations = dilation > 1.0;
+    }
+    if queried.brightness_override.is_some() {
+        view.brightness_override = queried.brightness_override;
     }
     UpdateResult::none()
 }
@@ -506,10 +678,30 @@ mod tests {
         );
         assert_eq!(parse_default_panel("network"), DevToolsPanel::Network);
         assert_eq!(parse_default_panel("net"), DevToolsPanel::Network);
+        assert_eq!(parse_default_panel("dumps"), DevToolsPanel::Dumps);
+        assert_eq!(parse_default_panel("profiler"), DevToolsPanel::Profiler);
+        assert_eq!(parse_default_panel("console"), DevToolsPanel::Console);
+        assert_eq!(parse_default_panel("extensions"), DevToolsPanel::Extensions);
+        assert_eq!(parse_default_panel("ext"), DevToolsPanel::Extensions);
         assert_eq!(parse_default_panel("invalid"), DevToolsPanel::Inspector); // fallback
         assert_eq!(parse_default_panel(""), DevToolsPanel::Inspector); // empty fallback
     }
 
+    #[test]
+    fn test_panel_config_name_roundtrips() {
+        for panel in [
+            DevToolsPanel::Inspector,
+            DevToolsPanel::Performance,
+            DevToolsPanel::Network,
+            DevToolsPanel::Dumps,
+            DevToolsPanel::Profiler,
+            DevToolsPanel::Console,
+            DevToolsPanel::Extensions,
+        ] {
+            assert_eq!(parse_default_panel(panel_config_name(panel)), panel);
+        }
+    }
+
     #[test]
     fn test_handle_enter_devtools_mode_transitions_ui_mode() {
         let mut state = make_state();
@@ -585,6 +777,79 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_cycle_isolate_switches_and_falls_back_on_exit() {
+        use fdemon_daemon::vm_service::debugger_types::{IsolateEvent, IsolateRef};
+
+        let mut state = make_state_with_session();
+        let session_id = state.session_manager.selected_id().unwrap();
+        let vm_handle = fdemon_daemon::vm_service::VmRequestHandle::new_for_test(Some(
+            "isolates/1".to_string(),
+        ));
+        state
+            .session_manager
+            .get_mut(session_id)
+            .unwrap()
+            .vm_request_handle = Some(vm_handle.clone());
+
+        // Only the main isolate: nothing to switch to.
+        handle_cycle_isolate(&mut state);
+        let perf = &state
+            .session_manager
+            .selected()
+            .unwrap()
+            .session
+            .performance;
+        assert_eq!(perf.isolate_id, None);
+
+        let isolate = |id: &str, name: &str| IsolateRef {
+            id: id.to_string(),
+            name: Some(name.to_string()),
+        };
+        debug::handle_isolates_discovered(
+            &mut state,
+            session_id,
+            vec![
+                isolate("isolates/1", "main"),
+                isolate("isolates/2", "worker"),
+            ],
+        );
+        state
+            .session_manager
+            .get_mut(session_id)
+            .unwrap()
+            .session
+            .performance
+            .allocation_selected = 4;
+
+        handle_cycle_isolate(&mut state);
+        let perf = &state
+            .session_manager
+            .selected()
+            .unwrap()
+            .session
+            .performance;
+        assert_eq!(perf.isolate_id.as_deref(), Some("isolates/2"));
+        assert_eq!(perf.allocation_selected, 0);
+        assert_eq!(vm_handle.target_isolate_id().await.unwrap(), "isolates/2");
+
+        debug::handle_isolate_event(
+            &mut state,
+            session_id,
+            IsolateEvent::IsolateExit {
+                isolate: isolate("isolates/2", "worker"),
+            },
+        );
+        let perf = &state
+            .session_manager
+            .selected()
+            .unwrap()
+            .session
+            .performance;
+        assert_eq!(perf.isolate_id, None);
+        assert_eq!(vm_handle.target_isolate_id().await.unwrap(), "isolates/1");
+    }
+
     #[test]
     fn test_handle_debug_overlay_toggled_repaint_rainbow() {
         let mut state = make_state();
@@ -611,6 +876,70 @@ mod tests {
         assert!(state.devtools_view_state.overlay_performance);
     }
 
+    #[test]
+    fn test_handle_debug_overlay_toggled_banner_is_inverted() {
+        let mut state = make_state();
+        handle_debug_overlay_toggled(&mut state, DebugOverlayKind::DebugBanner, false);
+        assert!(state.devtools_view_state.overlay_banner_hidden);
+        handle_debug_overlay_toggled(&mut state, DebugOverlayKind::DebugBanner, true);
+        assert!(!state.devtools_view_state.overlay_banner_hidden);
+    }
+
+    #[test]
+    fn test_handle_debug_override_changed() {
+        let mut state = make_state();
+        handle_debug_override_changed(&mut state, DebugOverrideKind::Platform, Some("iOS".into()));
+        handle_debug_override_changed(
+            &mut state,
+            DebugOverrideKind::Brightness,
+            Some("Brightness.dark".into()),
+        );
+        assert_eq!(
+            state.devtools_view_state.platform_override.as_deref(),
+            Some("iOS")
+        );
+        assert_eq!(
+            state.devtools_view_state.brightness_override.as_deref(),
+            Some("Brightness.dark")
+        );
+
+        // Cycling back to "default" clears the override indicator.
+        handle_debug_override_changed(&mut state, DebugOverrideKind::Platform, None);
+        assert!(state.devtools_view_state.platform_override.is_none());
+    }
+
+    #[test]
+    fn test_handle_debug_toggles_queried_applies_reported_fields() {
+        let mut state = make_state_with_session();
+        let session_id = state.session_manager.selected_id().unwrap();
+        state.devtools_view_state.overlay_debug_paint = true;
+        let queried = fdemon_daemon::vm_service::DebugOverlayState {
+            repaint_rainbow: Some(true),
+            time_dilation: Some(5.0),
+            debug_allow_banner: Some(false),
+            profile_widget_builds: Some(true),
+            platform_override: Some("android".to_string()),
+            ..Default::default()
+        };
+
+        handle_debug_toggles_queried(&mut state, session_id, queried.clone());
+        let view = &state.devtools_view_state;
+        assert!(view.overlay_repaint_rainbow);
+        assert!(view.overlay_debug_paint, "unreported field is unchanged");
+        assert!(view.overlay_slow_animations);
+        assert!(view.overlay_banner_hidden);
+        assert!(!view.overlay_widget_builds_off);
+        assert!(
+            view.platform_override.is_none(),
+            "the device's own platform is not an override"
+        );
+
+        // Results for a session that is not selected are ignored.
+        let mut state = make_state_with_session();
+        handle_debug_toggles_queried(&mut state, session_id + 100, queried);
+        assert!(!state.devtools_view_state.overlay_repaint_rainbow);
+    }
+
     #[test]
     fn test_percent_encode_uri_uppercase_hex() {
         let uri = "ws://127.0.0.1:12345/abc=/ws";
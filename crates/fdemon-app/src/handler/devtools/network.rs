@@ -5,16 +5,21 @@
 //! recording toggle, and clear operations.
 
 use crate::handler::{UpdateAction, UpdateResult};
-use crate::message::NetworkNav;
+use crate::message::{NetworkNav, WaterfallNav};
 use crate::session::NetworkDetailTab;
 use crate::session::SessionId;
 use crate::state::AppState;
 use fdemon_core::network::{HttpProfileEntry, HttpProfileEntryDetail};
+use fdemon_core::{LogEntry, LogSource};
 use tracing::warn;
 
 /// Number of entries to skip per page-up or page-down navigation.
 const NETWORK_PAGE_STEP: usize = 10;
 
+/// Shown instead of fetching details for a request from an earlier run.
+const PREVIOUS_RUN_DETAIL_ERROR: &str =
+    "Details are not available for requests from a previous run";
+
 /// Shared, abort-able handle to a background monitoring task.
 ///
 /// The `Arc<Mutex<Option<...>>>` wrapper lets the spawn site store the handle
@@ -29,13 +34,28 @@ type SharedTaskHandle = std::sync::Arc<std::sync::Mutex<Option<tokio::task::Join
 /// cursor keeps moving. Entries are only merged into the session's network
 /// state when recording is active, so pausing recording does not flood the
 /// list with stale entries when recording is resumed.
+///
+/// A change of `isolate_id` means the app was hot restarted; with history
+/// persistence enabled the previous run's entries are kept under a new
+/// restart generation instead of colliding with the new isolate's IDs.
 pub(crate) fn handle_http_profile_received(
     state: &mut AppState,
     session_id: SessionId,
+    isolate_id: &str,
     timestamp: i64,
     entries: Vec<HttpProfileEntry>,
 ) -> UpdateResult {
     if let Some(handle) = state.session_manager.get_mut(session_id) {
+        if handle.session.network.observe_isolate(isolate_id) {
+            handle.session.add_log(LogEntry::info(
+                LogSource::App,
+                format!(
+                    "Network history kept across restart (generation {})",
+                    handle.session.network.generation
+                ),
+            ));
+        }
+
         // Always advance the timestamp so the poller stays incremental.
         // This ensures that when recording resumes, only NEW requests appear
         // (not a flood of everything that arrived during the pause).
@@ -46,7 +66,23 @@ pub(crate) fn handle_http_profile_received(
             handle.session.network.merge_entries(entries);
         }
     }
-    UpdateResult::none()
+    spill_action(state, session_id)
+}
+
+/// Drain entries evicted from the session's network history into a
+/// [`UpdateAction::SpillNetworkEntries`] action, if any are pending.
+fn spill_action(state: &mut AppState, session_id: SessionId) -> UpdateResult {
+    let Some(handle) = state.session_manager.get_mut(session_id) else {
+        return UpdateResult::none();
+    };
+    if handle.session.network.pending_spill.is_empty() {
+        return UpdateResult::none();
+    }
+    UpdateResult::action(UpdateAction::SpillNetworkEntries {
+        device_id: handle.session.device_id.clone(),
+        entries: std::mem::take(&mut handle.session.network.pending_spill),
+        max_entries: handle.session.network.max_entries,
+    })
 }
 
 /// Handle full request detail received.
@@ -59,7 +95,7 @@ pub(crate) fn handle_http_request_detail_received(
 ) -> UpdateResult {
     if let Some(handle) = state.session_manager.get_mut(session_id) {
         handle.session.network.loading_detail = false;
-        handle.session.network.selected_detail = Some(detail);
+        handle.session.network.set_selected_detail(detail);
     }
     UpdateResult::none()
 }
@@ -82,18 +118,76 @@ pub(crate) fn handle_http_request_detail_failed(
 /// Handle network extensions unavailable (release mode).
 ///
 /// Marks the session's network state as unavailable and disables recording
-/// so the UI can show a "not available in release mode" message.
+/// so the UI can show a "not available in release mode" message. Recording
+/// stays on when a local proxy is feeding the session, since proxied traffic
+/// does not depend on the VM extensions.
 pub(crate) fn handle_network_extensions_unavailable(
     state: &mut AppState,
     session_id: SessionId,
 ) -> UpdateResult {
     if let Some(handle) = state.session_manager.get_mut(session_id) {
-        handle.session.network.extensions_available = Some(false);
-        handle.session.network.recording = false;
+        let network = &mut handle.session.network;
+        network.extensions_available = Some(false);
+        if !network.proxy_active() {
+            network.recording = false;
+        }
+    }
+    UpdateResult::none()
+}
+
+/// Handle the session's local HTTP proxy starting.
+///
+/// Remembers the advertised address so the Network panel can stay usable
+/// without VM extensions, and logs how the app should pick it up.
+pub(crate) fn handle_network_proxy_started(
+    state: &mut AppState,
+    session_id: SessionId,
+    address: String,
+    dart_define: String,
+) -> UpdateResult {
+    if let Some(handle) = state.session_manager.get_mut(session_id) {
+        handle.session.add_log(LogEntry::info(
+            LogSource::App,
+            format!("HTTP proxy listening on {address} (--dart-define={dart_define}={address})"),
+        ));
+        handle.session.network.proxy_address = Some(address);
+    }
+    UpdateResult::none()
+}
+
+/// Handle a failure to start the session's local HTTP proxy.
+///
+/// The session is launched without the proxy; the failure is only logged.
+pub(crate) fn handle_network_proxy_failed(
+    state: &mut AppState,
+    session_id: SessionId,
+    error: String,
+) -> UpdateResult {
+    if let Some(handle) = state.session_manager.get_mut(session_id) {
+        handle.session.add_log(LogEntry::warn(
+            LogSource::App,
+            format!("HTTP proxy not started: {error}"),
+        ));
     }
     UpdateResult::none()
 }
 
+/// Handle an exchange recorded by the session's local HTTP proxy.
+///
+/// Like polled VM entries, exchanges are dropped while recording is paused.
+pub(crate) fn handle_network_proxy_exchange(
+    state: &mut AppState,
+    session_id: SessionId,
+    detail: Box<HttpProfileEntryDetail>,
+) -> UpdateResult {
+    if let Some(handle) = state.session_manager.get_mut(session_id) {
+        if handle.session.network.recording {
+            handle.session.network.merge_proxy_exchange(detail);
+        }
+    }
+    spill_action(state, session_id)
+}
+
 /// Handle network monitoring task started.
 ///
 /// Stores the shutdown sender, pause sender, and task handle in the session
@@ -289,22 +383,60 @@ pub(crate) fn handle_filter_backspace(state: &mut AppState) -> UpdateResult {
     UpdateResult::none()
 }
 
+/// Toggle the request list between the table and waterfall views.
+pub(crate) fn handle_network_toggle_waterfall(state: &mut AppState) -> UpdateResult {
+    if let Some(handle) = state.session_manager.selected_mut() {
+        handle.session.network.toggle_view_mode();
+    }
+    UpdateResult::none()
+}
+
+/// Zoom or pan the waterfall time axis.
+pub(crate) fn handle_network_waterfall_navigate(
+    state: &mut AppState,
+    nav: WaterfallNav,
+) -> UpdateResult {
+    if let Some(handle) = state.session_manager.selected_mut() {
+        let network = &mut handle.session.network;
+        match nav {
+            WaterfallNav::ZoomIn => network.waterfall_zoom_in(),
+            WaterfallNav::ZoomOut => network.waterfall_zoom_out(),
+            WaterfallNav::PanLeft => network.waterfall_pan_left(),
+            WaterfallNav::PanRight => network.waterfall_pan_right(),
+            WaterfallNav::Reset => network.waterfall_reset(),
+        }
+    }
+    UpdateResult::none()
+}
+
 /// Build a `FetchHttpRequestDetail` action for the currently selected entry.
 ///
-/// Returns `UpdateResult::none()` when there is no active session, no
-/// selection, or no entry at the selected index.
-fn fetch_selected_detail_action(state: &AppState) -> UpdateResult {
+/// Proxy-recorded entries already have their detail cached, so it is shown
+/// directly instead of asking the VM. Returns `UpdateResult::none()` when
+/// there is no active session, no selection, or no entry at the selected index.
+fn fetch_selected_detail_action(state: &mut AppState) -> UpdateResult {
     let Some(session_id) = state.session_manager.selected_id() else {
         return UpdateResult::none();
     };
-    let Some(handle) = state.session_manager.get(session_id) else {
+    let Some(handle) = state.session_manager.get_mut(session_id) else {
         return UpdateResult::none();
     };
-    let Some(entry) = handle.session.network.selected_entry() else {
+    let network = &mut handle.session.network;
+    let Some(entry) = network.selected_entry() else {
         return UpdateResult::none();
     };
 
     let request_id = entry.id.clone();
+    if let Some(detail) = network.proxy_details.get(&request_id).cloned() {
+        network.set_selected_detail(detail);
+        return UpdateResult::none();
+    }
+    if network.is_from_previous_run(&request_id) {
+        // The VM that recorded this request is gone; only the summary remains.
+        network.selected_detail = None;
+        network.last_error = Some(PREVIOUS_RUN_DETAIL_ERROR.to_string());
+        return UpdateResult::none();
+    }
     UpdateResult::action(UpdateAction::FetchHttpRequestDetail {
         session_id,
         request_id,
@@ -376,7 +508,8 @@ mod tests {
         let mut state = make_devtools_state();
         let session_id = active_session_id(&state);
         let entries = vec![make_entry("1", "GET", Some(200))];
-        let result = handle_http_profile_received(&mut state, session_id, 5000, entries);
+        let result =
+            handle_http_profile_received(&mut state, session_id, "isolates/1", 5000, entries);
         assert!(result.action.is_none());
         let handle = state.session_manager.get(session_id).unwrap();
         assert_eq!(handle.session.network.entries.len(), 1);
@@ -388,9 +521,9 @@ mod tests {
         let mut state = make_devtools_state();
         let session_id = active_session_id(&state);
         let entries = vec![make_entry("1", "GET", None)]; // pending
-        handle_http_profile_received(&mut state, session_id, 1000, entries);
+        handle_http_profile_received(&mut state, session_id, "isolates/1", 1000, entries);
         let entries2 = vec![make_entry("1", "GET", Some(200))]; // completed
-        handle_http_profile_received(&mut state, session_id, 2000, entries2);
+        handle_http_profile_received(&mut state, session_id, "isolates/1", 2000, entries2);
         let handle = state.session_manager.get(session_id).unwrap();
         assert_eq!(handle.session.network.entries.len(), 1); // not duplicated
         assert_eq!(handle.session.network.entries[0].status_code, Some(200));
@@ -623,19 +756,238 @@ mod tests {
 
     #[test]
     fn test_fetch_selected_detail_no_session_returns_none() {
-        let state = AppState::new(); // no sessions
-        let result = fetch_selected_detail_action(&state);
+        let mut state = AppState::new(); // no sessions
+        let result = fetch_selected_detail_action(&mut state);
         assert!(result.action.is_none());
     }
 
     #[test]
     fn test_fetch_selected_detail_no_selection_returns_none() {
-        let state = make_devtools_state_with_entries(3);
+        let mut state = make_devtools_state_with_entries(3);
         // No selection set.
-        let result = fetch_selected_detail_action(&state);
+        let result = fetch_selected_detail_action(&mut state);
         assert!(result.action.is_none());
     }
 
+    // ── Local HTTP proxy tests ─────────────────────────────────────────────────
+
+    fn make_proxy_detail(id: &str, status: Option<u16>) -> Box<HttpProfileEntryDetail> {
+        Box::new(HttpProfileEntryDetail {
+            entry: make_entry(id, "GET", status),
+            request_headers: vec![],
+            response_headers: vec![],
+            request_body: vec![],
+            response_body: vec![],
+            events: vec![],
+            connection_info: None,
+        })
+    }
+
+    #[test]
+    fn test_proxy_started_sets_address_and_logs() {
+        let mut state = make_devtools_state();
+        let session_id = active_session_id(&state);
+        handle_network_proxy_started(
+            &mut state,
+            session_id,
+            "10.0.2.2:9000".to_string(),
+            "FDEMON_HTTP_PROXY".to_string(),
+        );
+        let handle = state.session_manager.get(session_id).unwrap();
+        assert_eq!(
+            handle.session.network.proxy_address.as_deref(),
+            Some("10.0.2.2:9000")
+        );
+        assert!(handle
+            .session
+            .logs
+            .iter()
+            .any(|l| l.message.contains("FDEMON_HTTP_PROXY=10.0.2.2:9000")));
+    }
+
+    #[test]
+    fn test_proxy_exchange_respects_recording() {
+        let mut state = make_devtools_state();
+        let session_id = active_session_id(&state);
+        state
+            .session_manager
+            .get_mut(session_id)
+            .unwrap()
+            .session
+            .network
+            .recording = false;
+        handle_network_proxy_exchange(&mut state, session_id, make_proxy_detail("proxy-1", None));
+        let network = &state
+            .session_manager
+            .get(session_id)
+            .unwrap()
+            .session
+            .network;
+        assert!(network.entries.is_empty());
+
+        state
+            .session_manager
+            .get_mut(session_id)
+            .unwrap()
+            .session
+            .network
+            .recording = true;
+        handle_network_proxy_exchange(&mut state, session_id, make_proxy_detail("proxy-1", None));
+        let network = &state
+            .session_manager
+            .get(session_id)
+            .unwrap()
+            .session
+            .network;
+        assert_eq!(network.entries.len(), 1);
+    }
+
+    #[test]
+    fn test_select_proxy_entry_uses_cached_detail() {
+        let mut state = make_devtools_state();
+        let session_id = active_session_id(&state);
+        handle_network_proxy_exchange(
+            &mut state,
+            session_id,
+            make_proxy_detail("proxy-1", Some(200)),
+        );
+        let result = handle_network_select_request(&mut state, Some(0));
+        assert!(result.action.is_none(), "proxy entries need no VM fetch");
+        let network = &state
+            .session_manager
+            .get(session_id)
+            .unwrap()
+            .session
+            .network;
+        assert_eq!(
+            network.selected_detail.as_ref().unwrap().entry.id,
+            "proxy-1"
+        );
+    }
+
+    #[test]
+    fn test_extensions_unavailable_keeps_recording_with_proxy() {
+        let mut state = make_devtools_state();
+        let session_id = active_session_id(&state);
+        state
+            .session_manager
+            .get_mut(session_id)
+            .unwrap()
+            .session
+            .network
+            .proxy_address = Some("127.0.0.1:9000".to_string());
+        handle_network_extensions_unavailable(&mut state, session_id);
+        let network = &state
+            .session_manager
+            .get(session_id)
+            .unwrap()
+            .session
+            .network;
+        assert_eq!(network.extensions_available, Some(false));
+        assert!(network.recording);
+    }
+
+    fn network_mut(
+        state: &mut AppState,
+        session_id: SessionId,
+    ) -> &mut crate::session::NetworkState {
+        &mut state
+            .session_manager
+            .get_mut(session_id)
+            .unwrap()
+            .session
+            .network
+    }
+
+    #[test]
+    fn test_isolate_change_keeps_history_when_persisting() {
+        let mut state = make_devtools_state();
+        let session_id = active_session_id(&state);
+        network_mut(&mut state, session_id).persist_history = true;
+        let first = vec![make_entry("1", "GET", Some(200))];
+        handle_http_profile_received(&mut state, session_id, "isolates/1", 1000, first);
+        let second = vec![make_entry("1", "POST", Some(201))];
+        handle_http_profile_received(&mut state, session_id, "isolates/2", 2000, second);
+
+        let network = network_mut(&mut state, session_id);
+        assert_eq!(
+            network.entries.len(),
+            2,
+            "restart must not overwrite old entry"
+        );
+        assert_eq!(network.generation, 1);
+        assert_eq!(network.entries[0].id, "isolates/1/1");
+        assert!(network.is_from_previous_run("isolates/1/1"));
+        assert!(!network.is_from_previous_run("1"));
+    }
+
+    #[test]
+    fn test_isolate_change_without_persistence_merges_as_before() {
+        let mut state = make_devtools_state();
+        let session_id = active_session_id(&state);
+        let first = vec![make_entry("1", "GET", Some(200))];
+        handle_http_profile_received(&mut state, session_id, "isolates/1", 1000, first);
+        let second = vec![make_entry("1", "POST", Some(201))];
+        handle_http_profile_received(&mut state, session_id, "isolates/2", 2000, second);
+
+        let network = network_mut(&mut state, session_id);
+        assert_eq!(network.entries.len(), 1);
+        assert_eq!(network.generation, 0);
+    }
+
+    #[test]
+    fn test_select_previous_run_entry_skips_vm_fetch() {
+        let mut state = make_devtools_state();
+        let session_id = active_session_id(&state);
+        network_mut(&mut state, session_id).persist_history = true;
+        let first = vec![make_entry("1", "GET", Some(200))];
+        handle_http_profile_received(&mut state, session_id, "isolates/1", 1000, first);
+        handle_http_profile_received(&mut state, session_id, "isolates/2", 2000, vec![]);
+
+        let result = handle_network_select_request(&mut state, Some(0));
+        assert!(
+            result.action.is_none(),
+            "previous-run entries cannot be fetched"
+        );
+        let network = network_mut(&mut state, session_id);
+        assert_eq!(
+            network.last_error.as_deref(),
+            Some(PREVIOUS_RUN_DETAIL_ERROR)
+        );
+    }
+
+    #[test]
+    fn test_eviction_with_spill_returns_spill_action() {
+        let mut state = make_devtools_state();
+        let session_id = active_session_id(&state);
+        {
+            let network = network_mut(&mut state, session_id);
+            network.spill_to_disk = true;
+            network.max_entries = 1;
+        }
+        let entries = vec![
+            make_entry("1", "GET", Some(200)),
+            make_entry("2", "GET", Some(200)),
+        ];
+        let result =
+            handle_http_profile_received(&mut state, session_id, "isolates/1", 1000, entries);
+        match result.action {
+            Some(UpdateAction::SpillNetworkEntries {
+                device_id,
+                entries,
+                max_entries,
+            }) => {
+                assert_eq!(device_id, "test-device");
+                assert_eq!(max_entries, 1);
+                assert_eq!(entries.len(), 1);
+                assert_eq!(entries[0].0, 0);
+                assert_eq!(entries[0].1.id, "1");
+            }
+            other => panic!("expected SpillNetworkEntries, got {other:?}"),
+        }
+        assert!(network_mut(&mut state, session_id).pending_spill.is_empty());
+    }
+
     // ── Filter input mode tests ────────────────────────────────────────────────
 
     #[test]
@@ -800,4 +1152,30 @@ mod tests {
             assert!(handle.network_pause_tx.is_some());
         });
     }
+
+    #[test]
+    fn test_handle_toggle_waterfall_switches_view_mode() {
+        use crate::session::NetworkViewMode;
+
+        let mut state = make_devtools_state();
+        handle_network_toggle_waterfall(&mut state);
+        let handle = state.session_manager.selected().unwrap();
+        assert_eq!(handle.session.network.view_mode, NetworkViewMode::Waterfall);
+    }
+
+    #[test]
+    fn test_handle_waterfall_navigate_zoom_and_reset() {
+        let mut state = make_devtools_state();
+        handle_network_waterfall_navigate(&mut state, WaterfallNav::ZoomIn);
+        handle_network_waterfall_navigate(&mut state, WaterfallNav::PanRight);
+        {
+            let network = &state.session_manager.selected().unwrap().session.network;
+            assert_eq!(network.waterfall_zoom, 1);
+            assert_eq!(network.waterfall_pan, 1);
+        }
+        handle_network_waterfall_navigate(&mut state, WaterfallNav::Reset);
+        let network = &state.session_manager.selected().unwrap().session.network;
+        assert_eq!(network.waterfall_zoom, 0);
+        assert_eq!(network.waterfall_pan, 0);
+    }
 }
@@ -1,13 +1,23 @@
 //! Performance panel handlers.
 //!
 //! Handles frame selection, allocation profile updates, and rich memory samples
-//! for the Performance panel's bar chart and time-series views.
-
-use crate::handler::UpdateResult;
-use crate::session::AllocationSortColumn;
-use crate::session::SessionId;
+//! for the Performance panel's bar chart and time-series views, plus widget
+//! rebuild tracking and its hot-spot table, performance data export,
+//! baseline save/compare, allocation diffs, the instance browser, and leak
+//! hints.
+
+use crate::editor::open_in_editor;
+use crate::handler::screenshot::file_url;
+use crate::handler::{UpdateAction, UpdateResult};
+use crate::hyperlinks::FileReference;
+use crate::session::{AllocationSortColumn, InstanceBrowser, RebuildSortColumn};
+use crate::session::{Session, SessionId};
 use crate::state::AppState;
-use fdemon_core::performance::{AllocationProfile, MemorySample};
+use fdemon_core::performance::{AllocationProfile, MemorySample, RebuiltWidgets};
+use fdemon_core::{
+    HeapDiff, InstanceDetails, InstanceSet, LogEntry, LogSource, PerfBaseline, PerfCapture,
+    PerfComparison,
+};
 
 /// Handle frame selection by direct index.
 ///
@@ -30,14 +40,19 @@ pub(crate) fn handle_select_performance_frame(
 /// Handle rich memory sample received from the VM service.
 ///
 /// Pushes the sample into `PerformanceState::memory_samples` for the session
-/// identified by `session_id`. No-op if the session does not exist.
+/// identified by `session_id`. No-op if the session does not exist or the
+/// sample was taken from an isolate that is no longer picked.
 pub(crate) fn handle_memory_sample_received(
     state: &mut AppState,
     session_id: SessionId,
+    isolate_id: Option<String>,
     sample: MemorySample,
 ) -> UpdateResult {
     if let Some(handle) = state.session_manager.get_mut(session_id) {
-        handle.session.performance.memory_samples.push(sample);
+        let perf = &mut handle.session.performance;
+        if perf.isolate_id == isolate_id {
+            perf.memory_samples.push(sample);
+        }
     }
     UpdateResult::none()
 }
@@ -46,13 +61,22 @@ pub(crate) fn handle_memory_sample_received(
 ///
 /// Replaces `PerformanceState::allocation_profile` with the new snapshot for
 /// the session identified by `session_id`. Only the most recent profile is
-/// retained in state. No-op if the session does not exist.
+/// retained in state. No-op if the session does not exist or the profile
+/// was taken from an isolate that is no longer picked.
 pub(crate) fn handle_allocation_profile_received(
     state: &mut AppState,
     session_id: SessionId,
+    isolate_id: Option<String>,
     profile: AllocationProfile,
 ) -> UpdateResult {
     if let Some(handle) = state.session_manager.get_mut(session_id) {
+        if handle.session.performance.isolate_id != isolate_id {
+            tracing::debug!(
+                "Dropping allocation profile from a previously picked isolate (session {})",
+                session_id
+            );
+            return UpdateResult::none();
+        }
         tracing::debug!(
             "Allocation profile received for session {}: {} classes",
             session_id,
@@ -78,6 +102,622 @@ pub(crate) fn handle_toggle_allocation_sort(state: &mut AppState) -> UpdateResul
     UpdateResult::none()
 }
 
+/// Ask the device to flip widget rebuild tracking for the selected session.
+///
+/// The state is only updated once the device confirms the change
+/// (`RebuildTrackingChanged`). No-op when the VM Service is not connected.
+pub(crate) fn handle_toggle_rebuild_tracking(state: &mut AppState) -> UpdateResult {
+    let Some(handle) = state.session_manager.selected() else {
+        return UpdateResult::none();
+    };
+    if !handle.session.vm_connected {
+        return UpdateResult::none();
+    }
+    UpdateResult::action(UpdateAction::SetRebuildTracking {
+        session_id: handle.session.id,
+        enabled: !handle.session.performance.rebuild_tracking,
+        vm_handle: None,
+    })
+}
+
+/// Apply the rebuild tracking state reported by the device.
+///
+/// Turning tracking on starts a fresh count so the table reflects only the
+/// interaction being investigated.
+pub(crate) fn handle_rebuild_tracking_changed(
+    state: &mut AppState,
+    session_id: SessionId,
+    enabled: bool,
+) -> UpdateResult {
+    if let Some(handle) = state.session_manager.get_mut(session_id) {
+        let perf = &mut handle.session.performance;
+        if enabled && !perf.rebuild_tracking {
+            perf.rebuild_stats.reset_counts();
+            perf.rebuild_selected = 0;
+        }
+        perf.rebuild_tracking = enabled;
+    }
+    UpdateResult::none()
+}
+
+/// Fold a `Flutter.RebuiltWidgets` event into the session's rebuild counts.
+///
+/// Events that arrive after tracking was turned off are dropped.
+pub(crate) fn handle_rebuilt_widgets(
+    state: &mut AppState,
+    session_id: SessionId,
+    rebuilds: RebuiltWidgets,
+) -> UpdateResult {
+    if let Some(handle) = state.session_manager.get_mut(session_id) {
+        let perf = &mut handle.session.performance;
+        if perf.rebuild_tracking {
+            perf.rebuild_stats.record(rebuilds);
+        }
+    }
+    UpdateResult::none()
+}
+
+/// Toggle the rebuild table sort between [`RebuildSortColumn::ByTotal`] and
+/// [`RebuildSortColumn::ByLastFrame`]. Resets the row selection.
+pub(crate) fn handle_toggle_rebuild_sort(state: &mut AppState) -> UpdateResult {
+    if let Some(handle) = state.session_manager.selected_mut() {
+        let perf = &mut handle.session.performance;
+        perf.rebuild_sort = match perf.rebuild_sort {
+            RebuildSortColumn::ByTotal => RebuildSortColumn::ByLastFrame,
+            RebuildSortColumn::ByLastFrame => RebuildSortColumn::ByTotal,
+        };
+        perf.rebuild_selected = 0;
+    }
+    UpdateResult::none()
+}
+
+/// Select a row in the rebuild table, clamped to the number of rows.
+pub(crate) fn handle_select_rebuild_row(state: &mut AppState, index: usize) -> UpdateResult {
+    if let Some(handle) = state.session_manager.selected_mut() {
+        let perf = &mut handle.session.performance;
+        let rows = perf.sorted_rebuilds().len();
+        perf.rebuild_selected = index.min(rows.saturating_sub(1));
+    }
+    UpdateResult::none()
+}
+
+/// Reset all rebuild counts for the selected session.
+pub(crate) fn handle_clear_rebuild_counts(state: &mut AppState) -> UpdateResult {
+    if let Some(handle) = state.session_manager.selected_mut() {
+        handle.session.performance.rebuild_stats.reset_counts();
+        handle.session.performance.rebuild_selected = 0;
+    }
+    UpdateResult::none()
+}
+
+/// Set a session's frame budget from the engine-reported refresh rate, or
+/// from `devtools.refresh_rates` when it configures one for the device.
+pub(crate) fn handle_display_refresh_rate(
+    state: &mut AppState,
+    session_id: SessionId,
+    refresh_rate_hz: f64,
+) -> UpdateResult {
+    let devtools = &state.settings.devtools;
+    let Some(handle) = state.session_manager.get_mut(session_id) else {
+        return UpdateResult::none();
+    };
+    let session = &mut handle.session;
+    let configured = devtools.refresh_rate_for(&session.device_id, &session.device_name);
+    tracing::debug!(
+        "Session {} display refresh rate: {:.0} Hz (configured: {:?})",
+        session_id,
+        refresh_rate_hz,
+        configured
+    );
+    session
+        .performance
+        .set_display_refresh_rate(configured.unwrap_or(refresh_rate_hz));
+    UpdateResult::none()
+}
+
+/// Export the selected session's buffered frame, memory and GC data.
+///
+/// Logs a warning instead when nothing has been collected yet.
+pub(crate) fn handle_export_performance(state: &mut AppState) -> UpdateResult {
+    let Some(handle) = state.session_manager.selected_mut() else {
+        return UpdateResult::none();
+    };
+    let session = &mut handle.session;
+    let capture = session_capture(session);
+    if capture.is_empty() {
+        session.add_log(LogEntry::warn(
+            LogSource::App,
+            "Performance export skipped: no frame, memory or GC data yet",
+        ));
+        return UpdateResult::none();
+    }
+    UpdateResult::action(UpdateAction::ExportPerformance {
+        session_id: session.id,
+        capture,
+    })
+}
+
+/// Log where an export was written to the session it came from.
+pub(crate) fn handle_performance_exported(
+    state: &mut AppState,
+    session_id: SessionId,
+    result: Result<std::path::PathBuf, String>,
+) -> UpdateResult {
+    let entry = match result {
+        Ok(dir) => LogEntry::info(
+            LogSource::App,
+            format!("Performance data exported: {}", file_url(&dir)),
+        ),
+        Err(e) => {
+            tracing::warn!(
+                "Performance export failed for session {}: {}",
+                session_id,
+                e
+            );
+            LogEntry::error(LogSource::App, format!("Performance export failed: {e}"))
+        }
+    };
+    if let Some(handle) = state.session_manager.get_mut(session_id) {
+        handle.session.add_log(entry);
+    }
+    UpdateResult::none()
+}
+
+/// Copy of the frame, memory and GC data buffered for `session`.
+fn session_capture(session: &Session) -> PerfCapture {
+    let perf = &session.performance;
+    PerfCapture {
+        device: session.device_name.clone(),
+        frames: perf.frame_history.iter().cloned().collect(),
+        memory: perf.memory_samples.iter().cloned().collect(),
+        gc: perf.gc_history.iter().cloned().collect(),
+        frame_budget_micros: Some(perf.frame_budget_micros),
+    }
+}
+
+/// Summarise the selected session's buffered frames as baseline `name`.
+///
+/// Logs a warning and returns `None` when no frames have been collected yet.
+fn selected_baseline(
+    state: &mut AppState,
+    name: Option<String>,
+) -> Option<(SessionId, PerfBaseline)> {
+    let name = name.unwrap_or_else(|| state.settings.devtools.baseline.name.clone());
+    let handle = state.session_manager.selected_mut()?;
+    let session = &mut handle.session;
+    let baseline = PerfBaseline::from_capture(&name, &session_capture(session));
+    if baseline.frames == 0 {
+        session.add_log(LogEntry::warn(
+            LogSource::App,
+            format!("Performance baseline '{name}' skipped: no frame data yet"),
+        ));
+        return None;
+    }
+    Some((session.id, baseline))
+}
+
+/// Save the selected session's metrics as a baseline.
+pub(crate) fn handle_save_baseline(state: &mut AppState, name: Option<String>) -> UpdateResult {
+    match selected_baseline(state, name) {
+        Some((session_id, baseline)) => UpdateResult::action(UpdateAction::SavePerfBaseline {
+            session_id,
+            baseline,
+        }),
+        None => UpdateResult::none(),
+    }
+}
+
+/// Log where a baseline was saved to the session it came from.
+pub(crate) fn handle_baseline_saved(
+    state: &mut AppState,
+    session_id: SessionId,
+    result: Result<std::path::PathBuf, String>,
+) -> UpdateResult {
+    let entry = match result {
+        Ok(path) => LogEntry::info(
+            LogSource::App,
+            format!("Performance baseline saved: {}", file_url(&path)),
+        ),
+        Err(e) => LogEntry::error(
+            LogSource::App,
+            format!("Performance baseline save failed: {e}"),
+        ),
+    };
+    if let Some(handle) = state.session_manager.get_mut(session_id) {
+        handle.session.add_log(entry);
+    }
+    UpdateResult::none()
+}
+
+/// Compare the selected session's metrics against a saved baseline.
+pub(crate) fn handle_compare_baseline(state: &mut AppState, name: Option<String>) -> UpdateResult {
+    let thresholds = state.settings.devtools.baseline.thresholds();
+    let Some((session_id, current)) = selected_baseline(state, name) else {
+        return UpdateResult::none();
+    };
+    if let Some(handle) = state.session_manager.get_mut(session_id) {
+        handle.session.performance.baseline_compares_pending += 1;
+    }
+    UpdateResult::action(UpdateAction::ComparePerfBaseline {
+        session_id,
+        current,
+        thresholds,
+    })
+}
+
+/// Store a comparison for the Performance panel and log a one-line summary,
+/// as a warning when any metric regressed.
+pub(crate) fn handle_baseline_compared(
+    state: &mut AppState,
+    session_id: SessionId,
+    result: Result<PerfComparison, String>,
+) -> UpdateResult {
+    let Some(handle) = state.session_manager.get_mut(session_id) else {
+        return UpdateResult::none();
+    };
+    let session = &mut handle.session;
+    session.performance.baseline_compares_pending = session
+        .performance
+        .baseline_compares_pending
+        .saturating_sub(1);
+    match result {
+        Ok(comparison) => {
+            let regressed: Vec<&str> = comparison
+                .metrics
+                .iter()
+                .filter(|m| m.regressed)
+                .map(|m| m.metric.label())
+                .collect();
+            let entry = if regressed.is_empty() {
+                LogEntry::info(
+                    LogSource::App,
+                    format!("Performance within baseline '{}'", comparison.baseline_name),
+                )
+            } else {
+                LogEntry::warn(
+                    LogSource::App,
+                    format!(
+                        "Performance regressed against baseline '{}': {}",
+                        comparison.baseline_name,
+                        regressed.join(", ")
+                    ),
+                )
+            };
+            session.add_log(entry);
+            session.performance.baseline_comparison = Some(comparison);
+        }
+        Err(e) => {
+            session.add_log(LogEntry::error(
+                LogSource::App,
+                format!("Performance baseline comparison failed: {e}"),
+            ));
+        }
+    }
+    UpdateResult::none()
+}
+
+/// Capture the allocation profile of the selected session for a diff.
+///
+/// No-op when the VM Service is not connected or a capture is in flight.
+pub(crate) fn handle_capture_allocation_diff(state: &mut AppState) -> UpdateResult {
+    let Some(handle) = state.session_manager.selected_mut() else {
+        return UpdateResult::none();
+    };
+    let session = &mut handle.session;
+    if !session.vm_connected || session.performance.diff_pending {
+        return UpdateResult::none();
+    }
+    session.performance.diff_pending = true;
+    UpdateResult::action(UpdateAction::CaptureAllocationDiff {
+        session_id: session.id,
+        vm_handle: None, // hydrated by process.rs
+    })
+}
+
+/// Keep the first allocation capture as the base and diff later ones against it,
+/// logging a one-line summary of each step.
+pub(crate) fn handle_allocation_diff_captured(
+    state: &mut AppState,
+    session_id: SessionId,
+    result: Result<AllocationProfile, String>,
+) -> UpdateResult {
+    let Some(handle) = state.session_manager.get_mut(session_id) else {
+        return UpdateResult::none();
+    };
+    let session = &mut handle.session;
+    session.performance.diff_pending = false;
+    let entry = match result {
+        Ok(profile) => match &session.performance.diff_base {
+            None => {
+                let entry = LogEntry::info(
+                    LogSource::App,
+                    format!(
+                        "Allocation baseline taken ({} classes). Repeat the interaction and press H again to diff",
+                        profile.members.len()
+                    ),
+                );
+                session.performance.diff_base = Some(profile);
+                entry
+            }
+            Some(base) => {
+                let diff = HeapDiff::between(base, &profile);
+                let grown = diff.grown_count();
+                let entry = LogEntry::info(
+                    LogSource::App,
+                    format!(
+                        "Allocation diff: net {}, {} {} grew",
+                        format_signed_bytes(diff.total_size_delta()),
+                        grown,
+                        if grown == 1 { "class" } else { "classes" }
+                    ),
+                );
+                session.performance.heap_diff = Some(diff);
+                entry
+            }
+        },
+        Err(e) => LogEntry::error(
+            LogSource::App,
+            format!("Allocation diff capture failed: {e}"),
+        ),
+    };
+    session.add_log(entry);
+    UpdateResult::none()
+}
+
+/// Drop the base allocation capture and its diff for the selected session.
+pub(crate) fn handle_clear_allocation_diff(state: &mut AppState) -> UpdateResult {
+    if let Some(handle) = state.session_manager.selected_mut() {
+        handle.session.performance.diff_base = None;
+        handle.session.performance.heap_diff = None;
+    }
+    UpdateResult::none()
+}
+
+/// Request a leak hint checkpoint after a hot reload or route change.
+///
+/// No-op when leak hints are disabled, the VM Service is not connected, or a
+/// checkpoint is already waiting for its profile.
+pub(crate) fn handle_leak_checkpoint(state: &mut AppState, session_id: SessionId) -> UpdateResult {
+    let Some(handle) = state.session_manager.get_mut(session_id) else {
+        return UpdateResult::none();
+    };
+    if !handle.session.vm_connected {
+        return UpdateResult::none();
+    }
+    let marked = handle
+        .session
+        .performance
+        .leak_tracker
+        .as_mut()
+        .is_some_and(|tracker| tracker.mark_checkpoint());
+    if !marked {
+        return UpdateResult::none();
+    }
+    UpdateResult::action(UpdateAction::CaptureLeakCheckpoint {
+        session_id,
+        vm_handle: None,
+    })
+}
+
+/// Record a leak hint checkpoint and log a warning for each newly flagged
+/// class. The allocation table keeps showing the polled profile.
+pub(crate) fn handle_leak_checkpoint_captured(
+    state: &mut AppState,
+    session_id: SessionId,
+    result: Result<AllocationProfile, String>,
+) -> UpdateResult {
+    let Some(handle) = state.session_manager.get_mut(session_id) else {
+        return UpdateResult::none();
+    };
+    let perf = &mut handle.session.performance;
+    let Some(tracker) = &mut perf.leak_tracker else {
+        return UpdateResult::none();
+    };
+    let profile = match result {
+        Ok(profile) => profile,
+        Err(e) => {
+            tracker.cancel_checkpoint();
+            tracing::debug!("Leak checkpoint failed for session {}: {}", session_id, e);
+            return UpdateResult::none();
+        }
+    };
+
+    let new_hints = tracker.record_checkpoint(&profile);
+    for hint in new_hints {
+        let counts: Vec<String> = hint.counts.iter().map(u64::to_string).collect();
+        handle.session.add_log(LogEntry::warn(
+            LogSource::App,
+            format!(
+                "Possible leak: {} grew after {} reloads/route changes in a row ({} live) — check dispose()",
+                hint.class_name,
+                hint.counts.len() - 1,
+                counts.join(" → ")
+            ),
+        ));
+    }
+    UpdateResult::none()
+}
+
+/// Select a row of the allocation table, clamped to the rows shown.
+pub(crate) fn handle_select_allocation_row(state: &mut AppState, index: usize) -> UpdateResult {
+    if let Some(handle) = state.session_manager.selected_mut() {
+        let perf = &mut handle.session.performance;
+        let rows = perf.allocation_rows().len();
+        perf.allocation_selected = index.min(rows.saturating_sub(1));
+    }
+    UpdateResult::none()
+}
+
+/// Open the instance browser for the class in the selected allocation table
+/// row and list its instances.
+///
+/// No-op when the VM Service is not connected or the row has no class ID.
+pub(crate) fn handle_open_instance_browser(state: &mut AppState) -> UpdateResult {
+    let Some(handle) = state.session_manager.selected_mut() else {
+        return UpdateResult::none();
+    };
+    let session = &mut handle.session;
+    if !session.vm_connected {
+        return UpdateResult::none();
+    }
+    let perf = &mut session.performance;
+    let rows = perf.allocation_rows();
+    let Some((class_name, Some(class_id))) = rows
+        .get(perf.allocation_selected.min(rows.len().saturating_sub(1)))
+        .map(|&(name, id)| (name.to_string(), id.map(String::from)))
+    else {
+        return UpdateResult::none();
+    };
+    perf.instance_browser = Some(InstanceBrowser::new(class_name, class_id.clone()));
+    UpdateResult::action(UpdateAction::FetchInstances {
+        session_id: session.id,
+        class_id,
+        vm_handle: None, // hydrated by process.rs
+    })
+}
+
+/// Show the listed instances if the browser is still open on the same class.
+pub(crate) fn handle_instances_fetched(
+    state: &mut AppState,
+    session_id: SessionId,
+    class_id: String,
+    result: Result<InstanceSet, String>,
+) -> UpdateResult {
+    let Some(browser) = state
+        .session_manager
+        .get_mut(session_id)
+        .and_then(|h| h.session.performance.instance_browser.as_mut())
+        .filter(|b| b.class_id == class_id)
+    else {
+        return UpdateResult::none();
+    };
+    browser.loading = false;
+    match result {
+        Ok(set) => {
+            browser.total_count = set.total_count;
+            browser.instances = set.instances;
+            browser.selected = 0;
+            browser.details = None;
+            browser.error = None;
+        }
+        Err(e) => browser.error = Some(e),
+    }
+    UpdateResult::none()
+}
+
+/// Select an instance in the browser (clamped), dropping the details of the
+/// previously selected one.
+pub(crate) fn handle_select_instance(state: &mut AppState, index: usize) -> UpdateResult {
+    if let Some(browser) = state
+        .session_manager
+        .selected_mut()
+        .and_then(|h| h.session.performance.instance_browser.as_mut())
+    {
+        let index = index.min(browser.instances.len().saturating_sub(1));
+        if index != browser.selected {
+            browser.selected = index;
+            browser.details = None;
+            browser.error = None;
+        }
+    }
+    UpdateResult::none()
+}
+
+/// Fetch the fields and retaining path of the selected instance.
+pub(crate) fn handle_inspect_instance(state: &mut AppState) -> UpdateResult {
+    let Some(handle) = state.session_manager.selected_mut() else {
+        return UpdateResult::none();
+    };
+    let session_id = handle.session.id;
+    let Some(browser) = handle.session.performance.instance_browser.as_mut() else {
+        return UpdateResult::none();
+    };
+    if browser.loading {
+        return UpdateResult::none();
+    }
+    let Some(object_id) = browser.selected_instance().map(|i| i.id.clone()) else {
+        return UpdateResult::none();
+    };
+    browser.loading = true;
+    browser.error = None;
+    UpdateResult::action(UpdateAction::InspectInstance {
+        session_id,
+        object_id,
+        vm_handle: None, // hydrated by process.rs
+    })
+}
+
+/// Show an instance's details if it is still the selected one.
+pub(crate) fn handle_instance_inspected(
+    state: &mut AppState,
+    session_id: SessionId,
+    object_id: String,
+    result: Result<InstanceDetails, String>,
+) -> UpdateResult {
+    let Some(browser) = state
+        .session_manager
+        .get_mut(session_id)
+        .and_then(|h| h.session.performance.instance_browser.as_mut())
+    else {
+        return UpdateResult::none();
+    };
+    browser.loading = false;
+    if browser.selected_instance().map(|i| i.id.as_str()) != Some(object_id.as_str()) {
+        return UpdateResult::none();
+    }
+    match result {
+        Ok(details) => browser.details = Some(details),
+        Err(e) => browser.error = Some(e),
+    }
+    UpdateResult::none()
+}
+
+/// Close the instance browser of the selected session.
+pub(crate) fn handle_close_instance_browser(state: &mut AppState) -> UpdateResult {
+    if let Some(handle) = state.session_manager.selected_mut() {
+        handle.session.performance.instance_browser = None;
+    }
+    UpdateResult::none()
+}
+
+fn format_signed_bytes(bytes: i64) -> String {
+    let sign = if bytes < 0 { "-" } else { "+" };
+    format!(
+        "{sign}{}",
+        fdemon_core::MemoryUsage::format_bytes(bytes.unsigned_abs())
+    )
+}
+
+/// Open the creation location of the selected rebuild table row in the
+/// configured editor.
+pub(crate) fn handle_open_rebuild_location(state: &mut AppState) -> UpdateResult {
+    let Some(entry) = state.session_manager.selected().and_then(|h| {
+        let perf = &h.session.performance;
+        perf.sorted_rebuilds()
+            .into_iter()
+            .nth(perf.rebuild_selected)
+    }) else {
+        return UpdateResult::none();
+    };
+
+    let location = entry.location;
+    let file_ref = FileReference::new(location.file, location.line, location.column);
+    match open_in_editor(&file_ref, &state.settings.editor, &state.project_path) {
+        Ok(result) => {
+            tracing::info!(
+                "Opened {}:{} in {}",
+                result.file,
+                result.line,
+                result.editor_display_name
+            );
+        }
+        Err(e) => {
+            tracing::warn!("Failed to open rebuild location: {}", e);
+        }
+    }
+    UpdateResult::none()
+}
+
 // ── Tests ─────────────────────────────────────────────────────────────────────
 
 #[cfg(test)]
@@ -85,10 +725,10 @@ mod tests {
     use super::handle_toggle_allocation_sort;
     use crate::handler::update::update;
     use crate::message::Message;
-    use crate::session::AllocationSortColumn;
     use crate::session::SessionId;
+    use crate::session::{AllocationSortColumn, RebuildSortColumn};
     use crate::state::{AppState, DevToolsPanel, UiMode};
-    use fdemon_core::performance::{AllocationProfile, FrameTiming, MemorySample};
+    use fdemon_core::performance::{AllocationProfile, FrameTiming, MemorySample, RebuiltWidgets};
 
     // ── Helpers ──────────────────────────────────────────────────────────────
 
@@ -429,7 +1069,11 @@ mod tests {
 
         update(
             &mut state,
-            Message::VmServiceMemorySample { session_id, sample },
+            Message::VmServiceMemorySample {
+                session_id,
+                isolate_id: None,
+                sample,
+            },
         );
 
         let count = state
@@ -452,6 +1096,7 @@ mod tests {
                 &mut state,
                 Message::VmServiceMemorySample {
                     session_id,
+                    isolate_id: None,
                     sample: make_memory_sample(),
                 },
             );
@@ -482,6 +1127,7 @@ mod tests {
             &mut state,
             Message::VmServiceMemorySample {
                 session_id: unknown_session_id,
+                isolate_id: None,
                 sample,
             },
         );
@@ -499,6 +1145,7 @@ mod tests {
             &mut state,
             Message::VmServiceAllocationProfileReceived {
                 session_id,
+                isolate_id: None,
                 profile,
             },
         );
@@ -526,6 +1173,7 @@ mod tests {
         let profile1 = AllocationProfile {
             members: vec![ClassHeapStats {
                 class_name: "String".to_string(),
+                class_id: None,
                 library_uri: None,
                 new_space_instances: 10,
                 new_space_size: 100,
@@ -538,6 +1186,7 @@ mod tests {
             &mut state,
             Message::VmServiceAllocationProfileReceived {
                 session_id,
+                isolate_id: None,
                 profile: profile1,
             },
         );
@@ -551,6 +1200,7 @@ mod tests {
             &mut state,
             Message::VmServiceAllocationProfileReceived {
                 session_id,
+                isolate_id: None,
                 profile: profile2,
             },
         );
@@ -581,11 +1231,66 @@ mod tests {
             &mut state,
             Message::VmServiceAllocationProfileReceived {
                 session_id: unknown_session_id,
+                isolate_id: None,
                 profile,
             },
         );
     }
 
+    #[test]
+    fn test_results_from_previous_isolate_are_dropped() {
+        let (mut state, session_id) = make_state_in_performance_panel();
+        state
+            .session_manager
+            .get_mut(session_id)
+            .unwrap()
+            .session
+            .performance
+            .set_isolate(Some("isolates/worker".to_string()));
+
+        // Polled from the main isolate before the switch.
+        update(
+            &mut state,
+            Message::VmServiceAllocationProfileReceived {
+                session_id,
+                isolate_id: None,
+                profile: make_allocation_profile(),
+            },
+        );
+        update(
+            &mut state,
+            Message::VmServiceMemorySample {
+                session_id,
+                isolate_id: None,
+                sample: make_memory_sample(),
+            },
+        );
+        let perf = &state
+            .session_manager
+            .get(session_id)
+            .unwrap()
+            .session
+            .performance;
+        assert!(perf.allocation_profile.is_none());
+        assert!(perf.memory_samples.is_empty());
+
+        update(
+            &mut state,
+            Message::VmServiceAllocationProfileReceived {
+                session_id,
+                isolate_id: Some("isolates/worker".to_string()),
+                profile: make_allocation_profile(),
+            },
+        );
+        let perf = &state
+            .session_manager
+            .get(session_id)
+            .unwrap()
+            .session
+            .performance;
+        assert!(perf.allocation_profile.is_some());
+    }
+
     // ── ToggleAllocationSort handler ──────────────────────────────────────────
 
     #[test]
@@ -671,4 +1376,551 @@ mod tests {
             "ToggleAllocationSort message should toggle from BySize to ByInstances"
         );
     }
+
+    // ── Rebuild tracking ─────────────────────────────────────────────────────
+
+    fn rebuilds(counts: Vec<(u32, u64)>, names: &[(u32, &str)]) -> RebuiltWidgets {
+        RebuiltWidgets {
+            counts,
+            new_locations: names
+                .iter()
+                .map(|(id, name)| {
+                    (
+                        *id,
+                        fdemon_core::widget_tree::CreationLocation {
+                            file: "file:///app/lib/main.dart".to_string(),
+                            line: *id,
+                            column: 1,
+                            name: Some(name.to_string()),
+                        },
+                    )
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_rebuilt_widgets_ignored_until_tracking_confirmed() {
+        let (mut state, session_id) = make_state_in_performance_panel();
+        dispatch(
+            &mut state,
+            Message::VmServiceRebuiltWidgets {
+                session_id,
+                rebuilds: rebuilds(vec![(1, 2)], &[(1, "Counter")]),
+            },
+        );
+        let perf = &state
+            .session_manager
+            .get(session_id)
+            .unwrap()
+            .session
+            .performance;
+        assert!(perf.rebuild_stats.is_empty());
+
+        dispatch(
+            &mut state,
+            Message::RebuildTrackingChanged {
+                session_id,
+                enabled: true,
+            },
+        );
+        dispatch(
+            &mut state,
+            Message::VmServiceRebuiltWidgets {
+                session_id,
+                rebuilds: rebuilds(vec![(1, 2), (2, 7)], &[(1, "Counter"), (2, "Header")]),
+            },
+        );
+        let perf = &state
+            .session_manager
+            .get(session_id)
+            .unwrap()
+            .session
+            .performance;
+        assert!(perf.rebuild_tracking);
+        let rows = perf.sorted_rebuilds();
+        assert_eq!(rows[0].location.name.as_deref(), Some("Header"));
+        assert_eq!(rows[0].total, 7);
+    }
+
+    #[test]
+    fn test_rebuild_stats_reset_on_hot_restart() {
+        let (mut state, session_id) = make_state_in_performance_panel();
+        dispatch(
+            &mut state,
+            Message::RebuildTrackingChanged {
+                session_id,
+                enabled: true,
+            },
+        );
+        dispatch(
+            &mut state,
+            Message::VmServiceRebuiltWidgets {
+                session_id,
+                rebuilds: rebuilds(vec![(1, 2)], &[(1, "Counter")]),
+            },
+        );
+
+        dispatch(&mut state, Message::SessionRestartCompleted { session_id });
+        // The new isolate reuses location IDs for other widgets.
+        dispatch(
+            &mut state,
+            Message::VmServiceRebuiltWidgets {
+                session_id,
+                rebuilds: rebuilds(vec![(1, 3)], &[(1, "Sidebar")]),
+            },
+        );
+        let perf = &state
+            .session_manager
+            .get(session_id)
+            .unwrap()
+            .session
+            .performance;
+        let rows = perf.sorted_rebuilds();
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].location.name.as_deref(), Some("Sidebar"));
+        assert_eq!(rows[0].total, 3);
+    }
+
+    #[test]
+    fn test_toggle_rebuild_tracking_requires_vm_connection() {
+        let (mut state, session_id) = make_state_in_performance_panel();
+        let result = update(&mut state, Message::ToggleRebuildTracking);
+        assert!(result.action.is_none());
+
+        state
+            .session_manager
+            .get_mut(session_id)
+            .unwrap()
+            .session
+            .vm_connected = true;
+        let result = update(&mut state, Message::ToggleRebuildTracking);
+        assert!(matches!(
+            result.action,
+            Some(crate::handler::UpdateAction::SetRebuildTracking { enabled: true, .. })
+        ));
+    }
+
+    #[test]
+    fn test_select_rebuild_row_clamps_and_sort_resets() {
+        let (mut state, session_id) = make_state_in_performance_panel();
+        dispatch(
+            &mut state,
+            Message::RebuildTrackingChanged {
+                session_id,
+                enabled: true,
+            },
+        );
+        dispatch(
+            &mut state,
+            Message::VmServiceRebuiltWidgets {
+                session_id,
+                rebuilds: rebuilds(vec![(1, 1), (2, 1)], &[(1, "A"), (2, "B")]),
+            },
+        );
+
+        dispatch(&mut state, Message::SelectRebuildRow { index: 9 });
+        let perf = &state
+            .session_manager
+            .get(session_id)
+            .unwrap()
+            .session
+            .performance;
+        assert_eq!(perf.rebuild_selected, 1);
+
+        dispatch(&mut state, Message::ToggleRebuildSort);
+        let perf = &state
+            .session_manager
+            .get(session_id)
+            .unwrap()
+            .session
+            .performance;
+        assert_eq!(perf.rebuild_sort, RebuildSortColumn::ByLastFrame);
+        assert_eq!(perf.rebuild_selected, 0);
+    }
+
+    // ── Export ────────────────────────────────────────────────────────────────
+
+    #[test]
+    fn test_export_performance_requires_data() {
+        let (mut state, session_id) = make_state_in_performance_panel();
+        let result = update(&mut state, Message::ExportPerformance);
+        assert!(result.action.is_none());
+        let logs = &state.session_manager.get(session_id).unwrap().session.logs;
+        assert!(logs
+            .back()
+            .is_some_and(|l| l.message.contains("export skipped")));
+
+        push_frames(&mut state, 3);
+        let result = update(&mut state, Message::ExportPerformance);
+        match result.action {
+            Some(crate::handler::UpdateAction::ExportPerformance {
+                session_id: id,
+                capture,
+            }) => {
+                assert_eq!(id, session_id);
+                assert_eq!(capture.frames.len(), 3);
+                assert_eq!(capture.device, "Test Device");
+            }
+            other => panic!("expected ExportPerformance action, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_performance_exported_logs_path() {
+        let (mut state, session_id) = make_state_in_performance_panel();
+        update(
+            &mut state,
+            Message::PerformanceExported {
+                session_id,
+                result: Ok(std::path::PathBuf::from("/app/.fdemon/perf/x")),
+            },
+        );
+        let logs = &state.session_manager.get(session_id).unwrap().session.logs;
+        assert!(logs
+            .back()
+            .is_some_and(|l| l.message.ends_with("file:///app/.fdemon/perf/x")));
+    }
+
+    // ── Baselines ─────────────────────────────────────────────────────────────
+
+    #[test]
+    fn test_baseline_actions_use_configured_name() {
+        let (mut state, session_id) = make_state_in_performance_panel();
+        state.settings.devtools.baseline.name = "scroll".to_string();
+        let result = update(&mut state, Message::SavePerfBaseline { name: None });
+        assert!(result.action.is_none(), "no frames yet");
+
+        push_frames(&mut state, 3);
+        match update(&mut state, Message::SavePerfBaseline { name: None }).action {
+            Some(crate::handler::UpdateAction::SavePerfBaseline {
+                session_id: id,
+                baseline,
+            }) => {
+                assert_eq!(id, session_id);
+                assert_eq!(baseline.name, "scroll");
+                assert_eq!(baseline.frames, 3);
+            }
+            other => panic!("expected SavePerfBaseline action, got {other:?}"),
+        }
+
+        let result = update(
+            &mut state,
+            Message::ComparePerfBaseline {
+                name: Some("release".to_string()),
+            },
+        );
+        assert!(matches!(
+            result.action,
+            Some(crate::handler::UpdateAction::ComparePerfBaseline { ref current, .. })
+                if current.name == "release"
+        ));
+    }
+
+    #[test]
+    fn test_baseline_compared_stores_comparison() {
+        use fdemon_core::{MetricDelta, PerfComparison, PerfMetric};
+
+        let (mut state, session_id) = make_state_in_performance_panel();
+        let comparison = PerfComparison {
+            baseline_name: "scroll".to_string(),
+            metrics: vec![MetricDelta {
+                metric: PerfMetric::JankCount,
+                baseline: 2.0,
+                current: 12.0,
+                regressed: true,
+            }],
+        };
+        update(
+            &mut state,
+            Message::PerfBaselineCompared {
+                session_id,
+                result: Ok(comparison.clone()),
+            },
+        );
+        let session = &state.session_manager.get(session_id).unwrap().session;
+        assert_eq!(session.performance.baseline_comparison, Some(comparison));
+        let last = session.logs.back().unwrap();
+        assert_eq!(last.level, fdemon_core::LogLevel::Warning);
+        assert!(last.message.ends_with("'scroll': Jank"));
+    }
+
+    #[test]
+    fn test_baseline_compare_pending_until_reported() {
+        let (mut state, session_id) = make_state_in_performance_panel();
+        push_frames(&mut state, 3);
+        update(&mut state, Message::ComparePerfBaseline { name: None });
+        assert!(state.session_manager.any_baseline_compare_pending());
+
+        update(
+            &mut state,
+            Message::PerfBaselineCompared {
+                session_id,
+                result: Err("missing".to_string()),
+            },
+        );
+        assert!(!state.session_manager.any_baseline_compare_pending());
+    }
+
+    // ── Refresh rate ──────────────────────────────────────────────────────────
+
+    #[test]
+    fn test_display_refresh_rate_sets_budget_preferring_configured_rate() {
+        let (mut state, session_id) = make_state_in_performance_panel();
+        update(
+            &mut state,
+            Message::VmServiceDisplayRefreshRate {
+                session_id,
+                refresh_rate_hz: 120.0,
+            },
+        );
+        let perf = &state
+            .session_manager
+            .get(session_id)
+            .unwrap()
+            .session
+            .performance;
+        assert_eq!(perf.display_refresh_rate, Some(120.0));
+        assert_eq!(perf.frame_budget_micros, 8_333);
+
+        state
+            .settings
+            .devtools
+            .refresh_rates
+            .insert("Test Device".to_string(), 90.0);
+        update(
+            &mut state,
+            Message::VmServiceDisplayRefreshRate {
+                session_id,
+                refresh_rate_hz: 60.0,
+            },
+        );
+        let perf = &state
+            .session_manager
+            .get(session_id)
+            .unwrap()
+            .session
+            .performance;
+        assert_eq!(
+            perf.display_refresh_rate,
+            Some(90.0),
+            "configured rate wins over the engine"
+        );
+        assert_eq!(perf.frame_budget_micros, 11_111);
+    }
+
+    // ── Allocation diffs ──────────────────────────────────────────────────────
+
+    fn heap_profile(instances: u64, bytes: u64) -> AllocationProfile {
+        AllocationProfile {
+            members: vec![fdemon_core::ClassHeapStats {
+                class_name: "Listener".to_string(),
+                class_id: None,
+                library_uri: None,
+                new_space_instances: 0,
+                new_space_size: 0,
+                old_space_instances: instances,
+                old_space_size: bytes,
+            }],
+            timestamp: chrono::Local::now(),
+        }
+    }
+
+    #[test]
+    fn test_capture_allocation_diff_requires_vm_and_blocks_while_pending() {
+        let (mut state, session_id) = make_state_in_performance_panel();
+        assert!(update(&mut state, Message::CaptureAllocationDiff)
+            .action
+            .is_none());
+
+        state
+            .session_manager
+            .get_mut(session_id)
+            .unwrap()
+            .session
+            .vm_connected = true;
+        assert!(matches!(
+            update(&mut state, Message::CaptureAllocationDiff).action,
+            Some(crate::handler::UpdateAction::CaptureAllocationDiff { .. })
+        ));
+        assert!(update(&mut state, Message::CaptureAllocationDiff)
+            .action
+            .is_none());
+    }
+
+    #[test]
+    fn test_allocation_diffs_against_first_and_clear() {
+        let (mut state, session_id) = make_state_in_performance_panel();
+        for (instances, bytes) in [(1, 100), (3, 300)] {
+            update(
+                &mut state,
+                Message::AllocationDiffCaptured {
+                    session_id,
+                    result: Ok(heap_profile(instances, bytes)),
+                },
+            );
+        }
+        let session = &state.session_manager.get(session_id).unwrap().session;
+        let diff = session.performance.heap_diff.as_ref().unwrap();
+        assert_eq!(diff.classes[0].instance_delta(), 2);
+        assert_eq!(diff.classes[0].size_delta(), 200);
+        assert_eq!(
+            session.performance.diff_base.as_ref().unwrap().members[0].old_space_size,
+            100,
+            "the first snapshot stays the base"
+        );
+        assert!(session
+            .logs
+            .back()
+            .unwrap()
+            .message
+            .ends_with("1 class grew"));
+
+        update(&mut state, Message::ClearAllocationDiff);
+        let perf = &state
+            .session_manager
+            .get(session_id)
+            .unwrap()
+            .session
+            .performance;
+        assert!(perf.diff_base.is_none());
+        assert!(perf.heap_diff.is_none());
+    }
+
+    // ── Instance browser ──────────────────────────────────────────────────────
+
+    #[test]
+    fn test_instance_browser_flow() {
+        use fdemon_core::{
+            HeapInstance, InstanceDetails, InstanceSet, RetainingObject, RetainingPath,
+        };
+
+        let (mut state, session_id) = make_state_in_performance_panel();
+        {
+            let session = &mut state.session_manager.get_mut(session_id).unwrap().session;
+            session.vm_connected = true;
+            let mut profile = heap_profile(2, 200);
+            profile.members[0].class_id = Some("classes/7".to_string());
+            session.performance.allocation_profile = Some(profile);
+        }
+        update(&mut state, Message::SelectAllocationRow { index: 5 });
+        let result = update(&mut state, Message::OpenInstanceBrowser);
+        assert!(matches!(
+            result.action,
+            Some(crate::handler::UpdateAction::FetchInstances { ref class_id, .. })
+                if class_id == "classes/7"
+        ));
+
+        let instance = |id: &str| HeapInstance {
+            id: id.to_string(),
+            description: "Listener".to_string(),
+        };
+        update(
+            &mut state,
+            Message::InstancesFetched {
+                session_id,
+                class_id: "classes/7".to_string(),
+                result: Ok(InstanceSet {
+                    total_count: 2,
+                    instances: vec![instance("objects/1"), instance("objects/2")],
+                }),
+            },
+        );
+        update(&mut state, Message::SelectInstance { index: 9 });
+        let result = update(&mut state, Message::InspectInstance);
+        assert!(matches!(
+            result.action,
+            Some(crate::handler::UpdateAction::InspectInstance { ref object_id, .. })
+                if object_id == "objects/2"
+        ));
+
+        let details = InstanceDetails {
+            fields: vec![],
+            retaining_path: RetainingPath {
+                gc_root_type: "user global".to_string(),
+                elements: vec![RetainingObject {
+                    description: "Listener".to_string(),
+                    parent_slot: None,
+                }],
+            },
+        };
+        update(
+            &mut state,
+            Message::InstanceInspected {
+                session_id,
+                object_id: "objects/2".to_string(),
+                result: Ok(details.clone()),
+            },
+        );
+        let browser = state
+            .session_manager
+            .get(session_id)
+            .unwrap()
+            .session
+            .performance
+            .instance_browser
+            .clone()
+            .unwrap();
+        assert_eq!(browser.class_name, "Listener");
+        assert_eq!(browser.selected, 1);
+        assert!(!browser.loading);
+        assert_eq!(browser.details, Some(details));
+
+        update(&mut state, Message::CloseInstanceBrowser);
+        assert!(state
+            .session_manager
+            .get(session_id)
+            .unwrap()
+            .session
+            .performance
+            .instance_browser
+            .is_none());
+    }
+
+    // ── Leak hints ────────────────────────────────────────────────────────────
+
+    #[test]
+    fn test_leak_checkpoints_flag_growth() {
+        let (mut state, session_id) = make_state_in_performance_panel();
+        {
+            let session = &mut state.session_manager.get_mut(session_id).unwrap().session;
+            session.vm_connected = true;
+            session.performance.leak_tracker = Some(fdemon_core::LeakTracker::new(
+                vec!["Listener".to_string()],
+                1,
+            ));
+        }
+
+        for live in [1, 2] {
+            let result = update(
+                &mut state,
+                Message::SessionReloadCompleted {
+                    session_id,
+                    time_ms: 80,
+                },
+            );
+            assert!(matches!(
+                result.action,
+                Some(crate::handler::UpdateAction::CaptureLeakCheckpoint { .. })
+            ));
+            // A route change while the checkpoint is pending is folded into it.
+            let result = update(&mut state, Message::VmServiceNavigationEvent { session_id });
+            assert!(result.action.is_none());
+
+            update(
+                &mut state,
+                Message::LeakCheckpointCaptured {
+                    session_id,
+                    result: Ok(heap_profile(live, live * 100)),
+                },
+            );
+        }
+
+        let session = &state.session_manager.get(session_id).unwrap().session;
+        let hints = session.performance.leak_tracker.as_ref().unwrap().hints();
+        assert_eq!(hints[0].class_name, "Listener");
+        assert_eq!(hints[0].counts, [1, 2]);
+        let last = session.logs.back().unwrap();
+        assert!(last.message.starts_with("Possible leak: Listener"));
+    }
 }
@@ -0,0 +1,243 @@
+//! CPU profiler handlers.
+//!
+//! Handles starting and stopping recordings, storing fetched samples, and
+//! switching views, the project filter, and call tree navigation.
+
+use crate::handler::{UpdateAction, UpdateResult};
+use crate::message::ProfilerNav;
+use crate::profiler_view_state::{ProfilerRecording, ProfilerView, PROFILER_PAGE_STEP};
+use crate::session::SessionId;
+use crate::state::{AppState, DevToolsError};
+use fdemon_core::CpuProfile;
+
+use super::map_rpc_error;
+
+/// Start a recording for the selected session, or stop the running one and
+/// fetch its samples.
+pub(crate) fn handle_toggle_recording(state: &mut AppState) -> UpdateResult {
+    let profiler = &state.devtools_view_state.profiler;
+    if profiler.is_busy() {
+        return UpdateResult::none();
+    }
+
+    if let Some(recording) = profiler.recording {
+        let profiler = &mut state.devtools_view_state.profiler;
+        profiler.recording = None;
+        profiler.loading = true;
+        return UpdateResult::action(UpdateAction::StopCpuProfiling {
+            session_id: recording.session_id,
+            origin_micros: recording.origin_micros,
+            vm_handle: None, // hydrated by process.rs
+        });
+    }
+
+    let Some(handle) = state.session_manager.selected() else {
+        return UpdateResult::none();
+    };
+    let session_id = handle.session.id;
+    let vm_connected = handle.session.vm_connected;
+
+    let profiler = &mut state.devtools_view_state.profiler;
+    if !vm_connected {
+        profiler.set_error(DevToolsError::new(
+            "VM Service not available",
+            "Ensure the app is running in debug or profile mode",
+        ));
+        return UpdateResult::none();
+    }
+
+    profiler.error = None;
+    profiler.starting = true;
+    UpdateResult::action(UpdateAction::StartCpuProfiling {
+        session_id,
+        vm_handle: None, // hydrated by process.rs
+    })
+}
+
+/// Mark the recording as running.
+pub(crate) fn handle_started(
+    state: &mut AppState,
+    session_id: SessionId,
+    origin_micros: i64,
+) -> UpdateResult {
+    let profiler = &mut state.devtools_view_state.profiler;
+    if profiler.starting {
+        profiler.starting = false;
+        profiler.recording = Some(ProfilerRecording {
+            session_id,
+            origin_micros,
+        });
+    }
+    UpdateResult::none()
+}
+
+/// Store the samples of a finished recording.
+pub(crate) fn handle_profile_fetched(
+    state: &mut AppState,
+    session_id: SessionId,
+    profile: CpuProfile,
+) -> UpdateResult {
+    tracing::debug!(
+        "CPU profile for session {}: {} samples",
+        session_id,
+        profile.samples.len()
+    );
+    let profiler = &mut state.devtools_view_state.profiler;
+    if profiler.loading {
+        profiler.set_profile(profile);
+    }
+    UpdateResult::none()
+}
+
+/// Show a start or fetch failure.
+///
+/// A disabled profiler gets a specific message: sampling is off in release
+/// builds.
+pub(crate) fn handle_failed(
+    state: &mut AppState,
+    session_id: SessionId,
+    error: String,
+) -> UpdateResult {
+    tracing::debug!("CPU profiling failed for session {}: {}", session_id, error);
+    let mapped = if error.to_lowercase().contains("profiler") {
+        DevToolsError::new(
+            "CPU profiler is disabled",
+            "Run in debug or profile mode to record samples",
+        )
+    } else {
+        map_rpc_error(&error)
+    };
+    state.devtools_view_state.profiler.set_error(mapped);
+    UpdateResult::none()
+}
+
+/// Switch between bottom-up, top-down and flame graph views.
+pub(crate) fn handle_select_view(state: &mut AppState, view: ProfilerView) -> UpdateResult {
+    state.devtools_view_state.profiler.set_view(view);
+    UpdateResult::none()
+}
+
+/// Show or hide SDK, package and native frames.
+pub(crate) fn handle_toggle_project_only(state: &mut AppState) -> UpdateResult {
+    state.devtools_view_state.profiler.toggle_project_only();
+    UpdateResult::none()
+}
+
+/// Move the selection or expand/collapse call tree nodes.
+pub(crate) fn handle_navigate(state: &mut AppState, nav: ProfilerNav) -> UpdateResult {
+    let profiler = &mut state.devtools_view_state.profiler;
+    match nav {
+        ProfilerNav::Up => profiler.move_selection(-1),
+        ProfilerNav::Down => profiler.move_selection(1),
+        ProfilerNav::PageUp => profiler.move_selection(-(PROFILER_PAGE_STEP as isize)),
+        ProfilerNav::PageDown => profiler.move_selection(PROFILER_PAGE_STEP as isize),
+        ProfilerNav::Expand => profiler.expand_selected(),
+        ProfilerNav::Collapse => profiler.collapse_selected(),
+    }
+    UpdateResult::none()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::handler::update;
+    use crate::message::Message;
+
+    fn test_device() -> fdemon_daemon::Device {
+        fdemon_daemon::Device {
+            id: "dev-1".to_string(),
+            name: "Test Device".to_string(),
+            platform: "android".to_string(),
+            emulator: false,
+            category: None,
+            platform_type: None,
+            ephemeral: false,
+            emulator_id: None,
+        }
+    }
+
+    fn state_with_session(vm_connected: bool) -> (AppState, SessionId) {
+        let mut state = AppState::new();
+        let id = state
+            .session_manager
+            .create_session(&test_device())
+            .unwrap();
+        if let Some(handle) = state.session_manager.selected_mut() {
+            handle.session.vm_connected = vm_connected;
+        }
+        (state, id)
+    }
+
+    #[test]
+    fn test_toggle_recording_starts_and_stops() {
+        let (mut state, id) = state_with_session(true);
+        let result = update(&mut state, Message::ProfilerToggleRecording);
+        assert!(matches!(
+            result.action,
+            Some(UpdateAction::StartCpuProfiling { session_id, .. }) if session_id == id
+        ));
+        // A second press while the start request is in flight is ignored.
+        let result = update(&mut state, Message::ProfilerToggleRecording);
+        assert!(result.action.is_none());
+
+        update(
+            &mut state,
+            Message::CpuProfilingStarted {
+                session_id: id,
+                origin_micros: 5_000,
+            },
+        );
+        assert!(state.devtools_view_state.profiler.recording.is_some());
+
+        let result = update(&mut state, Message::ProfilerToggleRecording);
+        assert!(matches!(
+            result.action,
+            Some(UpdateAction::StopCpuProfiling {
+                origin_micros: 5_000,
+                ..
+            })
+        ));
+        let profiler = &state.devtools_view_state.profiler;
+        assert!(profiler.recording.is_none());
+        assert!(profiler.loading);
+
+        update(
+            &mut state,
+            Message::CpuProfileFetched {
+                session_id: id,
+                profile: CpuProfile::default(),
+            },
+        );
+        let profiler = &state.devtools_view_state.profiler;
+        assert!(!profiler.loading);
+        assert!(profiler.profile.is_some());
+    }
+
+    #[test]
+    fn test_toggle_recording_without_vm_sets_error() {
+        let (mut state, _) = state_with_session(false);
+        let result = update(&mut state, Message::ProfilerToggleRecording);
+        assert!(result.action.is_none());
+        assert!(state.devtools_view_state.profiler.error.is_some());
+    }
+
+    #[test]
+    fn test_disabled_profiler_error() {
+        let (mut state, id) = state_with_session(true);
+        update(&mut state, Message::ProfilerToggleRecording);
+        update(
+            &mut state,
+            Message::CpuProfilingFailed {
+                session_id: id,
+                error: "Protocol error: 106 Feature is disabled: The profiler is disabled."
+                    .to_string(),
+            },
+        );
+        let profiler = &state.devtools_view_state.profiler;
+        assert!(!profiler.starting);
+        assert_eq!(
+            profiler.error.as_ref().map(|e| e.message.as_str()),
+            Some("CPU profiler is disabled")
+        );
+    }
+}
@@ -1,8 +1,13 @@
 //! Key event handlers for different UI modes
 
+use crate::dump_view_state::DebugDumpKind;
 use crate::input_key::InputKey;
-use crate::message::{InspectorNav, Message, NetworkNav};
+use crate::message::{
+    ConsoleNav, DumpNav, ExtensionNav, InspectorNav, Message, NetworkNav, ProfilerNav, WaterfallNav,
+};
+use crate::profiler_view_state::ProfilerView;
 use crate::session::NetworkDetailTab;
+use crate::session_group::GroupOp;
 use crate::state::{AppState, DevToolsPanel, UiMode};
 
 /// Convert key events to messages based on current UI mode
@@ -125,6 +130,23 @@ fn handle_key_normal(state: &AppState, key: InputKey) -> Option<Message> {
         };
     }
 
+    // ─────────────────────────────────────────────────────────────────────────
+    // Session picker overlay intercepts ALL keys when open
+    // ─────────────────────────────────────────────────────────────────────────
+    if state.session_picker.is_some() {
+        return match key {
+            InputKey::Esc => Some(Message::HideSessionPicker),
+            InputKey::Enter => Some(Message::SessionPickerConfirm),
+            InputKey::Up | InputKey::BackTab => Some(Message::SessionPickerUp),
+            InputKey::Down | InputKey::Tab => Some(Message::SessionPickerDown),
+            InputKey::Backspace => Some(Message::SessionPickerBackspace),
+            InputKey::CharCtrl('u') => Some(Message::SessionPickerClear),
+            InputKey::CharCtrl('c') => Some(Message::Quit),
+            InputKey::Char(c) => Some(Message::SessionPickerInput(c)),
+            _ => None,
+        };
+    }
+
     // Check if any session is busy (reloading)
     let is_busy = state.session_manager.any_session_busy();
 
@@ -149,6 +171,9 @@ fn handle_key_normal(state: &AppState, key: InputKey) -> Option<Message> {
         InputKey::Char('8') => Some(Message::SelectSessionByIndex(7)),
         InputKey::Char('9') => Some(Message::SelectSessionByIndex(8)),
 
+        // 'S' - Session picker, for sessions beyond the ninth tab
+        InputKey::Char('S') => Some(Message::ShowSessionPicker),
+
         // Tab navigation
         InputKey::Tab => Some(Message::NextSession),
         InputKey::BackTab => Some(Message::PreviousSession),
@@ -172,6 +197,18 @@ fn handle_key_normal(state: &AppState, key: InputKey) -> Option<Message> {
         // Stop app (lowercase 's') - only when not busy
         InputKey::Char('s') if !is_busy => Some(Message::StopApp),
 
+        // Ctrl+R / Ctrl+T / Ctrl+S - Reload, restart or stop the session
+        // group (marked sessions, or all sessions when none are marked)
+        InputKey::CharCtrl('r') => Some(Message::SessionGroupAction(GroupOp::Reload)),
+        InputKey::CharCtrl('t') => Some(Message::SessionGroupAction(GroupOp::Restart)),
+        InputKey::CharCtrl('s') => Some(Message::SessionGroupAction(GroupOp::Stop)),
+
+        // 'p' - Screenshot of the selected session, 'P' - all running sessions
+        InputKey::Char('p') => Some(Message::CaptureScreenshot {
+            all_sessions: false,
+        }),
+        InputKey::Char('P') => Some(Message::CaptureScreenshot { all_sessions: true }),
+
         // ─────────────────────────────────────────────────────────
         // Session Management
         // ─────────────────────────────────────────────────────────
@@ -186,6 +223,9 @@ fn handle_key_normal(state: &AppState, key: InputKey) -> Option<Message> {
             }
         }
 
+        // 'm' - Mark/unmark the selected session for group actions
+        InputKey::Char('m') => Some(Message::ToggleSessionGroup),
+
         // 'd' for DevTools mode — available when any session exists.
         // Individual panels handle disconnected VM gracefully.
         InputKey::Char('d') => {
@@ -388,20 +428,68 @@ fn handle_key_link_highlight(key: InputKey) -> Option<Message> {
 /// - `Esc` — exit DevTools mode (or deselect frame when Performance panel has one selected)
 /// - `i` — switch to Inspector panel
 /// - `p` — switch to Performance panel
+/// - `d` — switch to Dumps panel (`1`–`5` pick the tree, `r` refetch, `s` save)
+/// - `c` — switch to CPU profiler panel (`Space` start/stop recording, `1`–`3`
+///   pick the view, `f` toggle project-only frames)
+/// - `:` — open the VM Service console with its command line focused
+/// - `x` — switch to the service Extensions panel (`↑`/`↓` select, `Enter`
+///   call, `r` refetch; configured button keys take precedence over all
+///   other bindings there)
 /// - `b` — open Flutter DevTools in system browser
 /// - `Ctrl+r` — toggle repaint rainbow overlay
 /// - `Ctrl+p` — toggle performance overlay
 /// - `Ctrl+d` — toggle debug paint overlay
+/// - `Ctrl+a` — toggle slow animations
+/// - `Ctrl+o` — cycle target platform override (android, iOS, macOS, ...)
+/// - `Ctrl+b` — toggle light/dark brightness override
+/// - `Ctrl+e` — toggle the "DEBUG" banner
+/// - `Ctrl+g` — toggle inverted oversized images
+/// - `Ctrl+t` — toggle widget build profiling
+/// - `Ctrl+l` — toggle text baseline painting
 /// - `j`/Down — scroll/navigate down (in Inspector: move selection down)
 /// - `k`/Up — scroll/navigate up (in Inspector: move selection up)
 /// - `h`/Left — in Inspector: collapse node; in Performance: previous frame
 /// - `Right`/`Enter` — in Inspector: expand node; in Performance (Right): next frame
 /// - `r` — in Inspector: refresh widget tree
+/// - `/` — in Inspector and Dumps: search (`]`/`[` next/previous match); in Network: filter
+/// - `u` — in Inspector: toggle user-code-only filter
+/// - `t` — in Inspector: toggle on-device select mode (tap a widget to jump to it)
+/// - `v` — in Inspector: switch the details pane between Layout and Properties
+/// - `Tab` — in Inspector: move focus between the tree and the properties pane
+/// - `w` — in Network: toggle waterfall view (`+`/`-` zoom, `←`/`→` pan, `0` reset);
+///   in Performance: toggle widget rebuild tracking (`↑`/`↓` select, `Enter` open
+///   source, `s` sort, `Ctrl+x` reset counts)
+/// - `e` — in Performance: export frame, memory and GC data to `.fdemon/perf/`
+/// - `H` — in Performance: capture the allocation profile and diff it against
+///   the first capture (`Ctrl+x` clears them while rebuild tracking is off)
+/// - `↑`/`↓` `Enter` — in Performance: select an allocation table row and open
+///   the instance browser for its class (`↑`/`↓` select an instance, `Enter`
+///   shows its fields and retaining path, `Esc` closes)
 /// - `q` — request quit
 fn handle_key_devtools(state: &AppState, key: InputKey) -> Option<Message> {
     let in_inspector = state.devtools_view_state.active_panel == DevToolsPanel::Inspector;
     let in_performance = state.devtools_view_state.active_panel == DevToolsPanel::Performance;
     let in_network = state.devtools_view_state.active_panel == DevToolsPanel::Network;
+    let in_dumps = state.devtools_view_state.active_panel == DevToolsPanel::Dumps;
+    let in_profiler = state.devtools_view_state.active_panel == DevToolsPanel::Profiler;
+    let in_console = state.devtools_view_state.active_panel == DevToolsPanel::Console;
+    let in_extensions = state.devtools_view_state.active_panel == DevToolsPanel::Extensions;
+    let in_waterfall = in_network
+        && state.session_manager.selected().is_some_and(|h| {
+            h.session.network.view_mode == crate::session::NetworkViewMode::Waterfall
+        });
+    let in_instances = in_performance
+        && state
+            .session_manager
+            .selected()
+            .is_some_and(|h| h.session.performance.instance_browser.is_some());
+    let in_rebuilds = !in_instances
+        && in_performance
+        && state
+            .session_manager
+            .selected()
+            .is_some_and(|h| h.session.performance.rebuild_tracking);
+    let in_allocations = in_performance && !in_instances && !in_rebuilds;
     let active_id = state.session_manager.selected().map(|h| h.session.id);
 
     // ── Network filter input mode ─────────────────────────────────────────────
@@ -425,6 +513,86 @@ fn handle_key_devtools(state: &AppState, key: InputKey) -> Option<Message> {
         }
     }
 
+    // ── Inspector search input mode ───────────────────────────────────────────
+    // Same routing as the network filter: while the search bar is open every
+    // printable key extends the query. Up/Down step between matches.
+    if in_inspector && state.devtools_view_state.inspector.search_input_active {
+        return match key {
+            InputKey::Esc => Some(Message::InspectorExitSearchMode),
+            InputKey::Enter => Some(Message::InspectorCommitSearch),
+            InputKey::Backspace => Some(Message::InspectorSearchBackspace),
+            InputKey::Down | InputKey::Tab => {
+                Some(Message::DevToolsInspectorNavigate(InspectorNav::NextMatch))
+            }
+            InputKey::Up | InputKey::BackTab => {
+                Some(Message::DevToolsInspectorNavigate(InspectorNav::PrevMatch))
+            }
+            InputKey::Char(c) if !c.is_control() => Some(Message::InspectorSearchInput(c)),
+            _ => None,
+        };
+    }
+
+    // ── Dump search input mode ────────────────────────────────────────────────
+    if in_dumps && state.devtools_view_state.dumps.search_input_active {
+        return match key {
+            InputKey::Esc => Some(Message::DumpExitSearchMode),
+            InputKey::Enter => Some(Message::DumpCommitSearch),
+            InputKey::Backspace => Some(Message::DumpSearchBackspace),
+            InputKey::Down | InputKey::Tab => Some(Message::DumpNavigate(DumpNav::NextMatch)),
+            InputKey::Up | InputKey::BackTab => Some(Message::DumpNavigate(DumpNav::PrevMatch)),
+            InputKey::Char(c) if !c.is_control() => Some(Message::DumpSearchInput(c)),
+            _ => None,
+        };
+    }
+
+    // ── Console command input ─────────────────────────────────────────────────
+    // While the command line is focused every printable key is typed. Tab
+    // completes the method name and Up/Down recall earlier commands.
+    if in_console && state.devtools_view_state.console.input_active {
+        return match key {
+            InputKey::Esc => Some(Message::ConsoleBlurInput),
+            InputKey::Enter => Some(Message::ConsoleSubmit),
+            InputKey::Backspace => Some(Message::ConsoleBackspace),
+            InputKey::Tab => Some(Message::ConsoleComplete),
+            InputKey::Up => Some(Message::ConsoleHistory { older: true }),
+            InputKey::Down => Some(Message::ConsoleHistory { older: false }),
+            InputKey::PageUp => Some(Message::ConsoleNavigate(ConsoleNav::PageUp)),
+            InputKey::PageDown => Some(Message::ConsoleNavigate(ConsoleNav::PageDown)),
+            InputKey::Char(c) if !c.is_control() => Some(Message::ConsoleInput(c)),
+            _ => None,
+        };
+    }
+
+    // ── Extension button keys ─────────────────────────────────────────────────
+    // Keys configured under `[[devtools.extensions]]` win over every other
+    // binding while the Extensions panel is shown.
+    if in_extensions {
+        if let InputKey::Char(c) = key {
+            if let Some(index) = state.devtools_view_state.extensions.button_for_key(c) {
+                return Some(Message::PressExtensionButton(index));
+            }
+        }
+    }
+
+    // ── Inspector properties pane focus ───────────────────────────────────────
+    // While the properties pane has focus, navigation keys move its cursor
+    // and Esc/Tab hand focus back to the tree. Other keys fall through.
+    if in_inspector && state.devtools_view_state.inspector.properties_focused {
+        let nav = match key {
+            InputKey::Esc | InputKey::Tab | InputKey::BackTab => {
+                return Some(Message::InspectorToggleFocus)
+            }
+            InputKey::Up | InputKey::Char('k') => Some(InspectorNav::Up),
+            InputKey::Down | InputKey::Char('j') => Some(InspectorNav::Down),
+            InputKey::Enter | InputKey::Right => Some(InspectorNav::Expand),
+            InputKey::Left | InputKey::Char('h') => Some(InspectorNav::Collapse),
+            _ => None,
+        };
+        if let Some(nav) = nav {
+            return Some(Message::InspectorPropertiesNavigate(nav));
+        }
+    }
+
     match key {
         // ── Exit DevTools / deselect frame ────────────────────────────────────
         //
@@ -435,6 +603,9 @@ fn handle_key_devtools(state: &AppState, key: InputKey) -> Option<Message> {
         //
         // When the Network panel is active, Esc deselects the current request.
         InputKey::Esc => {
+            if in_instances {
+                return Some(Message::CloseInstanceBrowser);
+            }
             if in_performance {
                 let frame_selected = state
                     .session_manager
@@ -467,9 +638,25 @@ fn handle_key_devtools(state: &AppState, key: InputKey) -> Option<Message> {
         // 'n' always switches to Network panel.
         InputKey::Char('n') => Some(Message::SwitchDevToolsPanel(DevToolsPanel::Network)),
 
+        // 'd' always switches to the Dumps panel.
+        InputKey::Char('d') => Some(Message::SwitchDevToolsPanel(DevToolsPanel::Dumps)),
+
+        // 'c' always switches to the CPU profiler panel.
+        InputKey::Char('c') => Some(Message::SwitchDevToolsPanel(DevToolsPanel::Profiler)),
+
+        // ':' opens the VM Service console with its command line focused.
+        InputKey::Char(':') => Some(Message::ConsoleFocusInput),
+
+        // 'x' always switches to the service Extensions panel.
+        InputKey::Char('x') => Some(Message::SwitchDevToolsPanel(DevToolsPanel::Extensions)),
+
         // ── Browser DevTools ──────────────────────────────────────────────────
         InputKey::Char('b') => Some(Message::OpenBrowserDevTools),
 
+        // ── Isolate picker ────────────────────────────────────────────────────
+        // 'I' cycles the isolate that memory and CPU data are read from.
+        InputKey::Char('I') => Some(Message::CycleDevToolsIsolate),
+
         // ── Debug overlay toggles ─────────────────────────────────────────────
         InputKey::CharCtrl('r') => Some(Message::ToggleDebugOverlay {
             extension: crate::message::DebugOverlayKind::RepaintRainbow,
@@ -480,6 +667,27 @@ fn handle_key_devtools(state: &AppState, key: InputKey) -> Option<Message> {
         InputKey::CharCtrl('d') => Some(Message::ToggleDebugOverlay {
             extension: crate::message::DebugOverlayKind::DebugPaint,
         }),
+        InputKey::CharCtrl('a') => Some(Message::ToggleDebugOverlay {
+            extension: crate::message::DebugOverlayKind::SlowAnimations,
+        }),
+        InputKey::CharCtrl('e') => Some(Message::ToggleDebugOverlay {
+            extension: crate::message::DebugOverlayKind::DebugBanner,
+        }),
+        InputKey::CharCtrl('g') => Some(Message::ToggleDebugOverlay {
+            extension: crate::message::DebugOverlayKind::InvertOversizedImages,
+        }),
+        InputKey::CharCtrl('t') => Some(Message::ToggleDebugOverlay {
+            extension: crate::message::DebugOverlayKind::ProfileWidgetBuilds,
+        }),
+        InputKey::CharCtrl('l') => Some(Message::ToggleDebugOverlay {
+            extension: crate::message::DebugOverlayKind::PaintBaselines,
+        }),
+        InputKey::CharCtrl('o') => Some(Message::CycleDebugOverride {
+            kind: crate::message::DebugOverrideKind::Platform,
+        }),
+        InputKey::CharCtrl('b') => Some(Message::CycleDebugOverride {
+            kind: crate::message::DebugOverrideKind::Brightness,
+        }),
 
         // ── Network panel — list navigation ───────────────────────────────────
         InputKey::Up | InputKey::Char('k') if in_network => {
@@ -533,6 +741,24 @@ fn handle_key_devtools(state: &AppState, key: InputKey) -> Option<Message> {
         // ── Network panel — enter filter input mode ───────────────────────────
         InputKey::Char('/') if in_network => Some(Message::NetworkEnterFilterMode),
 
+        // ── Network panel — waterfall view ────────────────────────────────────
+        InputKey::Char('w') if in_network => Some(Message::NetworkToggleWaterfall),
+        InputKey::Char('+' | '=') if in_waterfall => {
+            Some(Message::NetworkWaterfallNavigate(WaterfallNav::ZoomIn))
+        }
+        InputKey::Char('-') if in_waterfall => {
+            Some(Message::NetworkWaterfallNavigate(WaterfallNav::ZoomOut))
+        }
+        InputKey::Left if in_waterfall => {
+            Some(Message::NetworkWaterfallNavigate(WaterfallNav::PanLeft))
+        }
+        InputKey::Right if in_waterfall => {
+            Some(Message::NetworkWaterfallNavigate(WaterfallNav::PanRight))
+        }
+        InputKey::Char('0') if in_waterfall => {
+            Some(Message::NetworkWaterfallNavigate(WaterfallNav::Reset))
+        }
+
         // ── Inspector navigation (only active in Inspector panel) ─────────────
         InputKey::Up | InputKey::Char('k') if in_inspector => {
             Some(Message::DevToolsInspectorNavigate(InspectorNav::Up))
@@ -546,11 +772,162 @@ fn handle_key_devtools(state: &AppState, key: InputKey) -> Option<Message> {
         InputKey::Left | InputKey::Char('h') if in_inspector => {
             Some(Message::DevToolsInspectorNavigate(InspectorNav::Collapse))
         }
+        // ── Inspector search and user-code filter ─────────────────────────────
+        InputKey::Char('/') if in_inspector => Some(Message::InspectorEnterSearchMode),
+        InputKey::Char(']') if in_inspector => {
+            Some(Message::DevToolsInspectorNavigate(InspectorNav::NextMatch))
+        }
+        InputKey::Char('[') if in_inspector => {
+            Some(Message::DevToolsInspectorNavigate(InspectorNav::PrevMatch))
+        }
+        InputKey::Char('u') if in_inspector => Some(Message::InspectorToggleUserCodeOnly),
+        InputKey::Char('t') if in_inspector => Some(Message::InspectorSetSelectMode {
+            enabled: !state.devtools_view_state.inspector.select_mode,
+        }),
+        // ── Inspector details pane ────────────────────────────────────────────
+        InputKey::Char('v') if in_inspector => Some(Message::InspectorToggleDetailView),
+        InputKey::Tab if in_inspector => Some(Message::InspectorToggleFocus),
         // 'r' in Inspector panel refreshes the widget tree.
         InputKey::Char('r') if in_inspector => {
             active_id.map(|session_id| Message::RequestWidgetTree { session_id })
         }
 
+        // ── Dumps panel ───────────────────────────────────────────────────────
+        InputKey::Up | InputKey::Char('k') if in_dumps => Some(Message::DumpNavigate(DumpNav::Up)),
+        InputKey::Down | InputKey::Char('j') if in_dumps => {
+            Some(Message::DumpNavigate(DumpNav::Down))
+        }
+        InputKey::PageUp if in_dumps => Some(Message::DumpNavigate(DumpNav::PageUp)),
+        InputKey::PageDown if in_dumps => Some(Message::DumpNavigate(DumpNav::PageDown)),
+        InputKey::Enter | InputKey::Right if in_dumps => {
+            Some(Message::DumpNavigate(DumpNav::Expand))
+        }
+        InputKey::Left | InputKey::Char('h') if in_dumps => {
+            Some(Message::DumpNavigate(DumpNav::Collapse))
+        }
+        InputKey::Char('/') if in_dumps => Some(Message::DumpEnterSearchMode),
+        InputKey::Char(']') if in_dumps => Some(Message::DumpNavigate(DumpNav::NextMatch)),
+        InputKey::Char('[') if in_dumps => Some(Message::DumpNavigate(DumpNav::PrevMatch)),
+        InputKey::Char(c @ '1'..='5') if in_dumps => {
+            let index = c as usize - '1' as usize;
+            Some(Message::DumpSelectKind(DebugDumpKind::ALL[index]))
+        }
+        InputKey::Char('r') if in_dumps => {
+            active_id.map(|session_id| Message::RequestDump { session_id })
+        }
+        InputKey::Char('s') if in_dumps => Some(Message::SaveDump),
+
+        // ── CPU profiler panel ────────────────────────────────────────────────
+        InputKey::Char(' ') if in_profiler => Some(Message::ProfilerToggleRecording),
+        InputKey::Char(c @ '1'..='3') if in_profiler => {
+            let index = c as usize - '1' as usize;
+            Some(Message::ProfilerSelectView(ProfilerView::ALL[index]))
+        }
+        InputKey::Char('f') if in_profiler => Some(Message::ProfilerToggleProjectOnly),
+        InputKey::Up | InputKey::Char('k') if in_profiler => {
+            Some(Message::ProfilerNavigate(ProfilerNav::Up))
+        }
+        InputKey::Down | InputKey::Char('j') if in_profiler => {
+            Some(Message::ProfilerNavigate(ProfilerNav::Down))
+        }
+        InputKey::PageUp if in_profiler => Some(Message::ProfilerNavigate(ProfilerNav::PageUp)),
+        InputKey::PageDown if in_profiler => Some(Message::ProfilerNavigate(ProfilerNav::PageDown)),
+        InputKey::Enter | InputKey::Right if in_profiler => {
+            Some(Message::ProfilerNavigate(ProfilerNav::Expand))
+        }
+        InputKey::Left | InputKey::Char('h') if in_profiler => {
+            Some(Message::ProfilerNavigate(ProfilerNav::Collapse))
+        }
+
+        // ── Console panel ─────────────────────────────────────────────────────
+        InputKey::Enter if in_console => Some(Message::ConsoleFocusInput),
+        InputKey::Up | InputKey::Char('k') if in_console => {
+            Some(Message::ConsoleNavigate(ConsoleNav::Up))
+        }
+        InputKey::Down | InputKey::Char('j') if in_console => {
+            Some(Message::ConsoleNavigate(ConsoleNav::Down))
+        }
+        InputKey::PageUp if in_console => Some(Message::ConsoleNavigate(ConsoleNav::PageUp)),
+        InputKey::PageDown if in_console => Some(Message::ConsoleNavigate(ConsoleNav::PageDown)),
+
+        // ── Extensions panel ──────────────────────────────────────────────────
+        InputKey::Up | InputKey::Char('k') if in_extensions => {
+            Some(Message::ExtensionsNavigate(ExtensionNav::Up))
+        }
+        InputKey::Down | InputKey::Char('j') if in_extensions => {
+            Some(Message::ExtensionsNavigate(ExtensionNav::Down))
+        }
+        InputKey::Enter if in_extensions => Some(Message::CallSelectedExtension),
+        InputKey::Char('r') if in_extensions => Some(Message::RequestServiceExtensions),
+
+        // ── Performance panel — export ────────────────────────────────────────
+        InputKey::Char('e') if in_performance => Some(Message::ExportPerformance),
+
+        // ── Performance panel — baselines ─────────────────────────────────────
+        InputKey::Char('B') if in_performance => Some(Message::SavePerfBaseline { name: None }),
+        InputKey::Char('C') if in_performance => Some(Message::ComparePerfBaseline { name: None }),
+
+        // ── Performance panel — widget rebuild tracking ───────────────────────
+        //
+        // While tracking is on the rebuild table replaces the allocation
+        // table, so 's' sorts the rebuild table instead.
+        InputKey::Char('w') if in_performance => Some(Message::ToggleRebuildTracking),
+        InputKey::Char('s') if in_rebuilds => Some(Message::ToggleRebuildSort),
+        InputKey::Up | InputKey::Char('k') if in_rebuilds => {
+            state
+                .session_manager
+                .selected()
+                .map(|h| Message::SelectRebuildRow {
+                    index: h.session.performance.rebuild_selected.saturating_sub(1),
+                })
+        }
+        InputKey::Down | InputKey::Char('j') if in_rebuilds => state
+            .session_manager
+            .selected()
+            .map(|h| Message::SelectRebuildRow {
+                index: h.session.performance.rebuild_selected + 1,
+            }),
+        InputKey::Enter if in_rebuilds => Some(Message::OpenRebuildLocation),
+        InputKey::CharCtrl('x') if in_rebuilds => Some(Message::ClearRebuildCounts),
+
+        // ── Performance panel — instance browser ──────────────────────────────
+        //
+        // Up/Down select an allocation table row and Enter lists the live
+        // instances of its class. While the browser is open they select an
+        // instance and Enter fetches its fields and retaining path.
+        InputKey::Up | InputKey::Char('k') if in_instances => state
+            .session_manager
+            .selected()
+            .and_then(|h| h.session.performance.instance_browser.as_ref())
+            .map(|b| Message::SelectInstance {
+                index: b.selected.saturating_sub(1),
+            }),
+        InputKey::Down | InputKey::Char('j') if in_instances => state
+            .session_manager
+            .selected()
+            .and_then(|h| h.session.performance.instance_browser.as_ref())
+            .map(|b| Message::SelectInstance {
+                index: b.selected + 1,
+            }),
+        InputKey::Enter if in_instances => Some(Message::InspectInstance),
+        InputKey::Up | InputKey::Char('k') if in_allocations => state
+            .session_manager
+            .selected()
+            .map(|h| Message::SelectAllocationRow {
+                index: h.session.performance.allocation_selected.saturating_sub(1),
+            }),
+        InputKey::Down | InputKey::Char('j') if in_allocations => state
+            .session_manager
+            .selected()
+            .map(|h| Message::SelectAllocationRow {
+                index: h.session.performance.allocation_selected + 1,
+            }),
+        InputKey::Enter if in_allocations => Some(Message::OpenInstanceBrowser),
+
+        // ── Performance panel — allocation diffs ──────────────────────────────
+        InputKey::Char('H') if in_performance => Some(Message::CaptureAllocationDiff),
+        InputKey::CharCtrl('x') if in_performance => Some(Message::ClearAllocationDiff),
+
         // ── Performance panel — allocation table sort ─────────────────────────
         //
         // 's' toggles the allocation table sort column between BySize and
@@ -1705,6 +2082,284 @@ mod performance_sort_key_tests {
         // 's' has no binding in the Inspector panel.
         assert!(msg.is_none(), "'s' in Inspector panel should return None");
     }
+
+    #[test]
+    fn test_rebuild_table_keys_only_while_tracking() {
+        let mut state = make_state_in_performance_panel();
+        assert!(matches!(
+            handle_key_devtools(&state, InputKey::Char('w')),
+            Some(Message::ToggleRebuildTracking)
+        ));
+        assert!(matches!(
+            handle_key_devtools(&state, InputKey::Char('e')),
+            Some(Message::ExportPerformance)
+        ));
+        assert!(matches!(
+            handle_key_devtools(&state, InputKey::Char('B')),
+            Some(Message::SavePerfBaseline { name: None })
+        ));
+        assert!(matches!(
+            handle_key_devtools(&state, InputKey::Char('C')),
+            Some(Message::ComparePerfBaseline { name: None })
+        ));
+        assert!(matches!(
+            handle_key_devtools(&state, InputKey::Enter),
+            Some(Message::OpenInstanceBrowser)
+        ));
+        assert!(matches!(
+            handle_key_devtools(&state, InputKey::Char('H')),
+            Some(Message::CaptureAllocationDiff)
+        ));
+        assert!(matches!(
+            handle_key_devtools(&state, InputKey::CharCtrl('x')),
+            Some(Message::ClearAllocationDiff)
+        ));
+
+        state
+            .session_manager
+            .selected_mut()
+            .unwrap()
+            .session
+            .performance
+            .rebuild_tracking = true;
+        assert!(matches!(
+            handle_key_devtools(&state, InputKey::Char('s')),
+            Some(Message::ToggleRebuildSort)
+        ));
+        assert!(matches!(
+            handle_key_devtools(&state, InputKey::Down),
+            Some(Message::SelectRebuildRow { index: 1 })
+        ));
+        assert!(matches!(
+            handle_key_devtools(&state, InputKey::Enter),
+            Some(Message::OpenRebuildLocation)
+        ));
+        assert!(matches!(
+            handle_key_devtools(&state, InputKey::CharCtrl('x')),
+            Some(Message::ClearRebuildCounts)
+        ));
+    }
+
+    #[test]
+    fn test_isolate_picker_key() {
+        let mut state = make_state_in_performance_panel();
+        for panel in [DevToolsPanel::Performance, DevToolsPanel::Profiler] {
+            state.devtools_view_state.active_panel = panel;
+            assert!(matches!(
+                handle_key_devtools(&state, InputKey::Char('I')),
+                Some(Message::CycleDevToolsIsolate)
+            ));
+        }
+    }
+
+    #[test]
+    fn test_extension_button_keys_take_precedence_in_extensions_panel() {
+        let mut state = make_state_in_performance_panel();
+        assert!(matches!(
+            handle_key_devtools(&state, InputKey::Char('x')),
+            Some(Message::SwitchDevToolsPanel(DevToolsPanel::Extensions))
+        ));
+
+        state.devtools_view_state.active_panel = DevToolsPanel::Extensions;
+        state.devtools_view_state.extensions.buttons = vec![crate::config::ExtensionButton {
+            label: "Debug menu".to_string(),
+            method: "ext.myapp.debugMenu".to_string(),
+            key: Some('p'),
+            args: Default::default(),
+        }];
+        assert!(matches!(
+            handle_key_devtools(&state, InputKey::Char('p')),
+            Some(Message::PressExtensionButton(0))
+        ));
+        assert!(matches!(
+            handle_key_devtools(&state, InputKey::Char('j')),
+            Some(Message::ExtensionsNavigate(ExtensionNav::Down))
+        ));
+        assert!(matches!(
+            handle_key_devtools(&state, InputKey::Enter),
+            Some(Message::CallSelectedExtension)
+        ));
+        assert!(matches!(
+            handle_key_devtools(&state, InputKey::Char('r')),
+            Some(Message::RequestServiceExtensions)
+        ));
+    }
+
+    #[test]
+    fn test_console_keys_route_to_input_when_focused() {
+        let mut state = make_state_in_performance_panel();
+        assert!(matches!(
+            handle_key_devtools(&state, InputKey::Char(':')),
+            Some(Message::ConsoleFocusInput)
+        ));
+
+        state.devtools_view_state.active_panel = DevToolsPanel::Console;
+        assert!(matches!(
+            handle_key_devtools(&state, InputKey::Char('k')),
+            Some(Message::ConsoleNavigate(ConsoleNav::Up))
+        ));
+
+        state.devtools_view_state.console.input_active = true;
+        assert!(matches!(
+            handle_key_devtools(&state, InputKey::Char('k')),
+            Some(Message::ConsoleInput('k'))
+        ));
+        assert!(matches!(
+            handle_key_devtools(&state, InputKey::Tab),
+            Some(Message::ConsoleComplete)
+        ));
+        assert!(matches!(
+            handle_key_devtools(&state, InputKey::Up),
+            Some(Message::ConsoleHistory { older: true })
+        ));
+        assert!(matches!(
+            handle_key_devtools(&state, InputKey::Esc),
+            Some(Message::ConsoleBlurInput)
+        ));
+    }
+
+    #[test]
+    fn test_allocation_and_instance_browser_keys() {
+        let mut state = make_state_in_performance_panel();
+        assert!(matches!(
+            handle_key_devtools(&state, InputKey::Down),
+            Some(Message::SelectAllocationRow { index: 1 })
+        ));
+        assert!(matches!(
+            handle_key_devtools(&state, InputKey::Enter),
+            Some(Message::OpenInstanceBrowser)
+        ));
+
+        state
+            .session_manager
+            .selected_mut()
+            .unwrap()
+            .session
+            .performance
+            .instance_browser = Some(crate::session::InstanceBrowser::new(
+            "Listener".to_string(),
+            "classes/7".to_string(),
+        ));
+        assert!(matches!(
+            handle_key_devtools(&state, InputKey::Char('j')),
+            Some(Message::SelectInstance { index: 1 })
+        ));
+        assert!(matches!(
+            handle_key_devtools(&state, InputKey::Enter),
+            Some(Message::InspectInstance)
+        ));
+        assert!(matches!(
+            handle_key_devtools(&state, InputKey::Esc),
+            Some(Message::CloseInstanceBrowser)
+        ));
+    }
+}
+
+#[cfg(test)]
+mod dump_key_tests {
+    use super::*;
+
+    fn make_state_in_dumps_panel() -> AppState {
+        let mut state = AppState::new();
+        let device = fdemon_daemon::Device {
+            id: "test-device".to_string(),
+            name: "Test Device".to_string(),
+            platform: "android".to_string(),
+            emulator: false,
+            category: None,
+            platform_type: None,
+            ephemeral: false,
+            emulator_id: None,
+        };
+        let _session_id = state.session_manager.create_session(&device).unwrap();
+        state.ui_mode = UiMode::DevTools;
+        state.devtools_view_state.active_panel = DevToolsPanel::Dumps;
+        state
+    }
+
+    #[test]
+    fn test_dump_panel_keys() {
+        let mut state = make_state_in_dumps_panel();
+        assert!(matches!(
+            handle_key_devtools(&state, InputKey::Char('4')),
+            Some(Message::DumpSelectKind(DebugDumpKind::SemanticsTree))
+        ));
+        assert!(matches!(
+            handle_key_devtools(&state, InputKey::Char('s')),
+            Some(Message::SaveDump)
+        ));
+        assert!(matches!(
+            handle_key_devtools(&state, InputKey::Left),
+            Some(Message::DumpNavigate(DumpNav::Collapse))
+        ));
+
+        state.devtools_view_state.dumps.search_input_active = true;
+        assert!(matches!(
+            handle_key_devtools(&state, InputKey::Char('s')),
+            Some(Message::DumpSearchInput('s'))
+        ));
+    }
+
+    #[test]
+    fn test_profiler_panel_keys() {
+        let mut state = make_state_in_dumps_panel();
+        assert!(matches!(
+            handle_key_devtools(&state, InputKey::Char('c')),
+            Some(Message::SwitchDevToolsPanel(DevToolsPanel::Profiler))
+        ));
+
+        state.devtools_view_state.active_panel = DevToolsPanel::Profiler;
+        assert!(matches!(
+            handle_key_devtools(&state, InputKey::Char(' ')),
+            Some(Message::ProfilerToggleRecording)
+        ));
+        assert!(matches!(
+            handle_key_devtools(&state, InputKey::Char('3')),
+            Some(Message::ProfilerSelectView(ProfilerView::FlameGraph))
+        ));
+        assert!(matches!(
+            handle_key_devtools(&state, InputKey::Char('f')),
+            Some(Message::ProfilerToggleProjectOnly)
+        ));
+        assert!(matches!(
+            handle_key_devtools(&state, InputKey::Right),
+            Some(Message::ProfilerNavigate(ProfilerNav::Expand))
+        ));
+    }
+
+    #[test]
+    fn test_d_switches_to_dumps_panel() {
+        let mut state = make_state_in_dumps_panel();
+        state.devtools_view_state.active_panel = DevToolsPanel::Inspector;
+        assert!(matches!(
+            handle_key_devtools(&state, InputKey::Char('d')),
+            Some(Message::SwitchDevToolsPanel(DevToolsPanel::Dumps))
+        ));
+    }
+
+    #[test]
+    fn test_debug_toggle_keys_work_in_any_panel() {
+        use crate::message::{DebugOverlayKind, DebugOverrideKind};
+        let state = make_state_in_dumps_panel();
+        assert!(matches!(
+            handle_key_devtools(&state, InputKey::CharCtrl('a')),
+            Some(Message::ToggleDebugOverlay {
+                extension: DebugOverlayKind::SlowAnimations
+            })
+        ));
+        assert!(matches!(
+            handle_key_devtools(&state, InputKey::CharCtrl('l')),
+            Some(Message::ToggleDebugOverlay {
+                extension: DebugOverlayKind::PaintBaselines
+            })
+        ));
+        assert!(matches!(
+            handle_key_devtools(&state, InputKey::CharCtrl('o')),
+            Some(Message::CycleDebugOverride {
+                kind: DebugOverrideKind::Platform
+            })
+        ));
+    }
 }
 
 #[cfg(test)]
@@ -1811,21 +2466,111 @@ mod network_filter_key_tests {
         );
     }
 
-    #[test]
-    fn test_slash_does_not_trigger_filter_mode_in_inspector() {
+    fn make_state_in_inspector_panel() -> AppState {
         let mut state = AppState::new();
         let device = test_device();
         let _session_id = state.session_manager.create_session(&device).unwrap();
         state.ui_mode = UiMode::DevTools;
         state.devtools_view_state.active_panel = DevToolsPanel::Inspector;
+        state
+    }
+
+    #[test]
+    fn test_slash_in_inspector_enters_search_not_network_filter() {
+        let state = make_state_in_inspector_panel();
         let msg = handle_key_devtools(&state, InputKey::Char('/'));
-        // '/' has no binding in the Inspector panel.
         assert!(
-            msg.is_none(),
-            "'/' in Inspector panel should not emit NetworkEnterFilterMode"
+            matches!(msg, Some(Message::InspectorEnterSearchMode)),
+            "'/' in Inspector panel should open the inspector search, got {msg:?}"
         );
     }
 
+    #[test]
+    fn test_inspector_search_mode_routes_keys_to_query() {
+        let mut state = make_state_in_inspector_panel();
+        state.devtools_view_state.inspector.search_input_active = true;
+        assert!(matches!(
+            handle_key_devtools(&state, InputKey::Char('j')),
+            Some(Message::InspectorSearchInput('j'))
+        ));
+        assert!(matches!(
+            handle_key_devtools(&state, InputKey::Down),
+            Some(Message::DevToolsInspectorNavigate(InspectorNav::NextMatch))
+        ));
+        assert!(matches!(
+            handle_key_devtools(&state, InputKey::Enter),
+            Some(Message::InspectorCommitSearch)
+        ));
+        assert!(matches!(
+            handle_key_devtools(&state, InputKey::Esc),
+            Some(Message::InspectorExitSearchMode)
+        ));
+    }
+
+    #[test]
+    fn test_inspector_match_stepping_and_user_code_toggle() {
+        let state = make_state_in_inspector_panel();
+        assert!(matches!(
+            handle_key_devtools(&state, InputKey::Char(']')),
+            Some(Message::DevToolsInspectorNavigate(InspectorNav::NextMatch))
+        ));
+        assert!(matches!(
+            handle_key_devtools(&state, InputKey::Char('[')),
+            Some(Message::DevToolsInspectorNavigate(InspectorNav::PrevMatch))
+        ));
+        assert!(matches!(
+            handle_key_devtools(&state, InputKey::Char('u')),
+            Some(Message::InspectorToggleUserCodeOnly)
+        ));
+    }
+
+    #[test]
+    fn test_properties_focus_routes_navigation_keys() {
+        let mut state = make_state_in_inspector_panel();
+        assert!(matches!(
+            handle_key_devtools(&state, InputKey::Tab),
+            Some(Message::InspectorToggleFocus)
+        ));
+        assert!(matches!(
+            handle_key_devtools(&state, InputKey::Char('v')),
+            Some(Message::InspectorToggleDetailView)
+        ));
+
+        state.devtools_view_state.inspector.properties_focused = true;
+        assert!(matches!(
+            handle_key_devtools(&state, InputKey::Char('j')),
+            Some(Message::InspectorPropertiesNavigate(InspectorNav::Down))
+        ));
+        assert!(matches!(
+            handle_key_devtools(&state, InputKey::Enter),
+            Some(Message::InspectorPropertiesNavigate(InspectorNav::Expand))
+        ));
+        // Esc returns focus to the tree instead of leaving DevTools.
+        assert!(matches!(
+            handle_key_devtools(&state, InputKey::Esc),
+            Some(Message::InspectorToggleFocus)
+        ));
+        // Keys without a pane binding keep their panel meaning.
+        assert!(matches!(
+            handle_key_devtools(&state, InputKey::Char('r')),
+            Some(Message::RequestWidgetTree { .. })
+        ));
+    }
+
+    #[test]
+    fn test_t_in_inspector_toggles_select_mode() {
+        let mut state = make_state_in_inspector_panel();
+        assert!(matches!(
+            handle_key_devtools(&state, InputKey::Char('t')),
+            Some(Message::InspectorSetSelectMode { enabled: true })
+        ));
+        state.devtools_view_state.inspector.select_mode = true;
+        assert!(matches!(
+            handle_key_devtools(&state, InputKey::Char('t')),
+            Some(Message::InspectorSetSelectMode { enabled: false })
+        ));
+    }
+
     #[test]
     fn test_filter_mode_unknown_key_returns_none() {
         let state = make_state_in_network_filter_mode();
@@ -1837,6 +2582,85 @@ mod network_filter_key_tests {
     }
 }
 
+#[cfg(test)]
+mod network_waterfall_key_tests {
+    use super::*;
+    use crate::session::NetworkViewMode;
+
+    fn test_device() -> fdemon_daemon::Device {
+        fdemon_daemon::Device {
+            id: "test-device".to_string(),
+            name: "Test Device".to_string(),
+            platform: "android".to_string(),
+            emulator: false,
+            category: None,
+            platform_type: None,
+            ephemeral: false,
+            emulator_id: None,
+        }
+    }
+
+    fn make_state_in_network_panel(view_mode: NetworkViewMode) -> AppState {
+        let mut state = AppState::new();
+        let device = test_device();
+        let _session_id = state.session_manager.create_session(&device).unwrap();
+        state.ui_mode = UiMode::DevTools;
+        state.devtools_view_state.active_panel = DevToolsPanel::Network;
+        state
+            .session_manager
+            .selected_mut()
+            .unwrap()
+            .session
+            .network
+            .view_mode = view_mode;
+        state
+    }
+
+    #[test]
+    fn test_w_toggles_waterfall() {
+        let state = make_state_in_network_panel(NetworkViewMode::Table);
+        let msg = handle_key_devtools(&state, InputKey::Char('w'));
+        assert!(matches!(msg, Some(Message::NetworkToggleWaterfall)));
+    }
+
+    #[test]
+    fn test_zoom_keys_in_waterfall() {
+        let state = make_state_in_network_panel(NetworkViewMode::Waterfall);
+        assert!(matches!(
+            handle_key_devtools(&state, InputKey::Char('+')),
+            Some(Message::NetworkWaterfallNavigate(WaterfallNav::ZoomIn))
+        ));
+        assert!(matches!(
+            handle_key_devtools(&state, InputKey::Char('-')),
+            Some(Message::NetworkWaterfallNavigate(WaterfallNav::ZoomOut))
+        ));
+        assert!(matches!(
+            handle_key_devtools(&state, InputKey::Right),
+            Some(Message::NetworkWaterfallNavigate(WaterfallNav::PanRight))
+        ));
+        assert!(matches!(
+            handle_key_devtools(&state, InputKey::Char('0')),
+            Some(Message::NetworkWaterfallNavigate(WaterfallNav::Reset))
+        ));
+    }
+
+    #[test]
+    fn test_zoom_keys_ignored_in_table_view() {
+        let state = make_state_in_network_panel(NetworkViewMode::Table);
+        assert!(handle_key_devtools(&state, InputKey::Char('+')).is_none());
+        assert!(handle_key_devtools(&state, InputKey::Left).is_none());
+    }
+
+    #[test]
+    fn test_navigation_still_works_in_waterfall() {
+        let state = make_state_in_network_panel(NetworkViewMode::Waterfall);
+        assert!(matches!(
+            handle_key_devtools(&state, InputKey::Down),
+            Some(Message::NetworkNavigate(NetworkNav::Down))
+        ));
+    }
+}
+
 #[cfg(test)]
 mod dap_key_tests {
     use super::*;
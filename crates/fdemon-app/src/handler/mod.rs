@@ -16,6 +16,10 @@
 //! - `scroll`: Scroll handlers
 //! - `log_view`: Log view operation handlers
 //! - `flutter_version`: Flutter Version panel handlers
+//! - `screenshot`: Device screenshot capture handlers
+//! - `session_group`: Session group reload/restart/stop handlers
+//! - `session_picker`: Session picker overlay handlers
+//! - `workspace`: Workspace snapshot and restore handlers
 
 pub(crate) mod daemon;
 pub(crate) mod dap;
@@ -26,14 +30,18 @@ pub(crate) mod helpers;
 pub(crate) mod keys;
 pub(crate) mod log_view;
 pub(crate) mod new_session;
+pub(crate) mod screenshot;
 pub(crate) mod scroll;
 pub(crate) mod session;
+pub(crate) mod session_group;
 pub(crate) mod session_lifecycle;
+pub(crate) mod session_picker;
 pub(crate) mod settings;
 pub(crate) mod settings_dart_defines;
 pub(crate) mod settings_extra_args;
 pub(crate) mod settings_handlers;
 pub(crate) mod update;
+pub(crate) mod workspace;
 
 #[cfg(test)]
 mod tests;
@@ -52,6 +60,20 @@ pub(crate) use helpers::detect_raw_line_level;
 #[cfg(test)]
 pub(crate) use keys::handle_key;
 
+/// A session to capture in [`UpdateAction::CaptureScreenshots`].
+#[derive(Debug, Clone)]
+pub struct ScreenshotTarget {
+    pub session_id: SessionId,
+    pub device_id: String,
+    pub device_name: String,
+    /// App ID for the daemon's `app.screenshot` request.
+    pub app_id: Option<String>,
+    /// Daemon command sender (hydrated by `process.rs`).
+    pub cmd_sender: Option<fdemon_daemon::CommandSender>,
+    /// VM Service request handle (hydrated by `process.rs`).
+    pub vm_handle: Option<fdemon_daemon::vm_service::VmRequestHandle>,
+}
+
 /// Actions that the event loop should perform after update
 #[derive(Debug, Clone)]
 pub enum UpdateAction {
@@ -110,6 +132,21 @@ pub enum UpdateAction {
         cache_allowed: bool,
     },
 
+    /// Discover devices and match them against a saved workspace
+    /// Used when restore_workspace=true to relaunch the saved sessions
+    DiscoverDevicesAndRestoreWorkspace {
+        /// Pre-loaded configs to resolve saved config names
+        configs: LoadedConfigs,
+        /// Workspace loaded at startup
+        workspace: crate::config::Workspace,
+        /// Flutter executable to use for device discovery.
+        flutter: FlutterExecutable,
+    },
+
+    /// Run reload/restart/stop tasks for a session group concurrently
+    /// (command senders are looked up by process.rs)
+    SpawnGroupTasks { tasks: Vec<Task> },
+
     /// Discover available emulators
     DiscoverEmulators {
         /// Flutter executable to use for emulator discovery.
@@ -136,6 +173,8 @@ pub enum UpdateAction {
         config: Option<Box<LaunchConfig>>,
         /// Flutter executable to use for spawning the process.
         flutter: FlutterExecutable,
+        /// Local HTTP proxy to start before launch (`None` = disabled).
+        proxy: Option<crate::config::NetworkProxySettings>,
     },
 
     /// Reload all running sessions (file watcher auto-reload)
@@ -248,6 +287,238 @@ pub enum UpdateAction {
         vm_handle: Option<fdemon_daemon::vm_service::VmRequestHandle>,
     },
 
+    /// Advance a debug override (platform, brightness) via VM Service
+    /// extension call.
+    ///
+    /// `vm_handle` is `None` until hydrated by `process.rs` from the session's
+    /// `vm_request_handle`.
+    CycleOverride {
+        session_id: SessionId,
+        kind: crate::message::DebugOverrideKind,
+        /// The active override shown in the view, `None` when not overridden.
+        current: Option<String>,
+        /// VM Service request handle used for the RPC calls.
+        vm_handle: Option<fdemon_daemon::vm_service::VmRequestHandle>,
+    },
+
+    /// Fetch the details subtree (all diagnostics properties) of a widget.
+    ///
+    /// `vm_handle` is `None` until hydrated by `process.rs` from the session's
+    /// `vm_request_handle`.
+    FetchWidgetProperties {
+        session_id: SessionId,
+        node_id: String,
+        /// VM Service request handle used for the RPC call.
+        vm_handle: Option<fdemon_daemon::vm_service::VmRequestHandle>,
+    },
+
+    /// Enable or disable on-device widget selection via
+    /// `ext.flutter.inspector.show`.
+    ///
+    /// `vm_handle` is `None` until hydrated by `process.rs` from the session's
+    /// `vm_request_handle`.
+    SetInspectorSelectMode {
+        session_id: SessionId,
+        enabled: bool,
+        /// VM Service request handle used for the RPC call.
+        vm_handle: Option<fdemon_daemon::vm_service::VmRequestHandle>,
+    },
+
+    /// Fetch the widget currently selected on the device.
+    ///
+    /// `vm_handle` is `None` until hydrated by `process.rs` from the session's
+    /// `vm_request_handle`.
+    FetchSelectedWidget {
+        session_id: SessionId,
+        /// VM Service request handle used for the RPC call.
+        vm_handle: Option<fdemon_daemon::vm_service::VmRequestHandle>,
+    },
+
+    /// Enable or disable `Flutter.RebuiltWidgets` events via
+    /// `ext.flutter.inspector.trackRebuildDirtyWidgets`.
+    ///
+    /// `vm_handle` is `None` until hydrated by `process.rs` from the session's
+    /// `vm_request_handle`.
+    SetRebuildTracking {
+        session_id: SessionId,
+        enabled: bool,
+        /// VM Service request handle used for the RPC call.
+        vm_handle: Option<fdemon_daemon::vm_service::VmRequestHandle>,
+    },
+
+    /// Fetch a text dump (`ext.flutter.debugDump*`) for the dump viewer.
+    ///
+    /// `vm_handle` is `None` until hydrated by `process.rs` from the session's
+    /// `vm_request_handle`.
+    FetchDump {
+        session_id: SessionId,
+        kind: crate::dump_view_state::DebugDumpKind,
+        /// VM Service request handle used for the RPC call.
+        vm_handle: Option<fdemon_daemon::vm_service::VmRequestHandle>,
+    },
+
+    /// Write a loaded dump to `.fdemon/dumps/<kind>-<timestamp>.txt`.
+    SaveDump {
+        kind: crate::dump_view_state::DebugDumpKind,
+        text: String,
+    },
+
+    /// Write frame, memory and GC data as CSV, JSON and Chrome trace files
+    /// under `.fdemon/perf/`.
+    ExportPerformance {
+        session_id: SessionId,
+        capture: fdemon_core::PerfCapture,
+    },
+
+    /// Write a baseline to `.fdemon/baselines/<name>.json`.
+    SavePerfBaseline {
+        session_id: SessionId,
+        baseline: fdemon_core::PerfBaseline,
+    },
+
+    /// Load the baseline named `current.name` and compare `current` against it.
+    ComparePerfBaseline {
+        session_id: SessionId,
+        current: fdemon_core::PerfBaseline,
+        thresholds: fdemon_core::PerfThresholds,
+    },
+
+    /// Fetch an allocation profile after a forced GC for an allocation diff.
+    ///
+    /// `vm_handle` is `None` until hydrated by `process.rs` from the session's
+    /// `vm_request_handle`.
+    CaptureAllocationDiff {
+        session_id: SessionId,
+        /// VM Service request handle used for the RPC call.
+        vm_handle: Option<fdemon_daemon::vm_service::VmRequestHandle>,
+    },
+
+    /// Wait for the app to settle after a reload or route change, then fetch
+    /// an allocation profile after a forced GC for a leak hint checkpoint.
+    ///
+    /// `vm_handle` is `None` until hydrated by `process.rs` from the session's
+    /// `vm_request_handle`.
+    CaptureLeakCheckpoint {
+        session_id: SessionId,
+        /// VM Service request handle used for the RPC call.
+        vm_handle: Option<fdemon_daemon::vm_service::VmRequestHandle>,
+    },
+
+    /// List live instances of the class `class_id`.
+    ///
+    /// `vm_handle` is `None` until hydrated by `process.rs` from the session's
+    /// `vm_request_handle`.
+    FetchInstances {
+        session_id: SessionId,
+        class_id: String,
+        /// VM Service request handle used for the RPC call.
+        vm_handle: Option<fdemon_daemon::vm_service::VmRequestHandle>,
+    },
+
+    /// Fetch the fields and retaining path of the instance `object_id`.
+    ///
+    /// `vm_handle` is `None` until hydrated by `process.rs` from the session's
+    /// `vm_request_handle`.
+    InspectInstance {
+        session_id: SessionId,
+        object_id: String,
+        /// VM Service request handle used for the RPC calls.
+        vm_handle: Option<fdemon_daemon::vm_service::VmRequestHandle>,
+    },
+
+    /// Clear old CPU samples and mark the start of a recording.
+    ///
+    /// `vm_handle` is `None` until hydrated by `process.rs` from the session's
+    /// `vm_request_handle`.
+    StartCpuProfiling {
+        session_id: SessionId,
+        /// VM Service request handle used for the RPC calls.
+        vm_handle: Option<fdemon_daemon::vm_service::VmRequestHandle>,
+    },
+
+    /// Fetch the CPU samples recorded since `origin_micros`.
+    ///
+    /// `vm_handle` is `None` until hydrated by `process.rs` from the session's
+    /// `vm_request_handle`.
+    StopCpuProfiling {
+        session_id: SessionId,
+        /// Start of the recording on the VM timeline clock.
+        origin_micros: i64,
+        /// VM Service request handle used for the RPC calls.
+        vm_handle: Option<fdemon_daemon::vm_service::VmRequestHandle>,
+    },
+
+    /// Send a raw VM Service RPC typed in the console.
+    ///
+    /// `vm_handle` is `None` until hydrated by `process.rs` from the session's
+    /// `vm_request_handle`.
+    CallConsoleRpc {
+        session_id: SessionId,
+        method: String,
+        params: Option<serde_json::Map<String, serde_json::Value>>,
+        /// VM Service request handle used for the RPC call.
+        vm_handle: Option<fdemon_daemon::vm_service::VmRequestHandle>,
+    },
+
+    /// Start (`tail`) or stop echoing a stream's events to the console,
+    /// subscribing to the stream if needed.
+    ///
+    /// `vm_handle` is `None` until hydrated by `process.rs` from the session's
+    /// `vm_request_handle`.
+    TailConsoleStream {
+        session_id: SessionId,
+        stream_id: String,
+        tail: bool,
+        /// VM Service request handle used for the RPC call.
+        vm_handle: Option<fdemon_daemon::vm_service::VmRequestHandle>,
+    },
+
+    /// Collect the service extensions registered by the session's isolates
+    /// for console completion.
+    ///
+    /// `vm_handle` is `None` until hydrated by `process.rs` from the session's
+    /// `vm_request_handle`.
+    FetchConsoleExtensions {
+        session_id: SessionId,
+        /// VM Service request handle used for the RPC calls.
+        vm_handle: Option<fdemon_daemon::vm_service::VmRequestHandle>,
+    },
+
+    /// Fetch the app-registered service extensions of the DevTools isolate.
+    ///
+    /// `vm_handle` is `None` until hydrated by `process.rs` from the session's
+    /// `vm_request_handle`.
+    FetchServiceExtensions {
+        session_id: SessionId,
+        /// VM Service request handle used for the RPC call.
+        vm_handle: Option<fdemon_daemon::vm_service::VmRequestHandle>,
+    },
+
+    /// Call a service extension on the DevTools isolate and log the result
+    /// under `label`.
+    ///
+    /// `vm_handle` is `None` until hydrated by `process.rs` from the session's
+    /// `vm_request_handle`.
+    CallServiceExtension {
+        session_id: SessionId,
+        label: String,
+        method: String,
+        args: std::collections::HashMap<String, String>,
+        /// VM Service request handle used for the RPC call.
+        vm_handle: Option<fdemon_daemon::vm_service::VmRequestHandle>,
+    },
+
+    /// Capture screenshots of one or more sessions into `dir`.
+    ///
+    /// Each target's `cmd_sender` and `vm_handle` are filled in by
+    /// `process.rs`; a target without either falls back to
+    /// `flutter screenshot` via `flutter`.
+    CaptureScreenshots {
+        targets: Vec<ScreenshotTarget>,
+        dir: std::path::PathBuf,
+        flutter: Option<FlutterExecutable>,
+    },
+
     /// Open the Flutter DevTools URL in the system browser.
     ///
     /// Fire-and-forget OS call — no VM Service handle needed.
@@ -304,6 +575,18 @@ pub enum UpdateAction {
         vm_handle: Option<fdemon_daemon::vm_service::VmRequestHandle>,
     },
 
+    /// Append network entries evicted from a session's history to
+    /// `.fdemon/network/<device>.jsonl` (`network_spill_to_disk`).
+    SpillNetworkEntries {
+        /// Device the session ran on; names the spill file.
+        device_id: String,
+        /// Evicted entries paired with their restart generation.
+        entries: Vec<(u32, fdemon_core::network::HttpProfileEntry)>,
+        /// Most entries the spill file keeps (`max_network_entries`); older
+        /// lines are dropped when it grows past this.
+        max_entries: usize,
+    },
+
     /// Dispose both DevTools VM object groups when exiting DevTools mode.
     ///
     /// Disposes `"fdemon-inspector-1"` and `"devtools-layout"` groups to
@@ -576,13 +859,26 @@ pub enum Task {
         session_id: SessionId,
         app_id: String,
     },
-    /// Stop the app (with session context for cmd_sender lookup)
+    /// Stop the app (with session context for cmd_sender lookup). Attached
+    /// apps are detached from instead, leaving them running.
     Stop {
         session_id: SessionId,
         app_id: String,
+        detach: bool,
     },
 }
 
+impl Task {
+    /// The session the task runs against.
+    pub fn session_id(&self) -> SessionId {
+        match self {
+            Task::Reload { session_id, .. }
+            | Task::Restart { session_id, .. }
+            | Task::Stop { session_id, .. } => *session_id,
+        }
+    }
+}
+
 /// Result of processing a message
 #[derive(Debug, Default)]
 pub struct UpdateResult {
@@ -537,6 +537,7 @@ pub fn handle_launch(state: &mut AppState) -> UpdateResult {
                         device,
                         config: config.map(Box::new),
                         flutter,
+                        proxy: state.settings.devtools.proxy_if_enabled(),
                     }
                 };
 
@@ -0,0 +1,195 @@
+//! Device screenshot handlers.
+//!
+//! `p` captures the selected session and `P` every running session. Each
+//! saved PNG is logged to its session as a `file://` URL, which terminals
+//! render as a clickable link.
+
+use std::path::Path;
+
+use crate::handler::{ScreenshotTarget, UpdateAction, UpdateResult};
+use crate::session::SessionId;
+use crate::state::AppState;
+use fdemon_core::{LogEntry, LogSource};
+
+/// Start a screenshot capture of the selected session (or all running ones).
+pub(crate) fn handle_capture_screenshot(state: &mut AppState, all_sessions: bool) -> UpdateResult {
+    let mut session_ids: Vec<SessionId> = if all_sessions {
+        state.session_manager.running_sessions()
+    } else {
+        state
+            .session_manager
+            .selected()
+            .filter(|h| h.session.is_running())
+            .map(|h| h.session.id)
+            .into_iter()
+            .collect()
+    };
+    session_ids.sort_unstable();
+
+    if session_ids.is_empty() {
+        if let Some(handle) = state.session_manager.selected_mut() {
+            handle.session.add_log(LogEntry::warn(
+                LogSource::App,
+                "Screenshot skipped: no running app",
+            ));
+        }
+        return UpdateResult::none();
+    }
+
+    let targets = session_ids
+        .iter()
+        .filter_map(|id| state.session_manager.get(*id))
+        .map(|h| ScreenshotTarget {
+            session_id: h.session.id,
+            device_id: h.session.device_id.clone(),
+            device_name: h.session.device_name.clone(),
+            app_id: h.session.app_id.clone(),
+            cmd_sender: None, // hydrated by process.rs
+            vm_handle: None,  // hydrated by process.rs
+        })
+        .collect();
+
+    UpdateResult::action(UpdateAction::CaptureScreenshots {
+        targets,
+        dir: state
+            .project_path
+            .join(&state.settings.behavior.screenshot_dir),
+        flutter: state.flutter_executable(),
+    })
+}
+
+/// Log the outcome of a capture to the session it was taken from.
+pub(crate) fn handle_screenshot_saved(
+    state: &mut AppState,
+    session_id: SessionId,
+    result: Result<std::path::PathBuf, String>,
+) -> UpdateResult {
+    let entry = match result {
+        Ok(path) => LogEntry::info(
+            LogSource::App,
+            format!("Screenshot saved: {}", file_url(&path)),
+        ),
+        Err(e) => {
+            tracing::warn!("Screenshot failed for session {}: {}", session_id, e);
+            LogEntry::error(LogSource::App, format!("Screenshot failed: {e}"))
+        }
+    };
+    if let Some(handle) = state.session_manager.get_mut(session_id) {
+        handle.session.add_log(entry);
+    }
+    UpdateResult::none()
+}
+
+/// `file://` URL for an absolute path, with forward slashes on every platform.
+pub(crate) fn file_url(path: &Path) -> String {
+    let path = path.display().to_string().replace('\\', "/");
+    if path.starts_with('/') {
+        format!("file://{path}")
+    } else {
+        format!("file:///{path}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::handler::update::update;
+    use crate::message::Message;
+
+    fn device(id: &str) -> fdemon_daemon::Device {
+        fdemon_daemon::Device {
+            id: id.to_string(),
+            name: format!("Device {id}"),
+            platform: "android".to_string(),
+            emulator: false,
+            category: None,
+            platform_type: None,
+            ephemeral: false,
+            emulator_id: None,
+        }
+    }
+
+    /// State with two running sessions; the first one is selected.
+    fn state_with_running_sessions() -> (AppState, SessionId, SessionId) {
+        let mut state = AppState::new();
+        let first = state.session_manager.create_session(&device("a")).unwrap();
+        let second = state.session_manager.create_session(&device("b")).unwrap();
+        for (id, app_id) in [(first, "app-a"), (second, "app-b")] {
+            let handle = state.session_manager.get_mut(id).unwrap();
+            handle.session.mark_started(app_id.to_string());
+        }
+        state.session_manager.select_by_id(first);
+        (state, first, second)
+    }
+
+    #[test]
+    fn test_capture_selected_session() {
+        let (mut state, first, _) = state_with_running_sessions();
+        let result = update(
+            &mut state,
+            Message::CaptureScreenshot {
+                all_sessions: false,
+            },
+        );
+        let Some(UpdateAction::CaptureScreenshots { targets, dir, .. }) = result.action else {
+            panic!("expected CaptureScreenshots action");
+        };
+        assert_eq!(targets.len(), 1);
+        assert_eq!(targets[0].session_id, first);
+        assert_eq!(targets[0].app_id.as_deref(), Some("app-a"));
+        assert!(dir.ends_with(".fdemon/screenshots"));
+    }
+
+    #[test]
+    fn test_capture_all_sessions() {
+        let (mut state, first, second) = state_with_running_sessions();
+        let result = update(
+            &mut state,
+            Message::CaptureScreenshot { all_sessions: true },
+        );
+        let Some(UpdateAction::CaptureScreenshots { targets, .. }) = result.action else {
+            panic!("expected CaptureScreenshots action");
+        };
+        let ids: Vec<_> = targets.iter().map(|t| t.session_id).collect();
+        assert_eq!(ids, vec![first, second]);
+    }
+
+    #[test]
+    fn test_capture_without_running_app_logs_warning() {
+        let mut state = AppState::new();
+        state.session_manager.create_session(&device("a")).unwrap();
+        let result = update(
+            &mut state,
+            Message::CaptureScreenshot {
+                all_sessions: false,
+            },
+        );
+        assert!(result.action.is_none());
+        let logs = &state.session_manager.selected().unwrap().session.logs;
+        assert!(logs.iter().any(|l| l.message.contains("no running app")));
+    }
+
+    #[test]
+    fn test_screenshot_saved_logs_file_url() {
+        let (mut state, _, second) = state_with_running_sessions();
+        update(
+            &mut state,
+            Message::ScreenshotSaved {
+                session_id: second,
+                result: Ok("/app/.fdemon/screenshots/pixel-1.png".into()),
+            },
+        );
+        let logs = &state.session_manager.get(second).unwrap().session.logs;
+        assert!(logs
+            .iter()
+            .any(|l| l.message == "Screenshot saved: file:///app/.fdemon/screenshots/pixel-1.png"));
+    }
+
+    #[test]
+    fn test_file_url_windows_path() {
+        assert_eq!(
+            file_url(Path::new(r"C:\app\shot.png")),
+            "file:///C:/app/shot.png"
+        );
+    }
+}
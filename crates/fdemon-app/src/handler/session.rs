@@ -165,6 +165,8 @@ pub fn handle_session_exited(state: &mut AppState, session_id: SessionId, code:
         // Don't auto-quit - let user decide what to do with the session
         // The session tab remains visible showing the exit log
     }
+
+    super::session_group::record_group_session_ended(state, session_id);
 }
 
 /// Update session state based on daemon message type
@@ -0,0 +1,510 @@
+//! Session group handlers.
+//!
+//! `m` marks the selected session as part of the group; Ctrl+R / Ctrl+T /
+//! Ctrl+S hot reload, restart or stop every group session concurrently.
+//! Reload and restart fall back to all sessions when none are marked; stop
+//! only ever targets marked sessions, so it can't end every app by accident. Each
+//! session logs its own outcome as usual, and once all of them have reported,
+//! the aggregated result line is added to every participating session.
+
+use crate::handler::{Task, UpdateAction, UpdateResult};
+use crate::session::SessionId;
+use crate::session_group::{GroupOp, GroupRun};
+use crate::state::AppState;
+use fdemon_core::{LogEntry, LogSource};
+
+/// Toggle the group mark of the selected session.
+pub(crate) fn handle_toggle_session_group(state: &mut AppState) -> UpdateResult {
+    if let Some(handle) = state.session_manager.selected_mut() {
+        handle.session.in_group = !handle.session.in_group;
+        handle.session.add_log(LogEntry::info(
+            LogSource::App,
+            if handle.session.in_group {
+                "Added to session group".to_string()
+            } else {
+                "Removed from session group".to_string()
+            },
+        ));
+    }
+    UpdateResult::none()
+}
+
+/// Run `op` on every group session that has a running app and is not busy.
+pub(crate) fn handle_session_group_action(state: &mut AppState, op: GroupOp) -> UpdateResult {
+    if let Some(run) = &state.session_group_run {
+        let pending = run.op;
+        if let Some(handle) = state.session_manager.selected_mut() {
+            handle.session.add_log(LogEntry::warn(
+                LogSource::App,
+                format!(
+                    "Group {}: group {} still in progress",
+                    op.label(),
+                    pending.label()
+                ),
+            ));
+        }
+        return UpdateResult::none();
+    }
+
+    if op == GroupOp::Stop && !state.session_manager.iter().any(|h| h.session.in_group) {
+        if let Some(handle) = state.session_manager.selected_mut() {
+            handle.session.add_log(LogEntry::warn(
+                LogSource::App,
+                "Group Stop: no sessions marked (press m to mark the sessions to stop)".to_string(),
+            ));
+        }
+        return UpdateResult::none();
+    }
+
+    let session_ids: Vec<SessionId> = state
+        .session_manager
+        .group_sessions()
+        .iter()
+        .map(|h| h.session.id)
+        .collect();
+
+    let mut tasks = Vec::new();
+    let mut members = Vec::new();
+    for session_id in session_ids {
+        let Some(handle) = state.session_manager.get_mut(session_id) else {
+            continue;
+        };
+        if handle.session.is_busy() || handle.cmd_sender.is_none() {
+            continue;
+        }
+        let Some(app_id) = handle.session.app_id.clone() else {
+            continue;
+        };
+
+        let (task, log) = match op {
+            GroupOp::Reload => {
+                handle.session.start_reload();
+                (Task::Reload { session_id, app_id }, "Reloading...")
+            }
+            GroupOp::Restart => {
+                handle.session.start_reload();
+                (Task::Restart { session_id, app_id }, "Restarting...")
+            }
+            GroupOp::Stop => {
+                let detach = handle.session.is_attached();
+                (
+                    Task::Stop {
+                        session_id,
+                        app_id,
+                        detach,
+                    },
+                    if detach {
+                        "Detaching from app..."
+                    } else {
+                        "Stopping app..."
+                    },
+                )
+            }
+        };
+        handle
+            .session
+            .add_log(LogEntry::info(LogSource::App, log.to_string()));
+        members.push((session_id, handle.session.device_name.clone()));
+        tasks.push(task);
+    }
+
+    if tasks.is_empty() {
+        if let Some(handle) = state.session_manager.selected_mut() {
+            handle.session.add_log(LogEntry::warn(
+                LogSource::App,
+                format!("Group {}: no running app in the group", op.label()),
+            ));
+        }
+        return UpdateResult::none();
+    }
+
+    tracing::info!("Group {} for {} session(s)", op.label(), tasks.len());
+    state.session_group_run = Some(GroupRun::new(op, members));
+    UpdateResult::action(UpdateAction::SpawnGroupTasks { tasks })
+}
+
+/// Record one session's outcome for the group run in flight. When every
+/// session has reported, the aggregated result line is logged to each of
+/// them.
+pub(crate) fn record_group_outcome(
+    state: &mut AppState,
+    session_id: SessionId,
+    op: GroupOp,
+    outcome: Result<(), String>,
+) {
+    let Some(run) = state.session_group_run.as_mut() else {
+        return;
+    };
+    if !run.record(session_id, op, outcome) || !run.is_complete() {
+        return;
+    }
+    let Some(run) = state.session_group_run.take() else {
+        return;
+    };
+
+    let summary = run.summary();
+    for id in run.session_ids() {
+        if let Some(handle) = state.session_manager.get_mut(id) {
+            let entry = if run.all_ok() {
+                LogEntry::info(LogSource::App, summary.clone())
+            } else {
+                LogEntry::warn(LogSource::App, summary.clone())
+            };
+            handle.session.add_log(entry);
+        }
+    }
+}
+
+/// Record that a session exited or was closed during the group run in
+/// flight. It will not report a result of its own; for a group stop, exiting
+/// is the expected outcome.
+pub(crate) fn record_group_session_ended(state: &mut AppState, session_id: SessionId) {
+    let Some(op) = state.session_group_run.as_ref().map(|run| run.op) else {
+        return;
+    };
+    let outcome = match op {
+        GroupOp::Stop => Ok(()),
+        GroupOp::Reload | GroupOp::Restart => Err("session ended".to_string()),
+    };
+    record_group_outcome(state, session_id, op, outcome);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::handler::update::update;
+    use crate::message::Message;
+
+    fn device(id: &str) -> fdemon_daemon::Device {
+        fdemon_daemon::Device {
+            id: id.to_string(),
+            name: format!("Device {id}"),
+            platform: "android".to_string(),
+            emulator: false,
+            category: None,
+            platform_type: None,
+            ephemeral: false,
+            emulator_id: None,
+        }
+    }
+
+    /// State with three running sessions; the first one is selected.
+    fn state_with_running_sessions() -> (AppState, Vec<SessionId>) {
+        let mut state = AppState::new();
+        let mut ids = Vec::new();
+        for name in ["a", "b", "c"] {
+            let id = state.session_manager.create_session(&device(name)).unwrap();
+            let handle = state.session_manager.get_mut(id).unwrap();
+            handle.session.mark_started(format!("app-{name}"));
+            handle.cmd_sender = Some(fdemon_daemon::CommandSender::new_for_test());
+            ids.push(id);
+        }
+        state.session_manager.select_by_id(ids[0]);
+        (state, ids)
+    }
+
+    /// Mark every session as part of the group (group stop needs marks).
+    fn mark_all(state: &mut AppState, ids: &[SessionId]) {
+        for id in ids {
+            state.session_manager.get_mut(*id).unwrap().session.in_group = true;
+        }
+    }
+
+    fn task_ids(result: &UpdateResult) -> Vec<SessionId> {
+        match &result.action {
+            Some(UpdateAction::SpawnGroupTasks { tasks }) => {
+                tasks.iter().map(Task::session_id).collect()
+            }
+            other => panic!("expected SpawnGroupTasks, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_group_action_targets_all_sessions_without_marks() {
+        let (mut state, ids) = state_with_running_sessions();
+
+        let result = update(&mut state, Message::SessionGroupAction(GroupOp::Restart));
+
+        assert_eq!(task_ids(&result), ids);
+        for id in &ids {
+            assert!(state.session_manager.get(*id).unwrap().session.is_busy());
+        }
+        assert_eq!(
+            state.session_group_run.as_ref().unwrap().op,
+            GroupOp::Restart
+        );
+    }
+
+    #[test]
+    fn test_group_action_targets_marked_sessions() {
+        let (mut state, ids) = state_with_running_sessions();
+        update(&mut state, Message::ToggleSessionGroup);
+        state.session_manager.select_by_id(ids[2]);
+        update(&mut state, Message::ToggleSessionGroup);
+
+        let result = update(&mut state, Message::SessionGroupAction(GroupOp::Stop));
+
+        assert_eq!(task_ids(&result), vec![ids[0], ids[2]]);
+        assert!(state.session_manager.get(ids[0]).unwrap().session.in_group);
+        assert!(!state.session_manager.get(ids[1]).unwrap().session.in_group);
+    }
+
+    #[test]
+    fn test_group_stop_without_marks_stops_nothing() {
+        let (mut state, ids) = state_with_running_sessions();
+
+        let result = update(&mut state, Message::SessionGroupAction(GroupOp::Stop));
+
+        assert!(result.action.is_none());
+        assert!(state.session_group_run.is_none());
+        for id in &ids {
+            assert!(state.session_manager.get(*id).unwrap().session.is_running());
+        }
+        let handle = state.session_manager.selected().unwrap();
+        assert!(handle
+            .session
+            .logs
+            .back()
+            .unwrap()
+            .message
+            .contains("no sessions marked"));
+    }
+
+    #[test]
+    fn test_group_action_without_running_app_warns() {
+        let mut state = AppState::new();
+        state.session_manager.create_session(&device("a")).unwrap();
+
+        let result = update(&mut state, Message::SessionGroupAction(GroupOp::Reload));
+
+        assert!(result.action.is_none());
+        assert!(state.session_group_run.is_none());
+        let handle = state.session_manager.selected().unwrap();
+        assert!(handle
+            .session
+            .logs
+            .back()
+            .unwrap()
+            .message
+            .contains("no running app"));
+    }
+
+    #[test]
+    fn test_aggregated_line_logged_when_all_sessions_report() {
+        let (mut state, ids) = state_with_running_sessions();
+        update(&mut state, Message::SessionGroupAction(GroupOp::Reload));
+
+        update(
+            &mut state,
+            Message::SessionReloadCompleted {
+                session_id: ids[0],
+                time_ms: 120,
+            },
+        );
+        update(
+            &mut state,
+            Message::SessionReloadFailed {
+                session_id: ids[1],
+                reason: "Compile error".to_string(),
+            },
+        );
+        assert!(state.session_group_run.is_some());
+        update(
+            &mut state,
+            Message::SessionReloadCompleted {
+                session_id: ids[2],
+                time_ms: 80,
+            },
+        );
+
+        assert!(state.session_group_run.is_none());
+        for id in &ids {
+            let last = state
+                .session_manager
+                .get(*id)
+                .unwrap()
+                .session
+                .logs
+                .back()
+                .unwrap()
+                .clone();
+            assert!(
+                last.message.starts_with("Reload 2/3 ok: Device a "),
+                "{}",
+                last.message
+            );
+            assert!(last.message.contains("Device b failed (Compile error)"));
+            assert_eq!(last.level, fdemon_core::LogLevel::Warning);
+        }
+    }
+
+    #[test]
+    fn test_auto_reload_of_several_sessions_is_aggregated() {
+        let (mut state, ids) = state_with_running_sessions();
+        update(&mut state, Message::AutoReloadTriggered);
+        assert!(state.session_group_run.is_some());
+
+        for id in &ids {
+            update(
+                &mut state,
+                Message::SessionReloadCompleted {
+                    session_id: *id,
+                    time_ms: 50,
+                },
+            );
+        }
+
+        let last = state
+            .session_manager
+            .get(ids[1])
+            .unwrap()
+            .session
+            .logs
+            .back()
+            .unwrap()
+            .clone();
+        assert!(last.message.starts_with("Reload 3/3 ok: "));
+        assert_eq!(last.level, fdemon_core::LogLevel::Info);
+    }
+
+    #[test]
+    fn test_stop_failure_after_exit_counts_as_stopped() {
+        let (mut state, ids) = state_with_running_sessions();
+        mark_all(&mut state, &ids);
+        update(&mut state, Message::SessionGroupAction(GroupOp::Stop));
+        state.session_manager.get_mut(ids[0]).unwrap().session.phase =
+            fdemon_core::AppPhase::Stopped;
+
+        update(
+            &mut state,
+            Message::SessionStopFailed {
+                session_id: ids[0],
+                reason: "Command cancelled".to_string(),
+            },
+        );
+        update(
+            &mut state,
+            Message::SessionStopFailed {
+                session_id: ids[1],
+                reason: "Timeout".to_string(),
+            },
+        );
+        update(
+            &mut state,
+            Message::SessionStopCompleted { session_id: ids[2] },
+        );
+
+        let last = state
+            .session_manager
+            .get(ids[2])
+            .unwrap()
+            .session
+            .logs
+            .back()
+            .unwrap()
+            .clone();
+        assert!(
+            last.message.starts_with("Stop 2/3 ok: "),
+            "{}",
+            last.message
+        );
+        assert!(last.message.contains("Device b failed (Timeout)"));
+    }
+
+    #[tokio::test]
+    async fn test_session_exit_during_group_reload_completes_run() {
+        let (mut state, ids) = state_with_running_sessions();
+        update(&mut state, Message::SessionGroupAction(GroupOp::Reload));
+
+        update(
+            &mut state,
+            Message::SessionReloadCompleted {
+                session_id: ids[0],
+                time_ms: 120,
+            },
+        );
+        crate::handler::session::handle_session_exited(&mut state, ids[1], Some(1));
+        assert!(state.session_group_run.is_some());
+
+        // Closing the selected session ends its part of the run too
+        state.session_manager.select_by_id(ids[2]);
+        update(&mut state, Message::CloseCurrentSession);
+
+        assert!(state.session_group_run.is_none());
+        let last = state
+            .session_manager
+            .get(ids[0])
+            .unwrap()
+            .session
+            .logs
+            .back()
+            .unwrap()
+            .clone();
+        assert!(
+            last.message.starts_with("Reload 1/3 ok: "),
+            "{}",
+            last.message
+        );
+        assert!(last.message.contains("Device b failed (session ended)"));
+        assert!(last.message.contains("Device c failed (session ended)"));
+    }
+
+    #[test]
+    fn test_session_exit_during_group_stop_counts_as_stopped() {
+        let (mut state, ids) = state_with_running_sessions();
+        mark_all(&mut state, &ids);
+        update(&mut state, Message::SessionGroupAction(GroupOp::Stop));
+
+        for id in &ids {
+            crate::handler::session::handle_session_exited(&mut state, *id, Some(0));
+        }
+
+        assert!(state.session_group_run.is_none());
+        let last = state
+            .session_manager
+            .get(ids[0])
+            .unwrap()
+            .session
+            .logs
+            .back()
+            .unwrap()
+            .clone();
+        assert!(
+            last.message.starts_with("Stop 3/3 ok: "),
+            "{}",
+            last.message
+        );
+    }
+
+    #[test]
+    fn test_pending_group_run_is_not_replaced() {
+        let (mut state, ids) = state_with_running_sessions();
+        mark_all(&mut state, &ids);
+        update(&mut state, Message::SessionGroupAction(GroupOp::Stop));
+
+        // A watcher reload while the stop is in flight keeps the stop run
+        update(&mut state, Message::AutoReloadTriggered);
+        assert_eq!(state.session_group_run.as_ref().unwrap().op, GroupOp::Stop);
+
+        // So does another group action
+        let result = update(&mut state, Message::SessionGroupAction(GroupOp::Reload));
+        assert!(result.action.is_none());
+        assert_eq!(state.session_group_run.as_ref().unwrap().op, GroupOp::Stop);
+        let handle = state.session_manager.selected().unwrap();
+        assert!(handle
+            .session
+            .logs
+            .back()
+            .unwrap()
+            .message
+            .contains("group Stop still in progress"));
+
+        for id in &ids {
+            update(
+                &mut state,
+                Message::SessionStopCompleted { session_id: *id },
+            );
+        }
+        assert!(state.session_group_run.is_none());
+    }
+}
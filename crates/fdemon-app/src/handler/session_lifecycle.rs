@@ -88,7 +88,7 @@ pub fn handle_select_session_by_index(state: &mut AppState, index: usize) -> Upd
     // Silently ignore if index is out of range
     state.session_manager.select_by_index(index);
     if state.session_manager.selected_index() != old_index {
-        state.devtools_view_state.reset();
+        reset_devtools_for_selected_session(state);
         return maybe_start_monitoring_for_selected_session(state);
     }
     UpdateResult::none()
@@ -100,7 +100,7 @@ pub fn handle_next_session(state: &mut AppState) -> UpdateResult {
     state.session_manager.select_next();
     let new_id = state.session_manager.selected_id();
     if old_id != new_id {
-        state.devtools_view_state.reset();
+        reset_devtools_for_selected_session(state);
         return maybe_start_monitoring_for_selected_session(state);
     }
     UpdateResult::none()
@@ -112,12 +112,19 @@ pub fn handle_previous_session(state: &mut AppState) -> UpdateResult {
     state.session_manager.select_previous();
     let new_id = state.session_manager.selected_id();
     if old_id != new_id {
-        state.devtools_view_state.reset();
+        reset_devtools_for_selected_session(state);
         return maybe_start_monitoring_for_selected_session(state);
     }
     UpdateResult::none()
 }
 
+/// Drop the previous session's DevTools view state, keeping the stream tails
+/// the newly selected session still has.
+fn reset_devtools_for_selected_session(state: &mut AppState) {
+    state.devtools_view_state.reset();
+    super::devtools::console::sync_tailed_streams(state);
+}
+
 /// Start performance monitoring for the newly selected session if DevTools is
 /// active, the VM is connected, and no polling task is already running.
 ///
@@ -199,7 +206,7 @@ pub fn handle_close_current_session(state: &mut AppState) -> UpdateResult {
             h.session
                 .app_id
                 .clone()
-                .map(|app_id| (app_id, h.cmd_sender.clone()))
+                .map(|app_id| (app_id, h.cmd_sender.clone(), h.session.is_attached()))
         });
 
         // Signal VM Service and performance monitoring shutdown BEFORE removing
@@ -240,23 +247,25 @@ pub fn handle_close_current_session(state: &mut AppState) -> UpdateResult {
             handle.shutdown_native_logs();
         }
 
-        if let Some((app_id, cmd_sender_opt)) = session_info {
+        if let Some((app_id, cmd_sender_opt, attached)) = session_info {
             tracing::info!(
                 "Closing session {} (app: {})...",
                 current_session_id,
                 app_id
             );
 
-            // Send stop command if we have a cmd_sender
+            // Send stop command if we have a cmd_sender. An attached app was
+            // started by another tool, so only detach from it.
             if let Some(cmd_sender) = cmd_sender_opt {
                 // Spawn async task to stop the app
-                let app_id_clone = app_id.clone();
+                let app_id = app_id.clone();
+                let command = if attached {
+                    fdemon_daemon::DaemonCommand::Detach { app_id }
+                } else {
+                    fdemon_daemon::DaemonCommand::Stop { app_id }
+                };
                 tokio::spawn(async move {
-                    let _ = cmd_sender
-                        .send(fdemon_daemon::DaemonCommand::Stop {
-                            app_id: app_id_clone,
-                        })
-                        .await;
+                    let _ = cmd_sender.send(command).await;
                 });
             }
 
@@ -266,6 +275,7 @@ pub fn handle_close_current_session(state: &mut AppState) -> UpdateResult {
             // No running app, just remove the session
             state.session_manager.remove_session(current_session_id);
         }
+        super::session_group::record_group_session_ended(state, current_session_id);
 
         // If no sessions left after removal, show new session dialog
         if state.session_manager.is_empty() {
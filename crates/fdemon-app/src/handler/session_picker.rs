@@ -0,0 +1,124 @@
+//! Session picker handlers.
+//!
+//! `S` opens a fuzzy-searchable list of every session so that sessions beyond
+//! the ninth tab can be reached from the keyboard. Confirming switches via
+//! [`Message::SelectSessionByIndex`], the same path as the `1`-`9` keys.
+
+use crate::handler::UpdateResult;
+use crate::message::Message;
+use crate::session_picker::SessionPickerState;
+use crate::state::AppState;
+
+/// Open the picker with the current sessions.
+pub(crate) fn handle_show_session_picker(state: &mut AppState) -> UpdateResult {
+    if !state.session_manager.is_empty() {
+        state.session_picker = Some(SessionPickerState::new(&state.session_manager));
+    }
+    UpdateResult::none()
+}
+
+/// Close the picker without switching.
+pub(crate) fn handle_hide_session_picker(state: &mut AppState) -> UpdateResult {
+    state.session_picker = None;
+    UpdateResult::none()
+}
+
+/// Apply an edit or navigation to the open picker.
+pub(crate) fn handle_session_picker_update(
+    state: &mut AppState,
+    apply: impl FnOnce(&mut SessionPickerState),
+) -> UpdateResult {
+    if let Some(picker) = state.session_picker.as_mut() {
+        apply(picker);
+    }
+    UpdateResult::none()
+}
+
+/// Switch to the highlighted session and close the picker. Sessions closed
+/// while the picker was open are ignored.
+pub(crate) fn handle_session_picker_confirm(state: &mut AppState) -> UpdateResult {
+    let Some(picker) = state.session_picker.take() else {
+        return UpdateResult::none();
+    };
+    let index = picker.selected_session().and_then(|session_id| {
+        state
+            .session_manager
+            .iter()
+            .position(|h| h.session.id == session_id)
+    });
+    match index {
+        Some(index) => UpdateResult::message(Message::SelectSessionByIndex(index)),
+        None => UpdateResult::none(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::handler::update::update;
+
+    fn state_with_sessions(count: usize) -> AppState {
+        let mut state = AppState::new();
+        state.session_manager.set_max_sessions(count);
+        for i in 0..count {
+            let device = fdemon_daemon::Device {
+                id: format!("dev-{i}"),
+                name: format!("Lab phone {:02}", i + 1),
+                platform: "android".to_string(),
+                emulator: false,
+                category: None,
+                platform_type: None,
+                ephemeral: false,
+                emulator_id: None,
+            };
+            state.session_manager.create_session(&device).unwrap();
+        }
+        state
+    }
+
+    #[test]
+    fn test_picker_jumps_beyond_ninth_session() {
+        let mut state = state_with_sessions(12);
+
+        update(&mut state, Message::ShowSessionPicker);
+        for c in "phone 11".chars() {
+            update(&mut state, Message::SessionPickerInput(c));
+        }
+        let result = update(&mut state, Message::SessionPickerConfirm);
+
+        assert!(state.session_picker.is_none());
+        assert!(matches!(
+            result.message,
+            Some(Message::SelectSessionByIndex(10))
+        ));
+        update(&mut state, result.message.unwrap());
+        assert_eq!(state.session_manager.selected_index(), 10);
+    }
+
+    #[test]
+    fn test_picker_hide_and_empty_state() {
+        let mut state = AppState::new();
+        update(&mut state, Message::ShowSessionPicker);
+        assert!(state.session_picker.is_none());
+
+        let mut state = state_with_sessions(2);
+        update(&mut state, Message::ShowSessionPicker);
+        update(&mut state, Message::SessionPickerDown);
+        assert_eq!(state.session_picker.as_ref().unwrap().selected, 1);
+        update(&mut state, Message::HideSessionPicker);
+        assert!(state.session_picker.is_none());
+        assert_eq!(state.session_manager.selected_index(), 0);
+    }
+
+    #[test]
+    fn test_picker_confirm_without_match_keeps_selection() {
+        let mut state = state_with_sessions(2);
+        update(&mut state, Message::ShowSessionPicker);
+        update(&mut state, Message::SessionPickerInput('z'));
+
+        let result = update(&mut state, Message::SessionPickerConfirm);
+
+        assert!(result.message.is_none());
+        assert!(state.session_picker.is_none());
+    }
+}
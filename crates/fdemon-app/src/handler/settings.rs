@@ -18,6 +18,21 @@ pub fn apply_project_setting(settings: &mut Settings, item: &SettingItem) {
                 settings.behavior.auto_launch = *v;
             }
         }
+        "behavior.restore_workspace" => {
+            if let SettingValue::Bool(v) = &item.value {
+                settings.behavior.restore_workspace = *v;
+            }
+        }
+        "behavior.screenshot_dir" => {
+            if let SettingValue::String(v) = &item.value {
+                settings.behavior.screenshot_dir = v.clone();
+            }
+        }
+        "behavior.max_sessions" => {
+            if let SettingValue::Number(v) = &item.value {
+                settings.behavior.max_sessions = (*v).max(1) as usize;
+            }
+        }
 
         // Watcher
         "watcher.paths" => {
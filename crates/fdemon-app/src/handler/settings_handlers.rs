@@ -2,7 +2,7 @@
 //!
 //! Handles navigation, editing, and persistence of settings.
 
-use crate::config::{SettingValue, SettingsTab};
+use crate::config::{SettingItem, SettingValue, SettingsTab};
 use crate::confirm_dialog::ConfirmDialogState;
 use crate::message::Message;
 use crate::settings_items::get_selected_item;
@@ -209,7 +209,7 @@ pub fn handle_settings_toggle_bool(state: &mut AppState) -> UpdateResult {
             // Apply based on active tab
             match state.settings_view_state.active_tab {
                 SettingsTab::Project => {
-                    super::settings::apply_project_setting(&mut state.settings, &toggled_item);
+                    apply_project_item(state, &toggled_item);
                     state.settings_view_state.mark_dirty();
                 }
                 SettingsTab::UserPrefs => {
@@ -310,14 +310,41 @@ pub fn handle_settings_clear_buffer(state: &mut AppState) -> UpdateResult {
 /// Handle settings commit edit message
 pub fn handle_settings_commit_edit(state: &mut AppState) -> UpdateResult {
     // Commit the current edit
-    // Actual value update needs to happen here
+    // Project tab numbers are applied here; other values still need it
     if state.settings_view_state.editing {
+        if state.settings_view_state.active_tab == SettingsTab::Project {
+            let parsed = state.settings_view_state.edit_buffer.trim().parse::<u32>();
+            if let (Some(mut item), Ok(n)) = (
+                get_selected_item(
+                    &state.settings,
+                    &state.project_path,
+                    &state.settings_view_state,
+                ),
+                parsed,
+            ) {
+                if matches!(item.value, SettingValue::Number(_)) {
+                    item.value = SettingValue::Number(n.into());
+                    apply_project_item(state, &item);
+                }
+            }
+        }
         state.settings_view_state.mark_dirty();
         state.settings_view_state.stop_editing();
     }
     UpdateResult::none()
 }
 
+/// Apply a Project tab item to `state.settings` and to the live state that
+/// mirrors it.
+fn apply_project_item(state: &mut AppState, item: &SettingItem) {
+    super::settings::apply_project_setting(&mut state.settings, item);
+    if item.id == "behavior.max_sessions" {
+        state
+            .session_manager
+            .set_max_sessions(state.settings.behavior.max_sessions);
+    }
+}
+
 /// Handle settings cancel edit message
 pub fn handle_settings_cancel_edit(state: &mut AppState) -> UpdateResult {
     // Cancel the current edit
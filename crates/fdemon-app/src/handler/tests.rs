@@ -115,6 +115,53 @@ fn test_s_key_produces_stop() {
     assert!(matches!(result, Some(Message::StopApp)));
 }
 
+#[test]
+fn test_session_group_keys() {
+    use crate::session_group::GroupOp;
+    let state = AppState::new();
+
+    assert!(matches!(
+        handle_key(&state, InputKey::Char('m')),
+        Some(Message::ToggleSessionGroup)
+    ));
+    assert!(matches!(
+        handle_key(&state, InputKey::CharCtrl('r')),
+        Some(Message::SessionGroupAction(GroupOp::Reload))
+    ));
+    assert!(matches!(
+        handle_key(&state, InputKey::CharCtrl('t')),
+        Some(Message::SessionGroupAction(GroupOp::Restart))
+    ));
+    assert!(matches!(
+        handle_key(&state, InputKey::CharCtrl('s')),
+        Some(Message::SessionGroupAction(GroupOp::Stop))
+    ));
+}
+
+#[test]
+fn test_session_picker_keys() {
+    let mut state = AppState::new();
+    assert!(matches!(
+        handle_key(&state, InputKey::Char('S')),
+        Some(Message::ShowSessionPicker)
+    ));
+
+    // While open, the picker receives every key
+    state.session_picker = Some(Default::default());
+    assert!(matches!(
+        handle_key(&state, InputKey::Char('q')),
+        Some(Message::SessionPickerInput('q'))
+    ));
+    assert!(matches!(
+        handle_key(&state, InputKey::Enter),
+        Some(Message::SessionPickerConfirm)
+    ));
+    assert!(matches!(
+        handle_key(&state, InputKey::Esc),
+        Some(Message::HideSessionPicker)
+    ));
+}
+
 #[test]
 fn test_auto_reload_skipped_when_no_app() {
     let mut state = AppState::new();
@@ -989,14 +1036,53 @@ fn test_task_enum_includes_session_id() {
     let task = Task::Stop {
         session_id: 44,
         app_id: "test-app-3".to_string(),
+        detach: true,
     };
 
-    if let Task::Stop { session_id, app_id } = task {
+    if let Task::Stop {
+        session_id,
+        app_id,
+        detach,
+    } = task
+    {
         assert_eq!(session_id, 44);
         assert_eq!(app_id, "test-app-3");
+        assert!(detach);
     }
 }
 
+#[test]
+fn test_stop_app_detaches_attached_session() {
+    let mut state = AppState::new();
+    let device = test_device("device-1", "Device 1");
+    let session_id = state
+        .session_manager
+        .create_session_with_config(
+            &device,
+            crate::config::LaunchConfig {
+                attach: true,
+                ..Default::default()
+            },
+        )
+        .unwrap();
+    if let Some(handle) = state.session_manager.get_mut(session_id) {
+        handle.session.mark_started("app-1".to_string());
+        handle.cmd_sender = Some(fdemon_daemon::CommandSender::new_for_test());
+    }
+
+    let result = update(&mut state, Message::StopApp);
+
+    assert!(matches!(
+        result.action,
+        Some(UpdateAction::SpawnTask(Task::Stop { detach: true, .. }))
+    ));
+    let handle = state.session_manager.get(session_id).unwrap();
+    assert_eq!(
+        handle.session.logs.back().unwrap().message,
+        "Detaching from app..."
+    );
+}
+
 // ─────────────────────────────────────────────────────────
 // Multi-session auto-reload tests (Task 05)
 // ─────────────────────────────────────────────────────────
@@ -1958,9 +2044,10 @@ fn test_settings_toggle_bool_flips_value() {
     // Set initial boolean value to true for auto_reload setting
     state.settings.watcher.auto_reload = true;
 
-    // Select the auto_reload item (index 4 in Project tab: 0=confirm_quit, 1=auto_launch,
-    // 2=watch_paths, 3=debounce_ms, 4=auto_reload)
-    state.settings_view_state.selected_index = 4;
+    // Select the auto_reload item (index 7 in Project tab: 0=confirm_quit, 1=auto_launch,
+    // 2=restore_workspace, 3=screenshot_dir, 4=max_sessions, 5=watch_paths, 6=debounce_ms,
+    // 7=auto_reload)
+    state.settings_view_state.selected_index = 7;
 
     // Handle the toggle message
     update(&mut state, Message::SettingsToggleBool);
@@ -1979,6 +2066,31 @@ fn test_settings_toggle_bool_flips_value() {
     );
 }
 
+#[test]
+fn test_settings_max_sessions_edit_updates_session_limit() {
+    let mut state = AppState::new();
+    state.ui_mode = UiMode::Settings;
+
+    // Index 4 in Project tab is behavior.max_sessions
+    state.settings_view_state.selected_index = 4;
+    update(&mut state, Message::SettingsToggleEdit);
+    assert_eq!(state.settings_view_state.edit_buffer, "9");
+
+    state.settings_view_state.edit_buffer = "12".to_string();
+    update(&mut state, Message::SettingsCommitEdit);
+
+    assert_eq!(state.settings.behavior.max_sessions, 12);
+    assert_eq!(state.session_manager.max_sessions(), 12);
+    assert!(state.settings_view_state.dirty);
+    assert!(!state.settings_view_state.editing);
+
+    // Invalid input leaves the limit unchanged
+    update(&mut state, Message::SettingsToggleEdit);
+    state.settings_view_state.edit_buffer = "many".to_string();
+    update(&mut state, Message::SettingsCommitEdit);
+    assert_eq!(state.session_manager.max_sessions(), 12);
+}
+
 #[test]
 fn test_settings_toggle_bool_sets_dirty_flag() {
     // This test verifies that the SettingsToggleBool handler correctly sets
@@ -2177,6 +2289,8 @@ mod auto_launch_tests {
             entry_point: None,
             extra_args: vec![],
             auto_start: false,
+            attach: false,
+            debug_url: None,
         };
 
         let result = update(
@@ -4184,6 +4298,7 @@ fn test_memory_snapshot_handler() {
 
     let msg = Message::VmServiceMemorySnapshot {
         session_id,
+        isolate_id: None,
         memory: memory.clone(),
     };
     let result = update(&mut state, msg);
@@ -4473,6 +4588,7 @@ fn test_memory_snapshot_ignored_for_unknown_session() {
         &mut state,
         Message::VmServiceMemorySnapshot {
             session_id: 9999,
+            isolate_id: None,
             memory: MemoryUsage {
                 heap_usage: 1000,
                 heap_capacity: 2000,
@@ -4844,6 +4960,7 @@ fn test_memory_snapshot_triggers_stats_recompute() {
         &mut state,
         Message::VmServiceMemorySnapshot {
             session_id,
+            isolate_id: None,
             memory: MemoryUsage {
                 heap_usage: 50_000_000,
                 heap_capacity: 100_000_000,
@@ -5823,7 +5940,11 @@ fn test_memory_snapshot_still_works_alongside_sample() {
 
     update(
         &mut state,
-        Message::VmServiceMemorySnapshot { session_id, memory },
+        Message::VmServiceMemorySnapshot {
+            session_id,
+            isolate_id: None,
+            memory,
+        },
     );
 
     let perf = &state
@@ -6010,6 +6131,7 @@ fn test_http_profile_received_merges_entries_when_recording_on() {
         &mut state,
         Message::VmServiceHttpProfileReceived {
             session_id,
+            isolate_id: "isolates/1".to_string(),
             timestamp: 5000,
             entries: vec![make_test_http_entry("req-1")],
         },
@@ -6054,6 +6176,7 @@ fn test_http_profile_received_discards_entries_when_recording_off_but_advances_t
         &mut state,
         Message::VmServiceHttpProfileReceived {
             session_id,
+            isolate_id: "isolates/1".to_string(),
             timestamp: 9000,
             entries: vec![make_test_http_entry("req-paused")],
         },
@@ -6085,6 +6208,7 @@ fn test_http_profile_received_only_shows_entries_after_recording_resumed() {
         &mut state,
         Message::VmServiceHttpProfileReceived {
             session_id,
+            isolate_id: "isolates/1".to_string(),
             timestamp: 1000,
             entries: vec![make_test_http_entry("before-pause")],
         },
@@ -6098,6 +6222,7 @@ fn test_http_profile_received_only_shows_entries_after_recording_resumed() {
         &mut state,
         Message::VmServiceHttpProfileReceived {
             session_id,
+            isolate_id: "isolates/1".to_string(),
             timestamp: 2000,
             entries: vec![make_test_http_entry("during-pause")],
         },
@@ -6111,6 +6236,7 @@ fn test_http_profile_received_only_shows_entries_after_recording_resumed() {
         &mut state,
         Message::VmServiceHttpProfileReceived {
             session_id,
+            isolate_id: "isolates/1".to_string(),
             timestamp: 3000,
             entries: vec![make_test_http_entry("after-resume")],
         },
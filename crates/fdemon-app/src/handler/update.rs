@@ -7,15 +7,17 @@
 //! - `log_view`: Log filtering/search handlers (Phase 6.1, Task 04)
 //! - `settings_handlers`: Settings page handlers (Phase 6.1, Task 04)
 
-use crate::message::{AutoLaunchSuccess, Message};
+use crate::message::{AutoLaunchSuccess, DebugOverrideKind, Message};
+use crate::session_group::{GroupOp, GroupRun};
 use crate::state::{AppState, DevToolsError, DevToolsPanel, UiMode, MAX_PENDING_WATCHER_ERRORS};
 use fdemon_core::{AppPhase, LogLevel, LogSource};
 use tracing::warn;
 
 use super::{
     daemon::handle_session_daemon_event, dap, devtools, flutter_version, keys::handle_key,
-    log_view, new_session, scroll, session_lifecycle, settings_dart_defines, settings_extra_args,
-    settings_handlers, Task, UpdateAction, UpdateResult,
+    log_view, new_session, screenshot, scroll, session_group, session_lifecycle, session_picker,
+    settings_dart_defines, settings_extra_args, settings_handlers, workspace, Task, UpdateAction,
+    UpdateResult,
 };
 
 /// Process a message and update state.
@@ -159,13 +161,19 @@ pub fn update(state: &mut AppState, message: Message) -> UpdateResult {
                 if let Some(app_id) = handle.session.app_id.clone() {
                     if handle.cmd_sender.is_some() {
                         let session_id = handle.session.id;
+                        let detach = handle.session.is_attached();
                         handle.session.add_log(fdemon_core::LogEntry::info(
                             LogSource::App,
-                            "Stopping app...".to_string(),
+                            if detach {
+                                "Detaching from app...".to_string()
+                            } else {
+                                "Stopping app...".to_string()
+                            },
                         ));
                         return UpdateResult::action(UpdateAction::SpawnTask(Task::Stop {
                             session_id,
                             app_id,
+                            detach,
                         }));
                     }
                 }
@@ -178,6 +186,18 @@ pub fn update(state: &mut AppState, message: Message) -> UpdateResult {
             UpdateResult::none()
         }
 
+        Message::ToggleSessionGroup => session_group::handle_toggle_session_group(state),
+
+        Message::SessionGroupAction(op) => session_group::handle_session_group_action(state, op),
+
+        Message::CaptureScreenshot { all_sessions } => {
+            screenshot::handle_capture_screenshot(state, all_sessions)
+        }
+
+        Message::ScreenshotSaved { session_id, result } => {
+            screenshot::handle_screenshot_saved(state, session_id, result)
+        }
+
         // ─────────────────────────────────────────────────────────
         // Session Reload/Restart Completion (multi-session mode)
         // ─────────────────────────────────────────────────────────
@@ -192,7 +212,8 @@ pub fn update(state: &mut AppState, message: Message) -> UpdateResult {
                     format!("Reloaded in {}ms", time_ms),
                 ));
             }
-            UpdateResult::none()
+            session_group::record_group_outcome(state, session_id, GroupOp::Reload, Ok(()));
+            devtools::performance::handle_leak_checkpoint(state, session_id)
         }
 
         Message::SessionReloadFailed { session_id, reason } => {
@@ -204,6 +225,7 @@ pub fn update(state: &mut AppState, message: Message) -> UpdateResult {
                     format!("Reload failed: {}", reason),
                 ));
             }
+            session_group::record_group_outcome(state, session_id, GroupOp::Reload, Err(reason));
             UpdateResult::none()
         }
 
@@ -221,7 +243,12 @@ pub fn update(state: &mut AppState, message: Message) -> UpdateResult {
                 if let Some(ref vm_handle) = handle.vm_request_handle {
                     vm_handle.invalidate_isolate_cache();
                 }
+                // Rebuild location IDs belong to the old isolate.
+                let perf = &mut handle.session.performance;
+                perf.rebuild_stats = fdemon_core::performance::RebuildStats::default();
+                perf.rebuild_selected = 0;
             }
+            session_group::record_group_outcome(state, session_id, GroupOp::Restart, Ok(()));
             UpdateResult::none()
         }
 
@@ -234,6 +261,32 @@ pub fn update(state: &mut AppState, message: Message) -> UpdateResult {
                     format!("Restart failed: {}", reason),
                 ));
             }
+            session_group::record_group_outcome(state, session_id, GroupOp::Restart, Err(reason));
+            UpdateResult::none()
+        }
+
+        Message::SessionStopCompleted { session_id } => {
+            session_group::record_group_outcome(state, session_id, GroupOp::Stop, Ok(()));
+            UpdateResult::none()
+        }
+
+        Message::SessionStopFailed { session_id, reason } => {
+            // The stop request can fail with a cancelled response when the
+            // process exits first; that is still a successful stop.
+            let stopped = match state.session_manager.get_mut(session_id) {
+                Some(handle)
+                    if !matches!(handle.session.phase, AppPhase::Stopped | AppPhase::Quitting) =>
+                {
+                    handle.session.add_log(fdemon_core::LogEntry::error(
+                        LogSource::App,
+                        format!("Stop failed: {}", reason),
+                    ));
+                    false
+                }
+                _ => true,
+            };
+            let outcome = if stopped { Ok(()) } else { Err(reason) };
+            session_group::record_group_outcome(state, session_id, GroupOp::Stop, outcome);
             UpdateResult::none()
         }
 
@@ -266,6 +319,19 @@ pub fn update(state: &mut AppState, message: Message) -> UpdateResult {
 
                 tracing::info!("Auto-reload triggered for {} session(s)", count);
 
+                // Aggregate the per-device results when reloading several
+                // sessions, unless a group run is still waiting for results
+                if count > 1 && state.session_group_run.is_none() {
+                    let members = reloadable
+                        .iter()
+                        .filter_map(|(session_id, _)| {
+                            let handle = state.session_manager.get(*session_id)?;
+                            Some((*session_id, handle.session.device_name.clone()))
+                        })
+                        .collect();
+                    state.session_group_run = Some(GroupRun::new(GroupOp::Reload, members));
+                }
+
                 return UpdateResult::action(UpdateAction::ReloadAllSessions {
                     sessions: reloadable,
                 });
@@ -535,6 +601,35 @@ pub fn update(state: &mut AppState, message: Message) -> UpdateResult {
 
         Message::CloseCurrentSession => session_lifecycle::handle_close_current_session(state),
 
+        // ─────────────────────────────────────────────────────────
+        // Session Picker
+        // ─────────────────────────────────────────────────────────
+        Message::ShowSessionPicker => session_picker::handle_show_session_picker(state),
+
+        Message::HideSessionPicker => session_picker::handle_hide_session_picker(state),
+
+        Message::SessionPickerInput(c) => {
+            session_picker::handle_session_picker_update(state, |p| p.input_char(c))
+        }
+
+        Message::SessionPickerBackspace => {
+            session_picker::handle_session_picker_update(state, |p| p.backspace())
+        }
+
+        Message::SessionPickerClear => {
+            session_picker::handle_session_picker_update(state, |p| p.clear_query())
+        }
+
+        Message::SessionPickerUp => {
+            session_picker::handle_session_picker_update(state, |p| p.move_up())
+        }
+
+        Message::SessionPickerDown => {
+            session_picker::handle_session_picker_update(state, |p| p.move_down())
+        }
+
+        Message::SessionPickerConfirm => session_picker::handle_session_picker_confirm(state),
+
         // ─────────────────────────────────────────────────────────
         // Log Control (Task 10)
         // ─────────────────────────────────────────────────────────
@@ -905,6 +1000,18 @@ pub fn update(state: &mut AppState, message: Message) -> UpdateResult {
             })
         }
 
+        Message::StartWorkspaceRestore { configs, workspace } => {
+            workspace::handle_start_workspace_restore(state, configs, workspace)
+        }
+
+        Message::WorkspaceRestoreResult { result } => {
+            workspace::handle_workspace_restore_result(state, result)
+        }
+
+        Message::SpawnRestoredSessions { sessions } => {
+            workspace::handle_spawn_restored_sessions(state, sessions)
+        }
+
         Message::AutoLaunchProgress { message } => {
             // Update loading overlay message
             state.update_loading_message(&message);
@@ -972,6 +1079,7 @@ pub fn update(state: &mut AppState, message: Message) -> UpdateResult {
                                     device,
                                     config: config.map(Box::new),
                                     flutter,
+                                    proxy: state.settings.devtools.proxy_if_enabled(),
                                 }
                             };
                             UpdateResult::action(action)
@@ -1320,6 +1428,13 @@ pub fn update(state: &mut AppState, message: Message) -> UpdateResult {
         Message::VmServiceConnected { session_id } => {
             // Read config values before borrowing state mutably.
             let memory_history_size = state.settings.devtools.memory_history_size;
+            let leak_tracker = state.settings.devtools.leak_hints.tracker();
+            let configured_refresh_rate = state.session_manager.get(session_id).and_then(|h| {
+                state
+                    .settings
+                    .devtools
+                    .refresh_rate_for(&h.session.device_id, &h.session.device_name)
+            });
             let performance_refresh_ms = state.settings.devtools.performance_refresh_ms;
             let allocation_profile_interval_ms =
                 state.settings.devtools.allocation_profile_interval_ms;
@@ -1370,6 +1485,17 @@ pub fn update(state: &mut AppState, message: Message) -> UpdateResult {
                 // Use configurable memory history size from settings.
                 handle.session.performance =
                     crate::session::PerformanceState::with_memory_history_size(memory_history_size);
+                handle.session.performance.leak_tracker = leak_tracker;
+                // The picked isolate is reset along with the data read from it.
+                if let Some(vm_handle) = &handle.vm_request_handle {
+                    vm_handle.set_target_isolate(None);
+                }
+                if let Some(refresh_rate_hz) = configured_refresh_rate {
+                    handle
+                        .session
+                        .performance
+                        .set_display_refresh_rate(refresh_rate_hz);
+                }
             }
             // Clear any previous connection error and update status to Connected,
             // but only when this session is currently active in the UI.
@@ -1634,8 +1760,16 @@ pub fn update(state: &mut AppState, message: Message) -> UpdateResult {
         // ─────────────────────────────────────────────────────────
         // VM Service Performance Messages (Phase 3, Task 05)
         // ─────────────────────────────────────────────────────────
-        Message::VmServiceMemorySnapshot { session_id, memory } => {
+        Message::VmServiceMemorySnapshot {
+            session_id,
+            isolate_id,
+            memory,
+        } => {
             if let Some(handle) = state.session_manager.get_mut(session_id) {
+                // Polled before the DevTools isolate pick changed.
+                if handle.session.performance.isolate_id != isolate_id {
+                    return UpdateResult::none();
+                }
                 handle.session.performance.memory_history.push(memory);
                 handle.session.performance.monitoring_active = true;
                 // Recompute stats on every memory poll cycle (2-second backstop
@@ -1645,6 +1779,11 @@ pub fn update(state: &mut AppState, message: Message) -> UpdateResult {
             UpdateResult::none()
         }
 
+        Message::VmServiceDisplayRefreshRate {
+            session_id,
+            refresh_rate_hz,
+        } => devtools::performance::handle_display_refresh_rate(state, session_id, refresh_rate_hz),
+
         Message::VmServiceGcEvent {
             session_id,
             gc_event,
@@ -1676,14 +1815,26 @@ pub fn update(state: &mut AppState, message: Message) -> UpdateResult {
         Message::VmServiceIsolateEvent { session_id, event } => {
             devtools::debug::handle_isolate_event(state, session_id, event)
         }
+        Message::VmServiceIsolatesDiscovered {
+            session_id,
+            isolates,
+        } => devtools::debug::handle_isolates_discovered(state, session_id, isolates),
 
         // ─────────────────────────────────────────────────────────
         // VM Service Frame Timing Messages (Phase 3, Task 06)
         // ─────────────────────────────────────────────────────────
-        Message::VmServiceFrameTiming { session_id, timing } => {
+        Message::VmServiceFrameTiming {
+            session_id,
+            mut timing,
+        } => {
             if let Some(handle) = state.session_manager.get_mut(session_id) {
                 let perf = &mut handle.session.performance;
 
+                // Timeline phases can be correlated before the frame event lands.
+                if timing.phases.is_none() {
+                    timing.phases = perf.take_pending_frame_phases(timing.number);
+                }
+
                 // Check before pushing: if the buffer is already at capacity,
                 // `push()` will call `pop_front()`, evicting the oldest entry
                 // and shifting all positional indices down by 1. We must
@@ -1711,6 +1862,20 @@ pub fn update(state: &mut AppState, message: Message) -> UpdateResult {
             UpdateResult::none()
         }
 
+        Message::VmServiceFramePhases {
+            session_id,
+            number,
+            phases,
+        } => {
+            if let Some(handle) = state.session_manager.get_mut(session_id) {
+                handle
+                    .session
+                    .performance
+                    .apply_frame_phases(number, phases);
+            }
+            UpdateResult::none()
+        }
+
         Message::VmServicePerformanceMonitoringStarted {
             session_id,
             perf_shutdown_tx,
@@ -1872,8 +2037,87 @@ pub fn update(state: &mut AppState, message: Message) -> UpdateResult {
             devtools::handle_debug_overlay_toggled(state, extension, enabled)
         }
 
+        Message::CycleDebugOverride { kind } => {
+            // Shares the overlay toggle debounce.
+            if state.devtools_view_state.is_overlay_toggle_debounced() {
+                return UpdateResult::none();
+            }
+            if let Some(handle) = state.session_manager.selected() {
+                let session_id = handle.session.id;
+                state.devtools_view_state.record_overlay_toggle();
+                let current = match kind {
+                    DebugOverrideKind::Platform => {
+                        state.devtools_view_state.platform_override.clone()
+                    }
+                    DebugOverrideKind::Brightness => {
+                        state.devtools_view_state.brightness_override.clone()
+                    }
+                };
+                return UpdateResult::action(UpdateAction::CycleOverride {
+                    session_id,
+                    kind,
+                    current,
+                    vm_handle: None, // hydrated by process.rs
+                });
+            }
+            UpdateResult::none()
+        }
+
+        Message::DebugOverrideChanged { kind, value } => {
+            devtools::handle_debug_override_changed(state, kind, value)
+        }
+
+        Message::DebugTogglesQueried {
+            session_id,
+            state: queried,
+        } => devtools::handle_debug_toggles_queried(state, session_id, queried),
+
         Message::DevToolsInspectorNavigate(nav) => devtools::handle_inspector_navigate(state, nav),
 
+        Message::InspectorEnterSearchMode => devtools::inspector::handle_enter_search_mode(state),
+        Message::InspectorExitSearchMode => devtools::inspector::handle_exit_search_mode(state),
+        Message::InspectorCommitSearch => devtools::inspector::handle_commit_search(state),
+        Message::InspectorSearchInput(c) => devtools::inspector::handle_search_input(state, c),
+        Message::InspectorSearchBackspace => devtools::inspector::handle_search_backspace(state),
+        Message::InspectorToggleUserCodeOnly => {
+            devtools::inspector::handle_toggle_user_code_only(state)
+        }
+        Message::InspectorToggleDetailView => devtools::inspector::handle_toggle_detail_view(state),
+        Message::InspectorToggleFocus => devtools::inspector::handle_toggle_focus(state),
+        Message::InspectorPropertiesNavigate(nav) => {
+            devtools::inspector::handle_properties_navigate(state, nav)
+        }
+        Message::WidgetPropertiesFetched {
+            session_id,
+            node_id,
+            details,
+        } => devtools::inspector::handle_widget_properties_fetched(
+            state, session_id, node_id, details,
+        ),
+        Message::WidgetPropertiesFetchFailed {
+            session_id,
+            node_id,
+            error,
+        } => devtools::inspector::handle_widget_properties_fetch_failed(
+            state, session_id, node_id, error,
+        ),
+        Message::InspectorSetSelectMode { enabled } => {
+            devtools::inspector::handle_set_select_mode(state, enabled)
+        }
+        Message::InspectorSelectModeChanged {
+            session_id,
+            enabled,
+        } => devtools::inspector::handle_select_mode_changed(state, session_id, enabled),
+        Message::InspectorSelectedWidgetFetched { session_id, node } => {
+            devtools::inspector::handle_selected_widget_fetched(state, session_id, node)
+        }
+        Message::VmServiceNavigationEvent { session_id } => {
+            let checkpoint = devtools::performance::handle_leak_checkpoint(state, session_id);
+            let mut result = devtools::inspector::handle_navigation_event(state, session_id);
+            result.action = checkpoint.action;
+            result
+        }
+
         // ─────────────────────────────────────────────────────────
         // VM Service Connection State Messages (Phase 5, Task 02)
         // ─────────────────────────────────────────────────────────
@@ -1933,21 +2177,41 @@ pub fn update(state: &mut AppState, message: Message) -> UpdateResult {
             devtools::handle_select_performance_frame(state, index)
         }
 
-        Message::VmServiceMemorySample { session_id, sample } => {
-            devtools::handle_memory_sample_received(state, session_id, sample)
-        }
+        Message::VmServiceMemorySample {
+            session_id,
+            isolate_id,
+            sample,
+        } => devtools::handle_memory_sample_received(state, session_id, isolate_id, sample),
 
         Message::VmServiceAllocationProfileReceived {
             session_id,
+            isolate_id,
             profile,
-        } => devtools::handle_allocation_profile_received(state, session_id, profile),
+        } => devtools::handle_allocation_profile_received(state, session_id, isolate_id, profile),
+
+        Message::VmServiceRebuiltWidgets {
+            session_id,
+            rebuilds,
+        } => devtools::performance::handle_rebuilt_widgets(state, session_id, rebuilds),
+
+        Message::RebuildTrackingChanged {
+            session_id,
+            enabled,
+        } => devtools::performance::handle_rebuild_tracking_changed(state, session_id, enabled),
 
         // ── Network Monitor Messages (Phase 4, Task 04) ──────────────────────
         Message::VmServiceHttpProfileReceived {
             session_id,
+            isolate_id,
+            timestamp,
+            entries,
+        } => devtools::network::handle_http_profile_received(
+            state,
+            session_id,
+            &isolate_id,
             timestamp,
             entries,
-        } => devtools::network::handle_http_profile_received(state, session_id, timestamp, entries),
+        ),
 
         Message::VmServiceHttpRequestDetailReceived { session_id, detail } => {
             devtools::network::handle_http_request_detail_received(state, session_id, detail)
@@ -1974,6 +2238,22 @@ pub fn update(state: &mut AppState, message: Message) -> UpdateResult {
             devtools::network::handle_network_extensions_unavailable(state, session_id)
         }
 
+        Message::NetworkProxyStarted {
+            session_id,
+            address,
+            dart_define,
+        } => {
+            devtools::network::handle_network_proxy_started(state, session_id, address, dart_define)
+        }
+
+        Message::NetworkProxyFailed { session_id, error } => {
+            devtools::network::handle_network_proxy_failed(state, session_id, error)
+        }
+
+        Message::NetworkProxyExchange { session_id, detail } => {
+            devtools::network::handle_network_proxy_exchange(state, session_id, detail)
+        }
+
         Message::NetworkNavigate(nav) => devtools::network::handle_network_navigate(state, nav),
 
         Message::NetworkSelectRequest { index } => {
@@ -2006,10 +2286,163 @@ pub fn update(state: &mut AppState, message: Message) -> UpdateResult {
 
         Message::NetworkFilterBackspace => devtools::network::handle_filter_backspace(state),
 
+        Message::NetworkToggleWaterfall => {
+            devtools::network::handle_network_toggle_waterfall(state)
+        }
+
+        Message::NetworkWaterfallNavigate(nav) => {
+            devtools::network::handle_network_waterfall_navigate(state, nav)
+        }
+
         // ── Performance Panel UI Messages ─────────────────────────────────────
         Message::ToggleAllocationSort => {
             devtools::performance::handle_toggle_allocation_sort(state)
         }
+        Message::ToggleRebuildTracking => {
+            devtools::performance::handle_toggle_rebuild_tracking(state)
+        }
+        Message::ToggleRebuildSort => devtools::performance::handle_toggle_rebuild_sort(state),
+        Message::SelectRebuildRow { index } => {
+            devtools::performance::handle_select_rebuild_row(state, index)
+        }
+        Message::OpenRebuildLocation => devtools::performance::handle_open_rebuild_location(state),
+        Message::ClearRebuildCounts => devtools::performance::handle_clear_rebuild_counts(state),
+        Message::ExportPerformance => devtools::performance::handle_export_performance(state),
+        Message::PerformanceExported { session_id, result } => {
+            devtools::performance::handle_performance_exported(state, session_id, result)
+        }
+        Message::SavePerfBaseline { name } => {
+            devtools::performance::handle_save_baseline(state, name)
+        }
+        Message::PerfBaselineSaved { session_id, result } => {
+            devtools::performance::handle_baseline_saved(state, session_id, result)
+        }
+        Message::ComparePerfBaseline { name } => {
+            devtools::performance::handle_compare_baseline(state, name)
+        }
+        Message::PerfBaselineCompared { session_id, result } => {
+            devtools::performance::handle_baseline_compared(state, session_id, result)
+        }
+        Message::CaptureAllocationDiff => {
+            devtools::performance::handle_capture_allocation_diff(state)
+        }
+        Message::AllocationDiffCaptured { session_id, result } => {
+            devtools::performance::handle_allocation_diff_captured(state, session_id, result)
+        }
+        Message::ClearAllocationDiff => devtools::performance::handle_clear_allocation_diff(state),
+        Message::LeakCheckpointCaptured { session_id, result } => {
+            devtools::performance::handle_leak_checkpoint_captured(state, session_id, result)
+        }
+        Message::SelectAllocationRow { index } => {
+            devtools::performance::handle_select_allocation_row(state, index)
+        }
+        Message::OpenInstanceBrowser => devtools::performance::handle_open_instance_browser(state),
+        Message::InstancesFetched {
+            session_id,
+            class_id,
+            result,
+        } => devtools::performance::handle_instances_fetched(state, session_id, class_id, result),
+        Message::SelectInstance { index } => {
+            devtools::performance::handle_select_instance(state, index)
+        }
+        Message::InspectInstance => devtools::performance::handle_inspect_instance(state),
+        Message::InstanceInspected {
+            session_id,
+            object_id,
+            result,
+        } => devtools::performance::handle_instance_inspected(state, session_id, object_id, result),
+        Message::CloseInstanceBrowser => {
+            devtools::performance::handle_close_instance_browser(state)
+        }
+        Message::CycleDevToolsIsolate => devtools::handle_cycle_isolate(state),
+
+        // ── Dump viewer ───────────────────────────────────────────────────────
+        Message::DumpSelectKind(kind) => devtools::dumps::handle_select_kind(state, kind),
+        Message::RequestDump { session_id } => {
+            devtools::dumps::handle_request_dump(state, session_id)
+        }
+        Message::DumpFetched {
+            session_id,
+            kind,
+            text,
+        } => devtools::dumps::handle_dump_fetched(state, session_id, kind, text),
+        Message::DumpFetchFailed {
+            session_id,
+            kind,
+            error,
+        } => devtools::dumps::handle_dump_fetch_failed(state, session_id, kind, error),
+        Message::DumpNavigate(nav) => devtools::dumps::handle_navigate(state, nav),
+        Message::DumpEnterSearchMode => devtools::dumps::handle_enter_search_mode(state),
+        Message::DumpExitSearchMode => devtools::dumps::handle_exit_search_mode(state),
+        Message::DumpCommitSearch => devtools::dumps::handle_commit_search(state),
+        Message::DumpSearchInput(c) => devtools::dumps::handle_search_input(state, c),
+        Message::DumpSearchBackspace => devtools::dumps::handle_search_backspace(state),
+        Message::SaveDump => devtools::dumps::handle_save_dump(state),
+        Message::DumpSaved { result } => devtools::dumps::handle_dump_saved(state, result),
+
+        // ── CPU profiler ──────────────────────────────────────────────────────
+        Message::ProfilerToggleRecording => devtools::profiler::handle_toggle_recording(state),
+        Message::CpuProfilingStarted {
+            session_id,
+            origin_micros,
+        } => devtools::profiler::handle_started(state, session_id, origin_micros),
+        Message::CpuProfileFetched {
+            session_id,
+            profile,
+        } => devtools::profiler::handle_profile_fetched(state, session_id, profile),
+        Message::CpuProfilingFailed { session_id, error } => {
+            devtools::profiler::handle_failed(state, session_id, error)
+        }
+        Message::ProfilerSelectView(view) => devtools::profiler::handle_select_view(state, view),
+        Message::ProfilerToggleProjectOnly => devtools::profiler::handle_toggle_project_only(state),
+        Message::ProfilerNavigate(nav) => devtools::profiler::handle_navigate(state, nav),
+
+        // ── VM Service console ────────────────────────────────────────────────
+        Message::ConsoleFocusInput => devtools::console::handle_focus_input(state),
+        Message::ConsoleBlurInput => devtools::console::handle_blur_input(state),
+        Message::ConsoleInput(c) => devtools::console::handle_input(state, c),
+        Message::ConsoleBackspace => devtools::console::handle_backspace(state),
+        Message::ConsoleSubmit => devtools::console::handle_submit(state),
+        Message::ConsoleComplete => devtools::console::handle_complete(state),
+        Message::ConsoleHistory { older } => devtools::console::handle_history(state, older),
+        Message::ConsoleNavigate(nav) => devtools::console::handle_navigate(state, nav),
+        Message::ConsoleRpcCompleted {
+            session_id,
+            method,
+            result,
+        } => devtools::console::handle_rpc_completed(state, session_id, method, result),
+        Message::ConsoleExtensionsFetched {
+            session_id,
+            extensions,
+        } => devtools::console::handle_extensions_fetched(state, session_id, extensions),
+        Message::ConsoleStreamTailed {
+            session_id,
+            stream_id,
+            tailed,
+            result,
+        } => devtools::console::handle_stream_tailed(state, session_id, stream_id, tailed, result),
+        Message::VmServiceConsoleEvent {
+            session_id,
+            stream_id,
+            event,
+        } => devtools::console::handle_stream_event(state, session_id, stream_id, event),
+        Message::RequestServiceExtensions => devtools::extensions::fetch_on_show(state),
+        Message::ServiceExtensionsFetched { session_id, result } => {
+            devtools::extensions::handle_extensions_fetched(state, session_id, result)
+        }
+        Message::ExtensionsNavigate(nav) => devtools::extensions::handle_navigate(state, nav),
+        Message::CallSelectedExtension => devtools::extensions::handle_call_selected(state),
+        Message::PressExtensionButton(index) => {
+            devtools::extensions::handle_press_button(state, index)
+        }
+        Message::ServiceExtensionCalled {
+            session_id,
+            label,
+            method,
+            result,
+        } => {
+            devtools::extensions::handle_extension_called(state, session_id, label, method, result)
+        }
 
         // ─────────────────────────────────────────────────────────────────────
         // Settings — Dart Defines Modal (v1-refinements Phase 2, Task 03)
@@ -2296,6 +2729,7 @@ pub fn update(state: &mut AppState, message: Message) -> UpdateResult {
                     device,
                     config,
                     flutter,
+                    proxy: state.settings.devtools.proxy_if_enabled(),
                 })
             } else {
                 // Session was closed during the readiness wait — no-op.
@@ -0,0 +1,407 @@
+//! Workspace snapshot and restore handlers
+//!
+//! [`snapshot_workspace`] captures the open sessions for
+//! `.fdemon/workspace.toml` on quit. At startup the saved sessions are matched
+//! against the connected devices (`spawn::spawn_workspace_restore`), recreated
+//! with their filters and hidden tags, and spawned one per update cycle.
+
+use super::devtools::{panel_config_name, parse_default_panel};
+use super::{UpdateAction, UpdateResult};
+use crate::config::{LaunchConfig, LoadedConfigs, Workspace, WorkspaceSession};
+use crate::message::{AutoLaunchSuccess, Message, WorkspaceRestore};
+use crate::session::SessionId;
+use crate::state::{AppState, UiMode};
+use fdemon_core::{LogEntry, LogSource};
+use fdemon_daemon::Device;
+
+/// Capture the open sessions, their filters and the DevTools panel.
+pub(crate) fn snapshot_workspace(state: &AppState) -> Workspace {
+    let sessions = state
+        .session_manager
+        .iter()
+        .map(|handle| {
+            let session = &handle.session;
+            WorkspaceSession {
+                device_id: session.device_id.clone(),
+                device_name: session.device_name.clone(),
+                config: session.launch_config.as_ref().map(|c| c.name.clone()),
+                level_filter: session.filter_state.level_filter,
+                source_filter: session.filter_state.source_filter,
+                search: session.search_state.query.clone(),
+                hidden_tags: handle
+                    .native_tag_state
+                    .hidden_tags
+                    .iter()
+                    .cloned()
+                    .collect(),
+            }
+        })
+        .collect();
+
+    Workspace {
+        selected: state.session_manager.selected_index(),
+        devtools_panel: (state.ui_mode == UiMode::DevTools)
+            .then(|| panel_config_name(state.devtools_view_state.active_panel).to_string()),
+        sessions,
+    }
+}
+
+/// Start device discovery for the saved workspace.
+pub(crate) fn handle_start_workspace_restore(
+    state: &mut AppState,
+    configs: LoadedConfigs,
+    workspace: Workspace,
+) -> UpdateResult {
+    // Guard against a concurrent auto-launch (already in loading mode)
+    if state.ui_mode == UiMode::Loading {
+        return UpdateResult::none();
+    }
+
+    let Some(flutter) = state.flutter_executable() else {
+        tracing::warn!("StartWorkspaceRestore: no Flutter SDK — cannot restore workspace");
+        return UpdateResult::none();
+    };
+
+    state.set_loading_phase("Restoring workspace...");
+    UpdateResult::action(UpdateAction::DiscoverDevicesAndRestoreWorkspace {
+        configs,
+        workspace,
+        flutter,
+    })
+}
+
+/// Recreate the matched sessions and their layout, then spawn them.
+pub(crate) fn handle_workspace_restore_result(
+    state: &mut AppState,
+    result: Result<WorkspaceRestore, String>,
+) -> UpdateResult {
+    state.clear_loading();
+
+    let mut restore = match result {
+        Ok(restore) => restore,
+        Err(error_msg) => return show_dialog_with_error(state, error_msg),
+    };
+
+    let devtools = state.settings.devtools.clone();
+    let mut launches = Vec::new();
+    for (success, saved) in restore.sessions {
+        let created = match &success.config {
+            Some(cfg) => state.session_manager.create_session_with_config_configured(
+                &success.device,
+                cfg.clone(),
+                &devtools,
+            ),
+            None => state
+                .session_manager
+                .create_session_configured(&success.device, &devtools),
+        };
+        match created {
+            Ok(session_id) => {
+                apply_session_layout(state, session_id, &saved);
+                launches.push((session_id, success));
+            }
+            Err(e) => restore
+                .skipped
+                .push(format!("{} ({})", success.device.name, e)),
+        }
+    }
+
+    if launches.is_empty() {
+        return show_dialog_with_error(
+            state,
+            format!("Cannot restore workspace: {}", restore.skipped.join(", ")),
+        );
+    }
+
+    state.session_manager.select_by_index(restore.selected);
+    if let Some(panel) = &restore.devtools_panel {
+        state.enter_devtools_mode();
+        state.switch_devtools_panel(parse_default_panel(panel));
+    }
+
+    if !restore.skipped.is_empty() {
+        if let Some(handle) = state.session_manager.selected_mut() {
+            handle.session.add_log(LogEntry::warn(
+                LogSource::App,
+                format!(
+                    "Workspace sessions not restored: {}",
+                    restore.skipped.join(", ")
+                ),
+            ));
+        }
+    }
+
+    UpdateResult::message(Message::SpawnRestoredSessions { sessions: launches })
+}
+
+/// Spawn the first restored session and queue the rest.
+pub(crate) fn handle_spawn_restored_sessions(
+    state: &mut AppState,
+    mut sessions: Vec<(SessionId, AutoLaunchSuccess)>,
+) -> UpdateResult {
+    if sessions.is_empty() {
+        return UpdateResult::none();
+    }
+    let (session_id, AutoLaunchSuccess { device, config }) = sessions.remove(0);
+    let message = (!sessions.is_empty()).then_some(Message::SpawnRestoredSessions { sessions });
+
+    // The session may have been closed while waiting its turn.
+    let action = if state.session_manager.get(session_id).is_some() {
+        launch_action(state, session_id, device, config)
+    } else {
+        None
+    };
+    UpdateResult { message, action }
+}
+
+/// Restore the saved filters, search query and hidden tags of a session.
+fn apply_session_layout(state: &mut AppState, session_id: SessionId, saved: &WorkspaceSession) {
+    let Some(handle) = state.session_manager.get_mut(session_id) else {
+        return;
+    };
+    handle.session.filter_state.level_filter = saved.level_filter;
+    handle.session.filter_state.source_filter = saved.source_filter;
+    if !saved.search.is_empty() {
+        handle.session.set_search_query(&saved.search);
+    }
+    handle
+        .native_tag_state
+        .hidden_tags
+        .extend(saved.hidden_tags.iter().map(|tag| tag.to_ascii_lowercase()));
+}
+
+/// `SpawnPreAppSources` when custom sources must start first, otherwise
+/// `SpawnSession`. `None` when no Flutter SDK is available.
+fn launch_action(
+    state: &AppState,
+    session_id: SessionId,
+    device: Device,
+    config: Option<LaunchConfig>,
+) -> Option<UpdateAction> {
+    // A shared source that is already running does not need to be spawned again.
+    let needs_pre_app_spawn = state.settings.native_logs.enabled
+        && state
+            .settings
+            .native_logs
+            .pre_app_sources()
+            .any(|s| !s.shared || !state.is_shared_source_running(&s.name));
+
+    if needs_pre_app_spawn {
+        return Some(UpdateAction::SpawnPreAppSources {
+            session_id,
+            device,
+            config: config.map(Box::new),
+            settings: state.settings.native_logs.clone(),
+            project_path: state.project_path.clone(),
+            running_shared_names: state.running_shared_source_names(),
+        });
+    }
+
+    let Some(flutter) = state.flutter_executable() else {
+        tracing::warn!("SpawnRestoredSessions: no Flutter SDK — cannot spawn session");
+        return None;
+    };
+    Some(UpdateAction::SpawnSession {
+        session_id,
+        device,
+        config: config.map(Box::new),
+        flutter,
+        proxy: state.settings.devtools.proxy_if_enabled(),
+    })
+}
+
+/// Fall back to the new session dialog, showing why the restore failed.
+fn show_dialog_with_error(state: &mut AppState, error_msg: String) -> UpdateResult {
+    let configs = crate::config::load_all_configs(&state.project_path);
+    state.show_new_session_dialog(configs);
+    state
+        .new_session_dialog_state
+        .target_selector
+        .set_error(error_msg);
+    UpdateResult::none()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::handler::update::update;
+    use crate::state::DevToolsPanel;
+    use fdemon_core::{LogLevelFilter, LogSourceFilter};
+
+    fn test_device(id: &str) -> Device {
+        Device {
+            id: id.to_string(),
+            name: format!("Device {id}"),
+            platform: "android".to_string(),
+            emulator: false,
+            category: None,
+            platform_type: None,
+            ephemeral: false,
+            emulator_id: None,
+        }
+    }
+
+    fn saved_session(device_id: &str) -> WorkspaceSession {
+        WorkspaceSession {
+            device_id: device_id.to_string(),
+            device_name: format!("Device {device_id}"),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_snapshot_captures_sessions_filters_and_panel() {
+        let mut state = AppState::new();
+        let first = state
+            .session_manager
+            .create_session(&test_device("a"))
+            .unwrap();
+        state
+            .session_manager
+            .create_session_with_config(
+                &test_device("b"),
+                LaunchConfig {
+                    name: "Staging".to_string(),
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+        let handle = state.session_manager.get_mut(first).unwrap();
+        handle.session.filter_state.level_filter = LogLevelFilter::Errors;
+        handle.session.set_search_query("auth");
+        handle.native_tag_state.toggle_tag("OkHttp");
+        state.session_manager.select_by_index(1);
+
+        let workspace = snapshot_workspace(&state);
+        assert_eq!(workspace.selected, 1);
+        assert!(workspace.devtools_panel.is_none());
+        assert_eq!(workspace.sessions.len(), 2);
+        assert_eq!(workspace.sessions[0].device_id, "a");
+        assert_eq!(workspace.sessions[0].level_filter, LogLevelFilter::Errors);
+        assert_eq!(workspace.sessions[0].search, "auth");
+        assert_eq!(
+            workspace.sessions[0].hidden_tags,
+            vec!["okhttp".to_string()]
+        );
+        assert_eq!(workspace.sessions[1].config.as_deref(), Some("Staging"));
+
+        state.enter_devtools_mode();
+        state.switch_devtools_panel(DevToolsPanel::Network);
+        let workspace = snapshot_workspace(&state);
+        assert_eq!(workspace.devtools_panel.as_deref(), Some("network"));
+    }
+
+    #[test]
+    fn test_restore_result_creates_sessions_with_layout() {
+        let mut state = AppState::new();
+        let mut first = saved_session("a");
+        first.source_filter = LogSourceFilter::Native;
+        first.search = "auth".to_string();
+        first.hidden_tags = vec!["GoLog".to_string()];
+        let restore = WorkspaceRestore {
+            sessions: vec![
+                (
+                    AutoLaunchSuccess {
+                        device: test_device("a"),
+                        config: None,
+                    },
+                    first,
+                ),
+                (
+                    AutoLaunchSuccess {
+                        device: test_device("b"),
+                        config: None,
+                    },
+                    saved_session("b"),
+                ),
+            ],
+            selected: 1,
+            devtools_panel: Some("profiler".to_string()),
+            skipped: vec!["Device c (device not connected)".to_string()],
+        };
+
+        let result = update(
+            &mut state,
+            Message::WorkspaceRestoreResult {
+                result: Ok(restore),
+            },
+        );
+
+        assert_eq!(state.session_manager.len(), 2);
+        assert_eq!(state.session_manager.selected_index(), 1);
+        assert_eq!(state.ui_mode, UiMode::DevTools);
+        assert_eq!(
+            state.devtools_view_state.active_panel,
+            DevToolsPanel::Profiler
+        );
+
+        let first = state.session_manager.iter().next().unwrap();
+        assert_eq!(
+            first.session.filter_state.source_filter,
+            LogSourceFilter::Native
+        );
+        assert_eq!(first.session.search_state.query, "auth");
+        assert!(!first.native_tag_state.is_tag_visible("golog"));
+
+        let selected = state.session_manager.selected().unwrap();
+        assert!(selected
+            .session
+            .logs
+            .iter()
+            .any(|e| e.message.contains("Device c (device not connected)")));
+
+        match result.message {
+            Some(Message::SpawnRestoredSessions { ref sessions }) => {
+                assert_eq!(sessions.len(), 2)
+            }
+            ref other => panic!("expected SpawnRestoredSessions, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_restore_result_error_shows_dialog() {
+        let mut state = AppState::new();
+        let result = update(
+            &mut state,
+            Message::WorkspaceRestoreResult {
+                result: Err("Cannot restore workspace: Device a (device not connected)".into()),
+            },
+        );
+        assert!(result.message.is_none());
+        assert_eq!(state.ui_mode, UiMode::NewSessionDialog);
+        assert_eq!(state.session_manager.len(), 0);
+    }
+
+    #[test]
+    fn test_spawn_restored_sessions_chains_one_per_cycle() {
+        let mut state = AppState::new();
+        let a = state
+            .session_manager
+            .create_session(&test_device("a"))
+            .unwrap();
+        let b = state
+            .session_manager
+            .create_session(&test_device("b"))
+            .unwrap();
+        let launch = |id: &str| AutoLaunchSuccess {
+            device: test_device(id),
+            config: None,
+        };
+
+        let result =
+            handle_spawn_restored_sessions(&mut state, vec![(a, launch("a")), (b, launch("b"))]);
+        match result.message {
+            Some(Message::SpawnRestoredSessions { ref sessions }) => {
+                assert_eq!(sessions.len(), 1);
+                assert_eq!(sessions[0].0, b);
+            }
+            ref other => panic!("expected SpawnRestoredSessions, got {other:?}"),
+        }
+
+        // A closed session is skipped without ending the chain.
+        state.session_manager.remove_session(b);
+        let result = handle_spawn_restored_sessions(&mut state, vec![(b, launch("b"))]);
+        assert!(result.message.is_none());
+        assert!(result.action.is_none());
+    }
+}
@@ -60,9 +60,12 @@
 pub(crate) mod actions;
 pub mod config;
 pub mod confirm_dialog;
+pub mod console_view_state;
+pub mod dump_view_state;
 pub mod editor;
 pub mod engine;
 pub mod engine_event;
+pub mod extensions_view_state;
 pub mod flutter_version;
 pub mod handler;
 pub mod hyperlinks;
@@ -73,9 +76,12 @@ pub mod message;
 pub mod new_session_dialog;
 pub mod plugin;
 pub(crate) mod process;
+pub mod profiler_view_state;
 pub mod services;
 pub mod session;
+pub mod session_group;
 pub mod session_manager;
+pub mod session_picker;
 pub mod settings_items;
 pub(crate) mod signals;
 pub mod spawn;
@@ -86,7 +92,7 @@ pub mod watcher;
 pub use engine::Engine;
 pub use engine_event::EngineEvent;
 pub use handler::{update, Task, UpdateAction, UpdateResult};
-pub use message::{DebugOverlayKind, Message};
+pub use message::{DebugOverlayKind, DebugOverrideKind, Message};
 pub use plugin::EnginePlugin;
 pub use session::{Session, SessionHandle, SessionId};
 pub use session_manager::{SessionManager, MAX_SESSIONS};
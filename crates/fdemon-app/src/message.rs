@@ -1,9 +1,12 @@
 //! Message types for the application (TEA pattern)
 
-use crate::config::{FlutterMode, LaunchConfig, LoadedConfigs};
+use crate::config::{FlutterMode, LaunchConfig, LoadedConfigs, Workspace, WorkspaceSession};
+use crate::dump_view_state::DebugDumpKind;
 use crate::input_key::InputKey;
 use crate::new_session_dialog::{DartDefine, FuzzyModalType, TargetTab};
+use crate::profiler_view_state::ProfilerView;
 use crate::session::{NetworkDetailTab, SessionId};
+use crate::session_group::GroupOp;
 use crate::state::DevToolsPanel;
 use fdemon_core::network::{HttpProfileEntry, HttpProfileEntryDetail};
 use fdemon_core::{BootableDevice, DaemonEvent, DiagnosticsNode, LayoutInfo};
@@ -19,12 +22,31 @@ use fdemon_daemon::{
 /// session state so it can be cancelled on disconnect or session close.
 type SharedTaskHandle = std::sync::Arc<std::sync::Mutex<Option<tokio::task::JoinHandle<()>>>>;
 
-/// The three debug overlay types that can be toggled from DevTools mode.
+/// The on/off debug overlays and toggles that can be flipped from DevTools mode.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum DebugOverlayKind {
     RepaintRainbow,
     DebugPaint,
     PerformanceOverlay,
+    /// Slow animations (`timeDilation` 5.0 vs 1.0).
+    SlowAnimations,
+    /// The "DEBUG" banner (`debugAllowBanner`).
+    DebugBanner,
+    /// Color-inverted oversized images (`invertOversizedImages`).
+    InvertOversizedImages,
+    /// Timeline events for widget builds (`profileWidgetBuilds`).
+    ProfileWidgetBuilds,
+    /// Text baseline painting (`debugPaintBaselinesEnabled`).
+    PaintBaselines,
+}
+
+/// Debug overrides that cycle through a set of values rather than on/off.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DebugOverrideKind {
+    /// Target platform (`platformOverride`): android, iOS, macOS, ...
+    Platform,
+    /// Platform brightness (`brightnessOverride`): light / dark.
+    Brightness,
 }
 
 /// Navigation commands for the widget inspector tree view.
@@ -34,6 +56,10 @@ pub enum InspectorNav {
     Down,
     Expand,
     Collapse,
+    /// Jump to the next search match (wraps around).
+    NextMatch,
+    /// Jump to the previous search match (wraps around).
+    PrevMatch,
 }
 
 /// Navigation actions for the network request list.
@@ -45,6 +71,58 @@ pub enum NetworkNav {
     PageDown,
 }
 
+/// Navigation commands for the dump viewer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DumpNav {
+    Up,
+    Down,
+    PageUp,
+    PageDown,
+    Expand,
+    Collapse,
+    /// Jump to the next search match (wraps around).
+    NextMatch,
+    /// Jump to the previous search match (wraps around).
+    PrevMatch,
+}
+
+/// Navigation commands for the CPU profiler call tree.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProfilerNav {
+    Up,
+    Down,
+    PageUp,
+    PageDown,
+    Expand,
+    Collapse,
+}
+
+/// Scrolling commands for the VM Service console output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConsoleNav {
+    Up,
+    Down,
+    PageUp,
+    PageDown,
+}
+
+/// Selection commands for the Extensions panel.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExtensionNav {
+    Up,
+    Down,
+}
+
+/// Zoom and pan actions for the network waterfall timeline.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WaterfallNav {
+    ZoomIn,
+    ZoomOut,
+    PanLeft,
+    PanRight,
+    Reset,
+}
+
 /// Type of device discovery (Connected or Bootable)
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum DiscoveryType {
@@ -63,6 +141,19 @@ pub struct AutoLaunchSuccess {
     pub config: Option<LaunchConfig>,
 }
 
+/// Saved workspace matched against the connected devices
+#[derive(Debug, Clone)]
+pub struct WorkspaceRestore {
+    /// Sessions to relaunch, each with its saved filters and tags
+    pub sessions: Vec<(AutoLaunchSuccess, WorkspaceSession)>,
+    /// Index into `sessions` of the session to select
+    pub selected: usize,
+    /// Active DevTools panel name when DevTools mode was open
+    pub devtools_panel: Option<String>,
+    /// Saved sessions that could not be restored, with the reason
+    pub skipped: Vec<String>,
+}
+
 /// All possible messages/actions in the application
 #[derive(Debug, Clone)]
 pub enum Message {
@@ -115,9 +206,22 @@ pub enum Message {
     HotRestart,
     /// Stop the running app
     StopApp,
+    /// Mark or unmark the selected session for group actions
+    ToggleSessionGroup,
+    /// Reload, restart or stop every session of the group (the marked
+    /// sessions, or all sessions when none is marked) concurrently
+    SessionGroupAction(GroupOp),
+    /// Capture a device screenshot of the selected session, or of every
+    /// running session when `all_sessions` is set
+    CaptureScreenshot { all_sessions: bool },
+    /// Result of a screenshot capture: the written PNG path or an error
+    ScreenshotSaved {
+        session_id: SessionId,
+        result: Result<std::path::PathBuf, String>,
+    },
 
     // ─────────────────────────────────────────────────────────
-    // Session Reload/Restart Completion (multi-session mode)
+    // Session Reload/Restart/Stop Completion (multi-session mode)
     // ─────────────────────────────────────────────────────────
     /// Session-specific reload completed
     SessionReloadCompleted { session_id: SessionId, time_ms: u64 },
@@ -133,6 +237,13 @@ pub enum Message {
         session_id: SessionId,
         reason: String,
     },
+    /// Session-specific stop (or detach) request acknowledged
+    SessionStopCompleted { session_id: SessionId },
+    /// Session-specific stop (or detach) request failed
+    SessionStopFailed {
+        session_id: SessionId,
+        reason: String,
+    },
 
     // ─────────────────────────────────────────────────────────
     // File Watcher Messages
@@ -214,7 +325,7 @@ pub enum Message {
     // ─────────────────────────────────────────────────────────
     // Session Navigation (Task 10)
     // ─────────────────────────────────────────────────────────
-    /// Select session by index (0-based, for keys 1-9)
+    /// Select session by index (0-based, for keys 1-9 and the session picker)
     SelectSessionByIndex(usize),
     /// Switch to next session (Tab)
     NextSession,
@@ -223,6 +334,26 @@ pub enum Message {
     /// Close the current session (x / Ctrl+W)
     CloseCurrentSession,
 
+    // ─────────────────────────────────────────────────────────
+    // Session Picker
+    // ─────────────────────────────────────────────────────────
+    /// Open the session picker overlay (S)
+    ShowSessionPicker,
+    /// Close the session picker without switching
+    HideSessionPicker,
+    /// Add a character to the picker query
+    SessionPickerInput(char),
+    /// Remove the last character of the picker query
+    SessionPickerBackspace,
+    /// Clear the picker query (Ctrl+U)
+    SessionPickerClear,
+    /// Move the picker highlight up
+    SessionPickerUp,
+    /// Move the picker highlight down
+    SessionPickerDown,
+    /// Switch to the highlighted session and close the picker
+    SessionPickerConfirm,
+
     // ─────────────────────────────────────────────────────────
     // Log Control (Task 10)
     // ─────────────────────────────────────────────────────────
@@ -428,6 +559,30 @@ pub enum Message {
         result: Result<AutoLaunchSuccess, String>,
     },
 
+    // ─────────────────────────────────────────────────────────────
+    // Workspace Restore Messages
+    // ─────────────────────────────────────────────────────────────
+    /// Relaunch the sessions saved in `.fdemon/workspace.toml`
+    /// Sent by runner after first render when restore_workspace = true
+    StartWorkspaceRestore {
+        /// Pre-loaded configs to resolve saved config names
+        configs: LoadedConfigs,
+        /// Workspace loaded at startup
+        workspace: Workspace,
+    },
+
+    /// Report the workspace matched against the connected devices
+    /// Err: error message to display in the NewSessionDialog
+    WorkspaceRestoreResult {
+        result: Result<WorkspaceRestore, String>,
+    },
+
+    /// Spawn the restored sessions one per update cycle
+    /// (each spawn needs the action slot)
+    SpawnRestoredSessions {
+        sessions: Vec<(SessionId, AutoLaunchSuccess)>,
+    },
+
     // ─────────────────────────────────────────────────────────
     // NewSessionDialog Messages
     // ─────────────────────────────────────────────────────────
@@ -769,6 +924,9 @@ pub enum Message {
     /// Memory usage snapshot received from periodic polling.
     VmServiceMemorySnapshot {
         session_id: SessionId,
+        /// Isolate picked when the poll was issued (`None` for the main
+        /// isolate). Dropped if the pick has changed since.
+        isolate_id: Option<String>,
         memory: fdemon_core::performance::MemoryUsage,
     },
 
@@ -832,6 +990,15 @@ pub enum Message {
         timing: fdemon_core::performance::FrameTiming,
     },
 
+    /// Build/layout/paint/raster breakdown for a frame, correlated from
+    /// Timeline stream events. May arrive before or after the frame's
+    /// `VmServiceFrameTiming`.
+    VmServiceFramePhases {
+        session_id: SessionId,
+        number: u64,
+        phases: fdemon_core::performance::FramePhases,
+    },
+
     // ─────────────────────────────────────────────────────────────────────────
     // VM Service Performance Messages — Phase 3 extensions (Task 02)
     // ─────────────────────────────────────────────────────────────────────────
@@ -852,9 +1019,21 @@ pub enum Message {
     /// at 500ms polling resolution — richer than `VmServiceMemorySnapshot`.
     VmServiceMemorySample {
         session_id: SessionId,
+        /// Isolate picked when the poll was issued (`None` for the main
+        /// isolate).
+        isolate_id: Option<String>,
         sample: fdemon_core::performance::MemorySample,
     },
 
+    /// Display refresh rate reported by the engine, in Hz.
+    ///
+    /// Sets the session's frame budget, from the rate `devtools.refresh_rates`
+    /// configures for the device when there is one.
+    VmServiceDisplayRefreshRate {
+        session_id: SessionId,
+        refresh_rate_hz: f64,
+    },
+
     /// Allocation profile snapshot received from VM service.
     ///
     /// Replaces `PerformanceState::allocation_profile` with the new snapshot.
@@ -862,9 +1041,27 @@ pub enum Message {
     /// profile is retained in state.
     VmServiceAllocationProfileReceived {
         session_id: SessionId,
+        /// Isolate picked when the profile was requested (`None` for the main
+        /// isolate).
+        isolate_id: Option<String>,
         profile: fdemon_core::performance::AllocationProfile,
     },
 
+    /// Widget rebuild counts from a `Flutter.RebuiltWidgets` Extension event.
+    ///
+    /// Only posted while rebuild tracking is enabled. Folded into
+    /// `PerformanceState::rebuild_stats` by the handler.
+    VmServiceRebuiltWidgets {
+        session_id: SessionId,
+        rebuilds: fdemon_core::performance::RebuiltWidgets,
+    },
+
+    /// Rebuild tracking state reported by the device after a toggle.
+    RebuildTrackingChanged {
+        session_id: SessionId,
+        enabled: bool,
+    },
+
     // ── DevTools Mode (Phase 4) ──────────────────────────────────────────────
     /// Enter DevTools mode (from Normal mode via 'd' key).
     EnterDevToolsMode,
@@ -934,9 +1131,91 @@ pub enum Message {
         enabled: bool,
     },
 
+    /// Advance a debug override (platform, brightness) to its next value.
+    CycleDebugOverride { kind: DebugOverrideKind },
+
+    /// Debug override result: the value reported by the extension after the
+    /// change (e.g. `"iOS"`, `"Brightness.dark"`), or `None` once the override
+    /// has been cleared.
+    DebugOverrideChanged {
+        kind: DebugOverrideKind,
+        value: Option<String>,
+    },
+
+    /// Current state of all debug overlays and toggles, queried right after
+    /// the VM Service connects.
+    DebugTogglesQueried {
+        session_id: SessionId,
+        state: fdemon_daemon::vm_service::DebugOverlayState,
+    },
+
     /// Navigate within the widget inspector tree.
     DevToolsInspectorNavigate(InspectorNav),
 
+    /// Open the inspector search bar (activates text input).
+    InspectorEnterSearchMode,
+
+    /// Close the inspector search bar and clear the query.
+    InspectorExitSearchMode,
+
+    /// Close the inspector search bar, keeping the query for `]`/`[`.
+    InspectorCommitSearch,
+
+    /// Append a character to the inspector search query.
+    InspectorSearchInput(char),
+
+    /// Delete the last character of the inspector search query.
+    InspectorSearchBackspace,
+
+    /// Toggle showing only widgets created by the user's project.
+    InspectorToggleUserCodeOnly,
+
+    /// Enable or disable on-device widget selection (`ext.flutter.inspector.show`).
+    InspectorSetSelectMode { enabled: bool },
+
+    /// Result of setting `ext.flutter.inspector.show` on the device.
+    InspectorSelectModeChanged {
+        session_id: SessionId,
+        enabled: bool,
+    },
+
+    /// The widget selected on the device, fetched after an `Inspect` event.
+    ///
+    /// `node` is `None` when the device reports no selection.
+    InspectorSelectedWidgetFetched {
+        session_id: SessionId,
+        node: Option<Box<DiagnosticsNode>>,
+    },
+
+    /// Switch the inspector details pane between Layout and Properties.
+    InspectorToggleDetailView,
+
+    /// Move keyboard focus between the widget tree and the properties pane.
+    InspectorToggleFocus,
+
+    /// Navigate within the properties pane (Up/Down/Expand/Collapse).
+    InspectorPropertiesNavigate(InspectorNav),
+
+    /// Details subtree (with properties) received for a widget.
+    WidgetPropertiesFetched {
+        session_id: SessionId,
+        /// `value_id` of the widget the properties belong to.
+        node_id: String,
+        details: Box<DiagnosticsNode>,
+    },
+
+    /// Properties fetch failed or timed out.
+    WidgetPropertiesFetchFailed {
+        session_id: SessionId,
+        node_id: String,
+        error: String,
+    },
+
+    /// A `Flutter.Navigation` event arrived on the Extension stream.
+    ///
+    /// The current route changed, so a widget tree fetched before it is stale.
+    VmServiceNavigationEvent { session_id: SessionId },
+
     // ─────────────────────────────────────────────────────────────────────────
     // VM Service Debug Messages (DAP Server Phase 1, Task 05)
     // ─────────────────────────────────────────────────────────────────────────
@@ -958,10 +1237,21 @@ pub enum Message {
         event: fdemon_daemon::vm_service::debugger_types::IsolateEvent,
     },
 
+    /// Isolates already running when the VM Service connected, from `getVM`.
+    ///
+    /// Their `IsolateStart` events were sent before the Isolate stream was
+    /// subscribed, so they are added to the known isolates here.
+    VmServiceIsolatesDiscovered {
+        session_id: SessionId,
+        isolates: Vec<fdemon_daemon::vm_service::debugger_types::IsolateRef>,
+    },
+
     // ── VM Service Network Messages (Phase 4, Network Monitor) ───────────────
     /// HTTP profile poll results arrived.
     VmServiceHttpProfileReceived {
         session_id: SessionId,
+        /// Isolate the entries were polled from; a change signals a hot restart.
+        isolate_id: String,
         timestamp: i64,
         entries: Vec<HttpProfileEntry>,
     },
@@ -995,6 +1285,28 @@ pub enum Message {
     /// Network extensions not available (e.g., release mode).
     VmServiceNetworkExtensionsUnavailable { session_id: SessionId },
 
+    // ── Local HTTP Proxy Messages ─────────────────────────────────────────────
+    /// The session's local HTTP proxy is listening.
+    NetworkProxyStarted {
+        session_id: SessionId,
+        /// `host:port` advertised to the app.
+        address: String,
+        /// Name of the `--dart-define` carrying the address.
+        dart_define: String,
+    },
+
+    /// The session's local HTTP proxy could not be started.
+    NetworkProxyFailed {
+        session_id: SessionId,
+        error: String,
+    },
+
+    /// The proxy recorded a request (pending or completed).
+    NetworkProxyExchange {
+        session_id: SessionId,
+        detail: Box<HttpProfileEntryDetail>,
+    },
+
     // ── Network Monitor UI Messages ───────────────────────────────────────────
     /// Navigate the network request list.
     NetworkNavigate(NetworkNav),
@@ -1029,10 +1341,278 @@ pub enum Message {
     /// Delete last character from filter input buffer.
     NetworkFilterBackspace,
 
+    /// Toggle the request list between table and waterfall views.
+    NetworkToggleWaterfall,
+
+    /// Zoom or pan the waterfall timeline.
+    NetworkWaterfallNavigate(WaterfallNav),
+
     // ── Performance Panel UI Messages ─────────────────────────────────────────
     /// Toggle the allocation table sort column (Size ↔ Instances).
     ToggleAllocationSort,
 
+    /// Enable or disable widget rebuild tracking for the selected session.
+    ToggleRebuildTracking,
+
+    /// Toggle the rebuild table sort column (Total ↔ Last frame).
+    ToggleRebuildSort,
+
+    /// Move the rebuild table selection to `index` (clamped by the handler).
+    SelectRebuildRow { index: usize },
+
+    /// Open the selected rebuild table row's creation location in the editor.
+    OpenRebuildLocation,
+
+    /// Reset all rebuild counts for the selected session.
+    ClearRebuildCounts,
+
+    /// Export the selected session's frame, memory and GC data to
+    /// `.fdemon/perf/`.
+    ExportPerformance,
+
+    /// Result of an export: the directory written or an error message.
+    PerformanceExported {
+        session_id: SessionId,
+        result: Result<std::path::PathBuf, String>,
+    },
+
+    /// Save the selected session's metrics as a named baseline. `None` uses
+    /// `devtools.baseline.name`.
+    SavePerfBaseline { name: Option<String> },
+
+    /// Result of saving a baseline: the file written or an error message.
+    PerfBaselineSaved {
+        session_id: SessionId,
+        result: Result<std::path::PathBuf, String>,
+    },
+
+    /// Compare the selected session's metrics against a saved baseline.
+    /// `None` uses `devtools.baseline.name`.
+    ComparePerfBaseline { name: Option<String> },
+
+    /// Result of a baseline comparison.
+    PerfBaselineCompared {
+        session_id: SessionId,
+        result: Result<fdemon_core::PerfComparison, String>,
+    },
+
+    /// Capture the allocation profile (after a forced GC) of the selected
+    /// session. The first capture becomes the base; later ones are diffed
+    /// against it.
+    CaptureAllocationDiff,
+
+    /// Result of an allocation diff capture.
+    AllocationDiffCaptured {
+        session_id: SessionId,
+        result: Result<fdemon_core::AllocationProfile, String>,
+    },
+
+    /// Drop the base allocation profile and its diff.
+    ClearAllocationDiff,
+
+    /// Allocation profile (after a forced GC) taken for a leak hint
+    /// checkpoint after a hot reload or route change.
+    LeakCheckpointCaptured {
+        session_id: SessionId,
+        result: Result<fdemon_core::AllocationProfile, String>,
+    },
+
+    // ── Instance Browser Messages ─────────────────────────────────────────────
+    /// Select a row of the allocation table (clamped to the rows shown).
+    SelectAllocationRow { index: usize },
+
+    /// List live instances of the class in the selected allocation table row.
+    OpenInstanceBrowser,
+
+    /// Result of listing a class's instances.
+    InstancesFetched {
+        session_id: SessionId,
+        class_id: String,
+        result: Result<fdemon_core::InstanceSet, String>,
+    },
+
+    /// Select an instance in the instance browser (clamped).
+    SelectInstance { index: usize },
+
+    /// Fetch the fields and retaining path of the selected instance.
+    InspectInstance,
+
+    /// Result of inspecting an instance.
+    InstanceInspected {
+        session_id: SessionId,
+        object_id: String,
+        result: Result<fdemon_core::InstanceDetails, String>,
+    },
+
+    /// Close the instance browser and return to the allocation table.
+    CloseInstanceBrowser,
+
+    /// Switch DevTools memory, allocation and CPU data to the next isolate
+    /// of the selected session (main first, then background isolates).
+    CycleDevToolsIsolate,
+
+    // ── Dump Viewer Messages ──────────────────────────────────────────────────
+    /// Select which tree the dump viewer shows and fetch it.
+    DumpSelectKind(DebugDumpKind),
+
+    /// Re-fetch the selected dump kind for a session.
+    RequestDump { session_id: SessionId },
+
+    /// Text dump received from the VM Service.
+    DumpFetched {
+        session_id: SessionId,
+        kind: DebugDumpKind,
+        text: String,
+    },
+
+    /// Text dump fetch failed.
+    DumpFetchFailed {
+        session_id: SessionId,
+        kind: DebugDumpKind,
+        error: String,
+    },
+
+    /// Navigate within the dump viewer.
+    DumpNavigate(DumpNav),
+
+    /// Open the dump search bar (activates text input).
+    DumpEnterSearchMode,
+
+    /// Close the dump search bar and clear the query.
+    DumpExitSearchMode,
+
+    /// Close the dump search bar, keeping the query for `]`/`[`.
+    DumpCommitSearch,
+
+    /// Append a character to the dump search query.
+    DumpSearchInput(char),
+
+    /// Delete the last character of the dump search query.
+    DumpSearchBackspace,
+
+    /// Save the loaded dump to `.fdemon/dumps/`.
+    SaveDump,
+
+    /// Result of saving a dump: the written path or an error message.
+    DumpSaved {
+        result: Result<std::path::PathBuf, String>,
+    },
+
+    // ── CPU Profiler Messages ─────────────────────────────────────────────────
+    /// Start a CPU recording, or stop the running one and fetch its samples.
+    ProfilerToggleRecording,
+
+    /// Recording started: samples were cleared at `origin_micros` on the VM
+    /// timeline clock.
+    CpuProfilingStarted {
+        session_id: SessionId,
+        origin_micros: i64,
+    },
+
+    /// CPU samples for a finished recording received from the VM Service.
+    CpuProfileFetched {
+        session_id: SessionId,
+        profile: fdemon_core::CpuProfile,
+    },
+
+    /// Starting a recording or fetching its samples failed.
+    CpuProfilingFailed {
+        session_id: SessionId,
+        error: String,
+    },
+
+    /// Switch between bottom-up, top-down and flame graph views.
+    ProfilerSelectView(ProfilerView),
+
+    /// Show or hide SDK, package and native frames.
+    ProfilerToggleProjectOnly,
+
+    /// Navigate within the profiler call tree.
+    ProfilerNavigate(ProfilerNav),
+
+    // ── VM Service Console Messages ───────────────────────────────────────────
+    /// Show the console panel and activate its command input.
+    ConsoleFocusInput,
+
+    /// Deactivate the command input, keeping the typed text.
+    ConsoleBlurInput,
+
+    /// Append a character to the console input.
+    ConsoleInput(char),
+
+    /// Delete the last character of the console input.
+    ConsoleBackspace,
+
+    /// Run the console input as a command.
+    ConsoleSubmit,
+
+    /// Complete the method name in the console input.
+    ConsoleComplete,
+
+    /// Recall the previous (`older`) or next command from the history.
+    ConsoleHistory { older: bool },
+
+    /// Scroll the console output.
+    ConsoleNavigate(ConsoleNav),
+
+    /// A console RPC finished with its JSON result or an error message.
+    ConsoleRpcCompleted {
+        session_id: SessionId,
+        method: String,
+        result: Result<serde_json::Value, String>,
+    },
+
+    /// Service extensions registered by the session's isolates, for
+    /// console completion.
+    ConsoleExtensionsFetched {
+        session_id: SessionId,
+        extensions: Vec<String>,
+    },
+
+    /// Starting (`tailed`) or stopping the echo of a stream finished.
+    ConsoleStreamTailed {
+        session_id: SessionId,
+        stream_id: String,
+        tailed: bool,
+        result: Result<(), String>,
+    },
+
+    /// An event arrived on a stream tailed by the console.
+    VmServiceConsoleEvent {
+        session_id: SessionId,
+        stream_id: String,
+        event: serde_json::Value,
+    },
+
+    // ── Service Extension Panel Messages ──────────────────────────────────────
+    /// Re-fetch the app-registered service extensions of the DevTools isolate.
+    RequestServiceExtensions,
+
+    /// App-registered service extensions of the DevTools isolate, or the
+    /// fetch error.
+    ServiceExtensionsFetched {
+        session_id: SessionId,
+        result: Result<Vec<String>, String>,
+    },
+
+    /// Move the Extensions panel selection.
+    ExtensionsNavigate(ExtensionNav),
+
+    /// Call the selected row of the Extensions panel.
+    CallSelectedExtension,
+
+    /// Call the configured extension button at this index.
+    PressExtensionButton(usize),
+
+    /// A service extension call finished with its response or an error
+    /// message.
+    ServiceExtensionCalled {
+        session_id: SessionId,
+        label: String,
+        method: String,
+        result: Result<serde_json::Value, String>,
+    },
+
     // ─────────────────────────────────────────────────────────────────────────
     // Settings — Dart Defines Modal (v1-refinements Phase 2, Task 02)
     // ─────────────────────────────────────────────────────────────────────────
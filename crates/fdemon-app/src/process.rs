@@ -48,10 +48,10 @@ pub fn process_message(
             let session_cmd_sender = get_session_cmd_sender(&action, state);
 
             // Capture the pre-hydration action for defense-in-depth failure
-            // reporting. If hydration discards a FetchWidgetTree or
-            // FetchLayoutData action (e.g. VM disconnected between handler and
-            // hydration), we send a failure message so the loading spinner is
-            // cleared.
+            // reporting. If hydration discards a FetchWidgetTree,
+            // FetchLayoutData, FetchWidgetProperties or FetchDump action (e.g. VM
+            // disconnected between handler and hydration), we send a failure
+            // message so the loading spinner is cleared.
             let pre_hydration_action = action.clone();
 
             // Hydrate actions that carry an optional VmRequestHandle with the
@@ -60,8 +60,20 @@ pub fn process_message(
             let action = hydrate_start_performance_monitoring(action, state);
             let action = action.and_then(|a| hydrate_fetch_widget_tree(a, state));
             let action = action.and_then(|a| hydrate_fetch_layout_data(a, state));
+            let action = action.and_then(|a| hydrate_fetch_widget_properties(a, state));
             let action = action.and_then(|a| hydrate_toggle_overlay(a, state));
+            let action = action.and_then(|a| hydrate_cycle_override(a, state));
+            let action = action.and_then(|a| hydrate_set_inspector_select_mode(a, state));
+            let action = action.and_then(|a| hydrate_fetch_selected_widget(a, state));
+            let action = action.and_then(|a| hydrate_set_rebuild_tracking(a, state));
+            let action = action.and_then(|a| hydrate_fetch_dump(a, state));
+            let action = action.and_then(|a| hydrate_memory_captures(a, state));
+            let action = action.and_then(|a| hydrate_cpu_profiling(a, state));
+            let action = action.and_then(|a| hydrate_instance_browser(a, state));
+            let action = action.and_then(|a| hydrate_console(a, state));
+            let action = action.and_then(|a| hydrate_service_extensions(a, state));
             let action = action.and_then(|a| hydrate_dispose_devtools_groups(a, state));
+            let action = action.map(|a| hydrate_capture_screenshots(a, state));
             let action = action.and_then(|a| hydrate_start_network_monitoring(a, state));
             let action = action.and_then(|a| hydrate_fetch_http_request_detail(a, state));
             let action = action.and_then(|a| hydrate_clear_http_profile(a, state));
@@ -96,6 +108,108 @@ pub fn process_message(
                             error: "VM Service handle unavailable".to_string(),
                         });
                     }
+                    UpdateAction::FetchWidgetProperties {
+                        session_id,
+                        node_id,
+                        ..
+                    } => {
+                        let _ = msg_tx.try_send(Message::WidgetPropertiesFetchFailed {
+                            session_id: *session_id,
+                            node_id: node_id.clone(),
+                            error: "VM Service handle unavailable".to_string(),
+                        });
+                    }
+                    UpdateAction::FetchDump {
+                        session_id, kind, ..
+                    } => {
+                        let _ = msg_tx.try_send(Message::DumpFetchFailed {
+                            session_id: *session_id,
+                            kind: *kind,
+                            error: "VM Service handle unavailable".to_string(),
+                        });
+                    }
+                    UpdateAction::FetchInstances {
+                        session_id,
+                        class_id,
+                        ..
+                    } => {
+                        let _ = msg_tx.try_send(Message::InstancesFetched {
+                            session_id: *session_id,
+                            class_id: class_id.clone(),
+                            result: Err("VM Service handle unavailable".to_string()),
+                        });
+                    }
+                    UpdateAction::InspectInstance {
+                        session_id,
+                        object_id,
+                        ..
+                    } => {
+                        let _ = msg_tx.try_send(Message::InstanceInspected {
+                            session_id: *session_id,
+                            object_id: object_id.clone(),
+                            result: Err("VM Service handle unavailable".to_string()),
+                        });
+                    }
+                    UpdateAction::CaptureAllocationDiff { session_id, .. } => {
+                        let _ = msg_tx.try_send(Message::AllocationDiffCaptured {
+                            session_id: *session_id,
+                            result: Err("VM Service handle unavailable".to_string()),
+                        });
+                    }
+                    UpdateAction::CaptureLeakCheckpoint { session_id, .. } => {
+                        let _ = msg_tx.try_send(Message::LeakCheckpointCaptured {
+                            session_id: *session_id,
+                            result: Err("VM Service handle unavailable".to_string()),
+                        });
+                    }
+                    UpdateAction::StartCpuProfiling { session_id, .. }
+                    | UpdateAction::StopCpuProfiling { session_id, .. } => {
+                        let _ = msg_tx.try_send(Message::CpuProfilingFailed {
+                            session_id: *session_id,
+                            error: "VM Service handle unavailable".to_string(),
+                        });
+                    }
+                    UpdateAction::CallConsoleRpc {
+                        session_id, method, ..
+                    } => {
+                        let _ = msg_tx.try_send(Message::ConsoleRpcCompleted {
+                            session_id: *session_id,
+                            method: method.clone(),
+                            result: Err("VM Service handle unavailable".to_string()),
+                        });
+                    }
+                    UpdateAction::TailConsoleStream {
+                        session_id,
+                        stream_id,
+                        tail,
+                        ..
+                    } => {
+                        let _ = msg_tx.try_send(Message::ConsoleStreamTailed {
+                            session_id: *session_id,
+                            stream_id: stream_id.clone(),
+                            tailed: *tail,
+                            result: Err("VM Service handle unavailable".to_string()),
+                        });
+                    }
+                    UpdateAction::FetchServiceExtensions { session_id, .. } => {
+                        let _ = msg_tx.try_send(Message::ServiceExtensionsFetched {
+                            session_id: *session_id,
+                            result: Err("VM Service handle unavailable".to_string()),
+                        });
+                    }
+                    UpdateAction::CallServiceExtension {
+                        session_id,
+                        label,
+                        method,
+                        ..
+                    } => {
+                        let _ = msg_tx.try_send(Message::ServiceExtensionCalled {
+                            session_id: *session_id,
+                            label: label.clone(),
+                            method: method.clone(),
+                            result: Err("VM Service handle unavailable".to_string()),
+                        });
+                    }
                     UpdateAction::FetchHttpRequestDetail { session_id, .. } => {
                         let _ = msg_tx.try_send(Message::VmServiceHttpRequestDetailFailed {
                             session_id: *session_id,
@@ -224,6 +338,38 @@ fn hydrate_fetch_layout_data(action: UpdateAction, state: &AppState) -> Option<U
     Some(action)
 }
 
+/// Hydrate `FetchWidgetProperties` with the `VmRequestHandle` from the session.
+///
+/// Returns `None` (discards the action) if the session has no active VM
+/// connection, since there is nothing to query without one.
+/// All other action variants are returned unchanged.
+fn hydrate_fetch_widget_properties(action: UpdateAction, state: &AppState) -> Option<UpdateAction> {
+    if let UpdateAction::FetchWidgetProperties {
+        session_id,
+        node_id,
+        vm_handle,
+    } = action
+    {
+        if vm_handle.is_some() {
+            return Some(UpdateAction::FetchWidgetProperties {
+                session_id,
+                node_id,
+                vm_handle,
+            });
+        }
+        let handle = state
+            .session_manager
+            .get(session_id)
+            .and_then(|h| h.vm_request_handle.clone())?;
+        return Some(UpdateAction::FetchWidgetProperties {
+            session_id,
+            node_id,
+            vm_handle: Some(handle),
+        });
+    }
+    Some(action)
+}
+
 /// Hydrate `ToggleOverlay` with the `VmRequestHandle` from the session.
 ///
 /// Returns `None` (discards the action) if the session has no active VM
@@ -255,6 +401,377 @@ fn hydrate_toggle_overlay(action: UpdateAction, state: &AppState) -> Option<Upda
     Some(action)
 }
 
+/// Hydrate `CycleOverride` with the `VmRequestHandle` from the session.
+///
+/// Returns `None` (discards the action) if the session has no active VM
+/// connection. All other action variants are returned unchanged.
+fn hydrate_cycle_override(action: UpdateAction, state: &AppState) -> Option<UpdateAction> {
+    if let UpdateAction::CycleOverride {
+        session_id,
+        kind,
+        current,
+        vm_handle,
+    } = action
+    {
+        if vm_handle.is_some() {
+            return Some(UpdateAction::CycleOverride {
+                session_id,
+                kind,
+                current,
+                vm_handle,
+            });
+        }
+        let handle = state
+            .session_manager
+            .get(session_id)
+            .and_then(|h| h.vm_request_handle.clone())?;
+        return Some(UpdateAction::CycleOverride {
+            session_id,
+            kind,
+            current,
+            vm_handle: Some(handle),
+        });
+    }
+    Some(action)
+}
+
+/// Hydrate each `CaptureScreenshots` target with the session's daemon
+/// `CommandSender` and `VmRequestHandle`.
+///
+/// Never discards the action: a target missing both falls back to
+/// `flutter screenshot`. All other action variants are returned unchanged.
+fn hydrate_capture_screenshots(action: UpdateAction, state: &AppState) -> UpdateAction {
+    if let UpdateAction::CaptureScreenshots {
+        mut targets,
+        dir,
+        flutter,
+    } = action
+    {
+        for target in &mut targets {
+            if let Some(handle) = state.session_manager.get(target.session_id) {
+                if target.cmd_sender.is_none() {
+                    target.cmd_sender = handle.cmd_sender.clone();
+                }
+                if target.vm_handle.is_none() {
+                    target.vm_handle = handle.vm_request_handle.clone();
+                }
+            }
+        }
+        return UpdateAction::CaptureScreenshots {
+            targets,
+            dir,
+            flutter,
+        };
+    }
+    action
+}
+
+/// Hydrate `SetInspectorSelectMode` with the `VmRequestHandle` from the session.
+///
+/// Returns `None` (discards the action) if the session has no active VM
+/// connection. All other action variants are returned unchanged.
+fn hydrate_set_inspector_select_mode(
+    action: UpdateAction,
+    state: &AppState,
+) -> Option<UpdateAction> {
+    if let UpdateAction::SetInspectorSelectMode {
+        session_id,
+        enabled,
+        vm_handle,
+    } = action
+    {
+        if vm_handle.is_some() {
+            return Some(UpdateAction::SetInspectorSelectMode {
+                session_id,
+                enabled,
+                vm_handle,
+            });
+        }
+        let handle = state
+            .session_manager
+            .get(session_id)
+            .and_then(|h| h.vm_request_handle.clone())?;
+        return Some(UpdateAction::SetInspectorSelectMode {
+            session_id,
+            enabled,
+            vm_handle: Some(handle),
+        });
+    }
+    Some(action)
+}
+
+/// Hydrate `SetRebuildTracking` with the `VmRequestHandle` from the session.
+///
+/// Returns `None` (discards the action) if the session has no active VM
+/// connection. All other action variants are returned unchanged.
+fn hydrate_set_rebuild_tracking(action: UpdateAction, state: &AppState) -> Option<UpdateAction> {
+    if let UpdateAction::SetRebuildTracking {
+        session_id,
+        enabled,
+        vm_handle,
+    } = action
+    {
+        if vm_handle.is_some() {
+            return Some(UpdateAction::SetRebuildTracking {
+                session_id,
+                enabled,
+                vm_handle,
+            });
+        }
+        let handle = state
+            .session_manager
+            .get(session_id)
+            .and_then(|h| h.vm_request_handle.clone())?;
+        return Some(UpdateAction::SetRebuildTracking {
+            session_id,
+            enabled,
+            vm_handle: Some(handle),
+        });
+    }
+    Some(action)
+}
+
+/// Hydrate `FetchDump` with the `VmRequestHandle` from the session.
+///
+/// Returns `None` (discards the action) if the session has no active VM
+/// connection. All other action variants are returned unchanged.
+fn hydrate_fetch_dump(action: UpdateAction, state: &AppState) -> Option<UpdateAction> {
+    if let UpdateAction::FetchDump {
+        session_id,
+        kind,
+        vm_handle,
+    } = action
+    {
+        if vm_handle.is_some() {
+            return Some(UpdateAction::FetchDump {
+                session_id,
+                kind,
+                vm_handle,
+            });
+        }
+        let handle = state
+            .session_manager
+            .get(session_id)
+            .and_then(|h| h.vm_request_handle.clone())?;
+        return Some(UpdateAction::FetchDump {
+            session_id,
+            kind,
+            vm_handle: Some(handle),
+        });
+    }
+    Some(action)
+}
+
+/// Hydrate `CaptureAllocationDiff` and `CaptureLeakCheckpoint` with the
+/// `VmRequestHandle` from the session.
+///
+/// Returns `None` (discards the action) if the session has no active VM
+/// connection. All other action variants are returned unchanged.
+fn hydrate_memory_captures(action: UpdateAction, state: &AppState) -> Option<UpdateAction> {
+    let vm_handle_for = |session_id: SessionId| {
+        state
+            .session_manager
+            .get(session_id)
+            .and_then(|h| h.vm_request_handle.clone())
+    };
+    match action {
+        UpdateAction::CaptureAllocationDiff {
+            session_id,
+            vm_handle: None,
+        } => Some(UpdateAction::CaptureAllocationDiff {
+            session_id,
+            vm_handle: Some(vm_handle_for(session_id)?),
+        }),
+        UpdateAction::CaptureLeakCheckpoint {
+            session_id,
+            vm_handle: None,
+        } => Some(UpdateAction::CaptureLeakCheckpoint {
+            session_id,
+            vm_handle: Some(vm_handle_for(session_id)?),
+        }),
+        other => Some(other),
+    }
+}
+
+/// Hydrate `StartCpuProfiling` and `StopCpuProfiling` with the
+/// `VmRequestHandle` from the session.
+///
+/// Returns `None` (discards the action) if the session has no active VM
+/// connection. All other action variants are returned unchanged.
+fn hydrate_cpu_profiling(action: UpdateAction, state: &AppState) -> Option<UpdateAction> {
+    let vm_handle_for = |session_id: SessionId| {
+        state
+            .session_manager
+            .get(session_id)
+            .and_then(|h| h.vm_request_handle.clone())
+    };
+    match action {
+        UpdateAction::StartCpuProfiling {
+            session_id,
+            vm_handle: None,
+        } => Some(UpdateAction::StartCpuProfiling {
+            session_id,
+            vm_handle: Some(vm_handle_for(session_id)?),
+        }),
+        UpdateAction::StopCpuProfiling {
+            session_id,
+            origin_micros,
+            vm_handle: None,
+        } => Some(UpdateAction::StopCpuProfiling {
+            session_id,
+            origin_micros,
+            vm_handle: Some(vm_handle_for(session_id)?),
+        }),
+        other => Some(other),
+    }
+}
+
+/// Hydrate `FetchInstances` and `InspectInstance` with the `VmRequestHandle`
+/// from the session.
+///
+/// Returns `None` (discards the action) if the session has no active VM
+/// connection. All other action variants are returned unchanged.
+fn hydrate_instance_browser(action: UpdateAction, state: &AppState) -> Option<UpdateAction> {
+    let vm_handle_for = |session_id: SessionId| {
+        state
+            .session_manager
+            .get(session_id)
+            .and_then(|h| h.vm_request_handle.clone())
+    };
+    match action {
+        UpdateAction::FetchInstances {
+            session_id,
+            class_id,
+            vm_handle: None,
+        } => Some(UpdateAction::FetchInstances {
+            session_id,
+            class_id,
+            vm_handle: Some(vm_handle_for(session_id)?),
+        }),
+        UpdateAction::InspectInstance {
+            session_id,
+            object_id,
+            vm_handle: None,
+        } => Some(UpdateAction::InspectInstance {
+            session_id,
+            object_id,
+            vm_handle: Some(vm_handle_for(session_id)?),
+        }),
+        other => Some(other),
+    }
+}
+
+/// Hydrate `CallConsoleRpc`, `TailConsoleStream` and `FetchConsoleExtensions`
+/// with the `VmRequestHandle` from the session.
+///
+/// Returns `None` (discards the action) if the session has no active VM
+/// connection. All other action variants are returned unchanged.
+fn hydrate_console(action: UpdateAction, state: &AppState) -> Option<UpdateAction> {
+    let vm_handle_for = |session_id: SessionId| {
+        state
+            .session_manager
+            .get(session_id)
+            .and_then(|h| h.vm_request_handle.clone())
+    };
+    match action {
+        UpdateAction::CallConsoleRpc {
+            session_id,
+            method,
+            params,
+            vm_handle: None,
+        } => Some(UpdateAction::CallConsoleRpc {
+            session_id,
+            method,
+            params,
+            vm_handle: Some(vm_handle_for(session_id)?),
+        }),
+        UpdateAction::TailConsoleStream {
+            session_id,
+            stream_id,
+            tail,
+            vm_handle: None,
+        } => Some(UpdateAction::TailConsoleStream {
+            session_id,
+            stream_id,
+            tail,
+            vm_handle: Some(vm_handle_for(session_id)?),
+        }),
+        UpdateAction::FetchConsoleExtensions {
+            session_id,
+            vm_handle: None,
+        } => Some(UpdateAction::FetchConsoleExtensions {
+            session_id,
+            vm_handle: Some(vm_handle_for(session_id)?),
+        }),
+        other => Some(other),
+    }
+}
+
+/// Hydrate `FetchServiceExtensions` and `CallServiceExtension` with the
+/// `VmRequestHandle` from the session.
+///
+/// Returns `None` (discards the action) if the session has no active VM
+/// connection. All other action variants are returned unchanged.
+fn hydrate_service_extensions(action: UpdateAction, state: &AppState) -> Option<UpdateAction> {
+    let vm_handle_for = |session_id: SessionId| {
+        state
+            .session_manager
+            .get(session_id)
+            .and_then(|h| h.vm_request_handle.clone())
+    };
+    match action {
+        UpdateAction::FetchServiceExtensions {
+            session_id,
+            vm_handle: None,
+        } => Some(UpdateAction::FetchServiceExtensions {
+            session_id,
+            vm_handle: Some(vm_handle_for(session_id)?),
+        }),
+        UpdateAction::CallServiceExtension {
+            session_id,
+            label,
+            method,
+            args,
+            vm_handle: None,
+        } => Some(UpdateAction::CallServiceExtension {
+            session_id,
+            label,
+            method,
+            args,
+            vm_handle: Some(vm_handle_for(session_id)?),
+        }),
+        other => Some(other),
+    }
+}
+
+/// Hydrate `FetchSelectedWidget` with the `VmRequestHandle` from the session.
+///
+/// Returns `None` (discards the action) if the session has no active VM
+/// connection. All other action variants are returned unchanged.
+fn hydrate_fetch_selected_widget(action: UpdateAction, state: &AppState) -> Option<UpdateAction> {
+    if let UpdateAction::FetchSelectedWidget {
+        session_id,
+        vm_handle,
+    } = action
+    {
+        if vm_handle.is_some() {
+            return Some(UpdateAction::FetchSelectedWidget {
+                session_id,
+                vm_handle,
+            });
+        }
+        let handle = state
+            .session_manager
+            .get(session_id)
+            .and_then(|h| h.vm_request_handle.clone())?;
+        return Some(UpdateAction::FetchSelectedWidget {
+            session_id,
+            vm_handle: Some(handle),
+        });
+    }
+    Some(action)
+}
+
 /// Hydrate `DisposeDevToolsGroups` with the `VmRequestHandle` from the session.
 ///
 /// Unlike the fetch hydration functions, this one does **not** return `None`
@@ -431,23 +948,34 @@ fn get_session_cmd_sender(action: &UpdateAction, state: &AppState) -> Option<Com
     None
 }
 
-/// Get command senders for all sessions in ReloadAllSessions action
+/// Get command senders for all sessions in ReloadAllSessions and
+/// SpawnGroupTasks actions
 fn get_session_cmd_senders_for_action(
     action: &UpdateAction,
     state: &AppState,
 ) -> Vec<(SessionId, String, CommandSender)> {
-    if let UpdateAction::ReloadAllSessions { sessions } = action {
-        sessions
+    let sessions: Vec<(SessionId, String)> = match action {
+        UpdateAction::ReloadAllSessions { sessions } => sessions.clone(),
+        UpdateAction::SpawnGroupTasks { tasks } => tasks
             .iter()
-            .filter_map(|(session_id, app_id)| {
-                state
-                    .session_manager
-                    .get(*session_id)
-                    .and_then(|h| h.cmd_sender.clone())
-                    .map(|sender| (*session_id, app_id.clone(), sender))
+            .map(|task| match task {
+                Task::Reload { session_id, app_id }
+                | Task::Restart { session_id, app_id }
+                | Task::Stop {
+                    session_id, app_id, ..
+                } => (*session_id, app_id.clone()),
             })
-            .collect()
-    } else {
-        Vec::new()
-    }
+            .collect(),
+        _ => return Vec::new(),
+    };
+    sessions
+        .into_iter()
+        .filter_map(|(session_id, app_id)| {
+            state
+                .session_manager
+                .get(session_id)
+                .and_then(|h| h.cmd_sender.clone())
+                .map(|sender| (session_id, app_id, sender))
+        })
+        .collect()
 }
@@ -0,0 +1,342 @@
+//! CPU profiler state - recording window, loaded profile and call tree rows.
+//!
+//! A recording runs from a keypress that marks the start on the VM timeline
+//! clock to a second keypress that fetches the samples taken since. The
+//! loaded [`CpuProfile`] is aggregated into a [`CallTree`] for the current
+//! view (bottom up, top down, or the top-down flame graph), optionally
+//! reduced to project frames. This module tracks which tree nodes are
+//! expanded and which row is selected; it is shared by the app handler
+//! layer and the TUI profiler view.
+
+use std::collections::HashSet;
+
+use fdemon_core::profiler::{CallTree, CallTreeDirection, CallTreeNode, CpuProfile};
+
+use crate::session::SessionId;
+use crate::state::DevToolsError;
+
+/// Number of rows moved by PageUp / PageDown in the profiler tables.
+pub const PROFILER_PAGE_STEP: usize = 10;
+
+/// How the loaded profile is shown.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ProfilerView {
+    /// Functions that were executing, expanding to their callers.
+    #[default]
+    BottomUp,
+    /// Entry points, expanding to their callees.
+    TopDown,
+    /// Top-down call tree drawn as stacked bars, widths proportional to time.
+    FlameGraph,
+}
+
+impl ProfilerView {
+    /// All views, in tab order.
+    pub const ALL: [ProfilerView; 3] = [
+        ProfilerView::BottomUp,
+        ProfilerView::TopDown,
+        ProfilerView::FlameGraph,
+    ];
+
+    /// Tab label.
+    pub fn label(self) -> &'static str {
+        match self {
+            ProfilerView::BottomUp => "Bottom Up",
+            ProfilerView::TopDown => "Top Down",
+            ProfilerView::FlameGraph => "Flame Graph",
+        }
+    }
+
+    /// Aggregation direction of the call tree behind this view.
+    pub fn direction(self) -> CallTreeDirection {
+        match self {
+            ProfilerView::BottomUp => CallTreeDirection::BottomUp,
+            ProfilerView::TopDown | ProfilerView::FlameGraph => CallTreeDirection::TopDown,
+        }
+    }
+}
+
+/// An in-progress recording.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProfilerRecording {
+    /// Session being profiled.
+    pub session_id: SessionId,
+    /// Start of the window on the VM timeline clock, in microseconds.
+    pub origin_micros: i64,
+}
+
+/// A visible row of the call tree table.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProfilerRow {
+    /// Child indices from the tree roots to this node.
+    pub path: Vec<usize>,
+}
+
+impl ProfilerRow {
+    /// Nesting depth (0 for roots).
+    pub fn depth(&self) -> usize {
+        self.path.len().saturating_sub(1)
+    }
+}
+
+/// State for the DevTools CPU profiler panel.
+#[derive(Debug, Clone, Default)]
+pub struct ProfilerViewState {
+    /// Selected view tab.
+    pub view: ProfilerView,
+
+    /// Whether SDK, package and native frames are hidden.
+    pub project_only: bool,
+
+    /// The recording in progress, if any.
+    pub recording: Option<ProfilerRecording>,
+
+    /// Whether a start request is in flight.
+    pub starting: bool,
+
+    /// Whether the samples for a finished recording are being fetched.
+    pub loading: bool,
+
+    /// Samples of the last finished recording.
+    pub profile: Option<CpuProfile>,
+
+    /// `profile` aggregated for the current view and filter.
+    pub tree: CallTree,
+
+    /// Paths of expanded tree nodes.
+    pub expanded: HashSet<Vec<usize>>,
+
+    /// Index into [`visible_rows`](Self::visible_rows) of the selected row.
+    pub selected: usize,
+
+    /// Last start or fetch error, if any.
+    pub error: Option<DevToolsError>,
+}
+
+impl ProfilerViewState {
+    /// Reset to the initial empty state, keeping the view and filter.
+    pub fn reset(&mut self) {
+        *self = Self {
+            view: self.view,
+            project_only: self.project_only,
+            ..Self::default()
+        };
+    }
+
+    /// Whether a start or fetch request is in flight.
+    pub fn is_busy(&self) -> bool {
+        self.starting || self.loading
+    }
+
+    /// Replace the loaded profile and rebuild the tree.
+    pub fn set_profile(&mut self, profile: CpuProfile) {
+        self.profile = Some(profile);
+        self.loading = false;
+        self.error = None;
+        self.rebuild_tree();
+    }
+
+    /// Record a failed start or fetch. A previously loaded profile is kept.
+    pub fn set_error(&mut self, error: DevToolsError) {
+        self.starting = false;
+        self.loading = false;
+        self.recording = None;
+        self.error = Some(error);
+    }
+
+    /// Switch views, rebuilding the tree if the direction changes.
+    pub fn set_view(&mut self, view: ProfilerView) {
+        let rebuild = view.direction() != self.view.direction();
+        self.view = view;
+        if rebuild {
+            self.rebuild_tree();
+        }
+    }
+
+    /// Show or hide non-project frames.
+    pub fn toggle_project_only(&mut self) {
+        self.project_only = !self.project_only;
+        self.rebuild_tree();
+    }
+
+    /// Re-aggregate `profile` for the current view and filter. Expansion and
+    /// selection are reset because node paths change.
+    fn rebuild_tree(&mut self) {
+        self.tree = self
+            .profile
+            .as_ref()
+            .map(|p| p.call_tree(self.view.direction(), self.project_only))
+            .unwrap_or_default();
+        self.expanded.clear();
+        self.selected = 0;
+    }
+
+    /// Rows of the call tree not hidden by a collapsed ancestor, in
+    /// depth-first order.
+    pub fn visible_rows(&self) -> Vec<ProfilerRow> {
+        fn walk(
+            nodes: &[CallTreeNode],
+            path: &mut Vec<usize>,
+            expanded: &HashSet<Vec<usize>>,
+            out: &mut Vec<ProfilerRow>,
+        ) {
+            for (i, node) in nodes.iter().enumerate() {
+                path.push(i);
+                out.push(ProfilerRow { path: path.clone() });
+                if expanded.contains(path.as_slice()) {
+                    walk(&node.children, path, expanded, out);
+                }
+                path.pop();
+            }
+        }
+        let mut rows = Vec::new();
+        walk(&self.tree.roots, &mut Vec::new(), &self.expanded, &mut rows);
+        rows
+    }
+
+    /// Path of the selected row, if any.
+    pub fn selected_path(&self) -> Option<Vec<usize>> {
+        self.visible_rows()
+            .into_iter()
+            .nth(self.selected)
+            .map(|row| row.path)
+    }
+
+    /// Move the selection by `delta` rows, clamped to the ends.
+    pub fn move_selection(&mut self, delta: isize) {
+        let count = self.visible_rows().len();
+        if count == 0 {
+            return;
+        }
+        self.selected = self.selected.saturating_add_signed(delta).min(count - 1);
+    }
+
+    /// Expand the selected node if it has children.
+    pub fn expand_selected(&mut self) {
+        if let Some(path) = self.selected_path() {
+            if self
+                .tree
+                .node(&path)
+                .is_some_and(|n| !n.children.is_empty())
+            {
+                self.expanded.insert(path);
+            }
+        }
+    }
+
+    /// Collapse the selected node, or select its parent if it is already
+    /// collapsed or has no children.
+    pub fn collapse_selected(&mut self) {
+        let Some(path) = self.selected_path() else {
+            return;
+        };
+        if self.expanded.remove(&path) {
+            return;
+        }
+        if path.len() > 1 {
+            let parent = &path[..path.len() - 1];
+            if let Some(index) = self
+                .visible_rows()
+                .iter()
+                .position(|row| row.path == parent)
+            {
+                self.selected = index;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fdemon_core::profiler::ProfileFunction;
+
+    fn function(name: &str) -> ProfileFunction {
+        ProfileFunction {
+            name: name.to_string(),
+            owner: None,
+            url: Some(format!("package:app/{name}.dart")),
+            kind: "Dart".to_string(),
+        }
+    }
+
+    /// main → build → layout, main → paint
+    fn loaded(view: ProfilerView) -> ProfilerViewState {
+        let mut state = ProfilerViewState {
+            view,
+            ..Default::default()
+        };
+        state.set_profile(CpuProfile {
+            functions: vec![
+                function("main"),
+                function("build"),
+                function("layout"),
+                function("paint"),
+            ],
+            samples: vec![vec![2, 1, 0], vec![2, 1, 0], vec![3, 0]],
+            sample_period_micros: 250,
+            time_extent_micros: 1_000,
+        });
+        state
+    }
+
+    #[test]
+    fn test_expand_and_collapse_rows() {
+        let mut state = loaded(ProfilerView::TopDown);
+        assert_eq!(state.visible_rows().len(), 1);
+
+        state.expand_selected();
+        let rows = state.visible_rows();
+        assert_eq!(rows.len(), 3);
+        assert_eq!(rows[1].path, vec![0, 0]);
+        assert_eq!(rows[1].depth(), 1);
+
+        state.move_selection(1);
+        state.collapse_selected();
+        assert_eq!(state.selected, 0, "leaf collapse selects the parent");
+        state.collapse_selected();
+        assert_eq!(state.visible_rows().len(), 1);
+    }
+
+    #[test]
+    fn test_move_selection_clamps() {
+        let mut state = loaded(ProfilerView::BottomUp);
+        state.move_selection(10);
+        assert_eq!(state.selected, 1, "layout and paint are the only roots");
+        state.move_selection(-10);
+        assert_eq!(state.selected, 0);
+    }
+
+    #[test]
+    fn test_set_view_rebuilds_on_direction_change() {
+        let mut state = loaded(ProfilerView::TopDown);
+        state.expand_selected();
+        state.set_view(ProfilerView::FlameGraph);
+        assert_eq!(state.expanded.len(), 1, "same direction keeps expansion");
+        state.set_view(ProfilerView::BottomUp);
+        assert!(state.expanded.is_empty());
+        assert_eq!(state.tree.roots.len(), 2);
+    }
+
+    #[test]
+    fn test_set_error_clears_recording() {
+        let mut state = loaded(ProfilerView::TopDown);
+        state.recording = Some(ProfilerRecording {
+            session_id: 1,
+            origin_micros: 0,
+        });
+        state.set_error(DevToolsError::new("failed", "retry"));
+        assert!(state.recording.is_none());
+        assert!(state.profile.is_some(), "loaded profile is kept");
+    }
+
+    #[test]
+    fn test_reset_keeps_view_and_filter() {
+        let mut state = loaded(ProfilerView::FlameGraph);
+        state.project_only = true;
+        state.reset();
+        assert_eq!(state.view, ProfilerView::FlameGraph);
+        assert!(state.project_only);
+        assert!(state.profile.is_none());
+    }
+}
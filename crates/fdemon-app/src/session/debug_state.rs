@@ -12,6 +12,9 @@ use std::collections::HashMap;
 // the direct submodule path is used here to keep the familiar `IsolateRef` name.
 use fdemon_daemon::vm_service::debugger_types::{ExceptionPauseMode, IsolateRef};
 
+/// Name Flutter gives the root isolate that runs `main()`.
+const MAIN_ISOLATE_NAME: &str = "main";
+
 // ---------------------------------------------------------------------------
 // PauseReason
 // ---------------------------------------------------------------------------
@@ -201,6 +204,20 @@ impl DebugState {
         self.isolates.retain(|i| i.id != isolate_id);
     }
 
+    /// Isolates that DevTools memory and CPU data can be read from, as
+    /// `(isolate ID, name)` pairs: the main isolate first with a `None` ID,
+    /// then the other known isolates (background workers) in start order.
+    pub fn devtools_isolates(&self) -> Vec<(Option<&str>, &str)> {
+        let mut isolates = vec![(None, MAIN_ISOLATE_NAME)];
+        isolates.extend(
+            self.isolates
+                .iter()
+                .filter(|i| i.name.as_deref() != Some(MAIN_ISOLATE_NAME))
+                .map(|i| (Some(i.id.as_str()), i.name.as_deref().unwrap_or(&i.id))),
+        );
+        isolates
+    }
+
     /// Clears all breakpoints.
     ///
     /// Used on hot restart when breakpoints need to be re-applied after
@@ -393,6 +410,31 @@ mod tests {
         assert!(state.isolates.is_empty());
     }
 
+    #[test]
+    fn test_devtools_isolates_lists_main_first() {
+        let mut state = DebugState::default();
+        assert_eq!(state.devtools_isolates(), [(None, "main")]);
+
+        for (id, name) in [("isolates/2", Some("worker")), ("isolates/1", Some("main"))] {
+            state.add_isolate(IsolateRef {
+                id: id.to_string(),
+                name: name.map(String::from),
+            });
+        }
+        state.add_isolate(IsolateRef {
+            id: "isolates/3".to_string(),
+            name: None,
+        });
+        assert_eq!(
+            state.devtools_isolates(),
+            [
+                (None, "main"),
+                (Some("isolates/2"), "worker"),
+                (Some("isolates/3"), "isolates/3"),
+            ]
+        );
+    }
+
     #[test]
     fn test_remove_isolate_noop_for_unknown_id() {
         let mut state = DebugState::default();
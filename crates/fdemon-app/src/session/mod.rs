@@ -21,9 +21,11 @@ pub use debug_state::{DebugState, PauseReason, TrackedBreakpoint};
 pub use handle::{CustomSourceHandle, SessionHandle, SharedSourceHandle};
 pub use log_batcher::LogBatcher;
 pub use native_tags::NativeTagState;
-pub use network::{NetworkDetailTab, NetworkState};
+pub use network::{NetworkDetailTab, NetworkState, NetworkViewMode};
 pub(crate) use performance::STATS_RECOMPUTE_INTERVAL;
-pub use performance::{AllocationSortColumn, PerformanceState};
+pub use performance::{
+    AllocationSortColumn, InstanceBrowser, PerformanceState, RebuildSortColumn, MAX_ALLOCATION_ROWS,
+};
 pub use session::Session;
 
 // SessionId and next_session_id live here in mod.rs
@@ -4,9 +4,9 @@
 //! Stores the rolling request history, selected request detail,
 //! and UI interaction state (filter, sort, recording toggle).
 
-use std::collections::VecDeque;
+use std::collections::{HashMap, VecDeque};
 
-use fdemon_core::network::{HttpProfileEntry, HttpProfileEntryDetail, SocketEntry};
+use fdemon_core::network::{HttpProfileEntry, HttpProfileEntryDetail, SocketEntry, TimingSegment};
 
 // ── NetworkDetailTab ──────────────────────────────────────────────────────────
 
@@ -25,9 +25,27 @@ pub enum NetworkDetailTab {
     Timing,
 }
 
+/// How the request list is presented in the Network panel.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NetworkViewMode {
+    /// Column table (status, method, duration, size, type, URI).
+    #[default]
+    Table,
+    /// Waterfall timeline — one bar per request over a shared time axis.
+    Waterfall,
+}
+
 /// Maximum number of network entries to keep per session.
 pub const DEFAULT_MAX_NETWORK_ENTRIES: usize = 500;
 
+/// Maximum waterfall zoom level. Each level halves the visible time window,
+/// so level 6 shows 1/64th of the captured span.
+pub const MAX_WATERFALL_ZOOM: u8 = 6;
+
+/// Number of pan steps per visible waterfall window (each step pans by a
+/// quarter of the window).
+pub const WATERFALL_PAN_STEPS_PER_WINDOW: u32 = 4;
+
 /// Per-session network monitoring state.
 #[derive(Debug)]
 pub struct NetworkState {
@@ -63,6 +81,33 @@ pub struct NetworkState {
     pub filter_input_active: bool,
     /// Buffer for the filter text being typed (committed on Enter).
     pub filter_input_buffer: String,
+    /// Whether the request list is shown as a table or a waterfall.
+    pub view_mode: NetworkViewMode,
+    /// Waterfall zoom level (0 = whole captured span, max [`MAX_WATERFALL_ZOOM`]).
+    pub waterfall_zoom: u8,
+    /// Waterfall pan position in quarter-window steps from the left edge.
+    pub waterfall_pan: u32,
+    /// Phase segments per request ID, cached from fetched request details so
+    /// the waterfall can draw DNS/connect/TLS/wait/receive breakdowns.
+    pub timing_segments: HashMap<String, Vec<TimingSegment>>,
+    /// Address of the session's local HTTP proxy, if one is running.
+    pub proxy_address: Option<String>,
+    /// Full details for proxy-recorded requests, keyed by request ID. The VM
+    /// knows nothing about these, so detail lookups are served from here.
+    pub proxy_details: HashMap<String, Box<HttpProfileEntryDetail>>,
+    /// Keep history across hot restarts and session relaunches (config-derived).
+    pub persist_history: bool,
+    /// Queue evicted entries for writing to disk (config-derived).
+    pub spill_to_disk: bool,
+    /// Restart generation of the current run (0 = first launch). Only
+    /// advances when `persist_history` is set.
+    pub generation: u32,
+    /// Isolate that the current run's VM entries belong to.
+    pub isolate_id: Option<String>,
+    /// Restart generation each entry was captured in, keyed by entry ID.
+    pub entry_generations: HashMap<String, u32>,
+    /// Evicted entries (with their generation) waiting to be spilled to disk.
+    pub pending_spill: Vec<(u32, HttpProfileEntry)>,
 }
 
 impl Default for NetworkState {
@@ -83,6 +128,18 @@ impl Default for NetworkState {
             last_error: None,
             filter_input_active: false,
             filter_input_buffer: String::new(),
+            view_mode: NetworkViewMode::default(),
+            waterfall_zoom: 0,
+            waterfall_pan: 0,
+            timing_segments: HashMap::new(),
+            proxy_address: None,
+            proxy_details: HashMap::new(),
+            persist_history: false,
+            spill_to_disk: false,
+            generation: 0,
+            isolate_id: None,
+            entry_generations: HashMap::new(),
+            pending_spill: Vec::new(),
         }
     }
 }
@@ -100,36 +157,86 @@ impl NetworkState {
         }
     }
 
+    /// Enable history persistence and/or spilling evicted entries to disk.
+    pub fn with_history(mut self, persist_history: bool, spill_to_disk: bool) -> Self {
+        self.persist_history = persist_history;
+        self.spill_to_disk = spill_to_disk;
+        self
+    }
+
+    /// Build the state for a relaunched session on the same device, carrying
+    /// over the recorded history as a new restart generation.
+    ///
+    /// Per-run state (selection, poll cursor, VM/proxy status) starts fresh;
+    /// `max_entries` and `recording` come from the current config.
+    pub fn carry_over(mut self, max_entries: usize, auto_record: bool) -> Self {
+        self.begin_generation();
+        let mut next = Self::with_config(max_entries, auto_record)
+            .with_history(self.persist_history, self.spill_to_disk);
+        next.entries = self.entries;
+        next.timing_segments = self.timing_segments;
+        next.proxy_details = self.proxy_details;
+        next.entry_generations = self.entry_generations;
+        next.pending_spill = self.pending_spill;
+        next.generation = self.generation;
+        next.evict_over_capacity();
+        next
+    }
+
     /// Reset to initial state (used on session switch or disconnect).
     ///
-    /// Preserves config-derived fields (`max_entries`, `recording`) so that
-    /// settings from `.fdemon/config.toml` (e.g. `network_auto_record = false`)
-    /// survive a session reset. All other fields revert to their defaults.
+    /// Preserves config-derived fields (`max_entries`, `recording`,
+    /// `persist_history`, `spill_to_disk`) so that settings from
+    /// `.fdemon/config.toml` (e.g. `network_auto_record = false`) survive a
+    /// session reset, as does `proxy_address` since the proxy lives as long
+    /// as the session. All other fields revert to their defaults.
     pub fn reset(&mut self) {
         *self = Self {
             max_entries: self.max_entries,
             recording: self.recording,
+            persist_history: self.persist_history,
+            spill_to_disk: self.spill_to_disk,
+            proxy_address: self.proxy_address.take(),
             ..Self::default()
         };
     }
 
     /// Merge new entries from an incremental poll into the existing list.
     ///
-    /// Updates existing entries (matched by ID) and appends new ones.
-    /// Evicts oldest entries if `max_entries` is exceeded.
+    /// Updates existing entries (matched by ID) and appends new ones, tagging
+    /// them with the current restart generation. Evicts oldest entries if
+    /// `max_entries` is exceeded.
     pub fn merge_entries(&mut self, new_entries: Vec<HttpProfileEntry>) {
         for new_entry in new_entries {
             if let Some(existing) = self.entries.iter_mut().find(|e| e.id == new_entry.id) {
                 // Update existing entry (e.g., request completed, status code arrived)
                 *existing = new_entry;
             } else {
+                self.entry_generations
+                    .insert(new_entry.id.clone(), self.generation);
                 self.entries.push_back(new_entry);
             }
         }
-        // Evict oldest entries if over capacity. `pop_front` is O(1) on VecDeque
-        // whereas the previous `Vec::remove(0)` was O(n) due to element shifting.
+        self.evict_over_capacity();
+    }
+
+    /// Evict oldest entries until `max_entries` is respected, queueing them
+    /// for spilling when enabled.
+    fn evict_over_capacity(&mut self) {
+        // `pop_front` is O(1) on VecDeque whereas the previous `Vec::remove(0)`
+        // was O(n) due to element shifting.
         while self.entries.len() > self.max_entries {
-            self.entries.pop_front();
+            if let Some(evicted) = self.entries.pop_front() {
+                self.timing_segments.remove(&evicted.id);
+                self.proxy_details.remove(&evicted.id);
+                let generation = self
+                    .entry_generations
+                    .remove(&evicted.id)
+                    .unwrap_or(self.generation);
+                if self.spill_to_disk {
+                    self.pending_spill.push((generation, evicted));
+                }
+            }
             // Adjust selected_index and scroll_offset
             if let Some(ref mut idx) = self.selected_index {
                 if *idx == 0 {
@@ -145,6 +252,72 @@ impl NetworkState {
         }
     }
 
+    /// Record the isolate that polled entries came from.
+    ///
+    /// A different isolate than before means the app was hot restarted. With
+    /// `persist_history` the previous run's entries are archived under a new
+    /// restart generation; otherwise nothing changes. Returns `true` when a
+    /// new generation was started.
+    pub fn observe_isolate(&mut self, isolate_id: &str) -> bool {
+        let restarted = match &self.isolate_id {
+            Some(current) => current != isolate_id,
+            None => false,
+        };
+        if restarted && self.persist_history {
+            self.begin_generation();
+        }
+        self.isolate_id = Some(isolate_id.to_string());
+        restarted && self.persist_history
+    }
+
+    /// Archive the current run's VM entries and advance the generation.
+    ///
+    /// VM request IDs restart with each isolate, so archived entries are
+    /// re-keyed by isolate + ID to keep the next run's requests from
+    /// overwriting them. Proxy entries have globally unique IDs and keep theirs.
+    fn begin_generation(&mut self) {
+        let scope = match &self.isolate_id {
+            Some(isolate) => isolate.clone(),
+            None => format!("run{}", self.generation),
+        };
+        for entry in self.entries.iter_mut() {
+            let current = self.entry_generations.get(&entry.id).copied();
+            if current != Some(self.generation) || self.proxy_details.contains_key(&entry.id) {
+                continue;
+            }
+            let archived_id = format!("{}/{}", scope, entry.id);
+            self.entry_generations.remove(&entry.id);
+            self.entry_generations
+                .insert(archived_id.clone(), self.generation);
+            if let Some(segments) = self.timing_segments.remove(&entry.id) {
+                self.timing_segments.insert(archived_id.clone(), segments);
+            }
+            if let Some(detail) = self.selected_detail.as_mut() {
+                if detail.entry.id == entry.id {
+                    detail.entry.id = archived_id.clone();
+                }
+            }
+            entry.id = archived_id;
+        }
+        self.generation += 1;
+        self.isolate_id = None;
+        self.last_poll_timestamp = None;
+    }
+
+    /// Restart generation an entry was captured in.
+    pub fn generation_of(&self, entry_id: &str) -> u32 {
+        self.entry_generations
+            .get(entry_id)
+            .copied()
+            .unwrap_or(self.generation)
+    }
+
+    /// Whether an entry was captured before the current run. Its details can
+    /// no longer be fetched from the VM.
+    pub fn is_from_previous_run(&self, entry_id: &str) -> bool {
+        self.generation_of(entry_id) < self.generation
+    }
+
     /// Returns `true` if `entry` matches the given lowercase filter string.
     ///
     /// Centralises the filter predicate used by both [`filtered_entries`] and
@@ -205,6 +378,9 @@ impl NetworkState {
     /// Clear all entries and reset poll timestamp.
     pub fn clear(&mut self) {
         self.entries.clear();
+        self.timing_segments.clear();
+        self.proxy_details.clear();
+        self.entry_generations.clear();
         self.selected_index = None;
         self.selected_detail = None;
         self.last_poll_timestamp = None;
@@ -243,6 +419,100 @@ impl NetworkState {
         let filtered = self.filtered_entries();
         self.selected_index.and_then(|i| filtered.get(i).copied())
     }
+
+    /// Store a fetched request detail and cache its waterfall phase segments.
+    pub fn set_selected_detail(&mut self, detail: Box<HttpProfileEntryDetail>) {
+        let segments = fdemon_core::network::timing_segments(&detail.events, &detail.entry);
+        if !segments.is_empty() {
+            self.timing_segments
+                .insert(detail.entry.id.clone(), segments);
+        }
+        self.selected_detail = Some(detail);
+    }
+
+    /// Record an exchange captured by the session's local HTTP proxy.
+    ///
+    /// The entry is merged like a polled VM entry and the full detail is kept
+    /// so selecting it needs no VM round-trip. Waterfall segments are cached
+    /// straight away, and the shown detail is refreshed if the entry is
+    /// currently selected.
+    pub fn merge_proxy_exchange(&mut self, detail: Box<HttpProfileEntryDetail>) {
+        let id = detail.entry.id.clone();
+        self.merge_entries(vec![detail.entry.clone()]);
+        if !self.entries.iter().any(|e| e.id == id) {
+            return;
+        }
+        let segments = fdemon_core::network::timing_segments(&detail.events, &detail.entry);
+        if !segments.is_empty() {
+            self.timing_segments.insert(id.clone(), segments);
+        }
+        if self.selected_entry().is_some_and(|e| e.id == id) {
+            self.selected_detail = Some(detail.clone());
+        }
+        self.proxy_details.insert(id, detail);
+    }
+
+    /// Whether a local HTTP proxy is feeding this session.
+    pub fn proxy_active(&self) -> bool {
+        self.proxy_address.is_some()
+    }
+
+    /// Switch between the table and waterfall presentations.
+    pub fn toggle_view_mode(&mut self) {
+        self.view_mode = match self.view_mode {
+            NetworkViewMode::Table => NetworkViewMode::Waterfall,
+            NetworkViewMode::Waterfall => NetworkViewMode::Table,
+        };
+    }
+
+    /// Zoom the waterfall in (halve the visible window), keeping the left edge
+    /// anchored at the same point in time.
+    pub fn waterfall_zoom_in(&mut self) {
+        if self.waterfall_zoom < MAX_WATERFALL_ZOOM {
+            self.waterfall_zoom += 1;
+            self.waterfall_pan *= 2;
+        }
+    }
+
+    /// Zoom the waterfall out (double the visible window).
+    pub fn waterfall_zoom_out(&mut self) {
+        if self.waterfall_zoom > 0 {
+            self.waterfall_zoom -= 1;
+            self.waterfall_pan = (self.waterfall_pan / 2).min(self.max_waterfall_pan());
+        }
+    }
+
+    /// Pan the waterfall window one step towards earlier requests.
+    pub fn waterfall_pan_left(&mut self) {
+        self.waterfall_pan = self.waterfall_pan.saturating_sub(1);
+    }
+
+    /// Pan the waterfall window one step towards later requests.
+    pub fn waterfall_pan_right(&mut self) {
+        self.waterfall_pan = (self.waterfall_pan + 1).min(self.max_waterfall_pan());
+    }
+
+    /// Reset the waterfall to show the whole captured span.
+    pub fn waterfall_reset(&mut self) {
+        self.waterfall_zoom = 0;
+        self.waterfall_pan = 0;
+    }
+
+    /// Largest pan position that keeps the window inside the captured span.
+    fn max_waterfall_pan(&self) -> u32 {
+        ((1u32 << self.waterfall_zoom) - 1) * WATERFALL_PAN_STEPS_PER_WINDOW
+    }
+
+    /// Visible `(start_us, end_us)` window of the waterfall for a captured
+    /// span of `span_start_us..span_end_us`, applying zoom and pan.
+    pub fn waterfall_window(&self, span_start_us: i64, span_end_us: i64) -> (i64, i64) {
+        let span = (span_end_us - span_start_us).max(1);
+        let window = (span >> self.waterfall_zoom).max(1);
+        let step = (window / WATERFALL_PAN_STEPS_PER_WINDOW as i64).max(1);
+        let start = (span_start_us + step * self.waterfall_pan as i64).min(span_end_us - window);
+        let start = start.max(span_start_us);
+        (start, start + window)
+    }
 }
 
 #[cfg(test)]
@@ -591,6 +861,81 @@ mod tests {
         );
     }
 
+    // ── Waterfall view tests ──────────────────────────────────────────────────
+
+    #[test]
+    fn test_toggle_view_mode() {
+        let mut state = NetworkState::default();
+        assert_eq!(state.view_mode, NetworkViewMode::Table);
+        state.toggle_view_mode();
+        assert_eq!(state.view_mode, NetworkViewMode::Waterfall);
+        state.toggle_view_mode();
+        assert_eq!(state.view_mode, NetworkViewMode::Table);
+    }
+
+    #[test]
+    fn test_waterfall_zoom_is_clamped() {
+        let mut state = NetworkState::default();
+        state.waterfall_zoom_out();
+        assert_eq!(state.waterfall_zoom, 0);
+        for _ in 0..20 {
+            state.waterfall_zoom_in();
+        }
+        assert_eq!(state.waterfall_zoom, MAX_WATERFALL_ZOOM);
+    }
+
+    #[test]
+    fn test_waterfall_pan_is_clamped_to_span() {
+        let mut state = NetworkState::default();
+        state.waterfall_pan_right();
+        assert_eq!(state.waterfall_pan, 0, "cannot pan when fully zoomed out");
+
+        state.waterfall_zoom_in(); // window = half the span
+        for _ in 0..20 {
+            state.waterfall_pan_right();
+        }
+        assert_eq!(state.waterfall_pan, WATERFALL_PAN_STEPS_PER_WINDOW);
+        assert_eq!(state.waterfall_window(0, 1_000), (500, 1_000));
+
+        state.waterfall_reset();
+        assert_eq!(state.waterfall_window(0, 1_000), (0, 1_000));
+    }
+
+    #[test]
+    fn test_waterfall_zoom_in_keeps_left_edge() {
+        let mut state = NetworkState::default();
+        state.waterfall_zoom_in();
+        state.waterfall_pan_right(); // left edge at 1/8 of span
+        let (before, _) = state.waterfall_window(0, 8_000);
+        state.waterfall_zoom_in();
+        let (after, end) = state.waterfall_window(0, 8_000);
+        assert_eq!(before, after);
+        assert_eq!(end - after, 2_000);
+    }
+
+    #[test]
+    fn test_set_selected_detail_caches_segments() {
+        let mut state = NetworkState::default();
+        let entry = make_entry("1", "GET", Some(200));
+        state.set_selected_detail(Box::new(HttpProfileEntryDetail {
+            entry,
+            request_headers: vec![],
+            response_headers: vec![],
+            request_body: vec![],
+            response_body: vec![],
+            events: vec![fdemon_core::network::HttpProfileEvent {
+                event: "response started".to_string(),
+                timestamp_us: 1_020_000,
+            }],
+            connection_info: None,
+        }));
+        assert!(state.selected_detail.is_some());
+        assert_eq!(state.timing_segments["1"].len(), 2);
+
+        state.clear();
+        assert!(state.timing_segments.is_empty());
+    }
+
     // ── NetworkDetailTab moved-location tests ─────────────────────────────────
 
     #[test]
@@ -622,4 +967,163 @@ mod tests {
             }
         }
     }
+
+    fn make_proxy_detail(id: &str, status: Option<u16>) -> Box<HttpProfileEntryDetail> {
+        Box::new(HttpProfileEntryDetail {
+            entry: make_entry(id, "GET", status),
+            request_headers: vec![],
+            response_headers: vec![],
+            request_body: vec![],
+            response_body: b"ok".to_vec(),
+            events: vec![fdemon_core::network::HttpProfileEvent {
+                event: "response started".to_string(),
+                timestamp_us: 1_020_000,
+            }],
+            connection_info: None,
+        })
+    }
+
+    #[test]
+    fn test_merge_proxy_exchange_stores_entry_and_detail() {
+        let mut state = NetworkState::default();
+        state.merge_proxy_exchange(make_proxy_detail("proxy-1", None));
+        state.merge_proxy_exchange(make_proxy_detail("proxy-1", Some(200)));
+        assert_eq!(state.entries.len(), 1);
+        assert_eq!(state.entries[0].status_code, Some(200));
+        assert!(state.proxy_details.contains_key("proxy-1"));
+        assert!(state.timing_segments.contains_key("proxy-1"));
+    }
+
+    #[test]
+    fn test_merge_proxy_exchange_refreshes_selected_detail() {
+        let mut state = NetworkState::default();
+        state.merge_proxy_exchange(make_proxy_detail("proxy-1", None));
+        state.selected_index = Some(0);
+        state.merge_proxy_exchange(make_proxy_detail("proxy-1", Some(201)));
+        let detail = state.selected_detail.as_ref().unwrap();
+        assert_eq!(detail.entry.status_code, Some(201));
+    }
+
+    #[test]
+    fn test_proxy_details_pruned_on_eviction_and_clear() {
+        let mut state = NetworkState::with_config(1, true);
+        state.merge_proxy_exchange(make_proxy_detail("proxy-1", Some(200)));
+        state.merge_proxy_exchange(make_proxy_detail("proxy-2", Some(200)));
+        assert!(!state.proxy_details.contains_key("proxy-1"));
+        assert!(state.proxy_details.contains_key("proxy-2"));
+        state.clear();
+        assert!(state.proxy_details.is_empty());
+    }
+
+    #[test]
+    fn test_reset_preserves_proxy_address() {
+        let mut state = NetworkState {
+            proxy_address: Some("127.0.0.1:9000".to_string()),
+            ..Default::default()
+        };
+        state.merge_proxy_exchange(make_proxy_detail("proxy-1", Some(200)));
+        state.reset();
+        assert!(state.proxy_active());
+        assert!(state.entries.is_empty());
+    }
+
+    #[test]
+    fn test_merge_tags_entries_with_generation() {
+        let mut state = NetworkState::default().with_history(true, false);
+        state.merge_entries(vec![make_entry("1", "GET", Some(200))]);
+        assert_eq!(state.generation_of("1"), 0);
+        state.observe_isolate("isolates/1");
+        assert!(state.observe_isolate("isolates/2"));
+        state.merge_entries(vec![make_entry("1", "GET", Some(200))]);
+        assert_eq!(state.generation_of("isolates/1/1"), 0);
+        assert_eq!(state.generation_of("1"), 1);
+    }
+
+    #[test]
+    fn test_observe_isolate_first_and_same_is_not_restart() {
+        let mut state = NetworkState::default().with_history(true, false);
+        assert!(!state.observe_isolate("isolates/1"));
+        assert!(!state.observe_isolate("isolates/1"));
+        assert_eq!(state.generation, 0);
+    }
+
+    #[test]
+    fn test_observe_isolate_without_persistence_keeps_generation() {
+        let mut state = NetworkState::default();
+        state.observe_isolate("isolates/1");
+        assert!(!state.observe_isolate("isolates/2"));
+        assert_eq!(state.generation, 0);
+        assert_eq!(state.isolate_id.as_deref(), Some("isolates/2"));
+    }
+
+    #[test]
+    fn test_restart_rekeys_selected_detail_and_segments() {
+        let mut state = NetworkState::default().with_history(true, false);
+        state.observe_isolate("isolates/1");
+        state.merge_entries(vec![make_entry("1", "GET", Some(200))]);
+        state.timing_segments.insert("1".to_string(), Vec::new());
+        state.selected_index = Some(0);
+        state.selected_detail = Some(make_proxy_detail("1", Some(200)));
+        state.observe_isolate("isolates/2");
+        assert!(state.timing_segments.contains_key("isolates/1/1"));
+        assert_eq!(
+            state.selected_detail.as_ref().unwrap().entry.id,
+            "isolates/1/1"
+        );
+        assert!(state.last_poll_timestamp.is_none());
+    }
+
+    #[test]
+    fn test_restart_keeps_proxy_entry_ids() {
+        let mut state = NetworkState::default().with_history(true, false);
+        state.observe_isolate("isolates/1");
+        state.merge_proxy_exchange(make_proxy_detail("proxy-1", Some(200)));
+        state.observe_isolate("isolates/2");
+        assert_eq!(state.entries[0].id, "proxy-1");
+        assert!(state.is_from_previous_run("proxy-1"));
+    }
+
+    #[test]
+    fn test_eviction_queues_spill_only_when_enabled() {
+        let mut state = NetworkState::with_config(1, true);
+        state.merge_entries(vec![make_entry("1", "GET", Some(200))]);
+        state.merge_entries(vec![make_entry("2", "GET", Some(200))]);
+        assert!(state.pending_spill.is_empty());
+
+        let mut state = NetworkState::with_config(1, true).with_history(false, true);
+        state.merge_entries(vec![make_entry("1", "GET", Some(200))]);
+        state.merge_entries(vec![make_entry("2", "GET", Some(200))]);
+        assert_eq!(state.pending_spill.len(), 1);
+        assert_eq!(state.pending_spill[0].1.id, "1");
+        assert!(!state.entry_generations.contains_key("1"));
+    }
+
+    #[test]
+    fn test_carry_over_starts_new_generation() {
+        let mut state = NetworkState::with_config(10, true).with_history(true, false);
+        state.observe_isolate("isolates/1");
+        state.merge_entries(vec![make_entry("1", "GET", Some(200))]);
+        state.selected_index = Some(0);
+        state.proxy_address = Some("127.0.0.1:9000".to_string());
+
+        let next = state.carry_over(5, false);
+        assert_eq!(next.generation, 1);
+        assert_eq!(next.entries.len(), 1);
+        assert_eq!(next.entries[0].id, "isolates/1/1");
+        assert!(next.is_from_previous_run("isolates/1/1"));
+        assert_eq!(next.max_entries, 5);
+        assert!(!next.recording);
+        assert!(next.persist_history);
+        assert!(next.selected_index.is_none());
+        assert!(next.isolate_id.is_none());
+        assert!(!next.proxy_active());
+    }
+
+    #[test]
+    fn test_reset_preserves_history_settings() {
+        let mut state = NetworkState::default().with_history(true, true);
+        state.reset();
+        assert!(state.persist_history);
+        assert!(state.spill_to_disk);
+    }
 }
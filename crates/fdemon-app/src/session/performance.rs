@@ -1,9 +1,13 @@
 //! Performance monitoring state — memory, GC, and frame timing.
 
+use std::collections::BTreeMap;
+
 use fdemon_core::performance::{
-    AllocationProfile, FrameTiming, GcEvent, MemorySample, MemoryUsage, PerformanceStats,
-    RingBuffer,
+    frame_budget_micros, AllocationProfile, FramePhases, FrameTiming, GcEvent, MemorySample,
+    MemoryUsage, PerformanceStats, RebuildEntry, RebuildStats, RingBuffer,
+    FRAME_BUDGET_60FPS_MICROS,
 };
+use fdemon_core::{HeapDiff, HeapInstance, InstanceDetails, LeakTracker, PerfComparison};
 
 /// Default number of memory snapshots to keep (at 2s interval = 2 minutes).
 pub(crate) const DEFAULT_MEMORY_HISTORY_SIZE: usize = 60;
@@ -15,6 +19,8 @@ pub(crate) const DEFAULT_MEMORY_HISTORY_SIZE: usize = 60;
 pub(crate) const DEFAULT_GC_HISTORY_SIZE: usize = 50;
 /// Default number of frame timings to keep.
 pub(crate) const DEFAULT_FRAME_HISTORY_SIZE: usize = 300;
+/// Phase breakdowns held for frames whose `Flutter.Frame` event has not arrived yet.
+const MAX_PENDING_FRAME_PHASES: usize = 32;
 /// Memory sample buffer size: 120 samples at 500ms polling = 60 seconds of history.
 pub(crate) const DEFAULT_MEMORY_SAMPLE_SIZE: usize = 120;
 
@@ -28,6 +34,64 @@ pub enum AllocationSortColumn {
     ByInstances,
 }
 
+/// Column by which the widget rebuild table is sorted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RebuildSortColumn {
+    /// Sort by rebuilds since tracking started (descending).
+    #[default]
+    ByTotal,
+    /// Sort by rebuilds in the most recent frame (descending).
+    ByLastFrame,
+}
+
+/// Maximum number of rows shown in the widget rebuild table.
+pub const MAX_REBUILD_ROWS: usize = 100;
+
+/// Maximum number of rows shown in the class allocation and heap growth tables.
+pub const MAX_ALLOCATION_ROWS: usize = 10;
+
+/// Live instances of one class, opened from the allocation table.
+#[derive(Debug, Clone, PartialEq)]
+pub struct InstanceBrowser {
+    /// Name of the browsed class.
+    pub class_name: String,
+    /// VM Service class ID passed to `getInstances`.
+    pub class_id: String,
+    /// Number of live instances, which may exceed `instances.len()`.
+    pub total_count: u64,
+    /// Instances returned by `getInstances`.
+    pub instances: Vec<HeapInstance>,
+    /// Index of the selected instance.
+    pub selected: usize,
+    /// Fields and retaining path of the selected instance, once inspected.
+    pub details: Option<InstanceDetails>,
+    /// Whether instances or details are being fetched.
+    pub loading: bool,
+    /// Error from the last fetch.
+    pub error: Option<String>,
+}
+
+impl InstanceBrowser {
+    /// Browser for `class_name`, waiting for its instances.
+    pub fn new(class_name: String, class_id: String) -> Self {
+        Self {
+            class_name,
+            class_id,
+            total_count: 0,
+            instances: Vec::new(),
+            selected: 0,
+            details: None,
+            loading: true,
+            error: None,
+        }
+    }
+
+    /// The selected instance, if any.
+    pub fn selected_instance(&self) -> Option<&HeapInstance> {
+        self.instances.get(self.selected)
+    }
+}
+
 /// Performance monitoring state for a session.
 ///
 /// Holds rolling ring-buffer history for memory snapshots, GC events, and
@@ -60,6 +124,10 @@ pub struct PerformanceState {
     /// the detail panel shows per-phase breakdown if available.
     pub selected_frame: Option<usize>,
 
+    /// Timeline phase breakdowns that arrived before their frame, keyed by
+    /// frame number. Attached when the matching frame is pushed.
+    pub pending_frame_phases: BTreeMap<u64, FramePhases>,
+
     /// Latest allocation profile snapshot from `getAllocationProfile`.
     ///
     /// `None` until the first profile is fetched or when monitoring is inactive.
@@ -68,6 +136,62 @@ pub struct PerformanceState {
 
     /// Column by which the class allocation table is sorted.
     pub allocation_sort: AllocationSortColumn,
+
+    /// Whether `Flutter.RebuiltWidgets` events are enabled on the device.
+    ///
+    /// While `true` the memory section is replaced by the rebuild table.
+    pub rebuild_tracking: bool,
+
+    /// Rebuild counts aggregated per widget creation location.
+    pub rebuild_stats: RebuildStats,
+
+    /// Column by which the widget rebuild table is sorted.
+    pub rebuild_sort: RebuildSortColumn,
+
+    /// Index of the selected row in the sorted rebuild table.
+    pub rebuild_selected: usize,
+
+    /// Result of the last comparison against a saved baseline.
+    pub baseline_comparison: Option<PerfComparison>,
+
+    /// Number of baseline comparisons requested but not yet reported.
+    /// Headless mode waits for these before quitting so the regression exit
+    /// code does not depend on message order.
+    pub baseline_compares_pending: usize,
+
+    /// Display refresh rate in Hz, from `devtools.refresh_rates` or the
+    /// engine. `None` until known, in which case 60 Hz is assumed.
+    pub display_refresh_rate: Option<f64>,
+
+    /// Frame budget derived from `display_refresh_rate`, used for jank
+    /// counts and the frame chart budget line.
+    pub frame_budget_micros: u64,
+
+    /// First allocation profile captured with `H` (after a forced GC).
+    /// Later captures are diffed against it.
+    pub diff_base: Option<AllocationProfile>,
+
+    /// Diff of the latest capture against `diff_base`. While set,
+    /// the growth table replaces the class allocation table.
+    pub heap_diff: Option<HeapDiff>,
+
+    /// Whether an allocation diff capture is in flight.
+    pub diff_pending: bool,
+
+    /// Index of the selected row in the allocation (or heap growth) table.
+    pub allocation_selected: usize,
+
+    /// Instance browser for a class picked from the allocation table. While
+    /// open it replaces the memory section.
+    pub instance_browser: Option<InstanceBrowser>,
+
+    /// Watched class counts at reload and route-change checkpoints, from
+    /// `devtools.leak_hints`. `None` while leak hints are disabled.
+    pub leak_tracker: Option<LeakTracker>,
+
+    /// Isolate picked in the DevTools header for memory, allocation and CPU
+    /// data. `None` for the main isolate.
+    pub isolate_id: Option<String>,
 }
 
 impl Default for PerformanceState {
@@ -80,8 +204,24 @@ impl Default for PerformanceState {
             monitoring_active: false,
             memory_samples: RingBuffer::new(DEFAULT_MEMORY_SAMPLE_SIZE),
             selected_frame: None,
+            pending_frame_phases: BTreeMap::new(),
             allocation_profile: None,
             allocation_sort: AllocationSortColumn::default(),
+            rebuild_tracking: false,
+            rebuild_stats: RebuildStats::default(),
+            rebuild_sort: RebuildSortColumn::default(),
+            rebuild_selected: 0,
+            baseline_comparison: None,
+            baseline_compares_pending: 0,
+            display_refresh_rate: None,
+            frame_budget_micros: FRAME_BUDGET_60FPS_MICROS,
+            diff_base: None,
+            heap_diff: None,
+            diff_pending: false,
+            allocation_selected: 0,
+            instance_browser: None,
+            leak_tracker: None,
+            isolate_id: None,
         }
     }
 }
@@ -105,8 +245,24 @@ impl PerformanceState {
             monitoring_active: false,
             memory_samples: RingBuffer::new(DEFAULT_MEMORY_SAMPLE_SIZE),
             selected_frame: None,
+            pending_frame_phases: BTreeMap::new(),
             allocation_profile: None,
             allocation_sort: AllocationSortColumn::default(),
+            rebuild_tracking: false,
+            rebuild_stats: RebuildStats::default(),
+            rebuild_sort: RebuildSortColumn::default(),
+            rebuild_selected: 0,
+            baseline_comparison: None,
+            baseline_compares_pending: 0,
+            display_refresh_rate: None,
+            frame_budget_micros: FRAME_BUDGET_60FPS_MICROS,
+            diff_base: None,
+            heap_diff: None,
+            diff_pending: false,
+            allocation_selected: 0,
+            instance_browser: None,
+            leak_tracker: None,
+            isolate_id: None,
         }
     }
 }
@@ -165,6 +321,39 @@ impl PerformanceState {
         self.selected_frame = None;
     }
 
+    /// Attach a timeline phase breakdown to frame `number`.
+    ///
+    /// If the frame is not in the history yet, the phases are held until
+    /// [`take_pending_frame_phases`](Self::take_pending_frame_phases) claims them.
+    pub fn apply_frame_phases(&mut self, number: u64, phases: FramePhases) {
+        if let Some(frame) = self
+            .frame_history
+            .iter_mut()
+            .rev()
+            .find(|f| f.number == number)
+        {
+            frame.phases = Some(phases);
+            return;
+        }
+        // Phases for frames older than the history are never claimed.
+        if self
+            .frame_history
+            .oldest()
+            .is_some_and(|oldest| number < oldest.number)
+        {
+            return;
+        }
+        self.pending_frame_phases.insert(number, phases);
+        while self.pending_frame_phases.len() > MAX_PENDING_FRAME_PHASES {
+            self.pending_frame_phases.pop_first();
+        }
+    }
+
+    /// Remove and return phases that arrived before frame `number`.
+    pub fn take_pending_frame_phases(&mut self, number: u64) -> Option<FramePhases> {
+        self.pending_frame_phases.remove(&number)
+    }
+
     /// Get the currently selected frame timing, if any.
     ///
     /// Returns `None` if no frame is selected or if the index is out of bounds.
@@ -172,6 +361,47 @@ impl PerformanceState {
         self.selected_frame
             .and_then(|i| self.frame_history.iter().nth(i))
     }
+
+    /// Class name and ID of each row of the allocation table as shown: the
+    /// heap growth table while a diff is shown, otherwise the latest
+    /// allocation profile, in the current sort order and capped at
+    /// [`MAX_ALLOCATION_ROWS`].
+    pub fn allocation_rows(&self) -> Vec<(&str, Option<&str>)> {
+        let by_size = self.allocation_sort == AllocationSortColumn::BySize;
+        if let Some(diff) = &self.heap_diff {
+            let rows = if by_size {
+                diff.top_by_size(MAX_ALLOCATION_ROWS)
+            } else {
+                diff.top_by_instances(MAX_ALLOCATION_ROWS)
+            };
+            return rows
+                .into_iter()
+                .map(|d| (d.class_name.as_str(), d.class_id.as_deref()))
+                .collect();
+        }
+        let Some(profile) = &self.allocation_profile else {
+            return Vec::new();
+        };
+        let rows = if by_size {
+            profile.top_by_size(MAX_ALLOCATION_ROWS)
+        } else {
+            profile.top_by_instances(MAX_ALLOCATION_ROWS)
+        };
+        rows.into_iter()
+            .map(|s| (s.class_name.as_str(), s.class_id.as_deref()))
+            .collect()
+    }
+
+    /// Rebuild table rows in the current sort order, capped at
+    /// [`MAX_REBUILD_ROWS`].
+    pub fn sorted_rebuilds(&self) -> Vec<RebuildEntry> {
+        match self.rebuild_sort {
+            RebuildSortColumn::ByTotal => self.rebuild_stats.top_by_total(MAX_REBUILD_ROWS),
+            RebuildSortColumn::ByLastFrame => {
+                self.rebuild_stats.top_by_last_frame(MAX_REBUILD_ROWS)
+            }
+        }
+    }
 }
 
 /// How often to recompute aggregated stats (every N frames).
@@ -191,13 +421,36 @@ impl PerformanceState {
     /// allocation overhead, and also from the memory-snapshot handler as a
     /// 2-second backstop.
     pub fn recompute_stats(&mut self) {
-        self.stats = Self::compute_stats(&self.frame_history);
+        self.stats = Self::compute_stats(&self.frame_history, self.frame_budget_micros);
+    }
+
+    /// Set the display refresh rate and recompute stats for its frame budget.
+    pub fn set_display_refresh_rate(&mut self, refresh_rate_hz: f64) {
+        self.display_refresh_rate = Some(refresh_rate_hz);
+        self.frame_budget_micros = frame_budget_micros(refresh_rate_hz);
+        self.recompute_stats();
+    }
+
+    /// Pick the isolate for memory and allocation data and drop the data
+    /// collected from the previous one. Frame timings and GC events are
+    /// kept: they are not per isolate.
+    pub fn set_isolate(&mut self, isolate_id: Option<String>) {
+        self.isolate_id = isolate_id;
+        self.memory_history.clear();
+        self.memory_samples.clear();
+        self.allocation_profile = None;
+        self.allocation_selected = 0;
+        self.diff_base = None;
+        self.heap_diff = None;
+        self.diff_pending = false;
+        self.instance_browser = None;
     }
 
     /// Compute performance statistics from frame history.
     ///
-    /// Returns [`PerformanceStats::default()`] when no frames are available.
-    pub fn compute_stats(frames: &RingBuffer<FrameTiming>) -> PerformanceStats {
+    /// Frames longer than `budget_micros` count as jank. Returns
+    /// [`PerformanceStats::default()`] when no frames are available.
+    pub fn compute_stats(frames: &RingBuffer<FrameTiming>, budget_micros: u64) -> PerformanceStats {
         if frames.is_empty() {
             return PerformanceStats::default();
         }
@@ -209,8 +462,11 @@ impl PerformanceState {
         // FPS: compute actual frames-per-second rate from recent frame timings
         let fps = Self::calculate_fps(frames);
 
-        // Jank count: frames exceeding 60fps budget
-        let jank_count = frames.iter().filter(|f| f.is_janky()).count() as u32;
+        // Jank count: frames exceeding the display's frame budget
+        let jank_count = frames
+            .iter()
+            .filter(|f| f.is_janky_at(budget_micros))
+            .count() as u32;
 
         // Average frame time (frame_times is non-empty because frames.is_empty() returned above)
         let avg_frame_ms = Some(frame_times.iter().sum::<f64>() / frame_times.len() as f64);
@@ -562,4 +818,55 @@ mod tests {
         let state = PerformanceState::default();
         assert!(state.allocation_profile.is_none());
     }
+
+    // ── Frame phases ────────────────────────────────────────────────────────
+
+    fn test_phases() -> FramePhases {
+        FramePhases {
+            build_micros: 3_000,
+            layout_micros: 2_000,
+            paint_micros: 1_000,
+            raster_micros: 4_000,
+            shader_compilation: false,
+        }
+    }
+
+    #[test]
+    fn test_apply_frame_phases_to_existing_frame() {
+        let mut state = PerformanceState::default();
+        push_test_frames(&mut state, 5);
+        state.apply_frame_phases(3, test_phases());
+        let frame = state.frame_history.iter().nth(2).unwrap();
+        assert_eq!(frame.phases, Some(test_phases()));
+        assert!(state.pending_frame_phases.is_empty());
+    }
+
+    #[test]
+    fn test_apply_frame_phases_before_frame_is_held() {
+        let mut state = PerformanceState::default();
+        push_test_frames(&mut state, 5);
+        state.apply_frame_phases(6, test_phases());
+        assert_eq!(state.take_pending_frame_phases(6), Some(test_phases()));
+        assert_eq!(state.take_pending_frame_phases(6), None);
+    }
+
+    #[test]
+    fn test_apply_frame_phases_for_evicted_frame_is_dropped() {
+        let mut state = PerformanceState::default();
+        push_test_frames(&mut state, 5);
+        state.frame_history.clear();
+        push_test_frames(&mut state, 5);
+        state.apply_frame_phases(0, test_phases());
+        assert!(state.pending_frame_phases.is_empty());
+    }
+
+    #[test]
+    fn test_pending_frame_phases_are_bounded() {
+        let mut state = PerformanceState::default();
+        for n in 0..(MAX_PENDING_FRAME_PHASES as u64 + 5) {
+            state.apply_frame_phases(n, test_phases());
+        }
+        assert_eq!(state.pending_frame_phases.len(), MAX_PENDING_FRAME_PHASES);
+        assert!(!state.pending_frame_phases.contains_key(&0));
+    }
 }
@@ -5,7 +5,7 @@ use std::time::{Duration, Instant};
 
 use chrono::{DateTime, Local};
 
-use crate::config::LaunchConfig;
+use crate::config::{FlutterMode, LaunchConfig};
 use crate::handler::helpers::{detect_raw_line_level, is_block_end, is_block_start};
 use crate::hyperlinks::LinkHighlightState;
 use crate::log_view_state::LogViewState;
@@ -92,6 +92,9 @@ pub struct Session {
     /// Launch configuration used
     pub launch_config: Option<LaunchConfig>,
 
+    /// Whether the session is marked for group actions (`m`)
+    pub in_group: bool,
+
     // ─────────────────────────────────────────────────────────
     // Timing
     // ─────────────────────────────────────────────────────────
@@ -167,6 +170,7 @@ impl Session {
             ws_uri: None,
             vm_connected: false,
             launch_config: None,
+            in_group: false,
             created_at: Local::now(),
             started_at: None,
             reload_start_time: None,
@@ -187,6 +191,20 @@ impl Session {
         self
     }
 
+    /// Whether this session attached to an app started by another tool
+    /// (`attach = true` in its launch configuration).
+    pub fn is_attached(&self) -> bool {
+        self.launch_config.as_ref().is_some_and(|c| c.attach)
+    }
+
+    /// Build mode the app was launched in (debug without a launch
+    /// configuration).
+    pub fn build_mode(&self) -> FlutterMode {
+        self.launch_config
+            .as_ref()
+            .map_or(FlutterMode::Debug, |c| c.mode)
+    }
+
     /// Apply network configuration from DevTools settings.
     ///
     /// Sets `max_entries` and initial `recording` state on the session's
@@ -197,6 +215,14 @@ impl Session {
         self
     }
 
+    /// Apply network history settings (persistence across restarts and
+    /// spilling evicted entries to disk). Call after [`Self::with_network_config`].
+    pub fn with_network_history(mut self, persist_history: bool, spill_to_disk: bool) -> Self {
+        self.network =
+            std::mem::take(&mut self.network).with_history(persist_history, spill_to_disk);
+        self
+    }
+
     /// Add a log entry
     ///
     /// Automatically detects Logger package blocks (from ┌ to └) and propagates
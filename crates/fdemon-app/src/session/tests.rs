@@ -15,7 +15,7 @@ mod tests {
     use crate::session::log_batcher::{BATCH_FLUSH_INTERVAL, BATCH_MAX_SIZE};
     use crate::session::performance::DEFAULT_FRAME_HISTORY_SIZE;
 
-    use fdemon_core::performance::{FrameTiming, RingBuffer};
+    use fdemon_core::performance::{FrameTiming, RingBuffer, FRAME_BUDGET_60FPS_MICROS};
 
     #[test]
     fn test_session_creation() {
@@ -1633,7 +1633,8 @@ mod tests {
 
     #[test]
     fn test_stats_computation_empty() {
-        let stats = PerformanceState::compute_stats(&RingBuffer::new(10));
+        let stats =
+            PerformanceState::compute_stats(&RingBuffer::new(10), FRAME_BUDGET_60FPS_MICROS);
         assert!(stats.fps.is_none());
         assert!(stats.avg_frame_ms.is_none());
         assert_eq!(stats.jank_count, 0);
@@ -1650,7 +1651,7 @@ mod tests {
             frames.push(make_frame(i, 25_000)); // 25ms = janky
         }
 
-        let stats = PerformanceState::compute_stats(&frames);
+        let stats = PerformanceState::compute_stats(&frames, FRAME_BUDGET_60FPS_MICROS);
         assert_eq!(stats.jank_count, 5);
         assert_eq!(stats.buffered_frames, 65);
         // Average: (60*10 + 5*25) / 65 ≈ 11.15ms
@@ -1716,6 +1717,20 @@ mod tests {
         assert!(perf.stats.avg_frame_ms.is_some());
     }
 
+    #[test]
+    fn test_set_display_refresh_rate_recomputes_jank() {
+        let mut perf = PerformanceState::default();
+        for i in 0..5 {
+            perf.frame_history.push(make_frame(i, 10_000)); // smooth at 60 Hz
+        }
+        perf.recompute_stats();
+        assert_eq!(perf.stats.jank_count, 0);
+
+        perf.set_display_refresh_rate(120.0);
+        assert_eq!(perf.frame_budget_micros, 8_333);
+        assert_eq!(perf.stats.jank_count, 5, "10ms misses the 120 Hz budget");
+    }
+
     #[test]
     fn test_performance_stats_is_stale() {
         use fdemon_core::performance::PerformanceStats;
@@ -1768,7 +1783,7 @@ mod tests {
         for i in 0..5 {
             frames.push(make_frame(i, 10_000)); // 10ms each
         }
-        let stats = PerformanceState::compute_stats(&frames);
+        let stats = PerformanceState::compute_stats(&frames, FRAME_BUDGET_60FPS_MICROS);
         assert_eq!(stats.buffered_frames, 5);
         assert!(stats.avg_frame_ms.is_some());
         let avg = stats.avg_frame_ms.unwrap();
@@ -1784,7 +1799,7 @@ mod tests {
         for i in 0..10 {
             frames.push(make_frame(i, 8_000));
         }
-        let stats = PerformanceState::compute_stats(&frames);
+        let stats = PerformanceState::compute_stats(&frames, FRAME_BUDGET_60FPS_MICROS);
         assert_eq!(stats.buffered_frames, 10);
 
         // Push 300+ frames — buffer is capped at DEFAULT_FRAME_HISTORY_SIZE (300)
@@ -1792,7 +1807,7 @@ mod tests {
         for i in 0..(DEFAULT_FRAME_HISTORY_SIZE + 50) {
             frames_full.push(make_frame(i as u64, 8_000));
         }
-        let stats_full = PerformanceState::compute_stats(&frames_full);
+        let stats_full = PerformanceState::compute_stats(&frames_full, FRAME_BUDGET_60FPS_MICROS);
         assert_eq!(
             stats_full.buffered_frames, DEFAULT_FRAME_HISTORY_SIZE as u64,
             "buffered_frames should be capped at ring buffer capacity"
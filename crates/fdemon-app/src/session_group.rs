@@ -0,0 +1,177 @@
+//! Session group operations - reload, restart or stop several sessions at once.
+//!
+//! Sessions marked with `m` form the group; with no marks, the group is every
+//! session. A [`GroupRun`] collects the per-session outcomes of one group
+//! operation (or of a watcher auto-reload across sessions) and produces the
+//! aggregated result line once every session has reported.
+
+use std::time::{Duration, Instant};
+
+use crate::session::SessionId;
+
+/// Operation applied to every session of the group.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GroupOp {
+    Reload,
+    Restart,
+    Stop,
+}
+
+impl GroupOp {
+    /// Label used in log lines ("Reload", "Restart", "Stop").
+    pub fn label(self) -> &'static str {
+        match self {
+            GroupOp::Reload => "Reload",
+            GroupOp::Restart => "Restart",
+            GroupOp::Stop => "Stop",
+        }
+    }
+}
+
+/// One session taking part in a [`GroupRun`].
+#[derive(Debug, Clone)]
+struct GroupMember {
+    session_id: SessionId,
+    device_name: String,
+    /// `None` while pending; elapsed time or failure reason once reported.
+    outcome: Option<Result<Duration, String>>,
+}
+
+/// Outcomes of a group operation in flight.
+#[derive(Debug, Clone)]
+pub struct GroupRun {
+    pub op: GroupOp,
+    started: Instant,
+    members: Vec<GroupMember>,
+}
+
+impl GroupRun {
+    /// Start tracking `op` for `sessions` (session ID and device name).
+    pub fn new(op: GroupOp, sessions: Vec<(SessionId, String)>) -> Self {
+        Self {
+            op,
+            started: Instant::now(),
+            members: sessions
+                .into_iter()
+                .map(|(session_id, device_name)| GroupMember {
+                    session_id,
+                    device_name,
+                    outcome: None,
+                })
+                .collect(),
+        }
+    }
+
+    /// IDs of the sessions taking part, in group order.
+    pub fn session_ids(&self) -> Vec<SessionId> {
+        self.members.iter().map(|m| m.session_id).collect()
+    }
+
+    /// Record the outcome of `op` for a session, timed from the start of the
+    /// run. Outcomes for other operations or sessions, and repeated outcomes,
+    /// are ignored. Returns `true` if the outcome was recorded.
+    pub fn record(
+        &mut self,
+        session_id: SessionId,
+        op: GroupOp,
+        outcome: Result<(), String>,
+    ) -> bool {
+        if op != self.op {
+            return false;
+        }
+        let elapsed = self.started.elapsed();
+        match self
+            .members
+            .iter_mut()
+            .find(|m| m.session_id == session_id && m.outcome.is_none())
+        {
+            Some(member) => {
+                member.outcome = Some(outcome.map(|()| elapsed));
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Whether every session has reported.
+    pub fn is_complete(&self) -> bool {
+        self.members.iter().all(|m| m.outcome.is_some())
+    }
+
+    /// Whether every reported session succeeded.
+    pub fn all_ok(&self) -> bool {
+        self.members
+            .iter()
+            .all(|m| !matches!(m.outcome, Some(Err(_))))
+    }
+
+    /// Aggregated result line, e.g.
+    /// `Reload 2/3 ok: Pixel 7 312ms · iPad 455ms · Chrome failed (Compile error)`.
+    pub fn summary(&self) -> String {
+        let ok = self
+            .members
+            .iter()
+            .filter(|m| matches!(m.outcome, Some(Ok(_))))
+            .count();
+        let parts: Vec<String> = self
+            .members
+            .iter()
+            .map(|m| match &m.outcome {
+                Some(Ok(elapsed)) => format!("{} {}ms", m.device_name, elapsed.as_millis()),
+                Some(Err(reason)) => format!("{} failed ({})", m.device_name, reason),
+                None => format!("{} pending", m.device_name),
+            })
+            .collect();
+        format!(
+            "{} {}/{} ok: {}",
+            self.op.label(),
+            ok,
+            self.members.len(),
+            parts.join(" · ")
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_group_run_records_outcomes_and_summarizes() {
+        let mut run = GroupRun::new(
+            GroupOp::Reload,
+            vec![
+                (1, "Pixel 7".to_string()),
+                (2, "iPad".to_string()),
+                (3, "Chrome".to_string()),
+            ],
+        );
+        assert_eq!(run.session_ids(), vec![1, 2, 3]);
+
+        assert!(run.record(2, GroupOp::Reload, Ok(())));
+        // Other operations, unknown sessions and repeats are ignored.
+        assert!(!run.record(1, GroupOp::Restart, Ok(())));
+        assert!(!run.record(9, GroupOp::Reload, Ok(())));
+        assert!(!run.record(2, GroupOp::Reload, Err("late".to_string())));
+        assert!(!run.is_complete());
+        assert!(run.summary().contains("Pixel 7 pending"));
+
+        assert!(run.record(1, GroupOp::Reload, Ok(())));
+        assert!(run.all_ok());
+        assert!(run.record(3, GroupOp::Reload, Err("Compile error".to_string())));
+        assert!(run.is_complete());
+        assert!(!run.all_ok());
+
+        let summary = run.summary();
+        assert!(summary.starts_with("Reload 2/3 ok: Pixel 7 "), "{summary}");
+        assert!(summary.contains("ms · iPad "));
+        assert!(summary.ends_with(" · Chrome failed (Compile error)"));
+    }
+
+    #[test]
+    fn test_group_op_labels() {
+        assert_eq!(GroupOp::Reload.label(), "Reload");
+        assert_eq!(GroupOp::Restart.label(), "Restart");
+        assert_eq!(GroupOp::Stop.label(), "Stop");
+    }
+}
@@ -6,9 +6,9 @@ use crate::config::{DevToolsSettings, LaunchConfig};
 use fdemon_core::{prelude::*, AppPhase};
 use fdemon_daemon::{Device, FlutterProcess};
 
-use super::session::{Session, SessionHandle, SessionId};
+use super::session::{NetworkState, Session, SessionHandle, SessionId};
 
-/// Maximum number of concurrent sessions
+/// Default maximum number of concurrent sessions (`[behavior] max_sessions`)
 pub const MAX_SESSIONS: usize = 9;
 
 /// Manages multiple Flutter app sessions
@@ -22,6 +22,14 @@ pub struct SessionManager {
 
     /// Currently selected/focused session
     selected_index: usize,
+
+    /// Maximum number of concurrent sessions
+    max_sessions: usize,
+
+    /// Network history of closed sessions by device ID, oldest first,
+    /// restored when a session is relaunched on the same device
+    /// (`network_persist_history`). Holds at most `max_sessions` devices.
+    retained_network: Vec<(String, NetworkState)>,
 }
 
 impl Default for SessionManager {
@@ -37,9 +45,22 @@ impl SessionManager {
             sessions: HashMap::new(),
             session_order: Vec::new(),
             selected_index: 0,
+            max_sessions: MAX_SESSIONS,
+            retained_network: Vec::new(),
         }
     }
 
+    /// Maximum number of concurrent sessions
+    pub fn max_sessions(&self) -> usize {
+        self.max_sessions
+    }
+
+    /// Set the maximum number of concurrent sessions (at least 1). Existing
+    /// sessions beyond the limit are kept; the limit applies to new ones.
+    pub fn set_max_sessions(&mut self, max_sessions: usize) {
+        self.max_sessions = max_sessions.max(1);
+    }
+
     /// Create a new session for a device
     pub fn create_session(&mut self, device: &Device) -> Result<SessionId> {
         self.ensure_capacity()?;
@@ -112,7 +133,12 @@ impl SessionManager {
             device.platform.clone(),
             device.emulator,
         )
-        .with_network_config(devtools.max_network_entries, devtools.network_auto_record);
+        .with_network_config(devtools.max_network_entries, devtools.network_auto_record)
+        .with_network_history(
+            devtools.network_persist_history,
+            devtools.network_spill_to_disk,
+        );
+        let session = self.restore_network_history(session, devtools);
 
         let id = session.id;
         let handle = SessionHandle::new(session);
@@ -143,7 +169,12 @@ impl SessionManager {
             device.emulator,
         )
         .with_config(config)
-        .with_network_config(devtools.max_network_entries, devtools.network_auto_record);
+        .with_network_config(devtools.max_network_entries, devtools.network_auto_record)
+        .with_network_history(
+            devtools.network_persist_history,
+            devtools.network_spill_to_disk,
+        );
+        let session = self.restore_network_history(session, devtools);
 
         let id = session.id;
         let handle = SessionHandle::new(session);
@@ -182,10 +213,10 @@ impl SessionManager {
 
     /// Ensure there is room for a new session, evicting the oldest stopped session if needed.
     fn ensure_capacity(&mut self) -> Result<()> {
-        if self.sessions.len() >= MAX_SESSIONS && !self.evict_oldest_stopped() {
+        if self.sessions.len() >= self.max_sessions && !self.evict_oldest_stopped() {
             return Err(Error::config(format!(
                 "Maximum of {} concurrent sessions reached",
-                MAX_SESSIONS
+                self.max_sessions
             )));
         }
         Ok(())
@@ -202,7 +233,43 @@ impl SessionManager {
             }
         }
 
-        self.sessions.remove(&session_id)
+        let mut handle = self.sessions.remove(&session_id)?;
+        if handle.session.network.persist_history {
+            let device_id = handle.session.device_id.clone();
+            self.retained_network.retain(|(id, _)| *id != device_id);
+            self.retained_network
+                .push((device_id, std::mem::take(&mut handle.session.network)));
+            if self.retained_network.len() > self.max_sessions {
+                self.retained_network.remove(0);
+            }
+        }
+        Some(handle)
+    }
+
+    /// Carry over the retained network history for the session's device, if
+    /// history persistence is enabled.
+    fn restore_network_history(
+        &mut self,
+        mut session: Session,
+        devtools: &DevToolsSettings,
+    ) -> Session {
+        if !devtools.network_persist_history {
+            return session;
+        }
+        let retained = self
+            .retained_network
+            .iter()
+            .position(|(id, _)| *id == session.device_id);
+        if let Some(index) = retained {
+            let (_, retained) = self.retained_network.remove(index);
+            session.network = retained
+                .with_history(
+                    devtools.network_persist_history,
+                    devtools.network_spill_to_disk,
+                )
+                .carry_over(devtools.max_network_entries, devtools.network_auto_record);
+        }
+        session
     }
 
     /// Get a session by ID
@@ -382,11 +449,29 @@ impl SessionManager {
             .collect()
     }
 
+    /// Sessions targeted by group actions: the sessions marked with `m`, or
+    /// every session when none is marked
+    pub fn group_sessions(&self) -> Vec<&SessionHandle> {
+        let marked: Vec<&SessionHandle> = self.iter().filter(|h| h.session.in_group).collect();
+        if marked.is_empty() {
+            self.iter().collect()
+        } else {
+            marked
+        }
+    }
+
     /// Check if any session is busy (reloading)
     pub fn any_session_busy(&self) -> bool {
         self.sessions.values().any(|h| h.session.is_busy())
     }
 
+    /// Check if any session has a baseline comparison in flight
+    pub fn any_baseline_compare_pending(&self) -> bool {
+        self.sessions
+            .values()
+            .any(|h| h.session.performance.baseline_compares_pending > 0)
+    }
+
     /// Attach a Flutter process to a session
     pub fn attach_process(&mut self, session_id: SessionId, process: FlutterProcess) -> bool {
         if let Some(handle) = self.sessions.get_mut(&session_id) {
@@ -863,6 +948,27 @@ mod tests {
             .is_err());
     }
 
+    #[test]
+    fn test_configured_session_limit() {
+        let mut manager = SessionManager::new();
+        manager.set_max_sessions(12);
+        assert_eq!(manager.max_sessions(), 12);
+        for i in 0..12 {
+            let id = manager
+                .create_session(&test_device(&format!("dev-{i}"), &format!("D{i}")))
+                .unwrap();
+            manager.get_mut(id).unwrap().session.phase = AppPhase::Running;
+        }
+        assert_eq!(manager.len(), 12);
+        assert!(manager
+            .create_session(&test_device("dev-new", "New"))
+            .is_err());
+
+        // The limit is at least one session
+        manager.set_max_sessions(0);
+        assert_eq!(manager.max_sessions(), 1);
+    }
+
     #[test]
     fn test_eviction_selects_oldest_stopped_first() {
         let mut manager = SessionManager::new();
@@ -918,4 +1024,88 @@ mod tests {
         // selected_index must be within bounds
         assert!(manager.selected_index() < manager.len());
     }
+
+    #[test]
+    fn test_network_history_carried_over_on_relaunch() {
+        let mut manager = SessionManager::new();
+        let devtools = DevToolsSettings {
+            network_persist_history: true,
+            ..Default::default()
+        };
+        let device = test_device("d1", "D1");
+        let id = manager
+            .create_session_configured(&device, &devtools)
+            .unwrap();
+        manager
+            .get_mut(id)
+            .unwrap()
+            .session
+            .network
+            .merge_entries(vec![fdemon_core::network::HttpProfileEntry {
+                id: "1".to_string(),
+                method: "GET".to_string(),
+                uri: "https://example.com/login".to_string(),
+                status_code: Some(200),
+                content_type: None,
+                start_time_us: 0,
+                end_time_us: Some(1),
+                request_content_length: None,
+                response_content_length: None,
+                error: None,
+            }]);
+        manager.remove_session(id);
+
+        let other = manager
+            .create_session_configured(&test_device("d2", "D2"), &devtools)
+            .unwrap();
+        assert!(manager
+            .get(other)
+            .unwrap()
+            .session
+            .network
+            .entries
+            .is_empty());
+
+        let relaunched = manager
+            .create_session_configured(&device, &devtools)
+            .unwrap();
+        let network = &manager.get(relaunched).unwrap().session.network;
+        assert_eq!(network.entries.len(), 1);
+        assert_eq!(network.generation, 1);
+        assert!(network.persist_history);
+    }
+
+    #[test]
+    fn test_retained_network_history_capped_at_max_sessions() {
+        let mut manager = SessionManager::new();
+        manager.set_max_sessions(2);
+        let devtools = DevToolsSettings {
+            network_persist_history: true,
+            ..Default::default()
+        };
+        for device_id in ["d1", "d2", "d3"] {
+            let id = manager
+                .create_session_configured(&test_device(device_id, device_id), &devtools)
+                .unwrap();
+            manager.remove_session(id);
+        }
+        let retained: Vec<&str> = manager
+            .retained_network
+            .iter()
+            .map(|(id, _)| id.as_str())
+            .collect();
+        assert_eq!(retained, ["d2", "d3"]);
+    }
+
+    #[test]
+    fn test_network_history_not_retained_by_default() {
+        let mut manager = SessionManager::new();
+        let devtools = DevToolsSettings::default();
+        let device = test_device("d1", "D1");
+        let id = manager
+            .create_session_configured(&device, &devtools)
+            .unwrap();
+        manager.remove_session(id);
+        assert!(manager.retained_network.is_empty());
+    }
 }
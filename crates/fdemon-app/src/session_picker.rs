@@ -0,0 +1,204 @@
+//! Session picker state - fuzzy-searchable list of every session.
+//!
+//! Keys `1`-`9` only reach the first nine tabs. The picker (`S`) lists all
+//! sessions by tab number, device name, platform and launch config, filtered
+//! with the same fuzzy matcher as the new session dialog. This module tracks
+//! the entries, query and selection; it is shared by the app handler layer and
+//! the TUI picker overlay.
+
+use crate::new_session_dialog::fuzzy::fuzzy_filter;
+use crate::session::SessionId;
+use crate::session_manager::SessionManager;
+
+/// One session in the picker.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SessionPickerEntry {
+    pub session_id: SessionId,
+    /// 1-based tab number.
+    pub number: usize,
+    /// `12 Pixel 7 · android · Development`, matched against the query.
+    pub label: String,
+}
+
+/// State of the session picker overlay.
+#[derive(Debug, Clone, Default)]
+pub struct SessionPickerState {
+    /// All sessions in tab order, captured when the picker opens.
+    pub entries: Vec<SessionPickerEntry>,
+
+    /// Search query.
+    pub query: String,
+
+    /// Indices into [`Self::entries`] matching the query, best match first.
+    pub filtered: Vec<usize>,
+
+    /// Index of the highlighted row in [`Self::filtered`].
+    pub selected: usize,
+}
+
+impl SessionPickerState {
+    /// Capture the sessions of `session_manager`, highlighting the selected
+    /// session.
+    pub fn new(session_manager: &SessionManager) -> Self {
+        let entries: Vec<SessionPickerEntry> = session_manager
+            .iter()
+            .enumerate()
+            .map(|(index, handle)| {
+                let session = &handle.session;
+                let mut label = format!(
+                    "{} {} · {}",
+                    index + 1,
+                    session.device_name,
+                    session.platform
+                );
+                if let Some(config) = &session.launch_config {
+                    label.push_str(" · ");
+                    label.push_str(&config.name);
+                }
+                SessionPickerEntry {
+                    session_id: session.id,
+                    number: index + 1,
+                    label,
+                }
+            })
+            .collect();
+        Self {
+            filtered: (0..entries.len()).collect(),
+            selected: session_manager
+                .selected_index()
+                .min(entries.len().saturating_sub(1)),
+            entries,
+            query: String::new(),
+        }
+    }
+
+    /// Entries matching the query, best match first.
+    pub fn visible(&self) -> impl Iterator<Item = &SessionPickerEntry> {
+        self.filtered.iter().filter_map(|&i| self.entries.get(i))
+    }
+
+    /// The highlighted session, if any entry matches.
+    pub fn selected_session(&self) -> Option<SessionId> {
+        self.filtered
+            .get(self.selected)
+            .and_then(|&i| self.entries.get(i))
+            .map(|e| e.session_id)
+    }
+
+    /// Add a character to the query.
+    pub fn input_char(&mut self, c: char) {
+        self.query.push(c);
+        self.refilter();
+    }
+
+    /// Remove the last character from the query.
+    pub fn backspace(&mut self) {
+        self.query.pop();
+        self.refilter();
+    }
+
+    /// Clear the query.
+    pub fn clear_query(&mut self) {
+        self.query.clear();
+        self.refilter();
+    }
+
+    /// Move the highlight up, wrapping to the bottom.
+    pub fn move_up(&mut self) {
+        if !self.filtered.is_empty() {
+            self.selected = self
+                .selected
+                .checked_sub(1)
+                .unwrap_or(self.filtered.len() - 1);
+        }
+    }
+
+    /// Move the highlight down, wrapping to the top.
+    pub fn move_down(&mut self) {
+        if !self.filtered.is_empty() {
+            self.selected = (self.selected + 1) % self.filtered.len();
+        }
+    }
+
+    fn refilter(&mut self) {
+        let labels: Vec<String> = self.entries.iter().map(|e| e.label.clone()).collect();
+        self.filtered = fuzzy_filter(&self.query, &labels);
+        self.selected = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn manager_with_devices(names: &[&str]) -> SessionManager {
+        let mut manager = SessionManager::new();
+        manager.set_max_sessions(names.len());
+        for (i, name) in names.iter().enumerate() {
+            let device = fdemon_daemon::Device {
+                id: format!("dev-{i}"),
+                name: name.to_string(),
+                platform: "android".to_string(),
+                emulator: false,
+                category: None,
+                platform_type: None,
+                ephemeral: false,
+                emulator_id: None,
+            };
+            manager.create_session(&device).unwrap();
+        }
+        manager
+    }
+
+    #[test]
+    fn test_new_lists_sessions_and_highlights_selected() {
+        let mut manager = manager_with_devices(&["Pixel 7", "Galaxy S23", "Moto G"]);
+        manager.select_by_index(1);
+
+        let picker = SessionPickerState::new(&manager);
+
+        assert_eq!(picker.entries.len(), 3);
+        assert_eq!(picker.entries[2].number, 3);
+        assert_eq!(picker.entries[2].label, "3 Moto G · android");
+        assert_eq!(picker.selected, 1);
+        assert_eq!(
+            picker.selected_session(),
+            Some(picker.entries[1].session_id)
+        );
+    }
+
+    #[test]
+    fn test_query_filters_and_resets_highlight() {
+        let names: Vec<String> = (1..=12).map(|i| format!("Lab phone {i:02}")).collect();
+        let names: Vec<&str> = names.iter().map(String::as_str).collect();
+        let manager = manager_with_devices(&names);
+        let mut picker = SessionPickerState::new(&manager);
+
+        for c in "phone 11".chars() {
+            picker.input_char(c);
+        }
+        assert_eq!(picker.selected, 0);
+        let first = picker.visible().next().unwrap();
+        assert_eq!(first.number, 11);
+
+        picker.clear_query();
+        assert_eq!(picker.visible().count(), 12);
+
+        picker.input_char('z');
+        assert_eq!(picker.visible().count(), 0);
+        assert_eq!(picker.selected_session(), None);
+        picker.backspace();
+        assert_eq!(picker.visible().count(), 12);
+    }
+
+    #[test]
+    fn test_navigation_wraps() {
+        let manager = manager_with_devices(&["A", "B"]);
+        let mut picker = SessionPickerState::new(&manager);
+
+        picker.move_up();
+        assert_eq!(picker.selected, 1);
+        picker.move_down();
+        assert_eq!(picker.selected, 0);
+    }
+}
@@ -93,6 +93,21 @@ pub fn project_settings_items(settings: &Settings) -> Vec<SettingItem> {
             .value(SettingValue::Bool(settings.behavior.auto_launch))
             .default(SettingValue::Bool(false))
             .section("Behavior"),
+        SettingItem::new("behavior.restore_workspace", "Restore Workspace")
+            .description("Save sessions, filters and DevTools panel on quit and relaunch them on next start")
+            .value(SettingValue::Bool(settings.behavior.restore_workspace))
+            .default(SettingValue::Bool(false))
+            .section("Behavior"),
+        SettingItem::new("behavior.screenshot_dir", "Screenshot Directory")
+            .description("Where screenshots are saved (relative to the project)")
+            .value(SettingValue::String(settings.behavior.screenshot_dir.clone()))
+            .default(SettingValue::String(".fdemon/screenshots".to_string()))
+            .section("Behavior"),
+        SettingItem::new("behavior.max_sessions", "Max Sessions")
+            .description("Maximum concurrent sessions (takes effect on next fdemon launch)")
+            .value(SettingValue::Number(settings.behavior.max_sessions as i64))
+            .default(SettingValue::Number(9))
+            .section("Behavior"),
         // ─────────────────────────────────────────────────────────
         // Watcher Section
         // ─────────────────────────────────────────────────────────
@@ -199,6 +214,10 @@ pub fn project_settings_items(settings: &Settings) -> Vec<SettingItem> {
                     "inspector".to_string(),
                     "performance".to_string(),
                     "network".to_string(),
+                    "dumps".to_string(),
+                    "profiler".to_string(),
+                    "console".to_string(),
+                    "extensions".to_string(),
                 ],
             })
             .default(SettingValue::Enum {
@@ -207,6 +226,10 @@ pub fn project_settings_items(settings: &Settings) -> Vec<SettingItem> {
                     "inspector".to_string(),
                     "performance".to_string(),
                     "network".to_string(),
+                    "dumps".to_string(),
+                    "profiler".to_string(),
+                    "console".to_string(),
+                    "extensions".to_string(),
                 ],
             })
             .section("DevTools"),
@@ -538,6 +561,10 @@ mod tests {
             assert!(options.contains(&"inspector".to_string()));
             assert!(options.contains(&"performance".to_string()));
             assert!(options.contains(&"network".to_string()));
+            assert!(options.contains(&"dumps".to_string()));
+            assert!(options.contains(&"profiler".to_string()));
+            assert!(options.contains(&"console".to_string()));
+            assert!(options.contains(&"extensions".to_string()));
             assert!(!options.contains(&"layout".to_string()));
         } else {
             panic!("devtools.default_panel value should be SettingValue::Enum");
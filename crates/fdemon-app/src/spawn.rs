@@ -5,16 +5,17 @@
 //! - Emulator discovery and launch
 //! - iOS Simulator launch
 //! - Auto-launch device discovery and selection
+//! - Workspace restore device discovery and matching
 
 use std::path::{Path, PathBuf};
 use tokio::sync::mpsc;
 use tokio::time::{timeout, Duration};
 
 use crate::config::{
-    get_first_auto_start, get_first_config, load_last_selection, validate_last_selection,
-    LoadedConfigs,
+    find_config, get_first_auto_start, get_first_config, load_last_selection,
+    validate_last_selection, LoadedConfigs, Workspace,
 };
-use crate::message::{AutoLaunchSuccess, Message};
+use crate::message::{AutoLaunchSuccess, Message, WorkspaceRestore};
 use fdemon_daemon::{devices, emulators, Device, FlutterExecutable, ToolAvailability};
 
 /// Spawn device discovery in background (foreground mode - shows errors to user)
@@ -349,6 +350,105 @@ fn bare_flutter_run(devices: &[Device]) -> Option<AutoLaunchSuccess> {
     })
 }
 
+/// Spawn workspace restore task for device discovery and session matching
+///
+/// Discovers devices, matches the saved sessions against them and sends
+/// `Message::WorkspaceRestoreResult` back via the message channel.
+pub fn spawn_workspace_restore(
+    msg_tx: mpsc::Sender<Message>,
+    configs: LoadedConfigs,
+    workspace: Workspace,
+    flutter: FlutterExecutable,
+) {
+    tokio::spawn(async move {
+        let _ = msg_tx
+            .send(Message::AutoLaunchProgress {
+                message: "Detecting devices...".to_string(),
+            })
+            .await;
+
+        let result = match devices::discover_devices(&flutter).await {
+            Ok(result) => {
+                let _ = msg_tx
+                    .send(Message::DevicesDiscovered {
+                        devices: result.devices.clone(),
+                    })
+                    .await;
+                resolve_workspace(&workspace, &configs, &result.devices)
+            }
+            Err(e) => Err(format!(
+                "Device discovery failed: {}. Check Flutter SDK installation.",
+                e
+            )),
+        };
+
+        let _ = msg_tx
+            .send(Message::WorkspaceRestoreResult { result })
+            .await;
+    });
+}
+
+/// Match the saved workspace sessions against the connected devices.
+///
+/// A session is skipped when its device is not connected or its launch
+/// config no longer exists. Returns `Err` when no session can be restored.
+pub fn resolve_workspace(
+    workspace: &Workspace,
+    configs: &LoadedConfigs,
+    devices: &[Device],
+) -> Result<WorkspaceRestore, String> {
+    let mut restore = WorkspaceRestore {
+        sessions: Vec::new(),
+        selected: 0,
+        devtools_panel: workspace.devtools_panel.clone(),
+        skipped: Vec::new(),
+    };
+
+    for (index, saved) in workspace.sessions.iter().enumerate() {
+        let name = if saved.device_name.is_empty() {
+            &saved.device_id
+        } else {
+            &saved.device_name
+        };
+        let Some(device) = devices.iter().find(|d| d.id == saved.device_id) else {
+            restore
+                .skipped
+                .push(format!("{name} (device not connected)"));
+            continue;
+        };
+        let config = match &saved.config {
+            Some(config_name) => match find_config(configs, config_name) {
+                Some(sourced) => Some(sourced.config.clone()),
+                None => {
+                    restore
+                        .skipped
+                        .push(format!("{name} (config '{config_name}' not found)"));
+                    continue;
+                }
+            },
+            None => None,
+        };
+        if index == workspace.selected {
+            restore.selected = restore.sessions.len();
+        }
+        restore.sessions.push((
+            AutoLaunchSuccess {
+                device: device.clone(),
+                config,
+            },
+            saved.clone(),
+        ));
+    }
+
+    if restore.sessions.is_empty() {
+        return Err(format!(
+            "Cannot restore workspace: {}",
+            restore.skipped.join(", ")
+        ));
+    }
+    Ok(restore)
+}
+
 /// Timeout for tool availability checks
 const TOOL_CHECK_TIMEOUT: Duration = Duration::from_secs(10);
 
@@ -751,6 +851,56 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_resolve_workspace_skips_missing_devices_and_configs() {
+        use crate::config::WorkspaceSession;
+
+        let mut configs = LoadedConfigs::default();
+        configs
+            .configs
+            .push(make_sourced_config("Dev", "auto", false));
+        configs.is_empty = false;
+        let devices = vec![
+            make_device("android-1", "android"),
+            make_device("macos", "macos"),
+        ];
+        let session = |device_id: &str, config: Option<&str>| WorkspaceSession {
+            device_id: device_id.to_string(),
+            device_name: device_id.to_string(),
+            config: config.map(str::to_string),
+            ..Default::default()
+        };
+        let workspace = Workspace {
+            selected: 3,
+            devtools_panel: Some("network".to_string()),
+            sessions: vec![
+                session("android-1", Some("Dev")),
+                session("iphone", None),
+                session("macos", Some("Removed")),
+                session("macos", None),
+            ],
+        };
+
+        let restore = resolve_workspace(&workspace, &configs, &devices).unwrap();
+        assert_eq!(restore.sessions.len(), 2);
+        assert_eq!(restore.sessions[0].0.device.id, "android-1");
+        assert_eq!(restore.sessions[0].0.config.as_ref().unwrap().name, "Dev");
+        assert!(restore.sessions[1].0.config.is_none());
+        // The saved selection (index 3) is the second restored session.
+        assert_eq!(restore.selected, 1);
+        assert_eq!(restore.devtools_panel.as_deref(), Some("network"));
+        assert_eq!(
+            restore.skipped,
+            vec![
+                "iphone (device not connected)".to_string(),
+                "macos (config 'Removed' not found)".to_string(),
+            ]
+        );
+
+        let err = resolve_workspace(&workspace, &configs, &[]).unwrap_err();
+        assert!(err.starts_with("Cannot restore workspace: android-1 (device not connected)"));
+    }
+
     /// None branch: empty device list causes all tiers to return None.
     ///
     /// All tiers depend on at least one device being present. When `devices` is
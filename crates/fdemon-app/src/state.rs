@@ -9,9 +9,13 @@ use rand::Rng;
 
 use crate::config::{LoadedConfigs, Settings, SettingsTab, UserPreferences};
 use crate::confirm_dialog::ConfirmDialogState;
+use crate::console_view_state::ConsoleViewState;
+use crate::dump_view_state::DumpViewState;
+use crate::extensions_view_state::ExtensionsViewState;
 use crate::flutter_version::FlutterVersionState;
 use crate::new_session_dialog::NewSessionDialogState;
 use crate::new_session_dialog::{DartDefinesModalState, FuzzyModalState};
+use crate::profiler_view_state::ProfilerViewState;
 use fdemon_core::{AppPhase, DiagnosticsNode, LayoutInfo};
 use fdemon_daemon::{AndroidAvd, Device, FlutterSdk, IosSimulator, ToolAvailability};
 
@@ -134,6 +138,29 @@ pub enum DevToolsPanel {
 
     /// HTTP/WebSocket network request monitor.
     Network,
+
+    /// Text dumps of the widget, render, layer, semantics and focus trees.
+    Dumps,
+
+    /// CPU sampling profiler with call trees and a flame graph.
+    Profiler,
+
+    /// Raw VM Service RPC console.
+    Console,
+
+    /// Buttons for app-registered service extensions.
+    Extensions,
+}
+
+/// Content of the inspector's details pane, next to the widget tree.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum InspectorDetailView {
+    /// Box model, constraints and flex data from the Layout Explorer.
+    #[default]
+    Layout,
+
+    /// Every diagnostics property of the selected widget.
+    Properties,
 }
 
 /// A user-friendly error with an actionable hint for DevTools panels.
@@ -161,7 +188,8 @@ impl DevToolsError {
 /// State for the widget inspector tree view.
 ///
 /// Also holds layout data for the currently selected widget (merged into this struct
-/// in Phase 2). Layout fields use a `layout_` prefix to avoid conflicts with inspector fields.
+/// in Phase 2). Layout fields use a `layout_` prefix to avoid conflicts with inspector fields,
+/// and properties-pane fields use a `properties_` prefix.
 #[derive(Debug, Clone, Default)]
 pub struct InspectorState {
     /// The root widget tree node (fetched on-demand via VM Service RPC).
@@ -245,6 +273,60 @@ pub struct InspectorState {
     ///
     /// This prevents RPC spam during rapid scrolling through the widget tree.
     pub layout_last_fetch_time: Option<Instant>,
+
+    // ── Search / filter fields ─────────────────────────────────────────────────
+    /// Whether the search input bar is active (keys go to `search_query`).
+    pub search_input_active: bool,
+
+    /// Incremental search text, matched against widget type, description and
+    /// key across the whole fetched tree. Kept after the input bar is closed
+    /// with Enter so `]`/`[` can step between matches.
+    pub search_query: String,
+
+    /// Show only widgets created by the user's project. Framework widgets are
+    /// hidden and their user-code descendants are lifted to the nearest
+    /// visible ancestor's level. Survives refreshes and resets.
+    pub user_code_only: bool,
+
+    // ── Device select mode fields ──────────────────────────────────────────────
+    /// Whether on-device widget selection (`ext.flutter.inspector.show`) is
+    /// enabled. Taps on the device select widgets instead of reaching the app,
+    /// and the tree panel follows each selection.
+    pub select_mode: bool,
+
+    /// `value_id` of a device selection that was not in the loaded tree.
+    ///
+    /// Set when the tapped widget was built after the last fetch; the tree is
+    /// refreshed and the selection is applied once it arrives.
+    pub pending_selection: Option<String>,
+
+    // ── Properties fields ──────────────────────────────────────────────────────
+    /// Which view the details pane shows. Survives refreshes and resets.
+    pub detail_view: InspectorDetailView,
+
+    /// Details subtree (`getDetailsSubtree`) of the selected widget; its
+    /// `properties` are listed in the properties pane.
+    pub properties: Option<DiagnosticsNode>,
+
+    /// Whether a properties fetch is in progress.
+    pub properties_loading: bool,
+
+    /// User-friendly error from the last failed properties fetch.
+    pub properties_error: Option<DevToolsError>,
+
+    /// The `value_id` of the widget whose properties are loaded or in flight.
+    ///
+    /// Responses for any other widget are discarded as stale.
+    pub properties_node_id: Option<String>,
+
+    /// Whether keyboard focus is in the properties pane instead of the tree.
+    pub properties_focused: bool,
+
+    /// Index of the selected row in [`Self::visible_properties`].
+    pub properties_selected: usize,
+
+    /// Child-index paths of expanded nested properties (e.g. a `TextStyle`).
+    pub properties_expanded: HashSet<Vec<usize>>,
 }
 
 impl InspectorState {
@@ -281,6 +363,63 @@ impl InspectorState {
         self.last_fetched_node_id = None;
         self.pending_node_id = None;
         self.layout_last_fetch_time = None;
+        // Search (the user-code filter is a view preference and is kept)
+        self.search_input_active = false;
+        self.search_query.clear();
+        // Device select mode
+        self.select_mode = false;
+        self.pending_selection = None;
+        // Properties (the chosen detail view is a view preference and is kept)
+        self.clear_properties();
+        self.properties_focused = false;
+    }
+
+    /// Drop the loaded properties, e.g. after the tree was re-fetched and
+    /// their `value_id` no longer means anything.
+    pub fn clear_properties(&mut self) {
+        self.properties = None;
+        self.properties_loading = false;
+        self.properties_error = None;
+        self.properties_node_id = None;
+        self.properties_selected = 0;
+        self.properties_expanded.clear();
+    }
+
+    /// Property rows shown in the properties pane, in display order.
+    ///
+    /// Each row is the child-index path of the property (through nested
+    /// `properties`), the property node, and its nesting depth. Hidden
+    /// properties are skipped; nested properties are listed only when their
+    /// parent is expanded.
+    pub fn visible_properties(&self) -> Vec<(Vec<usize>, &DiagnosticsNode, usize)> {
+        fn walk<'a>(
+            properties: &'a [DiagnosticsNode],
+            expanded: &HashSet<Vec<usize>>,
+            path: &mut Vec<usize>,
+            out: &mut Vec<(Vec<usize>, &'a DiagnosticsNode, usize)>,
+        ) {
+            for (i, property) in properties.iter().enumerate() {
+                if !property.is_visible() {
+                    continue;
+                }
+                path.push(i);
+                out.push((path.clone(), property, path.len() - 1));
+                if expanded.contains(path) {
+                    walk(&property.properties, expanded, path, out);
+                }
+                path.pop();
+            }
+        }
+        let mut out = Vec::new();
+        if let Some(details) = &self.properties {
+            walk(
+                &details.properties,
+                &self.properties_expanded,
+                &mut Vec::new(),
+                &mut out,
+            );
+        }
+        out
     }
 
     /// Returns `true` if a tree refresh request should be suppressed.
@@ -347,6 +486,13 @@ impl InspectorState {
         if !node.is_visible() {
             return;
         }
+        if self.is_filtered_out(node) {
+            // Framework node in user-code-only mode: lift its children.
+            for child in &node.children {
+                self.collect_visible(child, depth, result);
+            }
+            return;
+        }
         result.push((node, depth));
         if let Some(value_id) = &node.value_id {
             if self.is_expanded(value_id) {
@@ -357,6 +503,174 @@ impl InspectorState {
         }
     }
 
+    /// Whether `node` is hidden by the user-code-only filter (its children
+    /// may still be shown).
+    fn is_filtered_out(&self, node: &DiagnosticsNode) -> bool {
+        self.user_code_only && !node.is_user_code()
+    }
+
+    // ── Search ─────────────────────────────────────────────────────────────────
+
+    /// The currently selected visible node.
+    fn selected_node(&self) -> Option<&DiagnosticsNode> {
+        self.visible_nodes()
+            .get(self.selected_index)
+            .map(|(node, _)| *node)
+    }
+
+    /// Whether `node` matches the current search query.
+    pub fn is_search_match(&self, node: &DiagnosticsNode) -> bool {
+        !self.search_query.is_empty()
+            && !self.is_filtered_out(node)
+            && node.matches_search(&self.search_query.to_lowercase())
+    }
+
+    /// All nodes of the fetched tree in pre-order, with the child-index path
+    /// from the root to each. Hidden nodes and their subtrees are skipped,
+    /// collapsed subtrees are not.
+    fn all_nodes(&self) -> Vec<(Vec<usize>, &DiagnosticsNode)> {
+        fn walk<'a>(
+            node: &'a DiagnosticsNode,
+            path: &mut Vec<usize>,
+            out: &mut Vec<(Vec<usize>, &'a DiagnosticsNode)>,
+        ) {
+            if !node.is_visible() {
+                return;
+            }
+            out.push((path.clone(), node));
+            for (i, child) in node.children.iter().enumerate() {
+                path.push(i);
+                walk(child, path, out);
+                path.pop();
+            }
+        }
+        let mut out = Vec::new();
+        if let Some(root) = &self.root {
+            walk(root, &mut Vec::new(), &mut out);
+        }
+        out
+    }
+
+    /// Number of search matches in the whole tree and the 1-based position
+    /// of the selected node among them (if it is a match).
+    pub fn search_match_position(&self) -> (usize, Option<usize>) {
+        if self.search_query.is_empty() {
+            return (0, None);
+        }
+        let selected = self.selected_node();
+        let mut total = 0;
+        let mut position = None;
+        for (_, node) in self.all_nodes() {
+            if self.is_search_match(node) {
+                total += 1;
+                if selected.is_some_and(|s| std::ptr::eq(s, node)) {
+                    position = Some(total);
+                }
+            }
+        }
+        (total, position)
+    }
+
+    /// Select the next (or previous) search match in tree order, wrapping
+    /// around, and expand its ancestors so it is visible.
+    ///
+    /// With `include_current` the selected node itself counts as a candidate,
+    /// which keeps the selection put while typing extends a query it still
+    /// matches. Returns `true` if a match was selected.
+    pub fn select_search_match(&mut self, forward: bool, include_current: bool) -> bool {
+        let nodes = self.all_nodes();
+        let selected = self.selected_node();
+        let current = nodes
+            .iter()
+            .position(|(_, node)| selected.is_some_and(|s| std::ptr::eq(s, *node)))
+            .unwrap_or(0);
+        let count = nodes.len();
+        let offsets: Vec<usize> = if include_current {
+            (0..count).collect()
+        } else {
+            (1..=count).collect()
+        };
+        let target = offsets.into_iter().find_map(|offset| {
+            let index = if forward {
+                (current + offset) % count
+            } else {
+                (current + count - offset % count) % count
+            };
+            let (path, node) = &nodes[index];
+            self.is_search_match(node).then(|| path.clone())
+        });
+        match target {
+            Some(path) => {
+                self.reveal_path(&path);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Expand every ancestor of the node at `path` and select it.
+    fn reveal_path(&mut self, path: &[usize]) {
+        let Some(root) = self.root.as_ref() else {
+            return;
+        };
+        let mut node = root;
+        let mut ancestors = Vec::new();
+        for &i in path {
+            if let Some(id) = &node.value_id {
+                ancestors.push(id.clone());
+            }
+            node = &node.children[i];
+        }
+        let target: *const DiagnosticsNode = node;
+        self.expanded.extend(ancestors);
+        if let Some(index) = self
+            .visible_nodes()
+            .iter()
+            .position(|(n, _)| std::ptr::eq(*n, target))
+        {
+            self.selected_index = index;
+        }
+    }
+
+    /// Select the node with the given `value_id`, expanding its ancestors.
+    ///
+    /// When the node is hidden by the user-code filter, its nearest visible
+    /// ancestor is selected instead. Returns `false` if the node is not in
+    /// the loaded tree.
+    pub fn select_value_id(&mut self, value_id: &str) -> bool {
+        let Some(mut path) = self
+            .all_nodes()
+            .into_iter()
+            .find(|(_, node)| node.value_id.as_deref() == Some(value_id))
+            .map(|(path, _)| path)
+        else {
+            return false;
+        };
+        while !path.is_empty() && self.node_at(&path).is_some_and(|n| self.is_filtered_out(n)) {
+            path.pop();
+        }
+        self.reveal_path(&path);
+        true
+    }
+
+    fn node_at(&self, path: &[usize]) -> Option<&DiagnosticsNode> {
+        path.iter()
+            .try_fold(self.root.as_ref()?, |node, &i| node.children.get(i))
+    }
+
+    /// Toggle the user-code-only filter, keeping the selected node selected
+    /// when it stays visible.
+    pub fn toggle_user_code_only(&mut self) {
+        let selected: Option<*const DiagnosticsNode> =
+            self.selected_node().map(|node| node as *const _);
+        self.user_code_only = !self.user_code_only;
+        let visible = self.visible_nodes();
+        self.selected_index = visible
+            .iter()
+            .position(|(node, _)| selected.is_some_and(|s| std::ptr::eq(*node, s)))
+            .unwrap_or(0);
+    }
+
     /// Return the description of the currently selected visible node.
     ///
     /// Traverses the tree in pre-order (same order as [`Self::visible_nodes`])
@@ -385,6 +699,12 @@ impl InspectorState {
         if !node.is_visible() {
             return None;
         }
+        if self.is_filtered_out(node) {
+            return node
+                .children
+                .iter()
+                .find_map(|child| self.find_nth_description(child, remaining));
+        }
         if *remaining == 0 {
             return Some(&node.description);
         }
@@ -412,10 +732,38 @@ pub struct DevToolsViewState {
     /// Widget inspector tree state (also contains layout explorer data).
     pub inspector: InspectorState,
 
+    /// Text dump viewer state.
+    pub dumps: DumpViewState,
+
+    /// CPU profiler state.
+    pub profiler: ProfilerViewState,
+
+    /// VM Service console state.
+    pub console: ConsoleViewState,
+
+    /// Service extension panel state.
+    pub extensions: ExtensionsViewState,
+
     /// Current debug overlay states (synced from VM Service).
     pub overlay_repaint_rainbow: bool,
     pub overlay_debug_paint: bool,
     pub overlay_performance: bool,
+    pub overlay_slow_animations: bool,
+    /// `true` when the "DEBUG" banner has been turned off (it is on by default).
+    pub overlay_banner_hidden: bool,
+    pub overlay_invert_oversized_images: bool,
+    /// `true` when widget build profiling is off. fdemon turns it on for frame
+    /// phase timings, so only the unusual state is flagged.
+    pub overlay_widget_builds_off: bool,
+    pub overlay_paint_baselines: bool,
+
+    /// Active target platform override (e.g. `"iOS"`), `None` when the app
+    /// runs as its own platform.
+    pub platform_override: Option<String>,
+
+    /// Brightness reported by `brightnessOverride` (e.g. `"Brightness.dark"`),
+    /// if known.
+    pub brightness_override: Option<String>,
 
     /// Last VM Service connection error message, if any.
     /// Set on `VmServiceConnectionFailed`, cleared on `VmServiceConnected`.
@@ -455,9 +803,20 @@ impl DevToolsViewState {
     /// as it is a UI preference, not session data.
     pub fn reset(&mut self) {
         self.inspector.reset();
+        self.dumps.reset();
+        self.profiler.reset();
+        self.console.reset();
+        self.extensions.reset();
         self.overlay_repaint_rainbow = false;
         self.overlay_debug_paint = false;
         self.overlay_performance = false;
+        self.overlay_slow_animations = false;
+        self.overlay_banner_hidden = false;
+        self.overlay_invert_oversized_images = false;
+        self.overlay_widget_builds_off = false;
+        self.overlay_paint_baselines = false;
+        self.platform_override = None;
+        self.brightness_override = None;
         self.vm_connection_error = None;
         self.connection_status = VmConnectionStatus::Disconnected;
         self.last_overlay_toggle = None;
@@ -965,6 +1324,11 @@ pub struct AppState {
     /// When set, bypasses environment-based IDE detection.
     pub cli_dap_config_override: Option<crate::config::ParentIde>,
 
+    /// CLI-provided VM Service URI to attach to (`--attach <uri>`). When set,
+    /// startup auto-launches an attach session instead of showing the
+    /// NewSessionDialog or restoring the workspace.
+    pub cli_attach_url: Option<String>,
+
     // ── Tag Filter Overlay (Phase 2, Task 09) ────────────────────────────────
     /// Whether the native tag filter overlay is currently visible.
     ///
@@ -976,6 +1340,10 @@ pub struct AppState {
     /// UI state for the tag filter overlay (selection, scroll).
     pub tag_filter_ui: TagFilterUiState,
 
+    /// Session picker overlay (`S`); `Some` while open. Key events are routed
+    /// to the picker first.
+    pub session_picker: Option<crate::session_picker::SessionPickerState>,
+
     /// Watcher errors that arrived before any session existed.
     /// Flushed into the first session on `SessionStarted`.
     /// Capped at [`MAX_PENDING_WATCHER_ERRORS`] to prevent unbounded growth.
@@ -1007,6 +1375,10 @@ pub struct AppState {
     /// dialog so users see the change without needing to inspect the log file.
     /// Cleared when the New Session dialog is dismissed.
     pub show_migration_banner: bool,
+
+    /// Group reload/restart/stop in flight, reported as one line when every
+    /// session has finished.
+    pub session_group_run: Option<crate::session_group::GroupRun>,
 }
 
 /// Maximum number of watcher errors buffered before a session exists.
@@ -1036,9 +1408,12 @@ impl AppState {
         // Parse project name from pubspec.yaml
         let project_name = fdemon_core::get_project_name(&project_path);
 
+        let mut session_manager = SessionManager::new();
+        session_manager.set_max_sessions(settings.behavior.max_sessions);
+
         Self {
             ui_mode: UiMode::Normal,
-            session_manager: SessionManager::new(),
+            session_manager,
             settings,
             confirm_dialog_state: None,
             project_path,
@@ -1059,13 +1434,16 @@ impl AppState {
             pending_file_changes: 0,
             dap_config_status: None,
             cli_dap_config_override: None,
+            cli_attach_url: None,
             tag_filter_visible: false,
             tag_filter_ui: TagFilterUiState::default(),
+            session_picker: None,
             pending_watcher_errors: Vec::new(),
             shared_source_handles: Vec::new(),
             resolved_sdk: None,
             flutter_version_state: FlutterVersionState::default(),
             show_migration_banner: false,
+            session_group_run: None,
         }
     }
 
@@ -1396,6 +1774,9 @@ mod tests {
         assert!(!state.overlay_repaint_rainbow);
         assert!(!state.overlay_debug_paint);
         assert!(!state.overlay_performance);
+        assert!(!state.overlay_slow_animations);
+        assert!(!state.overlay_banner_hidden);
+        assert!(state.platform_override.is_none());
         assert!(state.last_overlay_toggle.is_none());
     }
 
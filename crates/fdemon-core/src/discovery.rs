@@ -3,6 +3,8 @@
 //! Discovers runnable Flutter projects by analyzing `pubspec.yaml` content
 //! and checking for platform directories. Filters out plugins, packages,
 //! and Dart-only projects that cannot be run with `flutter run`.
+//! Flutter modules (`flutter: module:`) are only picked up by the attach
+//! variants, since they can be attached to but not launched on their own.
 
 use regex::Regex;
 use std::fs;
@@ -16,6 +18,9 @@ pub const DEFAULT_MAX_DEPTH: usize = 3;
 /// Platform directories that indicate a runnable Flutter project
 const PLATFORM_DIRECTORIES: &[&str] = &["android", "ios", "macos", "web", "linux", "windows"];
 
+/// Generated host directories of a Flutter module (`flutter create -t module`)
+const MODULE_DIRECTORIES: &[&str] = &[".android", ".ios"];
+
 /// Subdirectories to check for runnable examples in plugins
 const EXAMPLE_DIRECTORIES: &[&str] = &["example", "sample"];
 
@@ -42,6 +47,9 @@ pub enum ProjectType {
     Application,
     /// A Flutter plugin (has `flutter: plugin:` section)
     Plugin,
+    /// A Flutter module embedded in a host app (has `flutter: module:` section
+    /// or `.android/` / `.ios/` directories); attachable but not runnable
+    Module,
     /// A Flutter package (has flutter dep but no platform directories)
     FlutterPackage,
     /// A pure Dart package (no flutter SDK dependency)
@@ -78,6 +86,11 @@ pub(crate) fn is_flutter_plugin(path: &Path) -> bool {
     }
 }
 
+/// Check if a project is a Flutter module
+pub(crate) fn is_flutter_module(path: &Path) -> bool {
+    get_project_type(path) == Some(ProjectType::Module)
+}
+
 /// Check if project has any platform directories
 pub(crate) fn has_platform_directories(path: &Path) -> bool {
     PLATFORM_DIRECTORIES
@@ -92,12 +105,16 @@ pub fn get_project_type(path: &Path) -> Option<ProjectType> {
 
     let has_flutter = check_has_flutter_dependency(&content);
     let is_plugin = check_is_plugin(&content);
+    let is_module =
+        check_is_module(&content) || MODULE_DIRECTORIES.iter().any(|dir| path.join(dir).is_dir());
     let has_platforms = has_platform_directories(path);
 
     Some(if !has_flutter {
         ProjectType::DartPackage
     } else if is_plugin {
         ProjectType::Plugin
+    } else if is_module {
+        ProjectType::Module
     } else if has_platforms {
         ProjectType::Application
     } else {
@@ -129,6 +146,12 @@ pub fn is_runnable_flutter_project(path: &Path) -> bool {
     has_platform_directories(path)
 }
 
+/// Check if a directory contains a Flutter project that `flutter attach` can
+/// target: a runnable project or a Flutter module.
+pub fn is_attachable_flutter_project(path: &Path) -> bool {
+    is_runnable_flutter_project(path) || is_flutter_module(path)
+}
+
 /// Discover runnable Flutter projects in the given directory
 ///
 /// This function searches for projects that can be run with `flutter run`.
@@ -144,6 +167,20 @@ pub fn is_runnable_flutter_project(path: &Path) -> bool {
 /// # Returns
 /// * `DiscoveryResult` with found runnable projects sorted by path
 pub fn discover_flutter_projects(base_path: &Path, max_depth: usize) -> DiscoveryResult {
+    discover_projects(base_path, max_depth, false)
+}
+
+/// Discover Flutter projects that can be attached to
+///
+/// Same as [`discover_flutter_projects`], but Flutter modules are returned
+/// alongside runnable projects instead of being skipped. Use this when the
+/// session will run `flutter attach` (`--attach` or `attach = true`).
+pub fn discover_attachable_flutter_projects(base_path: &Path, max_depth: usize) -> DiscoveryResult {
+    discover_projects(base_path, max_depth, true)
+}
+
+/// Shared discovery entry point; `include_modules` accepts Flutter modules
+fn discover_projects(base_path: &Path, max_depth: usize, include_modules: bool) -> DiscoveryResult {
     let mut result = DiscoveryResult {
         projects: Vec::new(),
         searched_from: base_path.to_path_buf(),
@@ -158,6 +195,12 @@ pub fn discover_flutter_projects(base_path: &Path, max_depth: usize) -> Discover
         return result;
     }
 
+    if include_modules && is_flutter_module(base_path) {
+        debug!("Base path is an attachable Flutter module: {:?}", base_path);
+        result.projects.push(base_path.to_path_buf());
+        return result;
+    }
+
     // Check if base_path is a plugin - look for examples
     if is_flutter_plugin(base_path) {
         debug!(
@@ -185,7 +228,7 @@ pub fn discover_flutter_projects(base_path: &Path, max_depth: usize) -> Discover
     }
 
     // Recursively search for projects
-    discover_recursive(base_path, 0, max_depth, &mut result);
+    discover_recursive(base_path, 0, max_depth, include_modules, &mut result);
 
     // Sort results by path
     result.projects.sort();
@@ -198,6 +241,7 @@ fn discover_recursive(
     dir: &Path,
     current_depth: usize,
     max_depth: usize,
+    include_modules: bool,
     result: &mut DiscoveryResult,
 ) {
     if current_depth > max_depth {
@@ -267,6 +311,21 @@ fn discover_recursive(
                         // Don't descend further into the plugin
                         continue;
                     }
+                    ProjectType::Module if include_modules => {
+                        debug!("Found attachable Flutter module: {:?}", path);
+                        result.projects.push(path.clone());
+                        continue;
+                    }
+                    ProjectType::Module => {
+                        debug!("Skipping Flutter module (attach only): {:?}", path);
+                        result.skipped.push(SkippedProject {
+                            path: path.clone(),
+                            project_type: ProjectType::Module,
+                            reason: "Flutter module can only be attached to (use --attach)"
+                                .to_string(),
+                        });
+                        continue;
+                    }
                     ProjectType::FlutterPackage => {
                         debug!("Skipping Flutter package (no platform dirs): {:?}", path);
                         result.skipped.push(SkippedProject {
@@ -290,7 +349,7 @@ fn discover_recursive(
         }
 
         // Recurse into subdirectory
-        discover_recursive(&path, current_depth + 1, max_depth, result);
+        discover_recursive(&path, current_depth + 1, max_depth, include_modules, result);
     }
 }
 
@@ -327,6 +386,35 @@ fn check_is_plugin(content: &str) -> bool {
     false
 }
 
+/// Check if pubspec.yaml indicates a Flutter module
+fn check_is_module(content: &str) -> bool {
+    // Look for pattern:
+    // flutter:
+    //   module:
+    //     androidPackage: ...
+
+    let mut in_flutter_section = false;
+
+    for line in content.lines() {
+        let trimmed = line.trim();
+
+        if line.starts_with("flutter:") && !line.starts_with(' ') {
+            in_flutter_section = true;
+            continue;
+        }
+
+        if in_flutter_section && !line.starts_with(' ') && !trimmed.is_empty() {
+            in_flutter_section = false;
+        }
+
+        if in_flutter_section && trimmed.starts_with("module:") {
+            return true;
+        }
+    }
+
+    false
+}
+
 /// Parse the project name from pubspec.yaml
 ///
 /// This function reads the pubspec.yaml file and extracts the project name.
@@ -651,6 +739,31 @@ dependencies:
         // Note: NO platform directories created
     }
 
+    /// Helper to create a Flutter module (`flutter create -t module`)
+    fn create_flutter_module(path: &Path, name: &str) {
+        fs::write(
+            path.join("pubspec.yaml"),
+            format!(
+                r#"name: {}
+dependencies:
+  flutter:
+    sdk: flutter
+flutter:
+  uses-material-design: true
+  module:
+    androidX: true
+    androidPackage: com.example.{}
+    iosBundleIdentifier: com.example.{}
+"#,
+                name, name, name
+            ),
+        )
+        .unwrap();
+        fs::create_dir_all(path.join("lib")).unwrap();
+        fs::create_dir_all(path.join(".android")).unwrap();
+        fs::create_dir_all(path.join(".ios")).unwrap();
+    }
+
     /// Check if pubspec.yaml has Flutter SDK dependency (test helper)
     fn has_flutter_dependency(path: &Path) -> bool {
         let pubspec_path = path.join("pubspec.yaml");
@@ -766,6 +879,64 @@ dependencies:
         assert_eq!(names, vec!["app_a", "app_b", "app_c"]);
     }
 
+    #[test]
+    fn test_flutter_module_is_attachable_but_not_runnable() {
+        let temp = TempDir::new().unwrap();
+        let module_path = temp.path().join("my_module");
+        fs::create_dir(&module_path).unwrap();
+        create_flutter_module(&module_path, "my_module");
+
+        assert_eq!(get_project_type(&module_path), Some(ProjectType::Module));
+        assert!(!is_runnable_flutter_project(&module_path));
+        assert!(is_attachable_flutter_project(&module_path));
+    }
+
+    #[test]
+    fn test_module_detected_from_host_directories() {
+        let temp = TempDir::new().unwrap();
+        let module_path = temp.path().join("my_module");
+        fs::create_dir(&module_path).unwrap();
+        create_flutter_package(&module_path, "my_module");
+        fs::create_dir(module_path.join(".ios")).unwrap();
+
+        assert_eq!(get_project_type(&module_path), Some(ProjectType::Module));
+    }
+
+    #[test]
+    fn test_discover_modules_only_for_attach() {
+        let temp = TempDir::new().unwrap();
+
+        let app_path = temp.path().join("app");
+        fs::create_dir(&app_path).unwrap();
+        create_flutter_app(&app_path, "app");
+
+        let module_path = temp.path().join("my_module");
+        fs::create_dir(&module_path).unwrap();
+        create_flutter_module(&module_path, "my_module");
+
+        let run = discover_flutter_projects(temp.path(), DEFAULT_MAX_DEPTH);
+        assert_eq!(run.projects, vec![app_path.clone()]);
+        assert!(run
+            .skipped
+            .iter()
+            .any(|s| s.path == module_path && s.project_type == ProjectType::Module));
+
+        let attach = discover_attachable_flutter_projects(temp.path(), DEFAULT_MAX_DEPTH);
+        assert_eq!(attach.projects, vec![app_path, module_path.clone()]);
+
+        let base = discover_attachable_flutter_projects(&module_path, DEFAULT_MAX_DEPTH);
+        assert_eq!(base.projects, vec![module_path]);
+    }
+
+    #[test]
+    fn test_check_is_module() {
+        assert!(check_is_module("flutter:\n  module:\n    androidX: true\n"));
+        assert!(!check_is_module("flutter:\n  uses-material-design: true\n"));
+        assert!(!check_is_module(
+            "module:\n  name: x\nflutter:\n  assets: []\n"
+        ));
+    }
+
     #[test]
     fn test_has_platform_directories() {
         let temp = TempDir::new().unwrap();
@@ -0,0 +1,209 @@
+//! Class-level diff of two heap captures.
+//!
+//! A [`HeapDiff`] compares the retained instances and bytes of every class in
+//! two [`AllocationProfile`]s taken with a forced GC, so only objects that are
+//! still reachable are counted. Classes that keep growing across repeated runs
+//! of the same interaction (e.g. opening and closing a screen) are leak
+//! candidates.
+
+use std::cmp::Reverse;
+use std::collections::HashMap;
+
+use chrono::{DateTime, Local};
+
+use crate::performance::{AllocationProfile, ClassHeapStats};
+
+/// Retained instances and bytes of one class in both captures.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClassHeapDelta {
+    /// Class name (e.g., "_String").
+    pub class_name: String,
+    /// VM Service class ID from the latest capture that has one.
+    pub class_id: Option<String>,
+    /// Library URI that defines the class.
+    pub library_uri: Option<String>,
+    /// Retained instances in the first capture.
+    pub instances_before: u64,
+    /// Retained instances in the second capture.
+    pub instances_after: u64,
+    /// Retained bytes in the first capture.
+    pub bytes_before: u64,
+    /// Retained bytes in the second capture.
+    pub bytes_after: u64,
+}
+
+impl ClassHeapDelta {
+    /// Instances added (positive) or removed (negative).
+    pub fn instance_delta(&self) -> i64 {
+        self.instances_after as i64 - self.instances_before as i64
+    }
+
+    /// Bytes added (positive) or removed (negative).
+    pub fn size_delta(&self) -> i64 {
+        self.bytes_after as i64 - self.bytes_before as i64
+    }
+}
+
+/// Per-class differences between two allocation profiles.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HeapDiff {
+    /// When the first capture was taken.
+    pub before: DateTime<Local>,
+    /// When the second capture was taken.
+    pub after: DateTime<Local>,
+    /// Classes whose retained instances or bytes changed, largest byte
+    /// growth first.
+    pub classes: Vec<ClassHeapDelta>,
+}
+
+impl HeapDiff {
+    /// Diff the retained (`bytesCurrent` / `instancesCurrent`) statistics of
+    /// `before` and `after`. Classes are matched by library URI and name; a
+    /// class missing from one side counts as zero there.
+    pub fn between(before: &AllocationProfile, after: &AllocationProfile) -> Self {
+        let key = |s: &ClassHeapStats| (s.library_uri.clone(), s.class_name.clone());
+
+        let mut deltas: HashMap<(Option<String>, String), ClassHeapDelta> = HashMap::new();
+        for stats in &before.members {
+            let delta = deltas
+                .entry(key(stats))
+                .or_insert_with(|| empty_delta(stats));
+            delta.instances_before += stats.old_space_instances;
+            delta.bytes_before += stats.old_space_size;
+        }
+        for stats in &after.members {
+            let delta = deltas
+                .entry(key(stats))
+                .or_insert_with(|| empty_delta(stats));
+            if stats.class_id.is_some() {
+                delta.class_id = stats.class_id.clone();
+            }
+            delta.instances_after += stats.old_space_instances;
+            delta.bytes_after += stats.old_space_size;
+        }
+
+        let mut classes: Vec<ClassHeapDelta> = deltas
+            .into_values()
+            .filter(|d| d.instance_delta() != 0 || d.size_delta() != 0)
+            .collect();
+        classes.sort_by(|a, b| {
+            b.size_delta()
+                .cmp(&a.size_delta())
+                .then_with(|| b.instance_delta().cmp(&a.instance_delta()))
+                .then_with(|| a.class_name.cmp(&b.class_name))
+        });
+
+        Self {
+            before: before.timestamp,
+            after: after.timestamp,
+            classes,
+        }
+    }
+
+    /// Return classes sorted by byte growth (descending).
+    pub fn top_by_size(&self, limit: usize) -> Vec<&ClassHeapDelta> {
+        self.classes.iter().take(limit).collect()
+    }
+
+    /// Return classes sorted by instance growth (descending).
+    pub fn top_by_instances(&self, limit: usize) -> Vec<&ClassHeapDelta> {
+        let mut sorted: Vec<_> = self.classes.iter().collect();
+        sorted.sort_by_key(|d| Reverse(d.instance_delta()));
+        sorted.truncate(limit);
+        sorted
+    }
+
+    /// Number of classes that retained more bytes or instances.
+    pub fn grown_count(&self) -> usize {
+        self.classes
+            .iter()
+            .filter(|d| d.size_delta() > 0 || d.instance_delta() > 0)
+            .count()
+    }
+
+    /// Net change in retained bytes across all classes.
+    pub fn total_size_delta(&self) -> i64 {
+        self.classes.iter().map(ClassHeapDelta::size_delta).sum()
+    }
+}
+
+fn empty_delta(stats: &ClassHeapStats) -> ClassHeapDelta {
+    ClassHeapDelta {
+        class_name: stats.class_name.clone(),
+        class_id: stats.class_id.clone(),
+        library_uri: stats.library_uri.clone(),
+        instances_before: 0,
+        instances_after: 0,
+        bytes_before: 0,
+        bytes_after: 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn class(name: &str, instances: u64, bytes: u64) -> ClassHeapStats {
+        ClassHeapStats {
+            class_name: name.to_string(),
+            class_id: None,
+            library_uri: Some("package:app/main.dart".to_string()),
+            new_space_instances: 1_000,
+            new_space_size: 64_000,
+            old_space_instances: instances,
+            old_space_size: bytes,
+        }
+    }
+
+    fn profile(members: Vec<ClassHeapStats>) -> AllocationProfile {
+        AllocationProfile {
+            members,
+            timestamp: Local::now(),
+        }
+    }
+
+    #[test]
+    fn test_diff_sorts_by_byte_growth_and_drops_unchanged() {
+        let before = profile(vec![
+            class("Steady", 10, 1_000),
+            class("Controller", 1, 200),
+            class("Gone", 4, 400),
+        ]);
+        let after = profile(vec![
+            class("Steady", 10, 1_000),
+            class("Controller", 3, 600),
+            class("Listener", 5, 2_000),
+        ]);
+
+        let diff = HeapDiff::between(&before, &after);
+        let names: Vec<&str> = diff.classes.iter().map(|d| d.class_name.as_str()).collect();
+        assert_eq!(names, ["Listener", "Controller", "Gone"]);
+        assert_eq!(diff.classes[0].instance_delta(), 5);
+        assert_eq!(diff.classes[1].size_delta(), 400);
+        assert_eq!(diff.classes[2].size_delta(), -400);
+        assert_eq!(diff.grown_count(), 2);
+        assert_eq!(diff.total_size_delta(), 2_000);
+    }
+
+    #[test]
+    fn test_diff_ignores_accumulated_churn() {
+        let mut churned = class("Churn", 2, 100);
+        churned.new_space_instances = 99_999;
+        churned.new_space_size = 9_999_999;
+        let diff = HeapDiff::between(
+            &profile(vec![class("Churn", 2, 100)]),
+            &profile(vec![churned]),
+        );
+        assert!(diff.classes.is_empty());
+    }
+
+    #[test]
+    fn test_top_by_instances() {
+        let diff = HeapDiff::between(
+            &profile(vec![]),
+            &profile(vec![class("Big", 1, 10_000), class("Many", 50, 800)]),
+        );
+        assert_eq!(diff.top_by_size(1)[0].class_name, "Big");
+        assert_eq!(diff.top_by_instances(1)[0].class_name, "Many");
+    }
+}
@@ -0,0 +1,144 @@
+//! Live heap instance domain types.
+//!
+//! An [`InstanceSet`] lists live instances of one class (VM Service
+//! `getInstances`). Each [`HeapInstance`] can be expanded into its
+//! [`InstanceField`]s (`getObject`) and the [`RetainingPath`] that keeps it
+//! alive (`getRetainingPath`) — the chain of references from the instance up
+//! to a GC root.
+
+/// A live instance of a class.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HeapInstance {
+    /// VM Service object ID. Only valid until the isolate's object ID ring
+    /// wraps, so instances should be inspected soon after listing.
+    pub id: String,
+    /// Short description, e.g. `_HomeState` or `String "hello"`.
+    pub description: String,
+}
+
+/// Live instances of one class.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct InstanceSet {
+    /// Number of live instances, which may exceed `instances.len()`.
+    pub total_count: u64,
+    /// The instances returned, up to the requested limit.
+    pub instances: Vec<HeapInstance>,
+}
+
+/// A field of an instance and a short description of its value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InstanceField {
+    /// Field name.
+    pub name: String,
+    /// Short description of the value, e.g. `int 3` or `null`.
+    pub value: String,
+}
+
+/// One object on a retaining path.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RetainingObject {
+    /// Short description of the object.
+    pub description: String,
+    /// Where the next object on the path holds this one: a field name,
+    /// list index or map key. `None` for the last object.
+    pub parent_slot: Option<RetainingSlot>,
+}
+
+/// How a parent object references a child on a retaining path.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RetainingSlot {
+    /// A named field.
+    Field(String),
+    /// A list element.
+    ListIndex(i64),
+    /// A map value, with a description of its key.
+    MapKey(String),
+}
+
+/// Chain of references keeping an object alive, from the object itself to
+/// the object held by a GC root.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RetainingPath {
+    /// Kind of GC root at the end of the path, e.g. `isolate_object` or
+    /// `user global`.
+    pub gc_root_type: String,
+    /// Objects on the path, starting with the inspected instance.
+    pub elements: Vec<RetainingObject>,
+}
+
+impl RetainingPath {
+    /// One line per object, each describing how the previous line's object is
+    /// referenced, followed by the GC root. For example:
+    ///
+    /// ```text
+    /// _HomeState
+    /// List[3]
+    /// _ChangeNotifier._listeners
+    /// GC root: user global
+    /// ```
+    pub fn chain(&self) -> Vec<String> {
+        let mut lines = Vec::with_capacity(self.elements.len() + 1);
+        let mut child_slot: Option<&RetainingSlot> = None;
+        for element in &self.elements {
+            let line = match child_slot {
+                None => element.description.clone(),
+                Some(RetainingSlot::Field(name)) => format!("{}.{name}", element.description),
+                Some(RetainingSlot::ListIndex(i)) => format!("{}[{i}]", element.description),
+                Some(RetainingSlot::MapKey(key)) => format!("{}[{key}]", element.description),
+            };
+            lines.push(line);
+            child_slot = element.parent_slot.as_ref();
+        }
+        lines.push(format!("GC root: {}", self.gc_root_type));
+        lines
+    }
+}
+
+/// Fields and retaining path of one inspected instance.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InstanceDetails {
+    /// The instance's fields.
+    pub fields: Vec<InstanceField>,
+    /// Why the instance is still alive.
+    pub retaining_path: RetainingPath,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_retaining_path_chain() {
+        let path = RetainingPath {
+            gc_root_type: "user global".to_string(),
+            elements: vec![
+                RetainingObject {
+                    description: "_HomeState".to_string(),
+                    parent_slot: Some(RetainingSlot::ListIndex(3)),
+                },
+                RetainingObject {
+                    description: "List".to_string(),
+                    parent_slot: Some(RetainingSlot::Field("_listeners".to_string())),
+                },
+                RetainingObject {
+                    description: "_ChangeNotifier".to_string(),
+                    parent_slot: Some(RetainingSlot::MapKey("\"home\"".to_string())),
+                },
+                RetainingObject {
+                    description: "_Map".to_string(),
+                    parent_slot: None,
+                },
+            ],
+        };
+        assert_eq!(
+            path.chain(),
+            [
+                "_HomeState",
+                "List[3]",
+                "_ChangeNotifier._listeners",
+                "_Map[\"home\"]",
+                "GC root: user global",
+            ]
+        );
+    }
+}
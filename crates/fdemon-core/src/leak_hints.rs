@@ -0,0 +1,271 @@
+//! Leak hints for classes that should be disposed.
+//!
+//! A [`LeakTracker`] records the live instance count of watched classes
+//! (`State`, `AnimationController`, ...) at checkpoints — hot reloads and
+//! route changes — and flags classes whose count grew at every one of the
+//! last N checkpoints. Objects that are disposed properly are collected
+//! eventually, so their counts level off; steady growth across repeated
+//! navigations hints at a missing `dispose()`.
+
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+
+use crate::performance::AllocationProfile;
+
+/// Classes watched when no list is configured.
+pub const DEFAULT_LEAK_WATCH_CLASSES: &[&str] = &[
+    "State",
+    "AnimationController",
+    "StreamSubscription",
+    "TextEditingController",
+    "ScrollController",
+    "FocusNode",
+];
+
+/// A watched class whose live instance count grew at every recent checkpoint.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LeakHint {
+    /// Class name as reported by the VM (e.g., `_HomeState`).
+    pub class_name: String,
+    /// Live instances at each of the last checkpoints, oldest first.
+    pub counts: Vec<u64>,
+}
+
+impl LeakHint {
+    /// Instances added between the first and last checkpoint.
+    pub fn growth(&self) -> u64 {
+        match (self.counts.first(), self.counts.last()) {
+            (Some(first), Some(last)) => last.saturating_sub(*first),
+            _ => 0,
+        }
+    }
+}
+
+/// Whether `class_name` is covered by the watched name `pattern`.
+///
+/// Concrete classes are matched by suffix so that `State` covers every
+/// `State` subclass (`_HomeState`) and `StreamSubscription` covers
+/// `_BufferingStreamSubscription`.
+pub fn matches_watched(class_name: &str, pattern: &str) -> bool {
+    !pattern.is_empty() && class_name.ends_with(pattern)
+}
+
+/// Tracks watched class counts across checkpoints.
+#[derive(Debug, Clone)]
+pub struct LeakTracker {
+    watched: Vec<String>,
+    /// Consecutive checkpoints with growth needed to flag a class.
+    threshold: usize,
+    /// Counts per class at the last `threshold + 1` checkpoints.
+    history: BTreeMap<String, VecDeque<u64>>,
+    /// Checkpoints recorded so far, capped at `threshold + 1`.
+    checkpoints: usize,
+    /// A checkpoint was requested and its profile has not arrived yet.
+    pending: bool,
+    /// Classes flagged at the last checkpoint.
+    flagged: BTreeSet<String>,
+    hints: Vec<LeakHint>,
+}
+
+impl LeakTracker {
+    /// Tracker for classes matching `watched`, flagging growth at
+    /// `threshold` consecutive checkpoints (at least 1).
+    pub fn new(watched: Vec<String>, threshold: usize) -> Self {
+        Self {
+            watched,
+            threshold: threshold.max(1),
+            history: BTreeMap::new(),
+            checkpoints: 0,
+            pending: false,
+            flagged: BTreeSet::new(),
+            hints: Vec::new(),
+        }
+    }
+
+    /// Request a checkpoint. Returns `false` if one is already waiting for
+    /// its profile, so that a burst of reloads or route changes is recorded
+    /// as one checkpoint.
+    pub fn mark_checkpoint(&mut self) -> bool {
+        !std::mem::replace(&mut self.pending, true)
+    }
+
+    /// Drop a requested checkpoint whose profile could not be fetched.
+    pub fn cancel_checkpoint(&mut self) {
+        self.pending = false;
+    }
+
+    /// Current hints, largest growth first.
+    pub fn hints(&self) -> &[LeakHint] {
+        &self.hints
+    }
+
+    /// Record the watched class counts in `profile` as a checkpoint and
+    /// return the hints for classes that were not flagged at the previous
+    /// checkpoint.
+    pub fn record_checkpoint(&mut self, profile: &AllocationProfile) -> Vec<LeakHint> {
+        self.pending = false;
+
+        let mut counts: BTreeMap<String, u64> = BTreeMap::new();
+        for stats in &profile.members {
+            if self
+                .watched
+                .iter()
+                .any(|p| matches_watched(&stats.class_name, p))
+            {
+                *counts.entry(stats.class_name.clone()).or_default() += stats.total_instances();
+            }
+        }
+
+        // Classes absent from this profile have no live instances.
+        let window = self.threshold + 1;
+        for (class, history) in &mut self.history {
+            history.push_back(counts.remove(class).unwrap_or(0));
+        }
+        for (class, count) in counts {
+            let mut history = VecDeque::from(vec![0; self.checkpoints]);
+            history.push_back(count);
+            self.history.insert(class, history);
+        }
+        self.checkpoints = (self.checkpoints + 1).min(window);
+        for history in self.history.values_mut() {
+            while history.len() > window {
+                history.pop_front();
+            }
+        }
+        self.history.retain(|_, h| h.iter().any(|&c| c > 0));
+
+        self.hints = self
+            .history
+            .iter()
+            .filter(|(_, h)| {
+                h.len() == window && h.iter().zip(h.iter().skip(1)).all(|(a, b)| b > a)
+            })
+            .map(|(class, h)| LeakHint {
+                class_name: class.clone(),
+                counts: h.iter().copied().collect(),
+            })
+            .collect();
+        self.hints.sort_by(|a, b| {
+            b.growth()
+                .cmp(&a.growth())
+                .then(a.class_name.cmp(&b.class_name))
+        });
+
+        let previous = std::mem::replace(
+            &mut self.flagged,
+            self.hints.iter().map(|h| h.class_name.clone()).collect(),
+        );
+        self.hints
+            .iter()
+            .filter(|h| !previous.contains(&h.class_name))
+            .cloned()
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::performance::ClassHeapStats;
+
+    fn profile(classes: &[(&str, u64)]) -> AllocationProfile {
+        AllocationProfile {
+            members: classes
+                .iter()
+                // Split live instances between the generations; both count.
+                .map(|(name, live)| ClassHeapStats {
+                    class_name: name.to_string(),
+                    class_id: None,
+                    library_uri: None,
+                    new_space_instances: live / 2,
+                    new_space_size: live / 2 * 64,
+                    old_space_instances: live - live / 2,
+                    old_space_size: (live - live / 2) * 64,
+                })
+                .collect(),
+            timestamp: chrono::Local::now(),
+        }
+    }
+
+    fn checkpoint(tracker: &mut LeakTracker, classes: &[(&str, u64)]) -> Vec<LeakHint> {
+        tracker.record_checkpoint(&profile(classes))
+    }
+
+    #[test]
+    fn test_matches_watched_by_suffix() {
+        assert!(matches_watched("_HomeState", "State"));
+        assert!(matches_watched(
+            "_BufferingStreamSubscription",
+            "StreamSubscription"
+        ));
+        assert!(!matches_watched("_StateFlag", "State"));
+        assert!(!matches_watched("_HomeState", ""));
+    }
+
+    #[test]
+    fn test_flags_monotonic_growth_once() {
+        let mut tracker = LeakTracker::new(vec!["State".to_string()], 2);
+        assert!(checkpoint(&mut tracker, &[("_HomeState", 1), ("_Steady", 9)]).is_empty());
+        assert!(checkpoint(&mut tracker, &[("_HomeState", 2), ("_Steady", 9)]).is_empty());
+
+        let new = checkpoint(&mut tracker, &[("_HomeState", 3), ("_Steady", 9)]);
+        assert_eq!(
+            new,
+            [LeakHint {
+                class_name: "_HomeState".to_string(),
+                counts: vec![1, 2, 3],
+            }]
+        );
+        assert_eq!(new[0].growth(), 2);
+
+        // Still growing: kept in the panel but not reported again.
+        assert!(checkpoint(&mut tracker, &[("_HomeState", 4)]).is_empty());
+        assert_eq!(tracker.hints()[0].counts, [2, 3, 4]);
+
+        // Levelled off.
+        checkpoint(&mut tracker, &[("_HomeState", 4)]);
+        assert!(tracker.hints().is_empty());
+    }
+
+    #[test]
+    fn test_mark_checkpoint_collapses_bursts() {
+        let mut tracker = LeakTracker::new(vec!["Controller".to_string()], 1);
+        assert!(tracker.mark_checkpoint());
+        assert!(!tracker.mark_checkpoint());
+        tracker.cancel_checkpoint();
+        assert!(tracker.mark_checkpoint());
+
+        checkpoint(&mut tracker, &[("AnimationController", 1)]);
+        assert!(tracker.mark_checkpoint());
+        let new = checkpoint(&mut tracker, &[("AnimationController", 2)]);
+        assert_eq!(new[0].counts, [1, 2]);
+    }
+
+    #[test]
+    fn test_new_class_counts_from_zero() {
+        let mut tracker = LeakTracker::new(vec!["State".to_string()], 2);
+        checkpoint(&mut tracker, &[]);
+        checkpoint(&mut tracker, &[("_DetailState", 1)]);
+        let new = checkpoint(&mut tracker, &[("_DetailState", 2)]);
+        assert_eq!(new[0].counts, [0, 1, 2]);
+    }
+
+    #[test]
+    fn test_counts_new_space_instances() {
+        let mut tracker = LeakTracker::new(vec!["State".to_string()], 1);
+        let young = |n| AllocationProfile {
+            members: vec![ClassHeapStats {
+                class_name: "_PageState".to_string(),
+                class_id: None,
+                library_uri: None,
+                new_space_instances: n,
+                new_space_size: n * 64,
+                old_space_instances: 0,
+                old_space_size: 0,
+            }],
+            timestamp: chrono::Local::now(),
+        };
+        tracker.record_checkpoint(&young(1));
+        let new = tracker.record_checkpoint(&young(2));
+        assert_eq!(new[0].counts, [1, 2]);
+    }
+}
@@ -27,6 +27,7 @@
 //! ### Project Discovery (`discovery`)
 //! - [`is_runnable_flutter_project()`] - Check if a directory is a runnable Flutter project
 //! - [`discover_flutter_projects()`] - Find Flutter projects in subdirectories
+//! - [`discover_attachable_flutter_projects()`] - Same, including Flutter modules for attach
 //! - [`get_project_type()`] - Determine project type (app, plugin, package)
 //!
 //! ### Stack Traces (`stack_trace`)
@@ -50,9 +51,15 @@ pub mod discovery;
 pub mod error;
 pub mod events;
 pub mod exception_block;
+pub mod heap_diff;
+pub mod instances;
+pub mod leak_hints;
 pub mod logging;
 pub mod network;
+pub mod perf_baseline;
+pub mod perf_export;
 pub mod performance;
+pub mod profiler;
 pub mod stack_trace;
 pub mod types;
 pub mod widget_tree;
@@ -66,8 +73,9 @@ pub mod prelude {
 // Re-export commonly used types at crate root for convenience
 pub use ansi::{contains_ansi_codes, contains_word, strip_ansi_codes};
 pub use discovery::{
-    discover_entry_points, discover_flutter_projects, get_project_name, get_project_type,
-    is_runnable_flutter_project, DiscoveryResult, ProjectType, SkippedProject, DEFAULT_MAX_DEPTH,
+    discover_attachable_flutter_projects, discover_entry_points, discover_flutter_projects,
+    get_project_name, get_project_type, is_attachable_flutter_project, is_runnable_flutter_project,
+    DiscoveryResult, ProjectType, SkippedProject, DEFAULT_MAX_DEPTH,
 };
 pub use error::{Error, Result, ResultExt};
 pub use events::{
@@ -75,15 +83,25 @@ pub use events::{
     DaemonLogMessage, DaemonMessage, DeviceInfo,
 };
 pub use exception_block::{ExceptionBlock, ExceptionBlockParser, FeedResult};
+pub use heap_diff::{ClassHeapDelta, HeapDiff};
+pub use instances::{
+    HeapInstance, InstanceDetails, InstanceField, InstanceSet, RetainingObject, RetainingPath,
+    RetainingSlot,
+};
+pub use leak_hints::{LeakHint, LeakTracker, DEFAULT_LEAK_WATCH_CLASSES};
 pub use network::{
-    format_bytes, format_duration_ms, ConnectionInfo, HttpProfileEntry, HttpProfileEntryDetail,
-    HttpProfileEvent, NetworkTiming, SocketEntry,
+    format_bytes, format_duration_ms, timing_segments, ConnectionInfo, HttpProfileEntry,
+    HttpProfileEntryDetail, HttpProfileEvent, NetworkTiming, SocketEntry, TimingPhase,
+    TimingSegment,
 };
+pub use perf_baseline::{MetricDelta, PerfBaseline, PerfComparison, PerfMetric, PerfThresholds};
+pub use perf_export::PerfCapture;
 pub use performance::{
-    AllocationProfile, ClassHeapStats, FramePhases, FrameTiming, GcEvent, MemorySample,
-    MemoryUsage, PerformanceStats, RingBuffer, FRAME_BUDGET_120FPS_MICROS,
-    FRAME_BUDGET_60FPS_MICROS,
+    frame_budget_micros, AllocationProfile, ClassHeapStats, FramePhase, FramePhases, FrameTiming,
+    GcEvent, MemorySample, MemoryUsage, PerformanceStats, RebuildEntry, RebuildStats,
+    RebuiltWidgets, RingBuffer, FRAME_BUDGET_120FPS_MICROS, FRAME_BUDGET_60FPS_MICROS,
 };
+pub use profiler::{CallTree, CallTreeDirection, CallTreeNode, CpuProfile, ProfileFunction};
 pub use stack_trace::{
     detect_format, is_package_path, is_project_path, ParsedStackTrace, StackFrame, StackTraceFormat,
 };
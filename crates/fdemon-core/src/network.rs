@@ -18,13 +18,17 @@
 //! - **`NetworkTiming` is computed**: Computed on-demand from the event list, not
 //!   stored as separate fields, to avoid redundancy.
 
+use serde::{Deserialize, Serialize};
+
 // ── HttpProfileEntry ──────────────────────────────────────────────────────────
 
 /// Summary of a single HTTP request from the VM Service HTTP profile.
 ///
 /// Returned by `ext.dart.io.getHttpProfile`. Does NOT include request/response
 /// bodies — those require a separate `getHttpProfileRequest` call.
-#[derive(Debug, Clone)]
+///
+/// Serializable so that history can be spilled to disk.
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HttpProfileEntry {
     /// Unique request identifier (String in protocol v4.0+).
     pub id: String,
@@ -172,8 +176,12 @@ pub struct ConnectionInfo {
 pub struct NetworkTiming {
     /// Total duration in milliseconds.
     pub total_ms: f64,
+    /// Time from request start to the DNS lookup completing, if reported.
+    pub dns_ms: Option<f64>,
     /// Time from first event to "connection established" event.
     pub connection_ms: Option<f64>,
+    /// Time spent in the TLS handshake, if reported.
+    pub tls_ms: Option<f64>,
     /// Time spent waiting for the first response byte.
     pub waiting_ms: Option<f64>,
     /// Time receiving the response body.
@@ -186,18 +194,19 @@ impl NetworkTiming {
         let total_ms = entry.duration_ms().unwrap_or(0.0);
 
         // Find known event timestamps for breakdown
-        let connection_ts = events
-            .iter()
-            .find(|e| e.event.contains("connection"))
-            .map(|e| e.timestamp_us);
-        let response_start_ts = events
-            .iter()
-            .find(|e| e.event.contains("response"))
-            .map(|e| e.timestamp_us);
+        let dns_ts = find_event_ts(events, DNS_EVENT_KEYWORDS);
+        let connection_ts = find_event_ts(events, CONNECTION_EVENT_KEYWORDS);
+        let tls_ts = find_event_ts(events, TLS_EVENT_KEYWORDS);
+        let response_start_ts = find_event_ts(events, RESPONSE_EVENT_KEYWORDS);
 
+        let dns_ms = dns_ts.map(|ts| (ts - entry.start_time_us) as f64 / 1000.0);
         let connection_ms = connection_ts.map(|ts| (ts - entry.start_time_us) as f64 / 1000.0);
-        let waiting_ms = response_start_ts.map(|rs| {
+        let tls_ms = tls_ts.map(|ts| {
             let base = connection_ts.unwrap_or(entry.start_time_us);
+            (ts - base) as f64 / 1000.0
+        });
+        let waiting_ms = response_start_ts.map(|rs| {
+            let base = tls_ts.or(connection_ts).unwrap_or(entry.start_time_us);
             (rs - base) as f64 / 1000.0
         });
         let receiving_ms = entry
@@ -206,13 +215,141 @@ impl NetworkTiming {
 
         Self {
             total_ms,
+            dns_ms,
             connection_ms,
+            tls_ms,
             waiting_ms,
             receiving_ms,
         }
     }
 }
 
+// ── Waterfall segments ────────────────────────────────────────────────────────
+
+/// A phase of the request lifecycle, as plotted in the waterfall timeline.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimingPhase {
+    /// Host name resolution.
+    Dns,
+    /// TCP connection setup.
+    Connect,
+    /// TLS handshake.
+    Tls,
+    /// Request sent, waiting for the first response byte.
+    Wait,
+    /// Receiving the response body.
+    Receive,
+}
+
+impl TimingPhase {
+    /// Short display label for legends and tooltips.
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Dns => "DNS",
+            Self::Connect => "Connect",
+            Self::Tls => "TLS",
+            Self::Wait => "Wait",
+            Self::Receive => "Receive",
+        }
+    }
+}
+
+/// A contiguous span of one [`TimingPhase`] on the shared waterfall time axis.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimingSegment {
+    /// Which lifecycle phase this span covers.
+    pub phase: TimingPhase,
+    /// Segment start (microseconds since Unix epoch).
+    pub start_us: i64,
+    /// Segment end (microseconds since Unix epoch).
+    pub end_us: i64,
+}
+
+/// Split a request into consecutive phase segments from its timeline events.
+///
+/// Each phase boundary is the first event whose name matches the phase
+/// keywords; phases whose events are missing (or out of order) are skipped so
+/// the remaining segments still tile `start_time_us..end_time_us` without gaps.
+/// Returns an empty list when there are no events, in which case callers
+/// should draw the request as a single undifferentiated bar.
+pub fn timing_segments(
+    events: &[HttpProfileEvent],
+    entry: &HttpProfileEntry,
+) -> Vec<TimingSegment> {
+    if events.is_empty() {
+        return Vec::new();
+    }
+
+    let boundaries = [
+        (TimingPhase::Dns, find_event_ts(events, DNS_EVENT_KEYWORDS)),
+        (
+            TimingPhase::Connect,
+            find_event_ts(events, CONNECTION_EVENT_KEYWORDS),
+        ),
+        (TimingPhase::Tls, find_event_ts(events, TLS_EVENT_KEYWORDS)),
+        (
+            TimingPhase::Wait,
+            find_event_ts(events, RESPONSE_EVENT_KEYWORDS),
+        ),
+    ];
+
+    let mut segments = Vec::new();
+    let mut cursor = entry.start_time_us;
+    for (phase, ts) in boundaries {
+        if let Some(ts) = ts.filter(|&ts| ts > cursor) {
+            segments.push(TimingSegment {
+                phase,
+                start_us: cursor,
+                end_us: ts,
+            });
+            cursor = ts;
+        }
+    }
+
+    if let Some(end) = entry.end_time_us.filter(|&end| end > cursor) {
+        // Without a "response" event the tail is still time spent waiting.
+        let phase = if segments
+            .last()
+            .is_some_and(|s| s.phase == TimingPhase::Wait)
+        {
+            TimingPhase::Receive
+        } else {
+            TimingPhase::Wait
+        };
+        segments.push(TimingSegment {
+            phase,
+            start_us: cursor,
+            end_us: end,
+        });
+    }
+
+    segments
+}
+
+/// Event-name keywords marking the end of DNS resolution.
+const DNS_EVENT_KEYWORDS: &[&str] = &["dns", "lookup"];
+
+/// Event-name keywords marking an established connection.
+const CONNECTION_EVENT_KEYWORDS: &[&str] = &["connection"];
+
+/// Event-name keywords marking the end of the TLS handshake.
+const TLS_EVENT_KEYWORDS: &[&str] = &["tls", "handshake"];
+
+/// Event-name keywords marking the first response byte.
+const RESPONSE_EVENT_KEYWORDS: &[&str] = &["response"];
+
+/// Timestamp of the first event whose name contains any of `keywords`
+/// (case-insensitive).
+fn find_event_ts(events: &[HttpProfileEvent], keywords: &[&str]) -> Option<i64> {
+    events
+        .iter()
+        .find(|e| {
+            let name = e.event.to_lowercase();
+            keywords.iter().any(|k| name.contains(k))
+        })
+        .map(|e| e.timestamp_us)
+}
+
 // ── SocketEntry ───────────────────────────────────────────────────────────────
 
 /// A socket statistics entry from the VM Service socket profile.
@@ -407,6 +544,84 @@ mod tests {
         assert!(timing.receiving_ms.is_none());
     }
 
+    #[test]
+    fn test_network_timing_dns_and_tls() {
+        let entry = make_entry(Some(200), 1_000_000, Some(1_100_000));
+        let events = vec![
+            HttpProfileEvent {
+                event: "DNS lookup complete".to_string(),
+                timestamp_us: 1_005_000,
+            },
+            HttpProfileEvent {
+                event: "Connection established".to_string(),
+                timestamp_us: 1_020_000,
+            },
+            HttpProfileEvent {
+                event: "TLS handshake complete".to_string(),
+                timestamp_us: 1_030_000,
+            },
+            HttpProfileEvent {
+                event: "Response started".to_string(),
+                timestamp_us: 1_060_000,
+            },
+        ];
+        let timing = NetworkTiming::from_events(&events, &entry);
+        assert!((timing.dns_ms.unwrap() - 5.0).abs() < f64::EPSILON);
+        assert!((timing.connection_ms.unwrap() - 20.0).abs() < f64::EPSILON);
+        assert!((timing.tls_ms.unwrap() - 10.0).abs() < f64::EPSILON);
+        assert!((timing.waiting_ms.unwrap() - 30.0).abs() < f64::EPSILON);
+        assert!((timing.receiving_ms.unwrap() - 40.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_timing_segments_tile_request() {
+        let entry = make_entry(Some(200), 1_000_000, Some(1_100_000));
+        let events = vec![
+            HttpProfileEvent {
+                event: "connection established".to_string(),
+                timestamp_us: 1_020_000,
+            },
+            HttpProfileEvent {
+                event: "response started".to_string(),
+                timestamp_us: 1_060_000,
+            },
+        ];
+        let segments = timing_segments(&events, &entry);
+        let phases: Vec<TimingPhase> = segments.iter().map(|s| s.phase).collect();
+        assert_eq!(
+            phases,
+            vec![
+                TimingPhase::Connect,
+                TimingPhase::Wait,
+                TimingPhase::Receive
+            ]
+        );
+        assert_eq!(segments[0].start_us, 1_000_000);
+        assert_eq!(segments[2].end_us, 1_100_000);
+        for pair in segments.windows(2) {
+            assert_eq!(pair[0].end_us, pair[1].start_us);
+        }
+    }
+
+    #[test]
+    fn test_timing_segments_without_response_event_ends_in_wait() {
+        let entry = make_entry(Some(200), 0, Some(50_000));
+        let events = vec![HttpProfileEvent {
+            event: "connection established".to_string(),
+            timestamp_us: 10_000,
+        }];
+        let segments = timing_segments(&events, &entry);
+        assert_eq!(segments.len(), 2);
+        assert_eq!(segments[1].phase, TimingPhase::Wait);
+        assert_eq!(segments[1].end_us, 50_000);
+    }
+
+    #[test]
+    fn test_timing_segments_no_events_is_empty() {
+        let entry = make_entry(Some(200), 0, Some(50_000));
+        assert!(timing_segments(&[], &entry).is_empty());
+    }
+
     #[test]
     fn test_format_bytes() {
         assert_eq!(format_bytes(0), "0 B");
@@ -0,0 +1,369 @@
+//! Performance baselines and regression comparison.
+//!
+//! A [`PerfBaseline`] summarises a [`PerfCapture`] — average FPS, p95/p99
+//! frame time, jank count and peak Dart heap — so a scripted scenario can be
+//! recorded once and later runs compared against it. [`PerfBaseline::compare`]
+//! produces a [`PerfComparison`] with one [`MetricDelta`] per metric, each
+//! flagged as regressed when it is worse than the baseline by more than the
+//! matching [`PerfThresholds`] limit.
+
+use chrono::{DateTime, Local};
+use serde::{Deserialize, Serialize};
+
+use crate::perf_export::PerfCapture;
+
+/// Summary metrics of one recorded run.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PerfBaseline {
+    /// Baseline name (also its file name).
+    pub name: String,
+    /// When the baseline was recorded.
+    pub created: DateTime<Local>,
+    /// Device the run was recorded on.
+    pub device: String,
+    /// Number of frames the metrics are based on.
+    pub frames: usize,
+    /// Average frames per second over the captured window.
+    pub fps: Option<f64>,
+    /// 95th percentile frame time in milliseconds.
+    pub p95_frame_ms: Option<f64>,
+    /// 99th percentile frame time in milliseconds.
+    pub p99_frame_ms: Option<f64>,
+    /// Number of frames over the capture's frame budget.
+    pub jank_count: u32,
+    /// Largest Dart heap usage seen, in bytes.
+    pub heap_peak_bytes: Option<u64>,
+}
+
+/// Maximum allowed regressions before a comparison fails.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PerfThresholds {
+    /// Allowed FPS drop, in percent of the baseline.
+    pub fps_drop_pct: f64,
+    /// Allowed p95/p99 frame time increase, in percent of the baseline.
+    pub frame_time_increase_pct: f64,
+    /// Allowed increase in janky frames.
+    pub jank_increase: u32,
+    /// Allowed peak heap increase, in percent of the baseline.
+    pub heap_increase_pct: f64,
+}
+
+impl Default for PerfThresholds {
+    fn default() -> Self {
+        Self {
+            fps_drop_pct: 10.0,
+            frame_time_increase_pct: 20.0,
+            jank_increase: 5,
+            heap_increase_pct: 20.0,
+        }
+    }
+}
+
+/// A compared metric.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PerfMetric {
+    Fps,
+    P95FrameTime,
+    P99FrameTime,
+    JankCount,
+    HeapPeak,
+}
+
+impl PerfMetric {
+    /// Short display label.
+    pub fn label(self) -> &'static str {
+        match self {
+            PerfMetric::Fps => "FPS",
+            PerfMetric::P95FrameTime => "P95",
+            PerfMetric::P99FrameTime => "P99",
+            PerfMetric::JankCount => "Jank",
+            PerfMetric::HeapPeak => "Heap",
+        }
+    }
+
+    /// Stable identifier used in JSON output.
+    pub fn key(self) -> &'static str {
+        match self {
+            PerfMetric::Fps => "fps",
+            PerfMetric::P95FrameTime => "p95_frame_ms",
+            PerfMetric::P99FrameTime => "p99_frame_ms",
+            PerfMetric::JankCount => "jank_count",
+            PerfMetric::HeapPeak => "heap_peak_bytes",
+        }
+    }
+}
+
+/// One metric of a comparison.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MetricDelta {
+    /// Which metric this is.
+    pub metric: PerfMetric,
+    /// Baseline value.
+    pub baseline: f64,
+    /// Value of the current run.
+    pub current: f64,
+    /// Whether the change exceeds its threshold in the bad direction.
+    pub regressed: bool,
+}
+
+impl MetricDelta {
+    /// `current - baseline`.
+    pub fn delta(&self) -> f64 {
+        self.current - self.baseline
+    }
+
+    /// Change relative to the baseline, in percent. `None` for a zero baseline.
+    pub fn delta_pct(&self) -> Option<f64> {
+        (self.baseline != 0.0).then(|| self.delta() / self.baseline * 100.0)
+    }
+}
+
+/// Result of comparing a run against a baseline.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PerfComparison {
+    /// Name of the baseline compared against.
+    pub baseline_name: String,
+    /// Metrics present in both runs, in [`PerfMetric`] order.
+    pub metrics: Vec<MetricDelta>,
+}
+
+impl PerfComparison {
+    /// Whether any metric exceeded its threshold.
+    pub fn has_regressions(&self) -> bool {
+        self.metrics.iter().any(|m| m.regressed)
+    }
+
+    /// The comparison as a JSON object, for headless output.
+    pub fn to_json(&self) -> serde_json::Value {
+        let metrics: serde_json::Map<String, serde_json::Value> = self
+            .metrics
+            .iter()
+            .map(|m| {
+                (
+                    m.metric.key().to_string(),
+                    serde_json::json!({
+                        "baseline": m.baseline,
+                        "current": m.current,
+                        "delta": m.delta(),
+                        "regressed": m.regressed,
+                    }),
+                )
+            })
+            .collect();
+        serde_json::json!({
+            "baseline": self.baseline_name,
+            "regressed": self.has_regressions(),
+            "metrics": metrics,
+        })
+    }
+}
+
+impl PerfBaseline {
+    /// Summarise `capture` under `name`. FPS is the frame rate between the
+    /// first and last captured frame.
+    pub fn from_capture(name: &str, capture: &PerfCapture) -> Self {
+        let frame_times: Vec<f64> = capture.frames.iter().map(|f| f.elapsed_ms()).collect();
+        let fps = match (capture.frames.first(), capture.frames.last()) {
+            (Some(first), Some(last)) if capture.frames.len() > 1 => {
+                let secs = (last.timestamp - first.timestamp).num_milliseconds() as f64 / 1000.0;
+                (secs > 0.0).then(|| (capture.frames.len() - 1) as f64 / secs)
+            }
+            _ => None,
+        };
+        Self {
+            name: name.to_string(),
+            created: Local::now(),
+            device: capture.device.clone(),
+            frames: capture.frames.len(),
+            fps,
+            p95_frame_ms: percentile(&frame_times, 95.0),
+            p99_frame_ms: percentile(&frame_times, 99.0),
+            jank_count: capture
+                .frames
+                .iter()
+                .filter(|f| f.is_janky_at(capture.budget_micros()))
+                .count() as u32,
+            heap_peak_bytes: capture.memory.iter().map(|m| m.dart_heap).max(),
+        }
+    }
+
+    /// Compare `current` against this baseline. Metrics missing from either
+    /// run are left out.
+    pub fn compare(&self, current: &PerfBaseline, thresholds: &PerfThresholds) -> PerfComparison {
+        let pairs = [
+            (PerfMetric::Fps, self.fps, current.fps),
+            (
+                PerfMetric::P95FrameTime,
+                self.p95_frame_ms,
+                current.p95_frame_ms,
+            ),
+            (
+                PerfMetric::P99FrameTime,
+                self.p99_frame_ms,
+                current.p99_frame_ms,
+            ),
+            (
+                PerfMetric::JankCount,
+                Some(f64::from(self.jank_count)),
+                Some(f64::from(current.jank_count)),
+            ),
+            (
+                PerfMetric::HeapPeak,
+                self.heap_peak_bytes.map(|b| b as f64),
+                current.heap_peak_bytes.map(|b| b as f64),
+            ),
+        ];
+
+        let metrics = pairs
+            .into_iter()
+            .filter_map(|(metric, baseline, current)| {
+                let (baseline, current) = (baseline?, current?);
+                let regressed = match metric {
+                    PerfMetric::Fps => current < baseline * (1.0 - thresholds.fps_drop_pct / 100.0),
+                    PerfMetric::P95FrameTime | PerfMetric::P99FrameTime => {
+                        current > baseline * (1.0 + thresholds.frame_time_increase_pct / 100.0)
+                    }
+                    PerfMetric::JankCount => {
+                        current > baseline + f64::from(thresholds.jank_increase)
+                    }
+                    PerfMetric::HeapPeak => {
+                        current > baseline * (1.0 + thresholds.heap_increase_pct / 100.0)
+                    }
+                };
+                Some(MetricDelta {
+                    metric,
+                    baseline,
+                    current,
+                    regressed,
+                })
+            })
+            .collect();
+
+        PerfComparison {
+            baseline_name: self.name.clone(),
+            metrics,
+        }
+    }
+}
+
+/// Nearest-rank percentile of `values`, `None` when empty.
+fn percentile(values: &[f64], pct: f64) -> Option<f64> {
+    if values.is_empty() {
+        return None;
+    }
+    let mut sorted = values.to_vec();
+    sorted.sort_by(|a, b| a.total_cmp(b));
+    let index = ((pct / 100.0) * (sorted.len() - 1) as f64).round() as usize;
+    Some(sorted[index.min(sorted.len() - 1)])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::performance::{FrameTiming, MemorySample};
+    use chrono::TimeZone;
+
+    fn frame(number: u64, millis: i64, elapsed_micros: u64) -> FrameTiming {
+        FrameTiming {
+            number,
+            build_micros: elapsed_micros / 2,
+            raster_micros: elapsed_micros / 2,
+            elapsed_micros,
+            timestamp: Local.timestamp_millis_opt(millis).unwrap(),
+            phases: None,
+            shader_compilation: false,
+        }
+    }
+
+    fn baseline(fps: f64, p95: f64, jank: u32, heap: u64) -> PerfBaseline {
+        PerfBaseline {
+            name: "scroll".to_string(),
+            created: Local.timestamp_opt(0, 0).unwrap(),
+            device: "Pixel".to_string(),
+            frames: 100,
+            fps: Some(fps),
+            p95_frame_ms: Some(p95),
+            p99_frame_ms: None,
+            jank_count: jank,
+            heap_peak_bytes: Some(heap),
+        }
+    }
+
+    #[test]
+    fn test_from_capture_metrics() {
+        // 11 frames over one second, the last one janky.
+        let mut frames: Vec<_> = (0..10).map(|i| frame(i, i as i64 * 100, 8_000)).collect();
+        frames.push(frame(10, 1_000, 40_000));
+        let capture = PerfCapture {
+            device: "Pixel".to_string(),
+            frames,
+            memory: vec![
+                MemorySample {
+                    dart_heap: 3_000,
+                    dart_native: 0,
+                    raster_cache: 0,
+                    allocated: 0,
+                    rss: 0,
+                    timestamp: Local.timestamp_opt(0, 0).unwrap(),
+                },
+                MemorySample {
+                    dart_heap: 5_000,
+                    dart_native: 0,
+                    raster_cache: 0,
+                    allocated: 0,
+                    rss: 0,
+                    timestamp: Local.timestamp_opt(1, 0).unwrap(),
+                },
+            ],
+            gc: vec![],
+            frame_budget_micros: None,
+        };
+
+        let b = PerfBaseline::from_capture("scroll", &capture);
+        assert_eq!(b.frames, 11);
+        assert!((b.fps.unwrap() - 10.0).abs() < 1e-9);
+        assert_eq!(b.p95_frame_ms, Some(40.0));
+        assert_eq!(b.p99_frame_ms, Some(40.0));
+        assert_eq!(b.jank_count, 1);
+        assert_eq!(b.heap_peak_bytes, Some(5_000));
+    }
+
+    #[test]
+    fn test_from_empty_capture() {
+        let b = PerfBaseline::from_capture("empty", &PerfCapture::default());
+        assert_eq!(b.fps, None);
+        assert_eq!(b.p95_frame_ms, None);
+        assert_eq!(b.heap_peak_bytes, None);
+    }
+
+    #[test]
+    fn test_compare_within_thresholds() {
+        let base = baseline(60.0, 10.0, 2, 1_000);
+        let current = baseline(56.0, 11.0, 6, 1_100);
+        let cmp = base.compare(&current, &PerfThresholds::default());
+        assert!(!cmp.has_regressions());
+        assert_eq!(cmp.metrics.len(), 4, "p99 is missing from both runs");
+        assert_eq!(cmp.metrics[0].delta(), -4.0);
+    }
+
+    #[test]
+    fn test_compare_flags_regressions() {
+        let base = baseline(60.0, 10.0, 2, 1_000);
+        let current = baseline(50.0, 12.5, 8, 1_300);
+        let cmp = base.compare(&current, &PerfThresholds::default());
+        assert!(cmp.has_regressions());
+        assert!(cmp.metrics.iter().all(|m| m.regressed));
+
+        let json = cmp.to_json();
+        assert_eq!(json["regressed"], true);
+        assert_eq!(json["metrics"]["fps"]["baseline"], 60.0);
+    }
+
+    #[test]
+    fn test_improvements_never_regress() {
+        let base = baseline(50.0, 20.0, 10, 2_000);
+        let current = baseline(60.0, 8.0, 0, 500);
+        let cmp = base.compare(&current, &PerfThresholds::default());
+        assert!(!cmp.has_regressions());
+    }
+}
@@ -0,0 +1,409 @@
+//! Performance data export formats.
+//!
+//! A [`PerfCapture`] is a copy of the frame timings, memory samples and GC
+//! events buffered for a session. It can be written as:
+//!
+//! - **CSV** — one file per series ([`PerfCapture::frames_csv`],
+//!   [`PerfCapture::memory_csv`], [`PerfCapture::gc_csv`]).
+//! - **JSON** — all series plus a summary ([`PerfCapture::to_json`]).
+//! - **Chrome trace events** — loadable in Perfetto or `chrome://tracing`
+//!   ([`PerfCapture::to_chrome_trace`]). Frames become slices on a UI and a
+//!   Raster track, memory samples become counters, and GC events become
+//!   instant events.
+
+use chrono::{DateTime, Local, SecondsFormat};
+use serde_json::{json, Value};
+
+use crate::performance::{FrameTiming, GcEvent, MemorySample, FRAME_BUDGET_60FPS_MICROS};
+
+/// Trace thread id of the UI thread track.
+const UI_TID: u64 = 1;
+/// Trace thread id of the raster thread track.
+const RASTER_TID: u64 = 2;
+/// Trace thread id of the GC track.
+const GC_TID: u64 = 3;
+
+/// Performance data captured from one session.
+#[derive(Debug, Clone, Default)]
+pub struct PerfCapture {
+    /// Device or session name, used as the trace process name.
+    pub device: String,
+    /// Frame timings, oldest first.
+    pub frames: Vec<FrameTiming>,
+    /// Memory samples, oldest first.
+    pub memory: Vec<MemorySample>,
+    /// Major GC events, oldest first.
+    pub gc: Vec<GcEvent>,
+    /// Frame budget used to flag janky frames. `None` means 60 FPS.
+    pub frame_budget_micros: Option<u64>,
+}
+
+impl PerfCapture {
+    /// Whether the capture holds no data at all.
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty() && self.memory.is_empty() && self.gc.is_empty()
+    }
+
+    /// Frame budget used to flag janky frames.
+    pub fn budget_micros(&self) -> u64 {
+        self.frame_budget_micros
+            .unwrap_or(FRAME_BUDGET_60FPS_MICROS)
+    }
+
+    /// Whether `frame` exceeded the capture's frame budget.
+    fn janky(&self, frame: &FrameTiming) -> bool {
+        frame.is_janky_at(self.budget_micros())
+    }
+
+    /// Earliest and latest timestamps across all series.
+    pub fn window(&self) -> Option<(DateTime<Local>, DateTime<Local>)> {
+        let timestamps = self
+            .frames
+            .iter()
+            .map(|f| f.timestamp)
+            .chain(self.memory.iter().map(|m| m.timestamp))
+            .chain(self.gc.iter().map(|g| g.timestamp));
+        timestamps.fold(None, |window, t| match window {
+            None => Some((t, t)),
+            Some((start, end)) => Some((start.min(t), end.max(t))),
+        })
+    }
+
+    /// Frame timings as CSV. Phase columns are empty for frames without a
+    /// phase breakdown.
+    pub fn frames_csv(&self) -> String {
+        let mut out = String::from(
+            "number,timestamp,elapsed_us,ui_us,raster_us,build_us,layout_us,paint_us,janky,shader_compilation\n",
+        );
+        for frame in &self.frames {
+            let (build, layout, paint) = match &frame.phases {
+                Some(p) => (
+                    p.build_micros.to_string(),
+                    p.layout_micros.to_string(),
+                    p.paint_micros.to_string(),
+                ),
+                None => Default::default(),
+            };
+            out.push_str(&format!(
+                "{},{},{},{},{},{},{},{},{},{}\n",
+                frame.number,
+                rfc3339(&frame.timestamp),
+                frame.elapsed_micros,
+                frame.build_micros,
+                frame.raster_micros,
+                build,
+                layout,
+                paint,
+                self.janky(frame),
+                frame.has_shader_compilation(),
+            ));
+        }
+        out
+    }
+
+    /// Memory samples as CSV, in bytes.
+    pub fn memory_csv(&self) -> String {
+        let mut out = String::from("timestamp,dart_heap,dart_native,raster_cache,allocated,rss\n");
+        for sample in &self.memory {
+            out.push_str(&format!(
+                "{},{},{},{},{},{}\n",
+                rfc3339(&sample.timestamp),
+                sample.dart_heap,
+                sample.dart_native,
+                sample.raster_cache,
+                sample.allocated,
+                sample.rss,
+            ));
+        }
+        out
+    }
+
+    /// GC events as CSV.
+    pub fn gc_csv(&self) -> String {
+        let mut out = String::from("timestamp,gc_type,reason,isolate_id\n");
+        for event in &self.gc {
+            out.push_str(&format!(
+                "{},{},{},{}\n",
+                rfc3339(&event.timestamp),
+                csv_field(&event.gc_type),
+                csv_field(event.reason.as_deref().unwrap_or("")),
+                csv_field(event.isolate_id.as_deref().unwrap_or("")),
+            ));
+        }
+        out
+    }
+
+    /// All series plus a summary as one JSON document.
+    pub fn to_json(&self) -> Value {
+        let window = self.window();
+        let janky = self.frames.iter().filter(|f| self.janky(f)).count();
+        json!({
+            "device": self.device,
+            "window_start": window.map(|(start, _)| rfc3339(&start)),
+            "window_end": window.map(|(_, end)| rfc3339(&end)),
+            "summary": {
+                "frames": self.frames.len(),
+                "frame_budget_us": self.budget_micros(),
+                "janky_frames": janky,
+                "memory_samples": self.memory.len(),
+                "gc_events": self.gc.len(),
+            },
+            "frames": self.frames.iter().map(|f| json!({
+                "number": f.number,
+                "timestamp": rfc3339(&f.timestamp),
+                "elapsed_us": f.elapsed_micros,
+                "ui_us": f.build_micros,
+                "raster_us": f.raster_micros,
+                "phases": f.phases.as_ref().map(|p| json!({
+                    "build_us": p.build_micros,
+                    "layout_us": p.layout_micros,
+                    "paint_us": p.paint_micros,
+                    "raster_us": p.raster_micros,
+                })),
+                "janky": self.janky(f),
+                "shader_compilation": f.has_shader_compilation(),
+            })).collect::<Vec<_>>(),
+            "memory": self.memory.iter().map(|m| json!({
+                "timestamp": rfc3339(&m.timestamp),
+                "dart_heap": m.dart_heap,
+                "dart_native": m.dart_native,
+                "raster_cache": m.raster_cache,
+                "allocated": m.allocated,
+                "rss": m.rss,
+            })).collect::<Vec<_>>(),
+            "gc": self.gc.iter().map(|g| json!({
+                "timestamp": rfc3339(&g.timestamp),
+                "gc_type": g.gc_type,
+                "reason": g.reason,
+                "isolate_id": g.isolate_id,
+            })).collect::<Vec<_>>(),
+        })
+    }
+
+    /// Chrome trace-event JSON (`{"traceEvents": [...]}`).
+    ///
+    /// Frame timestamps mark when the frame finished, so each frame slice
+    /// ends there: the UI slice starts `elapsed` earlier and the raster
+    /// slice ends at the timestamp.
+    pub fn to_chrome_trace(&self) -> Value {
+        let mut events = vec![
+            metadata("process_name", 0, json!({ "name": self.device })),
+            metadata("thread_name", UI_TID, json!({ "name": "UI" })),
+            metadata("thread_name", RASTER_TID, json!({ "name": "Raster" })),
+            metadata("thread_name", GC_TID, json!({ "name": "GC" })),
+        ];
+
+        for frame in &self.frames {
+            let end = frame.timestamp.timestamp_micros();
+            let start = end - frame.elapsed_micros as i64;
+            events.push(json!({
+                "name": format!("Frame #{}", frame.number),
+                "cat": "frame",
+                "ph": "X",
+                "pid": 1,
+                "tid": UI_TID,
+                "ts": start,
+                "dur": frame.build_micros,
+                "args": {
+                    "elapsed_ms": frame.elapsed_ms(),
+                    "janky": self.janky(frame),
+                    "shader_compilation": frame.has_shader_compilation(),
+                },
+            }));
+            if let Some(phases) = &frame.phases {
+                let mut ts = start;
+                for (name, dur) in [
+                    ("Build", phases.build_micros),
+                    ("Layout", phases.layout_micros),
+                    ("Paint", phases.paint_micros),
+                ] {
+                    events.push(slice(name, UI_TID, ts, dur));
+                    ts += dur as i64;
+                }
+            }
+            events.push(slice(
+                &format!("Raster #{}", frame.number),
+                RASTER_TID,
+                end - frame.raster_micros as i64,
+                frame.raster_micros,
+            ));
+        }
+
+        for sample in &self.memory {
+            events.push(json!({
+                "name": "Memory",
+                "cat": "memory",
+                "ph": "C",
+                "pid": 1,
+                "ts": sample.timestamp.timestamp_micros(),
+                "args": {
+                    "dart_heap": sample.dart_heap,
+                    "dart_native": sample.dart_native,
+                    "raster_cache": sample.raster_cache,
+                    "rss": sample.rss,
+                },
+            }));
+        }
+
+        for event in &self.gc {
+            events.push(json!({
+                "name": event.gc_type,
+                "cat": "gc",
+                "ph": "i",
+                "s": "t",
+                "pid": 1,
+                "tid": GC_TID,
+                "ts": event.timestamp.timestamp_micros(),
+                "args": { "reason": event.reason },
+            }));
+        }
+
+        json!({ "traceEvents": events, "displayTimeUnit": "ms" })
+    }
+}
+
+fn rfc3339(timestamp: &DateTime<Local>) -> String {
+    timestamp.to_rfc3339_opts(SecondsFormat::Micros, false)
+}
+
+/// Quote a CSV field if it contains a delimiter, quote or newline.
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+fn metadata(name: &str, tid: u64, args: Value) -> Value {
+    json!({ "name": name, "ph": "M", "pid": 1, "tid": tid, "args": args })
+}
+
+fn slice(name: &str, tid: u64, ts: i64, dur: u64) -> Value {
+    json!({
+        "name": name,
+        "cat": "frame",
+        "ph": "X",
+        "pid": 1,
+        "tid": tid,
+        "ts": ts,
+        "dur": dur,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::performance::{FramePhases, FRAME_BUDGET_120FPS_MICROS};
+    use chrono::TimeZone;
+
+    fn at(secs: i64) -> DateTime<Local> {
+        Local.timestamp_opt(1_700_000_000 + secs, 0).unwrap()
+    }
+
+    fn capture() -> PerfCapture {
+        PerfCapture {
+            device: "Pixel 7".to_string(),
+            frames: vec![
+                FrameTiming {
+                    number: 1,
+                    build_micros: 6_000,
+                    raster_micros: 4_000,
+                    elapsed_micros: 10_000,
+                    timestamp: at(1),
+                    phases: None,
+                    shader_compilation: false,
+                },
+                FrameTiming {
+                    number: 2,
+                    build_micros: 15_000,
+                    raster_micros: 5_000,
+                    elapsed_micros: 20_000,
+                    timestamp: at(2),
+                    phases: Some(FramePhases {
+                        build_micros: 9_000,
+                        layout_micros: 4_000,
+                        paint_micros: 2_000,
+                        raster_micros: 5_000,
+                        shader_compilation: false,
+                    }),
+                    shader_compilation: false,
+                },
+            ],
+            memory: vec![MemorySample {
+                dart_heap: 1_000,
+                dart_native: 200,
+                raster_cache: 0,
+                allocated: 2_000,
+                rss: 5_000,
+                timestamp: at(0),
+            }],
+            gc: vec![GcEvent {
+                gc_type: "MarkSweep".to_string(),
+                reason: Some("old space, full".to_string()),
+                isolate_id: None,
+                timestamp: at(3),
+            }],
+            frame_budget_micros: None,
+        }
+    }
+
+    #[test]
+    fn test_window_spans_all_series() {
+        assert_eq!(capture().window(), Some((at(0), at(3))));
+        assert_eq!(PerfCapture::default().window(), None);
+    }
+
+    #[test]
+    fn test_csv_output() {
+        let capture = capture();
+        let frames = capture.frames_csv();
+        let lines: Vec<&str> = frames.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert!(lines[1].starts_with("1,"));
+        assert!(lines[1].ends_with(",10000,6000,4000,,,,false,false"));
+        assert!(lines[2].ends_with(",20000,15000,5000,9000,4000,2000,true,false"));
+
+        assert!(capture.memory_csv().ends_with(",1000,200,0,2000,5000\n"));
+        assert!(capture
+            .gc_csv()
+            .contains(",MarkSweep,\"old space, full\",\n"));
+    }
+
+    #[test]
+    fn test_json_summary() {
+        let value = capture().to_json();
+        assert_eq!(value["device"], "Pixel 7");
+        assert_eq!(value["summary"]["frames"], 2);
+        assert_eq!(value["summary"]["janky_frames"], 1);
+        assert_eq!(value["frames"][1]["phases"]["layout_us"], 4_000);
+        assert!(value["frames"][0]["phases"].is_null());
+
+        // At 120 FPS the 10ms frame misses its budget too.
+        let capture = PerfCapture {
+            frame_budget_micros: Some(FRAME_BUDGET_120FPS_MICROS),
+            ..capture()
+        };
+        let value = capture.to_json();
+        assert_eq!(value["summary"]["frame_budget_us"], 8_333);
+        assert_eq!(value["summary"]["janky_frames"], 2);
+    }
+
+    #[test]
+    fn test_chrome_trace_events() {
+        let trace = capture().to_chrome_trace();
+        let events = trace["traceEvents"].as_array().unwrap();
+        let named = |name: &str| events.iter().find(|e| e["name"] == name).unwrap();
+
+        let end = at(2).timestamp_micros();
+        let frame = named("Frame #2");
+        assert_eq!(frame["ph"], "X");
+        assert_eq!(frame["ts"], end - 20_000);
+        assert_eq!(frame["dur"], 15_000);
+        assert_eq!(named("Layout")["ts"], end - 20_000 + 9_000);
+        assert_eq!(named("Raster #2")["ts"], end - 5_000);
+        assert_eq!(named("Memory")["ph"], "C");
+        assert_eq!(named("MarkSweep")["ph"], "i");
+        assert_eq!(named("process_name")["args"]["name"], "Pixel 7");
+    }
+}
@@ -8,7 +8,9 @@
 //! - `fdemon-app` (aggregation, session state)
 
 use std::cmp::Reverse;
-use std::collections::VecDeque;
+use std::collections::{HashMap, VecDeque};
+
+use crate::widget_tree::CreationLocation;
 
 // ── MemoryUsage ──────────────────────────────────────────────────────────────
 
@@ -138,6 +140,8 @@ impl GcEvent {
 pub struct ClassHeapStats {
     /// Fully qualified class name (e.g., "dart:core/String").
     pub class_name: String,
+    /// VM Service class ID, used to list its instances with `getInstances`.
+    pub class_id: Option<String>,
     /// Library URI that defines the class.
     pub library_uri: Option<String>,
     /// Number of instances in new space.
@@ -199,6 +203,26 @@ pub const FRAME_BUDGET_60FPS_MICROS: u64 = 16_667;
 /// Budget for a single frame at 120 FPS (8.333ms).
 pub const FRAME_BUDGET_120FPS_MICROS: u64 = 8_333;
 
+/// Frame budget for a display refreshing at `refresh_rate_hz`.
+///
+/// Rates that are not positive fall back to [`FRAME_BUDGET_60FPS_MICROS`].
+pub fn frame_budget_micros(refresh_rate_hz: f64) -> u64 {
+    if refresh_rate_hz > 0.0 {
+        (1_000_000.0 / refresh_rate_hz).round() as u64
+    } else {
+        FRAME_BUDGET_60FPS_MICROS
+    }
+}
+
+/// One phase of a rendered frame.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FramePhase {
+    Build,
+    Layout,
+    Paint,
+    Raster,
+}
+
 /// Breakdown of a single frame into build/layout/paint/raster phases.
 ///
 /// Not always available — requires timeline event data from the VM service.
@@ -231,6 +255,22 @@ impl FramePhases {
     pub fn raster_ms(&self) -> f64 {
         self.raster_micros as f64 / 1000.0
     }
+
+    /// The phase that took the longest — the one to look at first when a
+    /// frame misses its budget. Ties resolve in pipeline order.
+    pub fn slowest(&self) -> FramePhase {
+        [
+            (FramePhase::Build, self.build_micros),
+            (FramePhase::Layout, self.layout_micros),
+            (FramePhase::Paint, self.paint_micros),
+            (FramePhase::Raster, self.raster_micros),
+        ]
+        .into_iter()
+        .rev()
+        .max_by_key(|(_, micros)| *micros)
+        .map(|(phase, _)| phase)
+        .unwrap_or(FramePhase::Build)
+    }
 }
 
 /// Timing data for a single Flutter UI frame.
@@ -258,8 +298,16 @@ pub struct FrameTiming {
 
 impl FrameTiming {
     /// Whether this frame exceeded the 60 FPS budget (janky).
+    ///
+    /// Prefer [`is_janky_at`](Self::is_janky_at) with the session's budget
+    /// when the display refresh rate is known.
     pub fn is_janky(&self) -> bool {
-        self.elapsed_micros > FRAME_BUDGET_60FPS_MICROS
+        self.is_janky_at(FRAME_BUDGET_60FPS_MICROS)
+    }
+
+    /// Whether this frame exceeded `budget_micros` (janky).
+    pub fn is_janky_at(&self, budget_micros: u64) -> bool {
+        self.elapsed_micros > budget_micros
     }
 
     /// Frame duration in milliseconds.
@@ -291,7 +339,7 @@ impl FrameTiming {
 pub struct PerformanceStats {
     /// Current FPS (frames per second), calculated from recent frame timings.
     pub fps: Option<f64>,
-    /// Number of janky frames in the recent window.
+    /// Number of frames in the recent window over the session's frame budget.
     pub jank_count: u32,
     /// Average frame time in milliseconds over the recent window.
     pub avg_frame_ms: Option<f64>,
@@ -314,6 +362,100 @@ impl PerformanceStats {
     }
 }
 
+// ── Widget rebuilds ──────────────────────────────────────────────────────────
+
+/// Payload of one `Flutter.RebuiltWidgets` event.
+///
+/// Flutter assigns each widget creation location a numeric id and only sends
+/// the location itself the first time the id appears, so consumers must keep
+/// the id → location mapping across events (see [`RebuildStats`]).
+#[derive(Debug, Clone, Default)]
+pub struct RebuiltWidgets {
+    /// `(location id, rebuild count)` pairs for the reported frame.
+    pub counts: Vec<(u32, u64)>,
+    /// Locations seen for the first time in this event.
+    pub new_locations: Vec<(u32, CreationLocation)>,
+}
+
+/// Rebuild counts for one widget creation location.
+#[derive(Debug, Clone)]
+pub struct RebuildEntry {
+    /// Where the rebuilt widget is constructed in source.
+    pub location: CreationLocation,
+    /// Rebuilds since tracking started (or was last reset).
+    pub total: u64,
+    /// Rebuilds in the most recent frame that rebuilt anything.
+    pub last_frame: u64,
+}
+
+/// Widget rebuild counts aggregated per creation location.
+#[derive(Debug, Clone, Default)]
+pub struct RebuildStats {
+    locations: HashMap<u32, CreationLocation>,
+    totals: HashMap<u32, u64>,
+    last_frame: HashMap<u32, u64>,
+    /// Number of `Flutter.RebuiltWidgets` events recorded.
+    pub frames: u64,
+}
+
+impl RebuildStats {
+    /// Fold one event into the running totals.
+    pub fn record(&mut self, event: RebuiltWidgets) {
+        self.locations.extend(event.new_locations);
+        self.last_frame.clear();
+        for (id, count) in event.counts {
+            *self.totals.entry(id).or_default() += count;
+            *self.last_frame.entry(id).or_default() += count;
+        }
+        self.frames += 1;
+    }
+
+    /// Clear all counts while keeping the known locations.
+    ///
+    /// Location ids stay valid for the lifetime of the isolate, so they must
+    /// survive a reset or later events could not be attributed.
+    pub fn reset_counts(&mut self) {
+        self.totals.clear();
+        self.last_frame.clear();
+        self.frames = 0;
+    }
+
+    /// Returns `true` when no rebuilds have been recorded.
+    pub fn is_empty(&self) -> bool {
+        self.totals.is_empty()
+    }
+
+    /// Return locations sorted by total rebuild count (descending).
+    pub fn top_by_total(&self, limit: usize) -> Vec<RebuildEntry> {
+        let mut entries = self.entries();
+        entries.sort_by_key(|e| (Reverse(e.total), Reverse(e.last_frame)));
+        entries.truncate(limit);
+        entries
+    }
+
+    /// Return locations sorted by rebuilds in the last frame (descending).
+    pub fn top_by_last_frame(&self, limit: usize) -> Vec<RebuildEntry> {
+        let mut entries = self.entries();
+        entries.sort_by_key(|e| (Reverse(e.last_frame), Reverse(e.total)));
+        entries.truncate(limit);
+        entries
+    }
+
+    /// Entries for every id with a known location, in unspecified order.
+    fn entries(&self) -> Vec<RebuildEntry> {
+        self.totals
+            .iter()
+            .filter_map(|(id, total)| {
+                Some(RebuildEntry {
+                    location: self.locations.get(id)?.clone(),
+                    total: *total,
+                    last_frame: self.last_frame.get(id).copied().unwrap_or(0),
+                })
+            })
+            .collect()
+    }
+}
+
 // ── RingBuffer<T> ────────────────────────────────────────────────────────────
 
 /// A fixed-capacity circular buffer that overwrites the oldest entries
@@ -366,6 +508,11 @@ impl<T> RingBuffer<T> {
         self.buf.iter()
     }
 
+    /// Iterate mutably over items from oldest to newest.
+    pub fn iter_mut(&mut self) -> impl DoubleEndedIterator<Item = &mut T> {
+        self.buf.iter_mut()
+    }
+
     /// Get the most recently pushed item.
     pub fn latest(&self) -> Option<&T> {
         self.buf.back()
@@ -474,6 +621,7 @@ mod tests {
     fn test_class_heap_stats_totals() {
         let stats = ClassHeapStats {
             class_name: "String".into(),
+            class_id: None,
             library_uri: Some("dart:core".into()),
             new_space_instances: 100,
             new_space_size: 4000,
@@ -488,6 +636,7 @@ mod tests {
     fn make_class(name: &str, instances: u64, size: u64) -> ClassHeapStats {
         ClassHeapStats {
             class_name: name.to_string(),
+            class_id: None,
             library_uri: None,
             new_space_instances: instances,
             new_space_size: size,
@@ -554,6 +703,26 @@ mod tests {
         assert!(frame.is_janky()); // 18ms > 16.667ms
     }
 
+    #[test]
+    fn test_frame_budget_for_refresh_rate() {
+        assert_eq!(frame_budget_micros(60.0), FRAME_BUDGET_60FPS_MICROS);
+        assert_eq!(frame_budget_micros(120.0), FRAME_BUDGET_120FPS_MICROS);
+        assert_eq!(frame_budget_micros(90.0), 11_111);
+        assert_eq!(frame_budget_micros(0.0), FRAME_BUDGET_60FPS_MICROS);
+
+        let frame = FrameTiming {
+            number: 1,
+            build_micros: 5_000,
+            raster_micros: 5_000,
+            elapsed_micros: 10_000,
+            timestamp: chrono::Local::now(),
+            phases: None,
+            shader_compilation: false,
+        };
+        assert!(!frame.is_janky());
+        assert!(frame.is_janky_at(frame_budget_micros(120.0)));
+    }
+
     #[test]
     fn test_frame_timing_smooth() {
         let frame = FrameTiming {
@@ -641,6 +810,22 @@ mod tests {
         assert_eq!(phases.total_micros(), 10_000);
     }
 
+    #[test]
+    fn test_frame_phases_slowest() {
+        let mut phases = FramePhases {
+            build_micros: 2_000,
+            layout_micros: 9_000,
+            paint_micros: 1_000,
+            raster_micros: 4_000,
+            shader_compilation: false,
+        };
+        assert_eq!(phases.slowest(), FramePhase::Layout);
+        phases.raster_micros = 12_000;
+        assert_eq!(phases.slowest(), FramePhase::Raster);
+        phases.build_micros = 12_000;
+        assert_eq!(phases.slowest(), FramePhase::Build);
+    }
+
     #[test]
     fn test_frame_phases_ms_conversion() {
         let phases = FramePhases {
@@ -749,4 +934,81 @@ mod tests {
         assert_eq!(buf.oldest().unwrap().dart_heap, 2_000_000);
         assert_eq!(buf.latest().unwrap().dart_heap, 4_000_000);
     }
+
+    // ── RebuildStats ────────────────────────────────
+    fn loc(name: &str, line: u32) -> CreationLocation {
+        CreationLocation {
+            file: "file:///app/lib/main.dart".into(),
+            line,
+            column: 5,
+            name: Some(name.into()),
+        }
+    }
+
+    #[test]
+    fn test_rebuild_stats_aggregates_across_events() {
+        let mut stats = RebuildStats::default();
+        stats.record(RebuiltWidgets {
+            counts: vec![(1, 2), (2, 1)],
+            new_locations: vec![(1, loc("Counter", 10)), (2, loc("Header", 20))],
+        });
+        // Second event only references known ids.
+        stats.record(RebuiltWidgets {
+            counts: vec![(2, 5)],
+            new_locations: vec![],
+        });
+
+        let top = stats.top_by_total(10);
+        assert_eq!(top.len(), 2);
+        assert_eq!(top[0].location.name.as_deref(), Some("Header"));
+        assert_eq!(top[0].total, 6);
+        assert_eq!(top[0].last_frame, 5);
+        assert_eq!(top[1].total, 2);
+        assert_eq!(top[1].last_frame, 0);
+        assert_eq!(stats.frames, 2);
+    }
+
+    #[test]
+    fn test_rebuild_stats_sort_by_last_frame() {
+        let mut stats = RebuildStats::default();
+        stats.record(RebuiltWidgets {
+            counts: vec![(1, 50)],
+            new_locations: vec![(1, loc("List", 10)), (2, loc("Tile", 20))],
+        });
+        stats.record(RebuiltWidgets {
+            counts: vec![(2, 3)],
+            new_locations: vec![],
+        });
+
+        let top = stats.top_by_last_frame(1);
+        assert_eq!(top.len(), 1);
+        assert_eq!(top[0].location.name.as_deref(), Some("Tile"));
+    }
+
+    #[test]
+    fn test_rebuild_stats_reset_keeps_locations() {
+        let mut stats = RebuildStats::default();
+        stats.record(RebuiltWidgets {
+            counts: vec![(1, 2)],
+            new_locations: vec![(1, loc("Counter", 10))],
+        });
+        stats.reset_counts();
+        assert!(stats.is_empty());
+
+        stats.record(RebuiltWidgets {
+            counts: vec![(1, 1)],
+            new_locations: vec![],
+        });
+        assert_eq!(stats.top_by_total(10)[0].total, 1);
+    }
+
+    #[test]
+    fn test_rebuild_stats_skips_unknown_ids() {
+        let mut stats = RebuildStats::default();
+        stats.record(RebuiltWidgets {
+            counts: vec![(7, 1)],
+            new_locations: vec![],
+        });
+        assert!(stats.top_by_total(10).is_empty());
+    }
 }
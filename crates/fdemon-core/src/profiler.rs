@@ -0,0 +1,257 @@
+//! CPU profiler domain types.
+//!
+//! A [`CpuProfile`] holds the samples returned by the VM Service
+//! `getCpuSamples` RPC for a recorded time window. Each sample is a call
+//! stack of indices into [`CpuProfile::functions`]. The profile is
+//! aggregated into a [`CallTree`] in one of two directions:
+//!
+//! - **Top down** — roots are entry points, children are callees.
+//! - **Bottom up** — roots are the functions that were executing, children
+//!   are their callers.
+
+use crate::stack_trace::is_project_path;
+
+/// A function that appears in at least one CPU sample.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProfileFunction {
+    /// Function name, e.g. `build`.
+    pub name: String,
+    /// Owning class or library name, if any, e.g. `_CounterState`.
+    pub owner: Option<String>,
+    /// Resolved script URL, e.g. `package:app/main.dart`. `None` for native
+    /// and VM stub frames.
+    pub url: Option<String>,
+    /// VM function kind: `Dart`, `Native`, `Stub`, `Tag`, or `Collected`.
+    pub kind: String,
+}
+
+impl ProfileFunction {
+    /// `Owner.name`, or just `name` for top-level and native functions.
+    pub fn display_name(&self) -> String {
+        match &self.owner {
+            Some(owner) if !owner.is_empty() => format!("{owner}.{}", self.name),
+            _ => self.name.clone(),
+        }
+    }
+
+    /// Whether this is Dart code from the project (not the SDK or a package).
+    pub fn is_project(&self) -> bool {
+        self.kind == "Dart" && self.url.as_deref().is_some_and(is_project_path)
+    }
+}
+
+/// CPU samples for a recorded time window.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CpuProfile {
+    /// Every function referenced by `samples`.
+    pub functions: Vec<ProfileFunction>,
+    /// Call stacks, one per sample, as indices into `functions` with the
+    /// executing (leaf) frame first.
+    pub samples: Vec<Vec<usize>>,
+    /// Interval between samples in microseconds.
+    pub sample_period_micros: u64,
+    /// Length of the recorded window in microseconds.
+    pub time_extent_micros: u64,
+}
+
+/// Direction in which samples are aggregated into a [`CallTree`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CallTreeDirection {
+    /// Entry points first; children are callees.
+    TopDown,
+    /// Executing functions first; children are callers.
+    BottomUp,
+}
+
+/// One node of a [`CallTree`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CallTreeNode {
+    /// Index into [`CpuProfile::functions`].
+    pub function: usize,
+    /// Samples in which this call path was on the stack.
+    pub total: u64,
+    /// Samples in which this call path was the executing frame.
+    pub self_samples: u64,
+    /// Child nodes, sorted by `total` (descending).
+    pub children: Vec<CallTreeNode>,
+}
+
+/// Samples aggregated by call path.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CallTree {
+    /// Root nodes, sorted by `total` (descending).
+    pub roots: Vec<CallTreeNode>,
+    /// Number of samples aggregated into the tree.
+    pub sample_count: u64,
+}
+
+impl CallTree {
+    /// Follow `path` (child indices from the roots) to a node.
+    pub fn node(&self, path: &[usize]) -> Option<&CallTreeNode> {
+        let (first, rest) = path.split_first()?;
+        let mut node = self.roots.get(*first)?;
+        for &i in rest {
+            node = node.children.get(i)?;
+        }
+        Some(node)
+    }
+}
+
+impl CpuProfile {
+    /// Sample stacks, leaf first, optionally reduced to project frames.
+    ///
+    /// With `project_only`, SDK, package and native frames are removed and
+    /// samples left with no frames are skipped.
+    fn stacks(&self, project_only: bool) -> impl Iterator<Item = Vec<usize>> + '_ {
+        self.samples.iter().filter_map(move |stack| {
+            let stack: Vec<usize> = stack
+                .iter()
+                .copied()
+                .filter(|&f| {
+                    f < self.functions.len() && (!project_only || self.functions[f].is_project())
+                })
+                .collect();
+            (!stack.is_empty()).then_some(stack)
+        })
+    }
+
+    /// Aggregate the samples into a call tree.
+    pub fn call_tree(&self, direction: CallTreeDirection, project_only: bool) -> CallTree {
+        let mut tree = CallTree::default();
+        for mut stack in self.stacks(project_only) {
+            tree.sample_count += 1;
+            // Bottom up, the root is the executing frame; top down it is the
+            // last node on the path.
+            let self_depth = match direction {
+                CallTreeDirection::TopDown => {
+                    stack.reverse();
+                    stack.len() - 1
+                }
+                CallTreeDirection::BottomUp => 0,
+            };
+            insert_path(&mut tree.roots, &stack, Some(self_depth));
+        }
+        sort_nodes(&mut tree.roots);
+        tree
+    }
+}
+
+/// Count one sample along `path`, creating nodes as needed. The node at
+/// `self_depth` also counts the sample as self time.
+fn insert_path(level: &mut Vec<CallTreeNode>, path: &[usize], self_depth: Option<usize>) {
+    let Some((&function, rest)) = path.split_first() else {
+        return;
+    };
+    let index = match level.iter().position(|n| n.function == function) {
+        Some(i) => i,
+        None => {
+            level.push(CallTreeNode {
+                function,
+                total: 0,
+                self_samples: 0,
+                children: Vec::new(),
+            });
+            level.len() - 1
+        }
+    };
+    let node = &mut level[index];
+    node.total += 1;
+    if self_depth == Some(0) {
+        node.self_samples += 1;
+    }
+    insert_path(
+        &mut node.children,
+        rest,
+        self_depth.and_then(|d| d.checked_sub(1)),
+    );
+}
+
+fn sort_nodes(nodes: &mut [CallTreeNode]) {
+    nodes.sort_by(|a, b| b.total.cmp(&a.total).then(a.function.cmp(&b.function)));
+    for node in nodes {
+        sort_nodes(&mut node.children);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn function(name: &str, url: Option<&str>) -> ProfileFunction {
+        ProfileFunction {
+            name: name.to_string(),
+            owner: None,
+            url: url.map(str::to_string),
+            kind: if url.is_some() { "Dart" } else { "Native" }.to_string(),
+        }
+    }
+
+    /// main → build → layout (x2), main → build (x1), main → dart:core sort (x1)
+    fn profile() -> CpuProfile {
+        CpuProfile {
+            functions: vec![
+                function("main", Some("package:app/main.dart")),
+                function("build", Some("package:app/widget.dart")),
+                function("layout", Some("package:flutter/src/rendering/box.dart")),
+                function("sort", Some("dart:core")),
+            ],
+            samples: vec![vec![2, 1, 0], vec![2, 1, 0], vec![1, 0], vec![3, 0]],
+            sample_period_micros: 250,
+            time_extent_micros: 1_000,
+        }
+    }
+
+    #[test]
+    fn test_top_down_tree() {
+        let tree = profile().call_tree(CallTreeDirection::TopDown, false);
+        assert_eq!(tree.sample_count, 4);
+        assert_eq!(tree.roots.len(), 1);
+        let main = &tree.roots[0];
+        assert_eq!((main.function, main.total, main.self_samples), (0, 4, 0));
+        let build = &main.children[0];
+        assert_eq!((build.function, build.total, build.self_samples), (1, 3, 1));
+        assert_eq!(build.children[0].total, 2);
+        assert_eq!(build.children[0].self_samples, 2);
+        assert_eq!(tree.node(&[0, 1]).map(|n| n.function), Some(3));
+    }
+
+    #[test]
+    fn test_bottom_up_tree() {
+        let tree = profile().call_tree(CallTreeDirection::BottomUp, false);
+        let roots: Vec<_> = tree
+            .roots
+            .iter()
+            .map(|n| (n.function, n.total, n.self_samples))
+            .collect();
+        assert_eq!(roots, vec![(2, 2, 2), (1, 1, 1), (3, 1, 1)]);
+        // layout is called from build, which is called from main.
+        let callers = &tree.roots[0].children;
+        assert_eq!(callers[0].function, 1);
+        assert_eq!(callers[0].self_samples, 0);
+        assert_eq!(callers[0].children[0].function, 0);
+    }
+
+    #[test]
+    fn test_project_only_drops_sdk_frames() {
+        let tree = profile().call_tree(CallTreeDirection::BottomUp, true);
+        assert_eq!(tree.sample_count, 4);
+        let roots: Vec<_> = tree
+            .roots
+            .iter()
+            .map(|n| (n.function, n.self_samples))
+            .collect();
+        // Samples in layout count against build; the sort sample against main.
+        assert_eq!(roots, vec![(1, 3), (0, 1)]);
+    }
+
+    #[test]
+    fn test_display_name_and_project() {
+        let mut f = function("build", Some("package:app/widget.dart"));
+        assert_eq!(f.display_name(), "build");
+        f.owner = Some("_CounterState".to_string());
+        assert_eq!(f.display_name(), "_CounterState.build");
+        assert!(f.is_project());
+        assert!(!function("sort", Some("dart:core")).is_project());
+        assert!(!function("memcpy", None).is_project());
+    }
+}
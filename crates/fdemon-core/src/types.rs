@@ -189,7 +189,8 @@ impl LogLevel {
 }
 
 /// Filter for log levels - controls which severity levels are displayed
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum LogLevelFilter {
     /// Show all log levels
     #[default]
@@ -276,7 +277,8 @@ impl LogSource {
 }
 
 /// Filter for log sources - controls which sources are displayed
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum LogSourceFilter {
     /// Show all log sources
     #[default]
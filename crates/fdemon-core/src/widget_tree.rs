@@ -81,6 +81,11 @@ pub struct DiagnosticsNode {
     /// Property nodes (populated when includeProperties is true)
     #[serde(default)]
     pub properties: Vec<DiagnosticsNode>,
+
+    /// The property's default value as a string (property nodes only).
+    ///
+    /// Flutter only sends this for properties that declare a default.
+    pub default_value: Option<String>,
 }
 
 impl DiagnosticsNode {
@@ -101,6 +106,42 @@ impl DiagnosticsNode {
         self.created_by_local_project
     }
 
+    /// Parsed diagnostic level. Nodes without a level are [`DiagnosticLevel::Info`].
+    pub fn diagnostic_level(&self) -> DiagnosticLevel {
+        self.level
+            .as_deref()
+            .map(DiagnosticLevel::parse)
+            .unwrap_or(DiagnosticLevel::Info)
+    }
+
+    /// Whether this property node's value differs from its declared default.
+    ///
+    /// Returns `false` when no default is declared.
+    pub fn differs_from_default(&self) -> bool {
+        self.default_value
+            .as_deref()
+            .is_some_and(|default| default != self.description)
+    }
+
+    /// The widget's key, taken from its `key` property, if fetched.
+    pub fn key(&self) -> Option<&str> {
+        self.properties
+            .iter()
+            .find(|p| p.name.as_deref() == Some("key"))
+            .map(|p| p.description.as_str())
+            .filter(|d| !d.is_empty() && *d != "null")
+    }
+
+    /// Whether the widget type, description, or key contains `query_lower`.
+    ///
+    /// `query_lower` must already be lowercased; matching is case-insensitive.
+    pub fn matches_search(&self, query_lower: &str) -> bool {
+        let contains = |s: &str| s.to_lowercase().contains(query_lower);
+        contains(&self.description)
+            || self.node_type.as_deref().is_some_and(contains)
+            || self.key().is_some_and(contains)
+    }
+
     /// Get the source file path, stripping the `file://` URI prefix if present.
     ///
     /// Returns `None` if no creation location is available.
@@ -527,6 +568,7 @@ mod tests {
             summary_tree: false,
             children: vec![],
             properties: vec![],
+            default_value: None,
         }
     }
 
@@ -909,4 +951,47 @@ mod tests {
         let restored: EdgeInsets = serde_json::from_str(&json).unwrap();
         assert_eq!(ei, restored);
     }
+
+    #[test]
+    fn test_matches_search_description_type_and_key() {
+        let mut node = make_test_node("ListTile");
+        node.node_type = Some("_ListTileElement".to_string());
+        assert!(node.matches_search("listtile"));
+        assert!(node.matches_search("element"));
+        assert!(!node.matches_search("login"));
+
+        let mut key = make_test_node("[<'login-button'>]");
+        key.name = Some("key".to_string());
+        node.properties.push(key);
+        assert_eq!(node.key(), Some("[<'login-button'>]"));
+        assert!(node.matches_search("login"));
+    }
+
+    #[test]
+    fn test_key_ignores_null() {
+        let mut node = make_test_node("Text");
+        let mut key = make_test_node("null");
+        key.name = Some("key".to_string());
+        node.properties.push(key);
+        assert_eq!(node.key(), None);
+    }
+
+    #[test]
+    fn test_diagnostic_level_defaults_to_info() {
+        let mut node = make_test_node("color");
+        assert_eq!(node.diagnostic_level(), DiagnosticLevel::Info);
+        node.level = Some("fine".to_string());
+        assert_eq!(node.diagnostic_level(), DiagnosticLevel::Fine);
+    }
+
+    #[test]
+    fn test_differs_from_default() {
+        let json = r#"{"description": "1.0", "name": "textScaleFactor", "defaultValue": "1.0"}"#;
+        let mut node: DiagnosticsNode = serde_json::from_str(json).unwrap();
+        assert!(!node.differs_from_default());
+        node.description = "2.0".to_string();
+        assert!(node.differs_from_default());
+        node.default_value = None;
+        assert!(!node.differs_from_default());
+    }
 }
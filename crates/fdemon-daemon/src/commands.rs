@@ -184,6 +184,8 @@ pub enum DaemonCommand {
     Restart { app_id: String },
     /// Stop the app
     Stop { app_id: String },
+    /// Detach from an attached app, leaving it running
+    Detach { app_id: String },
     /// Get daemon version
     Version,
     /// Shutdown daemon
@@ -213,6 +215,7 @@ impl DaemonCommand {
                 json!({ "appId": app_id, "fullRestart": true, "pause": false }),
             ),
             DaemonCommand::Stop { app_id } => ("app.stop", json!({ "appId": app_id })),
+            DaemonCommand::Detach { app_id } => ("app.detach", json!({ "appId": app_id })),
             DaemonCommand::Version => ("daemon.version", json!({})),
             DaemonCommand::Shutdown => ("daemon.shutdown", json!({})),
             DaemonCommand::EnableDevices => ("device.enable", json!({})),
@@ -240,6 +243,7 @@ impl DaemonCommand {
             DaemonCommand::Reload { .. } => "hot reload",
             DaemonCommand::Restart { .. } => "hot restart",
             DaemonCommand::Stop { .. } => "stop app",
+            DaemonCommand::Detach { .. } => "detach app",
             DaemonCommand::Version => "get version",
             DaemonCommand::Shutdown => "shutdown daemon",
             DaemonCommand::EnableDevices => "enable devices",
@@ -502,6 +506,17 @@ mod tests {
         assert_eq!(parsed["params"]["appId"], "abc123");
     }
 
+    #[test]
+    fn test_daemon_command_build_detach() {
+        let cmd = DaemonCommand::Detach {
+            app_id: "abc123".to_string(),
+        };
+        let parsed: Value = serde_json::from_str(&cmd.build(3)).unwrap();
+        assert_eq!(parsed["method"], "app.detach");
+        assert_eq!(parsed["params"]["appId"], "abc123");
+        assert_eq!(cmd.description(), "detach app");
+    }
+
     #[test]
     fn test_daemon_command_build_version() {
         let cmd = DaemonCommand::Version;
@@ -50,6 +50,16 @@
 //! - [`AndroidLogConfig`] - Configuration for Android logcat capture
 //! - [`IosLogConfig`] - Configuration for iOS native log capture (macOS only)
 //! - [`create_native_log_capture()`](native_logs::create_native_log_capture) - Platform dispatch
+//!
+//! ### HTTP Proxy
+//! - [`HttpProxy`] - Local recording proxy with mock response rules
+//! - [`ProxyConfig`] - Bind address, port and mock rules directory
+//! - [`ProxyHandle`] - Running proxy (address, recorded exchanges, task)
+//!
+//! ### Screenshots
+//! - [`vm_screenshot()`] - Capture via the engine's `_flutter.screenshot` RPC
+//! - [`flutter_cli_screenshot()`] - Capture via `flutter screenshot`
+//! - [`decode_screenshot_response()`] - Decode base64 PNG responses
 
 pub mod avds;
 pub mod commands;
@@ -59,6 +69,8 @@ pub mod flutter_sdk;
 pub mod native_logs;
 pub mod process;
 pub mod protocol;
+pub mod proxy;
+pub mod screenshot;
 pub mod simulators;
 #[cfg(any(test, feature = "test-helpers"))]
 pub mod test_utils;
@@ -95,6 +107,8 @@ pub use process::FlutterProcess;
 pub use protocol::{
     detect_log_level, parse_daemon_message, parse_flutter_log, to_log_entry, LogEntryInfo,
 };
+pub use proxy::{HttpProxy, ProxyConfig, ProxyHandle};
+pub use screenshot::{decode_screenshot_response, flutter_cli_screenshot, vm_screenshot};
 pub use simulators::{
     boot_simulator, group_simulators_by_runtime, list_ios_simulators, shutdown_simulator,
     IosSimulator, SimulatorState,
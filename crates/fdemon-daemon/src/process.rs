@@ -42,6 +42,9 @@ pub struct FlutterProcess {
     /// Notified by the wait task immediately after the child exits.
     /// Used by `shutdown()` to await graceful termination without polling.
     exit_notify: Arc<Notify>,
+    /// Whether this is a `flutter attach` process. Shutdown then detaches
+    /// from the app instead of stopping it.
+    attached: bool,
 }
 
 impl FlutterProcess {
@@ -136,6 +139,7 @@ impl FlutterProcess {
             kill_tx: Some(kill_tx),
             exited,
             exit_notify,
+            attached: args.first().is_some_and(|a| a == "attach"),
         })
     }
 
@@ -214,19 +218,25 @@ impl FlutterProcess {
         device_id: &str,
         event_tx: mpsc::Sender<DaemonEvent>,
     ) -> Result<Self> {
-        let args = vec![
+        let args = Self::device_run_args(device_id);
+        Self::spawn_internal(flutter, &args, project_path, event_tx)
+    }
+
+    /// Arguments for `flutter run` on a specific device with the default
+    /// build mode, as used by [`Self::spawn_with_device`]
+    pub fn device_run_args(device_id: &str) -> Vec<String> {
+        vec![
             "run".to_string(),
             "--machine".to_string(),
             "-d".to_string(),
             device_id.to_string(),
-        ];
-        Self::spawn_internal(flutter, &args, project_path, event_tx)
+        ]
     }
 
     /// Spawn a Flutter process with pre-built arguments
     ///
     /// The caller is responsible for building the complete argument list including
-    /// `run`, `--machine`, `-d`, and all other flags.
+    /// `run` (or `attach`), `--machine`, `-d`, and all other flags.
     pub async fn spawn_with_args(
         flutter: &FlutterExecutable,
         project_path: &Path,
@@ -314,7 +324,7 @@ impl FlutterProcess {
     ///
     /// Optimized for fast shutdown:
     /// 1. Early exit if process already dead (atomic check — no lock)
-    /// 2. Send app.stop command with 1s timeout
+    /// 2. Send app.stop (app.detach for `flutter attach`) with 1s timeout
     /// 3. Send daemon.shutdown command
     /// 4. Wait up to 2s for graceful exit via `exit_notify`
     /// 5. Send kill signal to the wait task if graceful exit times out
@@ -334,17 +344,20 @@ impl FlutterProcess {
 
         info!("Initiating Flutter process shutdown");
 
-        // Step 1: Stop the app if we have an app_id and command sender
+        // Step 1: Stop the app if we have an app_id and command sender.
+        // An attached app was started by another tool, so leave it running.
         // Reduced timeout from 5s to 1s for faster shutdown
         if let (Some(id), Some(sender)) = (app_id, cmd_sender) {
-            info!("Stopping Flutter app: {}", id);
+            let app_id = id.to_string();
+            let command = if self.attached {
+                info!("Detaching from Flutter app: {}", id);
+                DaemonCommand::Detach { app_id }
+            } else {
+                info!("Stopping Flutter app: {}", id);
+                DaemonCommand::Stop { app_id }
+            };
             match sender
-                .send_with_timeout(
-                    DaemonCommand::Stop {
-                        app_id: id.to_string(),
-                    },
-                    Duration::from_secs(1),
-                )
+                .send_with_timeout(command, Duration::from_secs(1))
                 .await
             {
                 Ok(_) => info!("App stop command acknowledged"),
@@ -533,6 +546,7 @@ mod tests {
             kill_tx: Some(kill_tx),
             exited,
             exit_notify,
+            attached: false,
         }
     }
 
@@ -683,6 +697,7 @@ mod tests {
             kill_tx: Some(kill_tx),
             exited,
             exit_notify,
+            attached: false,
         };
 
         // Confirm it's running
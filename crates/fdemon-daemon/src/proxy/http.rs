@@ -0,0 +1,341 @@
+//! Minimal HTTP/1.1 message parsing for the recording proxy.
+//!
+//! Only what a forward proxy needs: reading a message head, parsing the
+//! request/status line and headers, and reading a body framed by
+//! `Content-Length` or `Transfer-Encoding: chunked`.
+
+use fdemon_core::prelude::*;
+use tokio::io::{AsyncRead, AsyncReadExt};
+
+/// Maximum size of a request or response head (request line + headers).
+pub const MAX_HEAD_BYTES: usize = 64 * 1024;
+
+/// Size of each socket read.
+const READ_CHUNK: usize = 8 * 1024;
+
+/// Header list preserving order and duplicates.
+pub type Headers = Vec<(String, String)>;
+
+/// Parsed request line and headers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RequestHead {
+    pub method: String,
+    /// Request target: absolute URL (`http://host/path`), authority
+    /// (`host:443` for `CONNECT`) or origin-form path.
+    pub target: String,
+    pub version: String,
+    pub headers: Headers,
+}
+
+/// Parsed status line and headers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResponseHead {
+    pub status: u16,
+    pub headers: Headers,
+}
+
+/// Look up a header value by case-insensitive name.
+pub fn header<'a>(headers: &'a Headers, name: &str) -> Option<&'a str> {
+    headers
+        .iter()
+        .find(|(k, _)| k.eq_ignore_ascii_case(name))
+        .map(|(_, v)| v.as_str())
+}
+
+/// Group headers by name for [`fdemon_core::HttpProfileEntryDetail`].
+pub fn group_headers(headers: &Headers) -> Vec<(String, Vec<String>)> {
+    let mut grouped: Vec<(String, Vec<String>)> = Vec::new();
+    for (name, value) in headers {
+        match grouped
+            .iter_mut()
+            .find(|(k, _)| k.eq_ignore_ascii_case(name))
+        {
+            Some((_, values)) => values.push(value.clone()),
+            None => grouped.push((name.clone(), vec![value.clone()])),
+        }
+    }
+    grouped
+}
+
+/// Read from `stream` until the end of a message head (`\r\n\r\n`).
+///
+/// Returns the head bytes (without the terminator) and any body bytes that
+/// were read past it.
+pub async fn read_head<R: AsyncRead + Unpin>(stream: &mut R) -> Result<(Vec<u8>, Vec<u8>)> {
+    let mut buf = Vec::with_capacity(READ_CHUNK);
+    let mut chunk = [0u8; READ_CHUNK];
+    loop {
+        if let Some(pos) = find_head_end(&buf) {
+            let rest = buf.split_off(pos + 4);
+            buf.truncate(pos);
+            return Ok((buf, rest));
+        }
+        if buf.len() > MAX_HEAD_BYTES {
+            return Err(Error::protocol("HTTP head exceeds size limit"));
+        }
+        let n = stream.read(&mut chunk).await?;
+        if n == 0 {
+            return Err(Error::protocol("connection closed before end of HTTP head"));
+        }
+        buf.extend_from_slice(&chunk[..n]);
+    }
+}
+
+fn find_head_end(buf: &[u8]) -> Option<usize> {
+    buf.windows(4).position(|w| w == b"\r\n\r\n")
+}
+
+fn parse_headers<'a>(lines: impl Iterator<Item = &'a str>) -> Headers {
+    lines
+        .filter_map(|line| {
+            let (name, value) = line.split_once(':')?;
+            Some((name.trim().to_string(), value.trim().to_string()))
+        })
+        .collect()
+}
+
+impl RequestHead {
+    /// Parse a request head produced by [`read_head`].
+    pub fn parse(head: &[u8]) -> Result<Self> {
+        let text = String::from_utf8_lossy(head);
+        let mut lines = text.split("\r\n");
+        let request_line = lines.next().unwrap_or_default();
+        let mut parts = request_line.split_whitespace();
+        let (Some(method), Some(target), Some(version)) =
+            (parts.next(), parts.next(), parts.next())
+        else {
+            return Err(Error::protocol(format!(
+                "malformed HTTP request line: {request_line}"
+            )));
+        };
+        Ok(Self {
+            method: method.to_string(),
+            target: target.to_string(),
+            version: version.to_string(),
+            headers: parse_headers(lines),
+        })
+    }
+}
+
+impl ResponseHead {
+    /// Parse a response head produced by [`read_head`].
+    pub fn parse(head: &[u8]) -> Result<Self> {
+        let text = String::from_utf8_lossy(head);
+        let mut lines = text.split("\r\n");
+        let status_line = lines.next().unwrap_or_default();
+        let status = status_line
+            .split_whitespace()
+            .nth(1)
+            .and_then(|s| s.parse::<u16>().ok())
+            .ok_or_else(|| Error::protocol(format!("malformed HTTP status line: {status_line}")))?;
+        Ok(Self {
+            status,
+            headers: parse_headers(lines),
+        })
+    }
+}
+
+/// Whether the headers declare a chunked body.
+pub fn is_chunked(headers: &Headers) -> bool {
+    header(headers, "transfer-encoding").is_some_and(|v| v.to_ascii_lowercase().contains("chunked"))
+}
+
+/// Declared `Content-Length`, if present and valid.
+pub fn content_length(headers: &Headers) -> Option<usize> {
+    header(headers, "content-length").and_then(|v| v.parse().ok())
+}
+
+/// Read a request body framed by `Content-Length` or chunked encoding.
+///
+/// `already_read` holds bytes read past the head. Requests without either
+/// framing header have no body. Chunked bodies are returned decoded.
+/// Returns `None`, without reading the rest, once the body is known to
+/// exceed `limit` bytes.
+pub async fn read_request_body<R: AsyncRead + Unpin>(
+    stream: &mut R,
+    mut already_read: Vec<u8>,
+    headers: &Headers,
+    limit: usize,
+) -> Result<Option<Vec<u8>>> {
+    if is_chunked(headers) {
+        loop {
+            if let Some(body) = decode_chunked(&already_read) {
+                return Ok((body.len() <= limit).then_some(body));
+            }
+            // Chunk framing adds a few bytes per chunk on top of the body.
+            if already_read.len() > limit + MAX_HEAD_BYTES {
+                return Ok(None);
+            }
+            read_more(stream, &mut already_read).await?;
+        }
+    }
+    let Some(len) = content_length(headers) else {
+        return Ok(Some(Vec::new()));
+    };
+    if len > limit {
+        return Ok(None);
+    }
+    while already_read.len() < len {
+        read_more(stream, &mut already_read).await?;
+    }
+    already_read.truncate(len);
+    Ok(Some(already_read))
+}
+
+async fn read_more<R: AsyncRead + Unpin>(stream: &mut R, buf: &mut Vec<u8>) -> Result<()> {
+    let mut chunk = [0u8; READ_CHUNK];
+    let n = stream.read(&mut chunk).await?;
+    if n == 0 {
+        return Err(Error::protocol("connection closed before end of HTTP body"));
+    }
+    buf.extend_from_slice(&chunk[..n]);
+    Ok(())
+}
+
+/// Decode a complete chunked body. Returns `None` if `data` does not yet
+/// contain the terminating zero-length chunk.
+pub fn decode_chunked(data: &[u8]) -> Option<Vec<u8>> {
+    let mut body = Vec::new();
+    let mut pos = 0;
+    loop {
+        let line_end = pos + data.get(pos..)?.windows(2).position(|w| w == b"\r\n")?;
+        let size_line = std::str::from_utf8(&data[pos..line_end]).ok()?;
+        let size_hex = size_line.split(';').next()?.trim();
+        let size = usize::from_str_radix(size_hex, 16).ok()?;
+        let chunk_start = line_end + 2;
+        if size == 0 {
+            return Some(body);
+        }
+        let chunk_end = chunk_start + size;
+        body.extend_from_slice(data.get(chunk_start..chunk_end)?);
+        pos = chunk_end + 2;
+    }
+}
+
+/// Serialise a response head and body into raw HTTP/1.1 bytes.
+pub fn build_response(status: u16, headers: &Headers, body: &[u8]) -> Vec<u8> {
+    let mut out = format!("HTTP/1.1 {} {}\r\n", status, reason_phrase(status)).into_bytes();
+    for (name, value) in headers {
+        out.extend_from_slice(format!("{name}: {value}\r\n").as_bytes());
+    }
+    if content_length(headers).is_none() {
+        out.extend_from_slice(format!("Content-Length: {}\r\n", body.len()).as_bytes());
+    }
+    out.extend_from_slice(b"Connection: close\r\n\r\n");
+    out.extend_from_slice(body);
+    out
+}
+
+/// Canonical reason phrase for common status codes.
+pub fn reason_phrase(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        201 => "Created",
+        204 => "No Content",
+        301 => "Moved Permanently",
+        302 => "Found",
+        304 => "Not Modified",
+        400 => "Bad Request",
+        401 => "Unauthorized",
+        403 => "Forbidden",
+        404 => "Not Found",
+        409 => "Conflict",
+        413 => "Payload Too Large",
+        429 => "Too Many Requests",
+        500 => "Internal Server Error",
+        502 => "Bad Gateway",
+        503 => "Service Unavailable",
+        504 => "Gateway Timeout",
+        _ => "Status",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_request_head() {
+        let head = b"GET http://example.com/a?b=1 HTTP/1.1\r\nHost: example.com\r\nAccept: */*";
+        let req = RequestHead::parse(head).unwrap();
+        assert_eq!(req.method, "GET");
+        assert_eq!(req.target, "http://example.com/a?b=1");
+        assert_eq!(header(&req.headers, "host"), Some("example.com"));
+    }
+
+    #[test]
+    fn test_parse_request_head_malformed() {
+        assert!(RequestHead::parse(b"GARBAGE").is_err());
+    }
+
+    #[test]
+    fn test_parse_response_head() {
+        let head = b"HTTP/1.1 404 Not Found\r\nContent-Type: text/plain";
+        let resp = ResponseHead::parse(head).unwrap();
+        assert_eq!(resp.status, 404);
+        assert_eq!(header(&resp.headers, "content-type"), Some("text/plain"));
+    }
+
+    #[test]
+    fn test_decode_chunked_complete() {
+        let data = b"4\r\nWiki\r\n5;ext=1\r\npedia\r\n0\r\n\r\n";
+        assert_eq!(decode_chunked(data).unwrap(), b"Wikipedia");
+    }
+
+    #[test]
+    fn test_decode_chunked_incomplete() {
+        assert!(decode_chunked(b"4\r\nWi").is_none());
+        assert!(decode_chunked(b"4\r\nWiki\r\n").is_none());
+    }
+
+    #[test]
+    fn test_group_headers_merges_duplicates() {
+        let headers = vec![
+            ("Set-Cookie".to_string(), "a=1".to_string()),
+            ("set-cookie".to_string(), "b=2".to_string()),
+            ("Accept".to_string(), "*/*".to_string()),
+        ];
+        let grouped = group_headers(&headers);
+        assert_eq!(grouped.len(), 2);
+        assert_eq!(grouped[0].1, vec!["a=1", "b=2"]);
+    }
+
+    #[test]
+    fn test_build_response_adds_content_length() {
+        let raw = build_response(503, &Vec::new(), b"down");
+        let text = String::from_utf8(raw).unwrap();
+        assert!(text.starts_with("HTTP/1.1 503 Service Unavailable\r\n"));
+        assert!(text.contains("Content-Length: 4\r\n"));
+        assert!(text.ends_with("\r\n\r\ndown"));
+    }
+
+    #[tokio::test]
+    async fn test_read_head_and_body() {
+        let raw = b"POST /x HTTP/1.1\r\nContent-Length: 5\r\n\r\nhello".to_vec();
+        let mut cursor = std::io::Cursor::new(raw);
+        let (head, rest) = read_head(&mut cursor).await.unwrap();
+        let req = RequestHead::parse(&head).unwrap();
+        let body = read_request_body(&mut cursor, rest, &req.headers, 5)
+            .await
+            .unwrap();
+        assert_eq!(body.as_deref(), Some(&b"hello"[..]));
+    }
+
+    #[tokio::test]
+    async fn test_read_body_over_limit() {
+        let headers = vec![("Content-Length".to_string(), "6".to_string())];
+        let mut cursor = std::io::Cursor::new(b"hello!".to_vec());
+        let body = read_request_body(&mut cursor, Vec::new(), &headers, 5)
+            .await
+            .unwrap();
+        assert_eq!(body, None);
+
+        let headers = vec![("Transfer-Encoding".to_string(), "chunked".to_string())];
+        let chunked = b"3\r\nabc\r\n3\r\ndef\r\n0\r\n\r\n".to_vec();
+        let mut cursor = std::io::Cursor::new(Vec::new());
+        let body = read_request_body(&mut cursor, chunked, &headers, 5)
+            .await
+            .unwrap();
+        assert_eq!(body, None);
+    }
+}
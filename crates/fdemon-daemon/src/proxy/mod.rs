@@ -0,0 +1,683 @@
+//! # Local HTTP Proxy
+//!
+//! A small forward proxy started alongside a session so that traffic from
+//! the Flutter app can be recorded and, optionally, answered from mock
+//! rules instead of the real server.
+//!
+//! The proxy is HTTP/1.1 only and handles one exchange per connection
+//! (`Connection: close` is forced upstream and downstream):
+//!
+//! - Plain `http://` requests are forwarded (or mocked) and recorded with
+//!   headers, bodies and timing events.
+//! - `CONNECT` requests are tunnelled without inspection. They are recorded
+//!   as a single entry but cannot be mocked, since the payload is encrypted.
+//! - Request bodies over [`MAX_REQUEST_BODY_BYTES`] are refused with 413.
+//!
+//! The proxy has no authentication: bound to a non-loopback address such as
+//! `0.0.0.0`, anyone on the network can use it, including `CONNECT` tunnels
+//! to arbitrary hosts.
+//!
+//! Every exchange is reported twice on [`ProxyHandle::exchange_rx`]: once
+//! when the request head has been read (pending) and once on completion.
+
+pub mod http;
+pub mod rules;
+
+use std::net::SocketAddr;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use fdemon_core::network::{HttpProfileEntry, HttpProfileEntryDetail, HttpProfileEvent};
+use fdemon_core::prelude::*;
+use fdemon_core::ConnectionInfo;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+
+use http::{Headers, RequestHead, ResponseHead};
+
+/// Prefix of the IDs assigned to proxied exchanges, keeping them distinct
+/// from VM Service HTTP profile IDs.
+pub const PROXY_ID_PREFIX: &str = "proxy-";
+
+/// Maximum number of body bytes kept per recorded request/response.
+pub const MAX_RECORDED_BODY_BYTES: usize = 1024 * 1024;
+
+/// Maximum size of a request body the proxy accepts. Larger requests are
+/// answered with `413 Payload Too Large` without being read.
+pub const MAX_REQUEST_BODY_BYTES: usize = MAX_RECORDED_BODY_BYTES * 16;
+
+/// Capacity of the exchange channel.
+const EXCHANGE_CHANNEL_CAPACITY: usize = 256;
+
+/// Timeout for connecting to the upstream server.
+const UPSTREAM_CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Headers that are hop-by-hop and must not be forwarded.
+const HOP_BY_HOP_HEADERS: &[&str] = &[
+    "connection",
+    "proxy-connection",
+    "keep-alive",
+    "proxy-authorization",
+    "te",
+    "trailer",
+    "upgrade",
+];
+
+/// Event names emitted for the waterfall timing breakdown.
+const EVENT_CONNECTED: &str = "connection established";
+const EVENT_REQUEST_SENT: &str = "request sent";
+const EVENT_RESPONSE_STARTED: &str = "response started";
+
+/// Proxy listener configuration.
+#[derive(Debug, Clone)]
+pub struct ProxyConfig {
+    /// Address to bind, e.g. `127.0.0.1` or `0.0.0.0`.
+    pub bind_address: String,
+    /// Port to bind; `0` picks an ephemeral port. An ephemeral port is also
+    /// used when this one is taken, e.g. by another session's proxy.
+    pub port: u16,
+    /// Directory containing `*.toml` mock rule files.
+    pub rules_dir: PathBuf,
+}
+
+/// A bound but not yet running proxy.
+pub struct HttpProxy {
+    listener: TcpListener,
+    rules_dir: PathBuf,
+}
+
+/// Handle to a running proxy.
+///
+/// Abort `task_handle` to stop accepting connections.
+pub struct ProxyHandle {
+    /// Address the proxy is listening on.
+    pub local_addr: SocketAddr,
+    /// Recorded exchanges (pending and completed).
+    pub exchange_rx: mpsc::Receiver<HttpProfileEntryDetail>,
+    /// The accept loop task.
+    pub task_handle: JoinHandle<()>,
+}
+
+impl HttpProxy {
+    /// Bind the listener so the port is known before the app is launched.
+    pub async fn bind(config: ProxyConfig) -> Result<Self> {
+        let addr = format!("{}:{}", config.bind_address, config.port);
+        let listener = match TcpListener::bind(&addr).await {
+            Ok(listener) => listener,
+            Err(e) if config.port != 0 && e.kind() == std::io::ErrorKind::AddrInUse => {
+                tracing::warn!(
+                    "HTTP proxy port {} is in use, using a free port",
+                    config.port
+                );
+                let fallback = format!("{}:0", config.bind_address);
+                TcpListener::bind(&fallback).await.map_err(|e| {
+                    Error::config(format!("failed to bind HTTP proxy on {fallback}: {e}"))
+                })?
+            }
+            Err(e) => {
+                return Err(Error::config(format!(
+                    "failed to bind HTTP proxy on {addr}: {e}"
+                )))
+            }
+        };
+        Ok(Self {
+            listener,
+            rules_dir: config.rules_dir,
+        })
+    }
+
+    /// Address the listener is bound to.
+    pub fn local_addr(&self) -> Result<SocketAddr> {
+        Ok(self.listener.local_addr()?)
+    }
+
+    /// Start accepting connections.
+    pub fn spawn(self) -> Result<ProxyHandle> {
+        let local_addr = self.local_addr()?;
+        let (tx, exchange_rx) = mpsc::channel(EXCHANGE_CHANNEL_CAPACITY);
+        let rules = Arc::new(rules::RuleCache::new(self.rules_dir.clone()));
+        let rules_dir = Arc::new(self.rules_dir);
+        let next_id = Arc::new(AtomicU64::new(1));
+        let listener = self.listener;
+
+        let task_handle = tokio::spawn(async move {
+            loop {
+                let (client, peer) = match listener.accept().await {
+                    Ok(conn) => conn,
+                    Err(e) => {
+                        tracing::warn!("HTTP proxy accept failed: {}", e);
+                        continue;
+                    }
+                };
+                let id = format!(
+                    "{PROXY_ID_PREFIX}{}",
+                    next_id.fetch_add(1, Ordering::Relaxed)
+                );
+                let exchange = Exchange {
+                    id,
+                    tx: tx.clone(),
+                    rules: Arc::clone(&rules),
+                    rules_dir: Arc::clone(&rules_dir),
+                    local_port: peer.port(),
+                };
+                tokio::spawn(async move {
+                    if let Err(e) = exchange.run(client).await {
+                        tracing::debug!("HTTP proxy exchange ended: {}", e);
+                    }
+                });
+            }
+        });
+
+        Ok(ProxyHandle {
+            local_addr,
+            exchange_rx,
+            task_handle,
+        })
+    }
+}
+
+fn now_us() -> i64 {
+    chrono::Utc::now().timestamp_micros()
+}
+
+fn capped(body: &[u8]) -> Vec<u8> {
+    body[..body.len().min(MAX_RECORDED_BODY_BYTES)].to_vec()
+}
+
+/// Split an absolute `http://` URL into `(host, port, path)`.
+fn split_http_url(url: &str) -> Option<(String, u16, String)> {
+    let rest = url.strip_prefix("http://")?;
+    let (authority, path) = match rest.find('/') {
+        Some(pos) => (&rest[..pos], rest[pos..].to_string()),
+        None => (rest, "/".to_string()),
+    };
+    let (host, port) = split_authority(authority, 80)?;
+    Some((host, port, path))
+}
+
+/// Split `host[:port]` into its parts, using `default_port` if absent.
+fn split_authority(authority: &str, default_port: u16) -> Option<(String, u16)> {
+    // Bracketed IPv6 literal: `[::1]:8080`.
+    if let Some(rest) = authority.strip_prefix('[') {
+        let (host, after) = rest.split_once(']')?;
+        let port = match after.strip_prefix(':') {
+            Some(port) => port.parse().ok()?,
+            None => default_port,
+        };
+        return Some((host.to_string(), port));
+    }
+    match authority.split_once(':') {
+        _ if authority.is_empty() => None,
+        Some((host, port)) => Some((host.to_string(), port.parse().ok()?)),
+        None => Some((authority.to_string(), default_port)),
+    }
+}
+
+/// State for one client connection.
+struct Exchange {
+    id: String,
+    tx: mpsc::Sender<HttpProfileEntryDetail>,
+    rules: Arc<rules::RuleCache>,
+    rules_dir: Arc<PathBuf>,
+    local_port: u16,
+}
+
+impl Exchange {
+    async fn run(self, mut client: TcpStream) -> Result<()> {
+        let (head, rest) = http::read_head(&mut client).await?;
+        let request = RequestHead::parse(&head)?;
+
+        if request.method.eq_ignore_ascii_case("CONNECT") {
+            return self.tunnel(client, request).await;
+        }
+
+        let body =
+            http::read_request_body(&mut client, rest, &request.headers, MAX_REQUEST_BODY_BYTES)
+                .await?;
+        let Some(body) = body else {
+            return self.reject_too_large(client, &request).await;
+        };
+        let mut detail = self.pending_detail(&request, &request.target, &body);
+        self.emit(&detail).await;
+
+        let cache = Arc::clone(&self.rules);
+        let rules = tokio::task::spawn_blocking(move || cache.rules())
+            .await
+            .unwrap_or_else(|e| {
+                tracing::warn!("Mock rule reload failed: {}", e);
+                Arc::default()
+            });
+        let outcome = match rules::find_match(&rules, &request.method, &request.target) {
+            Some(rule) => self.serve_mock(&mut client, rule, &mut detail).await,
+            None => {
+                self.forward(&mut client, &request, &body, &mut detail)
+                    .await
+            }
+        };
+
+        if let Err(e) = &outcome {
+            let response = http::build_response(502, &Vec::new(), e.to_string().as_bytes());
+            let _ = client.write_all(&response).await;
+            detail.entry.error = Some(e.to_string());
+        }
+        detail.entry.end_time_us = Some(now_us());
+        self.emit(&detail).await;
+        let _ = client.shutdown().await;
+        Ok(())
+    }
+
+    /// Answer a request whose body exceeds [`MAX_REQUEST_BODY_BYTES`] with
+    /// 413 and record it as failed.
+    async fn reject_too_large(&self, mut client: TcpStream, request: &RequestHead) -> Result<()> {
+        let message = format!("request body exceeds {MAX_REQUEST_BODY_BYTES} bytes");
+        let mut detail = self.pending_detail(request, &request.target, &[]);
+        detail.entry.request_content_length =
+            http::content_length(&request.headers).map(|len| len as i64);
+        self.emit(&detail).await;
+
+        record_response(&mut detail, 413, &Vec::new(), &[]);
+        detail.entry.error = Some(message.clone());
+        detail.entry.end_time_us = Some(now_us());
+        self.emit(&detail).await;
+
+        let response = http::build_response(413, &Vec::new(), message.as_bytes());
+        let _ = client.write_all(&response).await;
+        let _ = client.shutdown().await;
+        Ok(())
+    }
+
+    /// Record a fresh entry for `request` with no response yet.
+    fn pending_detail(
+        &self,
+        request: &RequestHead,
+        uri: &str,
+        body: &[u8],
+    ) -> HttpProfileEntryDetail {
+        HttpProfileEntryDetail {
+            entry: HttpProfileEntry {
+                id: self.id.clone(),
+                method: request.method.to_uppercase(),
+                uri: uri.to_string(),
+                status_code: None,
+                content_type: None,
+                start_time_us: now_us(),
+                end_time_us: None,
+                request_content_length: Some(body.len() as i64),
+                response_content_length: None,
+                error: None,
+            },
+            request_headers: http::group_headers(&request.headers),
+            response_headers: Vec::new(),
+            request_body: capped(body),
+            response_body: Vec::new(),
+            events: Vec::new(),
+            connection_info: Some(ConnectionInfo {
+                local_port: Some(self.local_port),
+                remote_address: None,
+                remote_port: None,
+            }),
+        }
+    }
+
+    async fn emit(&self, detail: &HttpProfileEntryDetail) {
+        // The receiver goes away when the session ends; nothing to do then.
+        let _ = self.tx.send(detail.clone()).await;
+    }
+
+    /// Answer from a mock rule without contacting the upstream server.
+    async fn serve_mock(
+        &self,
+        client: &mut TcpStream,
+        rule: &rules::MockRule,
+        detail: &mut HttpProfileEntryDetail,
+    ) -> Result<()> {
+        let mock = rule.response(&self.rules_dir).await?;
+        if mock.delay_ms > 0 {
+            tokio::time::sleep(Duration::from_millis(mock.delay_ms)).await;
+        }
+        push_event(detail, EVENT_RESPONSE_STARTED);
+        client
+            .write_all(&http::build_response(
+                mock.status,
+                &mock.headers,
+                &mock.body,
+            ))
+            .await?;
+        record_response(detail, mock.status, &mock.headers, &mock.body);
+        Ok(())
+    }
+
+    /// Forward a plain HTTP request upstream and relay the response.
+    async fn forward(
+        &self,
+        client: &mut TcpStream,
+        request: &RequestHead,
+        body: &[u8],
+        detail: &mut HttpProfileEntryDetail,
+    ) -> Result<()> {
+        let (host, port, path) = split_http_url(&request.target).ok_or_else(|| {
+            Error::protocol(format!("unsupported proxy target: {}", request.target))
+        })?;
+
+        let mut upstream = connect_upstream(&host, port).await?;
+        if let Some(info) = detail.connection_info.as_mut() {
+            let peer = upstream.peer_addr().ok();
+            info.remote_address = peer.map(|a| a.ip().to_string());
+            info.remote_port = peer.map(|a| a.port());
+        }
+        push_event(detail, EVENT_CONNECTED);
+
+        upstream
+            .write_all(&upstream_request(request, &path, body))
+            .await?;
+        push_event(detail, EVENT_REQUEST_SENT);
+
+        // `Connection: close` was sent, so the response ends at EOF.
+        let mut raw = Vec::new();
+        let mut chunk = [0u8; 8 * 1024];
+        loop {
+            let n = upstream.read(&mut chunk).await?;
+            if n == 0 {
+                break;
+            }
+            if raw.is_empty() {
+                push_event(detail, EVENT_RESPONSE_STARTED);
+            }
+            client.write_all(&chunk[..n]).await?;
+            raw.extend_from_slice(&chunk[..n]);
+        }
+
+        let Some(head_end) = raw.windows(4).position(|w| w == b"\r\n\r\n") else {
+            return Err(Error::protocol("upstream closed before sending a response"));
+        };
+        let head = ResponseHead::parse(&raw[..head_end])?;
+        let payload = &raw[head_end + 4..];
+        let body = if http::is_chunked(&head.headers) {
+            http::decode_chunked(payload).unwrap_or_else(|| payload.to_vec())
+        } else {
+            payload.to_vec()
+        };
+        record_response(detail, head.status, &head.headers, &body);
+        Ok(())
+    }
+
+    /// Tunnel a `CONNECT` request and record it as a single entry.
+    async fn tunnel(&self, mut client: TcpStream, request: RequestHead) -> Result<()> {
+        let uri = format!("https://{}", request.target);
+        let mut detail = self.pending_detail(&request, &uri, &[]);
+        self.emit(&detail).await;
+
+        let result = async {
+            let (host, port) = split_authority(&request.target, 443).ok_or_else(|| {
+                Error::protocol(format!("invalid CONNECT target: {}", request.target))
+            })?;
+            let mut upstream = connect_upstream(&host, port).await?;
+            push_event(&mut detail, EVENT_CONNECTED);
+            client
+                .write_all(b"HTTP/1.1 200 Connection Established\r\n\r\n")
+                .await?;
+            detail.entry.status_code = Some(200);
+            let (sent, received) =
+                tokio::io::copy_bidirectional(&mut client, &mut upstream).await?;
+            detail.entry.request_content_length = Some(sent as i64);
+            detail.entry.response_content_length = Some(received as i64);
+            Ok::<(), Error>(())
+        }
+        .await;
+
+        if let Err(e) = &result {
+            if detail.entry.status_code.is_none() {
+                let response = http::build_response(502, &Vec::new(), e.to_string().as_bytes());
+                let _ = client.write_all(&response).await;
+            }
+            detail.entry.error = Some(e.to_string());
+        }
+        detail.entry.end_time_us = Some(now_us());
+        self.emit(&detail).await;
+        Ok(())
+    }
+}
+
+async fn connect_upstream(host: &str, port: u16) -> Result<TcpStream> {
+    match tokio::time::timeout(UPSTREAM_CONNECT_TIMEOUT, TcpStream::connect((host, port))).await {
+        Ok(Ok(stream)) => Ok(stream),
+        Ok(Err(e)) => Err(Error::protocol(format!(
+            "failed to connect to {host}:{port}: {e}"
+        ))),
+        Err(_) => Err(Error::protocol(format!(
+            "timed out connecting to {host}:{port}"
+        ))),
+    }
+}
+
+/// Rewrite a proxy request into origin-form for the upstream server.
+fn upstream_request(request: &RequestHead, path: &str, body: &[u8]) -> Vec<u8> {
+    let mut out = format!("{} {} HTTP/1.1\r\n", request.method, path).into_bytes();
+    for (name, value) in &request.headers {
+        let lower = name.to_ascii_lowercase();
+        if HOP_BY_HOP_HEADERS.contains(&lower.as_str())
+            || lower == "content-length"
+            || lower == "transfer-encoding"
+        {
+            continue;
+        }
+        out.extend_from_slice(format!("{name}: {value}\r\n").as_bytes());
+    }
+    if !body.is_empty() {
+        out.extend_from_slice(format!("Content-Length: {}\r\n", body.len()).as_bytes());
+    }
+    out.extend_from_slice(b"Connection: close\r\n\r\n");
+    out.extend_from_slice(body);
+    out
+}
+
+fn push_event(detail: &mut HttpProfileEntryDetail, event: &str) {
+    detail.events.push(HttpProfileEvent {
+        event: event.to_string(),
+        timestamp_us: now_us(),
+    });
+}
+
+fn record_response(
+    detail: &mut HttpProfileEntryDetail,
+    status: u16,
+    headers: &Headers,
+    body: &[u8],
+) {
+    detail.entry.status_code = Some(status);
+    detail.entry.content_type = http::header(headers, "content-type").map(str::to_string);
+    detail.entry.response_content_length = Some(body.len() as i64);
+    detail.response_headers = http::group_headers(headers);
+    detail.response_body = capped(body);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_http_url() {
+        assert_eq!(
+            split_http_url("http://a.com/x?y=1"),
+            Some(("a.com".to_string(), 80, "/x?y=1".to_string()))
+        );
+        assert_eq!(
+            split_http_url("http://a.com:8080"),
+            Some(("a.com".to_string(), 8080, "/".to_string()))
+        );
+        assert_eq!(split_http_url("https://a.com/"), None);
+    }
+
+    #[test]
+    fn test_split_authority() {
+        assert_eq!(
+            split_authority("a.com:8443", 443),
+            Some(("a.com".to_string(), 8443))
+        );
+        assert_eq!(
+            split_authority("a.com", 443),
+            Some(("a.com".to_string(), 443))
+        );
+        assert_eq!(split_authority("", 443), None);
+    }
+
+    #[test]
+    fn test_upstream_request_strips_hop_by_hop() {
+        let request = RequestHead::parse(
+            b"POST http://a.com/x HTTP/1.1\r\nHost: a.com\r\nProxy-Connection: keep-alive\r\nTransfer-Encoding: chunked",
+        )
+        .unwrap();
+        let text = String::from_utf8(upstream_request(&request, "/x", b"hi")).unwrap();
+        assert!(text.starts_with("POST /x HTTP/1.1\r\n"));
+        assert!(text.contains("Host: a.com\r\n"));
+        assert!(!text.to_lowercase().contains("proxy-connection"));
+        assert!(!text.to_lowercase().contains("transfer-encoding"));
+        assert!(text.contains("Content-Length: 2\r\n"));
+        assert!(text.ends_with("Connection: close\r\n\r\nhi"));
+    }
+
+    async fn start_proxy(rules_dir: PathBuf) -> ProxyHandle {
+        HttpProxy::bind(ProxyConfig {
+            bind_address: "127.0.0.1".to_string(),
+            port: 0,
+            rules_dir,
+        })
+        .await
+        .unwrap()
+        .spawn()
+        .unwrap()
+    }
+
+    async fn send_raw(addr: SocketAddr, request: &[u8]) -> String {
+        let mut stream = TcpStream::connect(addr).await.unwrap();
+        stream.write_all(request).await.unwrap();
+        let mut out = Vec::new();
+        stream.read_to_end(&mut out).await.unwrap();
+        String::from_utf8_lossy(&out).into_owned()
+    }
+
+    #[tokio::test]
+    async fn test_proxy_forwards_and_records() {
+        let upstream = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let upstream_addr = upstream.local_addr().unwrap();
+        tokio::spawn(async move {
+            let (mut sock, _) = upstream.accept().await.unwrap();
+            let (head, _) = http::read_head(&mut sock).await.unwrap();
+            let req = RequestHead::parse(&head).unwrap();
+            assert_eq!(req.target, "/hello");
+            sock.write_all(b"HTTP/1.1 200 OK\r\nContent-Type: text/plain\r\n\r\nworld")
+                .await
+                .unwrap();
+        });
+
+        let dir = tempfile::tempdir().unwrap();
+        let mut proxy = start_proxy(dir.path().to_path_buf()).await;
+        let response = send_raw(
+            proxy.local_addr,
+            format!("GET http://{upstream_addr}/hello HTTP/1.1\r\nHost: x\r\n\r\n").as_bytes(),
+        )
+        .await;
+        assert!(response.ends_with("world"));
+
+        let pending = proxy.exchange_rx.recv().await.unwrap();
+        assert!(pending.entry.is_pending());
+        assert!(pending.entry.id.starts_with(PROXY_ID_PREFIX));
+
+        let done = proxy.exchange_rx.recv().await.unwrap();
+        assert_eq!(done.entry.status_code, Some(200));
+        assert_eq!(done.entry.content_type.as_deref(), Some("text/plain"));
+        assert_eq!(done.response_body, b"world");
+        assert!(done.events.iter().any(|e| e.event == EVENT_CONNECTED));
+        proxy.task_handle.abort();
+    }
+
+    #[tokio::test]
+    async fn test_proxy_serves_mock_rule() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(
+            dir.path().join("mocks.toml"),
+            "[[rule]]\nname = \"m\"\nurl = \"http://mocked.invalid/*\"\nstatus = 418\nbody = \"tea\"",
+        )
+        .unwrap();
+
+        let mut proxy = start_proxy(dir.path().to_path_buf()).await;
+        let response = send_raw(
+            proxy.local_addr,
+            b"GET http://mocked.invalid/pot HTTP/1.1\r\nHost: mocked.invalid\r\n\r\n",
+        )
+        .await;
+        assert!(response.starts_with("HTTP/1.1 418 "));
+        assert!(response.contains("x-fdemon-mock: m\r\n"));
+        assert!(response.ends_with("tea"));
+
+        let _pending = proxy.exchange_rx.recv().await.unwrap();
+        let done = proxy.exchange_rx.recv().await.unwrap();
+        assert_eq!(done.entry.status_code, Some(418));
+        assert!(done.entry.error.is_none());
+        proxy.task_handle.abort();
+    }
+
+    #[tokio::test]
+    async fn test_proxy_rejects_oversized_body() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut proxy = start_proxy(dir.path().to_path_buf()).await;
+        let response = send_raw(
+            proxy.local_addr,
+            format!(
+                "POST http://upstream.invalid/ HTTP/1.1\r\nContent-Length: {}\r\n\r\n",
+                MAX_REQUEST_BODY_BYTES + 1
+            )
+            .as_bytes(),
+        )
+        .await;
+        assert!(response.starts_with("HTTP/1.1 413 Payload Too Large\r\n"));
+
+        let _pending = proxy.exchange_rx.recv().await.unwrap();
+        let done = proxy.exchange_rx.recv().await.unwrap();
+        assert_eq!(done.entry.status_code, Some(413));
+        assert!(done.entry.error.is_some());
+        proxy.task_handle.abort();
+    }
+
+    #[tokio::test]
+    async fn test_bind_falls_back_when_port_taken() {
+        let dir = tempfile::tempdir().unwrap();
+        let first = start_proxy(dir.path().to_path_buf()).await;
+        let second = HttpProxy::bind(ProxyConfig {
+            bind_address: "127.0.0.1".to_string(),
+            port: first.local_addr.port(),
+            rules_dir: dir.path().to_path_buf(),
+        })
+        .await
+        .unwrap();
+        assert_ne!(second.local_addr().unwrap().port(), first.local_addr.port());
+        first.task_handle.abort();
+    }
+
+    #[tokio::test]
+    async fn test_proxy_reports_upstream_failure() {
+        // Bind then drop to get a port with nothing listening.
+        let closed = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let closed_addr = closed.local_addr().unwrap();
+        drop(closed);
+
+        let dir = tempfile::tempdir().unwrap();
+        let mut proxy = start_proxy(dir.path().to_path_buf()).await;
+        let response = send_raw(
+            proxy.local_addr,
+            format!("GET http://{closed_addr}/ HTTP/1.1\r\n\r\n").as_bytes(),
+        )
+        .await;
+        assert!(response.starts_with("HTTP/1.1 502 "));
+
+        let _pending = proxy.exchange_rx.recv().await.unwrap();
+        let done = proxy.exchange_rx.recv().await.unwrap();
+        assert!(done.entry.error.is_some());
+        assert!(done.entry.end_time_us.is_some());
+        proxy.task_handle.abort();
+    }
+}
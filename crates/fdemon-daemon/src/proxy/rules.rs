@@ -0,0 +1,365 @@
+//! Mock response rules for the recording proxy.
+//!
+//! Rules live in `*.toml` files inside the configured rules directory
+//! (default `.fdemon/mocks/`). Each file holds one or more `[[rule]]` tables:
+//!
+//! ```toml
+//! [[rule]]
+//! name = "login fails"
+//! method = "POST"
+//! url = "http://api.example.com/login*"
+//! status = 401
+//! headers = { "Content-Type" = "application/json" }
+//! body = '{"error":"invalid credentials"}'
+//! delay_ms = 250
+//! ```
+//!
+//! The first enabled rule whose method and URL pattern match a request is
+//! served instead of contacting the upstream server.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::SystemTime;
+
+use fdemon_core::prelude::*;
+use serde::Deserialize;
+
+use super::http::Headers;
+
+/// Response header added to every mocked response so the app (and the
+/// Network panel) can tell it apart from a real one.
+pub const MOCK_HEADER: &str = "x-fdemon-mock";
+
+/// A single mock rule.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct MockRule {
+    /// Display name, shown in the mock header. Defaults to the URL pattern.
+    #[serde(default)]
+    pub name: String,
+
+    /// HTTP method to match (case-insensitive). Empty matches any method.
+    #[serde(default)]
+    pub method: String,
+
+    /// URL glob; `*` matches any run of characters.
+    pub url: String,
+
+    /// Response status code.
+    #[serde(default = "default_status")]
+    pub status: u16,
+
+    /// Response headers.
+    #[serde(default)]
+    pub headers: BTreeMap<String, String>,
+
+    /// Inline response body.
+    #[serde(default)]
+    pub body: String,
+
+    /// Response body file, relative to the rules directory. Takes
+    /// precedence over `body`.
+    #[serde(default)]
+    pub body_file: Option<PathBuf>,
+
+    /// Artificial latency before responding, in milliseconds.
+    #[serde(default)]
+    pub delay_ms: u64,
+
+    /// Disabled rules are loaded but never matched.
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+}
+
+fn default_status() -> u16 {
+    200
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+#[derive(Debug, Deserialize)]
+struct RuleFile {
+    #[serde(default)]
+    rule: Vec<MockRule>,
+}
+
+/// A rule resolved against its rules directory, ready to serve.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MockResponse {
+    pub rule_name: String,
+    pub status: u16,
+    pub headers: Headers,
+    pub body: Vec<u8>,
+    pub delay_ms: u64,
+}
+
+impl MockRule {
+    /// Whether this rule applies to `method` + `url`.
+    pub fn matches(&self, method: &str, url: &str) -> bool {
+        self.enabled
+            && (self.method.is_empty() || self.method.eq_ignore_ascii_case(method))
+            && glob_match(&self.url, url)
+    }
+
+    /// Build the response, reading `body_file` relative to `rules_dir`.
+    pub async fn response(&self, rules_dir: &Path) -> Result<MockResponse> {
+        let body = match &self.body_file {
+            Some(file) => tokio::fs::read(rules_dir.join(file)).await?,
+            None => self.body.clone().into_bytes(),
+        };
+        let rule_name = if self.name.is_empty() {
+            self.url.clone()
+        } else {
+            self.name.clone()
+        };
+        let mut headers: Headers = self
+            .headers
+            .iter()
+            .map(|(k, v)| (k.clone(), v.clone()))
+            .collect();
+        headers.push((MOCK_HEADER.to_string(), rule_name.clone()));
+        Ok(MockResponse {
+            rule_name,
+            status: self.status,
+            headers,
+            body,
+            delay_ms: self.delay_ms,
+        })
+    }
+}
+
+/// Parse the `[[rule]]` tables of one rules file.
+pub fn parse_rules(content: &str) -> Result<Vec<MockRule>> {
+    let file: RuleFile =
+        toml::from_str(content).map_err(|e| Error::config(format!("invalid mock rules: {e}")))?;
+    Ok(file.rule)
+}
+
+/// The `*.toml` files in `dir`, in file-name order. Empty when the directory
+/// is missing.
+fn rule_files(dir: &Path) -> Vec<PathBuf> {
+    let Ok(read_dir) = std::fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    let mut files: Vec<PathBuf> = read_dir
+        .filter_map(|e| e.ok().map(|e| e.path()))
+        .filter(|p| p.extension().is_some_and(|ext| ext == "toml"))
+        .collect();
+    files.sort();
+    files
+}
+
+/// Load every rule from the `*.toml` files in `dir`, in file-name order.
+///
+/// A missing directory yields no rules. Files that fail to parse are
+/// skipped with a warning so one bad file does not disable the others.
+pub fn load_rules(dir: &Path) -> Vec<MockRule> {
+    let mut rules = Vec::new();
+    for path in rule_files(dir) {
+        let parsed = std::fs::read_to_string(&path)
+            .map_err(Error::from)
+            .and_then(|content| parse_rules(&content));
+        match parsed {
+            Ok(mut file_rules) => rules.append(&mut file_rules),
+            Err(e) => tracing::warn!("Skipping mock rules file {}: {}", path.display(), e),
+        }
+    }
+    rules
+}
+
+/// Name, modification time and size of each rule file.
+type DirStamp = Vec<(PathBuf, Option<SystemTime>, u64)>;
+
+fn dir_stamp(dir: &Path) -> DirStamp {
+    rule_files(dir)
+        .into_iter()
+        .map(|path| {
+            let meta = std::fs::metadata(&path).ok();
+            let modified = meta.as_ref().and_then(|m| m.modified().ok());
+            let len = meta.map_or(0, |m| m.len());
+            (path, modified, len)
+        })
+        .collect()
+}
+
+/// Rules of one directory, parsed once and re-read only when a rule file is
+/// added, removed or modified.
+///
+/// Checking and reloading do blocking filesystem I/O: call [`Self::rules`]
+/// off the async runtime, e.g. from `spawn_blocking`. The lock is only held
+/// to swap the parsed rules, never across the I/O.
+#[derive(Debug)]
+pub struct RuleCache {
+    dir: PathBuf,
+    loaded: Mutex<(DirStamp, Arc<Vec<MockRule>>)>,
+}
+
+impl RuleCache {
+    /// Load the rules in `dir`.
+    pub fn new(dir: PathBuf) -> Self {
+        let stamp = dir_stamp(&dir);
+        let rules = Arc::new(load_rules(&dir));
+        Self {
+            dir,
+            loaded: Mutex::new((stamp, rules)),
+        }
+    }
+
+    /// The current rules, reloaded first if the rule files changed.
+    pub fn rules(&self) -> Arc<Vec<MockRule>> {
+        let stamp = dir_stamp(&self.dir);
+        {
+            let loaded = self.loaded.lock().unwrap_or_else(|e| e.into_inner());
+            if loaded.0 == stamp {
+                return Arc::clone(&loaded.1);
+            }
+        }
+        tracing::debug!("Reloading mock rules from {}", self.dir.display());
+        let rules = Arc::new(load_rules(&self.dir));
+        *self.loaded.lock().unwrap_or_else(|e| e.into_inner()) = (stamp, Arc::clone(&rules));
+        rules
+    }
+}
+
+/// Find the first rule matching `method` + `url`.
+pub fn find_match<'a>(rules: &'a [MockRule], method: &str, url: &str) -> Option<&'a MockRule> {
+    rules.iter().find(|r| r.matches(method, url))
+}
+
+/// Match `text` against `pattern`, where `*` matches any run of characters
+/// and everything else matches literally.
+pub fn glob_match(pattern: &str, text: &str) -> bool {
+    let mut parts = pattern.split('*');
+    let first = parts.next().unwrap_or_default();
+    let Some(mut rest) = text.strip_prefix(first) else {
+        return false;
+    };
+    let parts: Vec<&str> = parts.collect();
+    let Some((last, middle)) = parts.split_last() else {
+        // No `*` in the pattern: exact match.
+        return rest.is_empty();
+    };
+    for part in middle {
+        match rest.find(part) {
+            Some(pos) => rest = &rest[pos + part.len()..],
+            None => return false,
+        }
+    }
+    rest.ends_with(last)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_glob_match() {
+        assert!(glob_match("http://a.com/x", "http://a.com/x"));
+        assert!(!glob_match("http://a.com/x", "http://a.com/xy"));
+        assert!(glob_match("http://a.com/*", "http://a.com/users/1"));
+        assert!(glob_match("*/users/*", "http://a.com/users/1"));
+        assert!(glob_match("*", "anything"));
+        assert!(!glob_match("http://a.com/*/posts", "http://a.com/users/1"));
+        assert!(glob_match("*a*a", "banana"));
+    }
+
+    #[test]
+    fn test_parse_rules_defaults() {
+        let rules = parse_rules(
+            r#"
+            [[rule]]
+            url = "http://api.test/*"
+            "#,
+        )
+        .unwrap();
+        assert_eq!(rules.len(), 1);
+        assert_eq!(rules[0].status, 200);
+        assert!(rules[0].enabled);
+        assert!(rules[0].method.is_empty());
+    }
+
+    #[test]
+    fn test_parse_rules_invalid() {
+        assert!(parse_rules("[[rule]]\nstatus = 1").is_err());
+    }
+
+    #[test]
+    fn test_rule_matches_method_and_enabled() {
+        let mut rule = parse_rules("[[rule]]\nmethod = \"post\"\nurl = \"*/login\"")
+            .unwrap()
+            .remove(0);
+        assert!(rule.matches("POST", "http://x/login"));
+        assert!(!rule.matches("GET", "http://x/login"));
+        rule.enabled = false;
+        assert!(!rule.matches("POST", "http://x/login"));
+    }
+
+    #[tokio::test]
+    async fn test_response_adds_mock_header() {
+        let rule = parse_rules("[[rule]]\nname = \"n\"\nurl = \"*\"\nstatus = 503\nbody = \"x\"")
+            .unwrap()
+            .remove(0);
+        let resp = rule.response(Path::new(".")).await.unwrap();
+        assert_eq!(resp.status, 503);
+        assert_eq!(resp.body, b"x");
+        assert!(resp
+            .headers
+            .contains(&(MOCK_HEADER.to_string(), "n".to_string())));
+    }
+
+    #[tokio::test]
+    async fn test_load_rules_from_dir() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("b.toml"), "[[rule]]\nurl = \"b\"").unwrap();
+        std::fs::write(
+            dir.path().join("a.toml"),
+            "[[rule]]\nurl = \"a\"\nbody_file = \"a.json\"",
+        )
+        .unwrap();
+        std::fs::write(dir.path().join("a.json"), "{}").unwrap();
+        std::fs::write(dir.path().join("broken.toml"), "not = [valid").unwrap();
+
+        let rules = load_rules(dir.path());
+        assert_eq!(rules.len(), 2);
+        assert_eq!(rules[0].url, "a");
+        assert_eq!(rules[0].response(dir.path()).await.unwrap().body, b"{}");
+    }
+
+    #[test]
+    fn test_rule_cache_reloads_on_change() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = RuleCache::new(dir.path().to_path_buf());
+        assert!(cache.rules().is_empty());
+
+        std::fs::write(dir.path().join("a.toml"), "[[rule]]\nurl = \"a\"").unwrap();
+        let rules = cache.rules();
+        assert_eq!(rules.len(), 1);
+        // Unchanged files are not parsed again.
+        assert!(Arc::ptr_eq(&rules, &cache.rules()));
+
+        std::fs::write(
+            dir.path().join("a.toml"),
+            "[[rule]]\nurl = \"a\"\n[[rule]]\nurl = \"b\"",
+        )
+        .unwrap();
+        assert_eq!(cache.rules().len(), 2);
+
+        std::fs::remove_file(dir.path().join("a.toml")).unwrap();
+        assert!(cache.rules().is_empty());
+    }
+
+    #[test]
+    fn test_load_rules_missing_dir() {
+        assert!(load_rules(Path::new("/nonexistent/fdemon/mocks")).is_empty());
+    }
+
+    #[test]
+    fn test_find_match_first_wins() {
+        let rules =
+            parse_rules("[[rule]]\nname = \"1\"\nurl = \"*\"\n[[rule]]\nname = \"2\"\nurl = \"*\"")
+                .unwrap();
+        assert_eq!(find_match(&rules, "GET", "x").unwrap().name, "1");
+    }
+}
@@ -0,0 +1,131 @@
+//! Device screenshot capture.
+//!
+//! Three capture paths, tried in order by the app layer:
+//! 1. The daemon's `app.screenshot` request ([`DaemonCommand::Screenshot`]).
+//! 2. The engine's `_flutter.screenshot` VM Service RPC ([`vm_screenshot`]).
+//! 3. `flutter screenshot -d <device> -o <file>` ([`flutter_cli_screenshot`]).
+//!
+//! The first two return base64-encoded PNG data, decoded by
+//! [`decode_screenshot_response`].
+//!
+//! [`DaemonCommand::Screenshot`]: crate::DaemonCommand::Screenshot
+
+use std::path::Path;
+use std::process::Stdio;
+
+use base64::Engine as _;
+use fdemon_core::prelude::*;
+use serde_json::Value;
+
+use crate::vm_service::VmRequestHandle;
+use crate::FlutterExecutable;
+
+/// VM Service RPC registered by the Flutter engine that renders the current
+/// frame to a base64 PNG.
+pub const FLUTTER_SCREENSHOT_RPC: &str = "_flutter.screenshot";
+
+/// Decode the base64 PNG from a screenshot response.
+///
+/// The engine RPC returns `{"type": "Screenshot", "screenshot": "<base64>"}`;
+/// daemon responses use `"data"` or `"value"`.
+///
+/// # Errors
+///
+/// Returns [`Error::Protocol`] if no image field is present or it is not
+/// valid base64.
+pub fn decode_screenshot_response(value: &Value) -> Result<Vec<u8>> {
+    let encoded = ["screenshot", "data", "value"]
+        .iter()
+        .find_map(|key| value.get(key).and_then(Value::as_str))
+        .ok_or_else(|| Error::protocol("missing image data in screenshot response"))?;
+    base64::engine::general_purpose::STANDARD
+        .decode(encoded)
+        .map_err(|e| Error::protocol(format!("invalid screenshot data: {e}")))
+}
+
+/// Capture the current frame through the engine's `_flutter.screenshot` RPC.
+///
+/// # Errors
+///
+/// Returns an error if the RPC is unavailable (e.g. web targets), the call
+/// fails, or the response cannot be decoded.
+pub async fn vm_screenshot(handle: &VmRequestHandle) -> Result<Vec<u8>> {
+    let value = handle.request(FLUTTER_SCREENSHOT_RPC, None).await?;
+    decode_screenshot_response(&value)
+}
+
+/// Capture a screenshot with `flutter screenshot -d <device_id> -o <out>`.
+///
+/// Uses the platform tooling (adb, simctl, ...) rather than the running app,
+/// so it also works when neither the daemon nor the VM Service can take one.
+///
+/// # Errors
+///
+/// Returns [`Error::FlutterNotFound`] if the executable is missing, or
+/// [`Error::Process`] if the command fails.
+pub async fn flutter_cli_screenshot(
+    flutter: &FlutterExecutable,
+    device_id: &str,
+    out: &Path,
+) -> Result<()> {
+    let output = flutter
+        .command()
+        .arg("screenshot")
+        .args(["-d", device_id, "-o"])
+        .arg(out)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .output()
+        .await
+        .map_err(|e| {
+            if e.kind() == std::io::ErrorKind::NotFound {
+                Error::FlutterNotFound
+            } else {
+                Error::process(format!(
+                    "Failed to run flutter screenshot ({}): {}",
+                    flutter.path().display(),
+                    e
+                ))
+            }
+        })?;
+
+    if output.status.success() {
+        return Ok(());
+    }
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let detail = if stderr.trim().is_empty() {
+        stdout.trim()
+    } else {
+        stderr.trim()
+    };
+    Err(Error::process(format!(
+        "flutter screenshot failed: {detail}"
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_decode_engine_screenshot_response() {
+        let value = json!({"type": "Screenshot", "screenshot": "iVBORw0KGgo="});
+        let bytes = decode_screenshot_response(&value).unwrap();
+        assert_eq!(&bytes[1..4], b"PNG");
+    }
+
+    #[test]
+    fn test_decode_daemon_data_response() {
+        let value = json!({"data": "iVBORw0KGgo="});
+        assert!(decode_screenshot_response(&value).is_ok());
+    }
+
+    #[test]
+    fn test_decode_rejects_missing_or_invalid_data() {
+        assert!(decode_screenshot_response(&json!({"type": "Success"})).is_err());
+        assert!(decode_screenshot_response(&json!({"screenshot": "not base64!"})).is_err());
+    }
+}
@@ -26,13 +26,13 @@
 //! └─────────────────────────────────────────────────────────────┘
 //! ```
 
-use std::collections::HashMap;
+use std::collections::{BTreeSet, HashMap};
 use std::sync::Arc;
 use std::time::Duration;
 
 use futures_util::stream::SplitSink;
 use futures_util::{SinkExt, StreamExt};
-use tokio::sync::{mpsc, oneshot, Mutex};
+use tokio::sync::{mpsc, oneshot, watch, Mutex};
 use tokio_tungstenite::connect_async;
 use tokio_tungstenite::tungstenite::protocol::Message as WsMessage;
 use tokio_tungstenite::{MaybeTlsStream, WebSocketStream};
@@ -64,6 +64,17 @@ pub struct VmRequestHandle {
     state: Arc<std::sync::RwLock<ConnectionState>>,
     /// Cached main isolate ID. Cleared by the background task on reconnection.
     isolate_id_cache: Arc<Mutex<Option<String>>>,
+    /// Isolate picked in DevTools for per-isolate RPCs; `None` for the main
+    /// isolate.
+    target_isolate: Arc<std::sync::RwLock<Option<String>>>,
+    /// Streams whose raw events are echoed to the VM Service console.
+    tailed_streams: Arc<std::sync::RwLock<BTreeSet<String>>>,
+    /// Streams the console subscribed to itself with `streamListen`, and so
+    /// may cancel when the tail stops.
+    console_subscriptions: Arc<std::sync::RwLock<BTreeSet<String>>>,
+    /// Bumped by [`Self::invalidate_isolate_cache`] so polling tasks can
+    /// follow the new main isolate.
+    isolate_changes: Arc<watch::Sender<u64>>,
     /// The WebSocket URI this handle is connected to.
     ws_uri: String,
 }
@@ -166,6 +177,92 @@ impl VmRequestHandle {
         Ok(id)
     }
 
+    /// Pick the isolate used by [`Self::target_isolate_id`]. `None` returns
+    /// to the main isolate. Shared by all clones of this handle, so running
+    /// polling tasks follow the change on their next tick.
+    pub fn set_target_isolate(&self, isolate_id: Option<String>) {
+        *self
+            .target_isolate
+            .write()
+            .unwrap_or_else(|e| e.into_inner()) = isolate_id;
+    }
+
+    /// Isolate picked with [`Self::set_target_isolate`], `None` for the main
+    /// isolate.
+    pub fn target_isolate(&self) -> Option<String> {
+        self.target_isolate
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+    }
+
+    /// Isolate for per-isolate DevTools RPCs (memory usage, allocation
+    /// profiles, instances, CPU samples): the one picked with
+    /// [`Self::set_target_isolate`], or else the main isolate.
+    ///
+    /// Flutter service extensions only exist on the main isolate and keep
+    /// using [`Self::main_isolate_id`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no isolate is picked and the main isolate cannot
+    /// be discovered.
+    pub async fn target_isolate_id(&self) -> Result<String> {
+        match self.target_isolate() {
+            Some(id) => Ok(id),
+            None => self.main_isolate_id().await,
+        }
+    }
+
+    /// Start or stop echoing the raw events of `stream_id` to the console.
+    /// Only marks the stream; subscribing with `streamListen` is up to the
+    /// caller.
+    pub fn set_stream_tailed(&self, stream_id: &str, tailed: bool) {
+        let mut streams = self
+            .tailed_streams
+            .write()
+            .unwrap_or_else(|e| e.into_inner());
+        if tailed {
+            streams.insert(stream_id.to_string());
+        } else {
+            streams.remove(stream_id);
+        }
+    }
+
+    /// Whether raw events of `stream_id` are echoed to the console.
+    pub fn is_stream_tailed(&self, stream_id: &str) -> bool {
+        self.tailed_streams
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .contains(stream_id)
+    }
+
+    /// Streams whose raw events are echoed to the console.
+    pub fn tailed_streams(&self) -> BTreeSet<String> {
+        self.tailed_streams
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+    }
+
+    /// Record that the console's own `streamListen` subscribed to
+    /// `stream_id`.
+    pub fn mark_console_subscription(&self, stream_id: &str) {
+        self.console_subscriptions
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(stream_id.to_string());
+    }
+
+    /// Forget the console's subscription to `stream_id`, returning whether
+    /// there was one. Only then should the console send `streamCancel`.
+    pub fn take_console_subscription(&self, stream_id: &str) -> bool {
+        self.console_subscriptions
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .remove(stream_id)
+    }
+
     /// Create a `VmRequestHandle` backed by a disconnected dummy channel.
     ///
     /// Intended for unit tests that need a handle but do not make real RPC
@@ -178,6 +275,10 @@ impl VmRequestHandle {
             cmd_tx,
             state: Arc::new(std::sync::RwLock::new(ConnectionState::Connected)),
             isolate_id_cache: Arc::new(Mutex::new(isolate_id)),
+            target_isolate: Arc::default(),
+            tailed_streams: Arc::default(),
+            console_subscriptions: Arc::default(),
+            isolate_changes: Arc::new(watch::channel(0).0),
             ws_uri: String::new(),
         }
     }
@@ -218,6 +319,14 @@ impl VmRequestHandle {
         } else {
             debug!("VM Service: isolate ID cache lock contention during invalidation — skipped");
         }
+        self.isolate_changes
+            .send_modify(|generation| *generation += 1);
+    }
+
+    /// Receiver that changes whenever the main isolate is replaced (see
+    /// [`Self::invalidate_isolate_cache`]).
+    pub fn isolate_changes(&self) -> watch::Receiver<u64> {
+        self.isolate_changes.subscribe()
     }
 
     /// Call a Flutter service extension method.
@@ -285,6 +394,7 @@ const RESUBSCRIBE_STREAMS: &[&str] = &[
     stream_id::GC,
     stream_id::DEBUG,
     stream_id::ISOLATE,
+    stream_id::TIMELINE,
 ];
 
 /// How often to run stale request cleanup in the I/O loop.
@@ -403,6 +513,10 @@ impl VmServiceClient {
                 cmd_tx,
                 state,
                 isolate_id_cache,
+                target_isolate: Arc::default(),
+                tailed_streams: Arc::default(),
+                console_subscriptions: Arc::default(),
+                isolate_changes: Arc::new(watch::channel(0).0),
                 ws_uri: ws_uri.to_string(),
             },
             event_rx,
@@ -541,7 +655,7 @@ impl VmServiceClient {
         Ok(main_isolate.clone())
     }
 
-    /// Subscribe to Flutter streams (Extension, Logging, GC, Debug, and Isolate).
+    /// Subscribe to Flutter streams (Extension, Logging, GC, Debug, Isolate, and Timeline).
     ///
     /// Returns a list of human-readable error descriptions for any streams
     /// that could not be subscribed (non-fatal — the app continues without
@@ -574,6 +688,11 @@ impl VmServiceClient {
             errors.push(format!("Isolate stream: {e}"));
         }
 
+        // Timeline stream: trace events for per-frame phase breakdowns
+        if let Err(e) = self.stream_listen(stream_id::TIMELINE).await {
+            errors.push(format!("Timeline stream: {e}"));
+        }
+
         errors
     }
 
@@ -1329,6 +1448,10 @@ mod tests {
             cmd_tx,
             state: Arc::new(std::sync::RwLock::new(ConnectionState::Connected)),
             isolate_id_cache: Arc::new(Mutex::new(None)),
+            target_isolate: Arc::default(),
+            tailed_streams: Arc::default(),
+            console_subscriptions: Arc::default(),
+            isolate_changes: Arc::new(watch::channel(0).0),
             ws_uri: String::new(),
         };
         // Drop the receiver to simulate disconnection
@@ -1343,6 +1466,10 @@ mod tests {
             cmd_tx: mpsc::channel::<ClientCommand>(1).0,
             state: Arc::new(std::sync::RwLock::new(ConnectionState::Connected)),
             isolate_id_cache: Arc::new(Mutex::new(None)),
+            target_isolate: Arc::default(),
+            tailed_streams: Arc::default(),
+            console_subscriptions: Arc::default(),
+            isolate_changes: Arc::new(watch::channel(0).0),
             ws_uri: String::new(),
         };
         let debug_str = format!("{:?}", handle);
@@ -1357,6 +1484,10 @@ mod tests {
             cmd_tx: mpsc::channel::<ClientCommand>(1).0,
             state: Arc::clone(&state),
             isolate_id_cache: Arc::new(Mutex::new(None)),
+            target_isolate: Arc::default(),
+            tailed_streams: Arc::default(),
+            console_subscriptions: Arc::default(),
+            isolate_changes: Arc::new(watch::channel(0).0),
             ws_uri: String::new(),
         };
         let cloned = handle.clone();
@@ -1381,6 +1512,46 @@ mod tests {
         assert_send_sync::<VmRequestHandle>();
     }
 
+    // -- target_isolate_id ---------------------------------------------------
+
+    #[tokio::test]
+    async fn test_target_isolate_overrides_main_isolate() {
+        let handle = VmRequestHandle::new_for_test(Some("isolates/main".to_string()));
+        let cloned = handle.clone();
+        assert_eq!(handle.target_isolate_id().await.unwrap(), "isolates/main");
+
+        cloned.set_target_isolate(Some("isolates/worker".to_string()));
+        assert_eq!(handle.target_isolate_id().await.unwrap(), "isolates/worker");
+        assert_eq!(handle.main_isolate_id().await.unwrap(), "isolates/main");
+
+        handle.set_target_isolate(None);
+        assert_eq!(cloned.target_isolate_id().await.unwrap(), "isolates/main");
+    }
+
+    #[test]
+    fn test_stream_tailing_is_shared_by_clones() {
+        let handle = VmRequestHandle::new_for_test(None);
+        let cloned = handle.clone();
+        assert!(!handle.is_stream_tailed("Stdout"));
+
+        cloned.set_stream_tailed("Stdout", true);
+        assert!(handle.is_stream_tailed("Stdout"));
+        assert!(!handle.is_stream_tailed("Stderr"));
+
+        handle.set_stream_tailed("Stdout", false);
+        assert!(!cloned.is_stream_tailed("Stdout"));
+    }
+
+    #[test]
+    fn test_console_subscription_taken_once() {
+        let handle = VmRequestHandle::new_for_test(None);
+        assert!(!handle.take_console_subscription("GC"));
+
+        handle.clone().mark_console_subscription("GC");
+        assert!(handle.take_console_subscription("GC"));
+        assert!(!handle.take_console_subscription("GC"));
+    }
+
     // -- invalidate_isolate_cache --------------------------------------------
 
     #[tokio::test]
@@ -1392,6 +1563,10 @@ mod tests {
             cmd_tx: mpsc::channel::<ClientCommand>(1).0,
             state: Arc::new(std::sync::RwLock::new(ConnectionState::Connected)),
             isolate_id_cache: Arc::clone(&isolate_id_cache),
+            target_isolate: Arc::default(),
+            tailed_streams: Arc::default(),
+            console_subscriptions: Arc::default(),
+            isolate_changes: Arc::new(watch::channel(0).0),
             ws_uri: String::new(),
         };
 
@@ -1412,6 +1587,18 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_invalidate_isolate_cache_notifies_isolate_changes() {
+        let handle = VmRequestHandle::new_for_test(Some("isolates/1".to_string()));
+        let mut changes = handle.clone().isolate_changes();
+        assert!(!changes.has_changed().unwrap());
+
+        handle.invalidate_isolate_cache();
+        assert!(changes.has_changed().unwrap());
+        changes.mark_unchanged();
+        assert!(!changes.has_changed().unwrap());
+    }
+
     #[tokio::test]
     async fn test_invalidate_isolate_cache_is_idempotent_when_already_empty() {
         // Cache starts empty — invalidating should be a no-op without panic.
@@ -1421,6 +1608,10 @@ mod tests {
             cmd_tx: mpsc::channel::<ClientCommand>(1).0,
             state: Arc::new(std::sync::RwLock::new(ConnectionState::Connected)),
             isolate_id_cache: Arc::clone(&isolate_id_cache),
+            target_isolate: Arc::default(),
+            tailed_streams: Arc::default(),
+            console_subscriptions: Arc::default(),
+            isolate_changes: Arc::new(watch::channel(0).0),
             ws_uri: String::new(),
         };
 
@@ -1443,6 +1634,10 @@ mod tests {
             cmd_tx: mpsc::channel::<ClientCommand>(1).0,
             state: Arc::new(std::sync::RwLock::new(ConnectionState::Connected)),
             isolate_id_cache: Arc::clone(&isolate_id_cache),
+            target_isolate: Arc::default(),
+            tailed_streams: Arc::default(),
+            console_subscriptions: Arc::default(),
+            isolate_changes: Arc::new(watch::channel(0).0),
             ws_uri: String::new(),
         };
         let cloned = handle.clone();
@@ -1501,5 +1696,6 @@ mod tests {
         assert!(RESUBSCRIBE_STREAMS.contains(&stream_id::EXTENSION));
         assert!(RESUBSCRIBE_STREAMS.contains(&stream_id::LOGGING));
         assert!(RESUBSCRIBE_STREAMS.contains(&stream_id::GC));
+        assert!(RESUBSCRIBE_STREAMS.contains(&stream_id::TIMELINE));
     }
 }
@@ -0,0 +1,258 @@
+//! Raw RPC calls for the VM Service console.
+//!
+//! [`call_raw`] sends any method with JSON params, filling in `isolateId`
+//! for isolate-scoped methods, and [`list_extension_rpcs`] collects the
+//! service extensions registered by the running isolates. Together with
+//! [`VM_SERVICE_METHODS`] they back the console's method completion, and
+//! [`stream_event_to_json`] renders events of tailed streams.
+
+use fdemon_core::prelude::*;
+use serde_json::{Map, Value};
+
+use super::client::VmRequestHandle;
+use super::protocol::{IsolateInfo, StreamEvent, VmInfo};
+
+/// Public VM Service protocol methods, for completion.
+pub const VM_SERVICE_METHODS: &[&str] = &[
+    "addBreakpoint",
+    "addBreakpointAtEntry",
+    "addBreakpointWithScriptUri",
+    "clearCpuSamples",
+    "clearVMTimeline",
+    "evaluate",
+    "evaluateInFrame",
+    "getAllocationProfile",
+    "getAllocationTraces",
+    "getClassList",
+    "getCpuSamples",
+    "getFlagList",
+    "getInboundReferences",
+    "getInstances",
+    "getInstancesAsList",
+    "getIsolate",
+    "getIsolateGroup",
+    "getIsolateGroupMemoryUsage",
+    "getIsolatePauseEvent",
+    "getMemoryUsage",
+    "getObject",
+    "getPerfettoCpuSamples",
+    "getPerfettoVMTimeline",
+    "getPorts",
+    "getProcessMemoryUsage",
+    "getRetainingPath",
+    "getScripts",
+    "getSourceReport",
+    "getStack",
+    "getSupportedProtocols",
+    "getVM",
+    "getVMTimeline",
+    "getVMTimelineFlags",
+    "getVMTimelineMicros",
+    "getVersion",
+    "invoke",
+    "kill",
+    "lookupPackageUris",
+    "lookupResolvedPackageUris",
+    "pause",
+    "reloadSources",
+    "removeBreakpoint",
+    "requestHeapSnapshot",
+    "resume",
+    "setBreakpointState",
+    "setExceptionPauseMode",
+    "setFlag",
+    "setIsolatePauseMode",
+    "setLibraryDebuggable",
+    "setName",
+    "setTraceClassAllocation",
+    "setVMName",
+    "setVMTimelineFlags",
+    "streamCancel",
+    "streamCpuSamplesWithUserTag",
+    "streamListen",
+];
+
+/// Methods that address the VM or an isolate group rather than an isolate.
+const VM_SCOPED_METHODS: &[&str] = &[
+    "clearVMTimeline",
+    "getFlagList",
+    "getIsolateGroup",
+    "getIsolateGroupMemoryUsage",
+    "getPerfettoVMTimeline",
+    "getProcessMemoryUsage",
+    "getSupportedProtocols",
+    "getVM",
+    "getVMTimeline",
+    "getVMTimelineFlags",
+    "getVMTimelineMicros",
+    "getVersion",
+    "registerService",
+    "setFlag",
+    "setVMName",
+    "setVMTimelineFlags",
+    "streamCancel",
+    "streamCpuSamplesWithUserTag",
+    "streamListen",
+];
+
+/// Whether `method` takes an `isolateId`. Service extensions (`ext.*`) and
+/// unknown methods are assumed to.
+pub fn takes_isolate_id(method: &str) -> bool {
+    !VM_SCOPED_METHODS.contains(&method)
+}
+
+/// VM Service error code for `streamListen` on a stream this client
+/// already listens to.
+const STREAM_ALREADY_SUBSCRIBED_CODE: i32 = 103;
+
+/// Whether `error` is the VM Service's "stream already subscribed" error
+/// (code 103).
+pub fn is_stream_already_subscribed(error: &Error) -> bool {
+    match error {
+        Error::Protocol { message } => {
+            message
+                .strip_prefix("VM Service error ")
+                .and_then(|rest| rest.split(':').next())
+                .and_then(|code| code.parse::<i32>().ok())
+                == Some(STREAM_ALREADY_SUBSCRIBED_CODE)
+        }
+        _ => false,
+    }
+}
+
+/// Call `method` with `params`, adding the DevTools target isolate (see
+/// [`VmRequestHandle::target_isolate_id`]) as `isolateId` when the method
+/// takes one and `params` does not name it.
+///
+/// # Errors
+///
+/// Returns [`Error::Protocol`] for a JSON-RPC error response, or a
+/// transport error if the request fails.
+pub async fn call_raw(
+    handle: &VmRequestHandle,
+    method: &str,
+    params: Option<Map<String, Value>>,
+) -> Result<Value> {
+    let mut params = params.unwrap_or_default();
+    if takes_isolate_id(method) && !params.contains_key("isolateId") {
+        let isolate_id = handle.target_isolate_id().await?;
+        params.insert("isolateId".to_string(), Value::String(isolate_id));
+    }
+    let params = (!params.is_empty()).then_some(Value::Object(params));
+    handle.request(method, params).await
+}
+
+/// Service extension methods registered by one isolate, sorted.
+///
+/// # Errors
+///
+/// Returns [`Error::VmService`] if `getIsolate` cannot be parsed, or a
+/// transport error if it fails.
+pub async fn isolate_extension_rpcs(
+    handle: &VmRequestHandle,
+    isolate_id: &str,
+) -> Result<Vec<String>> {
+    let params = serde_json::json!({ "isolateId": isolate_id });
+    let info: IsolateInfo =
+        serde_json::from_value(handle.request("getIsolate", Some(params)).await?)
+            .map_err(|e| Error::vm_service(format!("parse getIsolate response: {e}")))?;
+    let mut extensions = info.extension_rpcs.unwrap_or_default();
+    extensions.sort();
+    Ok(extensions)
+}
+
+/// Service extension methods registered by the non-system isolates, sorted
+/// and without duplicates.
+///
+/// # Errors
+///
+/// Returns [`Error::VmService`] if `getVM` cannot be parsed, or a transport
+/// error if it fails. Isolates that cannot be read are skipped.
+pub async fn list_extension_rpcs(handle: &VmRequestHandle) -> Result<Vec<String>> {
+    let vm: VmInfo = serde_json::from_value(handle.request("getVM", None).await?)
+        .map_err(|e| Error::vm_service(format!("parse getVM response: {e}")))?;
+
+    let mut extensions = Vec::new();
+    for isolate in vm
+        .isolates
+        .iter()
+        .filter(|i| !i.is_system_isolate.unwrap_or(false))
+    {
+        if let Ok(rpcs) = isolate_extension_rpcs(handle, &isolate.id).await {
+            extensions.extend(rpcs);
+        }
+    }
+    extensions.sort();
+    extensions.dedup();
+    Ok(extensions)
+}
+
+/// A stream event as JSON for the console: its kind, isolate name and
+/// timestamp followed by the kind-specific fields.
+pub fn stream_event_to_json(event: &StreamEvent) -> Value {
+    let mut json = Map::new();
+    json.insert("kind".to_string(), Value::String(event.kind.clone()));
+    if let Some(isolate) = &event.isolate {
+        json.insert("isolate".to_string(), Value::String(isolate.name.clone()));
+    }
+    if let Some(timestamp) = event.timestamp {
+        json.insert("timestamp".to_string(), Value::from(timestamp));
+    }
+    if let Value::Object(data) = &event.data {
+        json.extend(data.iter().map(|(k, v)| (k.clone(), v.clone())));
+    }
+    Value::Object(json)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stream_event_to_json() {
+        let event: StreamEvent = serde_json::from_value(serde_json::json!({
+            "kind": "GC",
+            "isolate": { "id": "isolates/1", "name": "main" },
+            "timestamp": 1700,
+            "reason": "scavenge"
+        }))
+        .unwrap();
+        assert_eq!(
+            stream_event_to_json(&event),
+            serde_json::json!({
+                "kind": "GC",
+                "isolate": "main",
+                "timestamp": 1700,
+                "reason": "scavenge"
+            })
+        );
+    }
+
+    #[test]
+    fn test_takes_isolate_id() {
+        assert!(takes_isolate_id("getIsolate"));
+        assert!(takes_isolate_id("ext.flutter.debugDumpApp"));
+        assert!(!takes_isolate_id("getVM"));
+        assert!(!takes_isolate_id("streamListen"));
+        assert!(VM_SCOPED_METHODS
+            .iter()
+            .filter(|m| **m != "registerService")
+            .all(|m| VM_SERVICE_METHODS.contains(m)));
+    }
+
+    #[test]
+    fn test_is_stream_already_subscribed() {
+        assert!(is_stream_already_subscribed(&Error::protocol(
+            "VM Service error 103: Stream already subscribed"
+        )));
+        assert!(!is_stream_already_subscribed(&Error::protocol(
+            "VM Service error 104: Stream not subscribed"
+        )));
+        assert!(!is_stream_already_subscribed(&Error::protocol(
+            "VM Service error 100: already subscribed"
+        )));
+        assert!(!is_stream_already_subscribed(&Error::vm_service(
+            "VM Service error 103: Stream already subscribed"
+        )));
+    }
+}
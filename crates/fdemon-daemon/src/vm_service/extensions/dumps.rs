@@ -17,33 +17,61 @@ use super::VmServiceClient;
 ///
 /// Each variant corresponds to a Flutter service extension that formats an
 /// internal Flutter tree as a multi-line string.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum DebugDumpKind {
     /// Widget tree (`debugDumpApp`) — available in debug and profile mode.
+    #[default]
     WidgetTree,
     /// Render tree (`debugDumpRenderTree`) — available in debug and profile mode.
     RenderTree,
     /// Layer tree (`debugDumpLayerTree`) — debug mode only.
     LayerTree,
+    /// Semantics tree in traversal order
+    /// (`debugDumpSemanticsTreeInTraversalOrder`) — available in debug and
+    /// profile mode. Empty unless semantics are enabled on the device.
+    SemanticsTree,
+    /// Focus tree (`debugDumpFocusTree`) — available in debug and profile mode.
+    FocusTree,
 }
 
 impl DebugDumpKind {
+    /// Every dump kind, in display order.
+    pub const ALL: [DebugDumpKind; 5] = [
+        Self::WidgetTree,
+        Self::RenderTree,
+        Self::LayerTree,
+        Self::SemanticsTree,
+        Self::FocusTree,
+    ];
+
     /// Get the extension method name for this dump kind.
     pub fn method(&self) -> &'static str {
         match self {
             Self::WidgetTree => ext::DEBUG_DUMP_APP,
             Self::RenderTree => ext::DEBUG_DUMP_RENDER_TREE,
             Self::LayerTree => ext::DEBUG_DUMP_LAYER_TREE,
+            Self::SemanticsTree => ext::DEBUG_DUMP_SEMANTICS_TREE,
+            Self::FocusTree => ext::DEBUG_DUMP_FOCUS_TREE,
+        }
+    }
+
+    /// Short display label (also used in saved file names).
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::WidgetTree => "Widgets",
+            Self::RenderTree => "Render",
+            Self::LayerTree => "Layers",
+            Self::SemanticsTree => "Semantics",
+            Self::FocusTree => "Focus",
         }
     }
 
     /// Whether this dump is available in profile mode.
     ///
-    /// `WidgetTree` and `RenderTree` are available in both debug and profile
-    /// mode. `LayerTree` is debug mode only.
+    /// Only `LayerTree` is debug mode only.
     pub fn available_in_profile(&self) -> bool {
         match self {
-            Self::WidgetTree | Self::RenderTree => true,
+            Self::WidgetTree | Self::RenderTree | Self::SemanticsTree | Self::FocusTree => true,
             Self::LayerTree => false,
         }
     }
@@ -153,6 +181,28 @@ mod tests {
         assert!(DebugDumpKind::WidgetTree.available_in_profile());
         assert!(DebugDumpKind::RenderTree.available_in_profile());
         assert!(!DebugDumpKind::LayerTree.available_in_profile());
+        assert!(DebugDumpKind::SemanticsTree.available_in_profile());
+        assert!(DebugDumpKind::FocusTree.available_in_profile());
+    }
+
+    #[test]
+    fn test_debug_dump_kind_new_methods() {
+        assert_eq!(
+            DebugDumpKind::SemanticsTree.method(),
+            "ext.flutter.debugDumpSemanticsTreeInTraversalOrder"
+        );
+        assert_eq!(
+            DebugDumpKind::FocusTree.method(),
+            "ext.flutter.debugDumpFocusTree"
+        );
+    }
+
+    #[test]
+    fn test_debug_dump_kind_all_has_unique_labels() {
+        let labels: std::collections::HashSet<_> =
+            DebugDumpKind::ALL.iter().map(|k| k.label()).collect();
+        assert_eq!(labels.len(), DebugDumpKind::ALL.len());
+        assert_eq!(DebugDumpKind::ALL[0], DebugDumpKind::WidgetTree);
     }
 
     #[test]
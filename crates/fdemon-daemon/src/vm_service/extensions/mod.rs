@@ -7,6 +7,7 @@
 //! - [`parse_bool_extension_response`] — Parse `{"enabled": "true"|"false"}` responses.
 //! - [`parse_data_extension_response`] — Parse `{"data": "..."}` responses.
 //! - [`is_extension_not_available`] — Detect "method not found" VM Service errors.
+//! - [`is_framework_extension`] — Tell Flutter/SDK extensions from app-registered ones.
 //! - [`ObjectGroupManager`] — Manage Widget Inspector object group lifecycle.
 //!
 //! ## Protocol Notes
@@ -47,8 +48,10 @@ pub use inspector::{
 };
 pub use layout::{extract_layout_info, extract_layout_tree, fetch_layout_data, get_layout_node};
 pub use overlays::{
-    debug_paint, flip_overlay, performance_overlay, query_all_overlays, repaint_rainbow,
-    toggle_bool_extension, widget_inspector, DebugOverlayState,
+    brightness_override, debug_paint, flip_overlay, next_brightness, next_platform,
+    performance_overlay, platform_override, query_all_overlays, repaint_rainbow, time_dilation,
+    toggle_bool_extension, widget_inspector, DebugOverlayState, OVERRIDE_PLATFORMS,
+    PLATFORM_OVERRIDE_RESET, SLOW_ANIMATIONS_TIME_DILATION,
 };
 
 // ---------------------------------------------------------------------------
@@ -73,6 +76,29 @@ pub mod ext {
     /// Toggle the Widget Inspector show mode.
     pub const INSPECTOR_SHOW: &str = "ext.flutter.inspector.show";
 
+    // ── Debug toggles ───────────────────────────────────────────────────────
+
+    /// Get or set the animation time dilation factor (`timeDilation` param).
+    pub const TIME_DILATION: &str = "ext.flutter.timeDilation";
+
+    /// Get or set the target platform override (`value` param).
+    pub const PLATFORM_OVERRIDE: &str = "ext.flutter.platformOverride";
+
+    /// Get or set the platform brightness override (`value` param).
+    pub const BRIGHTNESS_OVERRIDE: &str = "ext.flutter.brightnessOverride";
+
+    /// Toggle the "DEBUG" banner in the top-right corner.
+    pub const DEBUG_ALLOW_BANNER: &str = "ext.flutter.debugAllowBanner";
+
+    /// Toggle color inversion of images decoded larger than their display size.
+    pub const INVERT_OVERSIZED_IMAGES: &str = "ext.flutter.invertOversizedImages";
+
+    /// Toggle timeline events for every widget build.
+    pub const PROFILE_WIDGET_BUILDS: &str = "ext.flutter.profileWidgetBuilds";
+
+    /// Toggle painting of text baselines.
+    pub const DEBUG_PAINT_BASELINES: &str = "ext.flutter.debugPaintBaselinesEnabled";
+
     // ── Widget inspector ────────────────────────────────────────────────────
 
     /// Get the full widget tree from the root.
@@ -87,6 +113,17 @@ pub mod ext {
     /// Get the currently selected widget in the inspector.
     pub const GET_SELECTED_WIDGET: &str = "ext.flutter.inspector.getSelectedWidget";
 
+    /// Get the nearest summary-tree ancestor of the selected widget.
+    ///
+    /// Unlike [`GET_SELECTED_WIDGET`], the returned node is guaranteed to be
+    /// part of the tree returned by `getRootWidgetTree` with
+    /// `isSummaryTree=true`.
+    pub const GET_SELECTED_SUMMARY_WIDGET: &str = "ext.flutter.inspector.getSelectedSummaryWidget";
+
+    /// Enable or disable `Flutter.RebuiltWidgets` events with per-location
+    /// rebuild counts. Requires widget creation tracking (debug builds).
+    pub const TRACK_REBUILD_DIRTY_WIDGETS: &str = "ext.flutter.inspector.trackRebuildDirtyWidgets";
+
     /// Dispose a named object group, releasing all its references.
     pub const DISPOSE_GROUP: &str = "ext.flutter.inspector.disposeGroup";
 
@@ -114,6 +151,13 @@ pub mod ext {
     /// Dump the layer tree to a string.
     pub const DEBUG_DUMP_LAYER_TREE: &str = "ext.flutter.debugDumpLayerTree";
 
+    /// Dump the semantics tree (in traversal order) to a string.
+    pub const DEBUG_DUMP_SEMANTICS_TREE: &str =
+        "ext.flutter.debugDumpSemanticsTreeInTraversalOrder";
+
+    /// Dump the focus tree to a string.
+    pub const DEBUG_DUMP_FOCUS_TREE: &str = "ext.flutter.debugDumpFocusTree";
+
     // ── Network Profiling (ext.dart.io) ──────────────────────────────────────
 
     /// Enable or disable HTTP timeline logging.
@@ -165,6 +209,23 @@ pub fn parse_bool_extension_response(result: &Value) -> Result<bool> {
         .ok_or_else(|| Error::protocol("missing 'enabled' field in extension response"))
 }
 
+/// Parse a string value response from a Flutter service extension.
+///
+/// Value extensions return the current setting under an extension-specific
+/// key, e.g. `{"value": "iOS"}` for `platformOverride` or
+/// `{"timeDilation": "1.0"}` for `timeDilation`.
+///
+/// # Errors
+///
+/// Returns [`Error::Protocol`] if the `key` field is missing or not a string.
+pub fn parse_string_extension_response(result: &Value, key: &str) -> Result<String> {
+    result
+        .get(key)
+        .and_then(|v| v.as_str())
+        .map(|s| s.to_string())
+        .ok_or_else(|| Error::protocol(format!("missing '{key}' field in extension response")))
+}
+
 /// Parse a string data response from a Flutter service extension.
 ///
 /// Debug dump extensions return `{"data": "<string content>"}`.
@@ -227,6 +288,18 @@ pub fn is_extension_not_available(error: &VmServiceError) -> bool {
     msg.contains("method not found") || msg.contains("extension not available")
 }
 
+/// Namespaces of the service extensions registered by Flutter and the Dart
+/// SDK.
+const FRAMEWORK_EXTENSION_PREFIXES: &[&str] = &["ext.flutter.", "ext.dart.", "ext.ui."];
+
+/// Whether `method` is registered by Flutter or the Dart SDK rather than by
+/// the app itself with `registerExtension`.
+pub fn is_framework_extension(method: &str) -> bool {
+    FRAMEWORK_EXTENSION_PREFIXES
+        .iter()
+        .any(|prefix| method.starts_with(prefix))
+}
+
 // ---------------------------------------------------------------------------
 // DiagnosticsNode response parsing
 // ---------------------------------------------------------------------------
@@ -340,6 +413,28 @@ mod tests {
         assert!(!parse_bool_extension_response(&json).unwrap());
     }
 
+    // ── parse_string_extension_response ─────────────────────────────────────
+
+    #[test]
+    fn test_parse_string_response_reads_named_key() {
+        let json = json!({"value": "iOS", "type": "_extensionType"});
+        assert_eq!(
+            parse_string_extension_response(&json, "value").unwrap(),
+            "iOS"
+        );
+        let json = json!({"timeDilation": "5.0"});
+        assert_eq!(
+            parse_string_extension_response(&json, "timeDilation").unwrap(),
+            "5.0"
+        );
+    }
+
+    #[test]
+    fn test_parse_string_response_missing_key_returns_error() {
+        let json = json!({"enabled": "true"});
+        assert!(parse_string_extension_response(&json, "value").is_err());
+    }
+
     // ── parse_data_extension_response ───────────────────────────────────────
 
     #[test]
@@ -421,6 +516,15 @@ mod tests {
         assert!(!is_extension_not_available(&error));
     }
 
+    #[test]
+    fn test_is_framework_extension() {
+        assert!(is_framework_extension("ext.flutter.debugPaint"));
+        assert!(is_framework_extension("ext.dart.io.getHttpProfile"));
+        assert!(is_framework_extension("ext.ui.window.scheduleFrame"));
+        assert!(!is_framework_extension("ext.myapp.toggleDebugMenu"));
+        assert!(!is_framework_extension("ext.flutterish.reset"));
+    }
+
     // ── extension constants ─────────────────────────────────────────────────
 
     #[test]
@@ -430,9 +534,19 @@ mod tests {
         assert!(ext::DEBUG_PAINT.starts_with("ext.flutter."));
         assert!(ext::SHOW_PERFORMANCE_OVERLAY.starts_with("ext.flutter."));
         assert!(ext::INSPECTOR_SHOW.starts_with("ext.flutter."));
+        assert!(ext::TIME_DILATION.starts_with("ext.flutter."));
+        assert!(ext::PLATFORM_OVERRIDE.starts_with("ext.flutter."));
+        assert!(ext::BRIGHTNESS_OVERRIDE.starts_with("ext.flutter."));
+        assert!(ext::DEBUG_ALLOW_BANNER.starts_with("ext.flutter."));
+        assert!(ext::INVERT_OVERSIZED_IMAGES.starts_with("ext.flutter."));
+        assert!(ext::PROFILE_WIDGET_BUILDS.starts_with("ext.flutter."));
+        assert!(ext::DEBUG_PAINT_BASELINES.starts_with("ext.flutter."));
+        assert!(ext::TRACK_REBUILD_DIRTY_WIDGETS.starts_with("ext.flutter."));
         assert!(ext::DEBUG_DUMP_APP.starts_with("ext.flutter."));
         assert!(ext::DEBUG_DUMP_RENDER_TREE.starts_with("ext.flutter."));
         assert!(ext::DEBUG_DUMP_LAYER_TREE.starts_with("ext.flutter."));
+        assert!(ext::DEBUG_DUMP_SEMANTICS_TREE.starts_with("ext.flutter."));
+        assert!(ext::DEBUG_DUMP_FOCUS_TREE.starts_with("ext.flutter."));
     }
 
     #[test]
@@ -441,6 +555,7 @@ mod tests {
         assert!(ext::GET_ROOT_WIDGET_SUMMARY_TREE.starts_with("ext.flutter.inspector."));
         assert!(ext::GET_DETAILS_SUBTREE.starts_with("ext.flutter.inspector."));
         assert!(ext::GET_SELECTED_WIDGET.starts_with("ext.flutter.inspector."));
+        assert!(ext::GET_SELECTED_SUMMARY_WIDGET.starts_with("ext.flutter.inspector."));
         assert!(ext::DISPOSE_GROUP.starts_with("ext.flutter.inspector."));
         assert!(ext::GET_LAYOUT_EXPLORER_NODE.starts_with("ext.flutter.inspector."));
         assert!(ext::IS_WIDGET_TREE_READY.starts_with("ext.flutter.inspector."));
@@ -1,15 +1,17 @@
 //! Debug overlay toggle extensions.
 //!
 //! Provides [`DebugOverlayState`], [`toggle_bool_extension`], and helpers
-//! for querying and flipping Flutter debug overlay extensions.
+//! for querying and flipping Flutter debug overlay extensions, plus the
+//! value-based debug toggles (time dilation, platform and brightness
+//! overrides).
 
 use std::collections::HashMap;
 
 use fdemon_core::prelude::*;
 
 use super::ext;
-use super::parse_bool_extension_response;
 use super::VmServiceClient;
+use super::{parse_bool_extension_response, parse_string_extension_response};
 
 // ---------------------------------------------------------------------------
 // Debug overlay state
@@ -30,6 +32,61 @@ pub struct DebugOverlayState {
     pub performance_overlay: Option<bool>,
     /// Whether the widget inspector overlay is enabled.
     pub widget_inspector: Option<bool>,
+    /// Current animation time dilation factor (`1.0` is normal speed).
+    pub time_dilation: Option<f64>,
+    /// Target platform reported by `platformOverride` (e.g. `"android"`).
+    ///
+    /// This is the effective platform: the getter reports the device's own
+    /// platform when no override is set.
+    pub platform_override: Option<String>,
+    /// Brightness reported by `brightnessOverride` (e.g. `"Brightness.dark"`).
+    pub brightness_override: Option<String>,
+    /// Whether the "DEBUG" banner is shown.
+    pub debug_allow_banner: Option<bool>,
+    /// Whether oversized images are color-inverted.
+    pub invert_oversized_images: Option<bool>,
+    /// Whether widget builds are recorded in the timeline.
+    pub profile_widget_builds: Option<bool>,
+    /// Whether text baselines are painted.
+    pub debug_paint_baselines: Option<bool>,
+}
+
+/// Time dilation applied by the "slow animations" toggle.
+///
+/// Matches the factor used by Flutter DevTools.
+pub const SLOW_ANIMATIONS_TIME_DILATION: f64 = 5.0;
+
+/// Target platforms cycled through by the platform override toggle, using the
+/// names accepted by `ext.flutter.platformOverride`.
+pub const OVERRIDE_PLATFORMS: [&str; 6] =
+    ["android", "iOS", "macOS", "linux", "windows", "fuchsia"];
+
+/// Value for `ext.flutter.platformOverride` that clears the override.
+pub const PLATFORM_OVERRIDE_RESET: &str = "default";
+
+/// The platform override after `current`, the active override (`None` when
+/// there is none).
+///
+/// Steps through [`OVERRIDE_PLATFORMS`] and then [`PLATFORM_OVERRIDE_RESET`]
+/// after the last entry. An unknown or missing `current` starts the cycle at
+/// the first entry.
+pub fn next_platform(current: Option<&str>) -> &'static str {
+    match current.and_then(|c| OVERRIDE_PLATFORMS.iter().position(|p| *p == c)) {
+        Some(i) if i + 1 == OVERRIDE_PLATFORMS.len() => PLATFORM_OVERRIDE_RESET,
+        Some(i) => OVERRIDE_PLATFORMS[i + 1],
+        None => OVERRIDE_PLATFORMS[0],
+    }
+}
+
+/// The opposite of `current` for `ext.flutter.brightnessOverride`.
+///
+/// Anything other than `"Brightness.dark"` (including the unknown state)
+/// flips to dark.
+pub fn next_brightness(current: Option<&str>) -> &'static str {
+    match current {
+        Some("Brightness.dark") => "Brightness.light",
+        _ => "Brightness.dark",
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -138,11 +195,107 @@ pub async fn widget_inspector(
     toggle_bool_extension(client, ext::INSPECTOR_SHOW, isolate_id, enabled).await
 }
 
+// ---------------------------------------------------------------------------
+// Value toggles
+// ---------------------------------------------------------------------------
+
+/// Get or set a string-valued extension whose parameter and response share
+/// the name `key`.
+async fn string_extension(
+    client: &VmServiceClient,
+    method: &str,
+    key: &str,
+    isolate_id: &str,
+    value: Option<&str>,
+) -> Result<String> {
+    let args = value.map(|v| {
+        let mut m = HashMap::new();
+        m.insert(key.to_string(), v.to_string());
+        m
+    });
+    let result = client.call_extension(method, isolate_id, args).await?;
+    parse_string_extension_response(&result, key)
+}
+
+/// Get or set the animation time dilation factor.
+///
+/// If `dilation` is `Some`, sets the factor. Returns the factor after the call.
+///
+/// # Errors
+///
+/// Returns an error if the extension is unavailable, the RPC call fails, or
+/// the response is not a number.
+pub async fn time_dilation(
+    client: &VmServiceClient,
+    isolate_id: &str,
+    dilation: Option<f64>,
+) -> Result<f64> {
+    let value = dilation.map(|d| format!("{d:.1}"));
+    let result = string_extension(
+        client,
+        ext::TIME_DILATION,
+        "timeDilation",
+        isolate_id,
+        value.as_deref(),
+    )
+    .await?;
+    result
+        .parse()
+        .map_err(|_| Error::protocol(format!("invalid timeDilation value: {result}")))
+}
+
+/// Get or set the target platform override.
+///
+/// `platform` is one of [`OVERRIDE_PLATFORMS`], or `"default"` to clear the
+/// override. Returns the target platform after the call.
+///
+/// # Errors
+///
+/// Returns an error if the extension is unavailable or the RPC call fails.
+pub async fn platform_override(
+    client: &VmServiceClient,
+    isolate_id: &str,
+    platform: Option<&str>,
+) -> Result<String> {
+    string_extension(
+        client,
+        ext::PLATFORM_OVERRIDE,
+        "value",
+        isolate_id,
+        platform,
+    )
+    .await
+}
+
+/// Get or set the platform brightness override.
+///
+/// `brightness` is `"Brightness.light"`, `"Brightness.dark"`, or `"default"`
+/// to clear the override. Returns the brightness after the call.
+///
+/// # Errors
+///
+/// Returns an error if the extension is unavailable or the RPC call fails.
+pub async fn brightness_override(
+    client: &VmServiceClient,
+    isolate_id: &str,
+    brightness: Option<&str>,
+) -> Result<String> {
+    string_extension(
+        client,
+        ext::BRIGHTNESS_OVERRIDE,
+        "value",
+        isolate_id,
+        brightness,
+    )
+    .await
+}
+
 // ---------------------------------------------------------------------------
 // Bulk query
 // ---------------------------------------------------------------------------
 
-/// Query all 4 overlay extensions sequentially and return their states.
+/// Query all overlay and debug toggle extensions sequentially and return
+/// their states.
 ///
 /// Each overlay that is not available (e.g., in profile mode) is returned as `None`.
 /// Errors from individual overlay queries are silently converted to `None` to
@@ -159,6 +312,41 @@ pub async fn query_all_overlays(client: &VmServiceClient, isolate_id: &str) -> D
         debug_paint: debug_paint(client, isolate_id, None).await.ok(),
         performance_overlay: performance_overlay(client, isolate_id, None).await.ok(),
         widget_inspector: widget_inspector(client, isolate_id, None).await.ok(),
+        time_dilation: time_dilation(client, isolate_id, None).await.ok(),
+        platform_override: platform_override(client, isolate_id, None).await.ok(),
+        brightness_override: brightness_override(client, isolate_id, None).await.ok(),
+        debug_allow_banner: toggle_bool_extension(
+            client,
+            ext::DEBUG_ALLOW_BANNER,
+            isolate_id,
+            None,
+        )
+        .await
+        .ok(),
+        invert_oversized_images: toggle_bool_extension(
+            client,
+            ext::INVERT_OVERSIZED_IMAGES,
+            isolate_id,
+            None,
+        )
+        .await
+        .ok(),
+        profile_widget_builds: toggle_bool_extension(
+            client,
+            ext::PROFILE_WIDGET_BUILDS,
+            isolate_id,
+            None,
+        )
+        .await
+        .ok(),
+        debug_paint_baselines: toggle_bool_extension(
+            client,
+            ext::DEBUG_PAINT_BASELINES,
+            isolate_id,
+            None,
+        )
+        .await
+        .ok(),
     }
 }
 
@@ -201,6 +389,9 @@ mod tests {
         assert_eq!(state.debug_paint, None);
         assert_eq!(state.performance_overlay, None);
         assert_eq!(state.widget_inspector, None);
+        assert_eq!(state.time_dilation, None);
+        assert_eq!(state.platform_override, None);
+        assert_eq!(state.debug_paint_baselines, None);
     }
 
     #[test]
@@ -210,6 +401,7 @@ mod tests {
             debug_paint: Some(false),
             performance_overlay: None,
             widget_inspector: Some(true),
+            ..Default::default()
         };
         let cloned = state.clone();
         assert_eq!(cloned.repaint_rainbow, Some(true));
@@ -230,4 +422,21 @@ mod tests {
         assert_eq!(state.performance_overlay, None);
         assert_eq!(state.widget_inspector, None);
     }
+
+    // ── Value toggles ───────────────────────────────────────────────────────
+
+    #[test]
+    fn test_next_platform_cycles_then_resets() {
+        assert_eq!(next_platform(Some("android")), "iOS");
+        assert_eq!(next_platform(Some("fuchsia")), PLATFORM_OVERRIDE_RESET);
+        assert_eq!(next_platform(None), "android");
+        assert_eq!(next_platform(Some("unknown")), "android");
+    }
+
+    #[test]
+    fn test_next_brightness_flips() {
+        assert_eq!(next_brightness(Some("Brightness.light")), "Brightness.dark");
+        assert_eq!(next_brightness(Some("Brightness.dark")), "Brightness.light");
+        assert_eq!(next_brightness(None), "Brightness.dark");
+    }
 }
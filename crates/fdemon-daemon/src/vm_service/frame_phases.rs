@@ -0,0 +1,440 @@
+//! Frame phase breakdowns from Timeline stream events.
+//!
+//! `Flutter.Frame` events only carry the aggregate UI (`build`) and raster
+//! durations. The per-phase split comes from trace events recorded on the
+//! `Dart` and `Embedder` timeline streams and delivered in batches on the VM
+//! Service `Timeline` stream:
+//!
+//! | Event | Thread | Meaning |
+//! |-------|--------|---------|
+//! | `Animator::BeginFrame` (`args.frame_number`) | UI | Frame window on the UI thread |
+//! | `BUILD` / `LAYOUT` / `PAINT` | UI | Framework pipeline phases |
+//! | `Rasterizer::DoDraw` (`args.frame_number`) | raster | Raster work for the frame |
+//! | any event with `args.devtoolsTag == "shaders"` | raster | Shader compilation |
+//!
+//! ## Event structure
+//!
+//! ```json
+//! {
+//!     "kind": "TimelineEvents",
+//!     "timelineEvents": [
+//!         { "name": "Animator::BeginFrame", "ph": "B", "tid": 7, "ts": 1000,
+//!           "args": { "frame_number": "42" } },
+//!         { "name": "BUILD", "ph": "X", "tid": 7, "ts": 1100, "dur": 3000 },
+//!         { "name": "Animator::BeginFrame", "ph": "E", "tid": 7, "ts": 9000 }
+//!     ]
+//! }
+//! ```
+//!
+//! Events use the Chrome trace format: `X` events are complete spans with a
+//! `dur`, while `B`/`E` pairs bracket a span. Batches arrive per thread, so
+//! the UI and raster halves of a frame are usually split across batches;
+//! [`FramePhaseCorrelator`] buffers both until a frame is complete.
+
+use std::collections::{BTreeMap, HashMap, VecDeque};
+
+use fdemon_core::performance::FramePhases;
+use fdemon_core::prelude::*;
+use serde_json::Value;
+
+use super::client::VmRequestHandle;
+use super::protocol::StreamEvent;
+use super::timeline::parse_str_u64;
+
+/// Timeline streams recorded for phase breakdowns: framework phases (`Dart`),
+/// engine frame/raster events (`Embedder`) and collections (`GC`).
+pub const FRAME_TIMELINE_STREAMS: &[&str] = &["Dart", "Embedder", "GC"];
+
+/// UI-thread event that brackets a frame and carries its number.
+const UI_FRAME_EVENT: &str = "Animator::BeginFrame";
+
+/// Raster-thread events that carry the frame number (current and pre-3.0 engines).
+const RASTER_FRAME_EVENTS: &[&str] = &["Rasterizer::DoDraw", "GPURasterizer::Draw"];
+
+/// Frames waiting for their other half before being dropped.
+const MAX_PENDING_FRAMES: usize = 64;
+
+/// Phase and shader spans kept for matching against pending frames.
+const MAX_BUFFERED_SPANS: usize = 1024;
+
+/// Unmatched `B` events kept per thread and event name.
+const MAX_OPEN_SPANS: usize = 16;
+
+/// Enable recording of the timeline streams in [`FRAME_TIMELINE_STREAMS`].
+///
+/// Timeline flags are VM-wide, so the streams recorded before are returned
+/// for [`restore_timeline_streams`] once phase breakdowns are no longer
+/// needed.
+///
+/// # Errors
+///
+/// Returns a transport or protocol error if `getVMTimelineFlags` or
+/// `setVMTimelineFlags` fails.
+pub async fn enable_frame_timeline(handle: &VmRequestHandle) -> Result<Vec<String>> {
+    let flags = handle.request("getVMTimelineFlags", None).await?;
+    let previous = flags
+        .get("recordedStreams")
+        .and_then(Value::as_array)
+        .map(|streams| {
+            streams
+                .iter()
+                .filter_map(|s| s.as_str().map(str::to_string))
+                .collect()
+        })
+        .unwrap_or_default();
+    let params = serde_json::json!({ "recordedStreams": FRAME_TIMELINE_STREAMS });
+    handle.request("setVMTimelineFlags", Some(params)).await?;
+    Ok(previous)
+}
+
+/// Record exactly `streams` again, undoing [`enable_frame_timeline`].
+///
+/// # Errors
+///
+/// Returns a transport or protocol error if `setVMTimelineFlags` fails.
+pub async fn restore_timeline_streams(handle: &VmRequestHandle, streams: &[String]) -> Result<()> {
+    let params = serde_json::json!({ "recordedStreams": streams });
+    handle.request("setVMTimelineFlags", Some(params)).await?;
+    Ok(())
+}
+
+/// Extract the trace events from a `TimelineEvents` stream event.
+///
+/// Returns `None` for any other event kind.
+pub fn timeline_events(event: &StreamEvent) -> Option<&[Value]> {
+    if event.kind != "TimelineEvents" {
+        return None;
+    }
+    event
+        .data
+        .get("timelineEvents")
+        .and_then(Value::as_array)
+        .map(Vec::as_slice)
+}
+
+/// A closed time span on one thread, in timeline microseconds.
+#[derive(Debug, Clone, Copy)]
+struct Span {
+    tid: i64,
+    start: u64,
+    end: u64,
+}
+
+impl Span {
+    fn duration(&self) -> u64 {
+        self.end.saturating_sub(self.start)
+    }
+
+    fn contains(&self, other: &Span) -> bool {
+        self.tid == other.tid && other.start >= self.start && other.end <= self.end
+    }
+
+    fn overlaps(&self, other: &Span) -> bool {
+        other.start < self.end && other.end > self.start
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum UiPhase {
+    Build,
+    Layout,
+    Paint,
+}
+
+/// Correlates timeline trace events into per-frame [`FramePhases`].
+///
+/// Feed every `TimelineEvents` batch to [`ingest`](Self::ingest); it returns
+/// the frames completed by that batch. A frame is complete once its UI window,
+/// its raster span and its `PAINT` span (the last UI phase) have all been seen.
+#[derive(Debug, Default)]
+pub struct FramePhaseCorrelator {
+    /// `B` events waiting for their `E`, keyed by thread and event name.
+    open: HashMap<(i64, String), Vec<(u64, Value)>>,
+    ui_frames: BTreeMap<u64, Span>,
+    raster_frames: BTreeMap<u64, Span>,
+    phase_spans: VecDeque<(UiPhase, Span)>,
+    shader_spans: VecDeque<Span>,
+}
+
+impl FramePhaseCorrelator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Ingest one batch of trace events and return newly completed frames as
+    /// `(frame_number, phases)`, in frame order.
+    pub fn ingest(&mut self, events: &[Value]) -> Vec<(u64, FramePhases)> {
+        for event in events {
+            if let Some((name, span, args)) = self.close_span(event) {
+                self.record(&name, span, &args);
+            }
+        }
+        let completed = self.complete_frames();
+        self.prune();
+        completed
+    }
+
+    /// Turn an `X` event or the `E` of a `B`/`E` pair into a closed span.
+    fn close_span(&mut self, event: &Value) -> Option<(String, Span, Value)> {
+        let name = event.get("name")?.as_str()?;
+        let tid = event.get("tid").and_then(Value::as_i64).unwrap_or(0);
+        let ts = event.get("ts").and_then(Value::as_u64)?;
+        let args = event.get("args").cloned().unwrap_or(Value::Null);
+
+        match event.get("ph")?.as_str()? {
+            "X" => {
+                let dur = event.get("dur").and_then(Value::as_u64).unwrap_or(0);
+                let span = Span {
+                    tid,
+                    start: ts,
+                    end: ts + dur,
+                };
+                Some((name.to_string(), span, args))
+            }
+            "B" => {
+                let stack = self.open.entry((tid, name.to_string())).or_default();
+                if stack.len() == MAX_OPEN_SPANS {
+                    stack.remove(0);
+                }
+                stack.push((ts, args));
+                None
+            }
+            "E" => {
+                let (start, begin_args) = self.open.get_mut(&(tid, name.to_string()))?.pop()?;
+                let span = Span {
+                    tid,
+                    start,
+                    end: ts,
+                };
+                Some((name.to_string(), span, begin_args))
+            }
+            _ => None,
+        }
+    }
+
+    fn record(&mut self, name: &str, span: Span, args: &Value) {
+        if args.get("devtoolsTag").and_then(Value::as_str) == Some("shaders") {
+            self.shader_spans.push_back(span);
+        }
+        let frame_number = || args.get("frame_number").and_then(parse_str_u64);
+        match name {
+            UI_FRAME_EVENT => {
+                if let Some(number) = frame_number() {
+                    self.ui_frames.insert(number, span);
+                }
+            }
+            name if RASTER_FRAME_EVENTS.contains(&name) => {
+                if let Some(number) = frame_number() {
+                    self.raster_frames.insert(number, span);
+                }
+            }
+            "BUILD" => self.phase_spans.push_back((UiPhase::Build, span)),
+            "LAYOUT" => self.phase_spans.push_back((UiPhase::Layout, span)),
+            "PAINT" => self.phase_spans.push_back((UiPhase::Paint, span)),
+            _ => {}
+        }
+    }
+
+    fn complete_frames(&mut self) -> Vec<(u64, FramePhases)> {
+        let ready: Vec<u64> = self
+            .ui_frames
+            .iter()
+            .filter(|(number, ui)| {
+                self.raster_frames.contains_key(number)
+                    && self
+                        .phase_spans
+                        .iter()
+                        .any(|(phase, span)| *phase == UiPhase::Paint && ui.contains(span))
+            })
+            .map(|(number, _)| *number)
+            .collect();
+
+        ready
+            .into_iter()
+            .filter_map(|number| {
+                let ui = self.ui_frames.remove(&number)?;
+                let raster = self.raster_frames.remove(&number)?;
+                let phase_micros = |wanted: UiPhase| {
+                    self.phase_spans
+                        .iter()
+                        .filter(|(phase, span)| *phase == wanted && ui.contains(span))
+                        .map(|(_, span)| span.duration())
+                        .sum()
+                };
+                let phases = FramePhases {
+                    build_micros: phase_micros(UiPhase::Build),
+                    layout_micros: phase_micros(UiPhase::Layout),
+                    paint_micros: phase_micros(UiPhase::Paint),
+                    raster_micros: raster.duration(),
+                    shader_compilation: self.shader_spans.iter().any(|s| raster.overlaps(s)),
+                };
+                Some((number, phases))
+            })
+            .collect()
+    }
+
+    /// Drop the oldest buffered data so a stream without matching halves
+    /// (e.g. raster events missing on web) cannot grow without bound.
+    fn prune(&mut self) {
+        for frames in [&mut self.ui_frames, &mut self.raster_frames] {
+            while frames.len() > MAX_PENDING_FRAMES {
+                frames.pop_first();
+            }
+        }
+        while self.phase_spans.len() > MAX_BUFFERED_SPANS {
+            self.phase_spans.pop_front();
+        }
+        while self.shader_spans.len() > MAX_BUFFERED_SPANS {
+            self.shader_spans.pop_front();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    const UI: i64 = 1;
+    const RASTER: i64 = 2;
+
+    fn complete(name: &str, tid: i64, ts: u64, dur: u64) -> Value {
+        json!({ "name": name, "ph": "X", "tid": tid, "ts": ts, "dur": dur })
+    }
+
+    fn begin(name: &str, tid: i64, ts: u64, args: Value) -> Value {
+        json!({ "name": name, "ph": "B", "tid": tid, "ts": ts, "args": args })
+    }
+
+    fn end(name: &str, tid: i64, ts: u64) -> Value {
+        json!({ "name": name, "ph": "E", "tid": tid, "ts": ts })
+    }
+
+    /// UI half of frame `number` starting at `t`: build 3ms, layout 2ms, paint 1ms.
+    fn ui_batch(number: u64, t: u64) -> Vec<Value> {
+        vec![
+            begin(
+                UI_FRAME_EVENT,
+                UI,
+                t,
+                json!({ "frame_number": number.to_string() }),
+            ),
+            complete("BUILD", UI, t + 100, 3_000),
+            complete("LAYOUT", UI, t + 3_200, 2_000),
+            complete("PAINT", UI, t + 5_300, 1_000),
+            end(UI_FRAME_EVENT, UI, t + 6_500),
+        ]
+    }
+
+    fn raster_batch(number: u64, t: u64, dur: u64) -> Vec<Value> {
+        vec![
+            begin(
+                "Rasterizer::DoDraw",
+                RASTER,
+                t,
+                json!({ "frame_number": number.to_string() }),
+            ),
+            end("Rasterizer::DoDraw", RASTER, t + dur),
+        ]
+    }
+
+    #[test]
+    fn test_correlates_ui_and_raster_batches() {
+        let mut correlator = FramePhaseCorrelator::new();
+        assert!(correlator.ingest(&ui_batch(42, 10_000)).is_empty());
+
+        let frames = correlator.ingest(&raster_batch(42, 17_000, 4_000));
+        assert_eq!(frames.len(), 1);
+        let (number, phases) = &frames[0];
+        assert_eq!(*number, 42);
+        assert_eq!(phases.build_micros, 3_000);
+        assert_eq!(phases.layout_micros, 2_000);
+        assert_eq!(phases.paint_micros, 1_000);
+        assert_eq!(phases.raster_micros, 4_000);
+        assert!(!phases.shader_compilation);
+
+        // A completed frame is reported once.
+        assert!(correlator.ingest(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_raster_before_ui_batch() {
+        let mut correlator = FramePhaseCorrelator::new();
+        assert!(correlator
+            .ingest(&raster_batch(7, 17_000, 2_000))
+            .is_empty());
+        let frames = correlator.ingest(&ui_batch(7, 10_000));
+        assert_eq!(frames.len(), 1);
+        assert_eq!(frames[0].0, 7);
+    }
+
+    #[test]
+    fn test_phases_outside_frame_window_are_ignored() {
+        let mut correlator = FramePhaseCorrelator::new();
+        let mut events = ui_batch(1, 10_000);
+        // A BUILD from a neighbouring frame on the same thread.
+        events.push(complete("BUILD", UI, 50_000, 9_000));
+        // A BUILD inside the window but on another thread.
+        events.push(complete("BUILD", 99, 10_200, 9_000));
+        events.extend(raster_batch(1, 17_000, 1_000));
+
+        let frames = correlator.ingest(&events);
+        assert_eq!(frames[0].1.build_micros, 3_000);
+    }
+
+    #[test]
+    fn test_waits_for_paint_span() {
+        let mut correlator = FramePhaseCorrelator::new();
+        let mut ui = ui_batch(3, 10_000);
+        let paint = ui.remove(3);
+        ui.extend(raster_batch(3, 17_000, 1_000));
+        assert!(correlator.ingest(&ui).is_empty());
+        assert_eq!(correlator.ingest(&[paint]).len(), 1);
+    }
+
+    #[test]
+    fn test_detects_shader_compilation_during_raster() {
+        let mut correlator = FramePhaseCorrelator::new();
+        let mut events = ui_batch(5, 10_000);
+        events.extend(raster_batch(5, 17_000, 30_000));
+        events.push(json!({
+            "name": "GrGLProgramBuilder::finalize",
+            "ph": "X",
+            "tid": RASTER,
+            "ts": 18_000,
+            "dur": 20_000,
+            "args": { "devtoolsTag": "shaders" }
+        }));
+        let frames = correlator.ingest(&events);
+        assert!(frames[0].1.shader_compilation);
+    }
+
+    #[test]
+    fn test_unmatched_frames_are_pruned() {
+        let mut correlator = FramePhaseCorrelator::new();
+        for n in 0..(MAX_PENDING_FRAMES as u64 + 10) {
+            correlator.ingest(&raster_batch(n, n * 20_000, 1_000));
+        }
+        assert_eq!(correlator.raster_frames.len(), MAX_PENDING_FRAMES);
+        assert!(!correlator.raster_frames.contains_key(&0));
+    }
+
+    #[test]
+    fn test_timeline_events_extracts_batch() {
+        let event = StreamEvent {
+            kind: "TimelineEvents".to_string(),
+            isolate: None,
+            timestamp: None,
+            data: json!({ "timelineEvents": [complete("BUILD", UI, 0, 1)] }),
+        };
+        assert_eq!(timeline_events(&event).map(<[Value]>::len), Some(1));
+
+        let other = StreamEvent {
+            kind: "Extension".to_string(),
+            isolate: None,
+            timestamp: None,
+            data: json!({}),
+        };
+        assert!(timeline_events(&other).is_none());
+    }
+}
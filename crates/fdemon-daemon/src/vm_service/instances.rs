@@ -0,0 +1,380 @@
+//! Live instance RPC wrappers.
+//!
+//! [`get_instances`] lists live instances of a class, [`get_instance_fields`]
+//! reads one instance's fields via [`get_object`], and [`get_retaining_path`]
+//! asks the VM why an instance is still reachable.
+//!
+//! ## Response structure
+//!
+//! ```json
+//! { "type": "InstanceSet", "totalCount": 2,
+//!   "instances": [ { "type": "@Instance", "id": "objects/7", "kind": "PlainInstance",
+//!                    "class": { "type": "@Class", "name": "_HomeState" } } ] }
+//!
+//! { "type": "RetainingPath", "length": 2, "gcRootType": "user global",
+//!   "elements": [ { "value": { "type": "@Instance", ... }, "parentListIndex": 3 },
+//!                 { "value": { "type": "@Instance", ... } } ] }
+//! ```
+
+use fdemon_core::prelude::*;
+use fdemon_core::{
+    HeapInstance, InstanceField, InstanceSet, RetainingObject, RetainingPath, RetainingSlot,
+};
+use serde_json::Value;
+
+use super::client::VmRequestHandle;
+use super::debugger::get_object;
+
+/// List up to `limit` live instances of the class `class_id`.
+///
+/// # Errors
+///
+/// Returns [`Error::Protocol`] if the response has no `instances`, or a
+/// transport error if the request fails.
+pub async fn get_instances(
+    handle: &VmRequestHandle,
+    isolate_id: &str,
+    class_id: &str,
+    limit: u32,
+) -> Result<InstanceSet> {
+    let params = serde_json::json!({
+        "isolateId": isolate_id,
+        "objectId": class_id,
+        "limit": limit,
+    });
+    let result = handle.request("getInstances", Some(params)).await?;
+    parse_instance_set(&result)
+}
+
+/// Parse a `getInstances` response. Instances without an `id` are skipped.
+///
+/// # Errors
+///
+/// Returns [`Error::Protocol`] if `instances` is absent or not an array.
+pub fn parse_instance_set(result: &Value) -> Result<InstanceSet> {
+    let instances = result
+        .get("instances")
+        .and_then(Value::as_array)
+        .ok_or_else(|| Error::protocol("missing instances in getInstances response"))?;
+    let instances: Vec<HeapInstance> = instances
+        .iter()
+        .filter_map(|obj| {
+            Some(HeapInstance {
+                id: obj.get("id")?.as_str()?.to_string(),
+                description: describe_object_ref(obj),
+            })
+        })
+        .collect();
+    let total_count = result
+        .get("totalCount")
+        .and_then(Value::as_u64)
+        .unwrap_or(instances.len() as u64);
+    Ok(InstanceSet {
+        total_count,
+        instances,
+    })
+}
+
+/// Read the fields of the instance `object_id`.
+///
+/// # Errors
+///
+/// Returns [`Error::Protocol`] if the object has expired or is not an
+/// instance, or a transport error if the request fails.
+pub async fn get_instance_fields(
+    handle: &VmRequestHandle,
+    isolate_id: &str,
+    object_id: &str,
+) -> Result<Vec<InstanceField>> {
+    let result = get_object(handle, isolate_id, object_id, None, None).await?;
+    parse_instance_fields(&result)
+}
+
+/// Parse the `fields` of a `getObject` response for an instance.
+///
+/// # Errors
+///
+/// Returns [`Error::Protocol`] for a `Sentinel` (the object ID expired or
+/// the object was collected) or a response without `fields`.
+pub fn parse_instance_fields(result: &Value) -> Result<Vec<InstanceField>> {
+    if result.get("type").and_then(Value::as_str) == Some("Sentinel") {
+        return Err(Error::protocol(format!(
+            "object no longer available: {}",
+            describe_object_ref(result)
+        )));
+    }
+    let fields = result
+        .get("fields")
+        .and_then(Value::as_array)
+        .ok_or_else(|| Error::protocol("missing fields in getObject response"))?;
+    Ok(fields
+        .iter()
+        .filter_map(|field| {
+            let name = field
+                .get("name")
+                .and_then(value_as_name)
+                .or_else(|| field.get("decl")?.get("name")?.as_str().map(String::from))?;
+            let value = field
+                .get("value")
+                .map(describe_object_ref)
+                .unwrap_or_default();
+            Some(InstanceField { name, value })
+        })
+        .collect())
+}
+
+/// Fetch the chain of references (up to `limit` objects) that keeps
+/// `object_id` alive.
+///
+/// # Errors
+///
+/// Returns [`Error::Protocol`] if the object has expired, or a transport
+/// error if the request fails.
+pub async fn get_retaining_path(
+    handle: &VmRequestHandle,
+    isolate_id: &str,
+    object_id: &str,
+    limit: u32,
+) -> Result<RetainingPath> {
+    let params = serde_json::json!({
+        "isolateId": isolate_id,
+        "targetId": object_id,
+        "limit": limit,
+    });
+    let result = handle.request("getRetainingPath", Some(params)).await?;
+    parse_retaining_path(&result)
+}
+
+/// Parse a `getRetainingPath` response.
+///
+/// `parentField` is a string (or an integer for record positional fields)
+/// in current protocol versions and an `@Field` reference in older ones.
+///
+/// # Errors
+///
+/// Returns [`Error::Protocol`] for a `Sentinel` or a response without
+/// `elements`.
+pub fn parse_retaining_path(result: &Value) -> Result<RetainingPath> {
+    if result.get("type").and_then(Value::as_str) == Some("Sentinel") {
+        return Err(Error::protocol(format!(
+            "object no longer available: {}",
+            describe_object_ref(result)
+        )));
+    }
+    let elements = result
+        .get("elements")
+        .and_then(Value::as_array)
+        .ok_or_else(|| Error::protocol("missing elements in getRetainingPath response"))?;
+    let elements = elements
+        .iter()
+        .map(|element| {
+            let parent_slot = if let Some(field) = element.get("parentField") {
+                value_as_name(field)
+                    .or_else(|| field.get("name")?.as_str().map(String::from))
+                    .map(RetainingSlot::Field)
+            } else if let Some(index) = element.get("parentListIndex").and_then(Value::as_i64) {
+                Some(RetainingSlot::ListIndex(index))
+            } else {
+                element
+                    .get("parentMapKey")
+                    .map(|key| RetainingSlot::MapKey(describe_object_ref(key)))
+            };
+            RetainingObject {
+                description: element
+                    .get("value")
+                    .map(describe_object_ref)
+                    .unwrap_or_default(),
+                parent_slot,
+            }
+        })
+        .collect();
+    let gc_root_type = result
+        .get("gcRootType")
+        .and_then(Value::as_str)
+        .unwrap_or("unknown")
+        .to_string();
+    Ok(RetainingPath {
+        gc_root_type,
+        elements,
+    })
+}
+
+/// Short description of an object reference: the class name with its value
+/// for primitives (`int 3`, `String "hi"`), the length for collections, or
+/// the object type and name for non-instances (`Class _HomeState`).
+pub fn describe_object_ref(obj: &Value) -> String {
+    let str_field = |key: &str| obj.get(key).and_then(Value::as_str);
+    let ty = str_field("type").unwrap_or("").trim_start_matches('@');
+
+    match ty {
+        "Instance" => {
+            let kind = str_field("kind").unwrap_or("");
+            if kind == "Null" {
+                return "null".to_string();
+            }
+            let class = obj
+                .get("class")
+                .and_then(|c| c.get("name"))
+                .and_then(Value::as_str)
+                .unwrap_or(kind);
+            if let Some(value) = str_field("valueAsString") {
+                let ellipsis = if obj.get("valueAsStringIsTruncated") == Some(&Value::Bool(true)) {
+                    "…"
+                } else {
+                    ""
+                };
+                if kind == "String" {
+                    format!("{class} \"{value}{ellipsis}\"")
+                } else {
+                    format!("{class} {value}{ellipsis}")
+                }
+            } else if let Some(length) = obj.get("length").and_then(Value::as_i64) {
+                format!("{class} (length {length})")
+            } else {
+                class.to_string()
+            }
+        }
+        "Sentinel" => str_field("valueAsString")
+            .unwrap_or("<sentinel>")
+            .to_string(),
+        _ => match str_field("name") {
+            Some(name) => format!("{ty} {name}"),
+            None => ty.to_string(),
+        },
+    }
+}
+
+/// A field name given as a string, or as an integer for record fields.
+fn value_as_name(value: &Value) -> Option<String> {
+    value
+        .as_str()
+        .map(String::from)
+        .or_else(|| value.as_i64().map(|i| format!("${i}")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn instance(class: &str) -> Value {
+        json!({
+            "type": "@Instance",
+            "id": "objects/1",
+            "kind": "PlainInstance",
+            "class": { "type": "@Class", "id": "classes/9", "name": class }
+        })
+    }
+
+    #[test]
+    fn test_describe_object_ref() {
+        assert_eq!(describe_object_ref(&instance("_HomeState")), "_HomeState");
+        assert_eq!(
+            describe_object_ref(&json!({
+                "type": "@Instance", "kind": "Int", "valueAsString": "3",
+                "class": { "name": "_Smi" }
+            })),
+            "_Smi 3"
+        );
+        assert_eq!(
+            describe_object_ref(&json!({
+                "type": "@Instance", "kind": "String", "valueAsString": "hel",
+                "valueAsStringIsTruncated": true, "class": { "name": "_OneByteString" }
+            })),
+            "_OneByteString \"hel…\""
+        );
+        assert_eq!(
+            describe_object_ref(&json!({
+                "type": "@Instance", "kind": "List", "length": 4,
+                "class": { "name": "_GrowableList" }
+            })),
+            "_GrowableList (length 4)"
+        );
+        assert_eq!(
+            describe_object_ref(&json!({ "type": "@Instance", "kind": "Null" })),
+            "null"
+        );
+        assert_eq!(
+            describe_object_ref(&json!({ "type": "@Context", "id": "objects/3" })),
+            "Context"
+        );
+    }
+
+    #[test]
+    fn test_parse_instance_set() {
+        let set = parse_instance_set(&json!({
+            "type": "InstanceSet",
+            "totalCount": 5,
+            "instances": [instance("_HomeState"), { "type": "@Instance" }]
+        }))
+        .unwrap();
+        assert_eq!(set.total_count, 5);
+        assert_eq!(
+            set.instances,
+            [HeapInstance {
+                id: "objects/1".to_string(),
+                description: "_HomeState".to_string(),
+            }]
+        );
+        assert!(parse_instance_set(&json!({})).is_err());
+    }
+
+    #[test]
+    fn test_parse_instance_fields() {
+        let fields = parse_instance_fields(&json!({
+            "type": "Instance",
+            "fields": [
+                { "decl": { "type": "@Field", "name": "_count" },
+                  "value": { "type": "@Instance", "kind": "Int", "valueAsString": "2",
+                             "class": { "name": "_Smi" } } },
+                { "name": "_controller", "value": instance("AnimationController") }
+            ]
+        }))
+        .unwrap();
+        assert_eq!(
+            fields,
+            [
+                InstanceField {
+                    name: "_count".to_string(),
+                    value: "_Smi 2".to_string(),
+                },
+                InstanceField {
+                    name: "_controller".to_string(),
+                    value: "AnimationController".to_string(),
+                },
+            ]
+        );
+        let expired =
+            json!({ "type": "Sentinel", "kind": "Expired", "valueAsString": "<expired>" });
+        assert!(parse_instance_fields(&expired).is_err());
+    }
+
+    #[test]
+    fn test_parse_retaining_path() {
+        let path = parse_retaining_path(&json!({
+            "type": "RetainingPath",
+            "length": 3,
+            "gcRootType": "user global",
+            "elements": [
+                { "value": instance("_HomeState"), "parentListIndex": 0 },
+                { "value": { "type": "@Instance", "kind": "List", "length": 1,
+                             "class": { "name": "_GrowableList" } },
+                  "parentField": "_listeners" },
+                { "value": instance("Notifier"),
+                  "parentField": { "type": "@Field", "name": "instance" } },
+                { "value": { "type": "@Class", "name": "Notifier" } }
+            ]
+        }))
+        .unwrap();
+        assert_eq!(
+            path.chain(),
+            [
+                "_HomeState",
+                "_GrowableList (length 1)[0]",
+                "Notifier._listeners",
+                "Class Notifier.instance",
+                "GC root: user global",
+            ]
+        );
+    }
+}
@@ -54,6 +54,9 @@ pub struct VmLogRecord {
     pub error: Option<String>,
     /// Stack trace string if present, extracted from the `stackTrace` `InstanceRef`.
     pub stack_trace: Option<String>,
+    /// Name of the isolate that logged the record (e.g. `"main"`), taken from
+    /// the event's `isolate` reference.
+    pub isolate_name: Option<String>,
 }
 
 // ---------------------------------------------------------------------------
@@ -122,6 +125,8 @@ pub fn parse_log_record(event: &StreamEvent) -> Option<VmLogRecord> {
         .get("stackTrace")
         .and_then(extract_value_as_string);
 
+    let isolate_name = event.isolate.as_ref().map(|isolate| isolate.name.clone());
+
     Some(VmLogRecord {
         message,
         level,
@@ -130,6 +135,7 @@ pub fn parse_log_record(event: &StreamEvent) -> Option<VmLogRecord> {
         sequence_number,
         error,
         stack_trace,
+        isolate_name,
     })
 }
 
@@ -154,6 +160,8 @@ fn extract_value_as_string(value: &Value) -> Option<String> {
 /// - The [`LogSource`] is always [`LogSource::VmService`].
 /// - If a logger name is present and non-empty, the message is prefixed with
 ///   `[LoggerName] `.
+/// - Records from an isolate other than `main` (e.g. a background worker
+///   spawned with `Isolate.spawn`) are further prefixed with `(isolateName) `.
 /// - A stack trace is parsed if present.
 pub fn vm_log_to_log_entry(record: &VmLogRecord) -> LogEntry {
     use fdemon_core::stack_trace::ParsedStackTrace;
@@ -165,6 +173,12 @@ pub fn vm_log_to_log_entry(record: &VmLogRecord) -> LogEntry {
         Some(name) if !name.is_empty() => format!("[{}] {}", name, record.message),
         _ => record.message.clone(),
     };
+    let message = match &record.isolate_name {
+        Some(isolate) if !isolate.is_empty() && isolate != "main" => {
+            format!("({isolate}) {message}")
+        }
+        _ => message,
+    };
 
     // Parse stack trace if present.
     let stack_trace = record.stack_trace.as_deref().map(ParsedStackTrace::parse);
@@ -328,6 +342,21 @@ mod tests {
         assert_eq!(record.sequence_number, 42);
         assert!(record.error.is_none());
         assert!(record.stack_trace.is_none());
+        assert!(record.isolate_name.is_none());
+    }
+
+    #[test]
+    fn test_parse_log_record_with_isolate() {
+        let json = r#"{
+            "kind": "Logging",
+            "isolate": {"type": "@Isolate", "id": "isolates/2", "name": "worker", "number": "2"},
+            "logRecord": {
+                "message": {"type": "@Instance", "valueAsString": "parsed 10 items"},
+                "level": 800
+            }
+        }"#;
+        let record = parse_log_record(&parse_event(json)).unwrap();
+        assert_eq!(record.isolate_name.as_deref(), Some("worker"));
     }
 
     #[test]
@@ -437,6 +466,7 @@ mod tests {
             sequence_number: 42,
             error: None,
             stack_trace: None,
+            isolate_name: None,
         };
         let entry = vm_log_to_log_entry(&record);
         assert_eq!(entry.message, "[AuthService] User logged in");
@@ -455,6 +485,7 @@ mod tests {
             sequence_number: 1,
             error: None,
             stack_trace: None,
+            isolate_name: None,
         };
         let entry = vm_log_to_log_entry(&record);
         assert_eq!(entry.message, "raw message");
@@ -470,11 +501,36 @@ mod tests {
             sequence_number: 1,
             error: None,
             stack_trace: None,
+            isolate_name: None,
         };
         let entry = vm_log_to_log_entry(&record);
         assert_eq!(entry.message, "raw message");
     }
 
+    #[test]
+    fn test_vm_log_to_log_entry_tags_non_main_isolate() {
+        let mut record = VmLogRecord {
+            message: "parsed 10 items".to_string(),
+            level: 800,
+            logger_name: Some("Parser".to_string()),
+            time: 0,
+            sequence_number: 1,
+            error: None,
+            stack_trace: None,
+            isolate_name: Some("worker".to_string()),
+        };
+        assert_eq!(
+            vm_log_to_log_entry(&record).message,
+            "(worker) [Parser] parsed 10 items"
+        );
+
+        record.isolate_name = Some("main".to_string());
+        assert_eq!(
+            vm_log_to_log_entry(&record).message,
+            "[Parser] parsed 10 items"
+        );
+    }
+
     #[test]
     fn test_vm_log_to_log_entry_correct_level() {
         let levels = [
@@ -493,6 +549,7 @@ mod tests {
                 sequence_number: 0,
                 error: None,
                 stack_trace: None,
+                isolate_name: None,
             };
             assert_eq!(
                 vm_log_to_log_entry(&record).level,
@@ -512,6 +569,7 @@ mod tests {
             sequence_number: 0,
             error: None,
             stack_trace: None,
+            isolate_name: None,
         };
         let entry = vm_log_to_log_entry(&record);
         assert_eq!(entry.source, LogSource::VmService);
@@ -527,6 +585,7 @@ mod tests {
             sequence_number: 0,
             error: Some("NullPointerException".to_string()),
             stack_trace: Some("#0 main (package:app/main.dart:10:3)".to_string()),
+            isolate_name: None,
         };
         let entry = vm_log_to_log_entry(&record);
         assert!(entry.has_stack_trace());
@@ -12,9 +12,14 @@
 //! - [`errors`] — VM Service Flutter error event parsing.
 //! - [`extensions`] — Flutter service extension call infrastructure and constants.
 //! - [`timeline`] — Flutter.Frame Extension event parsing for frame timing data.
+//! - [`frame_phases`] — Timeline stream correlation into per-frame build/layout/paint/raster phases.
+//! - [`rebuilds`] — Flutter.RebuiltWidgets Extension event parsing for rebuild tracking.
+//! - [`profiler`] — CPU sample RPC wrappers (`getCpuSamples`, `clearCpuSamples`, `getVMTimelineMicros`).
+//! - [`instances`] — Live instance RPC wrappers (`getInstances`, instance fields, `getRetainingPath`).
 //! - [`performance`] — Memory/GC RPC wrappers (`getMemoryUsage`, `getAllocationProfile`) and GC event parsing.
 //! - [`debugger_types`] — VM Service debug type definitions for debugging RPCs and Debug/Isolate stream events.
 //! - [`debugger`] — Debug RPC wrappers (`pause`, `resume`, `addBreakpointWithScriptUri`, `getStack`, `evaluate`, etc.).
+//! - [`console`] — Raw RPC calls and method completion for the VM Service console.
 //!
 //! ## Quick start
 //!
@@ -66,17 +71,26 @@
 //! ```
 
 pub mod client;
+pub mod console;
 pub mod debugger;
 pub mod debugger_types;
 pub mod errors;
 pub mod extensions;
+pub mod frame_phases;
+pub mod instances;
 pub mod logging;
 pub mod network;
 pub mod performance;
+pub mod profiler;
 pub mod protocol;
+pub mod rebuilds;
 pub mod timeline;
 
 pub use client::{ConnectionState, VmRequestHandle, VmServiceClient, MAX_RECONNECT_ATTEMPTS};
+pub use console::{
+    call_raw, is_stream_already_subscribed, isolate_extension_rpcs, list_extension_rpcs,
+    stream_event_to_json, takes_isolate_id, VM_SERVICE_METHODS,
+};
 pub use debugger::{
     add_breakpoint_with_script_uri, evaluate, evaluate_in_frame, get_object, get_scripts,
     get_source_report, get_stack, pause, remove_breakpoint, resume, set_isolate_pause_mode,
@@ -88,13 +102,23 @@ pub use debugger_types::{
 };
 pub use errors::{flutter_error_to_log_entry, parse_flutter_error, FlutterErrorEvent};
 pub use extensions::{
-    debug_dump, debug_dump_app, debug_dump_layer_tree, debug_dump_render_tree, debug_paint, ext,
-    extract_layout_info, extract_layout_tree, fetch_layout_data, flip_overlay, get_details_subtree,
-    get_layout_node, get_root_widget_tree, get_selected_widget, is_extension_not_available,
+    brightness_override, debug_dump, debug_dump_app, debug_dump_layer_tree, debug_dump_render_tree,
+    debug_paint, ext, extract_layout_info, extract_layout_tree, fetch_layout_data, flip_overlay,
+    get_details_subtree, get_layout_node, get_root_widget_tree, get_selected_widget,
+    is_extension_not_available, is_framework_extension, next_brightness, next_platform,
     parse_bool_extension_response, parse_data_extension_response, parse_diagnostics_node_response,
-    parse_optional_diagnostics_node_response, performance_overlay, query_all_overlays,
-    repaint_rainbow, toggle_bool_extension, widget_inspector, DebugDumpKind, DebugOverlayState,
-    ObjectGroupManager, WidgetInspector,
+    parse_optional_diagnostics_node_response, parse_string_extension_response, performance_overlay,
+    platform_override, query_all_overlays, repaint_rainbow, time_dilation, toggle_bool_extension,
+    widget_inspector, DebugDumpKind, DebugOverlayState, ObjectGroupManager, WidgetInspector,
+    OVERRIDE_PLATFORMS, PLATFORM_OVERRIDE_RESET, SLOW_ANIMATIONS_TIME_DILATION,
+};
+pub use frame_phases::{
+    enable_frame_timeline, restore_timeline_streams, timeline_events, FramePhaseCorrelator,
+    FRAME_TIMELINE_STREAMS,
+};
+pub use instances::{
+    describe_object_ref, get_instance_fields, get_instances, get_retaining_path,
+    parse_instance_fields, parse_instance_set, parse_retaining_path,
 };
 pub use logging::{parse_log_record, vm_level_to_log_level, vm_log_to_log_entry, VmLogRecord};
 pub use network::{
@@ -104,15 +128,18 @@ pub use network::{
     set_socket_profiling_enabled, set_socket_profiling_enabled_handle, HttpProfile,
 };
 pub use performance::{
-    get_allocation_profile, get_memory_sample, get_memory_sample_from_usage, get_memory_usage,
-    parse_allocation_profile, parse_gc_event, parse_memory_usage,
+    get_allocation_profile, get_display_refresh_rate, get_memory_sample,
+    get_memory_sample_from_usage, get_memory_usage, parse_allocation_profile,
+    parse_display_refresh_rate, parse_gc_event, parse_memory_usage,
 };
+pub use profiler::{clear_cpu_samples, get_cpu_samples, get_vm_timeline_micros, parse_cpu_samples};
 pub use protocol::{
     parse_vm_message, IsolateGroupRef, IsolateInfo, IsolateRef, LibraryRef, StreamEvent,
     StreamEventParams, VersionInfo, VmClientEvent, VmInfo, VmRequestTracker, VmServiceError,
     VmServiceEvent, VmServiceMessage, VmServiceRequest, VmServiceResponse,
 };
+pub use rebuilds::parse_rebuilt_widgets;
 pub use timeline::{
-    enable_frame_tracking, flutter_extension_kind, is_frame_event, parse_frame_timing,
-    parse_str_u64,
+    enable_frame_tracking, flutter_extension_kind, is_frame_event, is_navigation_event,
+    parse_frame_timing, parse_str_u64,
 };
@@ -2,7 +2,8 @@
 //!
 //! This module provides functions for fetching heap memory statistics and
 //! allocation profiles from the Dart VM Service, plus parsing logic for
-//! GC stream events.
+//! GC stream events, and the display refresh rate reported by the Flutter
+//! engine.
 //!
 //! ## Callers
 //!
@@ -282,6 +283,10 @@ fn parse_class_heap_stats(member: &serde_json::Value) -> Option<ClassHeapStats>
     // (the Dart client renames it because `class` is a Dart reserved keyword).
     let class_ref = member.get("class").or_else(|| member.get("classRef"))?;
     let class_name = class_ref.get("name")?.as_str()?.to_string();
+    let class_id = class_ref
+        .get("id")
+        .and_then(|v| v.as_str())
+        .map(String::from);
     let library_uri = class_ref
         .get("library")
         .and_then(|lib| lib.get("uri"))
@@ -307,6 +312,7 @@ fn parse_class_heap_stats(member: &serde_json::Value) -> Option<ClassHeapStats>
 
     Some(ClassHeapStats {
         class_name,
+        class_id,
         library_uri,
         // Map current stats to old space (retained)
         old_space_instances: instances_current,
@@ -362,6 +368,52 @@ pub fn parse_gc_event(event: &StreamEvent) -> Option<GcEvent> {
     })
 }
 
+// ── Display refresh rate ──────────────────────────────────────────────────────
+
+/// Fetch the refresh rate of the app's first Flutter view, in Hz.
+///
+/// Calls the engine's `_flutter.listViews` service method to find a view id,
+/// then `_flutter.getDisplayRefreshRate` for that view.
+///
+/// # Errors
+///
+/// Returns [`Error::Protocol`] if the app has no views or the response has no
+/// positive `fps` field, or a transport error if a request fails.
+pub async fn get_display_refresh_rate(handle: &VmRequestHandle) -> Result<f64> {
+    let views = handle.request("_flutter.listViews", None).await?;
+    let view_id = parse_first_view_id(&views)
+        .ok_or_else(|| Error::protocol("_flutter.listViews returned no views"))?;
+    let params = serde_json::json!({ "viewId": view_id });
+    let result = handle
+        .request("_flutter.getDisplayRefreshRate", Some(params))
+        .await?;
+    parse_display_refresh_rate(&result)
+}
+
+/// Id of the first view in a `_flutter.listViews` response.
+fn parse_first_view_id(result: &serde_json::Value) -> Option<String> {
+    result
+        .get("views")?
+        .as_array()?
+        .iter()
+        .find_map(|view| view.get("id")?.as_str())
+        .map(str::to_string)
+}
+
+/// Parse a `_flutter.getDisplayRefreshRate` response (`{"fps": 120.0}`).
+///
+/// # Errors
+///
+/// Returns [`Error::Protocol`] if `fps` is missing or not positive. Some
+/// embedders report `0` when the rate is unknown.
+pub fn parse_display_refresh_rate(result: &serde_json::Value) -> Result<f64> {
+    result
+        .get("fps")
+        .and_then(|v| v.as_f64())
+        .filter(|fps| *fps > 0.0)
+        .ok_or_else(|| Error::protocol("display refresh rate unavailable"))
+}
+
 // ── Tests ─────────────────────────────────────────────────────────────────────
 
 #[cfg(test)]
@@ -369,6 +421,32 @@ mod tests {
     use super::*;
     use serde_json::json;
 
+    // ── display refresh rate ────────────────────────────────────────────────
+
+    #[test]
+    fn test_parse_display_refresh_rate() {
+        assert_eq!(
+            parse_display_refresh_rate(&json!({ "type": "DisplayRefreshRate", "fps": 120.0 }))
+                .unwrap(),
+            120.0
+        );
+        assert!(parse_display_refresh_rate(&json!({ "fps": 0.0 })).is_err());
+        assert!(parse_display_refresh_rate(&json!({})).is_err());
+    }
+
+    #[test]
+    fn test_parse_first_view_id() {
+        let views = json!({
+            "type": "FlutterViewList",
+            "views": [{ "type": "FlutterView", "id": "_flutterView/0x7f" }]
+        });
+        assert_eq!(
+            parse_first_view_id(&views),
+            Some("_flutterView/0x7f".to_string())
+        );
+        assert_eq!(parse_first_view_id(&json!({ "views": [] })), None);
+    }
+
     // ── get_memory_sample_from_usage ────────────────────────────────────────
 
     #[test]
@@ -570,6 +648,7 @@ mod tests {
         let profile = parse_allocation_profile(&result).unwrap();
         assert_eq!(profile.members.len(), 1);
         assert_eq!(profile.members[0].class_name, "String");
+        assert_eq!(profile.members[0].class_id.as_deref(), Some("classes/42"));
         assert_eq!(profile.members[0].library_uri.as_deref(), Some("dart:core"));
         assert_eq!(profile.members[0].old_space_size, 10240);
         assert_eq!(profile.members[0].old_space_instances, 128);
@@ -0,0 +1,210 @@
+//! CPU profiler RPC wrappers.
+//!
+//! A recording is a time window on the VM timeline clock:
+//! [`get_vm_timeline_micros`] marks the start (after [`clear_cpu_samples`]
+//! drops older samples) and, when recording stops, [`get_cpu_samples`]
+//! fetches the samples taken since then.
+//!
+//! ## Response structure
+//!
+//! ```json
+//! {
+//!     "type": "CpuSamples",
+//!     "samplePeriod": 250,
+//!     "timeOriginMicros": 1000, "timeExtentMicros": 2000000,
+//!     "functions": [
+//!         { "kind": "Dart", "resolvedUrl": "package:app/main.dart",
+//!           "function": { "type": "@Function", "name": "build",
+//!                         "owner": { "type": "@Class", "name": "_HomeState" } } }
+//!     ],
+//!     "samples": [ { "tid": 7, "timestamp": 1250, "stack": [0, 3, 5] } ]
+//! }
+//! ```
+//!
+//! `stack` lists indices into `functions`, executing frame first.
+
+use fdemon_core::prelude::*;
+use fdemon_core::profiler::{CpuProfile, ProfileFunction};
+use serde_json::Value;
+
+use super::client::VmRequestHandle;
+
+/// Current time on the VM timeline clock, in microseconds.
+///
+/// # Errors
+///
+/// Returns [`Error::Protocol`] if the response has no `timestamp`, or a
+/// transport error if the request fails.
+pub async fn get_vm_timeline_micros(handle: &VmRequestHandle) -> Result<i64> {
+    let result = handle.request("getVMTimelineMicros", None).await?;
+    result
+        .get("timestamp")
+        .and_then(Value::as_i64)
+        .ok_or_else(|| Error::protocol("missing timestamp in getVMTimelineMicros response"))
+}
+
+/// Drop all CPU samples collected so far for an isolate.
+///
+/// # Errors
+///
+/// Returns a transport or protocol error if the request fails.
+pub async fn clear_cpu_samples(handle: &VmRequestHandle, isolate_id: &str) -> Result<()> {
+    let params = serde_json::json!({ "isolateId": isolate_id });
+    handle.request("clearCpuSamples", Some(params)).await?;
+    Ok(())
+}
+
+/// Fetch the CPU samples taken in `[origin_micros, origin_micros + extent_micros)`.
+///
+/// # Errors
+///
+/// Returns [`Error::Protocol`] if the response cannot be parsed (see
+/// [`parse_cpu_samples`]), or a transport error if the request fails —
+/// including when the profiler is disabled (e.g. release builds).
+pub async fn get_cpu_samples(
+    handle: &VmRequestHandle,
+    isolate_id: &str,
+    origin_micros: i64,
+    extent_micros: i64,
+) -> Result<CpuProfile> {
+    let params = serde_json::json!({
+        "isolateId": isolate_id,
+        "timeOriginMicros": origin_micros,
+        "timeExtentMicros": extent_micros,
+    });
+    let result = handle.request("getCpuSamples", Some(params)).await?;
+    parse_cpu_samples(&result)
+}
+
+/// Parse a `getCpuSamples` response into a [`CpuProfile`].
+///
+/// Samples whose stacks reference unknown function indices keep only the
+/// valid frames.
+///
+/// # Errors
+///
+/// Returns [`Error::Protocol`] if `functions` or `samples` is missing.
+pub fn parse_cpu_samples(result: &Value) -> Result<CpuProfile> {
+    let functions: Vec<ProfileFunction> = result
+        .get("functions")
+        .and_then(Value::as_array)
+        .ok_or_else(|| Error::protocol("missing functions in getCpuSamples response"))?
+        .iter()
+        .map(parse_profile_function)
+        .collect();
+
+    let samples = result
+        .get("samples")
+        .and_then(Value::as_array)
+        .ok_or_else(|| Error::protocol("missing samples in getCpuSamples response"))?
+        .iter()
+        .filter_map(|sample| {
+            let stack = sample.get("stack")?.as_array()?;
+            Some(
+                stack
+                    .iter()
+                    .filter_map(Value::as_u64)
+                    .map(|i| i as usize)
+                    .filter(|&i| i < functions.len())
+                    .collect(),
+            )
+        })
+        .collect();
+
+    let micros = |key: &str| result.get(key).and_then(Value::as_u64).unwrap_or(0);
+    Ok(CpuProfile {
+        functions,
+        samples,
+        sample_period_micros: micros("samplePeriod"),
+        time_extent_micros: micros("timeExtentMicros"),
+    })
+}
+
+/// Parse one `ProfileFunction` entry. Unknown shapes become a function named
+/// after the entry's kind so sample indices stay aligned.
+fn parse_profile_function(entry: &Value) -> ProfileFunction {
+    let kind = entry
+        .get("kind")
+        .and_then(Value::as_str)
+        .unwrap_or("Unknown")
+        .to_string();
+    let function = entry.get("function");
+    let name = function
+        .and_then(|f| f.get("name"))
+        .and_then(Value::as_str)
+        .map(str::to_string)
+        .unwrap_or_else(|| format!("<{kind}>"));
+    let owner = function
+        .and_then(|f| f.get("owner"))
+        .filter(|o| o.get("type").and_then(Value::as_str) != Some("@Library"))
+        .and_then(|o| o.get("name"))
+        .and_then(Value::as_str)
+        .map(str::to_string);
+    let url = entry
+        .get("resolvedUrl")
+        .and_then(Value::as_str)
+        .filter(|u| !u.is_empty())
+        .map(str::to_string);
+    ProfileFunction {
+        name,
+        owner,
+        url,
+        kind,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_parse_cpu_samples() {
+        let value = json!({
+            "type": "CpuSamples",
+            "samplePeriod": 250,
+            "timeOriginMicros": 1000,
+            "timeExtentMicros": 2000000,
+            "functions": [
+                {
+                    "kind": "Dart",
+                    "resolvedUrl": "package:app/main.dart",
+                    "function": {
+                        "type": "@Function",
+                        "name": "build",
+                        "owner": { "type": "@Class", "name": "_HomeState" }
+                    }
+                },
+                {
+                    "kind": "Dart",
+                    "resolvedUrl": "package:app/main.dart",
+                    "function": {
+                        "type": "@Function",
+                        "name": "main",
+                        "owner": { "type": "@Library", "name": "main" }
+                    }
+                },
+                { "kind": "Native", "resolvedUrl": "", "function": { "type": "NativeFunction", "name": "memcpy" } }
+            ],
+            "samples": [
+                { "tid": 7, "timestamp": 1250, "stack": [0, 1] },
+                { "tid": 7, "timestamp": 1500, "stack": [2, 0, 9, 1] }
+            ]
+        });
+        let profile = parse_cpu_samples(&value).unwrap();
+        assert_eq!(profile.sample_period_micros, 250);
+        assert_eq!(profile.time_extent_micros, 2_000_000);
+        assert_eq!(profile.functions[0].display_name(), "_HomeState.build");
+        assert_eq!(profile.functions[1].display_name(), "main");
+        assert_eq!(profile.functions[2].url, None);
+        assert_eq!(profile.functions[2].kind, "Native");
+        // Out-of-range index 9 is dropped.
+        assert_eq!(profile.samples, vec![vec![0, 1], vec![2, 0, 1]]);
+    }
+
+    #[test]
+    fn test_parse_cpu_samples_missing_fields() {
+        assert!(parse_cpu_samples(&json!({ "samples": [] })).is_err());
+        assert!(parse_cpu_samples(&json!({ "functions": [] })).is_err());
+    }
+}
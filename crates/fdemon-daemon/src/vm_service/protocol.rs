@@ -35,6 +35,8 @@ pub mod stream_id {
     pub const DEBUG: &str = "Debug";
     /// Isolate lifecycle events (start, runnable, exit, reload, etc.).
     pub const ISOLATE: &str = "Isolate";
+    /// Batched timeline trace events (frame phase breakdowns).
+    pub const TIMELINE: &str = "Timeline";
 }
 
 // ---------------------------------------------------------------------------
@@ -211,7 +213,9 @@ pub struct IsolateInfo {
     pub start_time: Option<i64>,
     /// Loaded library references.
     pub libraries: Option<Vec<LibraryRef>>,
-    /// Flutter service extension RPCs registered by this isolate.
+    /// Flutter service extension RPCs registered by this isolate. The
+    /// protocol spells the field `extensionRPCs`.
+    #[serde(alias = "extensionRPCs")]
     pub extension_rpcs: Option<Vec<String>>,
 }
 
@@ -563,6 +567,12 @@ mod tests {
         assert!(exts.contains(&"ext.flutter.reassemble".to_string()));
         let libs = info.libraries.unwrap();
         assert_eq!(libs[0].uri, "dart:core");
+
+        let info: IsolateInfo = serde_json::from_str(
+            r#"{"id": "isolates/9", "name": "main", "extensionRPCs": ["ext.flutter.exit"]}"#,
+        )
+        .unwrap();
+        assert_eq!(info.extension_rpcs.unwrap(), ["ext.flutter.exit"]);
     }
 
     #[test]
@@ -0,0 +1,223 @@
+//! Widget rebuild counts from Flutter.RebuiltWidgets Extension events.
+//!
+//! When `ext.flutter.inspector.trackRebuildDirtyWidgets` is enabled, Flutter
+//! posts a `Flutter.RebuiltWidgets` event after every frame that rebuilt
+//! widgets. Counts are keyed by a numeric location id; the location itself is
+//! only included the first time an id is reported.
+//!
+//! ## Event structure
+//!
+//! ```json
+//! {
+//!     "kind": "Extension",
+//!     "extensionKind": "Flutter.RebuiltWidgets",
+//!     "extensionData": {
+//!         "startTime": 1704067200000,
+//!         "events": [1, 3, 2, 1],
+//!         "locations": {
+//!             "file:///app/lib/main.dart": {
+//!                 "ids": [1, 2],
+//!                 "lines": [12, 30],
+//!                 "columns": [5, 9],
+//!                 "names": ["Counter", "Header"]
+//!             }
+//!         }
+//!     }
+//! }
+//! ```
+//!
+//! `events` is a flat list of `id, count` pairs. Flutter versions before 3.4
+//! only send `newLocations`, a map from file to a flat list of
+//! `id, line, column` triples without widget names.
+
+use fdemon_core::performance::RebuiltWidgets;
+use fdemon_core::widget_tree::CreationLocation;
+use serde_json::Value;
+
+use super::protocol::StreamEvent;
+use super::timeline::flutter_extension_kind;
+
+/// Parse a `Flutter.RebuiltWidgets` Extension event.
+///
+/// Returns `None` if the event is not a `Flutter.RebuiltWidgets` event or
+/// carries no `events` list. Malformed location entries are skipped.
+pub fn parse_rebuilt_widgets(event: &StreamEvent) -> Option<RebuiltWidgets> {
+    if flutter_extension_kind(event) != Some("Flutter.RebuiltWidgets") {
+        return None;
+    }
+    let data = event.data.get("extensionData")?;
+
+    let flat = data.get("events")?.as_array()?;
+    let counts = flat
+        .chunks_exact(2)
+        .filter_map(|pair| Some((as_u32(&pair[0])?, pair[1].as_u64()?)))
+        .collect();
+
+    let new_locations = match data.get("locations").and_then(Value::as_object) {
+        Some(files) => parse_locations(files),
+        None => data
+            .get("newLocations")
+            .and_then(Value::as_object)
+            .map(parse_legacy_locations)
+            .unwrap_or_default(),
+    };
+
+    Some(RebuiltWidgets {
+        counts,
+        new_locations,
+    })
+}
+
+/// Parse the columnar `locations` encoding (Flutter 3.4+).
+fn parse_locations(files: &serde_json::Map<String, Value>) -> Vec<(u32, CreationLocation)> {
+    let mut out = Vec::new();
+    for (file, columns) in files {
+        let column = |key: &str| {
+            columns
+                .get(key)
+                .and_then(Value::as_array)
+                .cloned()
+                .unwrap_or_default()
+        };
+        let (ids, lines, cols, names) = (
+            column("ids"),
+            column("lines"),
+            column("columns"),
+            column("names"),
+        );
+        for (i, id) in ids.iter().enumerate() {
+            let (Some(id), Some(line), Some(col)) = (
+                as_u32(id),
+                lines.get(i).and_then(as_u32),
+                cols.get(i).and_then(as_u32),
+            ) else {
+                continue;
+            };
+            out.push((
+                id,
+                CreationLocation {
+                    file: file.clone(),
+                    line,
+                    column: col,
+                    name: names.get(i).and_then(Value::as_str).map(str::to_string),
+                },
+            ));
+        }
+    }
+    out
+}
+
+/// Parse the legacy `newLocations` encoding (`id, line, column` triples).
+fn parse_legacy_locations(files: &serde_json::Map<String, Value>) -> Vec<(u32, CreationLocation)> {
+    let mut out = Vec::new();
+    for (file, flat) in files {
+        let Some(flat) = flat.as_array() else {
+            continue;
+        };
+        for triple in flat.chunks_exact(3) {
+            let (Some(id), Some(line), Some(column)) =
+                (as_u32(&triple[0]), as_u32(&triple[1]), as_u32(&triple[2]))
+            else {
+                continue;
+            };
+            out.push((
+                id,
+                CreationLocation {
+                    file: file.clone(),
+                    line,
+                    column,
+                    name: None,
+                },
+            ));
+        }
+    }
+    out
+}
+
+fn as_u32(value: &Value) -> Option<u32> {
+    value.as_u64().and_then(|v| u32::try_from(v).ok())
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn make_event(kind: &str, data: Value) -> StreamEvent {
+        StreamEvent {
+            kind: "Extension".to_string(),
+            isolate: None,
+            timestamp: Some(1704067200000),
+            data: json!({
+                "extensionKind": kind,
+                "extensionData": data,
+            }),
+        }
+    }
+
+    #[test]
+    fn test_parse_rebuilt_widgets_with_locations() {
+        let event = make_event(
+            "Flutter.RebuiltWidgets",
+            json!({
+                "startTime": 1704067200000_u64,
+                "events": [1, 3, 2, 1],
+                "locations": {
+                    "file:///app/lib/main.dart": {
+                        "ids": [1, 2],
+                        "lines": [12, 30],
+                        "columns": [5, 9],
+                        "names": ["Counter", "Header"]
+                    }
+                }
+            }),
+        );
+
+        let parsed = parse_rebuilt_widgets(&event).unwrap();
+        assert_eq!(parsed.counts, vec![(1, 3), (2, 1)]);
+        assert_eq!(parsed.new_locations.len(), 2);
+        let (id, loc) = &parsed.new_locations[1];
+        assert_eq!(*id, 2);
+        assert_eq!(loc.file, "file:///app/lib/main.dart");
+        assert_eq!((loc.line, loc.column), (30, 9));
+        assert_eq!(loc.name.as_deref(), Some("Header"));
+    }
+
+    #[test]
+    fn test_parse_rebuilt_widgets_legacy_new_locations() {
+        let event = make_event(
+            "Flutter.RebuiltWidgets",
+            json!({
+                "events": [4, 2],
+                "newLocations": {
+                    "file:///app/lib/home.dart": [4, 18, 7]
+                }
+            }),
+        );
+
+        let parsed = parse_rebuilt_widgets(&event).unwrap();
+        assert_eq!(parsed.counts, vec![(4, 2)]);
+        let (id, loc) = &parsed.new_locations[0];
+        assert_eq!(*id, 4);
+        assert_eq!((loc.line, loc.column), (18, 7));
+        assert!(loc.name.is_none());
+    }
+
+    #[test]
+    fn test_parse_rebuilt_widgets_without_new_locations() {
+        let event = make_event("Flutter.RebuiltWidgets", json!({ "events": [1, 1] }));
+        let parsed = parse_rebuilt_widgets(&event).unwrap();
+        assert_eq!(parsed.counts, vec![(1, 1)]);
+        assert!(parsed.new_locations.is_empty());
+    }
+
+    #[test]
+    fn test_parse_rebuilt_widgets_ignores_other_kinds() {
+        let event = make_event("Flutter.Frame", json!({ "events": [1, 1] }));
+        assert!(parse_rebuilt_widgets(&event).is_none());
+    }
+}
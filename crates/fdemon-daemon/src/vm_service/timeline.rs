@@ -35,6 +35,7 @@ use fdemon_core::performance::FrameTiming;
 use fdemon_core::prelude::*;
 
 use super::client::VmRequestHandle;
+use super::extensions::ext;
 use super::protocol::StreamEvent;
 
 // ---------------------------------------------------------------------------
@@ -83,7 +84,7 @@ pub fn parse_frame_timing(event: &StreamEvent) -> Option<FrameTiming> {
         raster_micros: raster,
         elapsed_micros: elapsed,
         timestamp: chrono::Local::now(),
-        // Phase breakdown requires timeline event data (deferred to a future task).
+        // Filled in later from Timeline stream events (see `frame_phases`).
         phases: None,
         shader_compilation,
     })
@@ -110,6 +111,14 @@ pub fn is_frame_event(event: &StreamEvent) -> bool {
     flutter_extension_kind(event) == Some("Flutter.Frame")
 }
 
+/// Check if a stream event is a Flutter.Navigation event.
+///
+/// Posted by the framework's `Navigator` whenever the current route changes,
+/// which replaces most of the widget tree.
+pub fn is_navigation_event(event: &StreamEvent) -> bool {
+    flutter_extension_kind(event) == Some("Flutter.Navigation")
+}
+
 /// Parse a JSON value that may contain a u64 either as a string or as a
 /// JSON number.
 ///
@@ -134,6 +143,10 @@ pub fn parse_str_u64(value: &serde_json::Value) -> Option<u64> {
 /// profile mode where debug extensions are disabled), the call fails silently
 /// because `Flutter.Frame` events are still emitted by the framework.
 ///
+/// Timeline recording for frame phases is left alone here; it is turned on
+/// with [`enable_frame_timeline`](super::frame_phases::enable_frame_timeline)
+/// only while phases are shown.
+///
 /// # Errors
 ///
 /// Always returns `Ok(())`. Errors from the VM Service call are logged at
@@ -144,7 +157,7 @@ pub async fn enable_frame_tracking(handle: &VmRequestHandle, isolate_id: &str) -
     // because Flutter.Frame events may still arrive.
     let result = handle
         .call_extension(
-            "ext.flutter.profileWidgetBuilds",
+            ext::PROFILE_WIDGET_BUILDS,
             isolate_id,
             Some([("enabled".to_string(), "true".to_string())].into()),
         )
@@ -302,6 +315,23 @@ mod tests {
         assert!(!is_frame_event(&error));
     }
 
+    #[test]
+    fn test_is_navigation_event() {
+        let navigation = StreamEvent {
+            kind: "Extension".to_string(),
+            isolate: None,
+            timestamp: None,
+            data: json!({
+                "extensionKind": "Flutter.Navigation",
+                "extensionData": { "route": { "description": "/details" } }
+            }),
+        };
+        assert!(is_navigation_event(&navigation));
+
+        let frame = make_frame_event("1", "10000", "5000", "5000");
+        assert!(!is_navigation_event(&frame));
+    }
+
     /// Build an Extension stream event with arbitrary extensionData payload.
     fn make_extension_event(
         extension_kind: &str,
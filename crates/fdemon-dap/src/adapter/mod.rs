@@ -60,7 +60,7 @@ pub use stack::{
 };
 pub use threads::{
     session_index_from_thread_id, session_thread_base, DapSessionId, MultiSessionThreadMap,
-    ThreadMap, MAX_SESSIONS, THREADS_PER_SESSION,
+    ThreadMap, THREADS_PER_SESSION,
 };
 pub use types::{
     log_level_to_category, BreakpointResult, DapExceptionPauseMode, DebugEvent, PauseReason,
@@ -12,7 +12,7 @@
 //!   - Session 0: thread IDs 1000–1999
 //!   - Session 1: thread IDs 2000–2999
 //!   - Session 2: thread IDs 3000–3999
-//!   - … up to Session 8 (thread IDs 9000–9999)
+//!   - … and so on, one range per session with no upper limit
 //!
 //! DAP clients use integer thread IDs in all debugging requests; the VM Service
 //! uses opaque string isolate IDs. These maps provide the translation layer.
@@ -38,15 +38,10 @@ pub type DapSessionId = u64;
 
 /// Number of thread ID slots reserved per session.
 ///
-/// Session 0 owns IDs 1000–1999, session 1 owns 2000–2999, etc.
-/// This matches the `SessionManager`'s maximum of 9 concurrent sessions.
+/// Session 0 owns IDs 1000–1999, session 1 owns 2000–2999, etc. The
+/// session limit is configurable, so the ranges are not capped.
 pub const THREADS_PER_SESSION: i64 = 1000;
 
-/// Maximum number of concurrent sessions supported.
-///
-/// Mirrors `SessionManager`'s limit. Session indices 0..MAX_SESSIONS are valid.
-pub const MAX_SESSIONS: usize = 9;
-
 /// Calculate the base thread ID for the given session index.
 ///
 /// # Formula
@@ -276,7 +271,7 @@ impl SessionThreads {
 /// | 1 | 2000–2999 |
 /// | 2 | 3000–3999 |
 /// | … | … |
-/// | 8 | 9000–9999 |
+/// | 9 | 10000–10999 |
 ///
 /// The `threads` response aggregates all sessions and prefixes each thread name
 /// with the session name in brackets, e.g.:
@@ -308,22 +303,12 @@ impl MultiSessionThreadMap {
     ///
     /// The session is assigned the next available index (0, 1, 2, …). Returns
     /// the session's thread base so the caller knows where thread IDs start.
-    ///
-    /// # Panics
-    ///
-    /// Panics in debug builds if `sessions.len() >= MAX_SESSIONS`.
     pub fn add_session(
         &mut self,
         session_id: DapSessionId,
         session_name: impl Into<String>,
     ) -> i64 {
         let index = self.sessions.len();
-        debug_assert!(
-            index < MAX_SESSIONS,
-            "Too many sessions: max {} but tried to add session index {}",
-            MAX_SESSIONS,
-            index,
-        );
         let session = SessionThreads::new(session_id, session_name.into(), index);
         let base = session.thread_base;
         self.sessions.push(session);
@@ -1014,4 +999,21 @@ mod tests {
             "Different sessions must use different index ranges"
         );
     }
+
+    #[test]
+    fn test_multi_session_map_supports_more_than_nine_sessions() {
+        let mut map = MultiSessionThreadMap::new();
+        for session_id in 0..12 {
+            map.add_session(session_id, format!("Device {session_id}"));
+        }
+
+        let tid9 = map.add_isolate(9, "isolates/1", Some("main")).unwrap();
+        let tid11 = map.add_isolate(11, "isolates/1", Some("main")).unwrap();
+
+        assert_eq!(tid9, 10000);
+        assert_eq!(tid11, 12000);
+        assert_eq!(session_index_from_thread_id(tid11), 11);
+        assert_eq!(map.lookup_thread(tid9).unwrap().0, 9);
+        assert_eq!(map.lookup_thread(tid11).unwrap().0, 11);
+    }
 }
@@ -171,6 +171,17 @@ pub fn view(frame: &mut Frame, state: &mut AppState) {
                     );
                 }
             }
+
+            // Session picker overlay — drawn on top of normal log view.
+            if let Some(ref picker) = state.session_picker {
+                widgets::render_session_picker(
+                    frame,
+                    areas.logs,
+                    picker,
+                    &state.session_manager,
+                    icons,
+                );
+            }
         }
         UiMode::LinkHighlight => {
             // Link mode is active - the log view handles badge rendering
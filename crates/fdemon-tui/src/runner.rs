@@ -65,6 +65,8 @@ pub async fn run_with_project(project_path: &Path) -> Result<()> {
 ///    when `dap_config` is `Some(ide)`, bypassing environment-based detection.
 /// 3. Evaluates [`should_auto_start_dap`] after CLI flag processing and sends
 ///    `Message::StartDapServer` if the result is `true`.
+/// 4. Applies a `--attach` VM Service URI to `AppState.cli_attach_url` when
+///    `attach_url` is `Some(uri)`, so startup attaches instead of launching.
 ///
 /// This covers all startup paths:
 /// - `--dap-port` CLI flag → `dap.enabled = true` → auto-starts
@@ -75,6 +77,7 @@ pub async fn run_with_project_and_dap(
     project_path: &Path,
     dap_port: Option<u16>,
     dap_config: Option<fdemon_app::config::ParentIde>,
+    attach_url: Option<String>,
 ) -> Result<()> {
     // Install panic hook for terminal restoration
     terminal::install_panic_hook();
@@ -82,6 +85,11 @@ pub async fn run_with_project_and_dap(
     // Create the engine (handles all shared initialization)
     let mut engine = Engine::new(project_path.to_path_buf());
 
+    // Apply --attach: startup auto-launches an attach config for the URI.
+    if let Some(url) = attach_url {
+        engine.apply_cli_attach(url);
+    }
+
     // Apply --dap-port CLI override: sets port and forces enabled = true in
     // both settings copies, keeping them in sync.
     if let Some(port) = dap_port {
@@ -161,7 +169,8 @@ pub async fn run() -> Result<()> {
 /// Dispatch the startup action returned by [`startup::startup_flutter`].
 ///
 /// Auto-start sends `StartAutoLaunch` (which internally triggers device
-/// discovery and auto-launches the session). Ready state triggers device
+/// discovery and auto-launches the session). A saved workspace sends
+/// `StartWorkspaceRestore` the same way. Ready state triggers device
 /// discovery directly so the NewSessionDialog is populated.
 ///
 /// # Ordering
@@ -184,6 +193,11 @@ fn dispatch_startup_action(engine: &mut Engine, action: startup::StartupAction)
                 cache_allowed,
             });
         }
+        startup::StartupAction::RestoreWorkspace { configs, workspace } => {
+            // Saved workspace: the StartWorkspaceRestore handler dispatches
+            // DiscoverDevicesAndRestoreWorkspace, which relaunches the sessions.
+            engine.process_message(Message::StartWorkspaceRestore { configs, workspace });
+        }
         startup::StartupAction::Ready => {
             // No auto-start — discover devices for the NewSessionDialog
             if let Some(flutter) = engine.state.flutter_executable() {
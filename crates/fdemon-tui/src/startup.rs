@@ -6,8 +6,8 @@
 use std::path::Path;
 
 use fdemon_app::config::{
-    self, emit_migration_nudge, get_first_auto_start, has_cached_last_device, load_all_configs,
-    LoadedConfigs, NudgeMode,
+    self, add_cli_attach_config, emit_migration_nudge, get_first_auto_start,
+    has_cached_last_device, load_all_configs, load_workspace, LoadedConfigs, NudgeMode, Workspace,
 };
 use fdemon_app::state::{AppState, UiMode};
 
@@ -18,6 +18,11 @@ pub enum StartupAction {
     Ready,
     /// Auto-start detected — runner will send StartAutoLaunch message
     AutoStart { configs: LoadedConfigs },
+    /// Saved workspace found — runner will send StartWorkspaceRestore message
+    RestoreWorkspace {
+        configs: LoadedConfigs,
+        workspace: Workspace,
+    },
 }
 
 /// Initialize startup state.
@@ -36,6 +41,13 @@ pub enum StartupAction {
 /// cached device has since been disconnected, Tier 3 / Tier 4 handle the
 /// fall-through as usual.
 ///
+/// With `settings.behavior.restore_workspace == true` and a non-empty
+/// `.fdemon/workspace.toml`, the saved sessions are relaunched instead
+/// (`StartupAction::RestoreWorkspace`), ahead of both conditions above.
+///
+/// A `--attach <uri>` URI (`AppState::cli_attach_url`) takes precedence over
+/// everything: an attach config for it is added and auto-launched.
+///
 /// When neither condition holds, shows the NewSessionDialog in Startup mode
 /// and returns `StartupAction::Ready`.
 pub fn startup_flutter(
@@ -44,7 +56,12 @@ pub fn startup_flutter(
     project_path: &Path,
 ) -> StartupAction {
     // Load configs upfront
-    let configs = load_all_configs(project_path);
+    let mut configs = load_all_configs(project_path);
+
+    if let Some(url) = &state.cli_attach_url {
+        add_cli_attach_config(&mut configs, url);
+        return StartupAction::AutoStart { configs };
+    }
 
     let has_auto_start_config = get_first_auto_start(&configs).is_some();
     let has_cache = has_cached_last_device(project_path);
@@ -57,6 +74,12 @@ pub fn startup_flutter(
     // this once so they understand why fdemon didn't auto-launch like it used to.
     let migration_applied = emit_migration_nudge(NudgeMode::Tui, project_path, settings);
 
+    if settings.behavior.restore_workspace {
+        if let Some(workspace) = load_workspace(project_path).filter(|w| !w.sessions.is_empty()) {
+            return StartupAction::RestoreWorkspace { configs, workspace };
+        }
+    }
+
     if has_auto_start_config || cache_trigger {
         // Return AutoStart — runner will send StartAutoLaunch message
         // Don't set show_migration_banner: the dialog is never shown on this path.
@@ -202,7 +225,7 @@ auto_start = true
                 assert_eq!(configs.configs.len(), 1);
                 assert_eq!(configs.configs[0].config.name, "AutoConfig");
             }
-            StartupAction::Ready => panic!("Expected AutoStart, got Ready"),
+            other => panic!("Expected AutoStart, got {other:?}"),
         }
     }
 
@@ -525,4 +548,60 @@ auto_start = true
             "show_migration_banner must be false when no cache is present"
         );
     }
+
+    #[test]
+    fn test_startup_flutter_restores_workspace_when_opted_in() {
+        let temp = tempdir().unwrap();
+        let workspace = Workspace {
+            sessions: vec![config::WorkspaceSession {
+                device_id: "emulator-5554".to_string(),
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        config::save_workspace(temp.path(), &workspace).unwrap();
+
+        // Not opted in → the saved workspace is ignored
+        let mut state = AppState::new();
+        let mut settings = Settings::default();
+        let result = startup_flutter(&mut state, &settings, temp.path());
+        assert!(matches!(result, StartupAction::Ready));
+
+        settings.behavior.restore_workspace = true;
+        let mut state = AppState::new();
+        let result = startup_flutter(&mut state, &settings, temp.path());
+        match result {
+            StartupAction::RestoreWorkspace { workspace: w, .. } => assert_eq!(w, workspace),
+            other => panic!("expected RestoreWorkspace, got {other:?}"),
+        }
+        assert_ne!(state.ui_mode, UiMode::Startup);
+    }
+
+    #[test]
+    fn test_startup_flutter_cli_attach_auto_starts_ahead_of_workspace() {
+        let temp = tempdir().unwrap();
+        let workspace = Workspace {
+            sessions: vec![config::WorkspaceSession {
+                device_id: "emulator-5554".to_string(),
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        config::save_workspace(temp.path(), &workspace).unwrap();
+        let mut settings = Settings::default();
+        settings.behavior.restore_workspace = true;
+
+        let mut state = AppState::new();
+        state.cli_attach_url = Some("http://127.0.0.1:50300/abc=/".to_string());
+        let result = startup_flutter(&mut state, &settings, temp.path());
+
+        match result {
+            StartupAction::AutoStart { configs } => {
+                let first = get_first_auto_start(&configs).unwrap();
+                assert!(first.config.attach);
+            }
+            other => panic!("expected AutoStart, got {other:?}"),
+        }
+        assert_ne!(state.ui_mode, UiMode::Startup);
+    }
 }
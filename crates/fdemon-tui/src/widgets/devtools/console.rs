@@ -0,0 +1,252 @@
+//! VM Service console panel for the DevTools TUI.
+//!
+//! Renders the console output (entered commands, pretty-printed responses,
+//! errors and tailed stream events) anchored to the bottom of the panel,
+//! with the command line underneath and the tailed streams in the title.
+
+use fdemon_app::console_view_state::{ConsoleLineKind, ConsoleViewState};
+use fdemon_app::state::VmConnectionStatus;
+use ratatui::{
+    buffer::Buffer,
+    layout::{Alignment, Rect},
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph, Widget},
+};
+
+use super::truncate_str;
+use crate::theme::palette;
+
+/// VM Service console widget. Stateless; reads from [`ConsoleViewState`].
+pub struct VmConsole<'a> {
+    state: &'a ConsoleViewState,
+    vm_connected: bool,
+    connection_status: &'a VmConnectionStatus,
+}
+
+impl<'a> VmConsole<'a> {
+    /// Create a new `VmConsole` widget.
+    pub fn new(
+        state: &'a ConsoleViewState,
+        vm_connected: bool,
+        connection_status: &'a VmConnectionStatus,
+    ) -> Self {
+        Self {
+            state,
+            vm_connected,
+            connection_status,
+        }
+    }
+}
+
+impl Widget for VmConsole<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let mut block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(palette::BORDER_DIM))
+            .title(Span::styled(
+                " VM Service Console ",
+                Style::default().fg(palette::ACCENT_DIM),
+            ))
+            .title_alignment(Alignment::Left);
+        if !self.state.tailed.is_empty() {
+            let streams: Vec<&str> = self.state.tailed.iter().map(String::as_str).collect();
+            block = block.title(
+                Line::from(Span::styled(
+                    format!(" tailing {} ", streams.join(", ")),
+                    Style::default().fg(palette::STATUS_GREEN),
+                ))
+                .right_aligned(),
+            );
+        }
+        let mut inner = block.inner(area);
+        block.render(area, buf);
+
+        if inner.height == 0 || inner.width == 0 {
+            return;
+        }
+
+        // Command line on the last row, then the status of a pending RPC.
+        inner.height -= 1;
+        self.render_input(
+            Rect {
+                y: inner.bottom(),
+                height: 1,
+                ..inner
+            },
+            buf,
+        );
+        if let Some(method) = &self.state.pending {
+            if inner.height > 1 {
+                inner.height -= 1;
+                buf.set_string(
+                    inner.x,
+                    inner.bottom(),
+                    truncate_str(&format!("Waiting for {method}..."), inner.width as usize),
+                    Style::default().fg(palette::TEXT_MUTED),
+                );
+            }
+        }
+
+        if self.state.lines.is_empty() {
+            let (text, color) = if self.vm_connected {
+                (
+                    "Type a method such as getVM, [Tab] completes, tail <streamId> follows a stream"
+                        .to_string(),
+                    palette::TEXT_MUTED,
+                )
+            } else {
+                (self.disconnected_text(), palette::STATUS_YELLOW)
+            };
+            render_centered(inner, buf, &text, color);
+            return;
+        }
+
+        self.render_lines(inner, buf);
+    }
+}
+
+impl VmConsole<'_> {
+    /// Render the output lines, newest at the bottom, `scroll` lines up.
+    fn render_lines(&self, area: Rect, buf: &mut Buffer) {
+        let lines = &self.state.lines;
+        let height = area.height as usize;
+        let end = lines.len().saturating_sub(self.state.scroll);
+        let start = end.saturating_sub(height);
+        let top = area.y + (height - (end - start)) as u16;
+
+        for (offset, line) in lines.range(start..end).enumerate() {
+            let color = match line.kind {
+                ConsoleLineKind::Command => palette::ACCENT,
+                ConsoleLineKind::Response => palette::TEXT_PRIMARY,
+                ConsoleLineKind::Error => palette::STATUS_RED,
+                ConsoleLineKind::Event => palette::TEXT_SECONDARY,
+                ConsoleLineKind::Info => palette::STATUS_BLUE,
+            };
+            buf.set_string(
+                area.x,
+                top + offset as u16,
+                truncate_str(&line.text, area.width as usize),
+                Style::default().fg(color),
+            );
+        }
+
+        // Scroll marker on the right edge while looking at older output.
+        if self.state.scroll > 0 && height > 0 {
+            let marker = format!(" ↓{} ", self.state.scroll);
+            let width = marker.chars().count() as u16;
+            if width <= area.width {
+                buf.set_string(
+                    area.right() - width,
+                    area.bottom() - 1,
+                    marker,
+                    Style::default().fg(palette::STATUS_YELLOW),
+                );
+            }
+        }
+    }
+
+    /// Render `> input█`, keeping the end of a long input in view.
+    fn render_input(&self, area: Rect, buf: &mut Buffer) {
+        let state = self.state;
+        buf.set_string(area.x, area.y, "> ", Style::default().fg(palette::ACCENT));
+        let width = area.width.saturating_sub(2) as usize;
+
+        let (text, color) = if state.input_active {
+            (format!("{}█", state.input), palette::TEXT_BRIGHT)
+        } else if state.input.is_empty() {
+            (
+                "Press [:] or [Enter] to type a command".to_string(),
+                palette::TEXT_MUTED,
+            )
+        } else {
+            (state.input.clone(), palette::TEXT_SECONDARY)
+        };
+        let skip = text.chars().count().saturating_sub(width);
+        let shown: String = text.chars().skip(skip).collect();
+        buf.set_string(area.x + 2, area.y, shown, Style::default().fg(color));
+    }
+
+    fn disconnected_text(&self) -> String {
+        match self.connection_status {
+            VmConnectionStatus::Reconnecting {
+                attempt,
+                max_attempts,
+            } => format!("Reconnecting to VM Service... ({attempt}/{max_attempts})"),
+            _ => "VM Service not connected".to_string(),
+        }
+    }
+}
+
+fn render_centered(area: Rect, buf: &mut Buffer, text: &str, color: Color) {
+    if area.height == 0 {
+        return;
+    }
+    Paragraph::new(text)
+        .style(Style::default().fg(color))
+        .alignment(Alignment::Center)
+        .render(
+            Rect {
+                y: area.y + area.height / 2,
+                height: 1,
+                ..area
+            },
+            buf,
+        );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn render_to_text(state: &ConsoleViewState, vm_connected: bool) -> String {
+        let status = VmConnectionStatus::Connected;
+        let area = Rect::new(0, 0, 70, 8);
+        let mut buf = Buffer::empty(area);
+        VmConsole::new(state, vm_connected, &status).render(area, &mut buf);
+        let mut text = String::new();
+        for y in 0..area.height {
+            for x in 0..area.width {
+                text.push_str(buf[(x, y)].symbol());
+            }
+            text.push('\n');
+        }
+        text
+    }
+
+    #[test]
+    fn test_renders_output_bottom_up_with_input() {
+        let mut state = ConsoleViewState::default();
+        state.push(ConsoleLineKind::Command, "> getVersion");
+        for line in ["{", "  \"major\": 4", "}"] {
+            state.push(ConsoleLineKind::Response, line);
+        }
+        state.input = "getV".to_string();
+        state.input_active = true;
+        state.tailed.insert("GC".to_string());
+
+        let text = render_to_text(&state, true);
+        let rows: Vec<&str> = text.lines().collect();
+        assert!(rows[0].contains("VM Service Console"));
+        assert!(rows[0].contains("tailing GC"));
+        assert!(rows[2].contains("> getVersion"));
+        assert!(rows[5].contains("}"), "newest line sits above the input");
+        assert!(rows[6].contains("> getV█"));
+    }
+
+    #[test]
+    fn test_renders_scroll_marker_and_hints() {
+        let mut state = ConsoleViewState::default();
+        assert!(render_to_text(&state, false).contains("VM Service not connected"));
+        assert!(render_to_text(&state, true).contains("Press [:] or [Enter]"));
+
+        for i in 0..20 {
+            state.push(ConsoleLineKind::Event, format!("[GC] event {i}"));
+        }
+        state.scroll_by(5);
+        let text = render_to_text(&state, true);
+        assert!(text.contains("[GC] event 14"));
+        assert!(!text.contains("[GC] event 15"));
+        assert!(text.contains("↓5"));
+    }
+}
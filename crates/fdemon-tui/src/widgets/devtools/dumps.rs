@@ -0,0 +1,355 @@
+//! Dump viewer panel for the DevTools TUI.
+//!
+//! Renders the text returned by the `debugDump*` service extensions with a
+//! kind selector, `▶`/`▼` collapse markers on lines that have deeper lines
+//! below them, a search bar, and a one-line status message.
+
+use fdemon_app::dump_view_state::{DebugDumpKind, DumpViewState};
+use fdemon_app::state::VmConnectionStatus;
+use ratatui::{
+    buffer::Buffer,
+    layout::{Alignment, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph, Widget, Wrap},
+};
+
+use super::truncate_str;
+use crate::theme::palette;
+
+/// Dump viewer widget. Stateless; reads from [`DumpViewState`].
+pub struct DumpViewer<'a> {
+    state: &'a DumpViewState,
+    vm_connected: bool,
+    connection_status: &'a VmConnectionStatus,
+}
+
+impl<'a> DumpViewer<'a> {
+    /// Create a new `DumpViewer` widget.
+    pub fn new(
+        state: &'a DumpViewState,
+        vm_connected: bool,
+        connection_status: &'a VmConnectionStatus,
+    ) -> Self {
+        Self {
+            state,
+            vm_connected,
+            connection_status,
+        }
+    }
+}
+
+impl Widget for DumpViewer<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let title = match self.state.loaded_kind {
+            Some(kind) => format!(" {} Dump ", kind.label()),
+            None => " Dumps ".to_string(),
+        };
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(palette::BORDER_DIM))
+            .title(Span::styled(
+                title,
+                Style::default().fg(palette::ACCENT_DIM),
+            ))
+            .title_alignment(Alignment::Left);
+        let mut inner = block.inner(area);
+        block.render(area, buf);
+
+        if inner.height == 0 || inner.width == 0 {
+            return;
+        }
+
+        self.render_kind_tabs(Rect { height: 1, ..inner }, buf);
+        inner.y += 1;
+        inner.height -= 1;
+
+        // Footer rows: status message and search bar, bottom-up.
+        if let Some(status) = &self.state.status {
+            if inner.height > 1 {
+                inner.height -= 1;
+                buf.set_string(
+                    inner.x,
+                    inner.bottom(),
+                    truncate_str(status, inner.width as usize),
+                    Style::default().fg(palette::STATUS_GREEN),
+                );
+            }
+        }
+        let state = self.state;
+        if (state.search_input_active || !state.search_query().is_empty()) && inner.height > 1 {
+            inner.height -= 1;
+            let bar = Rect {
+                y: inner.bottom(),
+                height: 1,
+                ..inner
+            };
+            self.render_search_bar(bar, buf);
+        }
+
+        if let Some(error) = &state.error {
+            render_error(inner, buf, &error.message, &error.hint);
+            return;
+        }
+        if state.lines.is_empty() {
+            let (text, color) = if state.loading {
+                (
+                    format!("Loading {} dump...", state.kind.label()),
+                    palette::TEXT_MUTED,
+                )
+            } else if !self.vm_connected {
+                (self.disconnected_text(), palette::STATUS_YELLOW)
+            } else if state.loaded_kind == Some(DebugDumpKind::SemanticsTree) {
+                (
+                    "Semantics tree is empty — enable semantics on the device".to_string(),
+                    palette::TEXT_MUTED,
+                )
+            } else if state.loaded_kind.is_some() {
+                ("Dump is empty".to_string(), palette::TEXT_MUTED)
+            } else {
+                (
+                    "Press [r] to fetch the dump".to_string(),
+                    palette::TEXT_MUTED,
+                )
+            };
+            render_centered(inner, buf, &text, color);
+            return;
+        }
+
+        self.render_lines(inner, buf);
+    }
+}
+
+impl DumpViewer<'_> {
+    /// Render `[1] Widgets  [2] Render ...` with the selected kind highlighted.
+    fn render_kind_tabs(&self, area: Rect, buf: &mut Buffer) {
+        let mut x = area.x;
+        for (i, kind) in DebugDumpKind::ALL.iter().enumerate() {
+            let label = format!(" [{}] {} ", i + 1, kind.label());
+            let width = label.chars().count() as u16;
+            if x + width > area.right() {
+                break;
+            }
+            let style = if *kind == self.state.kind {
+                Style::default()
+                    .bg(palette::ACCENT_DIM)
+                    .fg(palette::TEXT_BRIGHT)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(palette::TEXT_SECONDARY)
+            };
+            buf.set_string(x, area.y, &label, style);
+            x += width + 1;
+        }
+    }
+
+    /// Render the visible dump lines, scrolled so the selection stays in view.
+    fn render_lines(&self, area: Rect, buf: &mut Buffer) {
+        let state = self.state;
+        let visible = state.visible_lines();
+        let selected = visible
+            .iter()
+            .position(|&i| i == state.selected)
+            .unwrap_or(0);
+        let height = area.height as usize;
+        let total = visible.len();
+        let start = selected
+            .saturating_sub(height / 2)
+            .min(total.saturating_sub(height));
+
+        for (offset, &index) in visible.iter().skip(start).take(height).enumerate() {
+            let y = area.y + offset as u16;
+            let line = &state.lines[index];
+            let is_selected = index == state.selected;
+
+            let marker = if state.has_children(index) {
+                if state.collapsed.contains(&index) {
+                    "▶"
+                } else {
+                    "▼"
+                }
+            } else {
+                " "
+            };
+
+            if is_selected {
+                let sel_bg = Style::default().bg(palette::SELECTED_ROW_BG);
+                for x in area.x..area.right() {
+                    if let Some(cell) = buf.cell_mut((x, y)) {
+                        cell.set_style(sel_bg);
+                    }
+                }
+            }
+
+            let mut style = Style::default().fg(palette::TEXT_PRIMARY);
+            if is_selected {
+                style = style.add_modifier(Modifier::BOLD);
+            }
+            if state.is_search_match(index) {
+                style = style
+                    .fg(palette::SEARCH_HIGHLIGHT_BG)
+                    .add_modifier(Modifier::BOLD);
+            }
+
+            buf.set_string(area.x, y, marker, Style::default().fg(palette::ACCENT));
+            let text_width = area.width.saturating_sub(2) as usize;
+            buf.set_string(area.x + 2, y, truncate_str(&line.text, text_width), style);
+        }
+
+        // Scroll thumb on the right edge when the dump overflows.
+        if total > height && height > 0 {
+            let thumb_y = area.y + ((selected * height) / total).min(height - 1) as u16;
+            if let Some(cell) = buf.cell_mut((area.right().saturating_sub(1), thumb_y)) {
+                cell.set_symbol("█").set_fg(palette::BORDER_DIM);
+            }
+        }
+    }
+
+    /// Render `/query  (2/5)` or `/query  no matches` on one row.
+    fn render_search_bar(&self, area: Rect, buf: &mut Buffer) {
+        let state = self.state;
+        let cursor = if state.search_input_active { "█" } else { "" };
+        let query = format!("/{}{}", state.search_query(), cursor);
+        let (total, position) = state.search_match_position();
+        let status = match (total, position) {
+            _ if state.search_query().is_empty() => String::new(),
+            (0, _) => "  no matches".to_string(),
+            (total, Some(pos)) => format!("  ({pos}/{total})"),
+            (total, None) => format!("  ({total} matches)"),
+        };
+        let query_text = truncate_str(&query, area.width as usize);
+        buf.set_string(
+            area.x,
+            area.y,
+            query_text,
+            Style::default().fg(palette::STATUS_YELLOW),
+        );
+        let used = query_text.chars().count() as u16;
+        let remaining = area.width.saturating_sub(used) as usize;
+        buf.set_string(
+            area.x + used,
+            area.y,
+            truncate_str(&status, remaining),
+            Style::default().fg(palette::TEXT_MUTED),
+        );
+    }
+
+    fn disconnected_text(&self) -> String {
+        match self.connection_status {
+            VmConnectionStatus::Reconnecting {
+                attempt,
+                max_attempts,
+            } => format!("Reconnecting to VM Service... ({attempt}/{max_attempts})"),
+            _ => "VM Service not connected".to_string(),
+        }
+    }
+}
+
+fn render_centered(area: Rect, buf: &mut Buffer, text: &str, color: Color) {
+    if area.height == 0 {
+        return;
+    }
+    Paragraph::new(text)
+        .style(Style::default().fg(color))
+        .alignment(Alignment::Center)
+        .render(
+            Rect {
+                y: area.y + area.height / 2,
+                height: 1,
+                ..area
+            },
+            buf,
+        );
+}
+
+fn render_error(area: Rect, buf: &mut Buffer, message: &str, hint: &str) {
+    if area.height == 0 {
+        return;
+    }
+    let lines = vec![
+        Line::from(Span::styled(
+            format!("\u{26a0} {message}"),
+            Style::default().fg(Color::Yellow),
+        )),
+        Line::from(""),
+        Line::from(Span::styled(hint, Style::default().fg(Color::DarkGray))),
+        Line::from(""),
+        Line::from(Span::styled(
+            "[r] Retry   [1-5] Other dump   [Esc] Return to logs",
+            Style::default().fg(palette::TEXT_MUTED),
+        )),
+    ];
+    let h = 5u16;
+    Paragraph::new(lines)
+        .alignment(Alignment::Center)
+        .wrap(Wrap { trim: true })
+        .render(
+            Rect {
+                y: area.y + area.height.saturating_sub(h) / 2,
+                height: h.min(area.height),
+                ..area
+            },
+            buf,
+        );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fdemon_app::state::DevToolsError;
+
+    fn render_to_text(state: &DumpViewState, vm_connected: bool) -> String {
+        let status = VmConnectionStatus::Connected;
+        let area = Rect::new(0, 0, 70, 12);
+        let mut buf = Buffer::empty(area);
+        DumpViewer::new(state, vm_connected, &status).render(area, &mut buf);
+        let mut text = String::new();
+        for y in 0..area.height {
+            for x in 0..area.width {
+                text.push_str(buf[(x, y)].symbol());
+            }
+            text.push('\n');
+        }
+        text
+    }
+
+    #[test]
+    fn test_renders_dump_with_collapse_markers() {
+        let mut state = DumpViewState::default();
+        state.set_dump(
+            DebugDumpKind::RenderTree,
+            "RenderView#1\n └─child: RenderFlex#2\n   └─child: RenderBox#3\n".to_string(),
+        );
+        state.collapsed.insert(1);
+        let text = render_to_text(&state, true);
+        assert!(text.contains("Render Dump"));
+        assert!(text.contains("[2] Render"));
+        assert!(text.contains("▼ RenderView#1"));
+        assert!(text.contains("▶  └─child: RenderFlex#2"));
+        assert!(!text.contains("RenderBox#3"), "collapsed line is hidden");
+    }
+
+    #[test]
+    fn test_renders_search_bar_and_status() {
+        let mut state = DumpViewState::default();
+        state.set_dump(DebugDumpKind::WidgetTree, "MyApp\n └Text".to_string());
+        state.set_search_query("text");
+        state.select_search_match(true, true);
+        state.status = Some("Saved to .fdemon/dumps/widgets.txt".to_string());
+        let text = render_to_text(&state, true);
+        assert!(text.contains("/text  (1/1)"));
+        assert!(text.contains("Saved to .fdemon/dumps/widgets.txt"));
+    }
+
+    #[test]
+    fn test_renders_error_and_empty_states() {
+        let mut state = DumpViewState::default();
+        assert!(render_to_text(&state, false).contains("VM Service not connected"));
+
+        state.set_error(DevToolsError::new(
+            "Layers dump not available in this mode",
+            "Try running in debug mode",
+        ));
+        assert!(render_to_text(&state, true).contains("Layers dump not available"));
+    }
+}
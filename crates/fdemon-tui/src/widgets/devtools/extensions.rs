@@ -0,0 +1,285 @@
+//! Service extensions panel for the DevTools TUI.
+//!
+//! Lists the buttons configured under `[[devtools.extensions]]` followed by
+//! the app-registered extensions of the DevTools isolate, with the outcome
+//! of the last call at the bottom.
+
+use fdemon_app::extensions_view_state::{ExtensionRow, ExtensionsViewState};
+use fdemon_app::state::VmConnectionStatus;
+use ratatui::{
+    buffer::Buffer,
+    layout::{Alignment, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph, Widget},
+};
+
+use super::truncate_str;
+use crate::theme::palette;
+
+/// Service extensions widget. Stateless; reads from [`ExtensionsViewState`].
+pub struct ExtensionPanel<'a> {
+    state: &'a ExtensionsViewState,
+    vm_connected: bool,
+    connection_status: &'a VmConnectionStatus,
+}
+
+impl<'a> ExtensionPanel<'a> {
+    /// Create a new `ExtensionPanel` widget.
+    pub fn new(
+        state: &'a ExtensionsViewState,
+        vm_connected: bool,
+        connection_status: &'a VmConnectionStatus,
+    ) -> Self {
+        Self {
+            state,
+            vm_connected,
+            connection_status,
+        }
+    }
+}
+
+impl Widget for ExtensionPanel<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(palette::BORDER_DIM))
+            .title(Span::styled(
+                " Service Extensions ",
+                Style::default().fg(palette::ACCENT_DIM),
+            ))
+            .title_alignment(Alignment::Left);
+        let mut inner = block.inner(area);
+        block.render(area, buf);
+
+        if inner.height == 0 || inner.width == 0 {
+            return;
+        }
+
+        // Outcome of the last call on the last row.
+        if let Some(status) = &self.state.status {
+            if inner.height > 1 {
+                inner.height -= 1;
+                buf.set_string(
+                    inner.x,
+                    inner.bottom(),
+                    truncate_str(status, inner.width as usize),
+                    Style::default().fg(palette::STATUS_BLUE),
+                );
+            }
+        }
+
+        let rows = self.state.rows();
+        if rows.is_empty() {
+            let (text, color) = if !self.vm_connected {
+                (self.disconnected_text(), palette::STATUS_YELLOW)
+            } else if let Some(error) = &self.state.error {
+                (
+                    format!("{} — {}", error.message, error.hint),
+                    palette::STATUS_RED,
+                )
+            } else if self.state.loading {
+                ("Loading extensions...".to_string(), palette::TEXT_MUTED)
+            } else {
+                (
+                    "No app-registered extensions. Add buttons under [[devtools.extensions]]"
+                        .to_string(),
+                    palette::TEXT_MUTED,
+                )
+            };
+            render_centered(inner, buf, &text, color);
+            return;
+        }
+
+        // Fetch errors stay visible above the configured buttons.
+        if let Some(error) = &self.state.error {
+            if inner.height > 1 {
+                buf.set_string(
+                    inner.x,
+                    inner.y,
+                    truncate_str(
+                        &format!("{} — {}", error.message, error.hint),
+                        inner.width as usize,
+                    ),
+                    Style::default().fg(palette::STATUS_RED),
+                );
+                inner.y += 1;
+                inner.height -= 1;
+            }
+        }
+
+        self.render_rows(&rows, inner, buf);
+    }
+}
+
+impl ExtensionPanel<'_> {
+    /// Render one line per row, scrolled to keep the selection visible.
+    fn render_rows(&self, rows: &[ExtensionRow<'_>], area: Rect, buf: &mut Buffer) {
+        let height = area.height as usize;
+        let selected = self.state.selected.min(rows.len().saturating_sub(1));
+        let start = (selected + 1).saturating_sub(height);
+
+        for (offset, row) in rows.iter().skip(start).take(height).enumerate() {
+            let is_selected = start + offset == selected;
+            let line = row_line(row, is_selected);
+            let y = area.y + offset as u16;
+            if is_selected {
+                buf.set_style(
+                    Rect {
+                        y,
+                        height: 1,
+                        ..area
+                    },
+                    Style::default().bg(palette::SELECTED_ROW_BG),
+                );
+            }
+            buf.set_line(area.x, y, &line, area.width);
+        }
+    }
+
+    fn disconnected_text(&self) -> String {
+        match self.connection_status {
+            VmConnectionStatus::Reconnecting {
+                attempt,
+                max_attempts,
+            } => format!("Reconnecting to VM Service... ({attempt}/{max_attempts})"),
+            _ => "VM Service not connected".to_string(),
+        }
+    }
+}
+
+/// `[m] Debug menu  ext.myapp.debugMenu  enabled=true`, with a marker for
+/// buttons whose method the isolate has not registered.
+fn row_line<'a>(row: &ExtensionRow<'a>, is_selected: bool) -> Line<'a> {
+    let label_style = if is_selected {
+        Style::default()
+            .fg(palette::TEXT_BRIGHT)
+            .add_modifier(Modifier::BOLD)
+    } else {
+        Style::default().fg(palette::TEXT_PRIMARY)
+    };
+    let method_style = Style::default().fg(palette::TEXT_MUTED);
+
+    match row {
+        ExtensionRow::Button {
+            button, registered, ..
+        } => {
+            let key = button.key.map(|k| format!("[{k}]")).unwrap_or_default();
+            let mut args: Vec<String> = button
+                .args
+                .iter()
+                .map(|(name, value)| format!("{name}={value}"))
+                .collect();
+            args.sort();
+
+            let mut spans = vec![
+                Span::styled(format!("{key:<4}"), Style::default().fg(palette::ACCENT)),
+                Span::styled(button.label.clone(), label_style),
+                Span::raw("  "),
+                Span::styled(button.method.clone(), method_style),
+            ];
+            if !args.is_empty() {
+                spans.push(Span::styled(
+                    format!("  {}", args.join(" ")),
+                    Style::default().fg(palette::TEXT_SECONDARY),
+                ));
+            }
+            if *registered == Some(false) {
+                spans.push(Span::styled(
+                    "  (not registered)",
+                    Style::default().fg(palette::STATUS_YELLOW),
+                ));
+            }
+            Line::from(spans)
+        }
+        ExtensionRow::Registered(method) => Line::from(vec![
+            Span::raw("    "),
+            Span::styled(method.to_string(), label_style),
+        ]),
+    }
+}
+
+fn render_centered(area: Rect, buf: &mut Buffer, text: &str, color: Color) {
+    if area.height == 0 {
+        return;
+    }
+    Paragraph::new(text)
+        .style(Style::default().fg(color))
+        .alignment(Alignment::Center)
+        .render(
+            Rect {
+                y: area.y + area.height / 2,
+                height: 1,
+                ..area
+            },
+            buf,
+        );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fdemon_app::config::ExtensionButton;
+
+    fn render_to_text(state: &ExtensionsViewState, vm_connected: bool) -> String {
+        let status = VmConnectionStatus::Connected;
+        let area = Rect::new(0, 0, 80, 8);
+        let mut buf = Buffer::empty(area);
+        ExtensionPanel::new(state, vm_connected, &status).render(area, &mut buf);
+        let mut text = String::new();
+        for y in 0..area.height {
+            for x in 0..area.width {
+                text.push_str(buf[(x, y)].symbol());
+            }
+            text.push('\n');
+        }
+        text
+    }
+
+    #[test]
+    fn test_renders_buttons_registered_extensions_and_status() {
+        let mut state = ExtensionsViewState {
+            buttons: vec![
+                ExtensionButton {
+                    label: "Debug menu".to_string(),
+                    method: "ext.myapp.debugMenu".to_string(),
+                    key: Some('m'),
+                    args: [("enabled".to_string(), "true".to_string())].into(),
+                },
+                ExtensionButton {
+                    label: "Old flag".to_string(),
+                    method: "ext.myapp.removed".to_string(),
+                    key: None,
+                    args: Default::default(),
+                },
+            ],
+            ..Default::default()
+        };
+        state.set_registered(vec![
+            "ext.myapp.debugMenu".to_string(),
+            "ext.myapp.resetCache".to_string(),
+        ]);
+        state.status = Some("Debug menu: done".to_string());
+
+        let text = render_to_text(&state, true);
+        let rows: Vec<&str> = text.lines().collect();
+        assert!(rows[0].contains("Service Extensions"));
+        assert!(rows[1].contains("[m] Debug menu  ext.myapp.debugMenu  enabled=true"));
+        assert!(!rows[1].contains("not registered"));
+        assert!(rows[2].contains("Old flag") && rows[2].contains("(not registered)"));
+        assert!(rows[3].contains("ext.myapp.resetCache"));
+        assert!(rows[6].contains("Debug menu: done"));
+    }
+
+    #[test]
+    fn test_renders_empty_and_disconnected_states() {
+        let mut state = ExtensionsViewState::default();
+        assert!(render_to_text(&state, false).contains("VM Service not connected"));
+
+        state.loading = true;
+        assert!(render_to_text(&state, true).contains("Loading extensions..."));
+
+        state.set_registered(Vec::new());
+        assert!(render_to_text(&state, true).contains("No app-registered extensions"));
+    }
+}
@@ -261,7 +261,7 @@ impl WidgetInspector<'_> {
 
 // ── Helper render functions ───────────────────────────────────────────────────
 
-fn render_centered_text(area: Rect, buf: &mut Buffer, text: &str, color: Color) {
+pub(super) fn render_centered_text(area: Rect, buf: &mut Buffer, text: &str, color: Color) {
     if area.height == 0 {
         return;
     }
@@ -278,7 +278,7 @@ fn render_centered_text(area: Rect, buf: &mut Buffer, text: &str, color: Color)
         );
 }
 
-fn render_layout_error(area: Rect, buf: &mut Buffer, message: &str, hint: &str) {
+pub(super) fn render_layout_error(area: Rect, buf: &mut Buffer, message: &str, hint: &str) {
     if area.height == 0 {
         return;
     }
@@ -4,9 +4,10 @@
 //! with the selected widget's details shown in a side panel.
 
 mod layout_panel;
+mod properties_panel;
 mod tree_panel;
 
-use fdemon_app::state::{DevToolsError, InspectorState, VmConnectionStatus};
+use fdemon_app::state::{DevToolsError, InspectorDetailView, InspectorState, VmConnectionStatus};
 use fdemon_core::widget_tree::DiagnosticsNode;
 use ratatui::{
     buffer::Buffer,
@@ -196,8 +197,13 @@ impl WidgetInspector<'_> {
 
         self.render_tree_panel(tree_area, buf, &visible, selected);
 
-        if let Some(lay_area) = layout_area {
-            self.render_layout_panel(lay_area, buf, &visible, selected);
+        if let Some(detail_area) = layout_area {
+            match self.inspector_state.detail_view {
+                InspectorDetailView::Layout => {
+                    self.render_layout_panel(detail_area, buf, &visible, selected)
+                }
+                InspectorDetailView::Properties => self.render_properties_panel(detail_area, buf),
+            }
         }
     }
 
@@ -0,0 +1,147 @@
+//! Properties panel rendering for the widget inspector.
+//!
+//! Shows the full diagnostics property list of the selected widget with
+//! expandable nested properties, level-based styling, and highlighting for
+//! values that differ from their defaults.
+
+use fdemon_core::widget_tree::{DiagnosticLevel, DiagnosticsNode};
+use ratatui::{
+    buffer::Buffer,
+    layout::{Alignment, Rect},
+    style::{Color, Modifier, Style},
+    text::Span,
+    widgets::{Block, Borders, Widget},
+};
+
+use super::layout_panel::{render_centered_text, render_layout_error};
+use super::truncate_str;
+use super::WidgetInspector;
+use crate::theme::palette;
+
+impl WidgetInspector<'_> {
+    /// Render the properties panel for the currently selected widget.
+    pub(super) fn render_properties_panel(&self, area: Rect, buf: &mut Buffer) {
+        let state = self.inspector_state;
+        let border = if state.properties_focused {
+            palette::BORDER_ACTIVE
+        } else {
+            palette::BORDER_DIM
+        };
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(border))
+            .title(Span::styled(
+                " Properties ",
+                Style::default().fg(palette::ACCENT_DIM),
+            ))
+            .title_alignment(Alignment::Left);
+        let inner = block.inner(area);
+        block.render(area, buf);
+
+        if inner.height == 0 || inner.width == 0 {
+            return;
+        }
+
+        if state.properties_loading {
+            render_centered_text(inner, buf, "Loading properties...", palette::TEXT_MUTED);
+            return;
+        }
+        if let Some(ref error) = state.properties_error {
+            render_layout_error(inner, buf, &error.message, &error.hint);
+            return;
+        }
+
+        let rows = state.visible_properties();
+        if rows.is_empty() {
+            let text = if state.properties.is_some() {
+                "This widget has no properties"
+            } else {
+                "Select a widget to see its properties"
+            };
+            render_centered_text(inner, buf, text, palette::TEXT_MUTED);
+            return;
+        }
+
+        let viewport_height = inner.height as usize;
+        let total = rows.len();
+        let selected = state.properties_selected.min(total - 1);
+        let start = if selected >= viewport_height {
+            selected + 1 - viewport_height
+        } else {
+            0
+        };
+        let end = (start + viewport_height).min(total);
+
+        for (offset, (path, property, depth)) in rows[start..end].iter().enumerate() {
+            let y = inner.y + offset as u16;
+            let is_selected = state.properties_focused && start + offset == selected;
+
+            if is_selected {
+                let sel_bg = Style::default().bg(palette::SELECTED_ROW_BG);
+                for x in inner.x..inner.right() {
+                    if let Some(cell) = buf.cell_mut((x, y)) {
+                        cell.set_style(sel_bg);
+                    }
+                }
+            }
+
+            let indent = "  ".repeat(*depth);
+            let icon = if property.properties.iter().any(|p| p.is_visible()) {
+                if state.properties_expanded.contains(path) {
+                    "▼"
+                } else {
+                    "▶"
+                }
+            } else {
+                " "
+            };
+            let marker = if property.differs_from_default() {
+                "•"
+            } else {
+                " "
+            };
+            let line = format!("{indent}{icon}{marker}{}", property_text(property));
+
+            let mut style = Style::default().fg(level_color(property));
+            if property.differs_from_default() {
+                style = style.add_modifier(Modifier::BOLD);
+            }
+            if is_selected {
+                style = style.add_modifier(Modifier::BOLD);
+            }
+            buf.set_string(inner.x, y, truncate_str(&line, inner.width as usize), style);
+        }
+
+        // Scroll indicator (right edge) if content overflows
+        if total > viewport_height {
+            let scroll_x = inner.right().saturating_sub(1);
+            let thumb_y = inner.y
+                + ((selected * viewport_height).checked_div(total).unwrap_or(0) as u16)
+                    .min(inner.height.saturating_sub(1));
+            if let Some(cell) = buf.cell_mut((scroll_x, thumb_y)) {
+                cell.set_symbol("█").set_fg(palette::BORDER_DIM);
+            }
+        }
+    }
+}
+
+/// `name: value` for named properties, the bare description otherwise.
+fn property_text(property: &DiagnosticsNode) -> String {
+    match property.name.as_deref() {
+        Some(name) if !name.is_empty() => format!("{name}: {}", property.description),
+        _ => property.description.clone(),
+    }
+}
+
+/// Text colour for a property row based on its diagnostic level.
+fn level_color(property: &DiagnosticsNode) -> Color {
+    match property.diagnostic_level() {
+        DiagnosticLevel::Error => palette::STATUS_RED,
+        DiagnosticLevel::Warning => palette::STATUS_YELLOW,
+        DiagnosticLevel::Hidden | DiagnosticLevel::Fine | DiagnosticLevel::Debug => {
+            palette::TEXT_MUTED
+        }
+        _ if property.differs_from_default() => palette::ACCENT,
+        _ => palette::TEXT_PRIMARY,
+    }
+}
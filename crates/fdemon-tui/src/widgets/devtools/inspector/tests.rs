@@ -1,5 +1,5 @@
 use super::*;
-use fdemon_app::state::{DevToolsError, InspectorState, VmConnectionStatus};
+use fdemon_app::state::{DevToolsError, InspectorDetailView, InspectorState, VmConnectionStatus};
 use fdemon_core::widget_tree::{CreationLocation, DiagnosticsNode};
 
 fn make_test_tree() -> DiagnosticsNode {
@@ -543,3 +543,86 @@ fn test_inspector_selected_index_preserved_after_small_render() {
         "selected_index should be preserved after rendering at small terminal size"
     );
 }
+
+#[test]
+fn test_inspector_search_bar_shows_query_and_match_count() {
+    let mut state = InspectorState::new();
+    state.root = Some(make_test_tree());
+    state.expanded.insert("widget-1".to_string());
+    state.search_input_active = true;
+    state.search_query = "scaf".to_string();
+    state.select_search_match(true, true);
+
+    let widget = WidgetInspector::new(&state, true, &VmConnectionStatus::Connected);
+    let mut buf = Buffer::empty(Rect::new(0, 0, 80, 24));
+    widget.render(Rect::new(0, 0, 80, 24), &mut buf);
+
+    let text = collect_buf_text(&buf, 80, 24);
+    assert!(text.contains("/scaf"), "search query should be shown");
+    assert!(text.contains("(1/1)"), "match position should be shown");
+    assert!(text.contains("Scaffold"), "match should be revealed");
+}
+
+#[test]
+fn test_inspector_title_marks_user_code_filter() {
+    let mut state = InspectorState::new();
+    state.root = Some(make_test_tree());
+    state.user_code_only = true;
+
+    let widget = WidgetInspector::new(&state, true, &VmConnectionStatus::Connected);
+    let mut buf = Buffer::empty(Rect::new(0, 0, 80, 24));
+    widget.render(Rect::new(0, 0, 80, 24), &mut buf);
+
+    assert!(collect_buf_text(&buf, 80, 24).contains("Widget Tree (user code)"));
+}
+
+#[test]
+fn test_inspector_properties_view_lists_details() {
+    let mut state = InspectorState::new();
+    state.root = Some(make_test_tree());
+    state.detail_view = InspectorDetailView::Properties;
+    state.properties = Some(DiagnosticsNode {
+        description: "Padding".to_string(),
+        properties: vec![
+            DiagnosticsNode {
+                name: Some("padding".to_string()),
+                description: "EdgeInsets.all(8.0)".to_string(),
+                ..Default::default()
+            },
+            DiagnosticsNode {
+                name: Some("textDirection".to_string()),
+                description: "ltr".to_string(),
+                level: Some("hidden".to_string()),
+                ..Default::default()
+            },
+        ],
+        ..Default::default()
+    });
+
+    let widget = WidgetInspector::new(&state, true, &VmConnectionStatus::Connected);
+    let mut buf = Buffer::empty(Rect::new(0, 0, 120, 24));
+    widget.render(Rect::new(0, 0, 120, 24), &mut buf);
+
+    let text = collect_buf_text(&buf, 120, 24);
+    assert!(text.contains("Properties"), "panel title should be shown");
+    assert!(text.contains("padding: EdgeInsets.all(8.0)"));
+    assert!(
+        !text.contains("textDirection"),
+        "hidden properties should be skipped"
+    );
+    assert!(!text.contains("Layout Explorer"));
+}
+
+#[test]
+fn test_inspector_properties_view_loading() {
+    let mut state = InspectorState::new();
+    state.root = Some(make_test_tree());
+    state.detail_view = InspectorDetailView::Properties;
+    state.properties_loading = true;
+
+    let widget = WidgetInspector::new(&state, true, &VmConnectionStatus::Connected);
+    let mut buf = Buffer::empty(Rect::new(0, 0, 120, 24));
+    widget.render(Rect::new(0, 0, 120, 24), &mut buf);
+
+    assert!(collect_buf_text(&buf, 120, 24).contains("Loading properties..."));
+}
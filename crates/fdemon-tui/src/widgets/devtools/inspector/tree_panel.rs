@@ -26,22 +26,40 @@ impl WidgetInspector<'_> {
         visible: &[(&DiagnosticsNode, usize)],
         selected: usize,
     ) {
+        let state = self.inspector_state;
+        let title = if state.user_code_only {
+            " Widget Tree (user code) "
+        } else {
+            " Widget Tree "
+        };
+
         // Block border for tree area
         let tree_block = Block::default()
             .borders(Borders::ALL)
             .border_style(Style::default().fg(palette::BORDER_DIM))
             .title(Span::styled(
-                " Widget Tree ",
+                title,
                 Style::default().fg(palette::ACCENT_DIM),
             ))
             .title_alignment(Alignment::Left);
-        let tree_inner = tree_block.inner(area);
+        let mut tree_inner = tree_block.inner(area);
         tree_block.render(area, buf);
 
         if tree_inner.height == 0 || tree_inner.width == 0 {
             return;
         }
 
+        // Search bar on the last inner row while searching or a query is kept.
+        if (state.search_input_active || !state.search_query.is_empty()) && tree_inner.height > 1 {
+            tree_inner.height -= 1;
+            let bar = Rect {
+                y: tree_inner.bottom(),
+                height: 1,
+                ..tree_inner
+            };
+            self.render_search_bar(bar, buf);
+        }
+
         let viewport_height = tree_inner.height as usize;
         let total = visible.len();
         let (start, end) = self.visible_viewport_range(viewport_height, total);
@@ -72,7 +90,12 @@ impl WidgetInspector<'_> {
                 }
             }
 
-            let style = self.node_style(is_selected, is_user_code);
+            let mut style = self.node_style(is_selected, is_user_code);
+            if state.is_search_match(node) {
+                style = style
+                    .fg(palette::SEARCH_HIGHLIGHT_BG)
+                    .add_modifier(Modifier::BOLD);
+            }
 
             // Truncate line to fit within available width
             let max_w = tree_inner.width as usize;
@@ -113,6 +136,36 @@ impl WidgetInspector<'_> {
         }
     }
 
+    /// Render `/query  (2/5)` or `/query  no matches` on one row.
+    fn render_search_bar(&self, area: Rect, buf: &mut Buffer) {
+        let state = self.inspector_state;
+        let cursor = if state.search_input_active { "█" } else { "" };
+        let query = format!("/{}{}", state.search_query, cursor);
+        let (total, position) = state.search_match_position();
+        let status = match (total, position) {
+            _ if state.search_query.is_empty() => String::new(),
+            (0, _) => "  no matches".to_string(),
+            (total, Some(pos)) => format!("  ({pos}/{total})"),
+            (total, None) => format!("  ({total} matches)"),
+        };
+        let max_w = area.width as usize;
+        let query_text = truncate_str(&query, max_w);
+        buf.set_string(
+            area.x,
+            area.y,
+            query_text,
+            Style::default().fg(palette::STATUS_YELLOW),
+        );
+        let used = query_text.chars().count() as u16;
+        let remaining = area.width.saturating_sub(used) as usize;
+        buf.set_string(
+            area.x + used,
+            area.y,
+            truncate_str(&status, remaining),
+            Style::default().fg(palette::TEXT_MUTED),
+        );
+    }
+
     // ── Node styling ──────────────────────────────────────────────────────────
 
     fn node_style(&self, is_selected: bool, is_user_code: bool) -> Style {
@@ -4,16 +4,26 @@
 //! The top-level [`DevToolsView`] composite widget renders a sub-tab bar and
 //! dispatches to the active panel below it.
 
+pub mod console;
+pub mod dumps;
+pub mod extensions;
 pub mod inspector;
 pub mod network;
 pub mod performance;
+pub mod profiler;
 
+pub use console::VmConsole;
+pub use dumps::DumpViewer;
+pub use extensions::ExtensionPanel;
 pub use inspector::WidgetInspector;
 pub use network::NetworkMonitor;
 pub use performance::PerformancePanel;
+pub use profiler::CpuProfiler;
 
 use fdemon_app::session::{PerformanceState, SessionHandle};
-use fdemon_app::state::{DevToolsPanel, DevToolsViewState, VmConnectionStatus};
+use fdemon_app::state::{
+    DevToolsPanel, DevToolsViewState, InspectorDetailView, VmConnectionStatus,
+};
 use ratatui::{
     buffer::Buffer,
     layout::{Constraint, Layout, Rect},
@@ -32,6 +42,63 @@ const DEVTOOLS_MIN_HEIGHT: u16 = 3;
 /// Minimum terminal width required to render any DevTools panel.
 const DEVTOOLS_MIN_WIDTH: u16 = 20;
 
+// ── Sub-tab labels ────────────────────────────────────────────────────────────
+
+/// Panel tabs with their full, short and key-only labels.
+const TABS: [(DevToolsPanel, [&str; 3]); 7] = [
+    (
+        DevToolsPanel::Inspector,
+        ["[i] Inspector", "[i] Insp", "[i]"],
+    ),
+    (
+        DevToolsPanel::Performance,
+        ["[p] Performance", "[p] Perf", "[p]"],
+    ),
+    (DevToolsPanel::Network, ["[n] Network", "[n] Net", "[n]"]),
+    (DevToolsPanel::Dumps, ["[d] Dumps", "[d] Dumps", "[d]"]),
+    (DevToolsPanel::Profiler, ["[c] CPU", "[c] CPU", "[c]"]),
+    (DevToolsPanel::Console, ["[:] Console", "[:] Cons", "[:]"]),
+    (
+        DevToolsPanel::Extensions,
+        ["[x] Extensions", "[x] Ext", "[x]"],
+    ),
+];
+
+/// Label variants (index into the `TABS` labels) for the active tab and the
+/// others, and the gap between tabs, from the widest layout to the most
+/// compact.
+const TAB_LAYOUTS: [(usize, usize, u16); 4] = [(0, 0, 1), (0, 1, 0), (0, 2, 0), (1, 2, 0)];
+
+/// Tab labels and the gap between tabs for the first of [`TAB_LAYOUTS`]
+/// that fits in `width` columns. The most compact layout is used if none
+/// fits.
+fn tab_labels(active: DevToolsPanel, width: u16) -> (Vec<(DevToolsPanel, &'static str)>, u16) {
+    let layout = |(active_variant, other_variant, gap): (usize, usize, u16)| {
+        let labels: Vec<_> = TABS
+            .iter()
+            .map(|(panel, variants)| {
+                let variant = if *panel == active {
+                    active_variant
+                } else {
+                    other_variant
+                };
+                (*panel, variants[variant])
+            })
+            .collect();
+        (labels, gap)
+    };
+    let fits = |(labels, gap): &(Vec<(DevToolsPanel, &str)>, u16)| {
+        let tabs: usize = labels.iter().map(|(_, label)| label.len() + 2).sum();
+        tabs + *gap as usize * (labels.len() - 1) <= width as usize
+    };
+
+    TAB_LAYOUTS
+        .iter()
+        .map(|l| layout(*l))
+        .find(fits)
+        .unwrap_or_else(|| layout(TAB_LAYOUTS[TAB_LAYOUTS.len() - 1]))
+}
+
 // ── DevToolsView ─────────────────────────────────────────────────────────────
 
 /// Top-level DevTools mode widget.
@@ -146,6 +213,54 @@ impl Widget for DevToolsView<'_> {
                     NetworkMonitor::new(network_state, vm_connected, &self.state.connection_status);
                 widget.render(chunks[1], buf);
             }
+            DevToolsPanel::Dumps => {
+                let vm_connected = self
+                    .session
+                    .map(|s| s.session.vm_connected)
+                    .unwrap_or(false);
+                let widget = DumpViewer::new(
+                    &self.state.dumps,
+                    vm_connected,
+                    &self.state.connection_status,
+                );
+                widget.render(chunks[1], buf);
+            }
+            DevToolsPanel::Profiler => {
+                let vm_connected = self
+                    .session
+                    .map(|s| s.session.vm_connected)
+                    .unwrap_or(false);
+                let widget = CpuProfiler::new(
+                    &self.state.profiler,
+                    vm_connected,
+                    &self.state.connection_status,
+                );
+                widget.render(chunks[1], buf);
+            }
+            DevToolsPanel::Console => {
+                let vm_connected = self
+                    .session
+                    .map(|s| s.session.vm_connected)
+                    .unwrap_or(false);
+                let widget = VmConsole::new(
+                    &self.state.console,
+                    vm_connected,
+                    &self.state.connection_status,
+                );
+                widget.render(chunks[1], buf);
+            }
+            DevToolsPanel::Extensions => {
+                let vm_connected = self
+                    .session
+                    .map(|s| s.session.vm_connected)
+                    .unwrap_or(false);
+                let widget = ExtensionPanel::new(
+                    &self.state.extensions,
+                    vm_connected,
+                    &self.state.connection_status,
+                );
+                widget.render(chunks[1], buf);
+            }
         }
 
         // Render footer hints at the bottom of the panel area
@@ -158,10 +273,20 @@ impl DevToolsView<'_> {
 
     /// Render the DevTools sub-tab bar with panel tabs and overlay status indicators.
     fn render_tab_bar(&self, area: Rect, buf: &mut Buffer) {
-        // Outer block with border
-        let block = ratatui::widgets::Block::bordered()
+        // Outer block with border; the picked isolate is shown on the right
+        // once the app runs more than one.
+        let mut block = ratatui::widgets::Block::bordered()
             .title(" DevTools ")
             .border_style(Style::default().fg(Color::Cyan));
+        if let Some(label) = self.isolate_label() {
+            block = block.title(
+                Line::from(Span::styled(
+                    format!(" {label} "),
+                    Style::default().fg(palette::ACCENT_DIM),
+                ))
+                .right_aligned(),
+            );
+        }
 
         let inner = block.inner(area);
         block.render(area, buf);
@@ -170,15 +295,72 @@ impl DevToolsView<'_> {
             return;
         }
 
-        let tabs = [
-            (DevToolsPanel::Inspector, "[i] Inspector"),
-            (DevToolsPanel::Performance, "[p] Performance"),
-            (DevToolsPanel::Network, "[n] Network"),
-        ];
+        // Right-aligned overlay status indicators
+        let mut indicators: Vec<&str> = Vec::new();
+        if self.state.overlay_repaint_rainbow {
+            indicators.push("Rainbow");
+        }
+        if self.state.overlay_debug_paint {
+            indicators.push("DebugPaint");
+        }
+        if self.state.overlay_performance {
+            indicators.push("PerfOverlay");
+        }
+        if self.state.overlay_slow_animations {
+            indicators.push("SlowAnim");
+        }
+        if self.state.overlay_banner_hidden {
+            indicators.push("NoBanner");
+        }
+        if self.state.overlay_invert_oversized_images {
+            indicators.push("InvertImages");
+        }
+        if self.state.overlay_widget_builds_off {
+            indicators.push("NoBuilds");
+        }
+        if self.state.overlay_paint_baselines {
+            indicators.push("Baselines");
+        }
+        if let Some(platform) = &self.state.platform_override {
+            indicators.push(platform);
+        }
+        match self.state.brightness_override.as_deref() {
+            Some("Brightness.dark") => indicators.push("Dark"),
+            Some("Brightness.light") => indicators.push("Light"),
+            _ => {}
+        }
+        if self.state.inspector.select_mode {
+            indicators.push("Select");
+        }
+        let overlay_text = indicators.join(" | ");
+
+        // Connection indicator (only shown for degraded states)
+        let mut conn_label_owned = String::new();
+        let conn_indicator: Option<(&str, Style)> =
+            self.connection_indicator_text(&mut conn_label_owned);
+
+        // Right-side width: connection indicator first, then overlay
+        // indicators (connection state is more important for the user to
+        // see), plus a trailing column.
+        let conn_len = conn_indicator.map_or(0, |(label, _)| label.chars().count() as u16);
+        let overlay_len = overlay_text.chars().count() as u16;
+        let right_len = match (conn_len, overlay_len) {
+            (0, 0) => 0,
+            (0, len) | (len, 0) => len + 1,
+            (conn, overlay) => conn + 3 + overlay + 1, // " | " separator
+        };
 
+        // Tabs get what the indicators leave, less one column between them.
         let mut x = inner.x + 1;
-        for (panel, label) in &tabs {
-            let is_active = self.state.active_panel == *panel;
+        let tabs_right = if right_len == 0 {
+            inner.right()
+        } else {
+            inner.right().saturating_sub(right_len + 1).max(x)
+        };
+        let (labels, gap) = tab_labels(self.state.active_panel, tabs_right - x);
+        let mut tabs_end = x;
+        for (panel, label) in labels {
+            let is_active = self.state.active_panel == panel;
             let padded = format!(" {label} ");
             let needed_width = padded.len() as u16;
 
@@ -196,68 +378,58 @@ impl DevToolsView<'_> {
             };
 
             buf.set_string(x, inner.y, &padded, style);
-            x += needed_width + 1;
+            tabs_end = x + needed_width;
+            x = tabs_end + gap;
         }
 
-        // Right-aligned overlay status indicators
-        let mut indicators: Vec<&str> = Vec::new();
-        if self.state.overlay_repaint_rainbow {
-            indicators.push("Rainbow");
+        // Indicators that would overlap the tabs are left out rather than
+        // painted over them.
+        let right_x = inner.right().saturating_sub(right_len);
+        if right_len == 0 || right_x <= tabs_end {
+            return;
         }
-        if self.state.overlay_debug_paint {
-            indicators.push("DebugPaint");
+        let mut right_x = right_x;
+        if let Some((label, style)) = conn_indicator {
+            buf.set_string(right_x, inner.y, label, style);
+            right_x += conn_len;
+            if overlay_len > 0 {
+                buf.set_string(
+                    right_x,
+                    inner.y,
+                    " | ",
+                    Style::default().fg(palette::TEXT_MUTED),
+                );
+                right_x += 3;
+            }
         }
-        if self.state.overlay_performance {
-            indicators.push("PerfOverlay");
+        if overlay_len > 0 {
+            buf.set_string(
+                right_x,
+                inner.y,
+                &overlay_text,
+                Style::default().fg(palette::STATUS_YELLOW),
+            );
         }
+    }
 
-        // Connection indicator (only shown for degraded states)
-        let mut conn_label_owned = String::new();
-        let conn_indicator: Option<(&str, Style)> =
-            self.connection_indicator_text(&mut conn_label_owned);
-
-        // Build right-side text: connection indicator first, then overlay indicators
-        // (connection state is more important for the user to see)
-        let right_parts_count = if conn_indicator.is_some() { 1 } else { 0 }
-            + if indicators.is_empty() { 0 } else { 1 };
-
-        if right_parts_count > 0 {
-            // Determine total right-side width to position correctly
-            let overlay_text = if indicators.is_empty() {
-                String::new()
-            } else {
-                indicators.join(" | ")
-            };
-
-            // Render connection indicator if present
-            if let Some((label, style)) = &conn_indicator {
-                let label_len = label.chars().count() as u16;
-                let overlay_extra = if overlay_text.is_empty() {
-                    0
-                } else {
-                    overlay_text.len() as u16 + 3 // " | " separator
-                };
-                let total_len = label_len + overlay_extra;
-                let right_x = inner.x + inner.width.saturating_sub(total_len + 1);
-                if right_x < inner.right() {
-                    buf.set_string(right_x, inner.y, label, *style);
-                }
-            }
-
-            // Render overlay indicators
-            if !overlay_text.is_empty() {
-                let text_len = overlay_text.len() as u16;
-                let right_x = inner.x + inner.width.saturating_sub(text_len + 1);
-                if right_x < inner.right() {
-                    buf.set_string(
-                        right_x,
-                        inner.y,
-                        &overlay_text,
-                        Style::default().fg(palette::STATUS_YELLOW),
-                    );
-                }
-            }
+    /// `Isolate: worker (2/3)` for the isolate that memory and CPU data are
+    /// read from, or `None` while the main isolate is the only one.
+    fn isolate_label(&self) -> Option<String> {
+        let session = &self.session?.session;
+        let isolates = session.debug.devtools_isolates();
+        if isolates.len() < 2 {
+            return None;
         }
+        let index = isolates
+            .iter()
+            .position(|(id, _)| *id == session.performance.isolate_id.as_deref())
+            .unwrap_or(0);
+        Some(format!(
+            "Isolate: {} ({}/{})",
+            isolates[index].1,
+            index + 1,
+            isolates.len()
+        ))
     }
 
     /// Return the connection indicator label and style for degraded states,
@@ -304,26 +476,97 @@ impl DevToolsView<'_> {
 
         let hints = match self.state.active_panel {
             DevToolsPanel::Inspector => {
-                "[Esc] Logs  [↑↓] Navigate  [→] Expand  [←] Collapse  [r] Refresh  [b] Browser"
+                let inspector = &self.state.inspector;
+                if inspector.search_input_active {
+                    "[Esc] Cancel  [Enter] Done  [↑↓] Prev/next match"
+                } else if inspector.properties_focused {
+                    "[Tab] Tree  [↑↓] Navigate  [→/←] Expand/Collapse  [v] Layout  [Esc] Back"
+                } else if inspector.select_mode {
+                    "[Esc] Logs  Tap a widget on the device to select it  [t] Stop selecting"
+                } else if !inspector.search_query.is_empty() {
+                    "[Esc] Logs  [↑↓] Navigate  [/] Search  [[/]] Prev/next match  [u] User code"
+                } else if inspector.detail_view == InspectorDetailView::Properties {
+                    "[Esc] Logs  [↑↓] Navigate  [/] Search  [b] Browser  [u] User code  [t] Select  [v] Layout  [Tab] Props  [r] Refresh"
+                } else {
+                    "[Esc] Logs  [↑↓] Navigate  [→/←] Expand/Collapse  [/] Search  [b] Browser  [u] User code  [t] Select  [v] Props  [r] Refresh"
+                }
             }
             DevToolsPanel::Performance => {
-                "[Esc] Logs  [i] Inspector  [b] Browser  [←/→] Frames  [Ctrl+p] PerfOverlay"
+                let perf = self.session.map(|s| &s.session.performance);
+                let browsing = perf.is_some_and(|p| p.instance_browser.is_some());
+                let tracking = perf.is_some_and(|p| p.rebuild_tracking);
+                let frame_selected = perf.is_some_and(|p| p.selected_frame.is_some());
+                let diffing = perf.is_some_and(|p| p.heap_diff.is_some());
+                if browsing {
+                    "[Esc] Close  [↑↓] Select  [Enter] Fields & retaining path  [s] Sort"
+                } else if tracking {
+                    "[Esc] Logs  [↑↓] Select  [Enter] Source  [s] Sort  [Ctrl+x] Reset  [w] Stop"
+                } else if frame_selected {
+                    "[Esc] Deselect  [←/→] Frames  [e] Export  [B/C] Baseline  [Ctrl+p] Overlay  [b] Browser"
+                } else if diffing {
+                    "[Esc] Logs  [H] Re-diff  [Ctrl+x] Clear diff  [s] Sort  [↑↓/Enter] Instances"
+                } else {
+                    "[Esc] Logs  [←/→] Frames  [↑↓/Enter] Instances  [H] Alloc diff  [w] Rebuilds  [I] Isolate  [b] Browser  [i] Inspector"
+                }
             }
             DevToolsPanel::Network => {
                 let has_selection = self
                     .session
                     .is_some_and(|s| s.session.network.selected_index.is_some());
-                if has_selection {
-                    "[Esc] Deselect  [g/h/q/s/t] Detail tabs  [Space] Toggle rec  [b] Browser"
+                let in_waterfall = self.session.is_some_and(|s| {
+                    s.session.network.view_mode == fdemon_app::session::NetworkViewMode::Waterfall
+                });
+                if in_waterfall {
+                    "[Esc] Logs  [↑↓] Navigate  [+/-] Zoom  [←/→] Pan  [0] Fit  [w] Table"
+                } else if has_selection {
+                    "[Esc] Deselect  [g/h/q/s/t] Detail tabs  [Space] Toggle rec  [w] Waterfall  [b] Browser"
                 } else {
-                    "[Esc] Logs  [↑↓] Navigate  [Enter] Detail  [Space] Toggle rec  [b] Browser"
+                    "[Esc] Logs  [↑↓] Navigate  [Enter] Detail  [Space] Toggle rec  [w] Waterfall  [b] Browser"
                 }
             }
+            DevToolsPanel::Dumps => {
+                let dumps = &self.state.dumps;
+                if dumps.search_input_active {
+                    "[Esc] Cancel  [Enter] Done  [↑↓] Prev/next match"
+                } else if !dumps.search_query().is_empty() {
+                    "[Esc] Logs  [↑↓] Navigate  [/] Search  [[/]] Prev/next match  [s] Save"
+                } else {
+                    "[Esc] Logs  [1-5] Dump  [↑↓] Navigate  [→/←] Expand/Collapse  [/] Search  [r] Refresh  [s] Save"
+                }
+            }
+            DevToolsPanel::Profiler => {
+                if self.state.profiler.recording.is_some() {
+                    "[Space] Stop recording  [Esc] Logs"
+                } else {
+                    "[Esc] Logs  [Space] Record  [1-3] View  [f] Project only  [↑↓] Navigate  [→/←] Expand/Collapse  [I] Isolate"
+                }
+            }
+            DevToolsPanel::Console => {
+                if self.state.console.input_active {
+                    "[Enter] Run  [Tab] Complete  [↑↓] History  [PgUp/PgDn] Scroll  [Esc] Done"
+                } else {
+                    "[Esc] Logs  [Enter] Type command  [↑↓/PgUp/PgDn] Scroll"
+                }
+            }
+            DevToolsPanel::Extensions => {
+                "[Esc] Logs  [↑↓] Navigate  [Enter] Call  [r] Refresh  [I] Isolate  [key] Button"
+            }
         };
 
-        // Truncate hints to fit available width
+        // Drop whole hints from the end to fit the available width, rather
+        // than cutting one in half. Hints are ordered most important first.
         let max_width = area.width.saturating_sub(2) as usize;
-        let display_hints: String = hints.chars().take(max_width).collect();
+        let mut display_hints = String::new();
+        for hint in hints.split("  ") {
+            let sep = if display_hints.is_empty() { 0 } else { 2 };
+            if display_hints.chars().count() + sep + hint.chars().count() > max_width {
+                break;
+            }
+            if sep > 0 {
+                display_hints.push_str("  ");
+            }
+            display_hints.push_str(hint);
+        }
 
         buf.set_string(
             area.x + 1,
@@ -414,6 +657,103 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_tab_bar_shows_console_and_extensions_when_wide_enough() {
+        let state = DevToolsViewState::default();
+        let widget = DevToolsView::new(&state, None, IconSet::default());
+        let mut buf = Buffer::empty(Rect::new(0, 0, 120, 3));
+        widget.render_tab_bar(Rect::new(0, 0, 120, 3), &mut buf);
+
+        let text = collect_buf_text(&buf, 120, 3);
+        assert!(text.contains("[:] Console"), "Expected console tab");
+        assert!(text.contains("[x] Extensions"), "Expected extensions tab");
+    }
+
+    #[test]
+    fn test_tab_bar_shows_active_console_at_80_columns() {
+        let state = DevToolsViewState {
+            active_panel: DevToolsPanel::Console,
+            ..Default::default()
+        };
+        let widget = DevToolsView::new(&state, None, IconSet::default());
+        let mut buf = Buffer::empty(Rect::new(0, 0, 80, 3));
+        widget.render_tab_bar(Rect::new(0, 0, 80, 3), &mut buf);
+
+        let text = collect_buf_text(&buf, 80, 3);
+        assert!(text.contains("[:] Console"), "got: {text:?}");
+        assert!(text.contains("[x] Ext"), "got: {text:?}");
+    }
+
+    #[test]
+    fn test_tab_bar_keeps_active_extensions_and_indicators_at_80_columns() {
+        let state = DevToolsViewState {
+            active_panel: DevToolsPanel::Extensions,
+            overlay_slow_animations: true,
+            overlay_banner_hidden: true,
+            platform_override: Some("iOS".to_string()),
+            ..Default::default()
+        };
+        let widget = DevToolsView::new(&state, None, IconSet::default());
+        let mut buf = Buffer::empty(Rect::new(0, 0, 80, 3));
+        widget.render_tab_bar(Rect::new(0, 0, 80, 3), &mut buf);
+
+        let text = collect_buf_text(&buf, 80, 3);
+        assert!(text.contains(" [x] Extensions "), "got: {text:?}");
+        for key in ["[i]", "[p]", "[n]", "[d]", "[c]", "[:]"] {
+            assert!(text.contains(key), "missing {key}; got: {text:?}");
+        }
+        assert!(text.contains("SlowAnim | NoBanner | iOS"), "got: {text:?}");
+    }
+
+    #[test]
+    fn test_tab_bar_drops_indicators_that_would_overlap_tabs() {
+        let state = DevToolsViewState {
+            active_panel: DevToolsPanel::Performance,
+            overlay_repaint_rainbow: true,
+            overlay_debug_paint: true,
+            overlay_performance: true,
+            overlay_slow_animations: true,
+            overlay_banner_hidden: true,
+            overlay_invert_oversized_images: true,
+            overlay_widget_builds_off: true,
+            ..Default::default()
+        };
+        let widget = DevToolsView::new(&state, None, IconSet::default());
+        let mut buf = Buffer::empty(Rect::new(0, 0, 80, 3));
+        widget.render_tab_bar(Rect::new(0, 0, 80, 3), &mut buf);
+
+        let text = collect_buf_text(&buf, 80, 3);
+        assert!(text.contains(" [p] Perf"), "got: {text:?}");
+        assert!(text.contains("[x]"), "got: {text:?}");
+        assert!(!text.contains("Rainbow"), "got: {text:?}");
+    }
+
+    #[test]
+    fn test_inspector_footer_shows_browser_hint() {
+        let state = DevToolsViewState::default();
+        let widget = DevToolsView::new(&state, None, IconSet::default());
+        let mut buf = Buffer::empty(Rect::new(0, 0, 80, 2));
+        widget.render_footer(Rect::new(0, 0, 80, 2), &mut buf);
+
+        let text = collect_buf_text(&buf, 80, 2);
+        assert!(text.contains("[b] Browser"), "got: {text:?}");
+    }
+
+    #[test]
+    fn test_network_footer_shows_browser_hint() {
+        let state = DevToolsViewState {
+            active_panel: DevToolsPanel::Network,
+            ..Default::default()
+        };
+        let widget = DevToolsView::new(&state, None, IconSet::default());
+        let mut buf = Buffer::empty(Rect::new(0, 0, 120, 2));
+        widget.render_footer(Rect::new(0, 0, 120, 2), &mut buf);
+
+        let text = collect_buf_text(&buf, 120, 2);
+        assert!(text.contains("[w] Waterfall"), "got: {text:?}");
+        assert!(text.contains("[b] Browser"), "got: {text:?}");
+    }
+
     #[test]
     fn test_tab_bar_shows_all_panels() {
         let state = DevToolsViewState::default();
@@ -421,9 +761,16 @@ mod tests {
         let mut buf = Buffer::empty(Rect::new(0, 0, 80, 3));
         widget.render_tab_bar(Rect::new(0, 0, 80, 3), &mut buf);
 
+        // 80 columns are too narrow for all full labels: the active tab
+        // keeps its label and the others are shortened.
         let text = collect_buf_text(&buf, 80, 3);
-        assert!(text.contains("Inspector"), "Expected Inspector tab");
-        assert!(text.contains("Performance"), "Expected Performance tab");
+        assert!(text.contains("[i] Inspector"), "Expected Inspector tab");
+        assert!(text.contains("[p] Perf "), "Expected Performance tab");
+        assert!(text.contains("[n] Net "), "Expected Network tab");
+        assert!(text.contains("[d] Dumps"), "Expected Dumps tab");
+        assert!(text.contains("[c] CPU"), "Expected CPU profiler tab");
+        assert!(text.contains("[:] Cons "), "Expected Console tab");
+        assert!(text.contains("[x] Ext "), "Expected Extensions tab");
         assert!(
             !text.contains("Layout"),
             "Layout tab should not appear; got: {text:?}"
@@ -453,6 +800,43 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_debug_toggle_indicators_shown_when_active() {
+        let state = DevToolsViewState {
+            overlay_slow_animations: true,
+            overlay_banner_hidden: true,
+            platform_override: Some("iOS".to_string()),
+            brightness_override: Some("Brightness.dark".to_string()),
+            ..Default::default()
+        };
+
+        let widget = DevToolsView::new(&state, None, IconSet::default());
+        let mut buf = Buffer::empty(Rect::new(0, 0, 120, 3));
+        widget.render_tab_bar(Rect::new(0, 0, 120, 3), &mut buf);
+
+        let text = collect_buf_text(&buf, 120, 3);
+        assert!(
+            text.contains("SlowAnim | NoBanner | iOS | Dark"),
+            "Expected debug toggle indicators, got: {text:?}"
+        );
+    }
+
+    #[test]
+    fn test_select_mode_indicator_shown_when_active() {
+        let mut state = DevToolsViewState::default();
+        state.inspector.select_mode = true;
+
+        let widget = DevToolsView::new(&state, None, IconSet::default());
+        let mut buf = Buffer::empty(Rect::new(0, 0, 80, 3));
+        widget.render_tab_bar(Rect::new(0, 0, 80, 3), &mut buf);
+
+        let text = collect_buf_text(&buf, 80, 3);
+        assert!(
+            text.contains("Select"),
+            "Expected 'Select' indicator, got: {text:?}"
+        );
+    }
+
     #[test]
     fn test_overlay_perf_overlay_shown_when_active() {
         let state = DevToolsViewState {
@@ -646,6 +1030,70 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_tab_bar_shows_picked_isolate() {
+        use fdemon_app::session::Session;
+        use fdemon_daemon::vm_service::DebugIsolateRef;
+
+        let state = DevToolsViewState::default();
+        let mut handle = SessionHandle::new(Session::new(
+            "d1".to_string(),
+            "Pixel 8".to_string(),
+            "android".to_string(),
+            false,
+        ));
+        let render = |handle: &SessionHandle| {
+            let widget = DevToolsView::new(&state, Some(handle), IconSet::default());
+            let mut buf = Buffer::empty(Rect::new(0, 0, 80, 3));
+            widget.render_tab_bar(Rect::new(0, 0, 80, 3), &mut buf);
+            collect_buf_text(&buf, 80, 3)
+        };
+        assert!(!render(&handle).contains("Isolate:"));
+
+        handle.session.debug.add_isolate(DebugIsolateRef {
+            id: "isolates/2".to_string(),
+            name: Some("worker".to_string()),
+        });
+        assert!(render(&handle).contains("Isolate: main (1/2)"));
+
+        handle.session.performance.isolate_id = Some("isolates/2".to_string());
+        assert!(render(&handle).contains("Isolate: worker (2/2)"));
+    }
+
+    #[test]
+    fn test_performance_footer_fits_80_columns_per_context() {
+        use fdemon_app::session::Session;
+
+        let state = DevToolsViewState {
+            active_panel: DevToolsPanel::Performance,
+            ..Default::default()
+        };
+        let mut handle = SessionHandle::new(Session::new(
+            "d1".to_string(),
+            "Pixel 8".to_string(),
+            "android".to_string(),
+            false,
+        ));
+        let render = |handle: &SessionHandle| {
+            let widget = DevToolsView::new(&state, Some(handle), IconSet::default());
+            let mut buf = Buffer::empty(Rect::new(0, 0, 80, 2));
+            widget.render_footer(Rect::new(0, 0, 80, 2), &mut buf);
+            collect_buf_text(&buf, 80, 2)
+        };
+
+        let text = render(&handle);
+        assert!(text.contains("[H] Alloc diff"), "got: {text:?}");
+        assert!(text.contains("[w] Rebuilds"), "got: {text:?}");
+        // Hints that do not fit are dropped whole, not cut off.
+        assert!(!text.contains("[I] Iso"), "got: {text:?}");
+
+        handle.session.performance.selected_frame = Some(0);
+        let text = render(&handle);
+        assert!(text.contains("[Esc] Deselect"), "got: {text:?}");
+        assert!(text.contains("[B/C] Baseline"), "got: {text:?}");
+        assert!(text.contains("[Ctrl+p] Overlay"), "got: {text:?}");
+    }
+
     // ── Minimum size guard tests ───────────────────────────────────────────────
 
     #[test]
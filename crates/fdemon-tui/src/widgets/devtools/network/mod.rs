@@ -8,16 +8,20 @@
 //! - **Wide** (>= [`WIDE_THRESHOLD`]): horizontal split — table left (55%), details right (45%)
 //! - **Narrow** (< [`WIDE_THRESHOLD`]) **with selection**: vertical split — table top (50%), details bottom (50%)
 //! - **No selection**: full-width table (both wide and narrow)
+//!
+//! The request list is either the column table or the waterfall timeline,
+//! depending on [`NetworkViewMode`].
 
 pub mod request_details;
 pub mod request_table;
+pub mod waterfall;
 
 #[cfg(test)]
 mod tests;
 
-use fdemon_app::session::NetworkState;
+use fdemon_app::session::{NetworkState, NetworkViewMode};
 use fdemon_app::state::VmConnectionStatus;
-use fdemon_core::network::HttpProfileEntry;
+use fdemon_core::network::{HttpProfileEntry, TimingPhase};
 use ratatui::{
     buffer::Buffer,
     layout::{Constraint, Direction, Layout, Rect},
@@ -31,6 +35,7 @@ use unicode_width::UnicodeWidthStr;
 use crate::theme::palette;
 use request_details::RequestDetails;
 use request_table::RequestTable;
+use waterfall::RequestWaterfall;
 
 /// Terminal width threshold for horizontal vs vertical split.
 const WIDE_THRESHOLD: u16 = 100;
@@ -58,6 +63,20 @@ pub(super) fn http_method_color(method: &str) -> Color {
     }
 }
 
+/// Return a foreground [`Color`] for a request lifecycle phase.
+///
+/// Shared by the waterfall timeline and the Timing detail tab so a phase has
+/// the same colour in both views.
+pub(super) fn phase_color(phase: TimingPhase) -> Color {
+    match phase {
+        TimingPhase::Dns => Color::Magenta,
+        TimingPhase::Connect => Color::Cyan,
+        TimingPhase::Tls => Color::Blue,
+        TimingPhase::Wait => Color::Yellow,
+        TimingPhase::Receive => Color::Green,
+    }
+}
+
 // ── NetworkMonitor ────────────────────────────────────────────────────────────
 
 /// Top-level Network Monitor widget for the DevTools mode.
@@ -97,14 +116,16 @@ impl Widget for NetworkMonitor<'_> {
             .style(Style::default().bg(palette::DEEPEST_BG))
             .render(area, buf);
 
-        // Gate on VM connection
-        if !self.vm_connected {
+        // Gate on VM connection. A local proxy records traffic independently
+        // of the VM, so its requests stay visible either way.
+        let proxy_active = self.network_state.proxy_active();
+        if !self.vm_connected && !proxy_active {
             self.render_disconnected(area, buf);
             return;
         }
 
         // Check if extensions are unavailable
-        if self.network_state.extensions_available == Some(false) {
+        if self.network_state.extensions_available == Some(false) && !proxy_active {
             self.render_unavailable(area, buf);
             return;
         }
@@ -173,15 +194,8 @@ impl NetworkMonitor<'_> {
             .constraints([Constraint::Percentage(55), Constraint::Percentage(45)])
             .split(area);
 
-        // Left: Request table
-        let table = RequestTable::new(
-            filtered,
-            self.network_state.selected_index,
-            self.network_state.scroll_offset,
-            self.network_state.recording,
-            &self.network_state.filter,
-        );
-        table.render(chunks[0], buf);
+        // Left: Request table (or waterfall)
+        self.render_table_only(chunks[0], buf, filtered);
 
         // Right: Request details (with border)
         let detail_block = Block::default()
@@ -221,12 +235,21 @@ impl NetworkMonitor<'_> {
     }
 
     fn render_table_only(&self, area: Rect, buf: &mut Buffer, filtered: &[&HttpProfileEntry]) {
+        if self.network_state.view_mode == NetworkViewMode::Waterfall {
+            RequestWaterfall::new(filtered, self.network_state).render(area, buf);
+            return;
+        }
         let table = RequestTable::new(
             filtered,
             self.network_state.selected_index,
             self.network_state.scroll_offset,
             self.network_state.recording,
             &self.network_state.filter,
+        )
+        .with_proxy_address(self.network_state.proxy_address.as_deref())
+        .with_generations(
+            &self.network_state.entry_generations,
+            self.network_state.generation,
         );
         table.render(area, buf);
     }
@@ -5,7 +5,7 @@
 
 use fdemon_app::session::NetworkDetailTab;
 use fdemon_core::network::{
-    format_bytes, format_duration_ms, HttpProfileEntry, HttpProfileEntryDetail,
+    format_bytes, format_duration_ms, HttpProfileEntry, HttpProfileEntryDetail, TimingPhase,
 };
 
 /// Width of the label column in the General tab layout (characters).
@@ -445,13 +445,19 @@ impl RequestDetails<'_> {
         y += 2;
 
         // Timing phases as horizontal bars
-        let phases: Vec<(&str, Option<f64>, Color)> = vec![
-            ("Connect", timing.connection_ms, Color::Cyan),
-            ("Wait", timing.waiting_ms, Color::Yellow),
-            ("Receive", timing.receiving_ms, Color::Green),
-        ];
-
-        for (label, duration_opt, color) in &phases {
+        // DNS and TLS rows are only shown when the timeline reports them.
+        let phases: Vec<(TimingPhase, Option<f64>)> = [
+            (TimingPhase::Dns, timing.dns_ms),
+            (TimingPhase::Connect, timing.connection_ms),
+            (TimingPhase::Tls, timing.tls_ms),
+            (TimingPhase::Wait, timing.waiting_ms),
+            (TimingPhase::Receive, timing.receiving_ms),
+        ]
+        .into_iter()
+        .filter(|(phase, ms)| ms.is_some() || !matches!(phase, TimingPhase::Dns | TimingPhase::Tls))
+        .collect();
+
+        for (phase, duration_opt) in &phases {
             if y >= area.bottom() {
                 break;
             }
@@ -459,12 +465,17 @@ impl RequestDetails<'_> {
             let bar_len = ((duration / total) * bar_width as f64) as usize;
 
             // Label
-            buf.set_string(area.x + 1, y, format!("{:>10}", label), label_style);
+            buf.set_string(area.x + 1, y, format!("{:>10}", phase.label()), label_style);
 
             // Bar
             let min_bar = if duration > 0.0 { 1 } else { 0 };
             let bar: String = "\u{2588}".repeat(bar_len.max(min_bar));
-            buf.set_string(area.x + 12, y, &bar, Style::default().fg(*color));
+            buf.set_string(
+                area.x + 12,
+                y,
+                &bar,
+                Style::default().fg(super::phase_color(*phase)),
+            );
 
             // Duration value
             let val_x = area.x + 12 + bar_len as u16 + 1;
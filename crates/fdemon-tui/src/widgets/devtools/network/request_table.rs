@@ -4,6 +4,8 @@
 //! content type, duration, and response size columns. Supports selection
 //! highlighting, pending request indicators, and filter highlighting.
 
+use std::collections::HashMap;
+
 use fdemon_core::network::{format_duration_ms, HttpProfileEntry};
 use ratatui::buffer::Buffer;
 use ratatui::layout::Rect;
@@ -47,6 +49,12 @@ pub struct RequestTable<'a> {
     recording: bool,
     /// Current filter text (empty = no filter).
     filter: &'a str,
+    /// Address of the session's local HTTP proxy, if one is running.
+    proxy_address: Option<&'a str>,
+    /// Restart generation of each entry, keyed by entry ID.
+    entry_generations: Option<&'a HashMap<String, u32>>,
+    /// Current restart generation; labels are only shown once it is non-zero.
+    current_generation: u32,
 }
 
 impl<'a> RequestTable<'a> {
@@ -64,7 +72,42 @@ impl<'a> RequestTable<'a> {
             scroll_offset,
             recording,
             filter,
+            proxy_address: None,
+            entry_generations: None,
+            current_generation: 0,
+        }
+    }
+
+    /// Show the local HTTP proxy address in the header.
+    pub fn with_proxy_address(mut self, proxy_address: Option<&'a str>) -> Self {
+        self.proxy_address = proxy_address;
+        self
+    }
+
+    /// Label each row with the restart generation it was captured in.
+    pub fn with_generations(
+        mut self,
+        entry_generations: &'a HashMap<String, u32>,
+        current_generation: u32,
+    ) -> Self {
+        self.entry_generations = Some(entry_generations);
+        self.current_generation = current_generation;
+        self
+    }
+
+    /// Restart generation to label an entry with, once history spans more
+    /// than one run.
+    fn entry_generation(&self, entry: &HttpProfileEntry) -> Option<u32> {
+        let generations = self.entry_generations?;
+        if self.current_generation == 0 {
+            return None;
         }
+        Some(
+            generations
+                .get(&entry.id)
+                .copied()
+                .unwrap_or(self.current_generation),
+        )
     }
 }
 
@@ -99,7 +142,8 @@ impl Widget for RequestTable<'_> {
 impl RequestTable<'_> {
     // ── Header bar ────────────────────────────────────────────────────────────
 
-    /// Render the status header: recording indicator, request count, active filter.
+    /// Render the status header: recording indicator, request count, proxy
+    /// address, active filter.
     fn render_header(&self, area: Rect, buf: &mut Buffer) {
         let header_area = Rect { height: 1, ..area };
 
@@ -115,7 +159,10 @@ impl RequestTable<'_> {
             Style::default().fg(Color::DarkGray)
         };
 
-        let count_text = format!("  {} requests", self.entries.len());
+        let mut count_text = format!("  {} requests", self.entries.len());
+        if let Some(address) = self.proxy_address {
+            count_text.push_str(&format!("  proxy {address}"));
+        }
         let filter_text = if self.filter.is_empty() {
             String::new()
         } else {
@@ -261,6 +308,18 @@ impl RequestTable<'_> {
             );
             x += COL_TYPE;
 
+            // Restart generation label (dimmed for earlier runs)
+            if let Some(generation) = self.entry_generation(entry) {
+                let label = format!("↻{generation} ");
+                let label_style = if generation == self.current_generation {
+                    Style::default().fg(Color::Cyan)
+                } else {
+                    Style::default().fg(Color::DarkGray)
+                };
+                buf.set_string(x, y, &label, label_style.patch(row_style));
+                x += label.chars().count() as u16;
+            }
+
             // URI — gets remaining width
             let uri_width = area.right().saturating_sub(x) as usize;
             let uri_text = entry.short_uri();
@@ -802,4 +861,50 @@ mod tests {
         // One over: ellipsis appears.
         assert!(truncate(s, 4).ends_with('…'));
     }
+
+    #[test]
+    fn test_shows_proxy_address() {
+        let widget =
+            RequestTable::new(&[], None, 0, true, "").with_proxy_address(Some("10.0.2.2:9000"));
+        let mut buf = Buffer::empty(Rect::new(0, 0, 80, 5));
+        widget.render(Rect::new(0, 0, 80, 5), &mut buf);
+        let text = buf_text(&buf, 80, 1);
+        assert!(
+            text.contains("proxy 10.0.2.2:9000"),
+            "Expected proxy address in header; got: {text:?}"
+        );
+    }
+
+    #[test]
+    fn test_generation_labels_shown_after_restart() {
+        let old = make_entry("iso1/1", "GET", Some(200));
+        let new = make_entry("1", "GET", Some(200));
+        let entries = vec![&old, &new];
+        let generations = HashMap::from([("iso1/1".to_string(), 0), ("1".to_string(), 1)]);
+        let widget =
+            RequestTable::new(&entries, None, 0, true, "").with_generations(&generations, 1);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 80, 5));
+        widget.render(Rect::new(0, 0, 80, 5), &mut buf);
+        let text = buf_text(&buf, 80, 4);
+        assert!(
+            text.contains("↻0 "),
+            "Expected old generation label; got: {text:?}"
+        );
+        assert!(
+            text.contains("↻1 "),
+            "Expected current generation label; got: {text:?}"
+        );
+    }
+
+    #[test]
+    fn test_generation_labels_hidden_on_first_run() {
+        let entry = make_entry("1", "GET", Some(200));
+        let entries = vec![&entry];
+        let generations = HashMap::from([("1".to_string(), 0)]);
+        let widget =
+            RequestTable::new(&entries, None, 0, true, "").with_generations(&generations, 0);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 80, 5));
+        widget.render(Rect::new(0, 0, 80, 5), &mut buf);
+        assert!(!buf_text(&buf, 80, 4).contains('↻'));
+    }
 }
@@ -156,6 +156,25 @@ fn test_extensions_unavailable_shows_release_mode_hint() {
     );
 }
 
+#[test]
+fn test_proxy_active_shows_table_when_extensions_unavailable() {
+    let mut state = make_network_state_with_entries(1);
+    state.extensions_available = Some(false);
+    state.proxy_address = Some("127.0.0.1:9000".to_string());
+    let buf = render_monitor(&state, true, 80, 24);
+    assert!(!buf_contains(&buf, 80, 24, "not available"));
+    assert!(buf_contains(&buf, 80, 24, "proxy 127.0.0.1:9000"));
+}
+
+#[test]
+fn test_proxy_active_shows_table_when_vm_disconnected() {
+    let mut state = make_network_state_with_entries(1);
+    state.proxy_address = Some("127.0.0.1:9000".to_string());
+    let buf = render_monitor(&state, false, 80, 24);
+    assert!(!buf_contains(&buf, 80, 24, "Waiting for VM Service"));
+    assert!(buf_contains(&buf, 80, 24, "1 requests"));
+}
+
 #[test]
 fn test_extensions_none_does_not_show_unavailable() {
     // extensions_available = None means we haven't checked yet, should NOT show the message
@@ -694,3 +713,105 @@ fn test_network_monitor_state_preserved_across_sizes() {
         "selected_index should be preserved after rendering at small terminal size"
     );
 }
+
+// ── Waterfall view ────────────────────────────────────────────────────────────
+
+fn make_waterfall_state(n: usize) -> NetworkState {
+    let mut state = make_network_state_with_entries(n);
+    state.view_mode = fdemon_app::session::NetworkViewMode::Waterfall;
+    state
+}
+
+#[test]
+fn test_waterfall_renders_bars_and_axis() {
+    let state = make_waterfall_state(4);
+    let buf = render_monitor(&state, true, 100, 20);
+    assert!(buf_contains(&buf, 100, 20, "4 requests"));
+    assert!(
+        buf_contains(&buf, 100, 20, "|0us"),
+        "axis should start at 0"
+    );
+    assert!(
+        buf_contains(&buf, 100, 20, "▒"),
+        "completed requests without timing draw hatched bars"
+    );
+    assert!(buf_contains(&buf, 100, 20, "4 timing unavailable"));
+    assert!(
+        !buf_contains(&buf, 100, 20, "Method"),
+        "table column headers should not be shown in waterfall view"
+    );
+}
+
+#[test]
+fn test_waterfall_labels_each_untimed_row() {
+    let state = make_waterfall_state(4);
+    let buf = render_monitor(&state, true, 100, 20);
+    // Rows start below the header and axis; the last request's bar ends at
+    // the right edge, so its label goes before the bar instead.
+    for row_y in 2..6 {
+        let row: String = (0..100)
+            .filter_map(|x| buf.cell((x, row_y)))
+            .map(|c| c.symbol())
+            .collect();
+        assert!(row.contains("timing unavailable"), "row {row_y}: {row}");
+    }
+}
+
+#[test]
+fn test_waterfall_shows_zoom_level() {
+    let mut state = make_waterfall_state(4);
+    state.waterfall_zoom_in();
+    state.waterfall_zoom_in();
+    let buf = render_monitor(&state, true, 100, 20);
+    assert!(buf_contains(&buf, 100, 20, "zoom 4x"));
+}
+
+#[test]
+fn test_waterfall_pending_request_uses_pending_glyph() {
+    let mut state = make_waterfall_state(2);
+    let mut pending = make_entry("pending", 2);
+    pending.status_code = None;
+    pending.end_time_us = None;
+    state.merge_entries(vec![pending]);
+    let buf = render_monitor(&state, true, 100, 20);
+    assert!(buf_contains(&buf, 100, 20, "░"));
+}
+
+#[test]
+fn test_waterfall_uses_phase_colors_for_cached_segments() {
+    use fdemon_core::network::{TimingPhase, TimingSegment};
+
+    let mut state = make_waterfall_state(1);
+    state.timing_segments.insert(
+        "req_0".to_string(),
+        vec![
+            TimingSegment {
+                phase: TimingPhase::Wait,
+                start_us: 1_000_000,
+                end_us: 1_040_000,
+            },
+            TimingSegment {
+                phase: TimingPhase::Receive,
+                start_us: 1_040_000,
+                end_us: 1_050_000,
+            },
+        ],
+    );
+    let buf = render_monitor(&state, true, 100, 10);
+    let row_y = 2;
+    let colors: Vec<Color> = (0..100)
+        .filter_map(|x| buf.cell((x, row_y)))
+        .filter(|c| c.symbol() == "█")
+        .filter_map(|c| c.style().fg)
+        .collect();
+    assert!(colors.contains(&phase_color(TimingPhase::Wait)));
+    assert!(colors.contains(&phase_color(TimingPhase::Receive)));
+    assert!(!buf_contains(&buf, 100, 10, "timing unavailable"));
+}
+
+#[test]
+fn test_waterfall_tiny_terminal_no_panic() {
+    let state = make_waterfall_state(3);
+    render_monitor(&state, true, 20, 4);
+    render_monitor(&state, true, 1, 1);
+}
@@ -0,0 +1,409 @@
+//! # Network Waterfall Widget
+//!
+//! Renders every request as a horizontal bar over a shared time axis so that
+//! request fan-out and serialization are visible at a glance. Requests whose
+//! detail has been fetched are split into DNS/connect/TLS/wait/receive
+//! segments; the rest are drawn as a hatched bar coloured by status and
+//! labelled "timing unavailable", and the header counts them, so missing
+//! timing is not mistaken for a single phase.
+
+use std::collections::HashMap;
+
+use fdemon_app::session::NetworkState;
+use fdemon_core::network::{format_duration_ms, HttpProfileEntry, TimingPhase, TimingSegment};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::widgets::Widget;
+
+use super::phase_color;
+use super::request_table::status_display;
+
+/// Maximum width of the request label column (method + path).
+const MAX_LABEL_WIDTH: u16 = 28;
+
+/// Minimum number of columns between two axis tick labels.
+const TICK_SPACING: u16 = 12;
+
+/// Glyph used for completed bar segments.
+const BAR_GLYPH: &str = "█";
+
+/// Glyph used for the open-ended part of an in-flight request.
+const PENDING_GLYPH: &str = "░";
+
+/// Glyph used for completed requests whose phase timing is not loaded yet.
+const UNTIMED_GLYPH: &str = "▒";
+
+/// Label drawn next to the bar of a request whose phase timing is not loaded.
+const UNTIMED_LABEL: &str = "timing unavailable";
+
+/// Phases shown in the header legend, in lifecycle order.
+const LEGEND_PHASES: [TimingPhase; 5] = [
+    TimingPhase::Dns,
+    TimingPhase::Connect,
+    TimingPhase::Tls,
+    TimingPhase::Wait,
+    TimingPhase::Receive,
+];
+
+// ── RequestWaterfall ──────────────────────────────────────────────────────────
+
+/// Waterfall timeline widget for the Network panel.
+///
+/// Like [`super::request_table::RequestTable`] the widget is pure: the parent
+/// passes the pre-filtered entries and the zoom/pan state lives in
+/// [`NetworkState`].
+pub struct RequestWaterfall<'a> {
+    /// Pre-filtered entries to display.
+    entries: &'a [&'a HttpProfileEntry],
+    /// Network state for selection, scroll, zoom/pan and cached segments.
+    state: &'a NetworkState,
+}
+
+impl<'a> RequestWaterfall<'a> {
+    /// Create a new `RequestWaterfall` widget.
+    pub fn new(entries: &'a [&'a HttpProfileEntry], state: &'a NetworkState) -> Self {
+        Self { entries, state }
+    }
+
+    /// Captured time span `(start_us, end_us)` covering every visible entry.
+    ///
+    /// In-flight requests have no end time, so their start still extends the
+    /// span and they are drawn up to its right edge.
+    fn captured_span(&self) -> Option<(i64, i64)> {
+        let start = self.entries.iter().map(|e| e.start_time_us).min()?;
+        let end = self
+            .entries
+            .iter()
+            .map(|e| e.end_time_us.unwrap_or(e.start_time_us))
+            .max()?;
+        Some((start, end.max(start + 1)))
+    }
+
+    /// Number of completed entries without cached phase segments.
+    fn untimed_count(&self) -> usize {
+        self.entries
+            .iter()
+            .filter(|e| e.end_time_us.is_some())
+            .filter(|e| !self.state.timing_segments.contains_key(&e.id))
+            .count()
+    }
+}
+
+impl Widget for RequestWaterfall<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        // Need at least 2 rows: header bar + time axis.
+        if area.height < 2 {
+            return;
+        }
+
+        let label_width = (area.width / 3).min(MAX_LABEL_WIDTH);
+        let bar_area = Rect {
+            x: area.x + label_width,
+            width: area.width.saturating_sub(label_width),
+            ..area
+        };
+
+        let span = self.captured_span();
+        let window = span.map(|(start, end)| self.state.waterfall_window(start, end));
+
+        self.render_header(Rect { height: 1, ..area }, buf, span, window);
+
+        let Some((span_start, _)) = span else {
+            return;
+        };
+        let Some(window) = window else {
+            return;
+        };
+
+        self.render_axis(
+            Rect {
+                y: area.y + 1,
+                height: 1,
+                ..bar_area
+            },
+            buf,
+            span_start,
+            window,
+        );
+
+        let rows_area = Rect {
+            y: area.y + 2,
+            height: area.height.saturating_sub(2),
+            ..area
+        };
+        self.render_rows(rows_area, buf, label_width, window);
+    }
+}
+
+impl RequestWaterfall<'_> {
+    // ── Header bar ────────────────────────────────────────────────────────────
+
+    /// Render request count, zoom level, visible window and the phase legend.
+    fn render_header(
+        &self,
+        area: Rect,
+        buf: &mut Buffer,
+        span: Option<(i64, i64)>,
+        window: Option<(i64, i64)>,
+    ) {
+        let mut text = format!("{} requests", self.entries.len());
+        if self.state.waterfall_zoom > 0 {
+            text.push_str(&format!("  zoom {}x", 1u32 << self.state.waterfall_zoom));
+        }
+        if let (Some((span_start, _)), Some((win_start, win_end))) = (span, window) {
+            text.push_str(&format!(
+                "  {} – {}",
+                format_duration_ms((win_start - span_start) as f64 / 1000.0),
+                format_duration_ms((win_end - span_start) as f64 / 1000.0),
+            ));
+        }
+        let untimed = self.untimed_count();
+        if untimed > 0 {
+            text.push_str(&format!(
+                "  {UNTIMED_GLYPH} {untimed} timing unavailable (select to load)"
+            ));
+        }
+        buf.set_stringn(
+            area.x,
+            area.y,
+            &text,
+            area.width as usize,
+            Style::default().fg(Color::Gray),
+        );
+
+        // Right-aligned legend, skipped entirely when it would collide.
+        let legend_width: u16 = LEGEND_PHASES
+            .iter()
+            .map(|p| p.label().len() as u16 + 3)
+            .sum();
+        let text_width = text.chars().count() as u16;
+        if text_width + legend_width + 2 > area.width {
+            return;
+        }
+        let mut x = area.right() - legend_width;
+        for phase in LEGEND_PHASES {
+            buf.set_string(x, area.y, "■", Style::default().fg(phase_color(phase)));
+            buf.set_string(
+                x + 2,
+                area.y,
+                phase.label(),
+                Style::default().fg(Color::DarkGray),
+            );
+            x += phase.label().len() as u16 + 3;
+        }
+    }
+
+    // ── Time axis ─────────────────────────────────────────────────────────────
+
+    /// Render tick labels (offsets from the start of the captured span).
+    fn render_axis(&self, area: Rect, buf: &mut Buffer, span_start: i64, window: (i64, i64)) {
+        let style = Style::default()
+            .fg(Color::DarkGray)
+            .add_modifier(Modifier::BOLD);
+        let (win_start, win_end) = window;
+        let mut col = 0;
+        while col + TICK_SPACING / 2 < area.width {
+            let t = win_start + (win_end - win_start) * col as i64 / area.width.max(1) as i64;
+            let label = format!("|{}", format_duration_ms((t - span_start) as f64 / 1000.0));
+            buf.set_stringn(
+                area.x + col,
+                area.y,
+                &label,
+                (area.width - col) as usize,
+                style,
+            );
+            col += TICK_SPACING.max(label.chars().count() as u16 + 1);
+        }
+    }
+
+    // ── Request rows ──────────────────────────────────────────────────────────
+
+    /// Render the visible window of request rows (label column + bar).
+    fn render_rows(&self, area: Rect, buf: &mut Buffer, label_width: u16, window: (i64, i64)) {
+        if area.height == 0 {
+            return;
+        }
+
+        let visible_rows = area.height as usize;
+        let start = first_visible_row(
+            self.state.scroll_offset,
+            self.state.selected_index,
+            visible_rows,
+        );
+        let end = (start + visible_rows).min(self.entries.len());
+        let bar_x = area.x + label_width;
+        let bar_width = area.width.saturating_sub(label_width);
+
+        for (row_idx, entry_idx) in (start..end).enumerate() {
+            let entry = self.entries[entry_idx];
+            let y = area.y + row_idx as u16;
+            let is_selected = self.state.selected_index == Some(entry_idx);
+
+            let row_style = if is_selected {
+                Style::default().bg(Color::DarkGray)
+            } else {
+                Style::default()
+            };
+            for x in area.x..area.right() {
+                if let Some(cell) = buf.cell_mut((x, y)) {
+                    cell.set_style(row_style).set_char(' ');
+                }
+            }
+
+            self.render_label(entry, area.x, y, label_width, row_style, buf);
+
+            let bar = BarGeometry {
+                x: bar_x,
+                y,
+                width: bar_width,
+                window,
+                row_style,
+            };
+            bar.draw_entry(entry, &self.state.timing_segments, buf);
+        }
+    }
+
+    /// Render `METHOD /path` truncated to the label column.
+    fn render_label(
+        &self,
+        entry: &HttpProfileEntry,
+        x: u16,
+        y: u16,
+        width: u16,
+        row_style: Style,
+        buf: &mut Buffer,
+    ) {
+        if width < 2 {
+            return;
+        }
+        let usable = width.saturating_sub(1) as usize;
+        let method_style = Style::default()
+            .fg(super::http_method_color(&entry.method))
+            .patch(row_style);
+        let (next_x, _) = buf.set_stringn(x, y, &entry.method, usable, method_style);
+        let used = (next_x - x) as usize;
+        if used + 1 < usable {
+            buf.set_stringn(
+                next_x + 1,
+                y,
+                entry.short_uri(),
+                usable - used - 1,
+                Style::default().fg(Color::White).patch(row_style),
+            );
+        }
+    }
+}
+
+// ── Bar geometry ──────────────────────────────────────────────────────────────
+
+/// Mapping from a time window onto a row of terminal cells.
+struct BarGeometry {
+    x: u16,
+    y: u16,
+    width: u16,
+    window: (i64, i64),
+    row_style: Style,
+}
+
+impl BarGeometry {
+    /// Draw one request: phase segments when cached, otherwise a single
+    /// hatched bar labelled [`UNTIMED_LABEL`].
+    fn draw_entry(
+        &self,
+        entry: &HttpProfileEntry,
+        segments: &HashMap<String, Vec<TimingSegment>>,
+        buf: &mut Buffer,
+    ) {
+        match (segments.get(&entry.id), entry.end_time_us) {
+            (Some(segments), _) => {
+                for segment in segments {
+                    self.draw_span(
+                        segment.start_us,
+                        segment.end_us,
+                        BAR_GLYPH,
+                        Style::default().fg(phase_color(segment.phase)),
+                        buf,
+                    );
+                }
+            }
+            (None, Some(end)) => {
+                let (_, status_style) = status_display(entry);
+                if let Some(cols) =
+                    self.draw_span(entry.start_time_us, end, UNTIMED_GLYPH, status_style, buf)
+                {
+                    self.draw_untimed_label(cols, buf);
+                }
+            }
+            (None, None) => {
+                self.draw_span(
+                    entry.start_time_us,
+                    self.window.1,
+                    PENDING_GLYPH,
+                    Style::default().fg(Color::DarkGray),
+                    buf,
+                );
+            }
+        }
+    }
+
+    /// Write [`UNTIMED_LABEL`] after the bar covering columns `start..end`,
+    /// or before it when there is no room after. Skipped if neither fits.
+    fn draw_untimed_label(&self, (start, end): (u16, u16), buf: &mut Buffer) {
+        let len = UNTIMED_LABEL.len() as u16;
+        let col = if end + 1 + len <= self.width {
+            end + 1
+        } else if start > len {
+            start - 1 - len
+        } else {
+            return;
+        };
+        let style = Style::default().fg(Color::DarkGray).patch(self.row_style);
+        buf.set_string(self.x + col, self.y, UNTIMED_LABEL, style);
+    }
+
+    /// Fill the cells covering `start_us..end_us`, clipped to the window, and
+    /// return the filled column range (relative to the bar), if any.
+    ///
+    /// Spans that overlap the window but are narrower than one cell still
+    /// occupy a single cell so short requests stay visible.
+    fn draw_span(
+        &self,
+        start_us: i64,
+        end_us: i64,
+        glyph: &str,
+        style: Style,
+        buf: &mut Buffer,
+    ) -> Option<(u16, u16)> {
+        let (win_start, win_end) = self.window;
+        if self.width == 0 || end_us < win_start || start_us > win_end {
+            return None;
+        }
+        let window = (win_end - win_start).max(1);
+        let width = self.width as i64;
+        let col_start = ((start_us - win_start) * width / window).clamp(0, width - 1);
+        let col_end = ((end_us - win_start) * width + window - 1) / window;
+        let col_end = col_end.clamp(0, width);
+        let col_end = col_end.max(col_start + 1);
+
+        let style = match self.row_style.bg {
+            Some(bg) => style.bg(bg),
+            None => style,
+        };
+        for col in col_start..col_end {
+            buf.set_string(self.x + col as u16, self.y, glyph, style);
+        }
+        Some((col_start as u16, col_end as u16))
+    }
+}
+
+// ── Helpers ───────────────────────────────────────────────────────────────────
+
+/// First row to render so that the selected row stays inside the viewport.
+fn first_visible_row(scroll_offset: usize, selected: Option<usize>, visible_rows: usize) -> usize {
+    match selected {
+        Some(sel) if sel < scroll_offset => sel,
+        Some(sel) if sel >= scroll_offset + visible_rows => sel + 1 - visible_rows,
+        _ => scroll_offset,
+    }
+}
@@ -1,7 +1,7 @@
 //! Bar chart rendering for [`FrameChart`].
 //!
 //! Contains the main bar chart rendering loop, visible range computation,
-//! the 16ms budget line, and pure helper functions for bar height/colour.
+//! the frame budget line, and pure helper functions for bar height/colour.
 
 use super::*;
 
@@ -56,7 +56,7 @@ impl FrameChart<'_> {
         let total_half_blocks = (area.height as f64) * 2.0;
 
         // Budget line y position (in chart row coordinates from top)
-        let budget_frac = BUDGET_LINE_MS / y_range_ms;
+        let budget_frac = self.budget_ms() / y_range_ms;
         let budget_row_from_bottom = (budget_frac * total_half_blocks / 2.0).round() as u16;
         let budget_y = area
             .bottom()
@@ -78,7 +78,7 @@ impl FrameChart<'_> {
 
             let is_selected = self.selected_frame == Some(global_idx);
 
-            let (ui_color, raster_color) = bar_colors(frame);
+            let (ui_color, raster_color) = bar_colors(frame, self.budget_micros);
 
             // UI bar height in half-block units
             let ui_ms = frame.build_ms();
@@ -150,14 +150,14 @@ impl FrameChart<'_> {
 
     // MSRV guard: `is_multiple_of` requires Rust 1.87; MSRV is 1.77.2 — suppress the lint.
     #[allow(clippy::manual_is_multiple_of)]
-    /// Draw the 16ms dashed budget line across the chart area.
+    /// Draw the dashed frame budget line across the chart area.
     pub(super) fn render_budget_line(&self, area: Rect, buf: &mut Buffer, budget_y: u16) {
         if budget_y < area.y || budget_y >= area.bottom() {
             return;
         }
 
-        // Label: "16ms" at the left edge
-        let label = "16ms";
+        // Label: the budget in whole milliseconds (e.g. "16ms", "8ms") at the left edge
+        let label = format!("{}ms", self.budget_micros / 1000);
         let label_style = Style::default().fg(COLOR_BUDGET_LINE);
         let line_style = Style::default().fg(COLOR_BUDGET_LINE);
 
@@ -190,13 +190,13 @@ impl FrameChart<'_> {
 
 // ── Pure helpers ──────────────────────────────────────────────────────────────
 
-/// Determine the UI and Raster bar colours for a frame.
+/// Determine the UI and Raster bar colours for a frame against `budget_micros`.
 ///
 /// `pub(crate)` to allow re-export from `mod.rs` into tests via `use super::*`.
-pub(crate) fn bar_colors(frame: &FrameTiming) -> (Color, Color) {
+pub(crate) fn bar_colors(frame: &FrameTiming, budget_micros: u64) -> (Color, Color) {
     if frame.has_shader_compilation() {
         (COLOR_SHADER, COLOR_SHADER)
-    } else if frame.is_janky() {
+    } else if frame.is_janky_at(budget_micros) {
         (COLOR_JANK, COLOR_JANK)
     } else {
         (COLOR_UI_NORMAL, COLOR_RASTER_NORMAL)
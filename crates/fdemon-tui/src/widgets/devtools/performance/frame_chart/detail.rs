@@ -5,7 +5,7 @@
 
 use super::*;
 
-use fdemon_core::performance::{FramePhases, FrameTiming};
+use fdemon_core::performance::{FramePhase, FramePhases, FrameTiming};
 use ratatui::buffer::Buffer;
 use ratatui::layout::Rect;
 use ratatui::style::{Color, Modifier, Style};
@@ -38,7 +38,7 @@ impl FrameChart<'_> {
         let frame_label = format!("Frame #{}", frame.number);
         let total_label = format!("  Total: {:.1}ms", total_ms);
 
-        let (status_label, status_style) = frame_status_label_and_style(frame);
+        let (status_label, status_style) = frame_status_label_and_style(frame, self.budget_micros);
 
         let line0 = Line::from(vec![
             Span::styled(
@@ -56,10 +56,17 @@ impl FrameChart<'_> {
             return;
         }
 
+        // For janky frames, flag the phase that used most of the budget.
+        let blown_phase = frame
+            .phases
+            .as_ref()
+            .filter(|_| frame.is_janky_at(self.budget_micros))
+            .map(FramePhases::slowest);
+
         // Line 1: UI thread breakdown
         let ui_ms = frame.build_ms();
         let line1 = if let Some(phases) = &frame.phases {
-            render_ui_phase_line(ui_ms, phases)
+            render_ui_phase_line(ui_ms, phases, blown_phase)
         } else {
             Line::from(vec![
                 Span::styled("UI: ", Style::default().fg(Color::Gray)),
@@ -78,7 +85,11 @@ impl FrameChart<'_> {
             Span::styled("Raster: ", Style::default().fg(Color::Gray)),
             Span::styled(
                 format!("{:.1}ms", raster_ms),
-                Style::default().fg(Color::Green),
+                if blown_phase == Some(FramePhase::Raster) {
+                    blown_phase_style()
+                } else {
+                    Style::default().fg(Color::Green)
+                },
             ),
         ]);
         buf.set_line(area.x, area.y + 2, &line2, area.width);
@@ -143,7 +154,10 @@ impl FrameChart<'_> {
 // ── Pure helpers ──────────────────────────────────────────────────────────────
 
 /// Build the status label and style for the detail panel header line.
-pub(super) fn frame_status_label_and_style(frame: &FrameTiming) -> (&'static str, Style) {
+pub(super) fn frame_status_label_and_style(
+    frame: &FrameTiming,
+    budget_micros: u64,
+) -> (&'static str, Style) {
     if frame.has_shader_compilation() {
         (
             "  (SHADER)",
@@ -151,7 +165,7 @@ pub(super) fn frame_status_label_and_style(frame: &FrameTiming) -> (&'static str
                 .fg(COLOR_SHADER)
                 .add_modifier(Modifier::BOLD),
         )
-    } else if frame.is_janky() {
+    } else if frame.is_janky_at(budget_micros) {
         (
             "  (JANK)",
             Style::default().fg(COLOR_JANK).add_modifier(Modifier::BOLD),
@@ -161,33 +175,39 @@ pub(super) fn frame_status_label_and_style(frame: &FrameTiming) -> (&'static str
     }
 }
 
-/// Build the UI thread breakdown line for the detail panel.
-pub(super) fn render_ui_phase_line(ui_ms: f64, phases: &FramePhases) -> Line<'static> {
-    let build_ms = phases.build_micros as f64 / 1000.0;
-    let layout_ms = phases.layout_micros as f64 / 1000.0;
-    let paint_ms = phases.paint_micros as f64 / 1000.0;
+/// Style for the phase that blew the frame budget.
+fn blown_phase_style() -> Style {
+    Style::default().fg(COLOR_JANK).add_modifier(Modifier::BOLD)
+}
 
+/// Build the UI thread breakdown line for the detail panel.
+///
+/// `blown_phase` is highlighted when it is one of the UI phases.
+pub(super) fn render_ui_phase_line(
+    ui_ms: f64,
+    phases: &FramePhases,
+    blown_phase: Option<FramePhase>,
+) -> Line<'static> {
     let phase_style = Style::default().fg(Color::DarkGray);
+    let value_style = |phase: FramePhase| {
+        if blown_phase == Some(phase) {
+            blown_phase_style()
+        } else {
+            Style::default().fg(Color::DarkGray)
+        }
+    };
+    let ms = |micros: u64| format!("{:.1}ms", micros as f64 / 1000.0);
 
     Line::from(vec![
         Span::styled("UI: ", Style::default().fg(Color::Gray)),
         Span::styled(format!("{:.1}ms", ui_ms), Style::default().fg(Color::Cyan)),
         Span::styled("  (", phase_style),
         Span::styled("Build: ", phase_style),
-        Span::styled(
-            format!("{:.1}ms", build_ms),
-            Style::default().fg(Color::DarkGray),
-        ),
+        Span::styled(ms(phases.build_micros), value_style(FramePhase::Build)),
         Span::styled("  Layout: ", phase_style),
-        Span::styled(
-            format!("{:.1}ms", layout_ms),
-            Style::default().fg(Color::DarkGray),
-        ),
+        Span::styled(ms(phases.layout_micros), value_style(FramePhase::Layout)),
         Span::styled("  Paint: ", phase_style),
-        Span::styled(
-            format!("{:.1}ms", paint_ms),
-            Style::default().fg(Color::DarkGray),
-        ),
+        Span::styled(ms(phases.paint_micros), value_style(FramePhase::Paint)),
         Span::styled(")", phase_style),
     ])
 }
@@ -1,7 +1,7 @@
 //! # Frame Bar Chart Widget
 //!
 //! Renders each Flutter frame as a pair of vertical bars (UI thread + Raster thread)
-//! with colour coding for jank and shader compilation, a 16ms budget line,
+//! with colour coding for jank and shader compilation, a frame budget line,
 //! frame selection with highlight, and a 3-line detail panel below the chart.
 //!
 //! Module wiring (Task 07) has connected `FrameChart` to the performance panel.
@@ -9,7 +9,9 @@
 mod bars;
 mod detail;
 
-use fdemon_core::performance::{FrameTiming, PerformanceStats, RingBuffer};
+use fdemon_core::performance::{
+    FrameTiming, PerformanceStats, RingBuffer, FRAME_BUDGET_60FPS_MICROS,
+};
 use ratatui::buffer::Buffer;
 use ratatui::layout::Rect;
 use ratatui::style::Color;
@@ -35,9 +37,6 @@ pub(super) const MIN_CHART_HEIGHT: u16 = 4;
 /// Minimum y-axis range in milliseconds — prevents flat charts for fast apps.
 pub(super) const MIN_Y_RANGE_MS: f64 = 20.0;
 
-/// 16.667ms frame budget line (60 FPS).
-pub(super) const BUDGET_LINE_MS: f64 = 16.667;
-
 // Colour helpers
 
 /// Bar colour for a normal (non-jank) UI thread bar.
@@ -46,13 +45,13 @@ pub(super) const COLOR_UI_NORMAL: Color = Color::Cyan;
 /// Bar colour for a normal (non-jank) Raster thread bar.
 pub(super) const COLOR_RASTER_NORMAL: Color = Color::Green;
 
-/// Bar colour for a janky frame (either thread, total > 16ms).
+/// Bar colour for a janky frame (total time over the frame budget).
 pub(super) const COLOR_JANK: Color = Color::Red;
 
 /// Bar colour for a frame with shader compilation detected.
 pub(super) const COLOR_SHADER: Color = Color::Magenta;
 
-/// Colour of the 16ms budget dashed line and its label.
+/// Colour of the frame budget dashed line and its label.
 pub(super) const COLOR_BUDGET_LINE: Color = Color::DarkGray;
 
 // FrameChart
@@ -60,13 +59,17 @@ pub(super) const COLOR_BUDGET_LINE: Color = Color::DarkGray;
 /// Frame timing bar chart with selectable frames.
 ///
 /// Renders each frame as a pair of vertical bars (UI thread + Raster thread)
-/// with colour coding for jank/shader compilation, a 16ms budget line,
+/// with colour coding for jank/shader compilation, a frame budget line,
 /// and a detail panel below for the selected frame.
+///
+/// The budget defaults to 60 FPS; use [`FrameChart::with_budget_micros`] for
+/// devices with a different display refresh rate.
 pub(crate) struct FrameChart<'a> {
     pub(super) frame_history: &'a RingBuffer<FrameTiming>,
     pub(super) selected_frame: Option<usize>,
     pub(super) stats: &'a PerformanceStats,
     pub(super) icons: bool,
+    pub(super) budget_micros: u64,
 }
 
 impl<'a> FrameChart<'a> {
@@ -88,8 +91,20 @@ impl<'a> FrameChart<'a> {
             selected_frame,
             stats,
             icons,
+            budget_micros: FRAME_BUDGET_60FPS_MICROS,
         }
     }
+
+    /// Set the frame budget used for the budget line and jank colouring.
+    pub fn with_budget_micros(mut self, budget_micros: u64) -> Self {
+        self.budget_micros = budget_micros;
+        self
+    }
+
+    /// Frame budget in milliseconds.
+    pub(super) fn budget_ms(&self) -> f64 {
+        self.budget_micros as f64 / 1000.0
+    }
 }
 
 impl Widget for FrameChart<'_> {
@@ -292,6 +292,39 @@ fn test_detail_panel_with_phases() {
     );
 }
 
+#[test]
+fn test_detail_panel_highlights_blown_phase() {
+    let mut history = RingBuffer::new(100);
+    let mut frame = make_janky_frame(5);
+    frame.phases = Some(FramePhases {
+        build_micros: 2_000,
+        layout_micros: 9_000,
+        paint_micros: 1_000,
+        raster_micros: 8_000,
+        shader_compilation: false,
+    });
+    history.push(frame);
+    let stats = make_stats(Some(50.0), 1, Some(20.0), 1);
+    let widget = FrameChart::new(&history, Some(0), &stats, false);
+    let buf = render_widget(widget, 80, 20);
+
+    let chars: Vec<char> = collect_text(&buf, 80, 20).chars().collect();
+    let label: Vec<char> = "Layout: ".chars().collect();
+    let start = chars
+        .windows(label.len())
+        .position(|w| w == label.as_slice())
+        .expect("layout phase shown");
+    let value = start + label.len();
+    let cell = buf
+        .cell(((value % 80) as u16, (value / 80) as u16))
+        .unwrap();
+    assert_eq!(
+        cell.style().fg,
+        Some(COLOR_JANK),
+        "Slowest phase of a janky frame should be highlighted"
+    );
+}
+
 #[test]
 fn test_detail_panel_jank_label() {
     let mut history = RingBuffer::new(100);
@@ -350,12 +383,30 @@ fn test_full_buffer_history_no_panic() {
     assert!(!text.is_empty());
 }
 
+#[test]
+fn test_budget_follows_refresh_rate() {
+    // 10ms total: within the 60 FPS budget, over the 120 FPS budget
+    let mut history = RingBuffer::new(10);
+    history.push(make_frame(1, 6_000, 4_000));
+    let stats = PerformanceStats::default();
+    let widget = FrameChart::new(&history, Some(0), &stats, false)
+        .with_budget_micros(fdemon_core::performance::frame_budget_micros(120.0));
+    let buf = render_widget(widget, 80, 24);
+    let text = collect_text(&buf, 80, 24);
+    assert!(text.contains("8ms"), "120 FPS budget label; text: {text:?}");
+    assert!(text.contains("(JANK)"), "10ms frame is janky at 120 FPS");
+    assert_eq!(
+        bar_colors(&make_frame(1, 6_000, 4_000), 8_333),
+        (COLOR_JANK, COLOR_JANK)
+    );
+}
+
 // ── Unit tests for pure helper functions ──────────────────────────────────
 
 #[test]
 fn test_bar_colors_normal_frame() {
     let frame = make_frame(1, 5_000, 3_000);
-    let (ui, raster) = bar_colors(&frame);
+    let (ui, raster) = bar_colors(&frame, FRAME_BUDGET_60FPS_MICROS);
     assert_eq!(ui, COLOR_UI_NORMAL);
     assert_eq!(raster, COLOR_RASTER_NORMAL);
 }
@@ -363,7 +414,7 @@ fn test_bar_colors_normal_frame() {
 #[test]
 fn test_bar_colors_jank_frame() {
     let frame = make_janky_frame(1);
-    let (ui, raster) = bar_colors(&frame);
+    let (ui, raster) = bar_colors(&frame, FRAME_BUDGET_60FPS_MICROS);
     assert_eq!(ui, COLOR_JANK);
     assert_eq!(raster, COLOR_JANK);
 }
@@ -371,7 +422,7 @@ fn test_bar_colors_jank_frame() {
 #[test]
 fn test_bar_colors_shader_frame() {
     let frame = make_shader_frame(1);
-    let (ui, raster) = bar_colors(&frame);
+    let (ui, raster) = bar_colors(&frame, FRAME_BUDGET_60FPS_MICROS);
     assert_eq!(ui, COLOR_SHADER);
     assert_eq!(raster, COLOR_SHADER);
 }
@@ -0,0 +1,166 @@
+//! Instance browser for the DevTools performance panel.
+//!
+//! Replaces the memory section while a class from the allocation table is
+//! being browsed: live instances on the left, the selected instance's fields
+//! and retaining path on the right.
+
+use fdemon_app::session::InstanceBrowser;
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::Widget;
+
+use super::super::truncate_str;
+use crate::theme::palette;
+
+/// Share of the width given to the instance list.
+const LIST_PERCENT: u16 = 40;
+
+/// Live instances of one class with the selected instance's details.
+///
+/// # Layout
+///
+/// ```text
+/// _HomeState             │ Fields
+/// _HomeState             │   _count  _Smi 3
+///                        │ Retaining path
+///                        │ _HomeState
+///                        │  ↳ _GrowableList (length 1)[0]
+///                        │   ↳ Notifier._listeners
+///                        │    ↳ GC root: user global
+/// ```
+pub(super) struct InstanceBrowserView<'a> {
+    browser: &'a InstanceBrowser,
+}
+
+impl<'a> InstanceBrowserView<'a> {
+    pub(super) fn new(browser: &'a InstanceBrowser) -> Self {
+        Self { browser }
+    }
+}
+
+impl Widget for InstanceBrowserView<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        if area.height == 0 || area.width < 10 {
+            return;
+        }
+        let chunks = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([
+                Constraint::Percentage(LIST_PERCENT),
+                Constraint::Percentage(100 - LIST_PERCENT),
+            ])
+            .split(area);
+        self.render_instances(chunks[0], buf);
+        self.render_details(chunks[1], buf);
+    }
+}
+
+impl InstanceBrowserView<'_> {
+    fn render_instances(&self, area: Rect, buf: &mut Buffer) {
+        let browser = self.browser;
+        let muted = Style::default().fg(palette::TEXT_SECONDARY);
+
+        if browser.instances.is_empty() {
+            let msg = if let Some(error) = &browser.error {
+                error.as_str()
+            } else if browser.loading {
+                "Loading instances..."
+            } else {
+                "No live instances"
+            };
+            buf.set_string(
+                area.x,
+                area.y,
+                truncate_str(msg, area.width as usize),
+                muted,
+            );
+            return;
+        }
+
+        let viewport = area.height as usize;
+        let selected = browser.selected.min(browser.instances.len() - 1);
+        let start = (selected + 1).saturating_sub(viewport);
+        let width = area.width.saturating_sub(1) as usize;
+
+        for (offset, instance) in browser
+            .instances
+            .iter()
+            .skip(start)
+            .take(viewport)
+            .enumerate()
+        {
+            let y = area.y + offset as u16;
+            let style = if start + offset == selected {
+                Style::default()
+                    .fg(palette::TEXT_PRIMARY)
+                    .bg(palette::SELECTED_ROW_BG)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(palette::TEXT_PRIMARY)
+            };
+            let text = truncate_str(&instance.description, width);
+            buf.set_string(area.x, y, format!("{text:<width$}"), style);
+        }
+    }
+
+    fn render_details(&self, area: Rect, buf: &mut Buffer) {
+        let browser = self.browser;
+        let muted = Style::default().fg(palette::TEXT_SECONDARY);
+        let heading = Style::default()
+            .fg(palette::ACCENT_DIM)
+            .add_modifier(Modifier::BOLD);
+
+        let lines: Vec<Line> = match &browser.details {
+            None if browser.instances.is_empty() => Vec::new(),
+            None => {
+                let msg = match &browser.error {
+                    Some(error) => error.clone(),
+                    None if browser.loading => "Inspecting...".to_string(),
+                    None => "Press Enter to show fields and retaining path".to_string(),
+                };
+                vec![Line::from(Span::styled(msg, muted))]
+            }
+            Some(details) => {
+                let mut lines = vec![Line::from(Span::styled("Fields", heading))];
+                if details.fields.is_empty() {
+                    lines.push(Line::from(Span::styled("  (none)", muted)));
+                }
+                for field in &details.fields {
+                    lines.push(Line::from(vec![
+                        Span::styled(
+                            format!("  {}  ", field.name),
+                            Style::default().fg(palette::TEXT_PRIMARY),
+                        ),
+                        Span::styled(field.value.clone(), muted),
+                    ]));
+                }
+                lines.push(Line::from(Span::styled("Retaining path", heading)));
+                for (depth, link) in details.retaining_path.chain().into_iter().enumerate() {
+                    let text = if depth == 0 {
+                        link
+                    } else {
+                        format!("{}\u{21b3} {link}", " ".repeat(depth))
+                    };
+                    lines.push(Line::from(Span::styled(
+                        text,
+                        Style::default().fg(palette::TEXT_PRIMARY),
+                    )));
+                }
+                lines
+            }
+        };
+
+        let divider = Style::default().fg(palette::BORDER_DIM);
+        for y in area.y..area.bottom() {
+            buf.set_string(area.x, y, "\u{2502}", divider);
+        }
+
+        let x = area.x + 2;
+        let width = area.width.saturating_sub(2);
+        for (i, line) in lines.iter().take(area.height as usize).enumerate() {
+            buf.set_line(x, area.y + i as u16, line, width);
+        }
+    }
+}
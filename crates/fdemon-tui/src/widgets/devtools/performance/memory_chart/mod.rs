@@ -3,7 +3,8 @@
 //! Renders a time-series chart using Unicode braille characters showing
 //! stacked memory layers (Dart heap, native, raster cache) with line
 //! overlays (allocated, RSS), GC event markers, a legend, and a class
-//! allocation table below.
+//! allocation table below (replaced by a growth table while an allocation
+//! diff is shown).
 //!
 //! Module wiring (Task 07) has connected `MemoryChart` to the performance panel.
 
@@ -13,10 +14,11 @@ mod table;
 
 use braille_canvas::BrailleCanvas;
 use chart::{render_history_chart, render_legend, render_sample_chart, render_x_axis_labels};
-use table::render_allocation_table;
+use table::{render_allocation_table, render_growth_table};
 
 use fdemon_app::session::AllocationSortColumn;
 use fdemon_core::performance::{AllocationProfile, GcEvent, MemorySample, MemoryUsage, RingBuffer};
+use fdemon_core::HeapDiff;
 use ratatui::buffer::Buffer;
 use ratatui::layout::Rect;
 use ratatui::style::{Color, Style};
@@ -34,7 +36,7 @@ pub(super) const MIN_CHART_HEIGHT: u16 = 6;
 /// Accepts header (1 row) + 1 data row, which is the smallest useful view.
 pub(super) const MIN_TABLE_HEIGHT: u16 = 2;
 pub(super) const TABLE_HEADER_HEIGHT: u16 = 2; // header + separator
-pub(super) const MAX_TABLE_ROWS: usize = 10;
+pub(super) const MAX_TABLE_ROWS: usize = fdemon_app::session::MAX_ALLOCATION_ROWS;
 const CHART_PROPORTION: f64 = 0.6; // 60% chart, 40% table
 /// Width of the Y-axis label column in characters (e.g., "128 MB ").
 const Y_AXIS_WIDTH: u16 = 7;
@@ -77,6 +79,8 @@ pub(crate) struct MemoryChart<'a> {
     gc_history: &'a RingBuffer<GcEvent>,
     allocation_profile: Option<&'a AllocationProfile>,
     allocation_sort: AllocationSortColumn,
+    heap_diff: Option<&'a HeapDiff>,
+    selected_row: Option<usize>,
     icons: bool,
 }
 
@@ -96,9 +100,25 @@ impl<'a> MemoryChart<'a> {
             gc_history,
             allocation_profile,
             allocation_sort,
+            heap_diff: None,
+            selected_row: None,
             icons,
         }
     }
+
+    /// Show the growth table of an allocation diff instead of the
+    /// allocation table.
+    pub(crate) fn with_heap_diff(mut self, heap_diff: Option<&'a HeapDiff>) -> Self {
+        self.heap_diff = heap_diff;
+        self
+    }
+
+    /// Highlight the table row at `index` (the class the instance browser
+    /// opens on Enter).
+    pub(crate) fn with_selected_row(mut self, index: Option<usize>) -> Self {
+        self.selected_row = index;
+        self
+    }
 }
 
 impl Widget for MemoryChart<'_> {
@@ -143,12 +163,16 @@ impl Widget for MemoryChart<'_> {
                 chart_area,
                 buf,
             );
-            render_allocation_table(
-                self.allocation_profile,
-                self.allocation_sort,
-                table_area,
-                buf,
-            );
+            match self.heap_diff {
+                Some(diff) => render_growth_table(diff, self.allocation_sort, table_area, buf),
+                None => render_allocation_table(
+                    self.allocation_profile,
+                    self.allocation_sort,
+                    table_area,
+                    buf,
+                ),
+            }
+            self.highlight_selected_row(table_area, buf);
         } else {
             render_chart_area(
                 self.memory_samples,
@@ -161,6 +185,31 @@ impl Widget for MemoryChart<'_> {
     }
 }
 
+impl MemoryChart<'_> {
+    /// Paint the selected-row background over the selected table row, if it
+    /// holds a class.
+    fn highlight_selected_row(&self, table_area: Rect, buf: &mut Buffer) {
+        let Some(index) = self.selected_row else {
+            return;
+        };
+        let row_count = match self.heap_diff {
+            Some(diff) => diff.classes.len(),
+            None => self.allocation_profile.map_or(0, |p| p.members.len()),
+        }
+        .min(MAX_TABLE_ROWS);
+        let y = table_area.y + TABLE_HEADER_HEIGHT + index as u16;
+        if index >= row_count || y >= table_area.bottom() {
+            return;
+        }
+        let sel_bg = Style::default().bg(palette::SELECTED_ROW_BG);
+        for x in table_area.x..table_area.right() {
+            if let Some(cell) = buf.cell_mut((x, y)) {
+                cell.set_style(sel_bg);
+            }
+        }
+    }
+}
+
 // ── Compact summary ───────────────────────────────────────────────────────────
 
 /// Render a single-line memory summary when the area is too small for a chart.
@@ -1,7 +1,8 @@
 //! Allocation table renderer for the memory chart.
 //!
 //! Renders the class allocation table below the chart, showing
-//! the top classes sorted by either total size or total instance count.
+//! the top classes sorted by either total size or total instance count,
+//! or the allocation diff growth table while a diff is shown.
 
 use fdemon_app::session::AllocationSortColumn;
 
@@ -95,15 +96,7 @@ pub(super) fn render_allocation_table(
                     break;
                 }
 
-                // Truncate class name to 30 chars (char-aware to avoid panic on multi-byte UTF-8)
-                let name = if class.class_name.chars().count() > 30 {
-                    format!(
-                        "{}...",
-                        class.class_name.chars().take(27).collect::<String>()
-                    )
-                } else {
-                    class.class_name.clone()
-                };
+                let name = truncate_class_name(&class.class_name);
 
                 let row = Line::from(vec![
                     Span::styled(
@@ -124,3 +117,113 @@ pub(super) fn render_allocation_table(
         }
     }
 }
+
+/// Render the per-class growth between the first allocation capture and the
+/// latest one, largest growth first according to `sort_column`.
+pub(super) fn render_growth_table(
+    diff: &HeapDiff,
+    sort_column: AllocationSortColumn,
+    area: Rect,
+    buf: &mut Buffer,
+) {
+    if area.height == 0 || area.width < 10 {
+        return;
+    }
+
+    let (instances_label, size_label) = match sort_column {
+        AllocationSortColumn::BySize => (
+            format!("{:>12}", "\u{0394} Inst"),
+            format!("{:>14}", "\u{0394} Retained \u{25bc}"),
+        ),
+        AllocationSortColumn::ByInstances => (
+            format!("{:>12}", "\u{0394} Inst \u{25bc}"),
+            format!("{:>14}", "\u{0394} Retained"),
+        ),
+    };
+    let header_line = Line::from(vec![
+        Span::styled(
+            format!("{:<30}", "Class (since capture 1)"),
+            Style::default().fg(palette::TEXT_SECONDARY),
+        ),
+        Span::styled(
+            instances_label,
+            Style::default().fg(palette::TEXT_SECONDARY),
+        ),
+        Span::styled(size_label, Style::default().fg(palette::TEXT_SECONDARY)),
+    ]);
+    buf.set_line(area.x, area.y, &header_line, area.width);
+
+    if area.height < 2 {
+        return;
+    }
+
+    let sep: String = "\u{2500}".repeat(area.width as usize);
+    let sep_line = Line::from(Span::styled(sep, Style::default().fg(palette::BORDER_DIM)));
+    buf.set_line(area.x, area.y + 1, &sep_line, area.width);
+
+    if area.height < 3 {
+        return;
+    }
+
+    let data_start_y = area.y + TABLE_HEADER_HEIGHT;
+    let available_rows = area.height.saturating_sub(TABLE_HEADER_HEIGHT) as usize;
+
+    let classes = match sort_column {
+        AllocationSortColumn::BySize => diff.top_by_size(MAX_TABLE_ROWS),
+        AllocationSortColumn::ByInstances => diff.top_by_instances(MAX_TABLE_ROWS),
+    };
+    if classes.is_empty() {
+        let msg = Line::from(Span::styled(
+            "No retained classes changed",
+            Style::default().fg(palette::TEXT_SECONDARY),
+        ));
+        buf.set_line(area.x, data_start_y, &msg, area.width);
+        return;
+    }
+
+    for (i, class) in classes.iter().take(available_rows).enumerate() {
+        let row_y = data_start_y + i as u16;
+        if row_y >= area.bottom() {
+            break;
+        }
+
+        let delta_style = if class.size_delta() > 0 || class.instance_delta() > 0 {
+            Style::default().fg(palette::STATUS_RED)
+        } else {
+            Style::default().fg(palette::STATUS_GREEN)
+        };
+        let row = Line::from(vec![
+            Span::styled(
+                format!("{:<30}", truncate_class_name(&class.class_name)),
+                Style::default().fg(palette::TEXT_PRIMARY),
+            ),
+            Span::styled(
+                format!("{:>12}", signed(class.instance_delta(), format_number)),
+                delta_style,
+            ),
+            Span::styled(
+                format!(
+                    "{:>14}",
+                    signed(class.size_delta(), MemoryUsage::format_bytes)
+                ),
+                delta_style,
+            ),
+        ]);
+        buf.set_line(area.x, row_y, &row, area.width);
+    }
+}
+
+/// Truncate a class name to 30 chars (char-aware to avoid panic on multi-byte UTF-8).
+fn truncate_class_name(name: &str) -> String {
+    if name.chars().count() > 30 {
+        format!("{}...", name.chars().take(27).collect::<String>())
+    } else {
+        name.to_string()
+    }
+}
+
+/// Format a signed delta with an explicit `+`/`-` using `format` for the magnitude.
+fn signed(value: i64, format: impl Fn(u64) -> String) -> String {
+    let sign = if value < 0 { "-" } else { "+" };
+    format!("{sign}{}", format(value.unsigned_abs()))
+}
@@ -342,6 +342,7 @@ fn test_allocation_table_shows_top_classes() {
         members: vec![
             ClassHeapStats {
                 class_name: "dart:core/String".to_string(),
+                class_id: None,
                 library_uri: None,
                 new_space_instances: 1000,
                 new_space_size: 500_000,
@@ -350,6 +351,7 @@ fn test_allocation_table_shows_top_classes() {
             },
             ClassHeapStats {
                 class_name: "dart:core/_List".to_string(),
+                class_id: None,
                 library_uri: None,
                 new_space_instances: 200,
                 new_space_size: 100_000,
@@ -391,6 +393,47 @@ fn test_allocation_table_none_profile() {
     );
 }
 
+#[test]
+fn test_growth_table_shows_signed_deltas() {
+    let class = |instances, bytes| ClassHeapStats {
+        class_name: "HomeController".to_string(),
+        class_id: None,
+        library_uri: None,
+        new_space_instances: 0,
+        new_space_size: 0,
+        old_space_instances: instances,
+        old_space_size: bytes,
+    };
+    let profile = |members| AllocationProfile {
+        members,
+        timestamp: chrono::Local::now(),
+    };
+    let diff = fdemon_core::HeapDiff::between(
+        &profile(vec![class(1, 1024)]),
+        &profile(vec![class(4, 4096)]),
+    );
+
+    let area = Rect::new(0, 0, 80, 10);
+    let mut buf = Buffer::empty(area);
+    render_growth_table(&diff, AllocationSortColumn::BySize, area, &mut buf);
+
+    let content: String = (0..10u16)
+        .flat_map(|y| (0..80u16).map(move |x| (x, y)))
+        .filter_map(|(x, y)| buf.cell((x, y)).map(|c| c.symbol().to_string()))
+        .collect();
+
+    assert!(content.contains("since capture 1"), "growth header");
+    assert!(content.contains("HomeController"));
+    assert!(
+        content.contains("+3"),
+        "instance delta; content: {content:?}"
+    );
+    assert!(
+        content.contains("+3.0 KB"),
+        "byte delta; content: {content:?}"
+    );
+}
+
 #[test]
 fn test_compact_mode_small_height() {
     // area height < MIN_CHART_HEIGHT (6) → compact summary
@@ -603,6 +646,7 @@ fn test_class_name_truncation_with_cjk() {
     let profile = AllocationProfile {
         members: vec![ClassHeapStats {
             class_name: long_cjk.to_string(),
+            class_id: None,
             library_uri: None,
             new_space_instances: 100,
             new_space_size: 50_000,
@@ -625,6 +669,7 @@ fn test_class_name_truncation_with_emoji() {
     let profile = AllocationProfile {
         members: vec![ClassHeapStats {
             class_name: emoji_name.to_string(),
+            class_id: None,
             library_uri: None,
             new_space_instances: 10,
             new_space_size: 1_000,
@@ -647,6 +692,7 @@ fn test_class_name_truncation_result_ends_with_ellipsis() {
     let profile = AllocationProfile {
         members: vec![ClassHeapStats {
             class_name: long_ascii.to_string(),
+            class_id: None,
             library_uri: None,
             new_space_instances: 1,
             new_space_size: 100,
@@ -681,6 +727,7 @@ fn test_class_name_no_truncation_for_short_name() {
     let profile = AllocationProfile {
         members: vec![ClassHeapStats {
             class_name: short_name.to_string(),
+            class_id: None,
             library_uri: None,
             new_space_instances: 500,
             new_space_size: 200_000,
@@ -712,6 +759,7 @@ fn make_two_class_profile() -> AllocationProfile {
             // ClassA: bigger in bytes, fewer instances
             ClassHeapStats {
                 class_name: "ClassA".to_string(),
+                class_id: None,
                 library_uri: None,
                 new_space_instances: 10,
                 new_space_size: 1_000_000,
@@ -721,6 +769,7 @@ fn make_two_class_profile() -> AllocationProfile {
             // ClassB: smaller in bytes, more instances
             ClassHeapStats {
                 class_name: "ClassB".to_string(),
+                class_id: None,
                 library_uri: None,
                 new_space_instances: 5_000,
                 new_space_size: 10_000,
@@ -13,20 +13,25 @@
 //! │           Frame Timing (~45%)           │
 //! │  [bar chart + detail panel]             │
 //! │                                         │
-//! ├─────────────────────────────────────────┤
+//! ├──── vs baseline: FPS … P95 … Jank … ────┤
 //! │                                         │
-//! │           Memory (~55%)                 │
+//! │           Memory (~55%)   leak hints ───│
 //! │  [time-series chart + alloc table]      │
+//! │  (widget rebuild table while tracking,  │
+//! │   instance browser while browsing)      │
 //! │                                         │
 //! └─────────────────────────────────────────┘
 //! ```
 
 mod frame_chart;
+mod instance_browser;
 mod memory_chart;
+mod rebuild_table;
 pub(super) mod styles;
 
 use fdemon_app::session::PerformanceState;
 use fdemon_app::state::VmConnectionStatus;
+use fdemon_core::{format_bytes, LeakHint, PerfComparison, PerfMetric};
 use ratatui::buffer::Buffer;
 use ratatui::layout::{Alignment, Constraint, Direction, Layout, Rect};
 use ratatui::style::Style;
@@ -36,7 +41,9 @@ use ratatui::widgets::{Block, BorderType, Borders, Paragraph, Widget, Wrap};
 use crate::theme::{icons::IconSet, palette};
 
 use frame_chart::FrameChart;
+use instance_browser::InstanceBrowserView;
 use memory_chart::MemoryChart;
+use rebuild_table::RebuildTable;
 use styles::fps_style;
 
 // ── Responsive layout thresholds ─────────────────────────────────────────────
@@ -146,6 +153,7 @@ impl PerformancePanel<'_> {
                 &self.performance.stats,
                 false,
             )
+            .with_budget_micros(self.performance.frame_budget_micros)
             .render(frame_inner, buf);
             return;
         }
@@ -166,13 +174,17 @@ impl PerformancePanel<'_> {
             .constraints([Constraint::Percentage(45), Constraint::Percentage(55)])
             .split(usable_area);
 
-        // Frame timing section (with block border)
-        let frame_block = Block::default()
+        // Frame timing section (with block border). The last baseline
+        // comparison, if any, is shown on the bottom border.
+        let mut frame_block = Block::default()
             .title(format!(" {} Frame Timing ", self.icons.activity()))
             .borders(Borders::ALL)
             .border_type(BorderType::Rounded)
             .border_style(Style::default().fg(palette::BORDER_DIM))
             .title_style(Style::default().fg(palette::ACCENT_DIM));
+        if let Some(comparison) = &self.performance.baseline_comparison {
+            frame_block = frame_block.title_bottom(baseline_line(comparison));
+        }
         let frame_inner = frame_block.inner(chunks[0]);
         frame_block.render(chunks[0], buf);
 
@@ -182,17 +194,60 @@ impl PerformancePanel<'_> {
             &self.performance.stats,
             false,
         )
+        .with_budget_micros(self.performance.frame_budget_micros)
         .render(frame_inner, buf);
 
+        // The instance browser, or else the rebuild table while tracking is
+        // on, takes the memory section's place.
+        if let Some(browser) = &self.performance.instance_browser {
+            let browser_block = Block::default()
+                .title(format!(
+                    " {} Instances of {} ({} live) ",
+                    self.icons.cpu(),
+                    browser.class_name,
+                    browser.total_count
+                ))
+                .borders(Borders::TOP)
+                .border_type(BorderType::Rounded)
+                .border_style(Style::default().fg(palette::BORDER_DIM))
+                .title_style(Style::default().fg(palette::ACCENT_DIM));
+            let browser_inner = browser_block.inner(chunks[1]);
+            browser_block.render(chunks[1], buf);
+            InstanceBrowserView::new(browser).render(browser_inner, buf);
+            return;
+        }
+        if self.performance.rebuild_tracking {
+            let rebuild_block = Block::default()
+                .title(format!(
+                    " {} Widget Rebuilds ({} frames) ",
+                    self.icons.refresh(),
+                    self.performance.rebuild_stats.frames
+                ))
+                .borders(Borders::TOP)
+                .border_type(BorderType::Rounded)
+                .border_style(Style::default().fg(palette::BORDER_DIM))
+                .title_style(Style::default().fg(palette::ACCENT_DIM));
+            let rebuild_inner = rebuild_block.inner(chunks[1]);
+            rebuild_block.render(chunks[1], buf);
+            RebuildTable::new(self.performance).render(rebuild_inner, buf);
+            return;
+        }
+
         // Memory section — use Borders::TOP only to maximise inner height.
         // The top border carries the title; no bottom/side borders are needed
         // because the footer hint line occupies the row below.
-        let memory_block = Block::default()
+        let mut memory_block = Block::default()
             .title(format!(" {} Memory ", self.icons.cpu()))
             .borders(Borders::TOP)
             .border_type(BorderType::Rounded)
             .border_style(Style::default().fg(palette::BORDER_DIM))
             .title_style(Style::default().fg(palette::ACCENT_DIM));
+        if let Some(tracker) = &self.performance.leak_tracker {
+            if !tracker.hints().is_empty() {
+                memory_block = memory_block
+                    .title(leak_hints_line(tracker.hints(), self.icons).right_aligned());
+            }
+        }
         let memory_inner = memory_block.inner(chunks[1]);
         memory_block.render(chunks[1], buf);
 
@@ -204,6 +259,8 @@ impl PerformancePanel<'_> {
             self.performance.allocation_sort,
             false,
         )
+        .with_heap_diff(self.performance.heap_diff.as_ref())
+        .with_selected_row(Some(self.performance.allocation_selected))
         .render(memory_inner, buf);
     }
 
@@ -275,6 +332,56 @@ impl PerformancePanel<'_> {
     }
 }
 
+// ── Baseline comparison ──────────────────────────────────────────────────────
+
+/// One-line summary of a baseline comparison: each metric's change, red when
+/// it exceeded its threshold.
+fn baseline_line(comparison: &PerfComparison) -> Line<'static> {
+    let mut spans = vec![Span::styled(
+        format!(" vs {}:", comparison.baseline_name),
+        Style::default().fg(palette::ACCENT_DIM),
+    )];
+    for m in &comparison.metrics {
+        let delta = m.delta();
+        let value = match m.metric {
+            PerfMetric::Fps => format!("{delta:+.1}"),
+            PerfMetric::P95FrameTime | PerfMetric::P99FrameTime => format!("{delta:+.1}ms"),
+            PerfMetric::JankCount => format!("{delta:+.0}"),
+            PerfMetric::HeapPeak => {
+                let sign = if delta < 0.0 { '-' } else { '+' };
+                format!("{sign}{}", format_bytes(delta.abs() as u64))
+            }
+        };
+        let color = if m.regressed {
+            palette::STATUS_RED
+        } else {
+            palette::TEXT_SECONDARY
+        };
+        spans.push(Span::styled(
+            format!(" {} {value}", m.metric.label()),
+            Style::default().fg(color),
+        ));
+    }
+    spans.push(Span::raw(" "));
+    Line::from(spans)
+}
+
+// ── Leak hints ───────────────────────────────────────────────────────────────
+
+/// Classes flagged by the leak tracker with their growth over the checked
+/// reloads and route changes, e.g. ` ⚠ Leak? _HomeState +3 `.
+fn leak_hints_line(hints: &[LeakHint], icons: IconSet) -> Line<'static> {
+    let mut text = format!(" {} Leak?", icons.alert());
+    for hint in hints {
+        text.push_str(&format!(" {} +{}", hint.class_name, hint.growth()));
+    }
+    text.push(' ');
+    Line::from(Span::styled(
+        text,
+        Style::default().fg(palette::STATUS_YELLOW),
+    ))
+}
+
 // ── Tests ─────────────────────────────────────────────────────────────────────
 
 #[cfg(test)]
@@ -0,0 +1,153 @@
+//! Widget rebuild table for the DevTools performance panel.
+//!
+//! Replaces the memory section while rebuild tracking is enabled, listing the
+//! widget creation locations that rebuilt most often.
+
+use fdemon_app::session::{PerformanceState, RebuildSortColumn};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::Widget;
+
+use super::super::inspector::short_path;
+use super::super::truncate_str;
+use super::styles::format_number;
+use crate::theme::palette;
+
+/// Width of the widget name column.
+const NAME_WIDTH: usize = 28;
+/// Width of each count column.
+const COUNT_WIDTH: usize = 12;
+/// Header line + separator.
+const HEADER_HEIGHT: u16 = 2;
+
+/// Sortable "most rebuilt" table.
+///
+/// # Layout
+///
+/// ```text
+/// Widget                      Location             Last frame     Total ▼
+/// ──────────────────────────────────────────────────────────────────────
+/// Counter                     lib/main.dart:42              3         120
+/// ```
+pub(super) struct RebuildTable<'a> {
+    performance: &'a PerformanceState,
+}
+
+impl<'a> RebuildTable<'a> {
+    pub(super) fn new(performance: &'a PerformanceState) -> Self {
+        Self { performance }
+    }
+}
+
+impl Widget for RebuildTable<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        if area.height == 0 || area.width < 10 {
+            return;
+        }
+        let perf = self.performance;
+
+        let (last_label, total_label) = match perf.rebuild_sort {
+            RebuildSortColumn::ByTotal => ("Last frame", "Total \u{25bc}"),
+            RebuildSortColumn::ByLastFrame => ("Last frame \u{25bc}", "Total"),
+        };
+        let location_width = (area.width as usize).saturating_sub(NAME_WIDTH + 2 * COUNT_WIDTH);
+        let header = Line::from(vec![
+            Span::raw(format!("{:<NAME_WIDTH$}", "Widget")),
+            Span::raw(format!("{:<location_width$}", "Location")),
+            Span::raw(format!("{last_label:>COUNT_WIDTH$}")),
+            Span::raw(format!("{total_label:>COUNT_WIDTH$}")),
+        ])
+        .style(Style::default().fg(palette::TEXT_SECONDARY));
+        buf.set_line(area.x, area.y, &header, area.width);
+
+        if area.height < 2 {
+            return;
+        }
+        let sep = "\u{2500}".repeat(area.width as usize);
+        buf.set_string(
+            area.x,
+            area.y + 1,
+            sep,
+            Style::default().fg(palette::BORDER_DIM),
+        );
+
+        let data_y = area.y + HEADER_HEIGHT;
+        let viewport = area.height.saturating_sub(HEADER_HEIGHT) as usize;
+        if viewport == 0 {
+            return;
+        }
+
+        let rows = perf.sorted_rebuilds();
+        if rows.is_empty() {
+            buf.set_string(
+                area.x,
+                data_y,
+                "Interact with the app to record widget rebuilds...",
+                Style::default().fg(palette::TEXT_SECONDARY),
+            );
+            return;
+        }
+
+        let selected = perf.rebuild_selected.min(rows.len() - 1);
+        let start = (selected + 1).saturating_sub(viewport);
+
+        for (offset, entry) in rows.iter().skip(start).take(viewport).enumerate() {
+            let y = data_y + offset as u16;
+            let is_selected = start + offset == selected;
+            if is_selected {
+                let sel_bg = Style::default().bg(palette::SELECTED_ROW_BG);
+                for x in area.x..area.right() {
+                    if let Some(cell) = buf.cell_mut((x, y)) {
+                        cell.set_style(sel_bg);
+                    }
+                }
+            }
+
+            let name = entry.location.name.as_deref().unwrap_or("?");
+            let location = format!(
+                "{}:{}",
+                short_path(&entry.location.file),
+                entry.location.line
+            );
+            let name_style = if is_selected {
+                Style::default()
+                    .fg(palette::TEXT_PRIMARY)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(palette::TEXT_PRIMARY)
+            };
+            let last_style = if entry.last_frame > 0 {
+                Style::default().fg(palette::STATUS_YELLOW)
+            } else {
+                Style::default().fg(palette::TEXT_MUTED)
+            };
+            let row = Line::from(vec![
+                Span::styled(
+                    format!(
+                        "{:<NAME_WIDTH$}",
+                        truncate_str(name, NAME_WIDTH.saturating_sub(1))
+                    ),
+                    name_style,
+                ),
+                Span::styled(
+                    format!(
+                        "{:<location_width$}",
+                        truncate_str(&location, location_width.saturating_sub(1))
+                    ),
+                    Style::default().fg(palette::TEXT_MUTED),
+                ),
+                Span::styled(
+                    format!("{:>COUNT_WIDTH$}", format_number(entry.last_frame)),
+                    last_style,
+                ),
+                Span::styled(
+                    format!("{:>COUNT_WIDTH$}", format_number(entry.total)),
+                    Style::default().fg(palette::TEXT_SECONDARY),
+                ),
+            ]);
+            buf.set_line(area.x, y, &row, area.width);
+        }
+    }
+}
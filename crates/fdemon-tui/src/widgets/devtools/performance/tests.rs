@@ -437,3 +437,146 @@ fn test_performance_panel_with_selected_frame() {
     render_to_buf(widget, 80, 30);
     // Should not panic with selected frame
 }
+
+#[test]
+fn test_performance_panel_rebuild_table_replaces_memory_when_tracking() {
+    use fdemon_core::performance::RebuiltWidgets;
+    use fdemon_core::widget_tree::CreationLocation;
+
+    let mut perf = make_test_performance();
+    perf.rebuild_tracking = true;
+    perf.rebuild_stats.record(RebuiltWidgets {
+        counts: vec![(1, 42)],
+        new_locations: vec![(
+            1,
+            CreationLocation {
+                file: "file:///app/lib/counter.dart".to_string(),
+                line: 17,
+                column: 12,
+                name: Some("CounterText".to_string()),
+            },
+        )],
+    });
+    let widget = PerformancePanel::new(
+        &perf,
+        true,
+        IconSet::default(),
+        &VmConnectionStatus::Connected,
+    );
+    let buf = render_to_buf(widget, 100, 30);
+    let text = collect_buf_text(&buf, 100, 30);
+    assert!(text.contains("Widget Rebuilds"), "got: {text:?}");
+    assert!(text.contains("CounterText"));
+    assert!(text.contains("lib/counter.dart:17"));
+    assert!(!text.contains("Memory"));
+}
+
+#[test]
+fn test_baseline_comparison_shown_on_frame_border() {
+    use fdemon_core::{MetricDelta, PerfComparison, PerfMetric};
+
+    let mut perf = make_test_performance();
+    perf.baseline_comparison = Some(PerfComparison {
+        baseline_name: "scroll".to_string(),
+        metrics: vec![
+            MetricDelta {
+                metric: PerfMetric::Fps,
+                baseline: 60.0,
+                current: 57.5,
+                regressed: false,
+            },
+            MetricDelta {
+                metric: PerfMetric::JankCount,
+                baseline: 2.0,
+                current: 9.0,
+                regressed: true,
+            },
+        ],
+    });
+    let status = VmConnectionStatus::Connected;
+    let widget = PerformancePanel::new(&perf, true, IconSet::default(), &status);
+    let buf = render_to_buf(widget, 100, 30);
+    assert!(buf_contains_text(
+        &buf,
+        100,
+        30,
+        "vs scroll: FPS -2.5 Jank +7"
+    ));
+}
+
+#[test]
+fn test_instance_browser_replaces_memory_section() {
+    use fdemon_app::session::InstanceBrowser;
+    use fdemon_core::{
+        HeapInstance, InstanceDetails, InstanceField, RetainingObject, RetainingPath, RetainingSlot,
+    };
+
+    let mut perf = make_test_performance();
+    let mut browser = InstanceBrowser::new("Listener".to_string(), "classes/7".to_string());
+    browser.loading = false;
+    browser.total_count = 3;
+    browser.instances = vec![HeapInstance {
+        id: "objects/1".to_string(),
+        description: "Listener".to_string(),
+    }];
+    browser.details = Some(InstanceDetails {
+        fields: vec![InstanceField {
+            name: "_count".to_string(),
+            value: "_Smi 2".to_string(),
+        }],
+        retaining_path: RetainingPath {
+            gc_root_type: "user global".to_string(),
+            elements: vec![
+                RetainingObject {
+                    description: "Listener".to_string(),
+                    parent_slot: Some(RetainingSlot::Field("_listener".to_string())),
+                },
+                RetainingObject {
+                    description: "Notifier".to_string(),
+                    parent_slot: None,
+                },
+            ],
+        },
+    });
+    perf.instance_browser = Some(browser);
+    let status = VmConnectionStatus::Connected;
+    let widget = PerformancePanel::new(&perf, true, IconSet::default(), &status);
+    let buf = render_to_buf(widget, 100, 30);
+    let text = collect_buf_text(&buf, 100, 30);
+    assert!(
+        text.contains("Instances of Listener (3 live)"),
+        "got: {text:?}"
+    );
+    assert!(text.contains("_count  _Smi 2"));
+    assert!(text.contains("\u{21b3} Notifier._listener"));
+    assert!(text.contains("\u{21b3} GC root: user global"));
+    assert!(!text.contains(" Memory "));
+}
+
+#[test]
+fn test_leak_hints_shown_on_memory_border() {
+    use fdemon_core::performance::{AllocationProfile, ClassHeapStats};
+    use fdemon_core::LeakTracker;
+
+    let mut tracker = LeakTracker::new(vec!["State".to_string()], 1);
+    for live in [2, 5] {
+        tracker.record_checkpoint(&AllocationProfile {
+            members: vec![ClassHeapStats {
+                class_name: "_HomeState".to_string(),
+                class_id: None,
+                library_uri: None,
+                new_space_instances: 0,
+                new_space_size: 0,
+                old_space_instances: live,
+                old_space_size: live * 48,
+            }],
+            timestamp: chrono::Local::now(),
+        });
+    }
+    let mut perf = make_test_performance();
+    perf.leak_tracker = Some(tracker);
+    let status = VmConnectionStatus::Connected;
+    let widget = PerformancePanel::new(&perf, true, IconSet::default(), &status);
+    let buf = render_to_buf(widget, 100, 30);
+    assert!(buf_contains_text(&buf, 100, 30, "Leak? _HomeState +3"));
+}
@@ -0,0 +1,539 @@
+//! CPU profiler panel for the DevTools TUI.
+//!
+//! Renders a view selector, the recording state, and the loaded profile as a
+//! bottom-up or top-down call tree table (`Total`/`Self` percentages with
+//! `▶`/`▼` expand markers) or as a flame graph: one row per stack depth,
+//! each function a bar whose width is proportional to its total samples.
+
+use fdemon_app::profiler_view_state::{ProfilerView, ProfilerViewState};
+use fdemon_app::state::VmConnectionStatus;
+use fdemon_core::{CallTreeNode, CpuProfile};
+use ratatui::{
+    buffer::Buffer,
+    layout::{Alignment, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph, Widget, Wrap},
+};
+
+use super::truncate_str;
+use crate::theme::palette;
+
+/// Width of the `Total` and `Self` percentage columns, including padding.
+const PERCENT_COL_WIDTH: u16 = 8;
+
+/// CPU profiler widget. Stateless; reads from [`ProfilerViewState`].
+pub struct CpuProfiler<'a> {
+    state: &'a ProfilerViewState,
+    vm_connected: bool,
+    connection_status: &'a VmConnectionStatus,
+}
+
+impl<'a> CpuProfiler<'a> {
+    /// Create a new `CpuProfiler` widget.
+    pub fn new(
+        state: &'a ProfilerViewState,
+        vm_connected: bool,
+        connection_status: &'a VmConnectionStatus,
+    ) -> Self {
+        Self {
+            state,
+            vm_connected,
+            connection_status,
+        }
+    }
+}
+
+impl Widget for CpuProfiler<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(palette::BORDER_DIM))
+            .title(Span::styled(
+                " CPU Profiler ",
+                Style::default().fg(palette::ACCENT_DIM),
+            ))
+            .title_alignment(Alignment::Left);
+        let mut inner = block.inner(area);
+        block.render(area, buf);
+
+        if inner.height == 0 || inner.width == 0 {
+            return;
+        }
+
+        self.render_header(Rect { height: 1, ..inner }, buf);
+        inner.y += 1;
+        inner.height -= 1;
+
+        let state = self.state;
+        if let Some(error) = &state.error {
+            render_error(inner, buf, &error.message, &error.hint);
+            return;
+        }
+        let profile = match &state.profile {
+            Some(profile) if !state.is_busy() && state.recording.is_none() => profile,
+            _ => {
+                let (text, color) = if state.starting {
+                    ("Starting recording...".to_string(), palette::TEXT_MUTED)
+                } else if state.recording.is_some() {
+                    (
+                        "Recording CPU samples... press [Space] to stop".to_string(),
+                        palette::STATUS_RED,
+                    )
+                } else if state.loading {
+                    ("Fetching CPU samples...".to_string(), palette::TEXT_MUTED)
+                } else if !self.vm_connected {
+                    (self.disconnected_text(), palette::STATUS_YELLOW)
+                } else {
+                    (
+                        "Press [Space] to start recording".to_string(),
+                        palette::TEXT_MUTED,
+                    )
+                };
+                render_centered(inner, buf, &text, color);
+                return;
+            }
+        };
+
+        if inner.height == 0 {
+            return;
+        }
+        render_summary(
+            Rect { height: 1, ..inner },
+            buf,
+            profile,
+            state.tree.sample_count,
+        );
+        inner.y += 1;
+        inner.height -= 1;
+
+        if state.tree.roots.is_empty() {
+            let text = if state.project_only {
+                "No samples in project code — press [f] to show all frames"
+            } else {
+                "No samples recorded"
+            };
+            render_centered(inner, buf, text, palette::TEXT_MUTED);
+            return;
+        }
+
+        match state.view {
+            ProfilerView::BottomUp | ProfilerView::TopDown => {
+                self.render_table(inner, buf, profile)
+            }
+            ProfilerView::FlameGraph => self.render_flame_graph(inner, buf, profile),
+        }
+    }
+}
+
+impl CpuProfiler<'_> {
+    /// Render `[1] Bottom Up  [2] Top Down  [3] Flame Graph` with the
+    /// recording indicator and filter status on the right.
+    fn render_header(&self, area: Rect, buf: &mut Buffer) {
+        let mut x = area.x;
+        for (i, view) in ProfilerView::ALL.iter().enumerate() {
+            let label = format!(" [{}] {} ", i + 1, view.label());
+            let width = label.chars().count() as u16;
+            if x + width > area.right() {
+                break;
+            }
+            let style = if *view == self.state.view {
+                Style::default()
+                    .bg(palette::ACCENT_DIM)
+                    .fg(palette::TEXT_BRIGHT)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(palette::TEXT_SECONDARY)
+            };
+            buf.set_string(x, area.y, &label, style);
+            x += width + 1;
+        }
+
+        let (status, color) = if self.state.recording.is_some() {
+            ("● REC", palette::STATUS_RED)
+        } else if self.state.project_only {
+            ("Project only", palette::STATUS_BLUE)
+        } else {
+            ("All frames", palette::TEXT_MUTED)
+        };
+        let width = status.chars().count() as u16;
+        if x + width < area.right() {
+            buf.set_string(
+                area.right() - width,
+                area.y,
+                status,
+                Style::default().fg(color).add_modifier(Modifier::BOLD),
+            );
+        }
+    }
+
+    /// Render the call tree as a table, scrolled so the selection stays in view.
+    fn render_table(&self, area: Rect, buf: &mut Buffer, profile: &CpuProfile) {
+        if area.height < 2 {
+            return;
+        }
+        let state = self.state;
+        let header_style = Style::default()
+            .fg(palette::TEXT_SECONDARY)
+            .add_modifier(Modifier::BOLD);
+        buf.set_string(area.x, area.y, "   Total    Self", header_style);
+        let name_x = area.x + 2 * PERCENT_COL_WIDTH + 1;
+        let title = match state.view {
+            ProfilerView::BottomUp => "Function (callers below)",
+            _ => "Function (callees below)",
+        };
+        if name_x < area.right() {
+            buf.set_string(name_x, area.y, title, header_style);
+        }
+
+        let body = Rect {
+            y: area.y + 1,
+            height: area.height - 1,
+            ..area
+        };
+        let rows = state.visible_rows();
+        let height = body.height as usize;
+        let total_rows = rows.len();
+        let start = state
+            .selected
+            .saturating_sub(height / 2)
+            .min(total_rows.saturating_sub(height));
+        let sample_count = state.tree.sample_count;
+
+        for (offset, row) in rows.iter().skip(start).take(height).enumerate() {
+            let Some(node) = state.tree.node(&row.path) else {
+                continue;
+            };
+            let y = body.y + offset as u16;
+            let is_selected = start + offset == state.selected;
+            if is_selected {
+                let sel_bg = Style::default().bg(palette::SELECTED_ROW_BG);
+                for x in body.x..body.right() {
+                    if let Some(cell) = buf.cell_mut((x, y)) {
+                        cell.set_style(sel_bg);
+                    }
+                }
+            }
+
+            let total = format!("{:>7} ", percent(node.total, sample_count));
+            let self_pct = format!("{:>7} ", percent(node.self_samples, sample_count));
+            buf.set_string(
+                body.x,
+                y,
+                &total,
+                Style::default().fg(palette::TEXT_PRIMARY),
+            );
+            buf.set_string(
+                body.x + PERCENT_COL_WIDTH,
+                y,
+                &self_pct,
+                Style::default().fg(palette::TEXT_SECONDARY),
+            );
+
+            let marker = if node.children.is_empty() {
+                " "
+            } else if state.expanded.contains(&row.path) {
+                "▼"
+            } else {
+                "▶"
+            };
+            let indent = "  ".repeat(row.depth());
+            let function = &profile.functions[node.function];
+            let text = format!("{indent}{marker} {}", function.display_name());
+            let mut style = Style::default().fg(function_color(node, profile));
+            if is_selected {
+                style = style.add_modifier(Modifier::BOLD);
+            }
+            let name_width = body.right().saturating_sub(name_x) as usize;
+            if name_width > 0 {
+                buf.set_string(name_x, y, truncate_str(&text, name_width), style);
+            }
+        }
+
+        // Scroll thumb on the right edge when the table overflows.
+        if total_rows > height && height > 0 {
+            let thumb_y = body.y + ((state.selected * height) / total_rows).min(height - 1) as u16;
+            if let Some(cell) = buf.cell_mut((body.right().saturating_sub(1), thumb_y)) {
+                cell.set_symbol("█").set_fg(palette::BORDER_DIM);
+            }
+        }
+    }
+
+    /// Render the top-down tree as a flame graph, roots on the first row.
+    fn render_flame_graph(&self, area: Rect, buf: &mut Buffer, profile: &CpuProfile) {
+        let state = self.state;
+        let selected = state.selected_path();
+        let mut path = Vec::new();
+        draw_flame_level(
+            &FlameContext {
+                area,
+                profile,
+                sample_count: state.tree.sample_count.max(1),
+                selected: selected.as_deref(),
+            },
+            buf,
+            &state.tree.roots,
+            0,
+            0,
+            &mut path,
+        );
+    }
+
+    fn disconnected_text(&self) -> String {
+        match self.connection_status {
+            VmConnectionStatus::Reconnecting {
+                attempt,
+                max_attempts,
+            } => format!("Reconnecting to VM Service... ({attempt}/{max_attempts})"),
+            _ => "VM Service not connected".to_string(),
+        }
+    }
+}
+
+/// Values shared by every level of a flame graph render.
+struct FlameContext<'a> {
+    area: Rect,
+    profile: &'a CpuProfile,
+    sample_count: u64,
+    selected: Option<&'a [usize]>,
+}
+
+/// Draw `nodes` on row `depth`, starting `first_sample` samples from the left
+/// edge, then recurse into their children on the row below.
+fn draw_flame_level(
+    ctx: &FlameContext<'_>,
+    buf: &mut Buffer,
+    nodes: &[CallTreeNode],
+    depth: u16,
+    first_sample: u64,
+    path: &mut Vec<usize>,
+) {
+    if depth >= ctx.area.height {
+        return;
+    }
+    let width = u64::from(ctx.area.width);
+    let column = |sample: u64| ctx.area.x + (sample * width / ctx.sample_count) as u16;
+    let y = ctx.area.y + depth;
+
+    let mut offset = first_sample;
+    for (i, node) in nodes.iter().enumerate() {
+        path.push(i);
+        let (x0, x1) = (column(offset), column(offset + node.total));
+        if x1 > x0 {
+            let is_selected = ctx.selected == Some(path.as_slice());
+            let function = &ctx.profile.functions[node.function];
+            let bg = if is_selected {
+                palette::STATUS_YELLOW
+            } else if function.is_project() {
+                palette::ACCENT_DIM
+            } else if depth.is_multiple_of(2) {
+                palette::SURFACE
+            } else {
+                palette::POPUP_BG
+            };
+            let fg = if is_selected {
+                palette::CONTRAST_FG
+            } else {
+                palette::TEXT_PRIMARY
+            };
+            let style = Style::default().bg(bg).fg(fg);
+            for x in x0..x1 {
+                if let Some(cell) = buf.cell_mut((x, y)) {
+                    cell.set_symbol(" ").set_style(style);
+                }
+            }
+            // One column is left as a gap between neighbouring bars.
+            let label_width = (x1 - x0).saturating_sub(1) as usize;
+            if label_width > 0 {
+                buf.set_string(
+                    x0,
+                    y,
+                    truncate_str(&function.display_name(), label_width),
+                    style,
+                );
+            }
+            draw_flame_level(ctx, buf, &node.children, depth + 1, offset, path);
+        }
+        path.pop();
+        offset += node.total;
+    }
+}
+
+/// `12.5%` of `count` out of `total`.
+fn percent(count: u64, total: u64) -> String {
+    if total == 0 {
+        return "-".to_string();
+    }
+    format!("{:.1}%", count as f64 * 100.0 / total as f64)
+}
+
+/// Project code is highlighted; SDK, package and native frames are muted.
+fn function_color(node: &CallTreeNode, profile: &CpuProfile) -> Color {
+    if profile.functions[node.function].is_project() {
+        palette::STACK_FUNCTION_PROJECT
+    } else {
+        palette::TEXT_SECONDARY
+    }
+}
+
+/// Render `1,234 samples · 250µs period · 1.23s window`.
+fn render_summary(area: Rect, buf: &mut Buffer, profile: &CpuProfile, sample_count: u64) {
+    let text = format!(
+        "{} samples · {}µs period · {:.2}s window",
+        sample_count,
+        profile.sample_period_micros,
+        profile.time_extent_micros as f64 / 1_000_000.0
+    );
+    buf.set_string(
+        area.x,
+        area.y,
+        truncate_str(&text, area.width as usize),
+        Style::default().fg(palette::TEXT_MUTED),
+    );
+}
+
+fn render_centered(area: Rect, buf: &mut Buffer, text: &str, color: Color) {
+    if area.height == 0 {
+        return;
+    }
+    Paragraph::new(text)
+        .style(Style::default().fg(color))
+        .alignment(Alignment::Center)
+        .render(
+            Rect {
+                y: area.y + area.height / 2,
+                height: 1,
+                ..area
+            },
+            buf,
+        );
+}
+
+fn render_error(area: Rect, buf: &mut Buffer, message: &str, hint: &str) {
+    if area.height == 0 {
+        return;
+    }
+    let lines = vec![
+        Line::from(Span::styled(
+            format!("\u{26a0} {message}"),
+            Style::default().fg(Color::Yellow),
+        )),
+        Line::from(""),
+        Line::from(Span::styled(hint, Style::default().fg(Color::DarkGray))),
+        Line::from(""),
+        Line::from(Span::styled(
+            "[Space] Record   [Esc] Return to logs",
+            Style::default().fg(palette::TEXT_MUTED),
+        )),
+    ];
+    let h = 5u16;
+    Paragraph::new(lines)
+        .alignment(Alignment::Center)
+        .wrap(Wrap { trim: true })
+        .render(
+            Rect {
+                y: area.y + area.height.saturating_sub(h) / 2,
+                height: h.min(area.height),
+                ..area
+            },
+            buf,
+        );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fdemon_app::profiler_view_state::ProfilerRecording;
+    use fdemon_app::state::DevToolsError;
+    use fdemon_core::ProfileFunction;
+
+    fn render_to_text(state: &ProfilerViewState, vm_connected: bool) -> String {
+        let status = VmConnectionStatus::Connected;
+        let area = Rect::new(0, 0, 70, 12);
+        let mut buf = Buffer::empty(area);
+        CpuProfiler::new(state, vm_connected, &status).render(area, &mut buf);
+        let mut text = String::new();
+        for y in 0..area.height {
+            for x in 0..area.width {
+                text.push_str(buf[(x, y)].symbol());
+            }
+            text.push('\n');
+        }
+        text
+    }
+
+    fn function(name: &str, url: &str) -> ProfileFunction {
+        ProfileFunction {
+            name: name.to_string(),
+            owner: None,
+            url: Some(url.to_string()),
+            kind: "Dart".to_string(),
+        }
+    }
+
+    /// main → build (x3), main → sort (x1)
+    fn loaded(view: ProfilerView) -> ProfilerViewState {
+        let mut state = ProfilerViewState {
+            view,
+            ..Default::default()
+        };
+        state.set_profile(CpuProfile {
+            functions: vec![
+                function("main", "package:app/main.dart"),
+                function("build", "package:app/widget.dart"),
+                function("sort", "dart:core"),
+            ],
+            samples: vec![vec![1, 0], vec![1, 0], vec![1, 0], vec![2, 0]],
+            sample_period_micros: 250,
+            time_extent_micros: 1_000_000,
+        });
+        state
+    }
+
+    #[test]
+    fn test_renders_top_down_table() {
+        let mut state = loaded(ProfilerView::TopDown);
+        state.expand_selected();
+        let text = render_to_text(&state, true);
+        assert!(text.contains("[2] Top Down"));
+        assert!(text.contains("4 samples · 250µs period · 1.00s window"));
+        assert!(text.contains("100.0%"));
+        assert!(text.contains("▼ main"));
+        assert!(text.contains("75.0%"));
+        assert!(text.contains("    build"));
+    }
+
+    #[test]
+    fn test_renders_flame_graph_bars() {
+        let state = loaded(ProfilerView::FlameGraph);
+        let text = render_to_text(&state, true);
+        let lines: Vec<&str> = text.lines().collect();
+        // Row 3 is the root bar, row 4 splits 3:1 between build and sort.
+        assert!(lines[3].contains("main"));
+        assert!(lines[4].contains("build"));
+        let sort_col = lines[4].find("sort").expect("sort bar");
+        let build_col = lines[4].find("build").unwrap();
+        assert!(sort_col > build_col + 40, "sort starts 3/4 across");
+    }
+
+    #[test]
+    fn test_renders_recording_and_error_states() {
+        let mut state = ProfilerViewState::default();
+        assert!(render_to_text(&state, false).contains("VM Service not connected"));
+        assert!(render_to_text(&state, true).contains("Press [Space] to start recording"));
+
+        state.recording = Some(ProfilerRecording {
+            session_id: 1,
+            origin_micros: 0,
+        });
+        let text = render_to_text(&state, true);
+        assert!(text.contains("● REC"));
+        assert!(text.contains("Recording CPU samples"));
+
+        state.set_error(DevToolsError::new(
+            "CPU profiler is disabled",
+            "Run in debug or profile mode to record samples",
+        ));
+        assert!(render_to_text(&state, true).contains("CPU profiler is disabled"));
+    }
+}
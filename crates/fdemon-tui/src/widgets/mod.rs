@@ -8,6 +8,7 @@ mod log_view;
 pub mod modal_overlay;
 pub mod new_session_dialog;
 mod search_input;
+pub mod session_picker;
 pub mod settings_panel;
 mod tabs;
 pub mod tag_filter;
@@ -19,6 +20,7 @@ pub use header::MainHeader;
 pub use log_view::{LogView, StatusInfo};
 pub use new_session_dialog::*;
 pub use search_input::SearchInput;
+pub use session_picker::render_session_picker;
 pub use settings_panel::SettingsPanel;
 pub use tabs::SessionTabs;
 pub use tag_filter::render_tag_filter;
@@ -0,0 +1,195 @@
+//! # Session Picker Widget
+//!
+//! Overlay listing every session for jumping beyond the ninth tab. Renders
+//! centered on top of the log view when the user presses `S`: the query line,
+//! the matching sessions with their status icon, and a key hint footer.
+
+use fdemon_app::session_manager::SessionManager;
+use fdemon_app::session_picker::SessionPickerState;
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::prelude::*;
+use ratatui::symbols;
+use ratatui::widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph};
+
+use crate::theme::icons::IconSet;
+use crate::theme::palette;
+
+/// Minimum width for the session picker overlay.
+const SESSION_PICKER_MIN_WIDTH: u16 = 48;
+
+/// Maximum number of visible session rows before the list scrolls.
+const SESSION_PICKER_MAX_VISIBLE_ROWS: u16 = 15;
+
+/// Render the session picker overlay centered within `area`.
+pub fn render_session_picker(
+    frame: &mut Frame,
+    area: Rect,
+    picker: &SessionPickerState,
+    session_manager: &SessionManager,
+    icons: IconSet,
+) {
+    let visible_rows = (picker.entries.len() as u16).min(SESSION_PICKER_MAX_VISIBLE_ROWS);
+    // +6: 2 border rows + query row + 2 separator rows + footer row
+    let overlay_height = (visible_rows + 6).min(area.height.saturating_sub(2)).max(7);
+    let overlay_width = SESSION_PICKER_MIN_WIDTH
+        .max(area.width / 3)
+        .min(area.width.saturating_sub(4));
+
+    let x = area.x + (area.width.saturating_sub(overlay_width)) / 2;
+    let y = area.y + (area.height.saturating_sub(overlay_height)) / 2;
+    let overlay_area = Rect::new(x, y, overlay_width, overlay_height);
+
+    frame.render_widget(Clear, overlay_area);
+
+    let block = Block::default()
+        .title(format!(" Sessions ({}) ", picker.entries.len()))
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL)
+        .border_set(symbols::border::ROUNDED)
+        .border_style(Style::default().fg(palette::ACCENT))
+        .style(Style::default().bg(palette::POPUP_BG));
+
+    let inner = block.inner(overlay_area);
+    frame.render_widget(block, overlay_area);
+
+    let chunks = Layout::vertical([
+        Constraint::Length(1), // query
+        Constraint::Length(1), // separator
+        Constraint::Min(1),    // session list
+        Constraint::Length(1), // separator
+        Constraint::Length(1), // footer
+    ])
+    .split(inner);
+
+    // ── Query ────────────────────────────────────────────────────────────────
+    let query = Line::from(vec![
+        Span::styled("> ", Style::default().fg(palette::ACCENT)),
+        Span::styled(
+            picker.query.clone(),
+            Style::default().fg(palette::TEXT_BRIGHT),
+        ),
+        Span::styled("█", Style::default().fg(palette::ACCENT)),
+    ]);
+    frame.render_widget(Paragraph::new(query), chunks[0]);
+
+    let separator = || {
+        Paragraph::new("─".repeat(inner.width as usize))
+            .style(Style::default().fg(palette::BORDER_DIM))
+    };
+    frame.render_widget(separator(), chunks[1]);
+    frame.render_widget(separator(), chunks[3]);
+
+    // ── Session list ─────────────────────────────────────────────────────────
+    if picker.filtered.is_empty() {
+        let msg = Paragraph::new("No matching sessions")
+            .style(Style::default().fg(palette::TEXT_MUTED))
+            .alignment(Alignment::Center);
+        frame.render_widget(msg, chunks[2]);
+    } else {
+        let items: Vec<ListItem> = picker
+            .visible()
+            .enumerate()
+            .map(|(i, entry)| {
+                let phase_icon = session_manager.get(entry.session_id).map(|handle| {
+                    let (icon, _label, style) =
+                        crate::theme::styles::phase_indicator(&handle.session.phase, &icons);
+                    Span::styled(icon, style)
+                });
+                let text_style = if i == picker.selected {
+                    Style::default()
+                        .fg(palette::CONTRAST_FG)
+                        .bg(palette::ACCENT)
+                } else {
+                    Style::default().fg(palette::TEXT_PRIMARY)
+                };
+                let mut spans = vec![Span::raw(" ")];
+                spans.extend(phase_icon);
+                spans.push(Span::raw(" "));
+                spans.push(Span::raw(entry.label.clone()));
+                ListItem::new(Line::from(spans)).style(text_style)
+            })
+            .collect();
+
+        let mut list_state = ListState::default().with_selected(Some(picker.selected));
+        frame.render_stateful_widget(List::new(items), chunks[2], &mut list_state);
+    }
+
+    // ── Footer ───────────────────────────────────────────────────────────────
+    let footer = Paragraph::new("[↑↓] Move  [Enter] Switch  [Esc] Close")
+        .style(Style::default().fg(palette::TEXT_SECONDARY));
+    frame.render_widget(footer, chunks[4]);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fdemon_app::config::IconMode;
+
+    fn manager_with_sessions(count: usize) -> SessionManager {
+        let mut manager = SessionManager::new();
+        manager.set_max_sessions(count);
+        for i in 0..count {
+            let device = fdemon_daemon::Device {
+                id: format!("dev-{i}"),
+                name: format!("Lab phone {:02}", i + 1),
+                platform: "android".to_string(),
+                emulator: false,
+                category: None,
+                platform_type: None,
+                ephemeral: false,
+                emulator_id: None,
+            };
+            manager.create_session(&device).unwrap();
+        }
+        manager
+    }
+
+    fn render_to_string(picker: &SessionPickerState, manager: &SessionManager) -> String {
+        let backend = ratatui::backend::TestBackend::new(80, 30);
+        let mut terminal = ratatui::Terminal::new(backend).unwrap();
+        terminal
+            .draw(|frame| {
+                render_session_picker(
+                    frame,
+                    frame.area(),
+                    picker,
+                    manager,
+                    IconSet::new(IconMode::Unicode),
+                );
+            })
+            .unwrap();
+        let buffer = terminal.backend().buffer();
+        let mut out = String::new();
+        for y in 0..buffer.area.height {
+            for x in 0..buffer.area.width {
+                out.push_str(buffer[(x, y)].symbol());
+            }
+            out.push('\n');
+        }
+        out
+    }
+
+    #[test]
+    fn test_render_lists_sessions_beyond_nine() {
+        let manager = manager_with_sessions(12);
+        let mut picker = SessionPickerState::new(&manager);
+        picker.input_char('1');
+        picker.input_char('2');
+
+        let rendered = render_to_string(&picker, &manager);
+
+        assert!(rendered.contains("Sessions (12)"));
+        assert!(rendered.contains("> 12"));
+        assert!(rendered.contains("12 Lab phone 12 · android"));
+        assert!(rendered.contains("[Enter] Switch"));
+    }
+
+    #[test]
+    fn test_render_no_match() {
+        let manager = manager_with_sessions(2);
+        let mut picker = SessionPickerState::new(&manager);
+        picker.input_char('z');
+
+        assert!(render_to_string(&picker, &manager).contains("No matching sessions"));
+    }
+}
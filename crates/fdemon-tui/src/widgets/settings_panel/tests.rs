@@ -171,9 +171,10 @@ fn test_project_settings_items_count() {
     let settings = Settings::default();
     let items = project_settings_items(&settings);
 
-    // Should have 34 items across 8 sections (includes DevTools + DevTools Logging + DAP Server +
-    // behavior.auto_launch added in cache-auto-launch-gate)
-    assert_eq!(items.len(), 34);
+    // Should have 37 items across 8 sections (includes DevTools + DevTools Logging + DAP Server +
+    // behavior.auto_launch added in cache-auto-launch-gate + behavior.screenshot_dir +
+    // behavior.restore_workspace + behavior.max_sessions)
+    assert_eq!(items.len(), 37);
 }
 
 #[test]
@@ -276,6 +277,8 @@ fn test_launch_config_items() {
         mode: FlutterMode::Debug,
         flavor: Some("dev".to_string()),
         auto_start: true,
+        attach: false,
+        debug_url: None,
         dart_defines: [("API_URL".to_string(), "https://dev.api.com".to_string())]
             .into_iter()
             .collect(),
@@ -465,6 +468,8 @@ fn test_vscode_config_items_count() {
         mode: FlutterMode::Debug,
         flavor: None,
         auto_start: false,
+        attach: false,
+        debug_url: None,
         dart_defines: Default::default(),
         extra_args: vec![],
         entry_point: None,
@@ -484,6 +489,8 @@ fn test_vscode_config_items_all_readonly() {
         mode: FlutterMode::Debug,
         flavor: None,
         auto_start: false,
+        attach: false,
+        debug_url: None,
         dart_defines: Default::default(),
         extra_args: vec![],
         entry_point: None,
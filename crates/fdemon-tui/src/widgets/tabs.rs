@@ -5,6 +5,7 @@
 use ratatui::{
     buffer::Buffer,
     layout::Rect,
+    style::Style,
     text::{Line, Span},
     widgets::{Paragraph, Tabs, Widget},
 };
@@ -12,6 +13,10 @@ use ratatui::{
 use fdemon_app::session_manager::SessionManager;
 
 use crate::theme::icons::IconSet;
+use crate::theme::palette;
+
+/// Width reserved for an overflow indicator such as `‹12 ` or ` 3›`.
+const OVERFLOW_INDICATOR_WIDTH: u16 = 4;
 
 /// Widget displaying session tabs in a standalone subheader row
 pub struct SessionTabs<'a> {
@@ -41,12 +46,17 @@ impl<'a> SessionTabs<'a> {
                 // Truncate device name if too long
                 let name = truncate_name(&session.device_name, 12);
 
-                // Build line with styled icon span
-                Line::from(vec![
+                // Build line with styled icon span; group members get a '+'
+                let mut spans = vec![
                     Span::raw(" "),
                     Span::styled(icon, style),
-                    Span::raw(format!(" {} ", name)),
-                ])
+                    Span::raw(format!(" {}", name)),
+                ];
+                if session.in_group {
+                    spans.push(Span::styled("+", Style::default().fg(palette::ACCENT)));
+                }
+                spans.push(Span::raw(" "));
+                Line::from(spans)
             })
             .collect()
     }
@@ -83,24 +93,54 @@ impl<'a> SessionTabs<'a> {
         }
     }
 
-    /// Render full tabs UI for multiple sessions
+    /// Render full tabs UI for multiple sessions.
+    ///
+    /// When the tabs don't fit, the bar scrolls to keep the selected tab
+    /// visible and shows how many tabs are hidden on each side.
     fn render_tabs(&self, area: Rect, buf: &mut Buffer) {
         let titles = self.tab_titles();
         let selected = self.session_manager.selected_index();
 
-        let tabs = Tabs::new(titles)
-            .select(selected)
-            .highlight_style(crate::theme::styles::focused_selected())
-            .divider("│");
-
         // Render with left padding
-        let padded_area = Rect {
+        let mut padded_area = Rect {
             x: area.x + 1,
             y: area.y,
             width: area.width.saturating_sub(2),
             height: area.height,
         };
 
+        // Each tab is padded by one space on both sides
+        let widths: Vec<u16> = titles.iter().map(|t| t.width() as u16 + 2).collect();
+        let (start, end) = visible_range(&widths, selected, padded_area.width);
+        let indicator_style = Style::default().fg(palette::TEXT_MUTED);
+
+        if start > 0 {
+            buf.set_string(
+                padded_area.x,
+                padded_area.y,
+                format!("‹{} ", start),
+                indicator_style,
+            );
+            padded_area.x += OVERFLOW_INDICATOR_WIDTH;
+            padded_area.width = padded_area.width.saturating_sub(OVERFLOW_INDICATOR_WIDTH);
+        }
+        if end < titles.len() {
+            let indicator = format!(" {}›", titles.len() - end);
+            let width = indicator.chars().count() as u16;
+            buf.set_string(
+                padded_area.right().saturating_sub(width),
+                padded_area.y,
+                indicator,
+                indicator_style,
+            );
+            padded_area.width = padded_area.width.saturating_sub(OVERFLOW_INDICATOR_WIDTH);
+        }
+
+        let tabs = Tabs::new(titles[start..end].to_vec())
+            .select(selected.saturating_sub(start))
+            .highlight_style(crate::theme::styles::focused_selected())
+            .divider("│");
+
         tabs.render(padded_area, buf);
     }
 }
@@ -121,6 +161,40 @@ impl Widget for SessionTabs<'_> {
     }
 }
 
+/// Range of tabs `start..end` to render within `available` columns, keeping
+/// `selected` visible. Tabs are separated by a one-column divider, and an
+/// overflow indicator is reserved on each side that has hidden tabs.
+fn visible_range(widths: &[u16], selected: usize, available: u16) -> (usize, usize) {
+    if widths.is_empty() {
+        return (0, 0);
+    }
+    let needed = |start: usize, end: usize| -> u16 {
+        let tabs: u16 = widths[start..end].iter().sum::<u16>() + (end - start - 1) as u16;
+        let left = if start > 0 {
+            OVERFLOW_INDICATOR_WIDTH
+        } else {
+            0
+        };
+        let right = if end < widths.len() {
+            OVERFLOW_INDICATOR_WIDTH
+        } else {
+            0
+        };
+        tabs + left + right
+    };
+
+    let selected = selected.min(widths.len() - 1);
+    let mut start = 0;
+    while start < selected && needed(start, selected + 1) > available {
+        start += 1;
+    }
+    let mut end = selected + 1;
+    while end < widths.len() && needed(start, end + 1) <= available {
+        end += 1;
+    }
+    (start, end)
+}
+
 /// Truncate a name to max length, adding ellipsis if needed
 fn truncate_name(name: &str, max_len: usize) -> String {
     if name.chars().count() <= max_len {
@@ -219,6 +293,27 @@ mod tests {
         assert!(title_str.contains('●')); // Running icon
     }
 
+    #[test]
+    fn test_tab_title_marks_group_sessions() {
+        let mut manager = SessionManager::new();
+        let id = manager
+            .create_session(&test_device("d1", "iPhone"))
+            .unwrap();
+        let icons = IconSet::new(IconMode::Unicode);
+        let title = |manager: &SessionManager| -> String {
+            SessionTabs::new(manager, icons).tab_titles()[0]
+                .spans
+                .iter()
+                .map(|s| s.content.to_string())
+                .collect()
+        };
+
+        assert!(title(&manager).ends_with(" iPhone "));
+
+        manager.get_mut(id).unwrap().session.in_group = true;
+        assert!(title(&manager).ends_with(" iPhone+ "));
+    }
+
     #[test]
     fn test_standalone_session_tabs() {
         use ratatui::{backend::TestBackend, Terminal};
@@ -250,6 +345,57 @@ mod tests {
         assert!(content.contains("Pixel 8"));
     }
 
+    #[test]
+    fn test_visible_range_fits_all() {
+        assert_eq!(visible_range(&[10, 10, 10], 1, 80), (0, 3));
+        assert_eq!(visible_range(&[], 0, 80), (0, 0));
+    }
+
+    #[test]
+    fn test_visible_range_scrolls_to_selection() {
+        let widths = [10; 12];
+        // From the start: 5 tabs + 4 dividers + right indicator = 58
+        assert_eq!(visible_range(&widths, 0, 60), (0, 5));
+        // Selecting past the window scrolls it, reserving both indicators
+        let (start, end) = visible_range(&widths, 11, 60);
+        assert_eq!(end, 12);
+        assert_eq!(start, 7);
+        let (start, end) = visible_range(&widths, 6, 60);
+        assert!(start <= 6 && 6 < end);
+    }
+
+    #[test]
+    fn test_session_tabs_overflow_keeps_selection_visible() {
+        use ratatui::{backend::TestBackend, Terminal};
+
+        let mut manager = SessionManager::new();
+        manager.set_max_sessions(12);
+        for i in 1..=12 {
+            manager
+                .create_session(&test_device(&format!("d{i}"), &format!("Phone {i:02}")))
+                .unwrap();
+        }
+        manager.select_by_index(11);
+
+        let icons = IconSet::new(IconMode::Unicode);
+        let mut terminal = Terminal::new(TestBackend::new(80, 1)).unwrap();
+        terminal
+            .draw(|f| f.render_widget(SessionTabs::new(&manager, icons), f.area()))
+            .unwrap();
+
+        let content: String = terminal
+            .backend()
+            .buffer()
+            .content
+            .iter()
+            .map(|c| c.symbol())
+            .collect();
+        assert!(content.contains("Phone 12"), "{content}");
+        assert!(!content.contains("Phone 01"));
+        assert!(content.contains('‹'));
+        assert!(!content.contains('›'));
+    }
+
     #[test]
     fn test_session_tabs_single_session_renders_device_name() {
         use ratatui::{backend::TestBackend, Terminal};
@@ -105,6 +105,65 @@ pub enum HeadlessEvent {
 
     /// DAP server started and bound to a port
     DapServerStarted { port: u16, timestamp: i64 },
+
+    /// Screenshot written to disk
+    ScreenshotSaved {
+        session_id: String,
+        path: String,
+        timestamp: i64,
+    },
+
+    /// Screenshot capture failed
+    ScreenshotFailed {
+        session_id: String,
+        error: String,
+        timestamp: i64,
+    },
+
+    /// Performance data exported to a directory
+    PerformanceExported {
+        session_id: String,
+        path: String,
+        timestamp: i64,
+    },
+
+    /// Performance data export failed
+    PerformanceExportFailed {
+        session_id: String,
+        error: String,
+        timestamp: i64,
+    },
+
+    /// Performance baseline written to disk
+    BaselineSaved {
+        session_id: String,
+        path: String,
+        timestamp: i64,
+    },
+
+    /// Performance baseline could not be written
+    BaselineSaveFailed {
+        session_id: String,
+        error: String,
+        timestamp: i64,
+    },
+
+    /// Run compared against a baseline; `metrics` maps each metric to its
+    /// baseline, current value, delta and whether it regressed
+    BaselineCompared {
+        session_id: String,
+        baseline: String,
+        regressed: bool,
+        metrics: serde_json::Value,
+        timestamp: i64,
+    },
+
+    /// Baseline comparison failed (e.g. the baseline does not exist)
+    BaselineCompareFailed {
+        session_id: String,
+        error: String,
+        timestamp: i64,
+    },
 }
 
 #[allow(dead_code)] // Future functionality - constructors will be used when headless mode is fully implemented
@@ -243,6 +302,73 @@ impl HeadlessEvent {
             timestamp: Self::now(),
         }
     }
+
+    pub fn screenshot_saved(session_id: &str, path: &str) -> Self {
+        Self::ScreenshotSaved {
+            session_id: session_id.to_string(),
+            path: path.to_string(),
+            timestamp: Self::now(),
+        }
+    }
+
+    pub fn screenshot_failed(session_id: &str, error: String) -> Self {
+        Self::ScreenshotFailed {
+            session_id: session_id.to_string(),
+            error,
+            timestamp: Self::now(),
+        }
+    }
+
+    pub fn performance_exported(session_id: &str, path: &str) -> Self {
+        Self::PerformanceExported {
+            session_id: session_id.to_string(),
+            path: path.to_string(),
+            timestamp: Self::now(),
+        }
+    }
+
+    pub fn performance_export_failed(session_id: &str, error: String) -> Self {
+        Self::PerformanceExportFailed {
+            session_id: session_id.to_string(),
+            error,
+            timestamp: Self::now(),
+        }
+    }
+
+    pub fn baseline_saved(session_id: &str, path: &str) -> Self {
+        Self::BaselineSaved {
+            session_id: session_id.to_string(),
+            path: path.to_string(),
+            timestamp: Self::now(),
+        }
+    }
+
+    pub fn baseline_save_failed(session_id: &str, error: String) -> Self {
+        Self::BaselineSaveFailed {
+            session_id: session_id.to_string(),
+            error,
+            timestamp: Self::now(),
+        }
+    }
+
+    pub fn baseline_compared(session_id: &str, comparison: &fdemon_core::PerfComparison) -> Self {
+        let json = comparison.to_json();
+        Self::BaselineCompared {
+            session_id: session_id.to_string(),
+            baseline: comparison.baseline_name.clone(),
+            regressed: comparison.has_regressions(),
+            metrics: json["metrics"].clone(),
+            timestamp: Self::now(),
+        }
+    }
+
+    pub fn baseline_compare_failed(session_id: &str, error: String) -> Self {
+        Self::BaselineCompareFailed {
+            session_id: session_id.to_string(),
+            error,
+            timestamp: Self::now(),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -344,4 +470,50 @@ mod tests {
         assert_eq!(value["port"], 54321);
         assert!(value["timestamp"].is_number());
     }
+
+    #[test]
+    fn test_screenshot_saved_serialization() {
+        let event = HeadlessEvent::screenshot_saved("session-1", "/app/shots/pixel-1.png");
+        let json = serde_json::to_string(&event).expect("serialization failed");
+
+        let value: serde_json::Value = serde_json::from_str(&json).expect("invalid JSON");
+
+        assert_eq!(value["event"], "screenshot_saved");
+        assert_eq!(value["session_id"], "session-1");
+        assert_eq!(value["path"], "/app/shots/pixel-1.png");
+        assert!(value["timestamp"].is_number());
+    }
+
+    #[test]
+    fn test_performance_exported_serialization() {
+        let event = HeadlessEvent::performance_exported("session-1", "/app/.fdemon/perf/run");
+        let value = serde_json::to_value(&event).expect("serialization failed");
+
+        assert_eq!(value["event"], "performance_exported");
+        assert_eq!(value["session_id"], "session-1");
+        assert_eq!(value["path"], "/app/.fdemon/perf/run");
+        assert!(value["timestamp"].is_number());
+    }
+
+    #[test]
+    fn test_baseline_compared_serialization() {
+        use fdemon_core::{MetricDelta, PerfComparison, PerfMetric};
+
+        let comparison = PerfComparison {
+            baseline_name: "scroll".to_string(),
+            metrics: vec![MetricDelta {
+                metric: PerfMetric::P95FrameTime,
+                baseline: 10.0,
+                current: 14.0,
+                regressed: true,
+            }],
+        };
+        let event = HeadlessEvent::baseline_compared("session-1", &comparison);
+        let value = serde_json::to_value(&event).expect("serialization failed");
+
+        assert_eq!(value["event"], "baseline_compared");
+        assert_eq!(value["baseline"], "scroll");
+        assert_eq!(value["regressed"], true);
+        assert_eq!(value["metrics"]["p95_frame_ms"]["delta"], 4.0);
+    }
 }
@@ -4,12 +4,16 @@
 //! It processes daemon events and emits JSON events to stdout for E2E testing.
 
 use std::path::Path;
+use std::process::ExitCode;
 
 use tokio::sync::mpsc;
-use tracing::{error, info, warn};
+use tracing::{debug, error, info, warn};
 
 use fdemon_app::{
-    config::{emit_migration_nudge, load_all_configs, should_auto_start_dap, NudgeMode},
+    config::{
+        add_cli_attach_config, emit_migration_nudge, load_all_configs, should_auto_start_dap,
+        NudgeMode,
+    },
     message::{AutoLaunchSuccess, Message},
     spawn::find_auto_launch_target,
     state::AppState,
@@ -20,8 +24,15 @@ use fdemon_daemon::devices;
 
 use super::HeadlessEvent;
 
+/// Process exit code when a baseline comparison reported a regression.
+pub const PERF_REGRESSION_EXIT_CODE: u8 = 3;
+
 /// Run in headless mode - output JSON events instead of TUI.
 ///
+/// Returns [`PERF_REGRESSION_EXIT_CODE`] after shutdown if any
+/// `baseline-compare` during the run exceeded its thresholds, for `main` to
+/// exit with.
+///
 /// # Arguments
 ///
 /// * `project_path` — Path to the Flutter project directory.
@@ -32,11 +43,14 @@ use super::HeadlessEvent;
 /// * `dap_config` — If `Some(ide)`, stores the CLI-provided IDE override on
 ///   `AppState` so `handle_started()` can pass it to `GenerateIdeConfig`,
 ///   bypassing environment-based IDE detection.
+/// * `attach_url` — If `Some(uri)`, auto-start attaches to the app at that
+///   VM Service URI instead of launching one.
 pub async fn run_headless(
     project_path: &Path,
     dap_port: Option<u16>,
     dap_config: Option<fdemon_app::config::ParentIde>,
-) -> Result<()> {
+    attach_url: Option<String>,
+) -> Result<ExitCode> {
     info!("═══════════════════════════════════════════════════════");
     info!("Flutter Demon starting in HEADLESS mode");
     info!("Project: {}", project_path.display());
@@ -57,6 +71,11 @@ pub async fn run_headless(
         engine.apply_cli_dap_config_override(ide);
     }
 
+    // Apply --attach: auto-start resolves to an attach config for the URI.
+    if let Some(url) = attach_url {
+        engine.apply_cli_attach(url);
+    }
+
     // Spawn headless-specific stdin reader
     let stdin_tx = engine.msg_sender();
     std::thread::spawn(move || {
@@ -80,19 +99,33 @@ pub async fn run_headless(
     engine.shutdown().await;
 
     info!("Flutter Demon headless mode exiting");
-    result
+    if !result? {
+        return Ok(ExitCode::SUCCESS);
+    }
+    warn!(
+        "Performance regression detected, exiting with code {}",
+        PERF_REGRESSION_EXIT_CODE
+    );
+    Ok(ExitCode::from(PERF_REGRESSION_EXIT_CODE))
 }
 
 /// Main headless event loop
-async fn headless_event_loop(engine: &mut Engine) -> Result<()> {
+///
+/// Returns whether any baseline comparison reported a regression.
+async fn headless_event_loop(engine: &mut Engine) -> Result<bool> {
     // Track how many logs we've already emitted to prevent duplicates
     let mut last_emitted_log_count: usize = 0;
+    let mut perf_regressed = false;
 
     loop {
-        // Check for shutdown
+        // Check for shutdown. A pending baseline comparison must report
+        // first, otherwise a regression would not set the exit code.
         if engine.should_quit() {
-            info!("Quit requested");
-            break;
+            if !engine.state.session_manager.any_baseline_compare_pending() {
+                info!("Quit requested");
+                break;
+            }
+            debug!("Quit requested, waiting for baseline comparison");
         }
 
         // Wait for next message
@@ -100,6 +133,13 @@ async fn headless_event_loop(engine: &mut Engine) -> Result<()> {
             Some(msg) => {
                 // Emit events based on message type before processing
                 emit_pre_message_events(&engine.state, &msg);
+                if let Message::PerfBaselineCompared {
+                    result: Ok(comparison),
+                    ..
+                } = &msg
+                {
+                    perf_regressed |= comparison.has_regressions();
+                }
 
                 // Process through engine
                 engine.process_message(msg);
@@ -118,7 +158,7 @@ async fn headless_event_loop(engine: &mut Engine) -> Result<()> {
         }
     }
 
-    Ok(())
+    Ok(perf_regressed)
 }
 
 /// Emit events before message processing
@@ -151,6 +191,38 @@ fn emit_pre_message_events(_state: &AppState, msg: &Message) {
         Message::DapServerStarted { port } => {
             HeadlessEvent::dap_server_started(*port).emit();
         }
+        Message::ScreenshotSaved { session_id, result } => {
+            let sid = session_id.to_string();
+            match result {
+                Ok(path) => {
+                    HeadlessEvent::screenshot_saved(&sid, &path.display().to_string()).emit()
+                }
+                Err(e) => HeadlessEvent::screenshot_failed(&sid, e.clone()).emit(),
+            }
+        }
+        Message::PerformanceExported { session_id, result } => {
+            let sid = session_id.to_string();
+            match result {
+                Ok(path) => {
+                    HeadlessEvent::performance_exported(&sid, &path.display().to_string()).emit()
+                }
+                Err(e) => HeadlessEvent::performance_export_failed(&sid, e.clone()).emit(),
+            }
+        }
+        Message::PerfBaselineSaved { session_id, result } => {
+            let sid = session_id.to_string();
+            match result {
+                Ok(path) => HeadlessEvent::baseline_saved(&sid, &path.display().to_string()).emit(),
+                Err(e) => HeadlessEvent::baseline_save_failed(&sid, e.clone()).emit(),
+            }
+        }
+        Message::PerfBaselineCompared { session_id, result } => {
+            let sid = session_id.to_string();
+            match result {
+                Ok(comparison) => HeadlessEvent::baseline_compared(&sid, comparison).emit(),
+                Err(e) => HeadlessEvent::baseline_compare_failed(&sid, e.clone()).emit(),
+            }
+        }
         _ => {}
     }
 }
@@ -216,6 +288,31 @@ fn spawn_stdin_reader_blocking(msg_tx: mpsc::Sender<Message>) {
                         info!("Stdin: hot restart requested");
                         let _ = msg_tx.blocking_send(Message::HotRestart);
                     }
+                    "p" | "screenshot" => {
+                        info!("Stdin: screenshot requested");
+                        let _ = msg_tx.blocking_send(Message::CaptureScreenshot {
+                            all_sessions: false,
+                        });
+                    }
+                    "P" | "screenshot-all" => {
+                        info!("Stdin: screenshot of all sessions requested");
+                        let _ =
+                            msg_tx.blocking_send(Message::CaptureScreenshot { all_sessions: true });
+                    }
+                    "e" | "export-perf" => {
+                        info!("Stdin: performance export requested");
+                        let _ = msg_tx.blocking_send(Message::ExportPerformance);
+                    }
+                    _ if is_command(trimmed, "baseline-save") => {
+                        let name = command_arg(trimmed);
+                        info!("Stdin: performance baseline save requested");
+                        let _ = msg_tx.blocking_send(Message::SavePerfBaseline { name });
+                    }
+                    _ if is_command(trimmed, "baseline-compare") => {
+                        let name = command_arg(trimmed);
+                        info!("Stdin: performance baseline comparison requested");
+                        let _ = msg_tx.blocking_send(Message::ComparePerfBaseline { name });
+                    }
                     "q" | "quit" => {
                         info!("Stdin: quit requested");
                         let _ = msg_tx.blocking_send(Message::Quit);
@@ -239,6 +336,18 @@ fn spawn_stdin_reader_blocking(msg_tx: mpsc::Sender<Message>) {
     info!("Stdin reader exiting");
 }
 
+/// Whether `line` is `command`, optionally followed by whitespace and an argument.
+fn is_command(line: &str, command: &str) -> bool {
+    line.split_whitespace().next() == Some(command)
+}
+
+/// The argument after the command word of `line`, if any.
+fn command_arg(line: &str) -> Option<String> {
+    line.split_once(char::is_whitespace)
+        .map(|(_, arg)| arg.trim().to_string())
+        .filter(|arg| !arg.is_empty())
+}
+
 /// **Sibling-bug coordination note (added 2026-04-29):**
 /// The `find_auto_launch_target` integration in this function was originally
 /// scoped to sibling bug `launch-toml-device-ignored` Task 03. It was absorbed
@@ -269,8 +378,12 @@ async fn headless_auto_start(engine: &mut Engine) {
 
     let project_path = engine.project_path.clone();
 
-    // Load launch.toml configs to drive tier-1 (auto_start) and tier-3 (first config) resolution
-    let configs = load_all_configs(&project_path);
+    // Load launch.toml configs to drive tier-1 (auto_start) and tier-3 (first config) resolution.
+    // An --attach URI adds an auto_start attach config ahead of them.
+    let mut configs = load_all_configs(&project_path);
+    if let Some(url) = &engine.state.cli_attach_url {
+        add_cli_attach_config(&mut configs, url);
+    }
 
     // Migration nudge: user has a cached device but the flag is not set. In
     // headless mode the cache is never consulted, so this helps CI/script users
@@ -677,4 +790,16 @@ auto_start = true
             "auto_start config name must be carried through"
         );
     }
+
+    #[test]
+    fn test_baseline_command_parsing() {
+        assert!(is_command("baseline-save", "baseline-save"));
+        assert!(is_command("baseline-save  scroll", "baseline-save"));
+        assert!(!is_command("baseline-saved", "baseline-save"));
+        assert_eq!(command_arg("baseline-save"), None);
+        assert_eq!(
+            command_arg("baseline-compare  release scroll "),
+            Some("release scroll".to_string())
+        );
+    }
 }
@@ -6,13 +6,14 @@ mod dap_stdio;
 mod headless;
 mod tui;
 
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
 
 use clap::Parser;
 use fdemon_core::prelude::*;
 use fdemon_core::{
-    discover_flutter_projects, get_project_type, is_runnable_flutter_project, ProjectType,
-    DEFAULT_MAX_DEPTH,
+    discover_attachable_flutter_projects, discover_flutter_projects, get_project_type,
+    is_attachable_flutter_project, is_runnable_flutter_project, ProjectType, DEFAULT_MAX_DEPTH,
 };
 use fdemon_tui::{select_project, SelectionResult};
 
@@ -75,10 +76,24 @@ struct Args {
     /// Cannot be combined with --dap-stdio.
     #[arg(long, value_name = "IDE", conflicts_with = "dap_stdio")]
     dap_config: Option<String>,
+
+    /// Attach to an already-running app by its VM Service URI instead of
+    /// launching one.
+    ///
+    /// Runs `flutter attach --debug-url <URI>` on the first available device.
+    /// The URI is printed by the app at startup ("The Dart VM service is
+    /// listening on ..."). For another device or a profile build, use an
+    /// `attach = true` configuration in `.fdemon/launch.toml` instead.
+    ///
+    /// Example: `fdemon --attach http://127.0.0.1:50300/abc=/ example/app3`
+    #[arg(long, value_name = "URI", conflicts_with = "dap_stdio")]
+    attach: Option<String>,
 }
 
+/// Exits with the code headless mode returns (see
+/// `headless::runner::PERF_REGRESSION_EXIT_CODE`) once destructors have run.
 #[tokio::main]
-async fn main() -> Result<()> {
+async fn main() -> Result<ExitCode> {
     // Initialize error handling (must happen once at binary startup)
     color_eyre::install().map_err(|e| Error::terminal(e.to_string()))?;
 
@@ -100,7 +115,9 @@ async fn main() -> Result<()> {
     // All tracing output is already going to a file (fdemon_core::logging::init above),
     // so stdout is clean for the DAP wire protocol.
     if args.dap_stdio {
-        return dap_stdio::runner::run_dap_stdio().await;
+        return dap_stdio::runner::run_dap_stdio()
+            .await
+            .map(|()| ExitCode::SUCCESS);
     }
 
     // --dap-config <IDE> with --dap-port: standalone config generation mode.
@@ -142,7 +159,7 @@ async fn main() -> Result<()> {
                         println!("IDE '{}' does not support DAP config generation", ide_str);
                     }
                 }
-                return Ok(());
+                return Ok(ExitCode::SUCCESS);
             }
             // Combined mode: validate the IDE name early so the user gets a clear
             // error before fdemon starts the TUI, then store for use when the DAP
@@ -163,14 +180,31 @@ async fn main() -> Result<()> {
         .path
         .unwrap_or_else(|| std::env::current_dir().unwrap_or_else(|_| PathBuf::from(".")));
 
-    // Step 1: Check if base_path is directly a runnable Flutter project
-    if is_runnable_flutter_project(&base_path) {
+    // Flutter modules can't be launched, but `flutter attach` can target them
+    let attach = attach_requested(args.attach.as_deref(), &base_path);
+
+    // Step 1: Check if base_path is directly a runnable (or attachable) Flutter project
+    if is_runnable_flutter_project(&base_path)
+        || (attach && is_attachable_flutter_project(&base_path))
+    {
         info!("Project path: {}", base_path.display());
         return if args.headless {
-            headless::runner::run_headless(&base_path, args.dap_port, dap_config_override).await
+            headless::runner::run_headless(
+                &base_path,
+                args.dap_port,
+                dap_config_override,
+                args.attach.clone(),
+            )
+            .await
         } else {
-            tui::runner::run_with_project_and_dap(&base_path, args.dap_port, dap_config_override)
-                .await
+            tui::runner::run_with_project_and_dap(
+                &base_path,
+                args.dap_port,
+                dap_config_override,
+                args.attach.clone(),
+            )
+            .await
+            .map(|()| ExitCode::SUCCESS)
         };
     }
 
@@ -182,6 +216,14 @@ async fn main() -> Result<()> {
                 eprintln!("   Plugins cannot be run directly. Searching for runnable examples...");
                 eprintln!();
             }
+            Some(ProjectType::Module) => {
+                eprintln!("📦 Detected Flutter module at: {}", base_path.display());
+                eprintln!("   Modules can only be attached to. Pass --attach <URI> or set");
+                eprintln!(
+                    "   `attach = true` in .fdemon/launch.toml. Searching for runnable projects..."
+                );
+                eprintln!();
+            }
             Some(ProjectType::FlutterPackage) => {
                 eprintln!("📦 Detected Flutter package at: {}", base_path.display());
                 eprintln!("   Package has no platform directories (android/, ios/, etc.).");
@@ -199,7 +241,11 @@ async fn main() -> Result<()> {
     }
 
     // Step 3: Discover runnable Flutter projects in subdirectories
-    let discovery = discover_flutter_projects(&base_path, DEFAULT_MAX_DEPTH);
+    let discovery = if attach {
+        discover_attachable_flutter_projects(&base_path, DEFAULT_MAX_DEPTH)
+    } else {
+        discover_flutter_projects(&base_path, DEFAULT_MAX_DEPTH)
+    };
 
     // Log skipped projects for debugging (only if there are some and we found nothing)
     if !discovery.skipped.is_empty() && discovery.projects.is_empty() {
@@ -227,6 +273,7 @@ async fn main() -> Result<()> {
             eprintln!("  • pubspec.yaml with 'sdk: flutter' dependency");
             eprintln!("  • At least one platform directory (android/, ios/, macos/, web/, linux/, windows/)");
             eprintln!("  • NOT be a plugin (no 'flutter: plugin:' section)");
+            eprintln!("Flutter modules ('flutter: module:') are accepted with --attach.");
             eprintln!();
             eprintln!("Hint: Run flutter-demon from a Flutter app directory,");
             eprintln!("      or pass the project path as an argument:");
@@ -239,10 +286,22 @@ async fn main() -> Result<()> {
             eprintln!("✅ Found Flutter project: {}", project.display());
             info!("Project path: {}", project.display());
             if args.headless {
-                headless::runner::run_headless(project, args.dap_port, dap_config_override).await
+                headless::runner::run_headless(
+                    project,
+                    args.dap_port,
+                    dap_config_override,
+                    args.attach.clone(),
+                )
+                .await
             } else {
-                tui::runner::run_with_project_and_dap(project, args.dap_port, dap_config_override)
-                    .await
+                tui::runner::run_with_project_and_dap(
+                    project,
+                    args.dap_port,
+                    dap_config_override,
+                    args.attach.clone(),
+                )
+                .await
+                .map(|()| ExitCode::SUCCESS)
             }
         }
         _ => {
@@ -255,7 +314,13 @@ async fn main() -> Result<()> {
                     project.display()
                 );
                 info!("Project path: {}", project.display());
-                headless::runner::run_headless(project, args.dap_port, dap_config_override).await
+                headless::runner::run_headless(
+                    project,
+                    args.dap_port,
+                    dap_config_override,
+                    args.attach.clone(),
+                )
+                .await
             } else {
                 match select_project(&discovery.projects, &discovery.searched_from)? {
                     SelectionResult::Selected(project) => {
@@ -264,15 +329,26 @@ async fn main() -> Result<()> {
                             &project,
                             args.dap_port,
                             dap_config_override,
+                            args.attach.clone(),
                         )
                         .await
+                        .map(|()| ExitCode::SUCCESS)
                     }
                     SelectionResult::Cancelled => {
                         eprintln!("Selection cancelled.");
-                        Ok(())
+                        Ok(ExitCode::SUCCESS)
                     }
                 }
             }
         }
     }
 }
+
+/// Whether this launch will use `flutter attach`: `--attach` was passed, or
+/// the project's `.fdemon/launch.toml` has an `attach = true` configuration.
+fn attach_requested(cli_attach: Option<&str>, project_path: &Path) -> bool {
+    cli_attach.is_some()
+        || fdemon_app::config::load_launch_configs(project_path)
+            .iter()
+            .any(|resolved| resolved.config.attach)
+}
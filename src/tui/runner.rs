@@ -26,11 +26,14 @@ use fdemon_core::prelude::*;
 /// * `dap_config` — If `Some(ide)`, stores the CLI-provided IDE override on
 ///   `AppState` so `handle_started()` can pass it to `GenerateIdeConfig`,
 ///   bypassing environment-based IDE detection.
+/// * `attach_url` — If `Some(uri)`, attaches to the app at that VM Service
+///   URI at startup instead of showing the NewSessionDialog.
 pub async fn run_with_project_and_dap(
     project_path: &Path,
     dap_port: Option<u16>,
     dap_config: Option<fdemon_app::config::ParentIde>,
+    attach_url: Option<String>,
 ) -> Result<()> {
     // Delegate to the TUI runner which handles DAP port override and auto-start.
-    fdemon_tui::run_with_project_and_dap(project_path, dap_port, dap_config).await
+    fdemon_tui::run_with_project_and_dap(project_path, dap_port, dap_config, attach_url).await
 }